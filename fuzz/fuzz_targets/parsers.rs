@@ -0,0 +1,98 @@
+//! Feeds arbitrary text to the input parsers behind the tools.
+//!
+//! The first byte picks the parser from [`PARSERS`] and the rest is the
+//! input, normalized as the tools normalize it. Parsed rules, durations, and
+//! formats are also applied, so the code reading their results is covered.
+
+#![no_main]
+
+use jiff::{Zoned, civil::DateTime, tz::TimeZone};
+use libfuzzer_sys::fuzz_target;
+use mcp_time::{
+    calendar_system::{self, CalendarSystem},
+    cron,
+    datetime_format::DatetimeFormat,
+    detect, duration,
+    epoch::EpochValue,
+    expression, ics,
+    locale::Locale,
+    natural,
+    rrule::RRule,
+    unicode,
+    validate::Expected,
+};
+
+/// A fixed instant to resolve relative input against.
+fn reference() -> Zoned {
+    DateTime::constant(2025, 3, 9, 1, 30, 0, 0)
+        .to_zoned(TimeZone::get("America/New_York").expect("bundled zone"))
+        .expect("valid reference")
+}
+
+/// Every parser under test, indexed by the input's first byte.
+const PARSERS: &[fn(&str)] = &[
+    |input| {
+        if let Ok(parsed) = duration::parse(input) {
+            let _ = parsed.apply(&reference());
+        }
+    },
+    |input| {
+        let _ = expression::parse(input);
+    },
+    |input| {
+        let _ = natural::parse(input);
+    },
+    |input| {
+        if let Ok(schedule) = cron::Schedule::parse(input) {
+            schedule
+                .after(reference().datetime())
+                .take(10)
+                .for_each(drop);
+        }
+    },
+    |input| {
+        if let Ok(rule) = RRule::parse(input) {
+            let start = reference();
+            rule.occurrences(start.datetime(), start.time_zone(), start.datetime())
+                .take(10)
+                .for_each(drop);
+        }
+    },
+    |input| {
+        if let Ok(format) = DatetimeFormat::parse(input) {
+            let _ = format.format(&reference());
+        }
+    },
+    |input| {
+        if let Ok(locale) = Locale::parse(input) {
+            locale.format(&reference());
+        }
+    },
+    |input| {
+        detect::detect_format(input);
+    },
+    |input| {
+        let _ = EpochValue::parse(input);
+    },
+    |input| {
+        let _ = Expected::parse(input);
+    },
+    |input| {
+        let _ = ics::parse(input, &TimeZone::UTC);
+    },
+    |input| {
+        for calendar in CalendarSystem::ALL {
+            let _ = calendar_system::parse(calendar, input);
+        }
+    },
+];
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, rest)) = data.split_first() else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(rest) else {
+        return;
+    };
+    PARSERS[usize::from(selector) % PARSERS.len()](&unicode::normalize_input(input));
+});
@@ -0,0 +1,121 @@
+use rmcp::model::JsonObject;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::local::{self, LocalClient};
+
+/// One line of batch input.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchRequest {
+    /// Echoed back so callers can match results to requests.
+    #[serde(default)]
+    id: Option<serde_json::Value>,
+    tool: String,
+    #[serde(default)]
+    params: JsonObject,
+}
+
+/// One line of batch output: the tool's result or its error.
+#[derive(Debug, Serialize)]
+struct BatchResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<serde_json::Value>,
+}
+
+/// Read newline-delimited `{"tool": ..., "params": ...}` requests from
+/// `input` and write one JSON result per line to `output`, in order.
+///
+/// Tool errors and malformed lines produce an `error` line rather than
+/// stopping the batch; blank lines are skipped.
+pub async fn run(
+    client: &mut LocalClient,
+    input: impl AsyncBufRead + Unpin,
+    mut output: impl AsyncWrite + Unpin,
+) -> std::io::Result<()> {
+    let mut lines = input.lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = respond(client, &line).await;
+        let json = serde_json::to_string(&response).unwrap_or_default();
+        output.write_all(format!("{json}\n").as_bytes()).await?;
+        output.flush().await?;
+    }
+    Ok(())
+}
+
+async fn respond(client: &mut LocalClient, line: &str) -> BatchResponse {
+    let request: BatchRequest = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => {
+            return BatchResponse {
+                id: None,
+                result: None,
+                error: Some(batch_error("invalid_request", e.to_string())),
+            };
+        }
+    };
+    let (result, error) = match client.call(&request.tool, request.params).await {
+        Ok(result) => {
+            // Tool output is JSON text; pass it through as JSON when it is.
+            let text = local::result_text(&result);
+            let value = serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text));
+            if result.is_error == Some(true) {
+                (None, Some(value))
+            } else {
+                (Some(value), None)
+            }
+        }
+        Err(e) => (None, Some(batch_error("invalid_request", e.message))),
+    };
+    BatchResponse {
+        id: request.id,
+        result,
+        error,
+    }
+}
+
+/// Error for a line that never reached a tool, shaped like tool errors.
+fn batch_error(code: &str, message: impl Into<String>) -> serde_json::Value {
+    serde_json::json!({ "code": code, "message": message.into() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::TimeServer;
+
+    #[tokio::test]
+    async fn run_writes_one_result_per_request() {
+        let mut client = LocalClient::new(TimeServer::new());
+        let input: &[u8] =
+            br#"{"id": 1, "tool": "get_current_time", "params": {"timezone": "Asia/Tokyo"}}
+
+{"id": "b", "tool": "get_current_time", "params": {"timezone": "Nowhere"}}
+not json
+{"tool": "no_such_tool"}
+"#;
+        let mut output = Vec::new();
+        run(&mut client, input, &mut output).await.unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0]["id"], 1);
+        assert_eq!(lines[0]["result"]["timezone"], "Asia/Tokyo");
+        assert_eq!(lines[1]["id"], "b");
+        assert_eq!(lines[1]["error"]["code"], "invalid_timezone");
+        assert_eq!(lines[2]["error"]["code"], "invalid_request");
+        assert_eq!(lines[3]["error"]["code"], "invalid_request");
+        assert!(lines[3].get("result").is_none());
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::BTreeSet;
+
+use jiff::civil::{Date, Weekday};
+
+use crate::duration;
+
+/// Most business days [`Calendar::add`] moves, keeping results within a few
+/// centuries of the start.
+pub const MAX_OFFSET: i64 = 100_000;
+
+/// Weekday names, Monday first.
+const NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// Days of the week that are not business days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weekend([bool; 7]);
+
+impl Default for Weekend {
+    /// Saturday and Sunday.
+    fn default() -> Self {
+        Weekend([false, false, false, false, false, true, true])
+    }
+}
+
+impl Weekend {
+    /// Read weekday names, in full or as their first three letters, in any
+    /// case (e.g., ["Friday", "sat"]). An empty list means every day is a
+    /// business day.
+    pub fn parse(names: &[String]) -> Result<Weekend, String> {
+        let mut days = [false; 7];
+        for name in names {
+            let day = duration::weekday(&name.trim().to_ascii_lowercase()).ok_or_else(|| {
+                format!(
+                    "Invalid weekend day: '{name}'. Expected a weekday name (e.g., 'saturday')."
+                )
+            })?;
+            days[day.to_monday_zero_offset() as usize] = true;
+        }
+        if days.iter().all(|&day| day) {
+            return Err("Invalid weekend: every day of the week is a weekend day, so there are no business days.".into());
+        }
+        Ok(Weekend(days))
+    }
+
+    pub fn contains(self, day: Weekday) -> bool {
+        self.0[day.to_monday_zero_offset() as usize]
+    }
+
+    /// Names of the weekend days, Monday first.
+    pub fn names(self) -> Vec<&'static str> {
+        NAMES
+            .iter()
+            .zip(self.0)
+            .filter_map(|(name, weekend)| weekend.then_some(*name))
+            .collect()
+    }
+
+    fn len(self) -> i64 {
+        self.0.iter().filter(|&&day| day).count() as i64
+    }
+}
+
+/// Which dates are business days: every date that is neither a weekend day
+/// nor a holiday.
+#[derive(Debug, Clone, Default)]
+pub struct Calendar {
+    pub weekend: Weekend,
+    holidays: BTreeSet<Date>,
+}
+
+impl Calendar {
+    pub fn new(weekend: Weekend, holidays: impl IntoIterator<Item = Date>) -> Calendar {
+        Calendar {
+            weekend,
+            holidays: holidays.into_iter().collect(),
+        }
+    }
+
+    pub fn is_business_day(&self, date: Date) -> bool {
+        !self.weekend.contains(date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Holidays after `start`, up to and including `end`, that fall on days
+    /// that would otherwise be business days.
+    pub fn holidays_between(&self, start: Date, end: Date) -> Vec<Date> {
+        let (low, high) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        self.holidays
+            .range(low..=high)
+            .filter(|&&date| date != low && !self.weekend.contains(date.weekday()))
+            .copied()
+            .collect()
+    }
+
+    /// Business days after `start`, up to and including `end`; negative
+    /// when `end` is before `start`.
+    ///
+    /// This is the inverse of [`Calendar::add`]: adding the count to
+    /// `start` lands on `end` whenever `end` is a business day.
+    pub fn count(&self, start: Date, end: Date) -> Result<i64, jiff::Error> {
+        if end < start {
+            return Ok(-self.count(end, start)?);
+        }
+        let days = i64::from(start.until(end)?.get_days());
+        let weeks = days / 7;
+        let mut count = weeks * (7 - self.weekend.len());
+        let mut date = start.checked_add(jiff::Span::new().try_weeks(weeks)?)?;
+        for _ in 0..days % 7 {
+            date = date.tomorrow()?;
+            if !self.weekend.contains(date.weekday()) {
+                count += 1;
+            }
+        }
+        Ok(count - self.holidays_between(start, end).len() as i64)
+    }
+
+    /// The date `days` business days after `start`, or before it when
+    /// `days` is negative.
+    ///
+    /// Counting starts from the day after `start`, so one business day
+    /// after a Friday or a Saturday is the following Monday. Zero days
+    /// returns `start` itself, business day or not.
+    pub fn add(&self, start: Date, days: i64) -> Result<Date, String> {
+        if days.abs() > MAX_OFFSET {
+            return Err(format!(
+                "Invalid number of business days: {days}. Expected at most {MAX_OFFSET} in either direction."
+            ));
+        }
+        let out_of_range = |e: jiff::Error| format!("Result is out of range: {e}");
+        let mut date = start;
+        let mut remaining = days.abs();
+        while remaining > 0 {
+            date = if days > 0 {
+                date.tomorrow()
+            } else {
+                date.yesterday()
+            }
+            .map_err(out_of_range)?;
+            if self.is_business_day(date) {
+                remaining -= 1;
+            }
+        }
+        Ok(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn weekend_parse_reads_names() {
+        let weekend = Weekend::parse(&names(&["Friday", "sat"])).unwrap();
+        assert_eq!(weekend.names(), ["friday", "saturday"]);
+        assert!(!weekend.contains(Weekday::Sunday));
+        assert!(Weekend::parse(&[]).unwrap().names().is_empty());
+        assert!(Weekend::parse(&names(&["caturday"])).is_err());
+        let every_day = names(&NAMES);
+        assert!(
+            Weekend::parse(&every_day)
+                .unwrap_err()
+                .contains("no business days")
+        );
+    }
+
+    #[test]
+    fn count_skips_weekends_and_holidays() {
+        let calendar = Calendar::default();
+        // Monday 2025-07-07 to Friday 2025-07-11.
+        assert_eq!(
+            calendar.count(date(2025, 7, 7), date(2025, 7, 11)).unwrap(),
+            4
+        );
+        assert_eq!(
+            calendar.count(date(2025, 7, 11), date(2025, 7, 7)).unwrap(),
+            -4
+        );
+        assert_eq!(
+            calendar.count(date(2025, 7, 4), date(2025, 7, 7)).unwrap(),
+            1
+        );
+        assert_eq!(
+            calendar
+                .count(date(2025, 1, 1), date(2025, 12, 31))
+                .unwrap(),
+            260
+        );
+
+        let calendar = Calendar::new(
+            Weekend::default(),
+            [date(2025, 7, 4), date(2025, 7, 5), date(2025, 12, 25)],
+        );
+        assert_eq!(
+            calendar.count(date(2025, 7, 1), date(2025, 7, 8)).unwrap(),
+            4
+        );
+        assert_eq!(
+            calendar.holidays_between(date(2025, 7, 1), date(2025, 7, 8)),
+            [date(2025, 7, 4)]
+        );
+    }
+
+    #[test]
+    fn add_is_the_inverse_of_count() {
+        let weekend = Weekend::parse(&names(&["friday", "saturday"])).unwrap();
+        let calendar = Calendar::new(weekend, [date(2025, 3, 31)]);
+        // Thursday 2025-03-27, with a Friday-Saturday weekend and a holiday.
+        let start = date(2025, 3, 27);
+        let end = calendar.add(start, 2).unwrap();
+        assert_eq!(end, date(2025, 4, 1));
+        assert_eq!(calendar.count(start, end).unwrap(), 2);
+        assert_eq!(calendar.add(end, -2).unwrap(), start);
+        assert_eq!(
+            calendar.add(date(2025, 3, 28), 0).unwrap(),
+            date(2025, 3, 28)
+        );
+        assert!(calendar.add(start, MAX_OFFSET + 1).is_err());
+        assert!(calendar.add(date(9999, 12, 30), 5).is_err());
+    }
+}
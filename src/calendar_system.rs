@@ -0,0 +1,664 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A calendar system dates can be converted between.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarSystem {
+    /// The proleptic Gregorian calendar, as used by ISO 8601.
+    #[default]
+    Gregorian,
+    /// The Hebrew calendar, with months numbered from Tishrei.
+    Hebrew,
+    /// The tabular Islamic (civil, Friday epoch) calendar.
+    Islamic,
+    /// Japanese era names over the Gregorian calendar, from Meiji 6
+    /// (1873), when Japan adopted it.
+    Japanese,
+}
+
+impl CalendarSystem {
+    /// Every calendar system, Gregorian first.
+    pub const ALL: [CalendarSystem; 4] = [
+        CalendarSystem::Gregorian,
+        CalendarSystem::Hebrew,
+        CalendarSystem::Islamic,
+        CalendarSystem::Japanese,
+    ];
+
+    /// Lowercase name of the calendar as used in tool parameters.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CalendarSystem::Gregorian => "gregorian",
+            CalendarSystem::Hebrew => "hebrew",
+            CalendarSystem::Islamic => "islamic",
+            CalendarSystem::Japanese => "japanese",
+        }
+    }
+
+    /// How a date is written in this calendar, for error messages.
+    fn expected(self) -> &'static str {
+        match self {
+            CalendarSystem::Gregorian => "YYYY-MM-DD (e.g., '2025-07-04')",
+            CalendarSystem::Hebrew => {
+                "YYYY-MM-DD with months numbered from Tishrei (e.g., '5786-01-01' for 1 Tishrei 5786)"
+            }
+            CalendarSystem::Islamic => "YYYY-MM-DD (e.g., '1447-09-01' for 1 Ramadan 1447)",
+            CalendarSystem::Japanese => {
+                "an era name or initial followed by YYYY-MM-DD (e.g., 'Reiwa 7-07-04', 'R7-07-04', or '令和7年7月4日')"
+            }
+        }
+    }
+}
+
+/// A date written in one calendar system.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct CalendarDate {
+    pub calendar: CalendarSystem,
+    /// The date in the form `convert_calendar` accepts as input.
+    pub date: String,
+    pub era: String,
+    /// Year within the era.
+    pub year: i32,
+    /// Month number within the year; Hebrew months count from Tishrei, so
+    /// Adar II is month 7 in leap years.
+    pub month: u8,
+    pub month_name: String,
+    pub day: u8,
+    /// The date written out in English (e.g., '8 Tammuz 5785').
+    pub formatted: String,
+    /// The date written in the calendar's own script, where it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native: Option<String>,
+    /// Whether the year has a leap day or, for the Hebrew calendar, a leap
+    /// month.
+    pub leap_year: bool,
+}
+
+/// Read `input` as a date in `calendar`.
+pub fn parse(calendar: CalendarSystem, input: &str) -> Result<jiff::civil::Date, String> {
+    let input = input.trim();
+    let invalid = || {
+        format!(
+            "Invalid {} date: '{input}'. Expected {}.",
+            calendar.as_str(),
+            calendar.expected()
+        )
+    };
+    let fixed = match calendar {
+        CalendarSystem::Gregorian => {
+            let date: jiff::civil::Date = input.parse().map_err(|_| invalid())?;
+            return Ok(date);
+        }
+        CalendarSystem::Hebrew => {
+            let (year, month, day) = numeric_date(input).ok_or_else(invalid)?;
+            if !(1..=9999).contains(&year) {
+                return Err(invalid());
+            }
+            let months = hebrew::months_in_year(year);
+            if !(1..=months).contains(&month) {
+                return Err(format!(
+                    "Invalid hebrew date: '{input}'. Year {year} has {months} months."
+                ));
+            }
+            let month = hebrew::from_civil_month(year, month);
+            let days = hebrew::days_in_month(year, month);
+            if !(1..=days).contains(&day) {
+                return Err(format!(
+                    "Invalid hebrew date: '{input}'. {} {year} has {days} days.",
+                    hebrew::month_name(year, month)
+                ));
+            }
+            hebrew::to_fixed(year, month, day)
+        }
+        CalendarSystem::Islamic => {
+            let (year, month, day) = numeric_date(input).ok_or_else(invalid)?;
+            if !(1..=9999).contains(&year) || !(1..=12).contains(&month) {
+                return Err(invalid());
+            }
+            let days = islamic::days_in_month(year, month);
+            if !(1..=days).contains(&day) {
+                return Err(format!(
+                    "Invalid islamic date: '{input}'. {} {year} has {days} days.",
+                    islamic::MONTHS[usize::from(month) - 1]
+                ));
+            }
+            islamic::to_fixed(year, month, day)
+        }
+        CalendarSystem::Japanese => {
+            let (era, rest) = japanese::split_era(input).ok_or_else(invalid)?;
+            let (year, month, day) = numeric_date(rest).ok_or_else(invalid)?;
+            let date = jiff::civil::Date::new(
+                i16::try_from(i32::from(era.start.year()) + year - 1).map_err(|_| invalid())?,
+                month as i8,
+                day as i8,
+            )
+            .map_err(|_| invalid())?;
+            let actual = japanese::era_of(date);
+            if year < 1 || actual.map(|e| e.name) != Some(era.name) {
+                return Err(format!(
+                    "Invalid japanese date: '{input}'. {} is not in the {} era.",
+                    date, era.name
+                ));
+            }
+            return Ok(date);
+        }
+    };
+    let date = from_fixed(fixed);
+    jiff::civil::Date::new(
+        i16::try_from(date.0).map_err(|_| invalid())?,
+        date.1 as i8,
+        date.2 as i8,
+    )
+    .map_err(|_| format!("'{input}' falls outside the supported Gregorian years 1 to 9999."))
+}
+
+/// Write `date` in `calendar`.
+///
+/// Fails for dates before the calendar's first year, including Japanese
+/// dates before Meiji 6 (1873-01-01).
+pub fn render(calendar: CalendarSystem, date: jiff::civil::Date) -> Result<CalendarDate, String> {
+    let fixed = to_fixed(i32::from(date.year()), date.month() as u8, date.day() as u8);
+    Ok(match calendar {
+        CalendarSystem::Gregorian => {
+            let month_name = date.strftime("%B").to_string();
+            CalendarDate {
+                calendar,
+                date: date.to_string(),
+                era: if date.year() > 0 { "CE" } else { "BCE" }.into(),
+                year: i32::from(date.year()),
+                month: date.month() as u8,
+                formatted: format!("{} {month_name} {}", date.day(), date.year()),
+                month_name,
+                day: date.day() as u8,
+                native: None,
+                leap_year: date.in_leap_year(),
+            }
+        }
+        CalendarSystem::Hebrew => {
+            let (year, month, day) = hebrew::from_fixed(fixed);
+            if year < 1 {
+                return Err(format!(
+                    "{date} is before 1 Tishrei AM 1, where the Hebrew calendar starts."
+                ));
+            }
+            let month_name = hebrew::month_name(year, month).to_string();
+            let civil = hebrew::to_civil_month(year, month);
+            CalendarDate {
+                calendar,
+                date: format!("{year:04}-{civil:02}-{day:02}"),
+                era: "AM".into(),
+                year,
+                month: civil,
+                formatted: format!("{day} {month_name} {year}"),
+                month_name,
+                day,
+                native: None,
+                leap_year: hebrew::is_leap_year(year),
+            }
+        }
+        CalendarSystem::Islamic => {
+            let (year, month, day) = islamic::from_fixed(fixed);
+            if year < 1 {
+                return Err(format!(
+                    "{date} is before 1 Muharram AH 1 (0622-07-19), where the Islamic calendar starts."
+                ));
+            }
+            let month_name = islamic::MONTHS[usize::from(month) - 1].to_string();
+            CalendarDate {
+                calendar,
+                date: format!("{year:04}-{month:02}-{day:02}"),
+                era: "AH".into(),
+                year,
+                month,
+                formatted: format!("{day} {month_name} {year} AH"),
+                month_name,
+                day,
+                native: None,
+                leap_year: islamic::is_leap_year(year),
+            }
+        }
+        CalendarSystem::Japanese => {
+            let era = japanese::era_of(date).ok_or_else(|| {
+                format!(
+                    "{date} is before Meiji 6 (1873-01-01), when Japan adopted the Gregorian calendar."
+                )
+            })?;
+            let year = i32::from(date.year()) - i32::from(era.start.year()) + 1;
+            let (month, day) = (date.month() as u8, date.day() as u8);
+            let month_name = date.strftime("%B").to_string();
+            // The first year of an era is written 元年 ("first year").
+            let native_year = match year {
+                1 => "元".to_string(),
+                _ => year.to_string(),
+            };
+            CalendarDate {
+                calendar,
+                date: format!("{} {year}-{month:02}-{day:02}", era.name),
+                era: era.name.into(),
+                year,
+                month,
+                formatted: format!("{day} {month_name} {} {year}", era.name),
+                month_name,
+                day,
+                native: Some(format!("{}{native_year}年{month}月{day}日", era.kanji)),
+                leap_year: date.in_leap_year(),
+            }
+        }
+    })
+}
+
+/// Read "YYYY-MM-DD", also accepting single-digit months and days and the
+/// "Y年M月D日" form.
+fn numeric_date(input: &str) -> Option<(i32, u8, u8)> {
+    let input = input.trim();
+    let parts: Vec<&str> = match input.strip_suffix('日') {
+        Some(rest) => rest.split(['年', '月']).collect(),
+        None => input.split('-').collect(),
+    };
+    let [year, month, day] = parts[..] else {
+        return None;
+    };
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !digits(month) || !digits(day) || month.len() > 2 || day.len() > 2 {
+        return None;
+    }
+    let year = match year {
+        "元" => 1,
+        _ if digits(year) && year.len() <= 4 => year.parse().ok()?,
+        _ => return None,
+    };
+    Some((year, month.parse().ok()?, day.parse().ok()?))
+}
+
+/// Days since the start of the Gregorian calendar: day 1 is 0001-01-01.
+///
+/// The Hebrew and Islamic conversions below count days the same way, after
+/// Reingold and Dershowitz's *Calendrical Calculations*.
+fn to_fixed(year: i32, month: u8, day: u8) -> i64 {
+    let (year, month, day) = (i64::from(year), i64::from(month), i64::from(day));
+    let prior = year - 1;
+    let correction = match month {
+        ..=2 => 0,
+        _ if is_gregorian_leap_year(year) => -1,
+        _ => -2,
+    };
+    365 * prior + prior.div_euclid(4) - prior.div_euclid(100)
+        + prior.div_euclid(400)
+        + (367 * month - 362).div_euclid(12)
+        + correction
+        + day
+}
+
+/// The Gregorian year, month, and day of a fixed day number.
+fn from_fixed(fixed: i64) -> (i64, u8, u8) {
+    let d0 = fixed - 1;
+    let (n400, d1) = (d0.div_euclid(146_097), d0.rem_euclid(146_097));
+    let (n100, d2) = (d1 / 36_524, d1 % 36_524);
+    let (n4, d3) = (d2 / 1461, d2 % 1461);
+    let n1 = d3 / 365;
+    let year = 400 * n400 + 100 * n100 + 4 * n4 + n1;
+    let year = if n100 == 4 || n1 == 4 { year } else { year + 1 };
+    let year32 = year as i32;
+    let prior_days = fixed - to_fixed(year32, 1, 1);
+    let correction = if fixed < to_fixed(year32, 3, 1) {
+        0
+    } else if is_gregorian_leap_year(year) {
+        1
+    } else {
+        2
+    };
+    let month = (12 * (prior_days + correction) + 373).div_euclid(367) as u8;
+    let day = (fixed - to_fixed(year32, month, 1) + 1) as u8;
+    (year, month, day)
+}
+
+fn is_gregorian_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+}
+
+/// The Hebrew calendar, with months numbered from Nisan (1) as in the
+/// Hebrew Bible; Tishrei, where the year begins, is month 7.
+mod hebrew {
+    /// Fixed day of 1 Tishrei AM 1 (7 October 3761 BCE, Julian).
+    const EPOCH: i64 = -1_373_427;
+
+    const NAMES: [&str; 13] = [
+        "Nisan", "Iyar", "Sivan", "Tammuz", "Av", "Elul", "Tishrei", "Cheshvan", "Kislev", "Tevet",
+        "Shevat", "Adar", "Adar II",
+    ];
+
+    pub fn is_leap_year(year: i32) -> bool {
+        (7 * i64::from(year) + 1).rem_euclid(19) < 7
+    }
+
+    pub fn months_in_year(year: i32) -> u8 {
+        if is_leap_year(year) { 13 } else { 12 }
+    }
+
+    pub fn month_name(year: i32, month: u8) -> &'static str {
+        match month {
+            12 if is_leap_year(year) => "Adar I",
+            _ => NAMES[usize::from(month) - 1],
+        }
+    }
+
+    /// Month number counted from Tishrei, as written in dates.
+    pub fn to_civil_month(year: i32, month: u8) -> u8 {
+        if month >= 7 {
+            month - 6
+        } else {
+            month + months_in_year(year) - 6
+        }
+    }
+
+    /// Inverse of [`to_civil_month`].
+    pub fn from_civil_month(year: i32, civil: u8) -> u8 {
+        let from_nisan = months_in_year(year) - 6;
+        if civil > from_nisan {
+            civil - from_nisan
+        } else {
+            civil + 6
+        }
+    }
+
+    /// Days from the epoch to the molad-based start of `year`, before the
+    /// year-length corrections.
+    fn elapsed_days(year: i64) -> i64 {
+        let months = (235 * year - 234).div_euclid(19);
+        let parts = 12_084 + 13_753 * months;
+        let days = 29 * months + parts.div_euclid(25_920);
+        if (3 * (days + 1)).rem_euclid(7) < 3 {
+            days + 1
+        } else {
+            days
+        }
+    }
+
+    /// Delay of the new year keeping every year 353–355 or 383–385 days.
+    fn new_year_delay(year: i64) -> i64 {
+        let (ny0, ny1, ny2) = (
+            elapsed_days(year - 1),
+            elapsed_days(year),
+            elapsed_days(year + 1),
+        );
+        if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn new_year(year: i32) -> i64 {
+        let year = i64::from(year);
+        EPOCH + elapsed_days(year) + new_year_delay(year)
+    }
+
+    fn days_in_year(year: i32) -> i64 {
+        new_year(year + 1) - new_year(year)
+    }
+
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        let length = days_in_year(year);
+        let short = match month {
+            2 | 4 | 6 | 10 | 13 => true,
+            12 => !is_leap_year(year),
+            // Cheshvan is long, and Kislev short, in some years.
+            8 => length % 10 != 5,
+            9 => length % 10 == 3,
+            _ => false,
+        };
+        if short { 29 } else { 30 }
+    }
+
+    pub fn to_fixed(year: i32, month: u8, day: u8) -> i64 {
+        let last = months_in_year(year);
+        let days_before: i64 = if month < 7 {
+            (7..=last)
+                .chain(1..month)
+                .map(|m| i64::from(days_in_month(year, m)))
+                .sum()
+        } else {
+            (7..month).map(|m| i64::from(days_in_month(year, m))).sum()
+        };
+        new_year(year) + days_before + i64::from(day) - 1
+    }
+
+    pub fn from_fixed(fixed: i64) -> (i32, u8, u8) {
+        // Mean year length: 35975351/98496 days.
+        let approx = ((fixed - EPOCH) * 98_496).div_euclid(35_975_351) as i32 + 1;
+        let mut year = approx - 1;
+        while new_year(year + 1) <= fixed {
+            year += 1;
+        }
+        let start = if fixed < to_fixed(year, 1, 1) { 7 } else { 1 };
+        let month = (start..=months_in_year(year))
+            .find(|&m| fixed <= to_fixed(year, m, days_in_month(year, m)))
+            .unwrap_or(start);
+        let day = (fixed - to_fixed(year, month, 1) + 1) as u8;
+        (year, month, day)
+    }
+}
+
+/// The tabular Islamic calendar: 30-year cycles with leap years 2, 5, 7,
+/// 10, 13, 16, 18, 21, 24, 26, and 29, counted from 16 July 622 (Julian).
+///
+/// Observational and Umm al-Qura calendars, which follow the sighting or
+/// computation of the new moon, can differ from it by a day or two.
+mod islamic {
+    /// Fixed day of 1 Muharram AH 1.
+    const EPOCH: i64 = 227_015;
+
+    pub const MONTHS: [&str; 12] = [
+        "Muharram",
+        "Safar",
+        "Rabi al-Awwal",
+        "Rabi al-Thani",
+        "Jumada al-Awwal",
+        "Jumada al-Thani",
+        "Rajab",
+        "Shaban",
+        "Ramadan",
+        "Shawwal",
+        "Dhu al-Qadah",
+        "Dhu al-Hijjah",
+    ];
+
+    pub fn is_leap_year(year: i32) -> bool {
+        (14 + 11 * i64::from(year)).rem_euclid(30) < 11
+    }
+
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            12 if is_leap_year(year) => 30,
+            _ if month % 2 == 1 => 30,
+            _ => 29,
+        }
+    }
+
+    pub fn to_fixed(year: i32, month: u8, day: u8) -> i64 {
+        let (year, month) = (i64::from(year), i64::from(month));
+        EPOCH - 1
+            + i64::from(day)
+            + 29 * (month - 1)
+            + (6 * month - 1).div_euclid(11)
+            + (year - 1) * 354
+            + (3 + 11 * year).div_euclid(30)
+    }
+
+    pub fn from_fixed(fixed: i64) -> (i32, u8, u8) {
+        let year = (30 * (fixed - EPOCH) + 10_646).div_euclid(10_631) as i32;
+        let prior = fixed - to_fixed(year, 1, 1);
+        let month = (11 * prior + 330).div_euclid(325) as u8;
+        let day = (fixed - to_fixed(year, month, 1) + 1) as u8;
+        (year, month, day)
+    }
+}
+
+/// Japanese era names since the adoption of the Gregorian calendar.
+mod japanese {
+    use jiff::civil::{Date, date};
+
+    pub struct Era {
+        pub name: &'static str,
+        pub kanji: &'static str,
+        /// First day of the era's first year.
+        pub start: Date,
+    }
+
+    /// Eras in order. Meiji began in 1868, but only dates from 1873, when
+    /// the Gregorian calendar was adopted, are written with it here.
+    const ERAS: [Era; 5] = [
+        Era {
+            name: "Meiji",
+            kanji: "明治",
+            start: date(1868, 1, 1),
+        },
+        Era {
+            name: "Taisho",
+            kanji: "大正",
+            start: date(1912, 7, 30),
+        },
+        Era {
+            name: "Showa",
+            kanji: "昭和",
+            start: date(1926, 12, 25),
+        },
+        Era {
+            name: "Heisei",
+            kanji: "平成",
+            start: date(1989, 1, 8),
+        },
+        Era {
+            name: "Reiwa",
+            kanji: "令和",
+            start: date(2019, 5, 1),
+        },
+    ];
+
+    /// First date written with Japanese era names over the Gregorian
+    /// calendar.
+    const ADOPTED: Date = date(1873, 1, 1);
+
+    pub fn era_of(day: Date) -> Option<&'static Era> {
+        if day < ADOPTED {
+            return None;
+        }
+        ERAS.iter().rev().find(|era| era.start <= day)
+    }
+
+    /// Split a leading era name ("Reiwa", "reiwa", "R", or "令和") from the
+    /// rest of the input.
+    pub fn split_era(input: &str) -> Option<(&'static Era, &str)> {
+        ERAS.iter().find_map(|era| {
+            let rest = strip_prefix_ignore_case(input, era.name)
+                .or_else(|| input.strip_prefix(era.kanji))
+                .or_else(|| strip_prefix_ignore_case(input, &era.name[..1]))?;
+            Some((era, rest.trim_start()))
+        })
+    }
+
+    fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+        let head = input.get(..prefix.len())?;
+        head.eq_ignore_ascii_case(prefix)
+            .then(|| &input[prefix.len()..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_date(calendar: CalendarSystem, date: &str) -> CalendarDate {
+        render(calendar, date.parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn fixed_days_round_trip_through_gregorian_dates() {
+        assert_eq!(to_fixed(1, 1, 1), 1);
+        for date in ["0001-01-01", "1582-10-15", "2000-02-29", "2025-12-31"] {
+            let date: jiff::civil::Date = date.parse().unwrap();
+            let fixed = to_fixed(i32::from(date.year()), date.month() as u8, date.day() as u8);
+            let (y, m, d) = from_fixed(fixed);
+            assert_eq!(
+                (y, m, d),
+                (i64::from(date.year()), date.month() as u8, date.day() as u8)
+            );
+        }
+    }
+
+    #[test]
+    fn hebrew_dates_match_known_holidays() {
+        // Rosh Hashanah 5786, Passover 5784, and Purim in the leap year 5784.
+        let rosh_hashanah = render_date(CalendarSystem::Hebrew, "2025-09-23");
+        assert_eq!(rosh_hashanah.formatted, "1 Tishrei 5786");
+        assert_eq!(rosh_hashanah.date, "5786-01-01");
+        assert_eq!(
+            render_date(CalendarSystem::Hebrew, "2024-04-23").formatted,
+            "15 Nisan 5784"
+        );
+        let purim = render_date(CalendarSystem::Hebrew, "2024-03-24");
+        assert_eq!(purim.formatted, "14 Adar II 5784");
+        assert_eq!(purim.month, 7);
+        assert!(purim.leap_year);
+        assert_eq!(
+            render_date(CalendarSystem::Hebrew, "2025-07-04").formatted,
+            "8 Tammuz 5785"
+        );
+    }
+
+    #[test]
+    fn islamic_dates_follow_the_tabular_calendar() {
+        let date = render_date(CalendarSystem::Islamic, "2025-03-01");
+        assert_eq!(date.formatted, "1 Ramadan 1446 AH");
+        assert_eq!(date.date, "1446-09-01");
+        assert_eq!(
+            render_date(CalendarSystem::Islamic, "0622-07-19").date,
+            "0001-01-01"
+        );
+    }
+
+    #[test]
+    fn japanese_dates_use_the_era_in_effect() {
+        let date = render_date(CalendarSystem::Japanese, "2019-05-01");
+        assert_eq!(date.date, "Reiwa 1-05-01");
+        assert_eq!(date.native.as_deref(), Some("令和元年5月1日"));
+        assert_eq!(
+            render_date(CalendarSystem::Japanese, "2019-04-30").date,
+            "Heisei 31-04-30"
+        );
+        assert!(render(CalendarSystem::Japanese, "1872-12-31".parse().unwrap()).is_err());
+    }
+
+    #[test]
+    fn parse_reads_each_calendar_back() {
+        // Every 13th day from 1873 to 2100, which lands on every month
+        // length and leap year shape of each calendar.
+        for fixed in (to_fixed(1873, 1, 1)..to_fixed(2100, 12, 31)).step_by(13) {
+            let (y, m, d) = from_fixed(fixed);
+            let date = jiff::civil::date(y as i16, m as i8, d as i8);
+            for calendar in CalendarSystem::ALL {
+                let written = render(calendar, date).unwrap();
+                assert_eq!(parse(calendar, &written.date), Ok(date), "{written:?}");
+            }
+        }
+        let reiwa = "2025-07-04".parse().unwrap();
+        for input in ["R7-07-04", "reiwa 7-7-4", "令和7年7月4日"] {
+            assert_eq!(parse(CalendarSystem::Japanese, input), Ok(reiwa), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_rejects_dates_outside_their_month_or_era() {
+        let err = parse(CalendarSystem::Hebrew, "5785-13-01").unwrap_err();
+        assert!(err.contains("12 months"), "Error was: {err}");
+        let err = parse(CalendarSystem::Islamic, "1446-10-30").unwrap_err();
+        assert!(err.contains("29 days"), "Error was: {err}");
+        let err = parse(CalendarSystem::Japanese, "Heisei 32-01-01").unwrap_err();
+        assert!(err.contains("Heisei era"), "Error was: {err}");
+        assert!(parse(CalendarSystem::Japanese, "Edo 1-01-01").is_err());
+        assert!(parse(CalendarSystem::Gregorian, "2025-02-30").is_err());
+    }
+}
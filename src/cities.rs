@@ -0,0 +1,375 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::OnceLock,
+};
+
+use crate::{
+    display_names,
+    locations::{self, Coordinates},
+};
+
+/// Environment variable that enables city names in timezone parameters.
+pub const ALLOW_CITY_NAMES_ENV: &str = "MCP_TIME_ALLOW_CITY_NAMES";
+
+/// Major cities whose names do not appear in any IANA zone name, with their
+/// zone and location.
+///
+/// Cities that do (e.g., "Berlin" in "Europe/Berlin") are found from the tz
+/// database itself, so only the rest need listing here.
+const CITIES: &[(&str, &str, Coordinates)] = &[
+    ("Abu Dhabi", "Asia/Dubai", at(24.45, 54.38)),
+    ("Ahmedabad", "Asia/Kolkata", at(23.02, 72.57)),
+    ("Atlanta", "America/New_York", at(33.75, -84.39)),
+    ("Austin", "America/Chicago", at(30.27, -97.74)),
+    ("Bangalore", "Asia/Kolkata", at(12.97, 77.59)),
+    ("Barcelona", "Europe/Madrid", at(41.39, 2.17)),
+    ("Beijing", "Asia/Shanghai", at(39.9, 116.41)),
+    ("Bengaluru", "Asia/Kolkata", at(12.97, 77.59)),
+    ("Boston", "America/New_York", at(42.36, -71.06)),
+    ("Brasilia", "America/Sao_Paulo", at(-15.79, -47.88)),
+    ("Calgary", "America/Edmonton", at(51.05, -114.07)),
+    ("Cape Town", "Africa/Johannesburg", at(-33.92, 18.42)),
+    ("Chennai", "Asia/Kolkata", at(13.08, 80.27)),
+    ("Dallas", "America/Chicago", at(32.78, -96.8)),
+    ("Delhi", "Asia/Kolkata", at(28.66, 77.23)),
+    ("Frankfurt", "Europe/Berlin", at(50.11, 8.68)),
+    ("Geneva", "Europe/Zurich", at(46.2, 6.14)),
+    ("Guangzhou", "Asia/Shanghai", at(23.13, 113.26)),
+    ("Hamburg", "Europe/Berlin", at(53.55, 9.99)),
+    ("Hanoi", "Asia/Bangkok", at(21.03, 105.85)),
+    ("Houston", "America/Chicago", at(29.76, -95.37)),
+    ("Hyderabad", "Asia/Kolkata", at(17.39, 78.49)),
+    ("Kyoto", "Asia/Tokyo", at(35.01, 135.77)),
+    ("Las Vegas", "America/Los_Angeles", at(36.17, -115.14)),
+    ("Manchester", "Europe/London", at(53.48, -2.24)),
+    ("Melbourne", "Australia/Melbourne", at(-37.81, 144.96)),
+    ("Miami", "America/New_York", at(25.76, -80.19)),
+    ("Milan", "Europe/Rome", at(45.46, 9.19)),
+    ("Montreal", "America/Toronto", at(45.5, -73.57)),
+    ("Mumbai", "Asia/Kolkata", at(19.08, 72.88)),
+    ("Munich", "Europe/Berlin", at(48.14, 11.58)),
+    ("New Delhi", "Asia/Kolkata", at(28.61, 77.21)),
+    ("Osaka", "Asia/Tokyo", at(34.69, 135.5)),
+    ("Ottawa", "America/Toronto", at(45.42, -75.7)),
+    ("Philadelphia", "America/New_York", at(39.95, -75.17)),
+    ("Pune", "Asia/Kolkata", at(18.52, 73.86)),
+    ("Rio de Janeiro", "America/Sao_Paulo", at(-22.91, -43.17)),
+    ("Saint Petersburg", "Europe/Moscow", at(59.94, 30.31)),
+    ("San Diego", "America/Los_Angeles", at(32.72, -117.16)),
+    ("San Francisco", "America/Los_Angeles", at(37.77, -122.42)),
+    ("Seattle", "America/Los_Angeles", at(47.61, -122.33)),
+    ("Shenzhen", "Asia/Shanghai", at(22.54, 114.06)),
+    ("St Petersburg", "Europe/Moscow", at(59.94, 30.31)),
+    ("Washington", "America/New_York", at(38.91, -77.04)),
+];
+
+const fn at(latitude: f64, longitude: f64) -> Coordinates {
+    Coordinates {
+        latitude,
+        longitude,
+    }
+}
+
+/// Key used to compare city names: lowercase, with underscores read as
+/// spaces so "Los Angeles" matches "America/Los_Angeles".
+fn key(name: &str) -> String {
+    name.trim().replace('_', " ").to_lowercase()
+}
+
+/// Every known city name with the IANA zone it resolves to.
+///
+/// Combines the curated list and localized exonyms (e.g., "Nueva York") with
+/// the final component of each regional zone name, displayed as written in
+/// the tz database (e.g., "Los Angeles").
+pub fn gazetteer() -> &'static HashMap<String, (String, String)> {
+    static GAZETTEER: OnceLock<HashMap<String, (String, String)>> = OnceLock::new();
+    GAZETTEER.get_or_init(|| {
+        let mut cities = HashMap::new();
+        for name in jiff::tz::db().available() {
+            let zone = name.as_str();
+            let (region, city) = match zone.rsplit_once('/') {
+                Some(parts) => parts,
+                None => continue,
+            };
+            if region == "Etc" || region.starts_with("SystemV") {
+                continue;
+            }
+            let display = city.replace('_', " ");
+            cities
+                .entry(key(city))
+                .or_insert_with(|| (display, zone.to_string()));
+        }
+        for (city, zone, _) in CITIES {
+            cities.insert(key(city), (city.to_string(), zone.to_string()));
+        }
+        // Localized names never shadow an English one (e.g., French "Mexico").
+        for (city, zone) in display_names::exonyms() {
+            cities
+                .entry(key(city))
+                .or_insert_with(|| (city.to_string(), zone.to_string()));
+        }
+        cities
+    })
+}
+
+/// IANA zone for a city name such as "Berlin" or "mumbai".
+pub fn lookup(name: &str) -> Option<&'static str> {
+    gazetteer().get(&key(name)).map(|(_, zone)| zone.as_str())
+}
+
+/// A city found by name.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct City {
+    /// The city's name as listed (e.g., "San Francisco").
+    pub name: &'static str,
+    pub zone: &'static str,
+    pub coordinates: Coordinates,
+}
+
+/// The zone and location of a city such as "Mumbai" or "new york".
+///
+/// Cities a zone is named after, and their localized names, are placed
+/// where the tz database locates the zone (e.g., "Tokyo" for "Asia/Tokyo").
+pub fn locate(name: &str) -> Option<City> {
+    let wanted = key(name);
+    if let Some((city, zone, coordinates)) = CITIES.iter().find(|(city, ..)| key(city) == wanted) {
+        return Some(City {
+            name: city,
+            zone,
+            coordinates: *coordinates,
+        });
+    }
+    let (city, zone) = gazetteer().get(&wanted)?;
+    Some(City {
+        name: city,
+        zone,
+        coordinates: locations::location(zone)?.coordinates,
+    })
+}
+
+/// Major cities in `zone`: the city it is named after, then curated cities
+/// that resolve to it, alphabetically (e.g., "Asia/Kolkata" → "Kolkata",
+/// "Ahmedabad", "Bangalore", …).
+pub fn cities_in(zone: &str) -> Vec<String> {
+    let named_after = zone
+        .rsplit_once('/')
+        .filter(|(region, _)| *region != "Etc")
+        .map(|(_, city)| city.replace('_', " "));
+    named_after
+        .into_iter()
+        .chain(
+            CITIES
+                .iter()
+                .filter(|(_, z, _)| *z == zone)
+                .map(|(city, _, _)| city.to_string()),
+        )
+        .collect()
+}
+
+/// A zone that a misspelled timezone or city name may have meant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub zone: String,
+    /// City the zone was matched through, when it was not the zone name.
+    pub city: Option<String>,
+}
+
+/// Up to `limit` zones whose name or city is within a few edits of `input`,
+/// closest first.
+///
+/// Both IANA names ("Amercia/New_York") and city names ("Los Angelos") are
+/// matched; the allowed number of edits grows with the input's length.
+pub fn suggest(input: &str, limit: usize) -> Vec<Suggestion> {
+    let input = key(input);
+    let input_len = input.chars().count();
+    let max_distance = (input_len / 4).max(1);
+    // The edit distance is at least the difference in length, so names that
+    // differ by more than that are skipped without comparing them.
+    let close_in_length = |name: &str| name.chars().count().abs_diff(input_len) <= max_distance;
+
+    // Closest match per zone, keyed by zone so each is suggested once.
+    let mut best: BTreeMap<&str, (usize, Option<&str>)> = BTreeMap::new();
+    let mut consider = |zone: &'static str, distance: usize, city: Option<&'static str>| {
+        if distance > max_distance {
+            return;
+        }
+        let entry = best.entry(zone).or_insert((distance, city));
+        if distance < entry.0 {
+            *entry = (distance, city);
+        }
+    };
+    for name in zone_names() {
+        let name_key = key(name);
+        if close_in_length(&name_key) {
+            consider(name, edit_distance(&input, &name_key), None);
+        }
+    }
+    for (city_key, (display, zone)) in gazetteer() {
+        if close_in_length(city_key) {
+            consider(zone, edit_distance(&input, city_key), Some(display));
+        }
+    }
+
+    let mut ranked: Vec<(usize, &str, Option<&str>)> = best
+        .into_iter()
+        .map(|(zone, (distance, city))| (distance, zone, city))
+        .collect();
+    ranked.sort();
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(_, zone, city)| Suggestion {
+            zone: zone.to_string(),
+            city: city.map(str::to_string),
+        })
+        .collect()
+}
+
+/// Zone names completing `prefix`: those that start with it, then those
+/// whose final component does (e.g., "tok" completes to "Asia/Tokyo"), each
+/// group sorted.
+///
+/// Case is ignored and underscores match spaces, so "new y" completes to
+/// "America/New_York". An empty prefix completes to every zone.
+pub fn complete(prefix: &str) -> Vec<String> {
+    let prefix = prefix.trim_start().replace('_', " ").to_lowercase();
+    let mut names: Vec<&String> = zone_names()
+        .iter()
+        .filter(|name| name.as_str() != "posixrules")
+        .collect();
+    names.sort();
+    let (mut whole, mut city) = (Vec::new(), Vec::new());
+    for name in names {
+        let name_key = name.replace('_', " ").to_lowercase();
+        if name_key.starts_with(&prefix) {
+            whole.push(name.clone());
+        } else if name_key
+            .rsplit_once('/')
+            .is_some_and(|(_, last)| last.starts_with(&prefix))
+        {
+            city.push(name.clone());
+        }
+    }
+    whole.extend(city);
+    whole
+}
+
+/// Names of every zone in the tz database.
+fn zone_names() -> &'static [String] {
+    static NAMES: OnceLock<Vec<String>> = OnceLock::new();
+    NAMES.get_or_init(|| {
+        jiff::tz::db()
+            .available()
+            .map(|name| name.as_str().to_string())
+            .collect()
+    })
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions,
+/// and transpositions of adjacent characters each count as one edit.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_finds_zone_cities_and_curated_cities() {
+        assert_eq!(lookup("Berlin"), Some("Europe/Berlin"));
+        assert_eq!(lookup("los angeles"), Some("America/Los_Angeles"));
+        assert_eq!(lookup(" Mumbai "), Some("Asia/Kolkata"));
+        assert_eq!(lookup("Nueva York"), Some("America/New_York"));
+        assert_eq!(lookup("moskau"), Some("Europe/Moscow"));
+        assert_eq!(lookup("Atlantis"), None);
+    }
+
+    #[test]
+    fn locate_places_curated_and_zone_cities() {
+        let mumbai = locate("mumbai").unwrap();
+        assert_eq!((mumbai.name, mumbai.zone), ("Mumbai", "Asia/Kolkata"));
+        assert_eq!(mumbai.coordinates.latitude, 19.08);
+        let tokyo = locate("Tokyo").unwrap();
+        assert_eq!(tokyo.zone, "Asia/Tokyo");
+        assert!((tokyo.coordinates.longitude - 139.7).abs() < 0.1);
+        assert_eq!(locate("Nueva York").unwrap().zone, "America/New_York");
+        assert_eq!(locate("Atlantis"), None);
+    }
+
+    #[test]
+    fn cities_in_starts_with_the_zone_city() {
+        assert_eq!(cities_in("Asia/Tokyo"), vec!["Tokyo", "Kyoto", "Osaka"]);
+        assert_eq!(cities_in("America/Sao_Paulo")[0], "Sao Paulo");
+        assert!(cities_in("Etc/GMT+5").is_empty());
+    }
+
+    #[test]
+    fn curated_cities_resolve_to_real_zones() {
+        for (city, zone, _) in CITIES {
+            assert!(
+                jiff::tz::TimeZone::get(zone).is_ok(),
+                "{city} maps to unknown zone {zone}"
+            );
+        }
+    }
+
+    #[test]
+    fn complete_matches_zone_and_city_prefixes() {
+        assert_eq!(complete("Europe/Lond"), ["Europe/London"]);
+        assert_eq!(complete("america/new_y"), ["America/New_York"]);
+        assert_eq!(complete("America/New Y"), ["America/New_York"]);
+        // Zones starting with the prefix come before city matches.
+        let completions = complete("par");
+        assert!(completions.contains(&"Europe/Paris".to_string()));
+        assert!(completions.contains(&"America/Paramaribo".to_string()));
+        assert!(complete("Tok").contains(&"Asia/Tokyo".to_string()));
+        assert!(complete("Zzz").is_empty());
+        assert!(!complete("").contains(&"posixrules".to_string()));
+    }
+
+    #[test]
+    fn suggest_matches_misspelled_cities_and_zones() {
+        let suggestions = suggest("Los Angelos", 3);
+        assert_eq!(
+            suggestions[0],
+            Suggestion {
+                zone: "America/Los_Angeles".into(),
+                city: Some("Los Angeles".into()),
+            }
+        );
+        assert_eq!(suggest("Amercia/New_York", 3)[0].zone, "America/New_York");
+        assert_eq!(suggest("Mumbia", 3)[0].zone, "Asia/Kolkata");
+        assert!(suggest("Zzzzzzzz", 3).is_empty());
+        assert!(suggest(&"Europe/Berlin".repeat(1000), 3).is_empty());
+    }
+
+    #[test]
+    fn edit_distance_counts_transpositions_once() {
+        assert_eq!(edit_distance("paris", "parsi"), 1);
+        assert_eq!(edit_distance("berlin", "berlin"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
@@ -0,0 +1,78 @@
+use jiff::{SignedDuration, Timestamp};
+
+/// Environment variable pinning the server's clock to an RFC 3339 instant.
+pub const FREEZE_TIME_ENV: &str = "MCP_TIME_FREEZE_TIME";
+
+/// Where the server reads the current time from.
+///
+/// Tools and resources that default to "now" ask the server's clock, so a
+/// fixed or offset clock makes their answers reproducible. Timers, waits,
+/// and scheduled notifications still run on real elapsed time.
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    fn now(&self) -> Timestamp;
+}
+
+/// The system clock, used unless another is configured.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// A clock stopped at one instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub Timestamp);
+
+impl Clock for FixedClock {
+    fn now(&self) -> Timestamp {
+        self.0
+    }
+}
+
+/// The system clock shifted by a fixed amount, so time still passes but
+/// "now" is, say, a day ahead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetClock(pub SignedDuration);
+
+impl Clock for OffsetClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+            .checked_add(self.0)
+            .unwrap_or_else(|_| Timestamp::now())
+    }
+}
+
+/// Parse a `--freeze-time` or `MCP_TIME_FREEZE_TIME` value: an RFC 3339
+/// instant with an offset (e.g., "2025-07-04T12:00:00Z").
+pub fn parse_freeze_time(value: &str) -> Result<FixedClock, String> {
+    value.trim().parse().map(FixedClock).map_err(|_| {
+        format!(
+            "Invalid freeze time: '{value}'. Expected an RFC 3339 datetime with an offset (e.g., '2025-07-04T12:00:00Z')."
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_freeze_time_needs_an_offset() {
+        let clock = parse_freeze_time(" 2025-07-04T14:00:00+02:00 ").unwrap();
+        assert_eq!(clock.now().to_string(), "2025-07-04T12:00:00Z");
+        for input in ["2025-07-04T12:00:00", "yesterday", ""] {
+            let err = parse_freeze_time(input).unwrap_err();
+            assert!(err.contains("RFC 3339"), "Error for '{input}' was: {err}");
+        }
+    }
+
+    #[test]
+    fn offset_clock_runs_ahead_of_the_system_clock() {
+        let clock = OffsetClock(SignedDuration::from_hours(24));
+        let ahead = clock.now().duration_since(Timestamp::now());
+        assert!(ahead > SignedDuration::from_hours(23), "{ahead:?}");
+    }
+}
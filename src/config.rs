@@ -0,0 +1,677 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use jiff::tz::TimeZone;
+use tracing_subscriber::EnvFilter;
+
+use crate::{
+    cities,
+    clock::{self, FREEZE_TIME_ENV, FixedClock},
+    display_names::{LANGUAGE_ENV, Language},
+    env, heartbeat,
+    holidays::{self, HOLIDAYS_FILE_ENV},
+    limits,
+    middleware::{self, Middleware},
+    ntp,
+    server::{DEFAULT_TIMEZONE_ENV, LENIENT_PARSING_ENV},
+    state::{STATE_FILE_ENV, StateFile},
+    telemetry::{self, LOG_FORMAT_ENV, LogFormat},
+    transport::{self, BIND_ENV, DEFAULT_BIND, TRANSPORT_ENV, Transport},
+    world_clock,
+};
+
+/// Environment variable naming the configuration file to read.
+pub const CONFIG_FILE_ENV: &str = "MCP_TIME_CONFIG";
+
+/// Configuration file read from the working directory when no other is
+/// named.
+pub const DEFAULT_CONFIG_FILE: &str = "mcp-time.toml";
+
+/// A problem found on one line of an environment or TOML configuration
+/// file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// 1-based line number.
+    pub line: usize,
+    /// The line as written.
+    pub text: String,
+    pub message: String,
+}
+
+/// Every `MCP_TIME_*` setting the server reads.
+const SETTINGS: &[&str] = &[
+    cities::ALLOW_CITY_NAMES_ENV,
+    BIND_ENV,
+    CONFIG_FILE_ENV,
+    DEFAULT_TIMEZONE_ENV,
+    FREEZE_TIME_ENV,
+    heartbeat::INTERVAL_ENV,
+    HOLIDAYS_FILE_ENV,
+    middleware::AUTH_TOKEN_ENV,
+    middleware::CORS_ORIGINS_ENV,
+    middleware::LOG_REQUESTS_ENV,
+    LANGUAGE_ENV,
+    LENIENT_PARSING_ENV,
+    LOG_FORMAT_ENV,
+    limits::MAX_LIST_ITEMS_ENV,
+    limits::MAX_RESULTS_ENV,
+    ntp::SERVER_ENV,
+    telemetry::OTLP_ENDPOINT_ENV,
+    limits::RATE_LIMIT_ENV,
+    STATE_FILE_ENV,
+    TRANSPORT_ENV,
+    world_clock::REFRESH_INTERVAL_ENV,
+    world_clock::ZONES_ENV,
+];
+
+/// Server and `mcp-time` binary settings, as read from a configuration
+/// file and the `MCP_TIME_*` environment variables.
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Zone used when a tool's timezone parameter is omitted.
+    pub default_timezone: TimeZone,
+    pub allow_city_names: bool,
+    pub lenient_parsing: bool,
+    pub language: Option<Language>,
+    pub heartbeat: Option<Duration>,
+    pub world_clock_zones: Vec<String>,
+    /// How often subscribers to the world clock dashboard are notified.
+    pub world_clock_refresh_interval: Duration,
+    pub state_file: Option<PathBuf>,
+    pub holidays_file: Option<PathBuf>,
+    pub freeze_time: Option<FixedClock>,
+    /// NTP server queried by `check_clock_drift`, as `host:port`.
+    pub ntp_server: String,
+    /// How the binary serves MCP when no subcommand is given.
+    pub transport: Transport,
+    pub bind: SocketAddr,
+    pub log_format: LogFormat,
+    /// OTLP/HTTP traces URL that `tool_call` spans are exported to.
+    pub otlp_endpoint: Option<String>,
+    /// Middleware wrapped around the routes of `--transport http`.
+    pub http_middleware: Middleware,
+    /// Tool calls each client may make per minute.
+    pub rate_limit: Option<u32>,
+    /// Most items an array argument may hold.
+    pub max_list_items: Option<u32>,
+    /// Largest `count` or `limit` argument a call may give.
+    pub max_results: Option<u32>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            default_timezone: TimeZone::UTC,
+            allow_city_names: false,
+            lenient_parsing: false,
+            language: None,
+            heartbeat: None,
+            world_clock_zones: vec!["UTC".into()],
+            world_clock_refresh_interval: world_clock::DEFAULT_REFRESH_INTERVAL,
+            state_file: None,
+            holidays_file: None,
+            freeze_time: None,
+            ntp_server: format!("{}:123", ntp::DEFAULT_SERVER),
+            transport: Transport::Stdio,
+            bind: DEFAULT_BIND.parse().expect("default bind address is valid"),
+            log_format: LogFormat::Text,
+            otlp_endpoint: None,
+            http_middleware: Middleware::default(),
+            rate_limit: None,
+            max_list_items: None,
+            max_results: None,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Read the configuration file named by `MCP_TIME_CONFIG`, or
+    /// `mcp-time.toml` in the working directory if there is one, then apply
+    /// the `MCP_TIME_*` environment variables over it.
+    pub fn from_env() -> Result<Self, String> {
+        Self::load(None)
+    }
+
+    /// Like [`ServerConfig::from_env`], but read `file` (e.g., from
+    /// `--config`) in place of `MCP_TIME_CONFIG`.
+    ///
+    /// A file named explicitly must exist. Environment variables override
+    /// the file's settings; empty ones are ignored.
+    pub fn load(file: Option<&Path>) -> Result<Self, String> {
+        let mut config = Self::default();
+        let named = file.map(PathBuf::from).or_else(|| {
+            std::env::var_os(CONFIG_FILE_ENV)
+                .filter(|v| !v.is_empty())
+                .map(PathBuf::from)
+        });
+        let path = named.or_else(|| {
+            Some(PathBuf::from(DEFAULT_CONFIG_FILE)).filter(|default| default.is_file())
+        });
+        if let Some(path) = path {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read config file {}: {e}", path.display()))?;
+            config
+                .apply_toml(&contents)
+                .map_err(|e| format!("Invalid config file {}: {e}", path.display()))?;
+        }
+        for &name in SETTINGS.iter().filter(|&&name| name != CONFIG_FILE_ENV) {
+            if let Some(value) = std::env::var(name).ok().filter(|v| !v.trim().is_empty()) {
+                config.set(name, &value)?;
+            }
+        }
+        Ok(config)
+    }
+
+    /// Apply the settings in a TOML configuration file.
+    ///
+    /// Keys are the environment variable names without the `MCP_TIME_`
+    /// prefix, in lower case (e.g., `default_timezone = "Europe/Berlin"`,
+    /// `heartbeat_interval = 30`), and take the same values. The world clock
+    /// zones may also be given as an array.
+    pub fn apply_toml(&mut self, contents: &str) -> Result<(), String> {
+        let table: toml::Table = contents
+            .parse()
+            .map_err(|e: toml::de::Error| toml_error(contents, &e))?;
+        for (key, value) in &table {
+            let (name, text) = toml_setting(key, value)?;
+            // Report the file's key rather than the variable it stands for.
+            self.set(&name, &text)
+                .map_err(|message| message.replace(&name, key))?;
+        }
+        Ok(())
+    }
+
+    /// Apply one `MCP_TIME_*` setting, with the variable's `value`.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let path = || Some(PathBuf::from(value)).filter(|_| !value.is_empty());
+        match name {
+            _ if name == DEFAULT_TIMEZONE_ENV => {
+                self.default_timezone = match value.trim() {
+                    "" => TimeZone::UTC,
+                    zone => {
+                        parse_default_timezone(zone).map_err(|e| format!("Invalid {name}: {e}"))?
+                    }
+                }
+            }
+            _ if name == cities::ALLOW_CITY_NAMES_ENV => {
+                self.allow_city_names = env::parse_flag(name, Some(value))?
+            }
+            _ if name == LENIENT_PARSING_ENV => {
+                self.lenient_parsing = env::parse_flag(name, Some(value))?
+            }
+            _ if name == LANGUAGE_ENV => {
+                self.language = match value.trim() {
+                    "" => None,
+                    language => Some(Language::parse(language)?),
+                }
+            }
+            _ if name == heartbeat::INTERVAL_ENV => {
+                self.heartbeat = heartbeat::parse_interval(Some(value))?
+            }
+            _ if name == world_clock::ZONES_ENV => {
+                self.world_clock_zones = world_clock::parse_zones(Some(value))?
+            }
+            _ if name == world_clock::REFRESH_INTERVAL_ENV => {
+                self.world_clock_refresh_interval =
+                    world_clock::parse_refresh_interval(Some(value))?
+            }
+            _ if name == STATE_FILE_ENV => self.state_file = path(),
+            _ if name == HOLIDAYS_FILE_ENV => self.holidays_file = path(),
+            _ if name == FREEZE_TIME_ENV => {
+                self.freeze_time = match value.trim() {
+                    "" => None,
+                    _ => Some(clock::parse_freeze_time(value)?),
+                }
+            }
+            _ if name == ntp::SERVER_ENV => self.ntp_server = ntp::parse_server(value)?,
+            _ if name == TRANSPORT_ENV => self.transport = Transport::parse(value)?,
+            _ if name == BIND_ENV => self.bind = transport::parse_bind(value)?,
+            _ if name == LOG_FORMAT_ENV => self.log_format = LogFormat::parse(value)?,
+            _ if name == telemetry::OTLP_ENDPOINT_ENV => {
+                self.otlp_endpoint = telemetry::parse_otlp_endpoint(value)?
+            }
+            _ if name == middleware::AUTH_TOKEN_ENV => {
+                self.http_middleware.auth_token =
+                    Some(value.trim().to_string()).filter(|token| !token.is_empty())
+            }
+            _ if name == middleware::LOG_REQUESTS_ENV => {
+                self.http_middleware.log_requests = env::parse_flag(name, Some(value))?
+            }
+            _ if name == middleware::CORS_ORIGINS_ENV => {
+                self.http_middleware.cors_origins = middleware::parse_origins(value)?
+            }
+            _ if name == limits::RATE_LIMIT_ENV => {
+                self.rate_limit = limits::parse_limit(name, value)?
+            }
+            _ if name == limits::MAX_LIST_ITEMS_ENV => {
+                self.max_list_items = limits::parse_limit(name, value)?
+            }
+            _ if name == limits::MAX_RESULTS_ENV => {
+                self.max_results = limits::parse_limit(name, value)?
+            }
+            _ => {
+                let mut message = format!("Unknown setting {name}.");
+                if let Some(closest) = closest_setting(SETTINGS.iter().copied(), name) {
+                    message.push_str(&format!(" Did you mean {closest}?"));
+                }
+                return Err(message);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check an environment file (`KEY=value` lines, as read by systemd's
+/// `EnvironmentFile=`, Docker's `--env-file`, or `.env` loaders) holding
+/// server settings.
+///
+/// Each `MCP_TIME_*` value and `RUST_LOG` is validated with the same rules
+/// the server applies at startup, and the state file it names is read and
+/// parsed. Unknown `MCP_TIME_*` names, duplicates, and malformed lines are
+/// reported too; other variables are ignored.
+pub fn check_env_file(contents: &str) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for (index, text) in contents.lines().enumerate() {
+        let line = index + 1;
+        let mut report = |message: String| {
+            issues.push(ConfigIssue {
+                line,
+                text: text.to_string(),
+                message,
+            })
+        };
+        let trimmed = text.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let assignment = trimmed.strip_prefix("export ").unwrap_or(trimmed);
+        let Some((name, value)) = assignment.split_once('=') else {
+            report("Expected KEY=value.".into());
+            continue;
+        };
+        let name = name.trim();
+        let value = unquote(value.trim());
+
+        if let Some(first) = seen.insert(name, line) {
+            report(format!("{name} is already set on line {first}."));
+        }
+        if let Err(message) = check_setting(name, value) {
+            report(message);
+        }
+    }
+    issues
+}
+
+/// Check a TOML configuration file, as read by [`ServerConfig::apply_toml`].
+///
+/// Each setting is validated like its environment variable in
+/// [`check_env_file`], so the state and holidays files it names are read
+/// too, and reported against the line of its key. A syntax error is
+/// reported alone, on its line.
+pub fn check_toml_file(contents: &str) -> Vec<ConfigIssue> {
+    let issue = |line: usize, message: String| ConfigIssue {
+        line,
+        text: contents
+            .lines()
+            .nth(line - 1)
+            .unwrap_or_default()
+            .to_string(),
+        message,
+    };
+    let table: BTreeMap<toml::Spanned<String>, toml::Value> = match toml::from_str(contents) {
+        Ok(table) => table,
+        Err(e) => {
+            let line = e.span().map_or(1, |span| line_of(contents, span.start));
+            return vec![issue(line, e.message().to_string())];
+        }
+    };
+    let mut issues: Vec<ConfigIssue> = table
+        .iter()
+        .filter_map(|(key, value)| {
+            let result = toml_setting(key.get_ref(), value).and_then(|(name, text)| {
+                check_setting(&name, &text).map_err(|message| message.replace(&name, key.get_ref()))
+            });
+            let line = line_of(contents, key.span().start);
+            result.err().map(|message| issue(line, message))
+        })
+        .collect();
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Read a default timezone setting: an IANA name, or "system" for the zone
+/// of the machine running the server.
+pub fn parse_default_timezone(value: &str) -> Result<TimeZone, String> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("system") {
+        return TimeZone::try_system()
+            .map_err(|e| format!("Could not detect the system timezone: {e}."));
+    }
+    crate::core::resolve_timezone(value).map_err(|e| e.message)
+}
+
+/// Validate one setting, ignoring variables the server does not read.
+fn check_setting(name: &str, value: &str) -> Result<(), String> {
+    match name {
+        _ if name == STATE_FILE_ENV => check_state_file(value),
+        _ if name == HOLIDAYS_FILE_ENV => {
+            if value.is_empty() {
+                Ok(())
+            } else {
+                holidays::Database::load(value).map(drop)
+            }
+        }
+        _ if name == CONFIG_FILE_ENV => {
+            if value.is_empty() {
+                return Ok(());
+            }
+            let contents = std::fs::read_to_string(value)
+                .map_err(|e| format!("Failed to read config file {value}: {e}"))?;
+            ServerConfig::default()
+                .apply_toml(&contents)
+                .map_err(|e| format!("Invalid config file {value}: {e}"))
+        }
+        "RUST_LOG" => EnvFilter::try_new(value)
+            .map(drop)
+            .map_err(|e| format!("Invalid RUST_LOG: '{value}': {e}.")),
+        _ if name.starts_with("MCP_TIME_") => ServerConfig::default().set(name, value),
+        _ => Ok(()),
+    }
+}
+
+/// The state file must parse if it exists, and its directory must exist so
+/// the server can create it.
+fn check_state_file(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Ok(());
+    }
+    let file = StateFile::new(value);
+    file.load()?;
+    let dir = match file.path().parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => return Ok(()),
+    };
+    if !dir.is_dir() {
+        return Err(format!(
+            "Invalid {STATE_FILE_ENV}: directory {} does not exist.",
+            dir.display()
+        ));
+    }
+    Ok(())
+}
+
+/// The variable a TOML `key` stands for, with its `value` written as the
+/// variable would hold it.
+fn toml_setting(key: &str, value: &toml::Value) -> Result<(String, String), String> {
+    let name = format!("MCP_TIME_{}", key.to_ascii_uppercase());
+    if key.bytes().any(|b| b.is_ascii_uppercase())
+        || name == CONFIG_FILE_ENV
+        || !SETTINGS.contains(&name.as_str())
+    {
+        let mut message = format!("Unknown setting '{key}'.");
+        let settings = SETTINGS.iter().copied().filter(|&s| s != CONFIG_FILE_ENV);
+        if let Some(closest) = closest_setting(settings, &name) {
+            message.push_str(&format!(
+                " Did you mean {}?",
+                closest["MCP_TIME_".len()..].to_ascii_lowercase()
+            ));
+        }
+        return Err(message);
+    }
+    let text = match value {
+        toml::Value::String(text) => text.clone(),
+        toml::Value::Integer(number) => number.to_string(),
+        toml::Value::Boolean(flag) => flag.to_string(),
+        toml::Value::Array(items) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| format!("Invalid {key}: expected an array of strings."))?
+            .join(","),
+        _ => {
+            return Err(format!(
+                "Invalid {key}: expected a string, number, boolean, or array of strings."
+            ));
+        }
+    };
+    Ok((name, text))
+}
+
+/// A TOML syntax error, with its line when known.
+fn toml_error(contents: &str, error: &toml::de::Error) -> String {
+    let message = error.message().to_string();
+    match error.span().map(|span| line_of(contents, span.start)) {
+        Some(line) => format!("line {line}: {message}"),
+        None => message,
+    }
+}
+
+/// The setting a "did you mean" hint offers for the unknown `name`: the
+/// fewest edits away, if within a third of its length (at least two), or
+/// one that `name` cuts short, so "MCP_TIME_LANG" suggests
+/// "MCP_TIME_LANGUAGE" and "MCP_TIME_UNKNOWN_KEY" suggests nothing.
+fn closest_setting(
+    settings: impl Iterator<Item = &'static str>,
+    name: &str,
+) -> Option<&'static str> {
+    let key = |setting: &str| {
+        setting
+            .strip_prefix("MCP_TIME_")
+            .unwrap_or(setting)
+            .to_ascii_lowercase()
+    };
+    let typed = key(name);
+    let most_edits = (typed.len() / 3).max(2);
+    settings
+        .map(|setting| {
+            let candidate = key(setting);
+            (
+                setting,
+                cities::edit_distance(&candidate, &typed),
+                candidate,
+            )
+        })
+        .filter(|(_, edits, candidate)| {
+            *edits <= most_edits || (typed.len() >= 3 && candidate.starts_with(&typed))
+        })
+        .min_by_key(|(_, edits, _)| *edits)
+        .map(|(setting, _, _)| setting)
+}
+
+/// 1-based line of the byte at `offset` in `contents`.
+fn line_of(contents: &str, offset: usize) -> usize {
+    contents[..offset.min(contents.len())].matches('\n').count() + 1
+}
+
+/// Strip one pair of matching surrounding quotes.
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|&q| value.strip_prefix(q).and_then(|v| v.strip_suffix(q)))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_env_file_accepts_valid_settings() {
+        let contents = "\
+# mcp-time settings
+MCP_TIME_HEARTBEAT_INTERVAL=30
+export MCP_TIME_ALLOW_CITY_NAMES=true
+MCP_TIME_LANGUAGE=\"es-MX\"
+MCP_TIME_WORLD_CLOCK_ZONES=America/New_York,Asia/Tokyo
+MCP_TIME_FREEZE_TIME=2025-07-04T12:00:00Z
+MCP_TIME_DEFAULT_TIMEZONE=Europe/Berlin
+MCP_TIME_TRANSPORT=http
+MCP_TIME_BIND=0.0.0.0:8080
+MCP_TIME_LOG_FORMAT=json
+RUST_LOG=mcp_time=debug
+PATH=/usr/bin
+";
+        assert_eq!(check_env_file(contents), vec![]);
+    }
+
+    #[test]
+    fn check_env_file_reports_issues_with_line_context() {
+        let contents = "\
+MCP_TIME_HEARTBEAT_INTERVAL=1m
+MCP_TIME_LENIENT_PARSING=maybe
+MCP_TIME_LANGUAGE=xx
+MCP_TIME_LANG=de
+MCP_TIME_LENIENT_PARSING=true
+not an assignment
+MCP_TIME_STATE_FILE=/no/such/dir/state.json
+MCP_TIME_WORLD_CLOCK_ZONES=Europe/Paris,Atlantis
+MCP_TIME_HOLIDAYS_FILE=/no/such/dir/holidays.json
+MCP_TIME_FREEZE_TIME=2025-07-04 12:00
+MCP_TIME_DEFAULT_TIMEZONE=Mars/Base
+MCP_TIME_CONFIG=/no/such/dir/mcp-time.toml
+";
+        let issues = check_env_file(contents);
+        let lines: Vec<usize> = issues.iter().map(|issue| issue.line).collect();
+        assert_eq!(lines, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+        assert_eq!(issues[0].text, "MCP_TIME_HEARTBEAT_INTERVAL=1m");
+        assert!(
+            issues[3]
+                .message
+                .contains("Did you mean MCP_TIME_LANGUAGE?")
+        );
+        assert!(issues[4].message.contains("already set on line 2"));
+        assert!(issues[6].message.contains("does not exist"));
+        assert!(issues[7].message.contains("'Atlantis'"));
+        assert!(issues[8].message.contains("Failed to read holidays file"));
+        assert!(issues[9].message.contains("Invalid freeze time"));
+        assert!(issues[10].message.contains("'Mars/Base'"));
+        assert!(issues[11].message.contains("Failed to read config file"));
+    }
+
+    #[test]
+    fn check_toml_file_reports_issues_by_key_line() {
+        assert_eq!(
+            check_toml_file("default_timezone = \"Europe/Berlin\"\nrate_limit = 60\n"),
+            vec![]
+        );
+
+        let contents = "\
+# mcp-time.toml
+heartbeat_interval = \"soon\"
+default_timezone = \"UTC\"
+langauge = \"de\"
+
+state_file = \"/no/such/dir/state.json\"
+";
+        let issues = check_toml_file(contents);
+        let lines: Vec<usize> = issues.iter().map(|issue| issue.line).collect();
+        assert_eq!(lines, vec![2, 4, 6]);
+        assert_eq!(issues[0].text, "heartbeat_interval = \"soon\"");
+        assert!(
+            issues[0].message.contains("Invalid heartbeat_interval"),
+            "{}",
+            issues[0].message
+        );
+        assert!(issues[1].message.contains("Did you mean language?"));
+        assert!(issues[2].message.contains("does not exist"));
+
+        let issues = check_toml_file("rate_limit = 60\nlanguage = [\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, 2);
+    }
+
+    #[test]
+    fn apply_toml_reads_settings_by_key() {
+        let mut config = ServerConfig::default();
+        config
+            .apply_toml(
+                r#"
+# mcp-time.toml
+default_timezone = "Europe/Berlin"
+lenient_parsing = true
+heartbeat_interval = 30
+world_clock_zones = ["Asia/Tokyo", "America/New_York"]
+world_clock_refresh_interval = 10
+transport = "http"
+ntp_server = "time.google.com"
+rate_limit = 120
+max_list_items = 0
+http_auth_token = "secret"
+http_cors_origins = ["https://app.example.com"]
+"#,
+            )
+            .unwrap();
+        assert_eq!(config.default_timezone.iana_name(), Some("Europe/Berlin"));
+        assert!(config.lenient_parsing);
+        assert_eq!(config.heartbeat, Some(Duration::from_secs(30)));
+        assert_eq!(config.world_clock_zones, ["Asia/Tokyo", "America/New_York"]);
+        assert_eq!(config.world_clock_refresh_interval, Duration::from_secs(10));
+        assert_eq!(config.transport, Transport::Http);
+        assert_eq!(config.ntp_server, "time.google.com:123");
+        assert_eq!(config.log_format, LogFormat::Text);
+        assert_eq!(config.rate_limit, Some(120));
+        assert_eq!(config.max_list_items, None);
+        assert_eq!(
+            config.http_middleware,
+            Middleware {
+                auth_token: Some("secret".into()),
+                log_requests: false,
+                cors_origins: vec!["https://app.example.com".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn apply_toml_reports_errors_by_key() {
+        for (contents, expected) in [
+            (
+                "heartbeat_interval = \"soon\"",
+                "Invalid heartbeat_interval: 'soon'",
+            ),
+            (
+                "defualt_timezone = \"UTC\"",
+                "Did you mean default_timezone?",
+            ),
+            ("config = \"other.toml\"", "Unknown setting 'config'"),
+            ("world_clock_zones = [1, 2]", "expected an array of strings"),
+            ("bind = 8080", "Invalid bind address: '8080'"),
+            (
+                "world_clock_refresh_interval = 0",
+                "Invalid world_clock_refresh_interval: '0'",
+            ),
+            ("http_cors_origins = \"example.com\"", "Invalid CORS origin"),
+            (
+                "default_timezone = \"Mars/Base\"",
+                "Invalid default_timezone",
+            ),
+            ("\nlanguage = [", "line 2:"),
+        ] {
+            let err = ServerConfig::default().apply_toml(contents).unwrap_err();
+            assert!(err.contains(expected), "Error for '{contents}' was: {err}");
+        }
+    }
+
+    #[test]
+    fn unknown_settings_suggest_only_close_names() {
+        let err = ServerConfig::default()
+            .apply_toml("unknown_key = 1")
+            .unwrap_err();
+        assert_eq!(err, "Unknown setting 'unknown_key'.");
+        let err = ServerConfig::default()
+            .set("MCP_TIME_UNKNOWN_KEY", "1")
+            .unwrap_err();
+        assert_eq!(err, "Unknown setting MCP_TIME_UNKNOWN_KEY.");
+        let err = ServerConfig::default()
+            .set("MCP_TIME_HEARTBEAT_INTERVL", "1")
+            .unwrap_err();
+        assert!(
+            err.ends_with("Did you mean MCP_TIME_HEARTBEAT_INTERVAL?"),
+            "Error was: {err}"
+        );
+    }
+}
@@ -0,0 +1,612 @@
+use jiff::{
+    Timestamp, Zoned,
+    civil::DateTime,
+    tz::{AmbiguousOffset, TimeZone},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{cities, unicode};
+
+/// Error message template for invalid timezone input.
+const ERR_INVALID_TIMEZONE: &str =
+    "Invalid timezone: '{}'. Please use a valid IANA timezone name (e.g., 'America/New_York').";
+
+/// Warning attached to conversions involving a fixed UTC offset.
+const WARN_FIXED_OFFSET: &str = "A fixed UTC offset was used, so this conversion is DST-unaware: if the location observes daylight saving time, its offset may differ on other dates.";
+
+/// An input validation failure.
+///
+/// The MCP tools serialize it as their error content, so clients and agents
+/// can recover from the `code` and `suggestions` instead of matching the
+/// English `message`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Error {
+    /// Stable, machine-readable error code (e.g., `invalid_timezone`).
+    pub code: &'static str,
+    pub message: String,
+    /// The input that failed validation, when there is one.
+    pub offending_value: Option<String>,
+    /// Values that would have been accepted, closest first.
+    pub suggestions: Vec<String>,
+    /// Seconds to wait before retrying, when the call was refused for being
+    /// made too soon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_seconds: Option<u64>,
+}
+
+impl Error {
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            offending_value: None,
+            suggestions: Vec::new(),
+            retry_after_seconds: None,
+        }
+    }
+
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.offending_value = Some(value.into());
+        self
+    }
+
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after_seconds = Some(seconds);
+        self
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Errors reported by other modules as plain messages.
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Self::new("invalid_input", message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Self::new("invalid_input", message)
+    }
+}
+
+/// The time in one timezone at one instant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CurrentTime {
+    pub timezone: String,
+    /// Zone name in the configured language.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// RFC 3339 datetime with the zone's UTC offset.
+    pub datetime: String,
+    /// The datetime in the requested `format`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted: Option<String>,
+    /// The datetime written out for the requested `locale` (e.g.,
+    /// "Dienstag, 5. März 2025, 14:30").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub human_readable: Option<String>,
+    pub utc_offset: String,
+    pub is_dst: bool,
+}
+
+/// One side of a [`Conversion`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ConvertedTime {
+    pub timezone: String,
+    /// Zone name in the configured language.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// RFC 3339 datetime with the zone's UTC offset.
+    pub datetime: String,
+    /// The datetime in the requested `format`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted: Option<String>,
+    /// The datetime written out for the requested `locale` (e.g.,
+    /// "Dienstag, 5. März 2025, 14:30").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub human_readable: Option<String>,
+    /// Calendar date in this entry's timezone (YYYY-MM-DD).
+    pub date: String,
+    pub utc_offset: String,
+    /// Set when the timezone is a fixed UTC offset rather than an IANA zone.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fixed_offset: bool,
+}
+
+/// Which instant a wall-clock time stands for when it happens twice, because
+/// clocks go back.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Ambiguity {
+    /// The first instance, at the offset before the change.
+    #[default]
+    Earlier,
+    /// The second instance, at the offset after the change.
+    Later,
+    /// Fail with an `ambiguous_local_time` error.
+    Reject,
+}
+
+/// How to read a wall-clock time that never happens, because clocks go
+/// forward.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Gap {
+    /// Move it forward by the length of the gap, to the first valid time
+    /// that far from the previous instant (e.g., 02:30 becomes 03:30 when
+    /// clocks skip from 02:00 to 03:00).
+    NextValid,
+    /// Fail with a `nonexistent_local_time` error.
+    #[default]
+    Reject,
+}
+
+/// A wall-clock time in one timezone and the same instant in another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Conversion {
+    pub source: ConvertedTime,
+    pub target: ConvertedTime,
+    /// Target UTC offset minus the source's (e.g., "+5:30").
+    pub time_difference: String,
+    /// Present when either side is a fixed offset, whose conversion ignores DST.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    /// Set when the source time happens twice because clocks go back.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub was_ambiguous: bool,
+    /// Set when the source time never happens because clocks go forward.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub was_skipped: bool,
+    /// Source UTC offset picked for an ambiguous or skipped time (e.g.,
+    /// "-05:00").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chosen_offset: Option<String>,
+    /// IANA Time Zone Database release whose rules, as in effect on the
+    /// date converted, gave these offsets (e.g., "2025b").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tzdb_version: Option<String>,
+}
+
+/// Parse and validate an IANA timezone string.
+///
+/// Returns an error for timezone abbreviations (e.g., "EST") and raw UTC
+/// offset strings (e.g., "+05:30", "UTC+5") with a message suggesting the
+/// IANA equivalent, and suggests close matches for unknown names.
+pub fn resolve_timezone(input: &str) -> Result<TimeZone, Error> {
+    let input = &*unicode::normalize_input(input);
+
+    // Reject raw offset strings like "+05:30", "-05:00"
+    if input.starts_with('+') || input.starts_with('-') {
+        return Err(Error::new(
+            "unsupported_offset",
+            format!(
+                "Timezone offset '{}' is not supported. Please use a valid IANA timezone name (e.g., 'Asia/Kolkata' instead of '+05:30').",
+                input
+            ),
+        )
+        .with_value(input));
+    }
+
+    // Reject "UTC+N" or "UTC-N" style offsets
+    if input.starts_with("UTC+")
+        || input.starts_with("UTC-")
+        || input.starts_with("GMT+")
+        || input.starts_with("GMT-")
+    {
+        return Err(Error::new(
+            "unsupported_offset",
+            format!(
+                "Timezone offset '{}' is not supported. Please use a valid IANA timezone name (e.g., 'Asia/Kolkata' instead of 'UTC+5:30').",
+                input
+            ),
+        )
+        .with_value(input));
+    }
+
+    // Reject timezone abbreviations (all uppercase, no '/', short, not "UTC")
+    // before calling jiff, since some abbreviations like "EST" exist in the
+    // IANA database but are ambiguous and should not be accepted.
+    if input != "UTC"
+        && !input.contains('/')
+        && input.len() <= 5
+        && input.chars().all(|c| c.is_ascii_uppercase())
+    {
+        return Err(Error::new(
+            "ambiguous_abbreviation",
+            format!(
+                "Timezone abbreviation '{}' is ambiguous. Please use a valid IANA timezone name (e.g., 'America/New_York' instead of 'EST').",
+                input
+            ),
+        )
+        .with_value(input));
+    }
+
+    TimeZone::get(input).map_err(|_| invalid_timezone(input))
+}
+
+/// Error for an unknown timezone, suggesting close matches among zone and
+/// city names.
+fn invalid_timezone(input: &str) -> Error {
+    let suggestions = cities::suggest(input, 3)
+        .into_iter()
+        .map(|s| s.zone)
+        .collect();
+    Error::new(
+        "invalid_timezone",
+        ERR_INVALID_TIMEZONE.replacen("{}", input, 1),
+    )
+    .with_value(input)
+    .with_suggestions(suggestions)
+}
+
+/// The time in `tz` at `now`, with its UTC offset and DST status.
+pub fn current_time_in(tz: &TimeZone, now: Timestamp) -> CurrentTime {
+    let zdt = now.to_zoned(tz.clone());
+
+    // Determine DST status using jiff's offset info, which provides
+    // authoritative DST data from the timezone database.
+    let is_dst = tz.to_offset_info(now).dst().is_dst();
+
+    CurrentTime {
+        timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+        display_name: None,
+        datetime: zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+        formatted: None,
+        human_readable: None,
+        utc_offset: format_utc_offset(zdt.offset()),
+        is_dst,
+    }
+}
+
+/// Convert the wall-clock `datetime` in `source` to `target`.
+///
+/// A time skipped when clocks go forward moves forward by the gap, and one
+/// repeated when they go back is read as its first instance; see
+/// [`convert_between_with`] to choose otherwise.
+///
+/// ```
+/// let new_york = jiff::tz::TimeZone::get("America/New_York").unwrap();
+/// let tokyo = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+/// let meeting = jiff::civil::date(2025, 3, 10).at(9, 0, 0, 0);
+/// let conversion = mcp_time::core::convert_between(meeting, &new_york, &tokyo).unwrap();
+/// assert_eq!(conversion.target.datetime, "2025-03-10T22:00:00+09:00");
+/// assert_eq!(conversion.time_difference, "+13:00");
+/// ```
+pub fn convert_between(
+    datetime: DateTime,
+    source: &TimeZone,
+    target: &TimeZone,
+) -> Result<Conversion, Error> {
+    convert_between_with(
+        datetime,
+        source,
+        target,
+        Ambiguity::default(),
+        Gap::NextValid,
+    )
+}
+
+/// Convert the wall-clock `datetime` in `source` to `target`, reading a
+/// repeated time by `ambiguity` and a skipped one by `gap`.
+pub fn convert_between_with(
+    datetime: DateTime,
+    source: &TimeZone,
+    target: &TimeZone,
+    ambiguity: Ambiguity,
+    gap: Gap,
+) -> Result<Conversion, Error> {
+    let local = resolve_local(datetime, source, ambiguity, gap)?;
+    Ok(convert_local(&local, source, target))
+}
+
+/// A wall-clock time read as an instant in its timezone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalTime {
+    pub zoned: Zoned,
+    /// The wall-clock time happens twice because clocks go back.
+    pub was_ambiguous: bool,
+    /// The wall-clock time never happens because clocks go forward.
+    pub was_skipped: bool,
+}
+
+/// Read the wall-clock `datetime` in `tz`, a repeated time by `ambiguity`
+/// and a skipped one by `gap`.
+pub fn resolve_local(
+    datetime: DateTime,
+    tz: &TimeZone,
+    ambiguity: Ambiguity,
+    gap: Gap,
+) -> Result<LocalTime, Error> {
+    let time = datetime.strftime("%H:%M").to_string();
+    let ambiguous = tz.to_ambiguous_zoned(datetime);
+    let (was_ambiguous, was_skipped) = match ambiguous.offset() {
+        AmbiguousOffset::Unambiguous { .. } => (false, false),
+        AmbiguousOffset::Fold { before, after } => {
+            if ambiguity == Ambiguity::Reject {
+                return Err(Error::new(
+                    "ambiguous_local_time",
+                    format!(
+                        "The time {time} happens twice in timezone '{}' due to a DST transition (fall back): at {} and at {}. \
+                         Pass ambiguity 'earlier' or 'later' to choose one.",
+                        zone_label(tz),
+                        format_utc_offset(before),
+                        format_utc_offset(after)
+                    ),
+                )
+                .with_value(time));
+            }
+            (true, false)
+        }
+        AmbiguousOffset::Gap { .. } => {
+            if gap == Gap::Reject {
+                return Err(nonexistent(&time, tz));
+            }
+            (false, true)
+        }
+    };
+    let resolved = match ambiguity {
+        Ambiguity::Later => ambiguous.later(),
+        Ambiguity::Earlier | Ambiguity::Reject => ambiguous.compatible(),
+    };
+    Ok(LocalTime {
+        zoned: resolved.map_err(|_| nonexistent(&time, tz))?,
+        was_ambiguous,
+        was_skipped,
+    })
+}
+
+/// Convert `local`, read in `source`, to `target`.
+pub fn convert_local(local: &LocalTime, source: &TimeZone, target: &TimeZone) -> Conversion {
+    let source_zdt = &local.zoned;
+    let target_zdt = source_zdt.with_time_zone(target.clone());
+
+    let diff_secs = target_zdt.offset().seconds() - source_zdt.offset().seconds();
+    let chosen_offset =
+        (local.was_ambiguous || local.was_skipped).then(|| format_utc_offset(source_zdt.offset()));
+    let source = converted_time(source, source_zdt);
+    let target = converted_time(target, &target_zdt);
+    let warning =
+        (source.fixed_offset || target.fixed_offset).then(|| WARN_FIXED_OFFSET.to_string());
+    Conversion {
+        source,
+        target,
+        time_difference: format_offset_diff(diff_secs),
+        warning,
+        was_ambiguous: local.was_ambiguous,
+        was_skipped: local.was_skipped,
+        chosen_offset,
+        tzdb_version: crate::tzdb::version().map(str::to_string),
+    }
+}
+
+/// The error for a wall-clock `time` that `tz` skips.
+fn nonexistent(time: &str, tz: &TimeZone) -> Error {
+    Error::new(
+        "nonexistent_local_time",
+        format!(
+            "The time {time} does not exist in timezone '{}' due to a DST transition (spring forward). \
+             Please choose a different time, or pass gap 'next_valid' to move it past the gap.",
+            zone_label(tz)
+        ),
+    )
+    .with_value(time)
+}
+
+/// The IANA name of `tz`, or its offset for fixed offsets.
+/// Name of `tz` for responses: its IANA name, or its offset when it has
+/// none.
+pub(crate) fn zone_label(tz: &TimeZone) -> String {
+    match (tz.iana_name(), tz.to_fixed_offset()) {
+        (Some(name), _) => name.to_string(),
+        (None, Ok(offset)) => format_utc_offset(offset),
+        (None, Err(_)) => "UTC".to_string(),
+    }
+}
+
+/// `zdt` as one side of a conversion, in `tz`.
+pub(crate) fn converted_time(tz: &TimeZone, zdt: &Zoned) -> ConvertedTime {
+    let fixed_offset = tz.iana_name().is_none() && tz.to_fixed_offset().is_ok();
+    ConvertedTime {
+        timezone: if fixed_offset {
+            format_utc_offset(zdt.offset())
+        } else {
+            tz.iana_name().unwrap_or("UTC").to_string()
+        },
+        display_name: None,
+        datetime: zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+        formatted: None,
+        human_readable: None,
+        date: zdt.date().to_string(),
+        utc_offset: format_utc_offset(zdt.offset()),
+        fixed_offset,
+    }
+}
+
+/// Format a UTC offset as "+HH:MM" or "-HH:MM".
+///
+/// Correctly handles fractional-hour offsets (e.g., +05:45 for Asia/Kathmandu).
+pub fn format_utc_offset(offset: jiff::tz::Offset) -> String {
+    let total_seconds = offset.seconds();
+    let sign = if total_seconds < 0 { '-' } else { '+' };
+    let abs_seconds = total_seconds.unsigned_abs();
+    let hours = abs_seconds / 3600;
+    let minutes = (abs_seconds % 3600) / 60;
+    format!("{}{:02}:{:02}", sign, hours, minutes)
+}
+
+/// Format an offset difference in seconds as a "+H:MM" or "-H:MM" string.
+pub fn format_offset_diff(diff_secs: i32) -> String {
+    let sign = if diff_secs < 0 { '-' } else { '+' };
+    let abs = diff_secs.unsigned_abs();
+    let hours = abs / 3600;
+    let minutes = (abs % 3600) / 60;
+    format!("{}{}:{:02}", sign, hours, minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_timezone_returns_ok_for_valid_iana_name() {
+        let result = resolve_timezone("America/New_York");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn resolve_timezone_returns_err_for_invalid_name() {
+        let result = resolve_timezone("Fake/Zone");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolve_timezone_suggests_close_matches() {
+        let err = resolve_timezone("Los Angelos").unwrap_err();
+        assert_eq!(err.code, "invalid_timezone");
+        assert_eq!(err.offending_value.as_deref(), Some("Los Angelos"));
+        assert_eq!(err.suggestions[0], "America/Los_Angeles");
+        let err = resolve_timezone("Mumbia").unwrap_err();
+        assert_eq!(err.suggestions[0], "Asia/Kolkata");
+    }
+
+    #[test]
+    fn resolve_timezone_returns_err_for_abbreviation() {
+        let result = resolve_timezone("PST");
+        let err = result.unwrap_err();
+        assert_eq!(err.code, "ambiguous_abbreviation");
+        assert!(
+            err.message.contains("IANA timezone name"),
+            "Error was: {err}"
+        );
+    }
+
+    #[test]
+    fn resolve_timezone_returns_err_for_offset_string() {
+        let result = resolve_timezone("+05:30");
+        let err = result.unwrap_err();
+        assert_eq!(err.code, "unsupported_offset");
+        assert!(
+            err.message.contains("IANA timezone name"),
+            "Error was: {err}"
+        );
+    }
+
+    #[test]
+    fn format_utc_offset_formats_positive_whole_hours() {
+        let offset = jiff::tz::Offset::from_seconds(5 * 3600).unwrap();
+        assert_eq!(format_utc_offset(offset), "+05:00");
+    }
+
+    #[test]
+    fn format_utc_offset_formats_negative_whole_hours() {
+        let offset = jiff::tz::Offset::from_seconds(-5 * 3600).unwrap();
+        assert_eq!(format_utc_offset(offset), "-05:00");
+    }
+
+    #[test]
+    fn format_utc_offset_formats_fractional_hours() {
+        // +05:45 for Asia/Kathmandu
+        let offset = jiff::tz::Offset::from_seconds(5 * 3600 + 45 * 60).unwrap();
+        assert_eq!(format_utc_offset(offset), "+05:45");
+    }
+
+    #[test]
+    fn format_utc_offset_formats_zero_as_positive() {
+        let offset = jiff::tz::Offset::from_seconds(0).unwrap();
+        assert_eq!(format_utc_offset(offset), "+00:00");
+    }
+
+    #[test]
+    fn current_time_in_reports_offset_and_dst() {
+        let tz = TimeZone::get("Europe/London").unwrap();
+        let now: Timestamp = "2025-07-04T12:00:00Z".parse().unwrap();
+        let time = current_time_in(&tz, now);
+        assert_eq!(time.datetime, "2025-07-04T13:00:00+01:00");
+        assert_eq!(time.utc_offset, "+01:00");
+        assert!(time.is_dst);
+        assert_eq!(time.display_name, None);
+    }
+
+    #[test]
+    fn convert_between_round_trips_through_json() {
+        let source = TimeZone::get("America/New_York").unwrap();
+        let target = TimeZone::fixed(jiff::tz::offset(5));
+        let datetime = jiff::civil::date(2025, 1, 15).at(23, 0, 0, 0);
+        let conversion = convert_between(datetime, &source, &target).unwrap();
+        assert_eq!(conversion.target.date, "2025-01-16");
+        assert_eq!(conversion.target.timezone, "+05:00");
+        assert!(conversion.target.fixed_offset);
+        assert_eq!(conversion.time_difference, "+10:00");
+        assert!(conversion.warning.is_some());
+
+        let json = serde_json::to_string(&conversion).unwrap();
+        assert!(!json.contains("display_name"));
+        assert_eq!(
+            serde_json::from_str::<Conversion>(&json).unwrap(),
+            conversion
+        );
+    }
+
+    #[test]
+    fn convert_between_moves_skipped_times_forward() {
+        let source = TimeZone::get("America/New_York").unwrap();
+        let datetime = jiff::civil::date(2025, 3, 9).at(2, 30, 0, 0);
+        let conversion = convert_between(datetime, &source, &TimeZone::UTC).unwrap();
+        assert_eq!(conversion.source.datetime, "2025-03-09T03:30:00-04:00");
+        assert_eq!(conversion.target.datetime, "2025-03-09T07:30:00+00:00");
+        assert!(conversion.was_skipped);
+        assert_eq!(conversion.chosen_offset.as_deref(), Some("-04:00"));
+
+        let err = convert_between_with(
+            datetime,
+            &source,
+            &TimeZone::UTC,
+            Ambiguity::Earlier,
+            Gap::Reject,
+        )
+        .unwrap_err();
+        assert_eq!(err.code, "nonexistent_local_time");
+    }
+
+    #[test]
+    fn convert_between_with_applies_the_ambiguity_policy() {
+        let source = TimeZone::get("America/New_York").unwrap();
+        let datetime = jiff::civil::date(2025, 11, 2).at(1, 30, 0, 0);
+        let convert = |ambiguity| {
+            convert_between_with(datetime, &source, &TimeZone::UTC, ambiguity, Gap::NextValid)
+        };
+        let earlier = convert(Ambiguity::Earlier).unwrap();
+        assert_eq!(earlier.target.datetime, "2025-11-02T05:30:00+00:00");
+        assert!(earlier.was_ambiguous);
+        assert_eq!(earlier.chosen_offset.as_deref(), Some("-04:00"));
+        let later = convert(Ambiguity::Later).unwrap();
+        assert_eq!(later.target.datetime, "2025-11-02T06:30:00+00:00");
+        assert_eq!(later.chosen_offset.as_deref(), Some("-05:00"));
+        let err = convert(Ambiguity::Reject).unwrap_err();
+        assert_eq!(err.code, "ambiguous_local_time");
+        assert!(err.message.contains("-04:00 and at -05:00"), "{err}");
+
+        let plain = convert_between(
+            jiff::civil::date(2025, 11, 3).at(1, 30, 0, 0),
+            &source,
+            &TimeZone::UTC,
+        )
+        .unwrap();
+        assert!(!plain.was_ambiguous && !plain.was_skipped);
+        assert_eq!(plain.chosen_offset, None);
+    }
+}
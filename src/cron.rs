@@ -0,0 +1,233 @@
+use jiff::civil::{Date, DateTime, Time};
+
+/// Days searched for the next match before giving up, enough to reach a
+/// February 29 across a skipped century leap year.
+const HORIZON_DAYS: i64 = 366 * 9;
+
+/// Month and weekday names accepted in place of numbers.
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// A five-field cron expression: minute, hour, day of month, month, and
+/// day of week.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    /// Bit 0 is Sunday.
+    weekdays: u8,
+    /// Whether the day-of-month and day-of-week fields were both
+    /// restricted, in which case a day matching either one matches.
+    either_day: bool,
+}
+
+impl Schedule {
+    /// Parse a cron expression such as "0 9 * * 1-5" or "*/15 * * * *", or
+    /// one of the `@yearly`, `@monthly`, `@weekly`, `@daily`, and `@hourly`
+    /// macros.
+    ///
+    /// Fields take `*`, numbers, ranges (`1-5`), steps (`*/15`, `0-30/10`),
+    /// and comma-separated lists of those, with month and weekday names
+    /// (`JAN`, `mon-fri`). Day of week runs from 0 (Sunday) to 7 (Sunday
+    /// again).
+    pub fn parse(input: &str) -> Result<Schedule, String> {
+        let trimmed = input.trim();
+        let expanded = match trimmed.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other if other.starts_with('@') => {
+                return Err(format!(
+                    "Unsupported cron macro: '{trimmed}'. Expected @yearly, @monthly, @weekly, @daily, or @hourly."
+                ));
+            }
+            _ => trimmed,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(format!(
+                "Invalid cron expression: '{trimmed}'. Expected 5 fields (minute hour day-of-month month day-of-week, e.g., '0 9 * * 1-5'), found {}.",
+                fields.len()
+            ));
+        };
+        let weekdays = field(weekday, "day of week", 0, 7, &WEEKDAYS)?;
+        // 7 is another name for Sunday.
+        let weekdays = (weekdays | (weekdays >> 7)) & 0x7f;
+        Ok(Schedule {
+            minutes: field(minute, "minute", 0, 59, &[])?,
+            hours: field(hour, "hour", 0, 23, &[])? as u32,
+            days_of_month: field(day, "day of month", 1, 31, &[])? as u32,
+            months: field(month, "month", 1, 12, &MONTHS)? as u16,
+            weekdays: weekdays as u8,
+            either_day: !day.starts_with('*') && !weekday.starts_with('*'),
+        })
+    }
+
+    fn matches_day(&self, date: Date) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day = self.days_of_month & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().to_sunday_zero_offset()) != 0;
+        if self.either_day {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+
+    /// Wall-clock times the schedule fires at strictly after `after`, in
+    /// order, for about nine years.
+    pub fn after(&self, after: DateTime) -> impl Iterator<Item = DateTime> + '_ {
+        after
+            .date()
+            .series(jiff::Span::new().days(1))
+            .take(HORIZON_DAYS as usize)
+            .filter(|&date| self.matches_day(date))
+            .flat_map(move |date| {
+                bits(u64::from(self.hours), 0, 23).flat_map(move |hour| {
+                    bits(self.minutes, 0, 59).map(move |minute| {
+                        date.to_datetime(Time::constant(hour as i8, minute as i8, 0, 0))
+                    })
+                })
+            })
+            .filter(move |&datetime| datetime > after)
+    }
+}
+
+/// Set bits of `set` from `low` through `high`, in order.
+fn bits(set: u64, low: u32, high: u32) -> impl Iterator<Item = u32> {
+    (low..=high).filter(move |&bit| set & (1 << bit) != 0)
+}
+
+/// Parse one field into a bit set of the values it allows.
+fn field(input: &str, name: &str, low: u32, high: u32, names: &[&str]) -> Result<u64, String> {
+    let invalid = |reason: &str| {
+        format!(
+            "Invalid cron {name} field '{input}': {reason}. Expected values from {low} to {high}."
+        )
+    };
+    let value = |text: &str| -> Result<u32, String> {
+        let lower = text.to_ascii_lowercase();
+        let number = match names.iter().position(|n| *n == lower) {
+            // Month names count from 1, weekday names from 0 (Sunday).
+            Some(index) => index as u32 + low,
+            None => text
+                .parse()
+                .map_err(|_| invalid(&format!("'{text}' is not a number")))?,
+        };
+        if !(low..=high).contains(&number) {
+            return Err(invalid(&format!("{number} is out of range")));
+        }
+        Ok(number)
+    };
+    let mut set = 0u64;
+    for part in input.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 =
+                    step.parse().ok().filter(|&step| step > 0).ok_or_else(|| {
+                        invalid(&format!("step '{step}' is not a positive number"))
+                    })?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (from, to) = match range.split_once('-') {
+            _ if range == "*" => (low, high),
+            Some((from, to)) => (value(from)?, value(to)?),
+            // "5/10" runs from 5 to the end of the range.
+            None if step > 1 => (value(range)?, high),
+            None => {
+                let single = value(range)?;
+                (single, single)
+            }
+        };
+        if from > to {
+            return Err(invalid(&format!("range {from}-{to} is reversed")));
+        }
+        for bit in (from..=to).step_by(step as usize) {
+            set |= 1 << bit;
+        }
+    }
+    Ok(set)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+
+    fn next(expression: &str, after: DateTime, n: usize) -> Vec<String> {
+        Schedule::parse(expression)
+            .unwrap()
+            .after(after)
+            .take(n)
+            .map(|datetime| datetime.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parse_reads_ranges_steps_and_names() {
+        let schedule = Schedule::parse("*/15 9-17 * JAN,jul mon-fri").unwrap();
+        assert_eq!(schedule.minutes, 1 | 1 << 15 | 1 << 30 | 1 << 45);
+        assert_eq!(schedule.months, 1 << 1 | 1 << 7);
+        assert_eq!(schedule.weekdays, 0b0011_1110);
+        assert_eq!(Schedule::parse("0 0 * * 7").unwrap().weekdays, 1);
+        assert_eq!(
+            Schedule::parse("@daily").unwrap(),
+            Schedule::parse("0 0 * * *").unwrap()
+        );
+        for (input, expected) in [
+            ("* * * *", "Expected 5 fields"),
+            ("60 * * * *", "out of range"),
+            ("* * * * fri-mon", "reversed"),
+            ("*/0 * * * *", "step"),
+            ("@reboot", "Unsupported cron macro"),
+        ] {
+            let err = Schedule::parse(input).unwrap_err();
+            assert!(err.contains(expected), "Error for '{input}' was: {err}");
+        }
+    }
+
+    #[test]
+    fn after_lists_matching_times_in_order() {
+        // 2025-07-04 is a Friday.
+        let after = date(2025, 7, 4).at(17, 30, 0, 0);
+        assert_eq!(
+            next("0 9 * * 1-5", after, 2),
+            ["2025-07-07T09:00:00", "2025-07-08T09:00:00"]
+        );
+        assert_eq!(
+            next("*/20 * * * *", after, 3),
+            [
+                "2025-07-04T17:40:00",
+                "2025-07-04T18:00:00",
+                "2025-07-04T18:20:00"
+            ]
+        );
+        assert_eq!(next("0 0 29 2 *", after, 1), ["2028-02-29T00:00:00"]);
+        assert!(next("0 0 30 2 *", after, 1).is_empty());
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either() {
+        // The 13th of the month, or any Friday.
+        let after = date(2025, 6, 1).at(0, 0, 0, 0);
+        assert_eq!(
+            next("0 0 13 * 5", after, 3),
+            [
+                "2025-06-06T00:00:00",
+                "2025-06-13T00:00:00",
+                "2025-06-20T00:00:00"
+            ]
+        );
+    }
+}
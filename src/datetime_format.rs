@@ -0,0 +1,97 @@
+/// Format names accepted besides strftime strings, for error messages.
+pub const PRESETS: &str = "'rfc3339', 'rfc2822', 'unix', or 'human'";
+
+/// strftime string of the `human` preset (e.g., "Friday, 4 July 2025 at
+/// 12:00 PM EDT").
+const HUMAN: &str = "%A, %-d %B %Y at %-I:%M %p %Z";
+
+/// How a datetime is rendered in a response's `formatted` field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatetimeFormat {
+    /// "2025-07-04T12:00:00-04:00", the same as `datetime`.
+    Rfc3339,
+    /// "Fri, 4 Jul 2025 12:00:00 -0400".
+    Rfc2822,
+    /// Unix epoch seconds (e.g., "1751644800").
+    Unix,
+    /// "Friday, 4 July 2025 at 12:00 PM EDT".
+    Human,
+    /// A strftime string, checked when parsed (e.g., "%d/%m/%Y %H:%M").
+    Strftime(String),
+}
+
+impl DatetimeFormat {
+    /// Read a preset name, in any case, or a strftime string.
+    ///
+    /// Strings without a `%` directive are rejected, since they are more
+    /// likely misspelled presets than formats meant to print the same text
+    /// for every datetime.
+    pub fn parse(input: &str) -> Result<DatetimeFormat, String> {
+        let format = match input.trim().to_ascii_lowercase().as_str() {
+            "rfc3339" | "iso8601" => DatetimeFormat::Rfc3339,
+            "rfc2822" => DatetimeFormat::Rfc2822,
+            "unix" => DatetimeFormat::Unix,
+            "human" => DatetimeFormat::Human,
+            _ if !input.contains('%') => {
+                return Err(format!(
+                    "Unknown format: '{input}'. Expected {PRESETS}, or a strftime string (e.g., '%d/%m/%Y %H:%M')."
+                ));
+            }
+            _ => DatetimeFormat::Strftime(input.to_string()),
+        };
+        // Check the directives against a datetime that has every field.
+        let sample = jiff::Timestamp::UNIX_EPOCH.to_zoned(jiff::tz::TimeZone::UTC);
+        format.format(&sample)?;
+        Ok(format)
+    }
+
+    /// Render `zdt` in this format.
+    ///
+    /// Fails for datetimes the format cannot represent, such as RFC 2822
+    /// years before 1900.
+    pub fn format(&self, zdt: &jiff::Zoned) -> Result<String, String> {
+        let strftime = |format: &str| {
+            jiff::fmt::strtime::format(format, zdt)
+                .map_err(|e| format!("Invalid strftime format '{format}': {e}"))
+        };
+        match self {
+            DatetimeFormat::Rfc3339 => strftime("%Y-%m-%dT%H:%M:%S%:z"),
+            DatetimeFormat::Rfc2822 => jiff::fmt::rfc2822::to_string(zdt)
+                .map_err(|e| format!("Cannot format {zdt} as RFC 2822: {e}")),
+            DatetimeFormat::Unix => Ok(zdt.timestamp().as_second().to_string()),
+            DatetimeFormat::Human => strftime(HUMAN),
+            DatetimeFormat::Strftime(format) => strftime(format),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zdt() -> jiff::Zoned {
+        "2025-07-04T12:00:00-04:00[America/New_York]"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn presets_render_the_same_instant() {
+        let render = |name| DatetimeFormat::parse(name).unwrap().format(&zdt()).unwrap();
+        assert_eq!(render("rfc3339"), "2025-07-04T12:00:00-04:00");
+        assert_eq!(render("RFC2822"), "Fri, 4 Jul 2025 12:00:00 -0400");
+        assert_eq!(render("unix"), "1751644800");
+        assert_eq!(render("human"), "Friday, 4 July 2025 at 12:00 PM EDT");
+        assert_eq!(render("%d/%m/%Y %H:%M"), "04/07/2025 12:00");
+    }
+
+    #[test]
+    fn parse_rejects_unknown_presets_and_bad_directives() {
+        let err = DatetimeFormat::parse("rfc3399").unwrap_err();
+        assert!(err.contains("Unknown format"), "Error was: {err}");
+        for input in ["%Y-%m-%d %", "%Y %!"] {
+            let err = DatetimeFormat::parse(input).unwrap_err();
+            assert!(err.contains("Invalid strftime"), "Error was: {err}");
+        }
+    }
+}
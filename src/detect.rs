@@ -0,0 +1,465 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::epoch::{EpochUnit, EpochValue};
+
+/// How strongly the input matches a candidate format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl Confidence {
+    /// One level lower, saturating at `Low`.
+    fn lower(self) -> Self {
+        match self {
+            Confidence::High => Confidence::Medium,
+            _ => Confidence::Low,
+        }
+    }
+}
+
+/// A format the input could plausibly be written in.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FormatCandidate {
+    /// Stable identifier for the format (e.g., `epoch_seconds`, `us_date`).
+    pub format: &'static str,
+    /// Human-readable description of the format.
+    pub description: &'static str,
+    pub confidence: Confidence,
+    /// The input read in this format, normalized to ISO 8601 / RFC 3339.
+    pub interpretation: String,
+    /// Why this candidate was suggested and what to watch out for.
+    pub notes: String,
+}
+
+/// Inspect a timestamp string and list the formats it could be written in,
+/// most likely first.
+///
+/// Returns an empty list when no known format matches.
+pub fn detect_format(input: &str) -> Vec<FormatCandidate> {
+    let input = input.trim();
+    let mut candidates = Vec::new();
+
+    if let Some(value) = EpochValue::parse(input) {
+        detect_numeric(input, value, &mut candidates);
+    } else if let Some(candidate) = detect_iso(input) {
+        candidates.push(candidate);
+    } else if let Ok(zdt) = jiff::fmt::rfc2822::parse(input) {
+        candidates.push(FormatCandidate {
+            format: "rfc2822",
+            description: "RFC 2822 / HTTP date (e.g., 'Tue, 1 Jul 2025 10:52:37 +0200')",
+            confidence: Confidence::High,
+            interpretation: zdt.timestamp().to_string(),
+            notes: "Common in email headers and HTTP. Normalized to UTC.".into(),
+        });
+    } else if let Some(numeric) = split_numeric_date(input) {
+        detect_numeric_date(&numeric, &mut candidates);
+    }
+
+    // Stable sort keeps the detection order among equally confident candidates.
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.confidence));
+    candidates
+}
+
+/// Candidates for an input consisting only of digits (and optionally a
+/// sign or fractional part): epoch values and compact ISO dates.
+fn detect_numeric(input: &str, value: EpochValue, candidates: &mut Vec<FormatCandidate>) {
+    let inference = value.infer_unit();
+    let compact_date = compact_date(input);
+
+    if let Ok(ts) = value.to_timestamp(inference.unit) {
+        let (confidence, notes) = if inference.ambiguous {
+            (
+                Confidence::Low,
+                format!(
+                    "No epoch unit places this value between 1971 and 2100; {} is a guess based on digit count.",
+                    inference.unit.as_str()
+                ),
+            )
+        } else {
+            let mut notes = format!(
+                "Magnitude only makes sense as {} between 1971 and 2100.",
+                inference.unit.as_str()
+            );
+            if input.starts_with('-') {
+                notes.push_str(" Negative values are instants before 1970.");
+            }
+            (Confidence::High, notes)
+        };
+        let (format, description) = epoch_format(inference.unit);
+        candidates.push(FormatCandidate {
+            format,
+            description,
+            confidence: if compact_date.is_some() {
+                confidence.lower()
+            } else {
+                confidence
+            },
+            interpretation: ts.to_string(),
+            notes,
+        });
+    }
+
+    if let Some((format, description, interpretation)) = compact_date {
+        candidates.push(FormatCandidate {
+            format,
+            description,
+            confidence: if inference.ambiguous {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            },
+            interpretation,
+            notes: "Digits form a valid calendar date; also readable as an epoch value.".into(),
+        });
+    }
+}
+
+/// Format identifier and description for an epoch unit.
+fn epoch_format(unit: EpochUnit) -> (&'static str, &'static str) {
+    match unit {
+        EpochUnit::Seconds => ("epoch_seconds", "Unix epoch seconds"),
+        EpochUnit::Milliseconds => ("epoch_milliseconds", "Unix epoch milliseconds"),
+        EpochUnit::Microseconds => ("epoch_microseconds", "Unix epoch microseconds"),
+        EpochUnit::Nanoseconds => ("epoch_nanoseconds", "Unix epoch nanoseconds"),
+    }
+}
+
+/// Read an 8- or 14-digit string as a compact ISO 8601 date (`YYYYMMDD`) or
+/// datetime (`YYYYMMDDHHMMSS`).
+fn compact_date(input: &str) -> Option<(&'static str, &'static str, String)> {
+    match input.len() {
+        8 => jiff::civil::Date::strptime("%Y%m%d", input).ok().map(|d| {
+            (
+                "iso8601_basic_date",
+                "Compact ISO 8601 date (YYYYMMDD)",
+                d.to_string(),
+            )
+        }),
+        14 => jiff::civil::DateTime::strptime("%Y%m%d%H%M%S", input)
+            .ok()
+            .map(|dt| {
+                (
+                    "compact_datetime",
+                    "Compact date-time (YYYYMMDDHHMMSS)",
+                    dt.to_string(),
+                )
+            }),
+        _ => None,
+    }
+}
+
+/// Recognize ISO 8601 / RFC 3339 / RFC 9557 variants.
+fn detect_iso(input: &str) -> Option<FormatCandidate> {
+    let has_date = input.len() >= 8 && input.as_bytes()[..4].iter().all(u8::is_ascii_digit);
+    let has_time = input.contains(':') || input.contains('T');
+
+    if input.contains('[') {
+        if let Ok(zdt) = input.parse::<jiff::Zoned>() {
+            return Some(FormatCandidate {
+                format: "rfc9557",
+                description: "RFC 9557 date-time with offset and bracketed IANA zone",
+                confidence: Confidence::High,
+                interpretation: zdt.to_string(),
+                notes: "Fully pins both the instant and the timezone.".into(),
+            });
+        }
+    }
+
+    if has_date && has_time {
+        if let Ok(ts) = input.parse::<jiff::Timestamp>() {
+            let notes = if input.ends_with('Z') || input.ends_with('z') {
+                "Uses 'Z' for UTC. Pins an exact instant."
+            } else {
+                "Includes a UTC offset, which pins the instant but not the timezone's DST rules. Normalized to UTC."
+            };
+            return Some(FormatCandidate {
+                format: "rfc3339",
+                description: "ISO 8601 / RFC 3339 date-time with UTC offset",
+                confidence: Confidence::High,
+                interpretation: ts.to_string(),
+                notes: notes.into(),
+            });
+        }
+        if let Ok(dt) = input.parse::<jiff::civil::DateTime>() {
+            return Some(FormatCandidate {
+                format: "iso8601_local_datetime",
+                description: "ISO 8601 date-time without offset",
+                confidence: Confidence::High,
+                interpretation: dt.to_string(),
+                notes: "No UTC offset or zone: a timezone must be supplied to pin an instant."
+                    .into(),
+            });
+        }
+    }
+
+    if input.contains('W') {
+        if let Ok(week_date) = input.parse::<jiff::civil::ISOWeekDate>() {
+            return Some(FormatCandidate {
+                format: "iso8601_week_date",
+                description: "ISO 8601 week date (YYYY-Www-D)",
+                confidence: Confidence::High,
+                interpretation: week_date.date().to_string(),
+                notes: "Week-numbering year can differ from the calendar year near January 1."
+                    .into(),
+            });
+        }
+    }
+
+    if has_date && !has_time {
+        if let Ok(date) = input.parse::<jiff::civil::Date>() {
+            return Some(FormatCandidate {
+                format: "iso8601_date",
+                description: "ISO 8601 calendar date (YYYY-MM-DD)",
+                confidence: Confidence::High,
+                interpretation: date.to_string(),
+                notes: "Date only; no time of day or timezone.".into(),
+            });
+        }
+    }
+
+    if !has_date && has_time {
+        if let Ok(time) = input.parse::<jiff::civil::Time>() {
+            return Some(FormatCandidate {
+                format: "time_of_day",
+                description: "24-hour time of day (HH:MM[:SS])",
+                confidence: Confidence::High,
+                interpretation: time.to_string(),
+                notes: "Time only; no date or timezone.".into(),
+            });
+        }
+    }
+
+    None
+}
+
+/// A date written as three numeric fields, e.g. `03/04/2025 14:30`.
+struct NumericDate<'a> {
+    fields: [&'a str; 3],
+    separator: char,
+    time: Option<jiff::civil::Time>,
+}
+
+/// Split `A<sep>B<sep>C[ HH:MM[:SS]]` where `<sep>` is `/`, `.`, or `-`.
+fn split_numeric_date(input: &str) -> Option<NumericDate<'_>> {
+    let (date_part, time) = match input.split_once(' ') {
+        Some((date, time)) => (date, Some(time.trim().parse::<jiff::civil::Time>().ok()?)),
+        None => (input, None),
+    };
+    let separator = ['/', '.', '-']
+        .into_iter()
+        .find(|&sep| date_part.contains(sep))?;
+    let mut parts = date_part.split(separator);
+    let fields = [parts.next()?, parts.next()?, parts.next()?];
+    if parts.next().is_some()
+        || fields
+            .iter()
+            .any(|f| f.is_empty() || f.len() > 4 || !f.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return None;
+    }
+    Some(NumericDate {
+        fields,
+        separator,
+        time,
+    })
+}
+
+/// The year for a 2- or 4-digit year field, with a note for 2-digit years.
+fn expand_year(field: &str) -> Option<(i16, Option<&'static str>)> {
+    let value: i16 = field.parse().ok()?;
+    match field.len() {
+        4 => Some((value, None)),
+        2 if value < 70 => Some((2000 + value, Some(TWO_DIGIT_YEAR_NOTE))),
+        2 => Some((1900 + value, Some(TWO_DIGIT_YEAR_NOTE))),
+        _ => None,
+    }
+}
+
+/// Note attached to candidates read with a two-digit year.
+const TWO_DIGIT_YEAR_NOTE: &str =
+    "Two-digit year assumed to be 1970-2069; verify the century with the data source.";
+
+/// Candidates for day/month/year orderings of a numeric date.
+fn detect_numeric_date(date: &NumericDate<'_>, candidates: &mut Vec<FormatCandidate>) {
+    let [a, b, c] = date.fields;
+    let render = |year: i16, month: &str, day: &str| -> Option<String> {
+        let d = jiff::civil::Date::new(year, month.parse().ok()?, day.parse().ok()?).ok()?;
+        Some(match date.time {
+            Some(t) => d.to_datetime(t).to_string(),
+            None => d.to_string(),
+        })
+    };
+
+    // Year first (YYYY/MM/DD) is unambiguous in practice.
+    if a.len() == 4 {
+        if let Some(interpretation) = a.parse().ok().and_then(|year| render(year, b, c)) {
+            candidates.push(FormatCandidate {
+                format: "ymd_date",
+                description: "Year-month-day date (YYYY/MM/DD)",
+                confidence: Confidence::High,
+                interpretation,
+                notes: "Year-first ordering is only used as year-month-day.".into(),
+            });
+        }
+        return;
+    }
+
+    let Some((year, year_note)) = expand_year(c) else {
+        return;
+    };
+    let us = render(year, a, b);
+    let eu = render(year, b, a);
+
+    let (us_confidence, eu_confidence, notes) = match (&us, &eu) {
+        (Some(_), None) => (
+            Confidence::High,
+            Confidence::Low,
+            "Only month-first ordering gives a valid date.",
+        ),
+        (None, Some(_)) => (
+            Confidence::Low,
+            Confidence::High,
+            "Only day-first ordering gives a valid date.",
+        ),
+        (Some(u), Some(e)) if u == e => (
+            Confidence::High,
+            Confidence::High,
+            "Day and month are equal, so both orderings give the same date.",
+        ),
+        (Some(_), Some(_)) if date.separator == '/' => (
+            Confidence::Medium,
+            Confidence::Medium,
+            "Both orderings give a valid date; check other values in the feed for a field above 12.",
+        ),
+        // '.' and '-' separators are overwhelmingly day-first in practice.
+        (Some(_), Some(_)) => (
+            Confidence::Low,
+            Confidence::Medium,
+            "Both orderings give a valid date; this separator is conventionally day-first.",
+        ),
+        (None, None) => return,
+    };
+    let notes = match year_note {
+        Some(year_note) => format!("{notes} {year_note}"),
+        None => notes.to_string(),
+    };
+
+    if let Some(interpretation) = us {
+        candidates.push(FormatCandidate {
+            format: "us_date",
+            description: "US month-first date (MM/DD/YYYY)",
+            confidence: us_confidence,
+            interpretation,
+            notes: notes.clone(),
+        });
+    }
+    if let Some(interpretation) = eu {
+        candidates.push(FormatCandidate {
+            format: "eu_date",
+            description: "European day-first date (DD/MM/YYYY)",
+            confidence: eu_confidence,
+            interpretation,
+            notes,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn formats(input: &str) -> Vec<&'static str> {
+        detect_format(input).iter().map(|c| c.format).collect()
+    }
+
+    #[test]
+    fn detect_format_recognizes_epoch_seconds() {
+        let candidates = detect_format("1700000000");
+        assert_eq!(candidates[0].format, "epoch_seconds");
+        assert_eq!(candidates[0].confidence, Confidence::High);
+        assert_eq!(candidates[0].interpretation, "2023-11-14T22:13:20Z");
+    }
+
+    #[test]
+    fn detect_format_recognizes_epoch_milliseconds() {
+        let candidates = detect_format("1700000000000");
+        assert_eq!(candidates[0].format, "epoch_milliseconds");
+        assert_eq!(candidates[0].confidence, Confidence::High);
+    }
+
+    #[test]
+    fn detect_format_prefers_compact_date_over_implausible_epoch() {
+        let candidates = detect_format("20250704");
+        assert_eq!(candidates[0].format, "iso8601_basic_date");
+        assert_eq!(candidates[0].interpretation, "2025-07-04");
+        assert_eq!(candidates[1].format, "epoch_seconds");
+        assert_eq!(candidates[1].confidence, Confidence::Low);
+    }
+
+    #[test]
+    fn detect_format_recognizes_rfc3339() {
+        let candidates = detect_format("2025-07-04T12:00:00+02:00");
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].format, "rfc3339");
+        assert_eq!(candidates[0].interpretation, "2025-07-04T10:00:00Z");
+    }
+
+    #[test]
+    fn detect_format_recognizes_rfc9557_and_local_datetime() {
+        assert_eq!(
+            formats("2025-07-04T12:00:00+02:00[Europe/Paris]"),
+            vec!["rfc9557"]
+        );
+        assert_eq!(
+            formats("2025-07-04T12:00:00"),
+            vec!["iso8601_local_datetime"]
+        );
+        assert_eq!(formats("2025-07-04"), vec!["iso8601_date"]);
+        assert_eq!(formats("2025-W27-5"), vec!["iso8601_week_date"]);
+        assert_eq!(formats("14:30"), vec!["time_of_day"]);
+    }
+
+    #[test]
+    fn detect_format_recognizes_rfc2822() {
+        assert_eq!(formats("Fri, 4 Jul 2025 12:00:00 +0200"), vec!["rfc2822"]);
+    }
+
+    #[test]
+    fn detect_format_reports_both_orderings_for_ambiguous_slash_date() {
+        let candidates = detect_format("03/04/2025");
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].format, "us_date");
+        assert_eq!(candidates[0].interpretation, "2025-03-04");
+        assert_eq!(candidates[0].confidence, Confidence::Medium);
+        assert_eq!(candidates[1].format, "eu_date");
+        assert_eq!(candidates[1].interpretation, "2025-04-03");
+    }
+
+    #[test]
+    fn detect_format_resolves_day_above_twelve() {
+        let candidates = detect_format("25/12/2025 08:15");
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].format, "eu_date");
+        assert_eq!(candidates[0].confidence, Confidence::High);
+        assert_eq!(candidates[0].interpretation, "2025-12-25T08:15:00");
+    }
+
+    #[test]
+    fn detect_format_favours_day_first_for_dotted_dates() {
+        let candidates = detect_format("03.04.25");
+        assert_eq!(candidates[0].format, "eu_date");
+        assert_eq!(candidates[0].interpretation, "2025-04-03");
+        assert!(candidates[0].notes.contains("Two-digit year"));
+    }
+
+    #[test]
+    fn detect_format_returns_empty_for_unrecognized_input() {
+        assert!(detect_format("next tuesday").is_empty());
+        assert!(detect_format("").is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+/// Environment variable selecting the language of zone display names.
+pub const LANGUAGE_ENV: &str = "MCP_TIME_LANGUAGE";
+
+/// Languages with localized zone display names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    German,
+    Spanish,
+    French,
+}
+
+impl Language {
+    /// Parse a language tag such as "de", "es-MX", or "fr_CA".
+    ///
+    /// Only the primary subtag is used.
+    pub fn parse(tag: &str) -> Result<Language, String> {
+        let primary = tag
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match primary.as_str() {
+            "de" => Ok(Language::German),
+            "es" => Ok(Language::Spanish),
+            "fr" => Ok(Language::French),
+            _ => Err(format!(
+                "Unsupported language: '{tag}'. Expected one of 'de', 'es', or 'fr'."
+            )),
+        }
+    }
+}
+
+/// Exemplar city names from CLDR for zones whose name differs in at least
+/// one supported language: (zone, German, Spanish, French).
+const EXONYMS: &[(&str, &str, &str, &str)] = &[
+    ("Africa/Cairo", "Kairo", "El Cairo", "Le Caire"),
+    ("America/Havana", "Havanna", "La Habana", "La Havane"),
+    (
+        "America/Los_Angeles",
+        "Los Angeles",
+        "Los Ángeles",
+        "Los Angeles",
+    ),
+    (
+        "America/Mexico_City",
+        "Mexiko-Stadt",
+        "Ciudad de México",
+        "Mexico",
+    ),
+    ("America/New_York", "New York", "Nueva York", "New York"),
+    ("America/Sao_Paulo", "São Paulo", "São Paulo", "São Paulo"),
+    ("Asia/Jerusalem", "Jerusalem", "Jerusalén", "Jérusalem"),
+    ("Asia/Shanghai", "Shanghai", "Shanghái", "Shanghai"),
+    ("Asia/Singapore", "Singapur", "Singapur", "Singapour"),
+    ("Asia/Tokyo", "Tokio", "Tokio", "Tokyo"),
+    ("Europe/Athens", "Athen", "Atenas", "Athènes"),
+    ("Europe/Belgrade", "Belgrad", "Belgrado", "Belgrade"),
+    ("Europe/Berlin", "Berlin", "Berlín", "Berlin"),
+    ("Europe/Brussels", "Brüssel", "Bruselas", "Bruxelles"),
+    ("Europe/Bucharest", "Bukarest", "Bucarest", "Bucarest"),
+    (
+        "Europe/Copenhagen",
+        "Kopenhagen",
+        "Copenhague",
+        "Copenhague",
+    ),
+    ("Europe/Istanbul", "Istanbul", "Estambul", "Istanbul"),
+    ("Europe/Lisbon", "Lissabon", "Lisboa", "Lisbonne"),
+    ("Europe/London", "London", "Londres", "Londres"),
+    ("Europe/Moscow", "Moskau", "Moscú", "Moscou"),
+    ("Europe/Paris", "Paris", "París", "Paris"),
+    ("Europe/Prague", "Prag", "Praga", "Prague"),
+    ("Europe/Rome", "Rom", "Roma", "Rome"),
+    ("Europe/Vienna", "Wien", "Viena", "Vienne"),
+    ("Europe/Warsaw", "Warschau", "Varsovia", "Varsovie"),
+    ("Europe/Zurich", "Zürich", "Zúrich", "Zurich"),
+];
+
+/// Every localized city name with the zone it stands for.
+pub fn exonyms() -> impl Iterator<Item = (&'static str, &'static str)> {
+    EXONYMS
+        .iter()
+        .flat_map(|&(zone, de, es, fr)| [(de, zone), (es, zone), (fr, zone)])
+}
+
+/// Display name of `zone` in `language`.
+///
+/// Zones without a localized name fall back to their city as written in the
+/// zone name (e.g., "Asia/Kolkata" → "Kolkata").
+pub fn display_name(zone: &str, language: Language) -> String {
+    let localized = EXONYMS
+        .iter()
+        .find(|entry| entry.0 == zone)
+        .map(|entry| match language {
+            Language::German => entry.1,
+            Language::Spanish => entry.2,
+            Language::French => entry.3,
+        });
+    match localized {
+        Some(name) => name.to_string(),
+        None => zone.rsplit('/').next().unwrap_or(zone).replace('_', " "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_parse_reads_primary_subtag() {
+        assert_eq!(Language::parse("es-MX"), Ok(Language::Spanish));
+        assert_eq!(Language::parse("DE"), Ok(Language::German));
+        assert_eq!(Language::parse("fr_CA"), Ok(Language::French));
+        assert!(Language::parse("xx").is_err());
+    }
+
+    #[test]
+    fn display_name_localizes_known_zones() {
+        assert_eq!(
+            display_name("America/New_York", Language::Spanish),
+            "Nueva York"
+        );
+        assert_eq!(display_name("Europe/Moscow", Language::German), "Moskau");
+        assert_eq!(display_name("Asia/Kolkata", Language::French), "Kolkata");
+    }
+
+    #[test]
+    fn exonyms_resolve_to_real_zones() {
+        for (name, zone) in exonyms() {
+            assert!(
+                jiff::tz::TimeZone::get(zone).is_ok(),
+                "{name} maps to unknown zone {zone}"
+            );
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use jiff::tz::AmbiguousOffset;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::core::format_utc_offset;
+
+/// Why a local timestamp was flagged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DstIssue {
+    /// The local time was skipped by a spring-forward gap.
+    Nonexistent,
+    /// The local time occurs twice because of a fall-back overlap.
+    Ambiguous,
+    /// The input is not a local datetime.
+    Invalid,
+}
+
+/// One way to turn a flagged local time into a concrete instant.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct Resolution {
+    /// Resolution strategy (`shift_forward`, `next_valid`, `earlier`, `later`).
+    pub strategy: &'static str,
+    /// RFC 3339 datetime with offset that the strategy produces.
+    pub datetime: String,
+    pub description: &'static str,
+}
+
+/// A local timestamp that needs attention.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DstFinding {
+    /// Zero-based position in the input list.
+    pub index: usize,
+    pub input: String,
+    pub issue: DstIssue,
+    pub message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<Resolution>,
+}
+
+/// Findings from scanning a list of local timestamps.
+#[derive(Debug)]
+pub struct DstAudit {
+    /// Number of inputs that map to exactly one instant.
+    pub ok_count: usize,
+    pub findings: Vec<DstFinding>,
+}
+
+/// Scan local (offset-free) datetimes in `tz` for values that fall into a
+/// DST gap or fold, suggesting how each could be resolved.
+pub fn audit(inputs: &[String], tz: &jiff::tz::TimeZone) -> DstAudit {
+    let mut ok_count = 0;
+    let mut findings = Vec::new();
+
+    for (index, input) in inputs.iter().enumerate() {
+        let Ok(dt) = input.trim().parse::<jiff::civil::DateTime>() else {
+            findings.push(DstFinding {
+                index,
+                input: input.clone(),
+                issue: DstIssue::Invalid,
+                message: "Expected a local datetime without offset (e.g., '2025-03-09T02:30:00')."
+                    .into(),
+                suggestions: Vec::new(),
+            });
+            continue;
+        };
+
+        match tz.to_ambiguous_zoned(dt).offset() {
+            AmbiguousOffset::Unambiguous { .. } => ok_count += 1,
+            AmbiguousOffset::Gap { before, after } => findings.push(DstFinding {
+                index,
+                input: input.clone(),
+                issue: DstIssue::Nonexistent,
+                message: format!(
+                    "{dt} does not exist: clocks jump from {} to {} here.",
+                    format_utc_offset(before),
+                    format_utc_offset(after)
+                ),
+                suggestions: gap_resolutions(dt, before, after, tz),
+            }),
+            AmbiguousOffset::Fold { before, after } => findings.push(DstFinding {
+                index,
+                input: input.clone(),
+                issue: DstIssue::Ambiguous,
+                message: format!(
+                    "{dt} occurs twice: once at {} and again at {}.",
+                    format_utc_offset(before),
+                    format_utc_offset(after)
+                ),
+                suggestions: fold_resolutions(dt, before, after, tz),
+            }),
+        }
+    }
+
+    DstAudit { ok_count, findings }
+}
+
+/// Suggested resolutions for a civil datetime inside a spring-forward gap.
+fn gap_resolutions(
+    dt: jiff::civil::DateTime,
+    before: jiff::tz::Offset,
+    after: jiff::tz::Offset,
+    tz: &jiff::tz::TimeZone,
+) -> Vec<Resolution> {
+    let mut suggestions = Vec::new();
+    // Reading the time with the pre-gap offset lands past the transition,
+    // which is the same as pushing the wall clock forward by the gap length.
+    if let Ok(ts) = before.to_timestamp(dt) {
+        suggestions.push(Resolution {
+            strategy: "shift_forward",
+            datetime: format_zoned(ts, tz),
+            description: "Move the wall clock forward by the length of the gap (the usual calendar-app behavior).",
+        });
+    }
+    // Reading it with the post-gap offset lands before the transition, so
+    // the next transition is the end of the gap.
+    if let Some(transition) = after
+        .to_timestamp(dt)
+        .ok()
+        .and_then(|ts| tz.following(ts).next())
+    {
+        suggestions.push(Resolution {
+            strategy: "next_valid",
+            datetime: format_zoned(transition.timestamp(), tz),
+            description: "Use the first valid local time after the gap.",
+        });
+    }
+    suggestions
+}
+
+/// Suggested resolutions for a civil datetime inside a fall-back fold.
+fn fold_resolutions(
+    dt: jiff::civil::DateTime,
+    before: jiff::tz::Offset,
+    after: jiff::tz::Offset,
+    tz: &jiff::tz::TimeZone,
+) -> Vec<Resolution> {
+    let mut suggestions = Vec::new();
+    if let Ok(ts) = before.to_timestamp(dt) {
+        suggestions.push(Resolution {
+            strategy: "earlier",
+            datetime: format_zoned(ts, tz),
+            description: "Use the first occurrence, before clocks fall back.",
+        });
+    }
+    if let Ok(ts) = after.to_timestamp(dt) {
+        suggestions.push(Resolution {
+            strategy: "later",
+            datetime: format_zoned(ts, tz),
+            description: "Use the second occurrence, after clocks fall back.",
+        });
+    }
+    suggestions
+}
+
+/// Render an instant in `tz` as RFC 3339 with offset.
+fn format_zoned(ts: jiff::Timestamp, tz: &jiff::tz::TimeZone) -> String {
+    ts.to_zoned(tz.clone())
+        .strftime("%Y-%m-%dT%H:%M:%S%:z")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_york() -> jiff::tz::TimeZone {
+        jiff::tz::TimeZone::get("America/New_York").unwrap()
+    }
+
+    #[test]
+    fn audit_counts_unambiguous_times() {
+        let result = audit(&["2025-06-01T09:00:00".into()], &new_york());
+        assert_eq!(result.ok_count, 1);
+        assert!(result.findings.is_empty());
+    }
+
+    #[test]
+    fn audit_flags_spring_forward_gap() {
+        let result = audit(&["2025-03-09T02:30:00".into()], &new_york());
+        let finding = &result.findings[0];
+        assert_eq!(finding.issue, DstIssue::Nonexistent);
+        assert!(finding.message.contains("-05:00 to -04:00"));
+        assert_eq!(finding.suggestions[0].strategy, "shift_forward");
+        assert_eq!(finding.suggestions[0].datetime, "2025-03-09T03:30:00-04:00");
+        assert_eq!(finding.suggestions[1].strategy, "next_valid");
+        assert_eq!(finding.suggestions[1].datetime, "2025-03-09T03:00:00-04:00");
+    }
+
+    #[test]
+    fn audit_flags_fall_back_fold() {
+        let result = audit(&["2025-11-02 01:30".into()], &new_york());
+        let finding = &result.findings[0];
+        assert_eq!(finding.issue, DstIssue::Ambiguous);
+        assert_eq!(finding.suggestions[0].strategy, "earlier");
+        assert_eq!(finding.suggestions[0].datetime, "2025-11-02T01:30:00-04:00");
+        assert_eq!(finding.suggestions[1].strategy, "later");
+        assert_eq!(finding.suggestions[1].datetime, "2025-11-02T01:30:00-05:00");
+    }
+
+    #[test]
+    fn audit_flags_invalid_input() {
+        let result = audit(&["02:30".into(), "2025-13-01T00:00".into()], &new_york());
+        assert_eq!(result.findings.len(), 2);
+        assert!(
+            result
+                .findings
+                .iter()
+                .all(|f| f.issue == DstIssue::Invalid && f.suggestions.is_empty())
+        );
+    }
+}
@@ -0,0 +1,638 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+/// A unit a duration phrase can count in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+    /// A Monday-to-Friday day, which only has a length once anchored.
+    BusinessDay,
+}
+
+impl Unit {
+    /// The unit named by `word`, in full, plural, or abbreviated form
+    /// (e.g., "hours", "hr", "h").
+    pub fn parse(word: &str) -> Option<Unit> {
+        Some(match word {
+            "s" | "sec" | "secs" | "second" | "seconds" => Unit::Second,
+            "m" | "min" | "mins" | "minute" | "minutes" => Unit::Minute,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Unit::Hour,
+            "d" | "day" | "days" => Unit::Day,
+            "w" | "wk" | "wks" | "week" | "weeks" => Unit::Week,
+            "mo" | "month" | "months" => Unit::Month,
+            "y" | "yr" | "yrs" | "year" | "years" => Unit::Year,
+            "weekday" | "weekdays" | "bd" => Unit::BusinessDay,
+            _ => return None,
+        })
+    }
+}
+
+/// A fixed-length unit a duration can be balanced up to or rounded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SpanUnit {
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl From<SpanUnit> for jiff::Unit {
+    fn from(unit: SpanUnit) -> jiff::Unit {
+        match unit {
+            SpanUnit::Week => jiff::Unit::Week,
+            SpanUnit::Day => jiff::Unit::Day,
+            SpanUnit::Hour => jiff::Unit::Hour,
+            SpanUnit::Minute => jiff::Unit::Minute,
+            SpanUnit::Second => jiff::Unit::Second,
+            SpanUnit::Millisecond => jiff::Unit::Millisecond,
+            SpanUnit::Microsecond => jiff::Unit::Microsecond,
+            SpanUnit::Nanosecond => jiff::Unit::Nanosecond,
+        }
+    }
+}
+
+/// What a duration phrase is counted from, when it names a starting point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Anchor {
+    Now,
+    Today,
+    Tomorrow,
+    Yesterday,
+    /// The next such day, today included.
+    Weekday(jiff::civil::Weekday),
+    /// Any other starting point, left for the caller to parse (e.g.,
+    /// "2025-07-04T09:00").
+    Datetime(String),
+}
+
+/// A duration read from a phrase such as "an hour and a half" or
+/// "3 business days from Friday".
+#[derive(Debug, Clone)]
+pub struct Duration {
+    /// Calendar and clock units, always non-negative.
+    pub span: jiff::Span,
+    /// Monday-to-Friday days, counted after `span` is applied.
+    pub business_days: i64,
+    /// Starting point the phrase names, if any.
+    pub anchor: Option<Anchor>,
+    /// `true` when the duration counts backward ("ago", "before").
+    pub backward: bool,
+}
+
+impl Duration {
+    /// Total length in seconds, when the duration has one regardless of the
+    /// date: clock units only, without days, months, or years.
+    pub fn fixed_seconds(&self) -> Option<f64> {
+        let calendar = self.span.get_years() != 0
+            || self.span.get_months() != 0
+            || self.span.get_weeks() != 0
+            || self.span.get_days() != 0
+            || self.business_days != 0;
+        if calendar {
+            return None;
+        }
+        self.span
+            .total(jiff::Unit::Second)
+            .ok()
+            .map(|seconds| if self.backward { -seconds } else { seconds })
+    }
+
+    /// The instant this duration lands on when counted from `start`.
+    ///
+    /// Calendar units follow the civil calendar of `start`'s zone, so a day
+    /// across a DST change is 23 or 25 hours long.
+    pub fn apply(&self, start: &jiff::Zoned) -> Result<jiff::Zoned, String> {
+        let too_large = |e: jiff::Error| format!("Result is out of range: {e}");
+        let span = if self.backward {
+            self.span.negate()
+        } else {
+            self.span
+        };
+        let zdt = start.checked_add(span).map_err(too_large)?;
+        add_business_days(
+            &zdt,
+            if self.backward {
+                -self.business_days
+            } else {
+                self.business_days
+            },
+        )
+        .map_err(too_large)
+    }
+}
+
+/// Move `days` Monday-to-Friday days from `start`, keeping its time of day.
+///
+/// Counting from a weekend day counts from the adjacent Friday (forward) or
+/// Monday (backward), so "1 business day from Saturday" is Monday.
+pub fn add_business_days(start: &jiff::Zoned, days: i64) -> Result<jiff::Zoned, jiff::Error> {
+    use jiff::civil::Weekday;
+
+    if days == 0 {
+        return Ok(start.clone());
+    }
+    let step: i64 = days.signum();
+    let mut date = start.date();
+    // Normalize the start so that whole weeks keep the weekday.
+    while matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday) {
+        date = date.checked_add(jiff::Span::new().days(-step))?;
+    }
+    let weeks = days / 5;
+    date = date.checked_add(jiff::Span::new().try_weeks(weeks)?)?;
+    let mut remaining = (days % 5).abs();
+    while remaining > 0 {
+        date = date.checked_add(jiff::Span::new().days(step))?;
+        if !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday) {
+            remaining -= 1;
+        }
+    }
+    date.to_datetime(start.time())
+        .to_zoned(start.time_zone().clone())
+}
+
+/// `span` carried into units no larger than `largest` and rounded (half
+/// away from zero) to a whole number of `smallest`, so "5400s" becomes
+/// "1h 30m".
+///
+/// Days count as 24 hours and weeks as 7 days; months and years have no
+/// fixed length and are rejected.
+pub fn balance(
+    span: jiff::Span,
+    largest: SpanUnit,
+    smallest: SpanUnit,
+) -> Result<jiff::Span, String> {
+    if span.get_years() != 0 || span.get_months() != 0 {
+        return Err(
+            "Months and years have no fixed length; express the duration in weeks or smaller units."
+                .to_string(),
+        );
+    }
+    if jiff::Unit::from(largest) < jiff::Unit::from(smallest) {
+        return Err("The largest unit must not be smaller than the smallest unit.".to_string());
+    }
+    let options = jiff::SpanRound::new()
+        .largest(largest.into())
+        .smallest(smallest.into())
+        .mode(jiff::RoundMode::HalfExpand)
+        .days_are_24_hours();
+    span.round(options)
+        .map_err(|e| format!("Duration is out of range: {e}"))
+}
+
+/// Total length of a fixed-length span in `unit`, with days of 24 hours.
+pub fn total(span: &jiff::Span, unit: jiff::Unit) -> Result<f64, String> {
+    span.total((unit, jiff::SpanRelativeTo::days_are_24_hours()))
+        .map_err(|e| format!("Duration is out of range: {e}"))
+}
+
+/// Spelled-out form of `span` (e.g., "1 hour 30 minutes"); a negative span
+/// reads "... ago", as [`parse`] accepts it.
+pub fn spell(span: &jiff::Span) -> String {
+    use jiff::fmt::friendly::{Designator, Spacing, SpanPrinter};
+
+    SpanPrinter::new()
+        .designator(Designator::Verbose)
+        .spacing(Spacing::BetweenUnitsAndDesignators)
+        .span_to_string(span)
+}
+
+/// Value of a spelled-out number word.
+fn number_word(word: &str) -> Option<f64> {
+    const WORDS: &[&str] = &[
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: &[&str] = &[
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    if let Some(n) = WORDS.iter().position(|w| *w == word) {
+        return Some(n as f64);
+    }
+    if let Some(n) = TENS.iter().position(|w| *w == word) {
+        return Some((n as f64 + 2.0) * 10.0);
+    }
+    match word {
+        "couple" => Some(2.0),
+        "dozen" => Some(12.0),
+        "hundred" => Some(100.0),
+        _ => None,
+    }
+}
+
+/// The weekday named by `word`, in full or as its first three letters
+/// (e.g., "friday", "fri"). `word` must already be lowercase.
+pub fn weekday(word: &str) -> Option<jiff::civil::Weekday> {
+    use jiff::civil::Weekday::*;
+    const WEEKDAYS: &[(&str, jiff::civil::Weekday)] = &[
+        ("monday", Monday),
+        ("tuesday", Tuesday),
+        ("wednesday", Wednesday),
+        ("thursday", Thursday),
+        ("friday", Friday),
+        ("saturday", Saturday),
+        ("sunday", Sunday),
+    ];
+    WEEKDAYS
+        .iter()
+        .find(|(name, _)| word == *name || word == &name[..3])
+        .map(|&(_, day)| day)
+}
+
+/// Parse the starting point after "from", "after", or "before".
+fn parse_anchor(text: &str) -> Anchor {
+    let word = text.trim().to_ascii_lowercase();
+    match word.as_str() {
+        "now" => Anchor::Now,
+        "today" => Anchor::Today,
+        "tomorrow" => Anchor::Tomorrow,
+        "yesterday" => Anchor::Yesterday,
+        _ => weekday(&word).map_or_else(
+            || Anchor::Datetime(text.trim().to_string()),
+            Anchor::Weekday,
+        ),
+    }
+}
+
+/// Split "3days" and "1h30m" into separate number and unit words.
+fn split_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for raw in text.split(|c: char| c.is_whitespace() || c == ',') {
+        let mut word = String::new();
+        let mut prev_digit: Option<bool> = None;
+        for c in raw.chars() {
+            let digit = c.is_ascii_digit() || c == '.';
+            if prev_digit.is_some_and(|p| p != digit) && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.push(c);
+            prev_digit = Some(digit);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+/// Parse a duration phrase.
+///
+/// Understands numbers as digits or words ("3", "1.5", "twenty five", "a",
+/// "a couple of"), halves and quarters ("half an hour", "two and a half
+/// days", "an hour and a quarter"), compact forms ("1h30m"), and business
+/// days. A phrase can count from a starting point ("two weeks from
+/// Friday", "3 days before 2025-07-04"), from now ("in 2 hours", "2 hours
+/// from now"), or backward from now ("3 days ago"). ISO 8601 durations
+/// ("P3DT4H", "-PT90M") are accepted too, a leading minus counting backward.
+pub fn parse(input: &str) -> Result<Duration, String> {
+    let iso = input.trim();
+    if iso.trim_start_matches(['+', '-']).starts_with(['P', 'p']) {
+        let span: jiff::Span = iso
+            .parse()
+            .map_err(|e| format!("Invalid ISO 8601 duration '{iso}': {e}"))?;
+        return Ok(Duration {
+            span: span.abs(),
+            business_days: 0,
+            anchor: None,
+            backward: span.is_negative(),
+        });
+    }
+
+    // ASCII lowercasing keeps byte offsets, so the anchor can be sliced
+    // from the original input with its casing intact.
+    let text = input.trim().to_ascii_lowercase();
+    let mut anchor = None;
+    let mut backward = false;
+    let mut body = text.as_str();
+
+    if let Some(rest) = body.strip_prefix("in ") {
+        body = rest;
+        anchor = Some(Anchor::Now);
+    } else if let Some(rest) = body.strip_suffix(" ago") {
+        body = rest;
+        anchor = Some(Anchor::Now);
+        backward = true;
+    } else if let Some(rest) = body
+        .strip_suffix(" from now")
+        .or_else(|| body.strip_suffix(" later"))
+    {
+        body = rest;
+        anchor = Some(Anchor::Now);
+    } else {
+        for (keyword, back) in [(" from ", false), (" after ", false), (" before ", true)] {
+            if let Some(at) = text.rfind(keyword) {
+                body = &text[..at];
+                // Keep the original casing for datetimes like "2025-07-04T09:00".
+                anchor = Some(parse_anchor(&input.trim()[at + keyword.len()..]));
+                backward = back;
+                break;
+            }
+        }
+    }
+
+    let mut amounts: Vec<(Unit, f64)> = Vec::new();
+    // The number waiting for its unit, and whether it came from "a"/"an".
+    let mut pending: Option<(f64, bool)> = None;
+    let mut after_and = false;
+    let words = split_words(body);
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i].as_str();
+        i += 1;
+        let fraction = match word {
+            "half" => Some(0.5),
+            "quarter" => Some(0.25),
+            _ => None,
+        };
+        if let Some(fraction) = fraction {
+            pending = match pending {
+                // "two and a half hours"
+                Some((n, false)) => Some((n + fraction, false)),
+                // "an hour and a half"
+                Some((_, true)) if after_and && !amounts.is_empty() => {
+                    let (unit, _) = amounts[amounts.len() - 1];
+                    amounts.push((unit, fraction));
+                    None
+                }
+                // "half an hour", "a half day"
+                _ => Some((fraction, false)),
+            };
+            after_and = false;
+            continue;
+        }
+        match word {
+            "and" => {
+                after_and = true;
+                continue;
+            }
+            "of" => continue,
+            "a" | "an" => {
+                // "half an hour" keeps the half.
+                if pending.is_none() {
+                    pending = Some((1.0, true));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        let unit = match word {
+            "business" | "working" if words.get(i).is_some_and(|w| w.starts_with("day")) => {
+                i += 1;
+                Some(Unit::BusinessDay)
+            }
+            _ => Unit::parse(word),
+        };
+        if let Some(unit) = unit {
+            let (n, _) = pending
+                .take()
+                .ok_or_else(|| format!("Expected a number before '{word}'."))?;
+            amounts.push((unit, n));
+            after_and = false;
+            continue;
+        }
+        let number = word
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite() && *n >= 0.0)
+            .or_else(|| number_word(word));
+        let Some(number) = number else {
+            return Err(format!("Unrecognized word in duration: '{word}'."));
+        };
+        pending = match pending {
+            // "twenty five", "two hundred"
+            Some((tens, false)) if tens >= 20.0 && tens % 10.0 == 0.0 && number < 10.0 => {
+                Some((tens + number, false))
+            }
+            Some((n, false)) if number == 100.0 => Some((n * 100.0, false)),
+            // "a dozen", "a couple of"
+            Some((_, true)) | None => Some((number, false)),
+            Some(_) => return Err(format!("Unexpected number in duration: '{word}'.")),
+        };
+        after_and = false;
+    }
+    if let Some((n, _)) = pending {
+        return Err(format!("Missing unit after {n}."));
+    }
+    if amounts.is_empty() {
+        return Err(format!("No duration found in '{}'.", input.trim()));
+    }
+
+    let (span, business_days) = to_span(&amounts)?;
+    Ok(Duration {
+        span,
+        business_days,
+        anchor,
+        backward,
+    })
+}
+
+/// Combine amounts into a span and a business-day count.
+///
+/// Fractional weeks and days are carried into smaller units; fractional
+/// months, years, and business days have no fixed length and are rejected.
+fn to_span(amounts: &[(Unit, f64)]) -> Result<(jiff::Span, i64), String> {
+    let whole = |value: f64, name: &str| {
+        if value.fract() != 0.0 {
+            return Err(format!("Fractional {name} are not supported."));
+        }
+        if value > i64::MAX as f64 {
+            return Err("Duration is too large.".to_string());
+        }
+        Ok(value as i64)
+    };
+    let total = |unit| -> f64 {
+        amounts
+            .iter()
+            .filter(|(u, _)| *u == unit)
+            .map(|(_, n)| n)
+            .sum()
+    };
+    let years = whole(total(Unit::Year), "years")?;
+    let months = whole(total(Unit::Month), "months")?;
+    let business_days = whole(total(Unit::BusinessDay), "business days")?;
+    let weeks_value = total(Unit::Week);
+    let weeks = weeks_value.trunc();
+    let days_value = total(Unit::Day) + weeks_value.fract() * 7.0;
+    let days = days_value.trunc();
+    let seconds = total(Unit::Second)
+        + total(Unit::Minute) * 60.0
+        + total(Unit::Hour) * 3600.0
+        + days_value.fract() * 86_400.0;
+    let nanos = (seconds * 1e9).round();
+    if nanos > i64::MAX as f64 || weeks > i64::MAX as f64 || days > i64::MAX as f64 {
+        return Err("Duration is too large.".to_string());
+    }
+    let nanos = nanos as i64;
+    let too_large = |_| "Duration is too large.".to_string();
+    let span = jiff::Span::new()
+        .try_years(years)
+        .and_then(|s| s.try_months(months))
+        .and_then(|s| s.try_weeks(weeks as i64))
+        .and_then(|s| s.try_days(days as i64))
+        .and_then(|s| s.try_hours(nanos / 3_600_000_000_000))
+        .and_then(|s| s.try_minutes(nanos / 60_000_000_000 % 60))
+        .and_then(|s| s.try_seconds(nanos / 1_000_000_000 % 60))
+        .and_then(|s| s.try_nanoseconds(nanos % 1_000_000_000))
+        .map_err(too_large)?;
+    Ok((span, business_days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(input: &str) -> String {
+        parse(input).unwrap().span.to_string()
+    }
+
+    #[test]
+    fn parse_reads_spelled_and_compact_durations() {
+        assert_eq!(span("an hour and a half"), "PT1H30M");
+        assert_eq!(span("half an hour"), "PT30M");
+        assert_eq!(span("two and a half days"), "P2DT12H");
+        assert_eq!(span("an hour and a quarter"), "PT1H15M");
+        assert_eq!(span("twenty five minutes"), "PT25M");
+        assert_eq!(span("1h30m"), "PT1H30M");
+        assert_eq!(span("1.5 weeks"), "P1W3DT12H");
+        assert_eq!(span("a couple of months, 3 days"), "P2M3D");
+    }
+
+    #[test]
+    fn parse_reads_iso_8601_durations() {
+        assert_eq!(span("P3DT4H"), "P3DT4H");
+        assert_eq!(span("pt90m"), "PT90M");
+        let parsed = parse("-P1W").unwrap();
+        assert!(parsed.backward);
+        assert_eq!(parsed.span.to_string(), "P1W");
+        assert!(parse("P3X").unwrap_err().contains("ISO 8601"));
+    }
+
+    #[test]
+    fn parse_reads_business_days_and_anchors() {
+        let parsed = parse("3 business days").unwrap();
+        assert_eq!(parsed.business_days, 3);
+        assert_eq!(parsed.anchor, None);
+
+        let parsed = parse("two weeks from Friday").unwrap();
+        assert_eq!(parsed.span.get_weeks(), 2);
+        assert_eq!(
+            parsed.anchor,
+            Some(Anchor::Weekday(jiff::civil::Weekday::Friday))
+        );
+
+        let parsed = parse("3 days ago").unwrap();
+        assert!(parsed.backward);
+        assert_eq!(parsed.anchor, Some(Anchor::Now));
+
+        let parsed = parse("45 minutes before 2025-07-04T09:00").unwrap();
+        assert!(parsed.backward);
+        assert_eq!(
+            parsed.anchor,
+            Some(Anchor::Datetime("2025-07-04T09:00".into()))
+        );
+        assert_eq!(parse("in 2 hours").unwrap().anchor, Some(Anchor::Now));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_words_and_fractional_months() {
+        assert!(
+            parse("three fortnights")
+                .unwrap_err()
+                .contains("fortnights")
+        );
+        assert!(parse("1.5 months").unwrap_err().contains("Fractional"));
+        assert!(parse("5").unwrap_err().contains("Missing unit"));
+        assert!(parse("hours").unwrap_err().contains("Expected a number"));
+        assert!(parse("99999999999999999999 years").is_err());
+    }
+
+    #[test]
+    fn fixed_seconds_only_for_clock_units() {
+        assert_eq!(parse("90 minutes").unwrap().fixed_seconds(), Some(5400.0));
+        assert_eq!(parse("2 days").unwrap().fixed_seconds(), None);
+    }
+
+    #[test]
+    fn balance_carries_into_larger_units_and_rounds() {
+        let balanced = |input: &str, largest, smallest| {
+            balance(parse(input).unwrap().span, largest, smallest).map(|span| spell(&span))
+        };
+        assert_eq!(
+            balanced("5400s", SpanUnit::Day, SpanUnit::Nanosecond).unwrap(),
+            "1 hour 30 minutes"
+        );
+        assert_eq!(
+            balanced("1.5 days", SpanUnit::Day, SpanUnit::Second).unwrap(),
+            "1 day 12 hours"
+        );
+        assert_eq!(
+            balanced("2 days", SpanUnit::Hour, SpanUnit::Second).unwrap(),
+            "48 hours"
+        );
+        assert_eq!(
+            balanced("90.6 seconds", SpanUnit::Hour, SpanUnit::Second).unwrap(),
+            "1 minute 31 seconds"
+        );
+        assert!(balanced("1 month", SpanUnit::Day, SpanUnit::Second).is_err());
+        assert!(balanced("1h", SpanUnit::Second, SpanUnit::Hour).is_err());
+    }
+
+    #[test]
+    fn add_business_days_skips_weekends() {
+        let tz = jiff::tz::TimeZone::UTC;
+        let at = |d| {
+            jiff::civil::date(2025, 7, d)
+                .at(9, 0, 0, 0)
+                .to_zoned(tz.clone())
+                .unwrap()
+        };
+        // 2025-07-04 is a Friday, 2025-07-05 a Saturday.
+        assert_eq!(add_business_days(&at(4), 1).unwrap().date().day(), 7);
+        assert_eq!(add_business_days(&at(5), 1).unwrap().date().day(), 7);
+        assert_eq!(add_business_days(&at(4), 5).unwrap().date().day(), 11);
+        assert_eq!(add_business_days(&at(7), -1).unwrap().date().day(), 4);
+        assert_eq!(add_business_days(&at(5), -1).unwrap().date().day(), 4);
+    }
+
+    #[test]
+    fn apply_follows_the_civil_calendar() {
+        let tz = jiff::tz::TimeZone::get("America/New_York").unwrap();
+        let start = jiff::civil::date(2025, 3, 8)
+            .at(12, 0, 0, 0)
+            .to_zoned(tz)
+            .unwrap();
+        let end = parse("1 day").unwrap().apply(&start).unwrap();
+        assert_eq!(
+            end.to_string(),
+            "2025-03-09T12:00:00-04:00[America/New_York]"
+        );
+    }
+}
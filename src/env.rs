@@ -0,0 +1,32 @@
+/// Read a boolean flag from a raw environment value.
+///
+/// Unset and empty values are `false`.
+pub fn parse_flag(name: &str, value: Option<&str>) -> Result<bool, String> {
+    match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+        None | Some("" | "0" | "false" | "no" | "off") => Ok(false),
+        Some("1" | "true" | "yes" | "on") => Ok(true),
+        Some(other) => Err(format!(
+            "Invalid {name}: '{other}'. Expected 'true' or 'false'."
+        )),
+    }
+}
+
+/// Read the boolean flag environment variable `name`.
+pub fn flag(name: &str) -> Result<bool, String> {
+    parse_flag(name, std::env::var(name).ok().as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_flag_reads_booleans() {
+        assert_eq!(parse_flag("FLAG", None), Ok(false));
+        assert_eq!(parse_flag("FLAG", Some("TRUE")), Ok(true));
+        assert_eq!(parse_flag("FLAG", Some(" yes ")), Ok(true));
+        assert_eq!(parse_flag("FLAG", Some("0")), Ok(false));
+        let err = parse_flag("FLAG", Some("maybe")).unwrap_err();
+        assert!(err.contains("FLAG"), "Error was: {err}");
+    }
+}
@@ -0,0 +1,338 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Earliest plausible epoch value in seconds (1971-01-01T00:00:00Z).
+///
+/// Together with [`MAX_PLAUSIBLE_SECONDS`] this defines the window used to
+/// infer the unit of a bare epoch number. Scaled to each unit, the windows
+/// do not overlap, so at most one unit yields a plausible instant.
+const MIN_PLAUSIBLE_SECONDS: f64 = 31_536_000.0;
+
+/// Latest plausible epoch value in seconds (2100-01-01T00:00:00Z).
+const MAX_PLAUSIBLE_SECONDS: f64 = 4_102_444_800.0;
+
+/// Unit of a numeric Unix epoch value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EpochUnit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl EpochUnit {
+    /// All units, from coarsest to finest.
+    pub const ALL: [EpochUnit; 4] = [
+        EpochUnit::Seconds,
+        EpochUnit::Milliseconds,
+        EpochUnit::Microseconds,
+        EpochUnit::Nanoseconds,
+    ];
+
+    /// Number of nanoseconds in one unit.
+    pub fn nanos_per_unit(self) -> i128 {
+        match self {
+            EpochUnit::Seconds => 1_000_000_000,
+            EpochUnit::Milliseconds => 1_000_000,
+            EpochUnit::Microseconds => 1_000,
+            EpochUnit::Nanoseconds => 1,
+        }
+    }
+
+    /// Lowercase name of the unit as used in tool responses.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EpochUnit::Seconds => "seconds",
+            EpochUnit::Milliseconds => "milliseconds",
+            EpochUnit::Microseconds => "microseconds",
+            EpochUnit::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// Number of units in one second.
+    fn per_second(self) -> f64 {
+        (EpochUnit::Seconds.nanos_per_unit() / self.nanos_per_unit()) as f64
+    }
+}
+
+/// A numeric epoch value, kept as an integer where possible so that
+/// nanosecond values do not lose precision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EpochValue {
+    Integer(i64),
+    Fractional(f64),
+}
+
+/// Outcome of inferring an epoch value's unit from its magnitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitInference {
+    pub unit: EpochUnit,
+    /// `true` when no unit places the value between 1971 and 2100, so the
+    /// unit was picked from digit count alone.
+    pub ambiguous: bool,
+}
+
+impl EpochValue {
+    /// Parse a plain decimal number such as `1700000000` or `-12.5`.
+    ///
+    /// Exponents, separators, and surrounding text are rejected.
+    pub fn parse(input: &str) -> Option<Self> {
+        let digits = input.strip_prefix('-').unwrap_or(input);
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits, None),
+        };
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(whole) || fraction.is_some_and(|f| !is_digits(f)) {
+            return None;
+        }
+
+        match fraction {
+            None => input.parse().ok().map(EpochValue::Integer),
+            Some(_) => input.parse().ok().map(EpochValue::Fractional),
+        }
+    }
+
+    /// Convert a JSON number into an epoch value.
+    pub fn from_json(number: &serde_json::Number) -> Option<Self> {
+        match number.as_i64() {
+            Some(i) => Some(EpochValue::Integer(i)),
+            None => number.as_f64().map(EpochValue::Fractional),
+        }
+    }
+
+    /// Absolute magnitude of the value.
+    fn magnitude(self) -> f64 {
+        match self {
+            EpochValue::Integer(i) => i.unsigned_abs() as f64,
+            EpochValue::Fractional(f) => f.abs(),
+        }
+    }
+
+    /// Infer the most likely unit from the value's magnitude.
+    ///
+    /// A unit is chosen unambiguously when it is the only one that places
+    /// the value between 1971 and 2100. Otherwise the unit is guessed from
+    /// digit count (up to 11 digits seconds, 14 milliseconds, 17
+    /// microseconds, nanoseconds beyond) and flagged as ambiguous.
+    pub fn infer_unit(self) -> UnitInference {
+        let magnitude = self.magnitude();
+        let plausible = EpochUnit::ALL.into_iter().find(|unit| {
+            let seconds = magnitude / unit.per_second();
+            (MIN_PLAUSIBLE_SECONDS..=MAX_PLAUSIBLE_SECONDS).contains(&seconds)
+        });
+
+        match plausible {
+            Some(unit) => UnitInference {
+                unit,
+                ambiguous: false,
+            },
+            None => {
+                let unit = if magnitude < 1e11 {
+                    EpochUnit::Seconds
+                } else if magnitude < 1e14 {
+                    EpochUnit::Milliseconds
+                } else if magnitude < 1e17 {
+                    EpochUnit::Microseconds
+                } else {
+                    EpochUnit::Nanoseconds
+                };
+                UnitInference {
+                    unit,
+                    ambiguous: true,
+                }
+            }
+        }
+    }
+
+    /// Interpret the value in the given unit as an instant.
+    pub fn to_timestamp(self, unit: EpochUnit) -> Result<jiff::Timestamp, String> {
+        let nanos = match self {
+            EpochValue::Integer(i) => i128::from(i) * unit.nanos_per_unit(),
+            EpochValue::Fractional(f) => {
+                let nanos = f * unit.nanos_per_unit() as f64;
+                if !nanos.is_finite() || nanos.abs() > i128::MAX as f64 {
+                    return Err(out_of_range(unit));
+                }
+                nanos.round() as i128
+            }
+        };
+        // Checked here rather than left to `from_nanosecond`, which asserts
+        // on values far outside its range instead of returning an error.
+        let range = jiff::Timestamp::MIN.as_nanosecond()..=jiff::Timestamp::MAX.as_nanosecond();
+        if !range.contains(&nanos) {
+            return Err(out_of_range(unit));
+        }
+        jiff::Timestamp::from_nanosecond(nanos).map_err(|_| out_of_range(unit))
+    }
+}
+
+/// One entry of a bulk epoch normalization.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct NormalizedEpoch {
+    /// The value exactly as supplied.
+    pub input: serde_json::Number,
+    /// Unit the value was read in.
+    pub unit: EpochUnit,
+    /// RFC 3339 datetime in the requested timezone, absent on error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime: Option<String>,
+    /// `true` when the unit could not be inferred with confidence.
+    pub ambiguous: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Infer the unit of each value and render it as an RFC 3339 datetime in `tz`.
+///
+/// Failures are reported per entry so one bad value doesn't sink the batch.
+pub fn normalize(values: &[serde_json::Number], tz: &jiff::tz::TimeZone) -> Vec<NormalizedEpoch> {
+    values
+        .iter()
+        .map(|number| {
+            let Some(value) = EpochValue::from_json(number) else {
+                return NormalizedEpoch {
+                    input: number.clone(),
+                    unit: EpochUnit::Seconds,
+                    datetime: None,
+                    ambiguous: true,
+                    error: Some("Value is not a finite number.".into()),
+                };
+            };
+            let inference = value.infer_unit();
+            let (datetime, error) = match value.to_timestamp(inference.unit) {
+                Ok(ts) => (
+                    Some(
+                        ts.to_zoned(tz.clone())
+                            .strftime("%Y-%m-%dT%H:%M:%S%.f%:z")
+                            .to_string(),
+                    ),
+                    None,
+                ),
+                Err(msg) => (None, Some(msg)),
+            };
+            NormalizedEpoch {
+                input: number.clone(),
+                unit: inference.unit,
+                datetime,
+                ambiguous: inference.ambiguous,
+                error,
+            }
+        })
+        .collect()
+}
+
+/// Error message for epoch values outside the supported range.
+fn out_of_range(unit: EpochUnit) -> String {
+    format!(
+        "Epoch value is out of the supported range when read as {}.",
+        unit.as_str()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_integers_and_fractions() {
+        assert_eq!(
+            EpochValue::parse("1700000000"),
+            Some(EpochValue::Integer(1_700_000_000))
+        );
+        assert_eq!(
+            EpochValue::parse("-12.5"),
+            Some(EpochValue::Fractional(-12.5))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_non_numeric_input() {
+        assert_eq!(EpochValue::parse("1.7e9"), None);
+        assert_eq!(EpochValue::parse("12:30"), None);
+        assert_eq!(EpochValue::parse(""), None);
+        assert_eq!(EpochValue::parse("-"), None);
+    }
+
+    #[test]
+    fn infer_unit_detects_each_unit_from_magnitude() {
+        let cases = [
+            (1_700_000_000, EpochUnit::Seconds),
+            (1_700_000_000_000, EpochUnit::Milliseconds),
+            (1_700_000_000_000_000, EpochUnit::Microseconds),
+            (1_700_000_000_000_000_000, EpochUnit::Nanoseconds),
+        ];
+        for (value, expected) in cases {
+            let inference = EpochValue::Integer(value).infer_unit();
+            assert_eq!(inference.unit, expected, "value {value}");
+            assert!(!inference.ambiguous, "value {value}");
+        }
+    }
+
+    #[test]
+    fn infer_unit_flags_values_outside_plausible_window() {
+        let inference = EpochValue::Integer(1000).infer_unit();
+        assert_eq!(inference.unit, EpochUnit::Seconds);
+        assert!(inference.ambiguous);
+
+        // Year ~2286 in seconds, or early 1970 in milliseconds
+        let inference = EpochValue::Integer(10_000_000_000).infer_unit();
+        assert!(inference.ambiguous);
+    }
+
+    #[test]
+    fn to_timestamp_converts_integer_milliseconds() {
+        let ts = EpochValue::Integer(1_700_000_000_123)
+            .to_timestamp(EpochUnit::Milliseconds)
+            .unwrap();
+        assert_eq!(ts.to_string(), "2023-11-14T22:13:20.123Z");
+    }
+
+    #[test]
+    fn normalize_infers_unit_per_value() {
+        let values: Vec<serde_json::Number> =
+            serde_json::from_str("[1700000000, 1700000000123, 1700000000.5, 42]").unwrap();
+        let tz = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+        let results = normalize(&values, &tz);
+
+        assert_eq!(results[0].unit, EpochUnit::Seconds);
+        assert_eq!(
+            results[0].datetime.as_deref(),
+            Some("2023-11-15T07:13:20+09:00")
+        );
+        assert_eq!(results[1].unit, EpochUnit::Milliseconds);
+        assert_eq!(
+            results[1].datetime.as_deref(),
+            Some("2023-11-15T07:13:20.123+09:00")
+        );
+        assert_eq!(
+            results[2].datetime.as_deref(),
+            Some("2023-11-15T07:13:20.5+09:00")
+        );
+        assert!(!results[0].ambiguous && !results[1].ambiguous && !results[2].ambiguous);
+        assert!(results[3].ambiguous);
+    }
+
+    #[test]
+    fn normalize_reports_out_of_range_values_per_entry() {
+        let values: Vec<serde_json::Number> = serde_json::from_str("[1e30, 1700000000]").unwrap();
+        let results = normalize(&values, &jiff::tz::TimeZone::UTC);
+        assert!(results[0].datetime.is_none());
+        assert!(results[0].error.is_some());
+        assert!(results[1].error.is_none());
+    }
+
+    #[test]
+    fn to_timestamp_rejects_out_of_range_values() {
+        let result = EpochValue::Integer(i64::MAX).to_timestamp(EpochUnit::Seconds);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn to_timestamp_rejects_fractions_far_outside_the_range() {
+        let value = EpochValue::parse("10002202777777777777777444440.2201").unwrap();
+        assert!(value.to_timestamp(EpochUnit::Nanoseconds).is_err());
+        assert!(value.to_timestamp(EpochUnit::Seconds).is_err());
+    }
+}
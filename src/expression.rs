@@ -0,0 +1,250 @@
+use crate::duration::{self, Anchor, Duration};
+
+/// Summary of the grammar, included in parse errors.
+pub const GRAMMAR: &str = "Expected: <start> [+ <duration> | - <duration> | in <zone> | to <zone>]... [- <start>], where <start> is 'now', 'today', 'tomorrow', 'yesterday', or a datetime optionally followed by an IANA zone (e.g., '2025-07-01T09:00 Europe/Paris'), and <duration> is like '3 days', '45m', or '1h30m'.";
+
+/// A datetime operand, with the zone written after it, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatetimeOperand {
+    pub value: String,
+    pub zone: Option<String>,
+}
+
+/// Where an expression starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Start {
+    /// `now`, `today`, `tomorrow`, or `yesterday`.
+    Anchor(Anchor),
+    Datetime(DatetimeOperand),
+}
+
+/// One operation applied to the running value, left to right.
+#[derive(Debug, Clone)]
+pub enum Step {
+    /// `+ <duration>`, with the duration as written.
+    Add(Duration, String),
+    /// `- <duration>`, with the duration as written.
+    Subtract(Duration, String),
+    /// `in <zone>` or `to <zone>`: the same instant in another zone.
+    Convert(String),
+    /// `- <start>`: the time between the two, ending the expression.
+    Difference(Start),
+}
+
+/// A parsed time expression.
+#[derive(Debug, Clone)]
+pub struct Expression {
+    pub start: Start,
+    pub steps: Vec<Step>,
+}
+
+fn is_operator(token: &str) -> bool {
+    token == "+" || token == "-"
+}
+
+fn is_keyword(token: &str) -> bool {
+    token.eq_ignore_ascii_case("in") || token.eq_ignore_ascii_case("to")
+}
+
+/// The anchor a start token names, if any.
+fn anchor(token: &str) -> Option<Anchor> {
+    match token.to_ascii_lowercase().as_str() {
+        "now" => Some(Anchor::Now),
+        "today" => Some(Anchor::Today),
+        "tomorrow" => Some(Anchor::Tomorrow),
+        "yesterday" => Some(Anchor::Yesterday),
+        _ => None,
+    }
+}
+
+/// Split on whitespace, separating operators glued to a duration ("+45m").
+fn tokenize(input: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    for token in input.split_whitespace() {
+        match token.split_at_checked(1) {
+            Some((op @ ("+" | "-"), rest)) if rest.starts_with(|c: char| c.is_ascii_digit()) => {
+                tokens.push(op);
+                tokens.push(rest);
+            }
+            _ => tokens.push(token),
+        }
+    }
+    tokens
+}
+
+/// Read a datetime operand starting at `tokens[0]`, returning it and the
+/// number of tokens used.
+///
+/// A time after a date ("2025-07-01 09:00") joins it, and a following token
+/// that is not an operator or keyword is taken as its zone.
+fn datetime_operand(tokens: &[&str]) -> (DatetimeOperand, usize) {
+    let mut value = tokens[0].to_string();
+    let mut used = 1;
+    let looks_like_time = |t: &str| t.contains(':') && t.starts_with(|c: char| c.is_ascii_digit());
+    if !value.contains(['T', 't'])
+        && let Some(time) = tokens.get(1).filter(|t| looks_like_time(t))
+    {
+        value = format!("{value}T{time}");
+        used += 1;
+    }
+    let zone = tokens
+        .get(used)
+        .filter(|t| !is_operator(t) && !is_keyword(t))
+        .map(|t| t.to_string());
+    if zone.is_some() {
+        used += 1;
+    }
+    (DatetimeOperand { value, zone }, used)
+}
+
+/// Parse a time expression such as "now + 3 days - 2 hours in Asia/Tokyo"
+/// or "2025-07-01T09:00 Europe/Paris to America/Chicago + 45m".
+///
+/// Steps apply left to right; see [`GRAMMAR`].
+pub fn parse(input: &str) -> Result<Expression, String> {
+    let tokens = tokenize(input);
+    let error = |message: String| format!("{message} {GRAMMAR}");
+    let Some(first) = tokens.first() else {
+        return Err(error("Empty expression.".into()));
+    };
+
+    let (start, mut i) = match anchor(first) {
+        Some(anchor) => (Start::Anchor(anchor), 1),
+        None if is_operator(first) || is_keyword(first) => {
+            return Err(error(format!("Expected a starting time, found '{first}'.")));
+        }
+        None => {
+            let (operand, used) = datetime_operand(&tokens);
+            (Start::Datetime(operand), used)
+        }
+    };
+    let difference_must_end = |rest: &[&str]| match rest.first() {
+        Some(next) => Err(error(format!(
+            "A difference between datetimes must end the expression; found '{next}'."
+        ))),
+        None => Ok(()),
+    };
+
+    let mut steps = Vec::new();
+    while i < tokens.len() {
+        let token = tokens[i];
+        i += 1;
+        if is_keyword(token) {
+            let zone = tokens
+                .get(i)
+                .filter(|t| !is_operator(t) && !is_keyword(t))
+                .ok_or_else(|| error(format!("Expected a timezone after '{token}'.")))?;
+            steps.push(Step::Convert(zone.to_string()));
+            i += 1;
+            continue;
+        }
+        if !is_operator(token) {
+            return Err(error(format!("Unexpected '{token}'.")));
+        }
+        let end = tokens[i..]
+            .iter()
+            .position(|t| is_operator(t) || is_keyword(t))
+            .map_or(tokens.len(), |n| i + n);
+        if end == i {
+            return Err(error(format!("Expected a duration after '{token}'.")));
+        }
+        if token == "-"
+            && let Some(anchor) = anchor(tokens[i])
+        {
+            difference_must_end(&tokens[i + 1..])?;
+            steps.push(Step::Difference(Start::Anchor(anchor)));
+            break;
+        }
+        let text = tokens[i..end].join(" ");
+        match duration::parse(&text) {
+            Ok(parsed) if parsed.anchor.is_some() => {
+                return Err(error(format!(
+                    "Durations in expressions cannot name a starting point: '{text}'."
+                )));
+            }
+            Ok(parsed) if token == "+" => steps.push(Step::Add(parsed, text)),
+            Ok(parsed) => steps.push(Step::Subtract(parsed, text)),
+            Err(msg) if token == "+" => return Err(error(msg)),
+            Err(_) => {
+                let (operand, used) = datetime_operand(&tokens[i..]);
+                difference_must_end(&tokens[i + used..])?;
+                steps.push(Step::Difference(Start::Datetime(operand)));
+                break;
+            }
+        }
+        i = end;
+    }
+    Ok(Expression { start, steps })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(step: &Step) -> String {
+        match step {
+            Step::Add(_, text) => format!("+{text}"),
+            Step::Subtract(_, text) => format!("-{text}"),
+            Step::Convert(zone) => format!("in {zone}"),
+            Step::Difference(Start::Anchor(anchor)) => format!("since {anchor:?}"),
+            Step::Difference(Start::Datetime(operand)) => format!("since {}", operand.value),
+        }
+    }
+
+    fn steps(input: &str) -> Vec<String> {
+        parse(input).unwrap().steps.iter().map(describe).collect()
+    }
+
+    #[test]
+    fn parse_reads_arithmetic_and_conversions() {
+        let expression = parse("now + 3 days - 2 hours in Asia/Tokyo").unwrap();
+        assert_eq!(expression.start, Start::Anchor(Anchor::Now));
+        assert_eq!(
+            steps("now + 3 days - 2 hours in Asia/Tokyo"),
+            vec!["+3 days", "-2 hours", "in Asia/Tokyo"]
+        );
+        assert_eq!(
+            steps("2025-07-01T09:00 Europe/Paris to America/Chicago +45m"),
+            vec!["in America/Chicago", "+45m"]
+        );
+    }
+
+    #[test]
+    fn parse_reads_datetime_operands() {
+        let expression = parse("2025-07-01 09:00 Europe/Paris").unwrap();
+        assert_eq!(
+            expression.start,
+            Start::Datetime(DatetimeOperand {
+                value: "2025-07-01T09:00".into(),
+                zone: Some("Europe/Paris".into()),
+            })
+        );
+        assert_eq!(
+            steps("2025-07-04T17:00 America/New_York - 2025-07-04T09:00 Europe/London"),
+            vec!["since 2025-07-04T09:00"]
+        );
+        assert_eq!(steps("now - now"), vec!["since Now"]);
+        assert_eq!(
+            steps("2025-07-04 + 1 day - Today"),
+            vec!["+1 day", "since Today"]
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_expressions() {
+        for input in [
+            "",
+            "+ 3 days",
+            "now +",
+            "now + soon",
+            "now in",
+            "now 3 days",
+            "now + 2 days ago",
+            "now - 2025-07-04 + 3 days",
+            "now - now + 3 days",
+        ] {
+            let err = parse(input).unwrap_err();
+            assert!(err.contains("Expected: <start>"), "{input}: {err}");
+        }
+    }
+}
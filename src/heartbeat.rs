@@ -0,0 +1,95 @@
+use std::time::{Duration, Instant};
+
+use rmcp::{
+    Peer, RoleServer,
+    model::{LoggingLevel, LoggingMessageNotificationParam},
+};
+use serde::Serialize;
+
+/// Environment variable holding the heartbeat interval in seconds.
+pub const INTERVAL_ENV: &str = "MCP_TIME_HEARTBEAT_INTERVAL";
+
+/// Payload of one heartbeat notification.
+#[derive(Debug, Serialize)]
+pub struct Heartbeat {
+    /// Current instant in UTC (RFC 3339).
+    pub utc: String,
+    /// Whole seconds since the heartbeat started.
+    pub uptime_seconds: u64,
+}
+
+/// Read the heartbeat interval from a raw environment value.
+///
+/// Unset, empty, or zero values disable the heartbeat.
+pub fn parse_interval(value: Option<&str>) -> Result<Option<Duration>, String> {
+    let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    match value.parse::<u64>() {
+        Ok(0) => Ok(None),
+        Ok(seconds) => Ok(Some(Duration::from_secs(seconds))),
+        Err(_) => Err(format!(
+            "Invalid {INTERVAL_ENV}: '{value}'. Expected a whole number of seconds."
+        )),
+    }
+}
+
+/// Send a heartbeat logging notification to `peer` every `interval` until
+/// the connection closes.
+pub fn spawn(peer: Peer<RoleServer>, interval: Duration) -> tokio::task::JoinHandle<()> {
+    let started = Instant::now();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately; skip it so the first
+        // heartbeat arrives one interval after startup.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let heartbeat = Heartbeat {
+                utc: jiff::Timestamp::now()
+                    .round(jiff::Unit::Second)
+                    .unwrap_or_default()
+                    .to_string(),
+                uptime_seconds: started.elapsed().as_secs(),
+            };
+            let notification = LoggingMessageNotificationParam {
+                level: LoggingLevel::Debug,
+                logger: Some("mcp-time/heartbeat".into()),
+                data: serde_json::to_value(&heartbeat).unwrap_or_default(),
+            };
+            if peer.notify_logging_message(notification).await.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_interval_reads_seconds() {
+        assert_eq!(
+            parse_interval(Some("30")),
+            Ok(Some(Duration::from_secs(30)))
+        );
+        assert_eq!(
+            parse_interval(Some(" 5 ")),
+            Ok(Some(Duration::from_secs(5)))
+        );
+    }
+
+    #[test]
+    fn parse_interval_disables_on_missing_or_zero() {
+        assert_eq!(parse_interval(None), Ok(None));
+        assert_eq!(parse_interval(Some("")), Ok(None));
+        assert_eq!(parse_interval(Some("0")), Ok(None));
+    }
+
+    #[test]
+    fn parse_interval_rejects_non_numeric_values() {
+        let err = parse_interval(Some("1m")).unwrap_err();
+        assert!(err.contains(INTERVAL_ENV), "Error was: {err}");
+    }
+}
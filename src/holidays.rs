@@ -0,0 +1,692 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, OnceLock},
+};
+
+use jiff::civil::{Date, Weekday};
+use serde::Deserialize;
+
+use crate::{duration, locations};
+
+/// Environment variable naming a JSON or TOML file of extra holiday
+/// definitions.
+pub const HOLIDAYS_FILE_ENV: &str = "MCP_TIME_HOLIDAYS_FILE";
+
+/// Holiday rules bundled with the server, in the same format as user files.
+const BUNDLED: &str = include_str!("../data/holidays.json");
+
+/// A holiday definitions file: rules keyed by ISO 3166 country code.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawFile {
+    countries: BTreeMap<String, RawCountry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawCountry {
+    /// Defaults to the ISO 3166 name of the country code.
+    #[serde(default)]
+    name: Option<String>,
+    /// Subdivision names keyed by code (e.g., "BY" for Bavaria).
+    #[serde(default)]
+    regions: BTreeMap<String, String>,
+    #[serde(default)]
+    holidays: Vec<RawHoliday>,
+}
+
+/// One holiday rule. Exactly one way of placing the date is given: `date`,
+/// `month` with `nth` and `weekday`, `weekday` with `before` or `after`, or
+/// `easter`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawHoliday {
+    name: String,
+    /// "MM-DD" every year, or "YYYY-MM-DD" for a one-off holiday.
+    #[serde(default)]
+    date: Option<String>,
+    #[serde(default)]
+    month: Option<i8>,
+    /// 1 for the first such weekday of the month, -1 for the last.
+    #[serde(default)]
+    nth: Option<i8>,
+    #[serde(default)]
+    weekday: Option<String>,
+    /// "MM-DD": the last such weekday before this date.
+    #[serde(default)]
+    before: Option<String>,
+    /// "MM-DD": the first such weekday after this date.
+    #[serde(default)]
+    after: Option<String>,
+    /// Days from Easter Sunday (e.g., -2 for Good Friday).
+    #[serde(default)]
+    easter: Option<i16>,
+    #[serde(default)]
+    observed: Observance,
+    /// Region codes the holiday applies to; empty for the whole country.
+    #[serde(default)]
+    regions: Vec<String>,
+    #[serde(default)]
+    from_year: Option<i16>,
+    #[serde(default)]
+    to_year: Option<i16>,
+}
+
+/// When a holiday falling on a weekend is observed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Observance {
+    /// On its date only.
+    #[default]
+    None,
+    /// Saturday holidays on the Friday before, Sunday ones on the Monday
+    /// after, as for US federal holidays.
+    NearestWeekday,
+    /// On the next weekday not already taken by another holiday, as for UK
+    /// bank holidays.
+    NextWeekday,
+}
+
+/// Where a holiday falls in a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum When {
+    Fixed {
+        month: i8,
+        day: i8,
+    },
+    Once(Date),
+    NthWeekday {
+        month: i8,
+        nth: i8,
+        weekday: Weekday,
+    },
+    /// The nearest `weekday` strictly before (or after) `month`-`day`.
+    Relative {
+        month: i8,
+        day: i8,
+        weekday: Weekday,
+        after: bool,
+    },
+    Easter(i16),
+}
+
+impl When {
+    /// The date in `year`, if the holiday happens that year.
+    fn date(self, year: i16) -> Option<Date> {
+        match self {
+            When::Fixed { month, day } => Date::new(year, month, day).ok(),
+            When::Once(date) => (date.year() == year).then_some(date),
+            When::NthWeekday {
+                month,
+                nth,
+                weekday,
+            } => Date::new(year, month, 1)
+                .and_then(|first| first.nth_weekday_of_month(nth, weekday))
+                .ok(),
+            When::Relative {
+                month,
+                day,
+                weekday,
+                after,
+            } => Date::new(year, month, day)
+                .and_then(|date| date.nth_weekday(if after { 1 } else { -1 }, weekday))
+                .ok(),
+            When::Easter(days) => easter(year)
+                .and_then(|sunday| sunday.checked_add(jiff::Span::new().days(days)))
+                .ok(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    name: String,
+    when: When,
+    observed: Observance,
+    regions: Vec<String>,
+    from_year: Option<i16>,
+    to_year: Option<i16>,
+}
+
+impl Rule {
+    fn date(&self, year: i16) -> Option<Date> {
+        if self.from_year.is_some_and(|from| year < from)
+            || self.to_year.is_some_and(|to| year > to)
+        {
+            return None;
+        }
+        self.when.date(year)
+    }
+
+    /// Whether this holiday applies in `region`, or anywhere when `None`.
+    fn applies_in(&self, region: Option<&str>) -> bool {
+        match region {
+            Some(region) => self.regions.is_empty() || self.regions.iter().any(|r| r == region),
+            None => true,
+        }
+    }
+
+    /// Whether some place has both holidays, so they cannot share a
+    /// substitute day.
+    fn overlaps(&self, other: &Rule) -> bool {
+        self.regions.is_empty()
+            || other.regions.is_empty()
+            || self.regions.iter().any(|r| other.regions.contains(r))
+    }
+}
+
+/// One occurrence of a holiday.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holiday {
+    pub name: String,
+    pub date: Date,
+    /// The weekday it is observed on instead, when `date` is a weekend day.
+    pub observed: Option<Date>,
+    /// Region codes it applies to; empty for the whole country.
+    pub regions: Vec<String>,
+}
+
+/// A country's holiday rules.
+#[derive(Debug, Clone)]
+pub struct Country {
+    pub code: String,
+    pub name: String,
+    /// Subdivision names keyed by code.
+    pub regions: BTreeMap<String, String>,
+    rules: Vec<Rule>,
+}
+
+impl Country {
+    /// The region code `input` names, in any case, with or without the
+    /// country prefix (e.g., "by" or "DE-BY").
+    pub fn region(&self, input: &str) -> Option<&str> {
+        let input = input.trim().to_ascii_uppercase();
+        let code = input
+            .strip_prefix(&format!("{}-", self.code))
+            .unwrap_or(&input);
+        self.regions
+            .get_key_value(code)
+            .map(|(code, _)| code.as_str())
+    }
+
+    /// Holidays in `year`, in date order: those of the whole country plus
+    /// those of `region`, or every regional holiday when `region` is `None`.
+    pub fn holidays(&self, year: i16, region: Option<&str>) -> Vec<Holiday> {
+        let mut dated: Vec<(&Rule, Date)> = self
+            .rules
+            .iter()
+            .filter_map(|rule| Some((rule, rule.date(year)?)))
+            .collect();
+        dated.sort_by_key(|&(_, date)| date);
+        let observed = observed_dates(&dated);
+        dated
+            .into_iter()
+            .zip(observed)
+            .filter(|((rule, _), _)| rule.applies_in(region))
+            .map(|((rule, date), observed)| Holiday {
+                name: rule.name.clone(),
+                date,
+                observed,
+                regions: rule.regions.clone(),
+            })
+            .collect()
+    }
+
+    /// Holidays falling on or observed on `date`.
+    pub fn holidays_on(&self, date: Date, region: Option<&str>) -> Vec<Holiday> {
+        // A holiday's observed day can fall in the year before or after it.
+        let years = date.year().saturating_sub(1)..=date.year().saturating_add(1);
+        years
+            .flat_map(|year| self.holidays(year, region))
+            .filter(|holiday| holiday.date == date || holiday.observed == Some(date))
+            .collect()
+    }
+}
+
+/// The observed date of each of `dated`, which is sorted by date.
+///
+/// Substitute days go to holidays in date order, so when Christmas and
+/// Boxing Day both fall on a weekend they are observed on Monday and
+/// Tuesday.
+fn observed_dates(dated: &[(&Rule, Date)]) -> Vec<Option<Date>> {
+    let is_weekend = |date: Date| matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday);
+    let mut taken: Vec<(&Rule, Date)> = dated
+        .iter()
+        .filter(|(_, date)| !is_weekend(*date))
+        .copied()
+        .collect();
+    let mut observed = Vec::with_capacity(dated.len());
+    for &(rule, date) in dated {
+        let substitute = match rule.observed {
+            _ if !is_weekend(date) => None,
+            Observance::None => None,
+            Observance::NearestWeekday => match date.weekday() {
+                Weekday::Saturday => date.yesterday().ok(),
+                _ => date.tomorrow().ok(),
+            },
+            Observance::NextWeekday => {
+                let mut candidate = date.tomorrow().ok();
+                while let Some(day) = candidate {
+                    let free = !taken
+                        .iter()
+                        .any(|&(other, taken)| taken == day && rule.overlaps(other));
+                    if !is_weekend(day) && free {
+                        break;
+                    }
+                    candidate = day.tomorrow().ok();
+                }
+                candidate
+            }
+        };
+        if let Some(day) = substitute {
+            taken.push((rule, day));
+        }
+        observed.push(substitute);
+    }
+    observed
+}
+
+/// Easter Sunday in the Gregorian calendar, by the anonymous Gregorian
+/// algorithm.
+fn easter(year: i16) -> Result<Date, jiff::Error> {
+    let y = i32::from(year);
+    let (a, b, c) = (y % 19, y / 100, y % 100);
+    let (d, e) = (b / 4, b % 4);
+    let g = (8 * b + 13) / 25;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 19 * l) / 433;
+    let month = (h + l - 7 * m + 90) / 25;
+    let day = (h + l - 7 * m + 33 * month + 19) % 32;
+    Date::new(year, month as i8, day as i8)
+}
+
+/// Holiday rules for every supported country.
+#[derive(Debug, Clone, Default)]
+pub struct Database {
+    countries: BTreeMap<String, Country>,
+}
+
+impl Database {
+    /// The bundled rules: US federal holidays and the public holidays of the
+    /// United Kingdom, Germany, France, and Canada with their regions.
+    pub fn bundled() -> Arc<Database> {
+        static DATABASE: OnceLock<Arc<Database>> = OnceLock::new();
+        DATABASE
+            .get_or_init(|| {
+                let mut database = Database::default();
+                database
+                    .merge(BUNDLED)
+                    .expect("bundled holidays.json is valid");
+                Arc::new(database)
+            })
+            .clone()
+    }
+
+    /// The bundled rules plus those in the file at `path`, read as TOML if
+    /// its name ends in `.toml` and as JSON otherwise.
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Database, String> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read holidays file {}: {e}", path.display()))?;
+        let mut database = (*Database::bundled()).clone();
+        let is_toml = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"));
+        let merged = if is_toml {
+            database.merge_toml(&contents)
+        } else {
+            database.merge(&contents)
+        };
+        merged.map_err(|e| format!("Invalid holidays file {}: {e}", path.display()))?;
+        Ok(database)
+    }
+
+    /// Add the countries, regions, and holidays in `json`. Holidays and
+    /// regions of countries already present are added to theirs, and a
+    /// `name` replaces the country's name.
+    pub fn merge(&mut self, json: &str) -> Result<(), String> {
+        let file: RawFile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        self.merge_file(file)
+    }
+
+    /// Like [`Database::merge`], with the definitions written in TOML
+    /// (e.g., `[[countries.US.holidays]]` tables).
+    pub fn merge_toml(&mut self, toml: &str) -> Result<(), String> {
+        let file: RawFile = toml::from_str(toml).map_err(|e| e.to_string())?;
+        self.merge_file(file)
+    }
+
+    fn merge_file(&mut self, file: RawFile) -> Result<(), String> {
+        for (code, raw) in file.countries {
+            let code = code.trim().to_ascii_uppercase();
+            let name = match (raw.name, self.countries.get(&code)) {
+                (Some(name), _) => name,
+                (None, Some(existing)) => existing.name.clone(),
+                (None, None) => locations::country_name(&code)
+                    .ok_or_else(|| {
+                        format!("Country '{code}' is not an ISO 3166 code, so it needs a name.")
+                    })?
+                    .to_string(),
+            };
+            let country = self
+                .countries
+                .entry(code.clone())
+                .or_insert_with(|| Country {
+                    code: code.clone(),
+                    name: String::new(),
+                    regions: BTreeMap::new(),
+                    rules: Vec::new(),
+                });
+            country.name = name;
+            for (region, name) in raw.regions {
+                country
+                    .regions
+                    .insert(region.trim().to_ascii_uppercase(), name);
+            }
+            for holiday in raw.holidays {
+                let rule = rule(holiday, &country.regions)
+                    .map_err(|e| format!("In country '{code}': {e}"))?;
+                country.rules.push(rule);
+            }
+        }
+        Ok(())
+    }
+
+    /// The country with ISO 3166 code `code`, in any case.
+    pub fn country(&self, code: &str) -> Option<&Country> {
+        self.countries.get(&code.trim().to_ascii_uppercase())
+    }
+
+    /// Codes of every country with holiday rules, in order.
+    pub fn codes(&self) -> Vec<&str> {
+        self.countries.keys().map(String::as_str).collect()
+    }
+}
+
+/// Check a holiday definition and turn it into a rule.
+fn rule(raw: RawHoliday, regions: &BTreeMap<String, String>) -> Result<Rule, String> {
+    let invalid = |reason: String| format!("Invalid holiday '{}': {reason}", raw.name);
+    let weekday = raw
+        .weekday
+        .as_deref()
+        .map(|name| {
+            duration::weekday(&name.trim().to_ascii_lowercase())
+                .ok_or_else(|| invalid(format!("unknown weekday '{name}'.")))
+        })
+        .transpose()?;
+    let when = match (
+        raw.date.as_deref(),
+        raw.month,
+        raw.nth,
+        weekday,
+        raw.before.as_deref(),
+        raw.after.as_deref(),
+        raw.easter,
+    ) {
+        (Some(date), None, None, None, None, None, None) => {
+            match Date::strptime("%Y-%m-%d", date) {
+                Ok(date) => When::Once(date),
+                Err(_) => {
+                    let (month, day) = month_day(date).map_err(invalid)?;
+                    When::Fixed { month, day }
+                }
+            }
+        }
+        (None, Some(month), Some(nth), Some(weekday), None, None, None) => {
+            if !(1..=12).contains(&month) {
+                return Err(invalid(format!("month {month} is not between 1 and 12.")));
+            }
+            if nth == 0 || !(-5..=5).contains(&nth) {
+                return Err(invalid(format!(
+                    "nth {nth} is not between 1 and 5, or -5 and -1 to count from the end of the month."
+                )));
+            }
+            When::NthWeekday {
+                month,
+                nth,
+                weekday,
+            }
+        }
+        (None, None, None, Some(weekday), before, after, None)
+            if before.is_some() != after.is_some() =>
+        {
+            let (month, day) = month_day(before.or(after).unwrap_or_default()).map_err(invalid)?;
+            When::Relative {
+                month,
+                day,
+                weekday,
+                after: after.is_some(),
+            }
+        }
+        (None, None, None, None, None, None, Some(days)) => When::Easter(days),
+        _ => {
+            return Err(invalid(
+                "expected exactly one of 'date', 'month' with 'nth' and 'weekday', 'weekday' with 'before' or 'after', or 'easter'.".into(),
+            ));
+        }
+    };
+    let mut rule_regions = Vec::with_capacity(raw.regions.len());
+    for region in &raw.regions {
+        let code = region.trim().to_ascii_uppercase();
+        if !regions.contains_key(&code) {
+            return Err(invalid(format!(
+                "region '{region}' is not listed in the country's regions."
+            )));
+        }
+        rule_regions.push(code);
+    }
+    Ok(Rule {
+        name: raw.name,
+        when,
+        observed: raw.observed,
+        regions: rule_regions,
+        from_year: raw.from_year,
+        to_year: raw.to_year,
+    })
+}
+
+/// Parse "MM-DD", allowing February 29.
+fn month_day(input: &str) -> Result<(i8, i8), String> {
+    let expected = || format!("'{input}' is not a date. Expected MM-DD or YYYY-MM-DD.");
+    let (month, day) = input.split_once('-').ok_or_else(expected)?;
+    let (month, day) = (
+        month.parse().map_err(|_| expected())?,
+        day.parse().map_err(|_| expected())?,
+    );
+    Date::new(2000, month, day).map_err(|_| expected())?;
+    Ok((month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+
+    fn names_and_dates(holidays: &[Holiday]) -> Vec<(String, Date, Option<Date>)> {
+        holidays
+            .iter()
+            .map(|h| (h.name.clone(), h.date, h.observed))
+            .collect()
+    }
+
+    #[test]
+    fn easter_matches_known_dates() {
+        assert_eq!(easter(2024).unwrap(), date(2024, 3, 31));
+        assert_eq!(easter(2025).unwrap(), date(2025, 4, 20));
+        assert_eq!(easter(2038).unwrap(), date(2038, 4, 25));
+        assert_eq!(easter(2285).unwrap(), date(2285, 3, 22));
+    }
+
+    #[test]
+    fn bundled_us_holidays_are_observed_on_weekdays() {
+        let database = Database::bundled();
+        let us = database.country("us").unwrap();
+        let holidays = us.holidays(2021, None);
+        assert_eq!(holidays.len(), 11);
+        // 2021-07-04 was a Sunday; 2021-12-25 a Saturday.
+        let independence = &holidays[5];
+        assert_eq!(independence.date, date(2021, 7, 4));
+        assert_eq!(independence.observed, Some(date(2021, 7, 5)));
+        assert_eq!(holidays[10].observed, Some(date(2021, 12, 24)));
+        assert_eq!(holidays[4].name, "Juneteenth National Independence Day");
+        assert_eq!(us.holidays(2020, None).len(), 10);
+        assert_eq!(holidays[9].date, date(2021, 11, 25));
+        // New Year's Day 2022 fell on a Saturday, observed in 2021.
+        assert_eq!(
+            names_and_dates(&us.holidays_on(date(2021, 12, 31), None)),
+            [(
+                "New Year's Day".to_string(),
+                date(2022, 1, 1),
+                Some(date(2021, 12, 31))
+            )]
+        );
+    }
+
+    #[test]
+    fn substitute_days_are_not_shared() {
+        let database = Database::bundled();
+        let gb = database.country("GB").unwrap();
+        // Christmas 2021 was a Saturday and Boxing Day a Sunday.
+        let holidays = gb.holidays(2021, Some("ENG"));
+        let christmas: Vec<_> = names_and_dates(&holidays)
+            .into_iter()
+            .rev()
+            .take(2)
+            .collect();
+        assert_eq!(
+            christmas,
+            [
+                (
+                    "Boxing Day".to_string(),
+                    date(2021, 12, 26),
+                    Some(date(2021, 12, 28))
+                ),
+                (
+                    "Christmas Day".to_string(),
+                    date(2021, 12, 25),
+                    Some(date(2021, 12, 27))
+                ),
+            ]
+        );
+        // In 2022 Christmas was a Sunday, so its substitute skips Boxing Day.
+        let holidays = gb.holidays(2022, Some("ENG"));
+        assert_eq!(holidays.last().unwrap().observed, None);
+        assert_eq!(
+            holidays[holidays.len() - 2].observed,
+            Some(date(2022, 12, 27))
+        );
+    }
+
+    #[test]
+    fn regional_holidays_are_filtered() {
+        let database = Database::bundled();
+        let de = database.country("DE").unwrap();
+        assert_eq!(de.region("de-by"), Some("BY"));
+        assert_eq!(de.region("Bavaria"), None);
+        let is_named = |holidays: &[Holiday], name: &str| holidays.iter().any(|h| h.name == name);
+        let bavaria = de.holidays(2025, Some("BY"));
+        let berlin = de.holidays(2025, Some("BE"));
+        assert!(is_named(&bavaria, "Corpus Christi"));
+        assert!(!is_named(&berlin, "Corpus Christi"));
+        assert!(is_named(&berlin, "International Women's Day"));
+        let corpus_christi = bavaria.iter().find(|h| h.name == "Corpus Christi").unwrap();
+        assert_eq!(corpus_christi.date, date(2025, 6, 19));
+        assert!(de.holidays(2025, None).len() > bavaria.len());
+        // Repentance and Prayer Day: the Wednesday before 23 November.
+        let saxony = de.holidays_on(date(2025, 11, 19), Some("SN"));
+        assert_eq!(saxony[0].name, "Repentance and Prayer Day");
+        let victoria = database.country("CA").unwrap().holidays(2025, Some("ON"));
+        assert!(victoria.iter().any(|h| h.date == date(2025, 5, 19)));
+    }
+
+    #[test]
+    fn merge_adds_rules_and_checks_them() {
+        let mut database = (*Database::bundled()).clone();
+        database
+            .merge(
+                r#"{"countries": {
+                    "US": {"regions": {"CA": "California"}, "holidays": [
+                        {"name": "César Chávez Day", "date": "03-31", "regions": ["ca"]}
+                    ]},
+                    "NZ": {"holidays": [
+                        {"name": "Company offsite", "date": "2025-02-14"}
+                    ]}
+                }}"#,
+            )
+            .unwrap();
+        let us = database.country("US").unwrap();
+        assert_eq!(us.name, "United States");
+        assert_eq!(us.holidays(2025, Some("CA")).len(), 12);
+        assert_eq!(us.holidays(2025, None).len(), 12);
+        let nz = database.country("nz").unwrap();
+        assert_eq!(nz.name, "New Zealand");
+        assert_eq!(nz.holidays(2025, None).len(), 1);
+        assert!(nz.holidays(2026, None).is_empty());
+
+        for (json, expected) in [
+            (
+                r#"{"countries": {"US": {"holidays": [{"name": "X"}]}}}"#,
+                "exactly one",
+            ),
+            (
+                r#"{"countries": {"US": {"holidays": [{"name": "X", "date": "02-30"}]}}}"#,
+                "not a date",
+            ),
+            (
+                r#"{"countries": {"US": {"holidays": [{"name": "X", "date": "01-01", "regions": ["TX"]}]}}}"#,
+                "region 'TX'",
+            ),
+            (
+                r#"{"countries": {"US": {"holidays": [{"name": "X", "month": 1, "nth": 0, "weekday": "monday"}]}}}"#,
+                "nth 0",
+            ),
+            (r#"{"countries": {"XX": {}}}"#, "needs a name"),
+            (r#"{"countries": {}, "extra": 1}"#, "unknown field"),
+        ] {
+            let err = database.merge(json).unwrap_err();
+            assert!(err.contains(expected), "Error for {json} was: {err}");
+        }
+    }
+
+    #[test]
+    fn load_reads_toml_by_extension() {
+        let path =
+            std::env::temp_dir().join(format!("mcp-time-{}-holidays.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"
+[countries.US]
+regions = { CA = "California" }
+
+[[countries.US.holidays]]
+name = "César Chávez Day"
+date = "03-31"
+regions = ["CA"]
+
+[[countries.NZ.holidays]]
+name = "Company offsite"
+weekday = "friday"
+after = "02-10"
+"#,
+        )
+        .unwrap();
+        let database = Database::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let database = database.unwrap();
+        let us = database.country("US").unwrap();
+        assert_eq!(us.holidays(2025, Some("CA")).len(), 12);
+        let nz = database.country("NZ").unwrap();
+        assert_eq!(nz.holidays(2025, None)[0].date, date(2025, 2, 14));
+
+        let err = Database::default()
+            .merge_toml(
+                "[countries.US]
+holidays = [{ name = \"X\" }]",
+            )
+            .unwrap_err();
+        assert!(err.contains("exactly one"), "Error was: {err}");
+    }
+}
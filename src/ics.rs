@@ -0,0 +1,591 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// An event read from an iCalendar file, rendered in a target zone.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct IcsEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Start in the target zone, or the date (YYYY-MM-DD) for all-day events.
+    pub start: String,
+    /// End in the target zone, or the exclusive end date for all-day events.
+    pub end: String,
+    /// Zone the event was written in: its TZID, "UTC", or "floating" for
+    /// local times without a zone, which are read in the target zone.
+    pub source_timezone: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub all_day: bool,
+}
+
+/// An event that could not be read.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct InvalidEvent {
+    /// Zero-based position among the file's events.
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    pub error: String,
+}
+
+/// Events read from an iCalendar file.
+#[derive(Debug)]
+pub struct IcsCalendar {
+    pub events: Vec<IcsEvent>,
+    pub invalid: Vec<InvalidEvent>,
+}
+
+/// A content line: `NAME;PARAM=value:VALUE`.
+#[derive(Debug)]
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A `BEGIN:NAME` … `END:NAME` block.
+#[derive(Debug, Default)]
+struct Component {
+    name: String,
+    properties: Vec<Property>,
+    children: Vec<Component>,
+}
+
+impl Component {
+    fn property(&self, name: &str) -> Option<&Property> {
+        self.properties.iter().find(|p| p.name == name)
+    }
+
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Component> {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+}
+
+/// Read the events of an iCalendar file, rendering their times in `tz`.
+///
+/// `TZID` parameters resolve to the file's own `VTIMEZONE` definitions
+/// first, then to IANA zone names. Events that cannot be read are reported
+/// in `invalid` rather than failing the whole file.
+pub fn parse(input: &str, tz: &jiff::tz::TimeZone) -> Result<IcsCalendar, String> {
+    let root = parse_components(input)?;
+    let calendar = root
+        .children("VCALENDAR")
+        .next()
+        .ok_or("No VCALENDAR found. Expected iCalendar data starting with 'BEGIN:VCALENDAR'.")?;
+
+    let timezones: HashMap<&str, Result<jiff::tz::TimeZone, String>> = calendar
+        .children("VTIMEZONE")
+        .filter_map(|c| Some((c.property("TZID")?.value.as_str(), vtimezone(c))))
+        .collect();
+
+    let mut events = Vec::new();
+    let mut invalid = Vec::new();
+    for (index, component) in calendar.children("VEVENT").enumerate() {
+        let summary = component.property("SUMMARY").map(|p| unescape(&p.value));
+        match event(component, &timezones, tz) {
+            Ok((start, end, source_timezone, all_day)) => events.push(IcsEvent {
+                uid: component.property("UID").map(|p| unescape(&p.value)),
+                summary: summary.unwrap_or_default(),
+                location: component.property("LOCATION").map(|p| unescape(&p.value)),
+                start,
+                end,
+                source_timezone,
+                all_day,
+            }),
+            Err(error) => invalid.push(InvalidEvent {
+                index,
+                summary,
+                error,
+            }),
+        }
+    }
+    Ok(IcsCalendar { events, invalid })
+}
+
+/// A DTSTART or DTEND value.
+enum When {
+    Date(jiff::civil::Date),
+    Instant(jiff::Zoned),
+}
+
+/// Rendered start, end, source zone label, and all-day flag of an event.
+fn event(
+    component: &Component,
+    timezones: &HashMap<&str, Result<jiff::tz::TimeZone, String>>,
+    tz: &jiff::tz::TimeZone,
+) -> Result<(String, String, String, bool), String> {
+    let dtstart = component
+        .property("DTSTART")
+        .ok_or("Event has no DTSTART.")?;
+    let (start, source) = when(dtstart, timezones, tz)?;
+    let duration = component
+        .property("DURATION")
+        .map(|p| {
+            p.value
+                .parse::<jiff::Span>()
+                .map_err(|_| format!("Invalid DURATION: '{}'.", p.value))
+        })
+        .transpose()?;
+    let end = match component.property("DTEND") {
+        Some(dtend) => Some(when(dtend, timezones, tz)?.0),
+        None => None,
+    };
+
+    let render = |zdt: &jiff::Zoned| {
+        zdt.with_time_zone(tz.clone())
+            .strftime("%Y-%m-%dT%H:%M:%S%:z")
+            .to_string()
+    };
+    let invalid_duration = |e: jiff::Error| format!("Invalid DURATION: {e}");
+    match (start, end) {
+        (When::Date(start), end) => {
+            // All-day events last one day unless told otherwise.
+            let end = match (end, duration) {
+                (Some(When::Date(end)), _) => end,
+                (Some(When::Instant(_)), _) => {
+                    return Err("DTEND must be a date when DTSTART is a date.".into());
+                }
+                (None, Some(span)) => start.checked_add(span).map_err(invalid_duration)?,
+                (None, None) => start.tomorrow().map_err(|e| e.to_string())?,
+            };
+            if end < start {
+                return Err("Event ends before it starts.".into());
+            }
+            Ok((start.to_string(), end.to_string(), source, true))
+        }
+        (When::Instant(start), end) => {
+            let end = match (end, duration) {
+                (Some(When::Instant(end)), _) => end,
+                (Some(When::Date(_)), _) => {
+                    return Err("DTEND must be a date-time when DTSTART is a date-time.".into());
+                }
+                (None, Some(span)) => start.checked_add(span).map_err(invalid_duration)?,
+                (None, None) => start.clone(),
+            };
+            if end < start {
+                return Err("Event ends before it starts.".into());
+            }
+            Ok((render(&start), render(&end), source, false))
+        }
+    }
+}
+
+/// Read a DATE or DATE-TIME property along with the label of its zone.
+///
+/// Floating times (no `Z` and no `TZID`) are read in `tz`.
+fn when(
+    property: &Property,
+    timezones: &HashMap<&str, Result<jiff::tz::TimeZone, String>>,
+    tz: &jiff::tz::TimeZone,
+) -> Result<(When, String), String> {
+    let value = property.value.trim();
+    let invalid = || format!("Invalid {} value: '{value}'.", property.name);
+    if property.param("VALUE") == Some("DATE") || value.len() == 8 {
+        let date = jiff::civil::Date::strptime("%Y%m%d", value).map_err(|_| invalid())?;
+        return Ok((When::Date(date), "floating".into()));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let dt = jiff::civil::DateTime::strptime("%Y%m%dT%H%M%S", utc).map_err(|_| invalid())?;
+        let zdt = dt
+            .to_zoned(jiff::tz::TimeZone::UTC)
+            .map_err(|_| invalid())?;
+        return Ok((When::Instant(zdt), "UTC".into()));
+    }
+    let dt = jiff::civil::DateTime::strptime("%Y%m%dT%H%M%S", value).map_err(|_| invalid())?;
+    let (zone, label) = match property.param("TZID") {
+        Some(tzid) => {
+            let zone = match timezones.get(tzid) {
+                Some(Ok(zone)) => zone.clone(),
+                Some(Err(e)) => jiff::tz::TimeZone::get(tzid)
+                    .map_err(|_| format!("Unsupported VTIMEZONE '{tzid}': {e}"))?,
+                None => jiff::tz::TimeZone::get(tzid).map_err(|_| {
+                    format!("Unknown TZID '{tzid}': it is not an IANA name and has no VTIMEZONE.")
+                })?,
+            };
+            (zone, tzid.to_string())
+        }
+        None => (tz.clone(), "floating".to_string()),
+    };
+    let zdt = dt.to_zoned(zone).map_err(|_| invalid())?;
+    Ok((When::Instant(zdt), label))
+}
+
+/// Build a zone from a VTIMEZONE's most recent STANDARD and DAYLIGHT rules.
+///
+/// A pair of yearly rules becomes a POSIX TZ string; a single observance
+/// becomes a fixed offset. Historical observances are ignored.
+fn vtimezone(component: &Component) -> Result<jiff::tz::TimeZone, String> {
+    let latest = |kind| {
+        component
+            .children(kind)
+            .max_by_key(|c| c.property("DTSTART").map(|p| p.value.clone()))
+    };
+    let observance_offset = |c: &Component| {
+        let value = &c.property("TZOFFSETTO").ok_or("Missing TZOFFSETTO.")?.value;
+        parse_utc_offset(value).ok_or_else(|| format!("Invalid TZOFFSETTO: '{value}'."))
+    };
+    match (latest("STANDARD"), latest("DAYLIGHT")) {
+        (Some(standard), Some(daylight))
+            if standard.property("RRULE").is_some() && daylight.property("RRULE").is_some() =>
+        {
+            let posix = format!(
+                "{}{},{},{}",
+                posix_zone(standard, "STD", observance_offset(standard)?),
+                posix_zone(daylight, "DST", observance_offset(daylight)?),
+                posix_rule(daylight)?,
+                posix_rule(standard)?,
+            );
+            jiff::tz::TimeZone::posix(&posix).map_err(|e| e.to_string())
+        }
+        (standard, daylight) => {
+            // Without two recurring rules, the observance that started last
+            // is in effect from then on.
+            let last = [standard, daylight]
+                .into_iter()
+                .flatten()
+                .max_by_key(|c| c.property("DTSTART").map(|p| p.value.clone()))
+                .ok_or("No STANDARD or DAYLIGHT definition.")?;
+            let offset = jiff::tz::Offset::from_seconds(observance_offset(last)?)
+                .map_err(|e| e.to_string())?;
+            Ok(jiff::tz::TimeZone::fixed(offset))
+        }
+    }
+}
+
+/// The `<NAME>offset` part of a POSIX TZ string for an observance.
+///
+/// POSIX offsets count hours west of UTC, the opposite sign of ISO 8601.
+fn posix_zone(component: &Component, fallback: &str, offset_seconds: i32) -> String {
+    let name: String = component
+        .property("TZNAME")
+        .map(|p| p.value.as_str())
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '+' || *c == '-')
+        .collect();
+    let name = if name.len() >= 3 {
+        name
+    } else {
+        fallback.into()
+    };
+    let west = -offset_seconds;
+    let sign = if west < 0 { "-" } else { "" };
+    let abs = west.unsigned_abs();
+    format!(
+        "<{name}>{sign}{}:{:02}:{:02}",
+        abs / 3600,
+        abs % 3600 / 60,
+        abs % 60
+    )
+}
+
+/// The `Mm.w.d/time` transition rule for an observance with a yearly
+/// `BYMONTH` and `BYDAY` (e.g., `2SU`, `-1SU`) recurrence.
+fn posix_rule(component: &Component) -> Result<String, String> {
+    let rrule = &component.property("RRULE").ok_or("Missing RRULE.")?.value;
+    let unsupported = || format!("Unsupported RRULE: '{rrule}'.");
+    let part = |key: &str| {
+        rrule.split(';').find_map(|kv| {
+            kv.split_once('=')
+                .filter(|(k, _)| k.eq_ignore_ascii_case(key))
+                .map(|(_, v)| v)
+        })
+    };
+    if !part("FREQ").is_some_and(|f| f.eq_ignore_ascii_case("YEARLY")) {
+        return Err(unsupported());
+    }
+    let month: u8 = part("BYMONTH")
+        .and_then(|m| m.parse().ok())
+        .filter(|m| (1..=12).contains(m))
+        .ok_or_else(unsupported)?;
+    let byday = part("BYDAY").ok_or_else(unsupported)?;
+    let split = byday.len().checked_sub(2).ok_or_else(unsupported)?;
+    let (nth, day) = byday.split_at_checked(split).ok_or_else(unsupported)?;
+    let week = match nth.trim_start_matches('+').parse::<i8>() {
+        Ok(n @ 1..=4) => n,
+        Ok(-1 | 5) => 5,
+        _ => return Err(unsupported()),
+    };
+    let weekday = ["SU", "MO", "TU", "WE", "TH", "FR", "SA"]
+        .iter()
+        .position(|d| d.eq_ignore_ascii_case(day))
+        .ok_or_else(unsupported)?;
+    // DTSTART holds the local wall-clock time of the change.
+    let start = &component
+        .property("DTSTART")
+        .ok_or("Missing DTSTART.")?
+        .value;
+    let time = start
+        .split_once('T')
+        .and_then(|(_, t)| jiff::civil::Time::strptime("%H%M%S", t).ok())
+        .ok_or_else(|| format!("Invalid DTSTART: '{start}'."))?;
+    Ok(format!(
+        "M{month}.{week}.{weekday}/{}:{:02}:{:02}",
+        time.hour(),
+        time.minute(),
+        time.second()
+    ))
+}
+
+/// Parse a `+HHMM` or `+HHMMSS` UTC offset into seconds east of UTC.
+fn parse_utc_offset(value: &str) -> Option<i32> {
+    let value = value.trim();
+    let (sign, digits) = match value.split_at_checked(1)? {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+    if !matches!(digits.len(), 4 | 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let field = |i: usize| {
+        digits
+            .get(i..i + 2)
+            .map_or(Some(0), |d| d.parse::<i32>().ok())
+    };
+    Some(sign * (field(0)? * 3600 + field(2)? * 60 + field(4)?))
+}
+
+/// Split iCalendar text into components, unfolding continuation lines.
+fn parse_components(input: &str) -> Result<Component, String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in input.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut stack = vec![Component::default()];
+    for line in lines.iter().filter(|l| !l.trim().is_empty()) {
+        let property =
+            parse_property(line).ok_or_else(|| format!("Malformed iCalendar line: '{line}'."))?;
+        match property.name.as_str() {
+            "BEGIN" => stack.push(Component {
+                name: property.value.trim().to_ascii_uppercase(),
+                ..Component::default()
+            }),
+            "END" => {
+                let name = property.value.trim().to_ascii_uppercase();
+                let open = stack.len() > 1 && stack.last().is_some_and(|c| c.name == name);
+                match (open, stack.pop()) {
+                    (true, Some(component)) => {
+                        if let Some(parent) = stack.last_mut() {
+                            parent.children.push(component);
+                        }
+                    }
+                    _ => return Err(format!("Unexpected 'END:{name}'.")),
+                }
+            }
+            _ => {
+                if let Some(current) = stack.last_mut() {
+                    current.properties.push(property);
+                }
+            }
+        }
+    }
+    match stack.len() {
+        1 => Ok(stack.remove(0)),
+        _ => Err(format!(
+            "Missing 'END:{}'.",
+            stack.last().map_or("", |c| c.name.as_str())
+        )),
+    }
+}
+
+/// Parse one unfolded content line.
+fn parse_property(line: &str) -> Option<Property> {
+    // The value starts at the first colon outside a quoted parameter value.
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            quoted = !quoted;
+            None
+        }
+        ':' if !quoted => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    if name.is_empty() {
+        return None;
+    }
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| {
+            (
+                k.trim().to_ascii_uppercase(),
+                v.trim_matches('"').to_string(),
+            )
+        })
+        .collect();
+    Some(Property {
+        name,
+        params,
+        value: value.to_string(),
+    })
+}
+
+/// Undo TEXT value escaping (`\n`, `\,`, `\;`, `\\`).
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTLOOK: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:Eastern Standard Time\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:16010101T020000\r\n\
+TZOFFSETFROM:-0400\r\n\
+TZOFFSETTO:-0500\r\n\
+RRULE:FREQ=YEARLY;BYDAY=1SU;BYMONTH=11\r\n\
+END:STANDARD\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:16010101T020000\r\n\
+TZOFFSETFROM:-0500\r\n\
+TZOFFSETTO:-0400\r\n\
+RRULE:FREQ=YEARLY;BYDAY=2SU;BYMONTH=3\r\n\
+END:DAYLIGHT\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+UID:1@example.com\r\n\
+SUMMARY:Budget review\\, Q3\r\n\
+DTSTART;TZID=Eastern Standard Time:20250704T090000\r\n\
+DTEND;TZID=Eastern Standard Time:20250704T100000\r\n\
+LOCATION:Room 4\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+SUMMARY:Winter sync\r\n\
+DTSTART;TZID=Eastern Standard Time:20250115T090000\r\n\
+DURATION:PT30M\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    fn tz(name: &str) -> jiff::tz::TimeZone {
+        jiff::tz::TimeZone::get(name).unwrap()
+    }
+
+    #[test]
+    fn parse_applies_vtimezone_rules() {
+        let calendar = parse(OUTLOOK, &tz("Europe/London")).unwrap();
+        assert!(calendar.invalid.is_empty(), "{:?}", calendar.invalid);
+        let summer = &calendar.events[0];
+        assert_eq!(summer.summary, "Budget review, Q3");
+        assert_eq!(summer.start, "2025-07-04T14:00:00+01:00");
+        assert_eq!(summer.end, "2025-07-04T15:00:00+01:00");
+        assert_eq!(summer.source_timezone, "Eastern Standard Time");
+        assert_eq!(summer.location.as_deref(), Some("Room 4"));
+        let winter = &calendar.events[1];
+        assert_eq!(winter.start, "2025-01-15T14:00:00+00:00");
+        assert_eq!(winter.end, "2025-01-15T14:30:00+00:00");
+    }
+
+    #[test]
+    fn parse_reads_iana_tzids_utc_floating_and_all_day_events() {
+        let input = "BEGIN:VCALENDAR\n\
+BEGIN:VEVENT\n\
+SUMMARY:Standup\n\
+DTSTART;TZID=Asia/Tokyo:20250704T090000\n\
+DTEND;TZID=Asia/Tokyo:20250704T091500\n\
+END:VEVENT\n\
+BEGIN:VEVENT\n\
+SUMMARY:Deploy\n\
+DTSTART:20250704T000000Z\n\
+END:VEVENT\n\
+BEGIN:VEVENT\n\
+SUMMARY:Lunch\n\
+DTSTART:20250704T120000\n\
+DTEND:20250704T130000\n\
+END:VEVENT\n\
+BEGIN:VEVENT\n\
+SUMMARY:Holiday\n\
+DTSTART;VALUE=DATE:20250704\n\
+END:VEVENT\n\
+END:VCALENDAR\n";
+        let calendar = parse(input, &tz("America/Chicago")).unwrap();
+        let events = &calendar.events;
+        assert_eq!(events[0].start, "2025-07-03T19:00:00-05:00");
+        assert_eq!(events[1].start, "2025-07-03T19:00:00-05:00");
+        assert_eq!(events[1].end, events[1].start);
+        assert_eq!(events[1].source_timezone, "UTC");
+        assert_eq!(events[2].start, "2025-07-04T12:00:00-05:00");
+        assert_eq!(events[2].source_timezone, "floating");
+        assert!(events[3].all_day);
+        assert_eq!(
+            (&*events[3].start, &*events[3].end),
+            ("2025-07-04", "2025-07-05")
+        );
+    }
+
+    #[test]
+    fn parse_reports_unreadable_events_and_rejects_non_calendars() {
+        let input = "BEGIN:VCALENDAR\n\
+BEGIN:VEVENT\n\
+SUMMARY:Mystery\n\
+DTSTART;TZID=Mars Standard Time:20250704T090000\n\
+END:VEVENT\n\
+BEGIN:VEVENT\n\
+SUMMARY:No start\n\
+END:VEVENT\n\
+END:VCALENDAR\n";
+        let calendar = parse(input, &jiff::tz::TimeZone::UTC).unwrap();
+        assert!(calendar.events.is_empty());
+        assert_eq!(calendar.invalid.len(), 2);
+        assert!(calendar.invalid[0].error.contains("Unknown TZID"));
+        assert_eq!(calendar.invalid[1].summary.as_deref(), Some("No start"));
+
+        assert!(parse("hello", &jiff::tz::TimeZone::UTC).is_err());
+        assert!(parse("BEGIN:VCALENDAR\nBEGIN:VEVENT\n", &jiff::tz::TimeZone::UTC).is_err());
+    }
+
+    #[test]
+    fn parse_property_handles_quoted_params_and_folding() {
+        let root = parse_components(
+            "BEGIN:X\nDESCRIPTION;ALTREP=\"cid:a:b\":first\n  and second\nEND:X\n",
+        )
+        .unwrap();
+        let property = root.children[0].property("DESCRIPTION").unwrap();
+        assert_eq!(property.param("ALTREP"), Some("cid:a:b"));
+        assert_eq!(property.value, "first and second");
+    }
+
+    #[test]
+    fn parse_utc_offset_reads_signed_offsets() {
+        assert_eq!(parse_utc_offset("-0500"), Some(-18_000));
+        assert_eq!(parse_utc_offset("+0545"), Some(20_700));
+        assert_eq!(parse_utc_offset("+013045"), Some(5_445));
+        assert_eq!(parse_utc_offset("0500"), None);
+    }
+}
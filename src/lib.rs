@@ -1 +1,49 @@
+pub mod batch;
+pub mod business_days;
+pub mod calendar_system;
+pub mod cities;
+pub mod clock;
+pub mod config;
+pub mod core;
+pub mod cron;
+pub mod datetime_format;
+pub mod detect;
+pub mod display_names;
+pub mod dst;
+pub mod duration;
+pub mod env;
+pub mod epoch;
+pub mod expression;
+pub mod heartbeat;
+pub mod holidays;
+pub mod ics;
+pub mod limits;
+pub mod local;
+pub mod local_day;
+pub mod locale;
+pub mod locations;
+pub mod meeting;
+pub mod metrics;
+pub mod middleware;
+pub mod natural;
+pub mod ntp;
+pub mod prompts;
+pub mod recurrence;
+pub mod repl;
+pub mod rrule;
+pub mod scheduler;
+pub mod serial_date;
 pub mod server;
+pub mod sql_literal;
+pub mod state;
+pub mod sun;
+pub mod telemetry;
+pub mod tick;
+pub mod timers;
+pub mod transitions;
+pub mod transport;
+pub mod tzdb;
+pub mod unicode;
+pub mod validate;
+pub mod world_clock;
+pub mod zone_info;
@@ -0,0 +1,340 @@
+//! Per-client rate limits and argument size caps for shared deployments,
+//! checked on every tool call before it reaches the tool router.
+
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use rmcp::model::{Extensions, JsonObject};
+use serde_json::Value;
+
+use crate::core::Error as ToolError;
+
+/// Environment variable holding the calls each client may make per minute.
+pub const RATE_LIMIT_ENV: &str = "MCP_TIME_RATE_LIMIT";
+
+/// Environment variable holding the most items a list argument may hold.
+pub const MAX_LIST_ITEMS_ENV: &str = "MCP_TIME_MAX_LIST_ITEMS";
+
+/// Environment variable holding the largest number of results a call may
+/// request.
+pub const MAX_RESULTS_ENV: &str = "MCP_TIME_MAX_RESULTS";
+
+/// Arguments that ask a tool for a number of results (e.g., the
+/// occurrences of a recurrence, or meeting slots).
+const RESULT_COUNT_ARGUMENTS: [&str; 2] = ["count", "limit"];
+
+/// String arguments that may hold a comma-separated list (e.g.,
+/// `get_current_time`'s zones), capped like arrays.
+const LIST_STRING_ARGUMENTS: [&str; 1] = ["timezone"];
+
+/// Limits on tool calls, each off when `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Calls each client may make per minute, in bursts of up to a minute's
+    /// allowance.
+    pub calls_per_minute: Option<u32>,
+    /// Most items any list argument may hold (e.g., target timezones).
+    pub max_list_items: Option<u32>,
+    /// Largest `count` or `limit` argument a call may give.
+    pub max_results: Option<u32>,
+}
+
+/// Read a limit from a raw environment value.
+///
+/// Empty and zero values disable the limit.
+pub fn parse_limit(name: &str, value: &str) -> Result<Option<u32>, String> {
+    match value.trim() {
+        "" => Ok(None),
+        value => match value.parse::<u32>() {
+            Ok(0) => Ok(None),
+            Ok(limit) => Ok(Some(limit)),
+            Err(_) => Err(format!(
+                "Invalid {name}: '{value}'. Expected a whole number, or 0 for no limit."
+            )),
+        },
+    }
+}
+
+/// Who a call counts against: the IP address of an HTTP client, or `None`
+/// for the one client of a stdio server.
+pub type Client = Option<IpAddr>;
+
+/// The client behind a request, from the HTTP request it arrived in.
+///
+/// Connections from the same address are one client, so opening new
+/// sessions does not reset the allowance.
+pub fn client_of(extensions: &Extensions) -> Client {
+    extensions
+        .get::<axum::http::request::Parts>()?
+        .extensions
+        .get::<axum::extract::ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip())
+}
+
+/// The limits, with the calls each client has left.
+///
+/// Clones, such as the sessions of an HTTP server, share the allowances.
+#[derive(Debug, Clone, Default)]
+pub struct Guard {
+    limits: Limits,
+    buckets: Arc<Mutex<HashMap<Client, Bucket>>>,
+}
+
+/// A token bucket holding the calls a client may still make.
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    /// Refill at `per_second` up to `capacity`, as of `now`.
+    fn refill(&mut self, capacity: f64, per_second: f64, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_second).min(capacity);
+        self.updated = now;
+    }
+}
+
+impl Guard {
+    pub fn new(limits: Limits) -> Self {
+        Guard {
+            limits,
+            buckets: Arc::default(),
+        }
+    }
+
+    /// Check a tool call's `arguments` against the limits, spending one call
+    /// of `client`'s allowance.
+    ///
+    /// Calls over the rate limit fail with `rate_limited` and the seconds to
+    /// wait before retrying; oversized arguments fail with `too_many_items`
+    /// or `too_many_results`.
+    pub fn check(&self, client: Client, arguments: Option<&JsonObject>) -> Result<(), ToolError> {
+        self.check_at(client, arguments, Instant::now())
+    }
+
+    fn check_at(
+        &self,
+        client: Client,
+        arguments: Option<&JsonObject>,
+        now: Instant,
+    ) -> Result<(), ToolError> {
+        if let Some(per_minute) = self.limits.calls_per_minute {
+            self.spend(client, per_minute, now)?;
+        }
+        let Some(arguments) = arguments else {
+            return Ok(());
+        };
+        if let Some(max) = self.limits.max_results {
+            for name in RESULT_COUNT_ARGUMENTS {
+                if let Some(requested) = arguments.get(name).and_then(Value::as_u64)
+                    && requested > u64::from(max)
+                {
+                    return Err(ToolError::new(
+                        "too_many_results",
+                        format!(
+                            "'{name}' asks for {requested} results, but this server returns at most {max} per call. Ask for {max} or fewer, and make further calls for the rest."
+                        ),
+                    )
+                    .with_value(requested.to_string()));
+                }
+            }
+        }
+        if let Some(max) = self.limits.max_list_items {
+            for (name, value) in arguments {
+                check_lists(name, value, max)?;
+            }
+            for name in LIST_STRING_ARGUMENTS {
+                if let Some(list) = arguments.get(name).and_then(Value::as_str) {
+                    let items = list.split(',').filter(|item| !item.trim().is_empty());
+                    check_count(name, items.count(), max)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Take one call from `client`'s allowance of `per_minute` calls,
+    /// refilled continuously.
+    fn spend(&self, client: Client, per_minute: u32, now: Instant) -> Result<(), ToolError> {
+        let capacity = f64::from(per_minute);
+        let per_second = capacity / 60.0;
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if !buckets.contains_key(&client) {
+            // Clients back to a full allowance are no different from new
+            // ones, so forget them rather than keep every address seen.
+            buckets.retain(|_, bucket| {
+                bucket.refill(capacity, per_second, now);
+                bucket.tokens < capacity
+            });
+        }
+        let bucket = buckets.entry(client).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        bucket.refill(capacity, per_second, now);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+        let wait = Duration::from_secs_f64((1.0 - bucket.tokens) / per_second);
+        let seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+        Err(ToolError::new(
+            "rate_limited",
+            format!(
+                "Rate limit of {per_minute} calls per minute exceeded. Retry in {seconds} second{}.",
+                if seconds == 1 { "" } else { "s" }
+            ),
+        )
+        .with_retry_after(seconds))
+    }
+}
+
+/// Check that the list in argument `path` has at most `max` items.
+fn check_count(path: &str, items: usize, max: u32) -> Result<(), ToolError> {
+    if items > max as usize {
+        return Err(ToolError::new(
+            "too_many_items",
+            format!(
+                "'{path}' has {items} items, but this server accepts at most {max} per list. Split them across several calls."
+            ),
+        )
+        .with_value(items.to_string()));
+    }
+    Ok(())
+}
+
+/// Check the arrays in argument `path` and everything nested in it.
+fn check_lists(path: &str, value: &Value, max: u32) -> Result<(), ToolError> {
+    match value {
+        Value::Array(items) => {
+            check_count(path, items.len(), max)?;
+            items
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, item)| check_lists(&format!("{path}[{i}]"), item, max))
+        }
+        Value::Object(fields) => fields
+            .iter()
+            .try_for_each(|(name, field)| check_lists(&format!("{path}.{name}"), field, max)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(json: Value) -> JsonObject {
+        json.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn parse_limit_reads_whole_numbers() {
+        assert_eq!(parse_limit("LIMIT", ""), Ok(None));
+        assert_eq!(parse_limit("LIMIT", "0"), Ok(None));
+        assert_eq!(parse_limit("LIMIT", " 120 "), Ok(Some(120)));
+        let err = parse_limit("LIMIT", "fast").unwrap_err();
+        assert!(err.contains("LIMIT"), "Error was: {err}");
+    }
+
+    #[test]
+    fn check_limits_calls_per_minute_and_refills() {
+        let guard = Guard::new(Limits {
+            calls_per_minute: Some(2),
+            ..Limits::default()
+        });
+        let start = Instant::now();
+        assert!(guard.check_at(None, None, start).is_ok());
+        assert!(guard.check_at(None, None, start).is_ok());
+        let err = guard.check_at(None, None, start).unwrap_err();
+        assert_eq!(err.code, "rate_limited");
+        assert_eq!(err.retry_after_seconds, Some(30));
+        assert!(
+            err.message.contains("Retry in 30 seconds"),
+            "{}",
+            err.message
+        );
+
+        // One call's allowance comes back every 30 seconds.
+        assert!(
+            guard
+                .check_at(None, None, start + Duration::from_secs(30))
+                .is_ok()
+        );
+        assert!(
+            guard
+                .check_at(None, None, start + Duration::from_secs(31))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn check_limits_each_client_address_across_sessions() {
+        let guard = Guard::new(Limits {
+            calls_per_minute: Some(1),
+            ..Limits::default()
+        });
+        let alice = Some(IpAddr::from([192, 0, 2, 1]));
+        let bob = Some(IpAddr::from([192, 0, 2, 2]));
+        let start = Instant::now();
+        assert!(guard.check_at(alice, None, start).is_ok());
+        // Another session from the same address shares the allowance.
+        let session = guard.clone();
+        assert!(session.check_at(alice, None, start).is_err());
+        assert!(session.check_at(bob, None, start).is_ok());
+        assert!(guard.check_at(bob, None, start).is_err());
+
+        // Refilled clients are forgotten when another one arrives.
+        let later = start + Duration::from_secs(60);
+        assert!(guard.check_at(None, None, later).is_ok());
+        assert_eq!(guard.buckets.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn check_caps_lists_and_result_counts() {
+        let guard = Guard::new(Limits {
+            max_list_items: Some(2),
+            max_results: Some(10),
+            ..Limits::default()
+        });
+        let check = |json: Value| guard.check(None, Some(&arguments(json)));
+        assert!(
+            check(serde_json::json!({"target_timezones": ["UTC", "Asia/Tokyo"], "count": 10}))
+                .is_ok()
+        );
+
+        let err =
+            check(serde_json::json!({"target_timezones": ["UTC", "Asia/Tokyo", "Europe/Paris"]}))
+                .unwrap_err();
+        assert_eq!(err.code, "too_many_items");
+        assert!(
+            err.message.starts_with("'target_timezones' has 3 items"),
+            "{}",
+            err.message
+        );
+
+        let err =
+            check(serde_json::json!({"events": [{"target_timezones": [1, 2, 3]}]})).unwrap_err();
+        assert!(
+            err.message.starts_with("'events[0].target_timezones'"),
+            "{}",
+            err.message
+        );
+
+        let err =
+            check(serde_json::json!({"timezone": "UTC, Asia/Tokyo, Europe/Paris"})).unwrap_err();
+        assert_eq!(err.code, "too_many_items");
+        assert_eq!(err.offending_value.as_deref(), Some("3"));
+        assert!(check(serde_json::json!({"timezone": "UTC, Asia/Tokyo,"})).is_ok());
+
+        let err = check(serde_json::json!({"count": 11})).unwrap_err();
+        assert_eq!(err.code, "too_many_results");
+        assert_eq!(err.offending_value.as_deref(), Some("11"));
+    }
+}
@@ -0,0 +1,177 @@
+use rmcp::{
+    ErrorData, RoleServer,
+    model::{CallToolResult, JsonObject, RawContent, Tool},
+    service::{RunningService, serve_directly},
+};
+use tokio::io::{
+    AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, Lines, ReadHalf, WriteHalf,
+};
+
+use crate::server::TimeServer;
+
+/// Calls tools on a [`TimeServer`] running in-process, without a client or
+/// MCP framing on the caller's side.
+///
+/// Calls go through the same routing as MCP requests, so disabled tools,
+/// parameter validation, and tracing behave exactly as they do when served.
+pub struct LocalClient {
+    /// Kept alive so the server keeps running until the client is dropped.
+    _service: RunningService<RoleServer, TimeServer>,
+    reader: Lines<BufReader<ReadHalf<DuplexStream>>>,
+    writer: WriteHalf<DuplexStream>,
+    next_id: u64,
+}
+
+impl LocalClient {
+    /// Start `server` in-process. Must be called within a Tokio runtime.
+    pub fn new(server: TimeServer) -> Self {
+        let (client, transport) = tokio::io::duplex(1 << 20);
+        let service = serve_directly(server, transport, None);
+        let (reader, writer) = tokio::io::split(client);
+        Self {
+            _service: service,
+            reader: BufReader::new(reader).lines(),
+            writer,
+            next_id: 0,
+        }
+    }
+
+    /// Call `tool` with `arguments`.
+    ///
+    /// Tool-level failures come back as a result with `is_error` set, as over
+    /// MCP; unknown tools and malformed arguments are errors.
+    pub async fn call(
+        &mut self,
+        tool: &str,
+        arguments: JsonObject,
+    ) -> Result<CallToolResult, ErrorData> {
+        let params = serde_json::json!({ "name": tool, "arguments": arguments });
+        let result = self.request("tools/call", params).await?;
+        serde_json::from_value(result).map_err(|e| ErrorData::internal_error(e.to_string(), None))
+    }
+
+    /// Every enabled tool, as listed to MCP clients.
+    pub async fn list_tools(&mut self) -> Result<Vec<Tool>, ErrorData> {
+        let result = self.request("tools/list", serde_json::json!({})).await?;
+        serde_json::from_value(result["tools"].clone())
+            .map_err(|e| ErrorData::internal_error(e.to_string(), None))
+    }
+
+    /// Send one JSON-RPC request and wait for its result.
+    async fn request(
+        &mut self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, ErrorData> {
+        self.next_id += 1;
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": self.next_id,
+            "method": method,
+            "params": params,
+        });
+        self.writer
+            .write_all(format!("{request}\n").as_bytes())
+            .await
+            .map_err(disconnected)?;
+
+        // Skip notifications (e.g., progress or scheduled messages) until the
+        // response to this request arrives.
+        loop {
+            let line = self
+                .reader
+                .next_line()
+                .await
+                .map_err(disconnected)?
+                .ok_or_else(|| disconnected("server stopped"))?;
+            let message: serde_json::Value = serde_json::from_str(&line)
+                .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
+            if message["id"] != self.next_id {
+                continue;
+            }
+            if let Some(error) = message.get("error") {
+                return Err(serde_json::from_value(error.clone())
+                    .unwrap_or_else(|e| ErrorData::internal_error(e.to_string(), None)));
+            }
+            return Ok(message["result"].clone());
+        }
+    }
+}
+
+/// Text content of a tool result, one block per line.
+pub fn result_text(result: &CallToolResult) -> String {
+    result
+        .content
+        .iter()
+        .filter_map(|content| match &content.raw {
+            RawContent::Text(text) => Some(text.text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn disconnected(e: impl std::fmt::Display) -> ErrorData {
+    ErrorData::internal_error(format!("In-process server unavailable: {e}"), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(value: serde_json::Value) -> JsonObject {
+        value.as_object().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn call_runs_tools_in_process() {
+        let mut client = LocalClient::new(TimeServer::new());
+        let result = client
+            .call(
+                "convert_time",
+                arguments(serde_json::json!({
+                    "source_timezone": "UTC",
+                    "time": "12:00",
+                    "target_timezone": "Asia/Tokyo",
+                })),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+
+        let result = client
+            .call(
+                "get_current_time",
+                arguments(serde_json::json!({"timezone": "Nowhere"})),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn list_tools_reports_enabled_tools() {
+        let server = TimeServer::new();
+        server.set_tool_enabled("wait", false).await.unwrap();
+        let tools = LocalClient::new(server).list_tools().await.unwrap();
+        assert!(tools.iter().any(|tool| tool.name == "convert_time"));
+        assert!(!tools.iter().any(|tool| tool.name == "wait"));
+    }
+
+    #[tokio::test]
+    async fn call_rejects_unknown_tools_and_bad_arguments() {
+        let mut client = LocalClient::new(TimeServer::new());
+        assert!(
+            client
+                .call("no_such_tool", JsonObject::new())
+                .await
+                .is_err()
+        );
+        assert!(
+            client
+                .call("convert_time", JsonObject::new())
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Number of timestamps falling on one local calendar day.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct LocalDay {
+    /// Local calendar date (YYYY-MM-DD).
+    pub date: String,
+    pub count: usize,
+    /// Length of the local day in hours: 24 normally, 23 or 25 on DST
+    /// transition days (or fractional values for zones like Australia/Lord_Howe).
+    pub hours_in_day: f64,
+    /// First instant of the local day, in UTC.
+    pub utc_start: String,
+    /// First instant of the following local day, in UTC (exclusive bound).
+    pub utc_end: String,
+}
+
+/// A timestamp that could not be parsed.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct InvalidTimestamp {
+    /// Zero-based position in the input list.
+    pub index: usize,
+    pub input: String,
+    pub error: String,
+}
+
+/// Result of grouping timestamps by local day.
+#[derive(Debug)]
+pub struct LocalDayGrouping {
+    pub days: Vec<LocalDay>,
+    pub invalid: Vec<InvalidTimestamp>,
+}
+
+/// Group RFC 3339 timestamps by their calendar day in `tz`.
+///
+/// Day boundaries are the zone's local midnights, so DST transition days
+/// cover 23 or 25 hours of UTC time rather than a fixed 24-hour window.
+/// With `include_empty_days`, days between the first and last populated day
+/// that contain no timestamps are reported with a count of zero.
+pub fn group_by_local_day(
+    inputs: &[String],
+    tz: &jiff::tz::TimeZone,
+    include_empty_days: bool,
+) -> Result<LocalDayGrouping, String> {
+    let mut counts: BTreeMap<jiff::civil::Date, usize> = BTreeMap::new();
+    let mut invalid = Vec::new();
+
+    for (index, input) in inputs.iter().enumerate() {
+        match input.trim().parse::<jiff::Timestamp>() {
+            Ok(ts) => *counts.entry(ts.to_zoned(tz.clone()).date()).or_default() += 1,
+            Err(_) => invalid.push(InvalidTimestamp {
+                index,
+                input: input.clone(),
+                error: "Expected an RFC 3339 timestamp with a UTC offset (e.g., '2025-03-09T12:00:00Z')."
+                    .into(),
+            }),
+        }
+    }
+
+    if include_empty_days {
+        if let (Some(&first), Some(&last)) = (counts.keys().next(), counts.keys().next_back()) {
+            let mut date = first;
+            while date < last {
+                date = date.tomorrow().map_err(|e| e.to_string())?;
+                counts.entry(date).or_default();
+            }
+        }
+    }
+
+    let days = counts
+        .into_iter()
+        .map(|(date, count)| local_day(date, count, tz))
+        .collect::<Result<_, _>>()?;
+
+    Ok(LocalDayGrouping { days, invalid })
+}
+
+/// Describe one local day, including its UTC bounds and length.
+fn local_day(
+    date: jiff::civil::Date,
+    count: usize,
+    tz: &jiff::tz::TimeZone,
+) -> Result<LocalDay, String> {
+    let start = date
+        .to_zoned(tz.clone())
+        .and_then(|zdt| zdt.start_of_day())
+        .map_err(|e| e.to_string())?;
+    let end = start.tomorrow().map_err(|e| e.to_string())?;
+    let end = end.start_of_day().map_err(|e| e.to_string())?;
+    let seconds = end.timestamp().as_second() - start.timestamp().as_second();
+
+    Ok(LocalDay {
+        date: date.to_string(),
+        count,
+        hours_in_day: seconds as f64 / 3600.0,
+        utc_start: start.timestamp().to_string(),
+        utc_end: end.timestamp().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn group_by_local_day_uses_local_midnight() {
+        let tz = jiff::tz::TimeZone::get("America/New_York").unwrap();
+        // 03:00Z on the 15th is still the 14th in New York
+        let grouping = group_by_local_day(
+            &inputs(&["2025-01-15T03:00:00Z", "2025-01-15T06:00:00Z"]),
+            &tz,
+            false,
+        )
+        .unwrap();
+        assert_eq!(grouping.days.len(), 2);
+        assert_eq!(grouping.days[0].date, "2025-01-14");
+        assert_eq!(grouping.days[1].date, "2025-01-15");
+        assert_eq!(grouping.days[1].utc_start, "2025-01-15T05:00:00Z");
+    }
+
+    #[test]
+    fn group_by_local_day_reports_short_and_long_dst_days() {
+        let tz = jiff::tz::TimeZone::get("America/New_York").unwrap();
+        let grouping = group_by_local_day(
+            &inputs(&["2025-03-09T12:00:00Z", "2025-11-02T12:00:00Z"]),
+            &tz,
+            false,
+        )
+        .unwrap();
+        assert_eq!(grouping.days[0].hours_in_day, 23.0);
+        assert_eq!(grouping.days[1].hours_in_day, 25.0);
+    }
+
+    #[test]
+    fn group_by_local_day_counts_late_night_events_on_long_day() {
+        let tz = jiff::tz::TimeZone::get("Europe/London").unwrap();
+        // 23:30 BST on 2025-10-25 is 22:30Z; 23:30 GMT on 2025-10-26 is 23:30Z
+        let grouping = group_by_local_day(
+            &inputs(&[
+                "2025-10-25T22:30:00Z",
+                "2025-10-25T23:30:00Z",
+                "2025-10-26T23:30:00Z",
+            ]),
+            &tz,
+            false,
+        )
+        .unwrap();
+        assert_eq!(grouping.days.len(), 2);
+        assert_eq!(grouping.days[0].count, 1);
+        assert_eq!(grouping.days[1].date, "2025-10-26");
+        assert_eq!(grouping.days[1].count, 2);
+        assert_eq!(grouping.days[1].hours_in_day, 25.0);
+    }
+
+    #[test]
+    fn group_by_local_day_fills_empty_days_when_requested() {
+        let tz = jiff::tz::TimeZone::UTC;
+        let grouping = group_by_local_day(
+            &inputs(&["2025-01-01T00:00:00Z", "2025-01-04T00:00:00Z"]),
+            &tz,
+            true,
+        )
+        .unwrap();
+        let counts: Vec<usize> = grouping.days.iter().map(|d| d.count).collect();
+        assert_eq!(counts, vec![1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn group_by_local_day_collects_invalid_entries() {
+        let grouping = group_by_local_day(
+            &inputs(&["2025-01-01T00:00:00Z", "yesterday", "2025-01-01T12:00:00"]),
+            &jiff::tz::TimeZone::UTC,
+            false,
+        )
+        .unwrap();
+        assert_eq!(grouping.days.len(), 1);
+        assert_eq!(grouping.invalid.len(), 2);
+        assert_eq!(grouping.invalid[0].index, 1);
+        assert_eq!(grouping.invalid[1].index, 2);
+    }
+}
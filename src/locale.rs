@@ -0,0 +1,511 @@
+/// Whether a locale writes times on a 12- or a 24-hour clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HourCycle {
+    /// "2:30 PM".
+    H12,
+    /// "14:30".
+    H23,
+}
+
+/// Month and weekday names and patterns from CLDR for one locale.
+///
+/// Patterns name their fields in braces: `{weekday}`, `{day}`, `{month}`,
+/// and `{year}` in dates; `{h}` (1-12), `{H}` (0-23), `{HH}` (00-23),
+/// `{mm}`, and `{a}` (AM or PM) in times; `{date}` and `{time}` when
+/// joining them.
+#[derive(Debug, PartialEq, Eq)]
+struct Names {
+    language: &'static str,
+    /// Regions this entry is for, besides being the language's default when
+    /// it is listed first.
+    regions: &'static [&'static str],
+    /// Monday first.
+    weekdays: [&'static str; 7],
+    months: [&'static str; 12],
+    date: &'static str,
+    time_12: &'static str,
+    time_24: &'static str,
+    join: &'static str,
+    /// Before and after noon.
+    day_periods: [&'static str; 2],
+    hour_cycle: HourCycle,
+}
+
+/// A locale datetimes can be written out in, such as "de-DE".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    names: &'static Names,
+    hour_cycle: HourCycle,
+}
+
+impl Locale {
+    /// Parse a BCP 47 tag such as "de-DE", "en_GB", or "ja".
+    ///
+    /// The language picks the names, the region (where it matters) the date
+    /// order and clock, and a `-u-hc-` extension overrides the clock (e.g.,
+    /// "en-US-u-hc-h23").
+    pub fn parse(tag: &str) -> Result<Locale, String> {
+        let normalized = tag.trim().replace('_', "-");
+        let mut subtags = normalized.split('-');
+        let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+        let mut region = None;
+        let mut hour_cycle = None;
+        while let Some(subtag) = subtags.next() {
+            if subtag.eq_ignore_ascii_case("u") {
+                while let Some(key) = subtags.next() {
+                    if key.eq_ignore_ascii_case("hc") {
+                        hour_cycle = match subtags.next().map(str::to_ascii_lowercase).as_deref() {
+                            Some("h11" | "h12") => Some(HourCycle::H12),
+                            Some("h23" | "h24") => Some(HourCycle::H23),
+                            _ => {
+                                return Err(format!(
+                                    "Invalid hour cycle in locale '{tag}'. Expected 'h12' or 'h23'."
+                                ));
+                            }
+                        };
+                    }
+                }
+                break;
+            }
+            // Skip script subtags such as "Hant".
+            if region.is_none() && (subtag.len() == 2 || subtag.len() == 3) {
+                region = Some(subtag.to_ascii_uppercase());
+            }
+        }
+        let names = lookup(&language, region.as_deref()).ok_or_else(|| {
+            format!(
+                "Unsupported locale: '{tag}'. Supported languages: {}.",
+                supported_languages()
+            )
+        })?;
+        Ok(Locale {
+            names,
+            hour_cycle: hour_cycle.unwrap_or(names.hour_cycle),
+        })
+    }
+
+    /// `zdt` written out in this locale (e.g., "Dienstag, 5. März 2025,
+    /// 14:30" for "de-DE").
+    pub fn format(&self, zdt: &jiff::Zoned) -> String {
+        let names = self.names;
+        let date = fill(names.date, |field| match field {
+            "weekday" => names.weekdays[zdt.weekday().to_monday_zero_offset() as usize].into(),
+            "day" => zdt.day().to_string(),
+            "month" => names.months[zdt.month() as usize - 1].into(),
+            "year" => zdt.year().to_string(),
+            _ => String::new(),
+        });
+        let hour = zdt.hour();
+        let time = fill(
+            match self.hour_cycle {
+                HourCycle::H12 => names.time_12,
+                HourCycle::H23 => names.time_24,
+            },
+            |field| match field {
+                "h" => (if hour % 12 == 0 { 12 } else { hour % 12 }).to_string(),
+                "H" => hour.to_string(),
+                "HH" => format!("{hour:02}"),
+                "mm" => format!("{:02}", zdt.minute()),
+                "a" => names.day_periods[usize::from(hour >= 12)].into(),
+                _ => String::new(),
+            },
+        );
+        fill(names.join, |field| match field {
+            "date" => date.clone(),
+            "time" => time.clone(),
+            _ => String::new(),
+        })
+    }
+}
+
+/// `pattern` with each `{field}` replaced by `value(field)`.
+fn fill(pattern: &str, value: impl Fn(&str) -> String) -> String {
+    let mut out = String::new();
+    let mut rest = pattern;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        out.push_str(&value(&rest[open + 1..open + close]));
+        rest = &rest[open + close + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The entry for `region` of `language`, or the language's default.
+fn lookup(language: &str, region: Option<&str>) -> Option<&'static Names> {
+    let entries = || LOCALES.iter().filter(|names| names.language == language);
+    region
+        .and_then(|region| entries().find(|names| names.regions.contains(&region)))
+        .or_else(|| entries().next())
+}
+
+/// Languages with localized formatting, for error messages.
+fn supported_languages() -> String {
+    let mut languages: Vec<&str> = LOCALES.iter().map(|names| names.language).collect();
+    languages.dedup();
+    if languages.is_empty() {
+        return "none (this build leaves out the 'locale' feature)".to_string();
+    }
+    languages.join(", ")
+}
+
+#[cfg(not(feature = "locale"))]
+const LOCALES: &[Names] = &[];
+
+#[cfg(feature = "locale")]
+const EN_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+#[cfg(feature = "locale")]
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Numeric months for the CJK "2025年3月5日" dates.
+#[cfg(feature = "locale")]
+const CJK_MONTHS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+#[cfg(feature = "locale")]
+const PT_WEEKDAYS: [&str; 7] = [
+    "segunda-feira",
+    "terça-feira",
+    "quarta-feira",
+    "quinta-feira",
+    "sexta-feira",
+    "sábado",
+    "domingo",
+];
+
+#[cfg(feature = "locale")]
+const PT_MONTHS: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+
+/// Supported locales, grouped by language with each language's default
+/// first.
+#[cfg(feature = "locale")]
+const LOCALES: &[Names] = &[
+    Names {
+        language: "de",
+        regions: &[],
+        weekdays: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        date: "{weekday}, {day}. {month} {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{HH}:{mm}",
+        join: "{date}, {time}",
+        day_periods: ["AM", "PM"],
+        hour_cycle: HourCycle::H23,
+    },
+    Names {
+        language: "en",
+        regions: &["US"],
+        weekdays: EN_WEEKDAYS,
+        months: EN_MONTHS,
+        date: "{weekday}, {month} {day}, {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{HH}:{mm}",
+        join: "{date} at {time}",
+        day_periods: ["AM", "PM"],
+        hour_cycle: HourCycle::H12,
+    },
+    Names {
+        language: "en",
+        regions: &["GB", "IE"],
+        weekdays: EN_WEEKDAYS,
+        months: EN_MONTHS,
+        date: "{weekday} {day} {month} {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{HH}:{mm}",
+        join: "{date} at {time}",
+        day_periods: ["am", "pm"],
+        hour_cycle: HourCycle::H23,
+    },
+    Names {
+        language: "en",
+        regions: &["AU", "NZ", "IN"],
+        weekdays: EN_WEEKDAYS,
+        months: EN_MONTHS,
+        date: "{weekday} {day} {month} {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{HH}:{mm}",
+        join: "{date} at {time}",
+        day_periods: ["am", "pm"],
+        hour_cycle: HourCycle::H12,
+    },
+    Names {
+        language: "es",
+        regions: &[],
+        weekdays: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        date: "{weekday}, {day} de {month} de {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{H}:{mm}",
+        join: "{date}, {time}",
+        day_periods: ["a. m.", "p. m."],
+        hour_cycle: HourCycle::H23,
+    },
+    Names {
+        language: "fr",
+        regions: &[],
+        weekdays: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        date: "{weekday} {day} {month} {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{HH}:{mm}",
+        join: "{date} à {time}",
+        day_periods: ["AM", "PM"],
+        hour_cycle: HourCycle::H23,
+    },
+    Names {
+        language: "it",
+        regions: &[],
+        weekdays: [
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+            "domenica",
+        ],
+        months: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        date: "{weekday} {day} {month} {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{HH}:{mm}",
+        join: "{date} alle ore {time}",
+        day_periods: ["AM", "PM"],
+        hour_cycle: HourCycle::H23,
+    },
+    Names {
+        language: "ja",
+        regions: &[],
+        weekdays: [
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        months: CJK_MONTHS,
+        date: "{year}年{month}{day}日{weekday}",
+        time_12: "{a}{h}:{mm}",
+        time_24: "{H}:{mm}",
+        join: "{date} {time}",
+        day_periods: ["午前", "午後"],
+        hour_cycle: HourCycle::H23,
+    },
+    Names {
+        language: "nl",
+        regions: &[],
+        weekdays: [
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+            "zondag",
+        ],
+        months: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        date: "{weekday} {day} {month} {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{HH}:{mm}",
+        join: "{date} om {time}",
+        day_periods: ["a.m.", "p.m."],
+        hour_cycle: HourCycle::H23,
+    },
+    Names {
+        language: "pt",
+        regions: &[],
+        weekdays: PT_WEEKDAYS,
+        months: PT_MONTHS,
+        date: "{weekday}, {day} de {month} de {year}",
+        time_12: "{h}:{mm} {a}",
+        time_24: "{HH}:{mm}",
+        join: "{date} às {time}",
+        day_periods: ["AM", "PM"],
+        hour_cycle: HourCycle::H23,
+    },
+    Names {
+        language: "zh",
+        regions: &[],
+        weekdays: [
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+            "星期日",
+        ],
+        months: CJK_MONTHS,
+        date: "{year}年{month}{day}日{weekday}",
+        time_12: "{a}{h}:{mm}",
+        time_24: "{HH}:{mm}",
+        join: "{date} {time}",
+        day_periods: ["上午", "下午"],
+        hour_cycle: HourCycle::H23,
+    },
+];
+
+#[cfg(all(test, feature = "locale"))]
+mod tests {
+    use super::*;
+
+    fn format(tag: &str, datetime: &str) -> String {
+        let zdt: jiff::Zoned = datetime.parse().unwrap();
+        Locale::parse(tag).unwrap().format(&zdt)
+    }
+
+    #[test]
+    fn format_follows_each_locale() {
+        let at = "2025-03-04T14:30:00+01:00[Europe/Berlin]";
+        assert_eq!(format("de-DE", at), "Dienstag, 4. März 2025, 14:30");
+        assert_eq!(format("en-US", at), "Tuesday, March 4, 2025 at 2:30 PM");
+        assert_eq!(format("en_GB", at), "Tuesday 4 March 2025 at 14:30");
+        assert_eq!(format("en-AU", at), "Tuesday 4 March 2025 at 2:30 pm");
+        assert_eq!(format("fr-CA", at), "mardi 4 mars 2025 à 14:30");
+        assert_eq!(format("es", at), "martes, 4 de marzo de 2025, 14:30");
+        assert_eq!(format("ja-JP", at), "2025年3月4日火曜日 14:30");
+        assert_eq!(format("zh-Hant-TW", at), "2025年3月4日星期二 14:30");
+    }
+
+    #[test]
+    fn parse_reads_hour_cycle_extensions() {
+        let midnight = "2025-03-04T00:05:00+00:00[UTC]";
+        assert_eq!(
+            format("en-US-u-hc-h23", midnight),
+            "Tuesday, March 4, 2025 at 00:05"
+        );
+        assert_eq!(
+            format("ja-u-hc-h12", midnight),
+            "2025年3月4日火曜日 午前12:05"
+        );
+        assert!(Locale::parse("en-u-hc-h99").is_err());
+        assert!(
+            Locale::parse("xx-YY")
+                .unwrap_err()
+                .contains("Supported languages: de, en, es")
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// tz database `zone1970.tab`: zones with distinct history since 1970, the
+/// countries that use each, and a representative location.
+const ZONE1970_TAB: &str = include_str!("../data/zone1970.tab");
+
+/// tz database `zone.tab`: one row per country and zone, including zones
+/// that `zone1970.tab` folds into another.
+const ZONE_TAB: &str = include_str!("../data/zone.tab");
+
+/// tz database `iso3166.tab`: country names by ISO 3166 alpha-2 code.
+const ISO3166_TAB: &str = include_str!("../data/iso3166.tab");
+
+/// tz database links from `tzdata.zi`: legacy and renamed names (e.g.,
+/// "US/Eastern", "Asia/Calcutta") and the zone each points to.
+const LINKS_ZI: &str = include_str!("../data/links.zi");
+
+/// A point in decimal degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, JsonSchema)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Where a zone is used, according to the tz database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneLocation {
+    pub zone: String,
+    /// ISO 3166 codes of the countries using the zone, principal one first.
+    pub countries: Vec<String>,
+    /// Location of the zone's principal city.
+    pub coordinates: Coordinates,
+    /// Region the zone covers within its countries, when they have several.
+    pub comment: Option<String>,
+}
+
+/// A nearby zone, by distance between representative locations.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct NeighborZone {
+    pub timezone: String,
+    pub countries: Vec<String>,
+    pub distance_km: f64,
+}
+
+/// Location data for `zone`, or `None` for zones not tied to a place (e.g.,
+/// "UTC", "Etc/GMT+5").
+///
+/// Links are followed, so "US/Eastern" finds "America/New_York".
+pub fn location(zone: &str) -> Option<&'static ZoneLocation> {
+    locations().get(canonical(zone))
+}
+
+/// The zone the tz database link `name` points to, or `name` itself when it
+/// is not a link.
+pub fn canonical(name: &str) -> &str {
+    static LINKS: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    LINKS
+        .get_or_init(|| {
+            LINKS_ZI
+                .lines()
+                .filter_map(
+                    |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                        ["L", zone, link] => Some((link, zone)),
+                        _ => None,
+                    },
+                )
+                .collect()
+        })
+        .get(name)
+        .copied()
+        .unwrap_or(name)
+}
+
+/// Name of the country with ISO 3166 code `code`.
+pub fn country_name(code: &str) -> Option<&'static str> {
+    static NAMES: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    NAMES
+        .get_or_init(|| {
+            rows(ISO3166_TAB)
+                .filter_map(|fields| Some((*fields.first()?, *fields.get(1)?)))
+                .collect()
+        })
+        .get(code)
+        .copied()
+}
+
+/// Up to `limit` zones closest to `zone`, nearest first.
+///
+/// Distances are great-circle distances between the zones' principal cities,
+/// so they approximate rather than trace shared borders.
+pub fn neighbors(zone: &ZoneLocation, limit: usize) -> Vec<NeighborZone> {
+    let mut neighbors: Vec<NeighborZone> = locations()
+        .values()
+        .filter(|other| other.zone != zone.zone)
+        .map(|other| NeighborZone {
+            timezone: other.zone.clone(),
+            countries: other.countries.clone(),
+            distance_km: distance_km(zone.coordinates, other.coordinates).round(),
+        })
+        .collect();
+    neighbors.sort_by(|a, b| {
+        a.distance_km
+            .total_cmp(&b.distance_km)
+            .then_with(|| a.timezone.cmp(&b.timezone))
+    });
+    neighbors.truncate(limit);
+    neighbors
+}
+
+/// The zone whose principal city is closest to `point`.
+pub fn nearest(point: Coordinates) -> Option<&'static ZoneLocation> {
+    locations().values().min_by(|a, b| {
+        distance_km(point, a.coordinates)
+            .total_cmp(&distance_km(point, b.coordinates))
+            .then_with(|| a.zone.cmp(&b.zone))
+    })
+}
+
+/// Every zone with location data, keyed by name.
+fn locations() -> &'static HashMap<String, ZoneLocation> {
+    static LOCATIONS: OnceLock<HashMap<String, ZoneLocation>> = OnceLock::new();
+    LOCATIONS.get_or_init(|| {
+        let mut locations = HashMap::new();
+        for table in [ZONE1970_TAB, ZONE_TAB] {
+            for fields in rows(table) {
+                let (Some(codes), Some(coordinates), Some(zone)) =
+                    (fields.first(), fields.get(1), fields.get(2))
+                else {
+                    continue;
+                };
+                let Some(coordinates) = parse_iso6709(coordinates) else {
+                    continue;
+                };
+                // zone1970.tab comes first and lists every country for a
+                // zone; zone.tab only fills in zones it does not have.
+                locations
+                    .entry(zone.to_string())
+                    .or_insert_with(|| ZoneLocation {
+                        zone: zone.to_string(),
+                        countries: codes.split(',').map(str::to_string).collect(),
+                        coordinates,
+                        comment: fields.get(3).map(|c| c.to_string()),
+                    });
+            }
+        }
+        locations
+    })
+}
+
+/// Tab-separated fields of each non-comment line.
+fn rows(table: &'static str) -> impl Iterator<Item = Vec<&'static str>> {
+    table
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .map(|line| line.split('\t').collect())
+}
+
+/// Parse ISO 6709 coordinates as written in the tz tables: `±DDMM±DDDMM` or
+/// `±DDMMSS±DDDMMSS`.
+fn parse_iso6709(input: &str) -> Option<Coordinates> {
+    let split = input.get(1..)?.find(['+', '-'])? + 1;
+    let (latitude, longitude) = input.split_at(split);
+    Some(Coordinates {
+        latitude: parse_degrees(latitude, 2)?,
+        longitude: parse_degrees(longitude, 3)?,
+    })
+}
+
+/// Parse `±D…DMM[SS]` with `degree_digits` digits of degrees.
+fn parse_degrees(input: &str, degree_digits: usize) -> Option<f64> {
+    let sign = match input.as_bytes().first()? {
+        b'+' => 1.0,
+        b'-' => -1.0,
+        _ => return None,
+    };
+    let digits = input.get(1..)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let part = |range: std::ops::Range<usize>| digits.get(range)?.parse::<f64>().ok();
+    let degrees = part(0..degree_digits)?;
+    let minutes = part(degree_digits..degree_digits + 2)?;
+    let seconds = match digits.len() - degree_digits {
+        2 => 0.0,
+        4 => part(degree_digits + 2..degree_digits + 4)?,
+        _ => return None,
+    };
+    // Round to four decimal places (about 10 m), since the tables are given
+    // to the nearest minute or second.
+    let value = degrees + minutes / 60.0 + seconds / 3600.0;
+    Some(sign * (value * 10_000.0).round() / 10_000.0)
+}
+
+/// Great-circle distance in kilometres (haversine formula).
+fn distance_km(a: Coordinates, b: Coordinates) -> f64 {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+    let (lat1, lat2) = (a.latitude.to_radians(), b.latitude.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (b.longitude - a.longitude).to_radians();
+    let h = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_lists_countries_sharing_a_zone() {
+        let dubai = location("Asia/Dubai").unwrap();
+        assert_eq!(dubai.countries[0], "AE");
+        assert!(dubai.countries.contains(&"OM".to_string()));
+
+        let oslo = location("Europe/Oslo").unwrap();
+        assert_eq!(oslo.countries, vec!["NO"]);
+        assert!(location("UTC").is_none());
+        assert!(location("Etc/GMT+5").is_none());
+    }
+
+    #[test]
+    fn location_follows_links_to_their_zone() {
+        assert_eq!(canonical("US/Eastern"), "America/New_York");
+        assert_eq!(canonical("Asia/Calcutta"), "Asia/Kolkata");
+        assert_eq!(canonical("Europe/Paris"), "Europe/Paris");
+        assert_eq!(location("US/Eastern").unwrap().zone, "America/New_York");
+        assert!(location("GMT").is_none());
+    }
+
+    #[test]
+    fn parse_iso6709_reads_minutes_and_seconds() {
+        assert_eq!(
+            parse_iso6709("+4230+00131"),
+            Some(Coordinates {
+                latitude: 42.5,
+                longitude: 1.5167
+            })
+        );
+        assert_eq!(
+            parse_iso6709("-3352+15113"),
+            Some(Coordinates {
+                latitude: -33.8667,
+                longitude: 151.2167
+            })
+        );
+        assert_eq!(
+            parse_iso6709("+404251-0740023"),
+            Some(Coordinates {
+                latitude: 40.7142,
+                longitude: -74.0064
+            })
+        );
+        assert_eq!(parse_iso6709("+40-07"), None);
+    }
+
+    #[test]
+    fn neighbors_are_nearest_first() {
+        let berlin = location("Europe/Berlin").unwrap();
+        let neighbors = neighbors(berlin, 5);
+        assert_eq!(neighbors.len(), 5);
+        assert!(
+            neighbors
+                .windows(2)
+                .all(|w| w[0].distance_km <= w[1].distance_km)
+        );
+        assert!(neighbors.iter().any(|n| n.timezone == "Europe/Prague"));
+    }
+
+    #[test]
+    fn nearest_finds_the_closest_principal_city() {
+        let boulder = Coordinates {
+            latitude: 40.015,
+            longitude: -105.27,
+        };
+        assert_eq!(nearest(boulder).unwrap().zone, "America/Denver");
+        let orleans = Coordinates {
+            latitude: 47.90,
+            longitude: 1.90,
+        };
+        assert_eq!(nearest(orleans).unwrap().zone, "Europe/Paris");
+    }
+
+    #[test]
+    fn country_name_reads_iso3166_table() {
+        assert_eq!(country_name("DE"), Some("Germany"));
+        assert_eq!(country_name("ZZ"), None);
+    }
+}
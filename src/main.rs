@@ -1,16 +1,199 @@
-use mcp_time::server::TimeServer;
-use rmcp::{ServiceExt, transport::stdio};
-use tracing_subscriber::EnvFilter;
+use std::io::IsTerminal;
+
+use clap::{Parser, Subcommand};
+use mcp_time::{
+    batch, clock,
+    config::{self, ServerConfig},
+    local::{self, LocalClient},
+    repl,
+    server::TimeServer,
+    telemetry::LogFormat,
+    transport::{self, Transport, TransportError},
+};
+use rmcp::{ServerHandler, model::JsonObject, transport::stdio};
+
+/// MCP server providing time and timezone tools over stdio or HTTP.
+///
+/// Without a subcommand, serves MCP on stdin/stdout, or over Streamable
+/// HTTP with `--transport http`, until the client disconnects or Ctrl-C or
+/// SIGTERM arrives, then exits with status 0; a transport failure exits with
+/// status 74. Configuration is read from `mcp-time.toml` (or the file named
+/// by `--config`), then the `MCP_TIME_*` environment variables, then these
+/// flags, in every mode.
+#[derive(Parser)]
+#[command(version, args_conflicts_with_subcommands = true)]
+struct Cli {
+    /// Print the tool catalog (names, descriptions, input and output JSON
+    /// schemas, and annotations) as JSON and exit.
+    #[arg(long)]
+    describe_tools: bool,
+    /// How to serve MCP when no subcommand is given: stdio
+    /// (newline-delimited JSON-RPC on stdin/stdout, the default) or http
+    /// (Streamable HTTP for multiple network clients).
+    #[arg(long, value_name = "TRANSPORT", value_parser = Transport::parse)]
+    transport: Option<Transport>,
+    /// Address to listen on with `--transport http` (default
+    /// 127.0.0.1:8080). The endpoint is served at /mcp.
+    #[arg(long, value_parser = transport::parse_bind)]
+    bind: Option<std::net::SocketAddr>,
+    /// How log lines are written to stderr: text (the default) or json.
+    #[arg(long, value_name = "FORMAT", value_parser = LogFormat::parse)]
+    log_format: Option<LogFormat>,
+    /// TOML configuration file to read instead of `mcp-time.toml` in the
+    /// working directory. Takes precedence over `MCP_TIME_CONFIG`.
+    #[arg(long, global = true, value_name = "PATH")]
+    config: Option<std::path::PathBuf>,
+    /// IANA timezone used when a tool's timezone is omitted (default UTC),
+    /// or "system" for this machine's zone. Takes precedence over
+    /// `MCP_TIME_DEFAULT_TIMEZONE`.
+    #[arg(long, global = true, value_name = "ZONE", value_parser = config::parse_default_timezone)]
+    default_timezone: Option<jiff::tz::TimeZone>,
+    /// JSON file of holiday definitions to add to the bundled ones (e.g.,
+    /// company holidays). Takes precedence over `MCP_TIME_HOLIDAYS_FILE`.
+    #[arg(long, global = true, value_name = "PATH")]
+    holidays_file: Option<std::path::PathBuf>,
+    /// Pin the server's clock to an RFC 3339 instant (e.g.,
+    /// 2025-07-04T12:00:00Z), so every "now" answers with it. Takes
+    /// precedence over `MCP_TIME_FREEZE_TIME`.
+    #[arg(long, global = true, value_name = "RFC3339", value_parser = clock::parse_freeze_time)]
+    freeze_time: Option<clock::FixedClock>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one tool and print its result, without speaking MCP.
+    ///
+    /// Exits with status 1 when the tool reports an error.
+    Call {
+        /// Tool name (e.g., convert_time).
+        tool: String,
+        /// Tool parameters as a JSON object.
+        #[arg(long, default_value = "{}")]
+        json: String,
+    },
+    /// Explore the tools from an interactive prompt.
+    Repl,
+    /// Run newline-delimited `{"tool": ..., "params": ...}` requests from
+    /// stdin, writing one JSON result per line to stdout.
+    Batch,
+    /// Validate a TOML configuration file or an environment file of
+    /// `MCP_TIME_*` settings.
+    ///
+    /// Reports each problem with its line and exits with status 1 if any
+    /// are found.
+    CheckConfig {
+        /// A `.toml` configuration file (e.g., mcp-time.toml), or an
+        /// environment file of KEY=value lines (e.g., a systemd
+        /// EnvironmentFile or Docker --env-file).
+        path: std::path::PathBuf,
+        /// Read the file as TOML whatever its extension.
+        #[arg(long)]
+        toml: bool,
+    },
+}
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::from_default_env())
-        .with_writer(std::io::stderr)
-        .init();
+    let cli = Cli::parse();
+    // Not read for check-config, which reports bad settings itself.
+    let config = || {
+        let mut config = ServerConfig::load(cli.config.as_deref()).map_err(anyhow::Error::msg)?;
+        if let Some(tz) = &cli.default_timezone {
+            config.default_timezone = tz.clone();
+        }
+        if let Some(path) = &cli.holidays_file {
+            config.holidays_file = Some(path.clone());
+        }
+        if let Some(clock) = cli.freeze_time {
+            config.freeze_time = Some(clock);
+        }
+        config.transport = cli.transport.unwrap_or(config.transport);
+        config.bind = cli.bind.unwrap_or(config.bind);
+        config.log_format = cli.log_format.unwrap_or(config.log_format);
+        Ok::<_, anyhow::Error>(config)
+    };
+    let server = || TimeServer::with_config(&config()?).map_err(anyhow::Error::msg);
+
+    if cli.describe_tools {
+        let server = server()?;
+        let info = server.get_info();
+        let tools = LocalClient::new(server).list_tools().await?;
+        let catalog = serde_json::json!({
+            "server": info.server_info,
+            "protocolVersion": info.protocol_version,
+            "tools": tools,
+        });
+        println!("{}", serde_json::to_string_pretty(&catalog)?);
+        return Ok(());
+    }
 
-    let service = TimeServer::new().serve(stdio()).await?;
-    service.waiting().await?;
+    match cli.command {
+        None => {
+            let config = config()?;
+            let server = TimeServer::with_config(&config).map_err(anyhow::Error::msg)?;
+            let served = match config.transport {
+                Transport::Stdio => server.run(stdio()).await,
+                Transport::Http => transport::serve_http(server, config.bind).await,
+            };
+            match served {
+                Err(e) if e.is::<TransportError>() => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(transport::EXIT_TRANSPORT_ERROR);
+                }
+                served => served,
+            }
+        }
+        Some(Command::Call { tool, json }) => {
+            let arguments: JsonObject = serde_json::from_str(&json)
+                .map_err(|e| anyhow::anyhow!("--json must be a JSON object: {e}"))?;
+            let result = LocalClient::new(server()?).call(&tool, arguments).await?;
+            println!("{}", local::result_text(&result));
+            if result.is_error == Some(true) {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Some(Command::Repl) => {
+            let mut client = LocalClient::new(server()?);
+            if std::io::stdin().is_terminal() {
+                repl::run_editor(&mut client).await?;
+            } else {
+                let input = tokio::io::BufReader::new(tokio::io::stdin());
+                repl::run(&mut client, input, tokio::io::stdout()).await?;
+            }
+            Ok(())
+        }
+        Some(Command::Batch) => {
+            let mut client = LocalClient::new(server()?);
+            let input = tokio::io::BufReader::new(tokio::io::stdin());
+            batch::run(&mut client, input, tokio::io::stdout()).await?;
+            Ok(())
+        }
+        Some(Command::CheckConfig { path, toml }) => check_config(&path, toml),
+    }
+}
 
-    Ok(())
+/// Print every issue in the configuration file at `path`, read as TOML when
+/// `toml` is set or it has a `.toml` extension and as an environment file
+/// otherwise, exiting with status 1 if there are any.
+fn check_config(path: &std::path::Path, toml: bool) -> anyhow::Result<()> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", path.display()))?;
+    let issues = if toml || path.extension().is_some_and(|ext| ext == "toml") {
+        config::check_toml_file(&contents)
+    } else {
+        config::check_env_file(&contents)
+    };
+    if issues.is_empty() {
+        println!("{}: OK", path.display());
+        return Ok(());
+    }
+    for issue in &issues {
+        eprintln!("{}:{}: {}", path.display(), issue.line, issue.message);
+        eprintln!("    | {}", issue.text);
+    }
+    eprintln!("{} problem(s) found.", issues.len());
+    std::process::exit(1);
 }
@@ -0,0 +1,236 @@
+use jiff::{SignedDuration, Timestamp, civil, tz::TimeZone};
+
+/// A span of time from `start` (inclusive) to `end` (exclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub start: Timestamp,
+    pub end: Timestamp,
+}
+
+impl Interval {
+    pub fn duration(&self) -> SignedDuration {
+        self.start.duration_until(self.end)
+    }
+
+    fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+}
+
+/// The local working hours of one participant zone.
+///
+/// An `end` at or before `start` is an overnight shift that ends on the
+/// following day.
+#[derive(Debug, Clone)]
+pub struct WorkingHours {
+    pub tz: TimeZone,
+    pub start: civil::Time,
+    pub end: civil::Time,
+    /// Whether Saturdays and Sundays, in the zone, are working days.
+    pub weekends: bool,
+}
+
+impl WorkingHours {
+    /// The working intervals that overlap `window`, clipped to it.
+    ///
+    /// Local times that DST skips or repeats resolve the way calendar apps
+    /// resolve them: to the later side of a gap and the first instance of a
+    /// fold.
+    pub fn intervals(&self, window: Interval) -> Result<Vec<Interval>, jiff::Error> {
+        // A shift that started the day before can reach into the window.
+        let first = window.start.to_zoned(self.tz.clone()).date().yesterday()?;
+        let last = window.end.to_zoned(self.tz.clone()).date();
+        let mut intervals = Vec::new();
+        for date in first.series(jiff::Span::new().days(1)) {
+            if date > last {
+                break;
+            }
+            if !self.weekends && date.weekday().to_monday_one_offset() > 5 {
+                continue;
+            }
+            let end_date = if self.end <= self.start {
+                date.tomorrow()?
+            } else {
+                date
+            };
+            let start = date.to_datetime(self.start).to_zoned(self.tz.clone())?;
+            let end = end_date.to_datetime(self.end).to_zoned(self.tz.clone())?;
+            let interval = Interval {
+                start: start.timestamp().max(window.start),
+                end: end.timestamp().min(window.end),
+            };
+            if interval.start < interval.end {
+                intervals.push(interval);
+            }
+        }
+        Ok(intervals)
+    }
+}
+
+/// The stretches of time that lie in both `a` and `b`, each sorted and
+/// non-overlapping.
+pub fn intersect(a: &[Interval], b: &[Interval]) -> Vec<Interval> {
+    let (mut i, mut j) = (0, 0);
+    let mut shared = Vec::new();
+    while i < a.len() && j < b.len() {
+        let start = a[i].start.max(b[j].start);
+        let end = a[i].end.min(b[j].end);
+        if start < end {
+            shared.push(Interval { start, end });
+        }
+        if a[i].end < b[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    shared
+}
+
+/// A possible meeting time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub slot: Interval,
+    /// The smallest gap between the meeting and the start or end of any
+    /// participant's working hours. Larger gaps keep everyone away from
+    /// the edges of their day.
+    pub margin: SignedDuration,
+}
+
+/// Meeting slots of `duration` inside the shared `windows`, starting every
+/// `step` from the start of each window, ranked by margin and then by
+/// start time.
+///
+/// `schedules` holds each participant's working intervals, which the
+/// margin is measured against.
+pub fn candidates(
+    windows: &[Interval],
+    schedules: &[Vec<Interval>],
+    duration: SignedDuration,
+    step: SignedDuration,
+) -> Vec<Candidate> {
+    let mut found = Vec::new();
+    for window in windows {
+        let mut start = window.start;
+        while let Ok(end) = start.checked_add(duration) {
+            if end > window.end {
+                break;
+            }
+            let slot = Interval { start, end };
+            let margin = schedules
+                .iter()
+                .filter_map(|intervals| intervals.iter().find(|i| i.contains(&slot)))
+                .map(|i| {
+                    i.start
+                        .duration_until(slot.start)
+                        .min(slot.end.duration_until(i.end))
+                })
+                .min()
+                .unwrap_or(SignedDuration::ZERO);
+            found.push(Candidate { slot, margin });
+            match start.checked_add(step) {
+                Ok(next) => start = next,
+                Err(_) => break,
+            }
+        }
+    }
+    found.sort_by(|a, b| {
+        b.margin
+            .cmp(&a.margin)
+            .then(a.slot.start.cmp(&b.slot.start))
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hours(zone: &str, start: i8, end: i8) -> WorkingHours {
+        WorkingHours {
+            tz: TimeZone::get(zone).unwrap(),
+            start: civil::time(start, 0, 0, 0),
+            end: civil::time(end, 0, 0, 0),
+            weekends: false,
+        }
+    }
+
+    fn interval(start: &str, end: &str) -> Interval {
+        Interval {
+            start: start.parse().unwrap(),
+            end: end.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn intervals_follow_local_days_and_skip_weekends() {
+        // Friday 2025-03-07 through Monday 2025-03-10 in UTC; New York
+        // springs forward on Sunday 2025-03-09.
+        let window = interval("2025-03-07T00:00:00Z", "2025-03-11T00:00:00Z");
+        let new_york = hours("America/New_York", 9, 17).intervals(window).unwrap();
+        assert_eq!(
+            new_york,
+            vec![
+                interval("2025-03-07T14:00:00Z", "2025-03-07T22:00:00Z"),
+                interval("2025-03-10T13:00:00Z", "2025-03-10T21:00:00Z"),
+            ]
+        );
+
+        let night = hours("UTC", 22, 6).intervals(window).unwrap();
+        assert_eq!(
+            night[0],
+            interval("2025-03-07T00:00:00Z", "2025-03-07T06:00:00Z")
+        );
+        assert_eq!(
+            night[1],
+            interval("2025-03-07T22:00:00Z", "2025-03-08T06:00:00Z")
+        );
+    }
+
+    #[test]
+    fn intersect_keeps_only_shared_time() {
+        let a = [
+            interval("2025-03-10T08:00:00Z", "2025-03-10T12:00:00Z"),
+            interval("2025-03-10T14:00:00Z", "2025-03-10T18:00:00Z"),
+        ];
+        let b = [interval("2025-03-10T11:00:00Z", "2025-03-10T15:00:00Z")];
+        assert_eq!(
+            intersect(&a, &b),
+            vec![
+                interval("2025-03-10T11:00:00Z", "2025-03-10T12:00:00Z"),
+                interval("2025-03-10T14:00:00Z", "2025-03-10T15:00:00Z"),
+            ]
+        );
+        assert_eq!(intersect(&a, &[]), vec![]);
+    }
+
+    #[test]
+    fn candidates_rank_slots_away_from_the_edges_of_the_day() {
+        let window = interval("2025-03-10T00:00:00Z", "2025-03-11T00:00:00Z");
+        let schedules: Vec<Vec<Interval>> = [
+            hours("Europe/London", 9, 17),
+            hours("America/New_York", 9, 17),
+        ]
+        .iter()
+        .map(|hours| hours.intervals(window).unwrap())
+        .collect();
+        let shared = intersect(&schedules[0], &schedules[1]);
+        assert_eq!(
+            shared,
+            vec![interval("2025-03-10T13:00:00Z", "2025-03-10T17:00:00Z")]
+        );
+
+        let found = candidates(
+            &shared,
+            &schedules,
+            SignedDuration::from_hours(1),
+            SignedDuration::from_mins(30),
+        );
+        assert_eq!(found.len(), 7);
+        // 14:30 UTC is 10:30 in New York and 14:30 in London: at least 1.5
+        // hours from either end of both days.
+        assert_eq!(found[0].slot.start.to_string(), "2025-03-10T14:30:00Z");
+        assert_eq!(found[0].margin, SignedDuration::from_mins(90));
+        assert_eq!(found[6].margin, SignedDuration::ZERO);
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::Write,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::telemetry::Outcome;
+
+/// Number of recent calls per tool that latency percentiles are taken over.
+const LATENCY_WINDOW: usize = 1024;
+
+/// Counters for one tool.
+#[derive(Debug, Default)]
+struct Counters {
+    calls: u64,
+    validation_errors: u64,
+    internal_errors: u64,
+    total_ms: f64,
+    /// Latencies of the most recent calls, oldest first, in milliseconds.
+    recent_ms: VecDeque<f64>,
+}
+
+impl Counters {
+    /// The `quantile` (0 to 1) of the recent latencies, by nearest rank.
+    fn percentile(sorted: &[f64], quantile: f64) -> f64 {
+        if sorted.is_empty() {
+            return 0.0;
+        }
+        let rank = (quantile * sorted.len() as f64).ceil() as usize;
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    }
+
+    fn stats(&self, tool: &str) -> ToolStats {
+        let mut sorted: Vec<f64> = self.recent_ms.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        ToolStats {
+            tool: tool.to_string(),
+            calls: self.calls,
+            errors: self.validation_errors + self.internal_errors,
+            validation_errors: self.validation_errors,
+            internal_errors: self.internal_errors,
+            p50_ms: round_ms(Self::percentile(&sorted, 0.5)),
+            p95_ms: round_ms(Self::percentile(&sorted, 0.95)),
+        }
+    }
+}
+
+fn round_ms(ms: f64) -> f64 {
+    (ms * 1000.0).round() / 1000.0
+}
+
+/// Call counts, error counts, and latency percentiles for one tool.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct ToolStats {
+    pub tool: String,
+    pub calls: u64,
+    /// Calls that ended in a validation or internal error.
+    pub errors: u64,
+    pub validation_errors: u64,
+    pub internal_errors: u64,
+    /// Median latency of the most recent 1024 calls, in milliseconds.
+    pub p50_ms: f64,
+    /// 95th percentile latency of the most recent 1024 calls, in
+    /// milliseconds.
+    pub p95_ms: f64,
+}
+
+/// Per-tool call metrics, recorded by the server for every routed tool
+/// call.
+///
+/// Clones share the same counters, so every session of an HTTP server
+/// reports the totals for the whole process.
+#[derive(Clone)]
+pub struct Metrics {
+    started: Instant,
+    tools: Arc<Mutex<BTreeMap<String, Counters>>>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            tools: Arc::default(),
+        }
+    }
+}
+
+impl Metrics {
+    /// Count a call to `tool` that ended with `outcome` after `latency`.
+    pub fn record(&self, tool: &str, outcome: Outcome, latency: Duration) {
+        let mut tools = self.tools.lock().unwrap_or_else(|e| e.into_inner());
+        let counters = tools.entry(tool.to_string()).or_default();
+        counters.calls += 1;
+        match outcome {
+            Outcome::Success => {}
+            Outcome::ValidationError => counters.validation_errors += 1,
+            Outcome::InternalError => counters.internal_errors += 1,
+        }
+        let ms = latency.as_secs_f64() * 1000.0;
+        counters.total_ms += ms;
+        if counters.recent_ms.len() == LATENCY_WINDOW {
+            counters.recent_ms.pop_front();
+        }
+        counters.recent_ms.push_back(ms);
+    }
+
+    /// Time since the metrics were created, which is when the server
+    /// started.
+    pub fn uptime(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Stats for every tool called so far, by tool name.
+    pub fn snapshot(&self) -> Vec<ToolStats> {
+        let tools = self.tools.lock().unwrap_or_else(|e| e.into_inner());
+        tools
+            .iter()
+            .map(|(tool, counters)| counters.stats(tool))
+            .collect()
+    }
+
+    /// The metrics in the Prometheus text exposition format.
+    pub fn render_prometheus(&self) -> String {
+        let tools = self.tools.lock().unwrap_or_else(|e| e.into_inner());
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "# HELP mcp_time_uptime_seconds Time since the server started.\n\
+             # TYPE mcp_time_uptime_seconds gauge\n\
+             mcp_time_uptime_seconds {:.3}",
+            self.uptime().as_secs_f64()
+        );
+        out.push_str(
+            "# HELP mcp_time_tool_calls_total Tool calls handled, by tool.\n\
+             # TYPE mcp_time_tool_calls_total counter\n",
+        );
+        for (tool, counters) in tools.iter() {
+            let _ = writeln!(
+                out,
+                "mcp_time_tool_calls_total{{tool=\"{}\"}} {}",
+                escape_label(tool),
+                counters.calls
+            );
+        }
+        out.push_str(
+            "# HELP mcp_time_tool_errors_total Tool calls that failed, by tool and outcome.\n\
+             # TYPE mcp_time_tool_errors_total counter\n",
+        );
+        for (tool, counters) in tools.iter() {
+            for (outcome, count) in [
+                (Outcome::ValidationError, counters.validation_errors),
+                (Outcome::InternalError, counters.internal_errors),
+            ] {
+                let _ = writeln!(
+                    out,
+                    "mcp_time_tool_errors_total{{tool=\"{}\",outcome=\"{}\"}} {count}",
+                    escape_label(tool),
+                    outcome.as_str()
+                );
+            }
+        }
+        out.push_str(
+            "# HELP mcp_time_tool_latency_milliseconds Tool call latency, by tool; quantiles cover the most recent 1024 calls.\n\
+             # TYPE mcp_time_tool_latency_milliseconds summary\n",
+        );
+        for (tool, counters) in tools.iter() {
+            let stats = counters.stats(tool);
+            let tool = escape_label(tool);
+            for (quantile, value) in [("0.5", stats.p50_ms), ("0.95", stats.p95_ms)] {
+                let _ = writeln!(
+                    out,
+                    "mcp_time_tool_latency_milliseconds{{tool=\"{tool}\",quantile=\"{quantile}\"}} {value}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "mcp_time_tool_latency_milliseconds_sum{{tool=\"{tool}\"}} {:.3}\n\
+                 mcp_time_tool_latency_milliseconds_count{{tool=\"{tool}\"}} {}",
+                counters.total_ms, counters.calls
+            );
+        }
+        out
+    }
+}
+
+/// Escape a Prometheus label value.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_counts_calls_errors_and_latency_percentiles() {
+        let metrics = Metrics::default();
+        for ms in 1..=100 {
+            metrics.record("convert_time", Outcome::Success, Duration::from_millis(ms));
+        }
+        metrics.record(
+            "convert_time",
+            Outcome::ValidationError,
+            Duration::from_millis(1),
+        );
+        metrics.record("wait", Outcome::InternalError, Duration::from_millis(5));
+
+        let stats = metrics.snapshot();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].tool, "convert_time");
+        assert_eq!(stats[0].calls, 101);
+        assert_eq!(stats[0].errors, 1);
+        assert_eq!(stats[0].validation_errors, 1);
+        assert_eq!(stats[0].p50_ms, 50.0);
+        assert_eq!(stats[0].p95_ms, 95.0);
+        assert_eq!(stats[1].internal_errors, 1);
+        assert_eq!(stats[1].p95_ms, 5.0);
+
+        // Clones share the counters.
+        metrics
+            .clone()
+            .record("wait", Outcome::Success, Duration::from_millis(5));
+        assert_eq!(metrics.snapshot()[1].calls, 2);
+    }
+
+    #[test]
+    fn latency_percentiles_cover_only_recent_calls() {
+        let metrics = Metrics::default();
+        for _ in 0..LATENCY_WINDOW {
+            metrics.record("wait", Outcome::Success, Duration::from_secs(1));
+        }
+        for _ in 0..LATENCY_WINDOW {
+            metrics.record("wait", Outcome::Success, Duration::from_millis(2));
+        }
+        let stats = &metrics.snapshot()[0];
+        assert_eq!(stats.calls, 2 * LATENCY_WINDOW as u64);
+        assert_eq!(stats.p95_ms, 2.0);
+    }
+
+    #[test]
+    fn render_prometheus_writes_the_text_format() {
+        let metrics = Metrics::default();
+        metrics.record("time_until", Outcome::Success, Duration::from_millis(4));
+        metrics.record(
+            "time_until",
+            Outcome::ValidationError,
+            Duration::from_millis(2),
+        );
+        let text = metrics.render_prometheus();
+        for line in [
+            "# TYPE mcp_time_tool_calls_total counter",
+            "mcp_time_tool_calls_total{tool=\"time_until\"} 2",
+            "mcp_time_tool_errors_total{tool=\"time_until\",outcome=\"validation_error\"} 1",
+            "mcp_time_tool_errors_total{tool=\"time_until\",outcome=\"internal_error\"} 0",
+            "# TYPE mcp_time_tool_latency_milliseconds summary",
+            "mcp_time_tool_latency_milliseconds{tool=\"time_until\",quantile=\"0.5\"} 2",
+            "mcp_time_tool_latency_milliseconds{tool=\"time_until\",quantile=\"0.95\"} 4",
+            "mcp_time_tool_latency_milliseconds_sum{tool=\"time_until\"} 6.000",
+            "mcp_time_tool_latency_milliseconds_count{tool=\"time_until\"} 2",
+        ] {
+            assert!(
+                text.lines().any(|l| l == line),
+                "Missing {line} in:\n{text}"
+            );
+        }
+        assert_eq!(escape_label("a\"b\\c"), "a\\\"b\\\\c");
+    }
+}
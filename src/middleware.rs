@@ -0,0 +1,238 @@
+//! Middleware for serving MCP over HTTP: bearer-token authentication,
+//! request logging, and CORS, chosen by settings and applied to an axum
+//! router.
+//!
+//! The settings are read with the rest of the configuration (see
+//! [`crate::config::ServerConfig`]), and [`crate::transport::serve_http`]
+//! applies them to its routes.
+
+use axum::{
+    Router,
+    extract::Request,
+    http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tower_http::{
+    cors::{AllowOrigin, CorsLayer},
+    trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer},
+};
+
+/// Environment variable holding the token HTTP clients must present.
+pub const AUTH_TOKEN_ENV: &str = "MCP_TIME_HTTP_AUTH_TOKEN";
+
+/// Environment variable turning on logging of every HTTP request.
+pub const LOG_REQUESTS_ENV: &str = "MCP_TIME_HTTP_LOG_REQUESTS";
+
+/// Environment variable holding the comma-separated origins browsers may
+/// call from.
+pub const CORS_ORIGINS_ENV: &str = "MCP_TIME_HTTP_CORS_ORIGINS";
+
+/// Headers of the Streamable HTTP protocol that browsers must be allowed to
+/// send and read across origins.
+const MCP_HEADERS: [&str; 3] = ["mcp-session-id", "mcp-protocol-version", "last-event-id"];
+
+/// Middleware wrapped around HTTP routes, each off by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Middleware {
+    /// Token clients must send as `Authorization: Bearer <token>`.
+    pub auth_token: Option<String>,
+    /// Log each request's method, path, status, and latency at info level.
+    pub log_requests: bool,
+    /// Origins browsers may call from (e.g., `https://app.example.com`), or
+    /// `*` for any.
+    pub cors_origins: Vec<String>,
+}
+
+impl Middleware {
+    /// Wrap every route of `router` in the configured middleware.
+    ///
+    /// Requests are logged before anything else happens to them, and CORS
+    /// preflight requests are answered before authentication, since
+    /// browsers send them without credentials.
+    pub fn apply<S: Clone + Send + Sync + 'static>(&self, mut router: Router<S>) -> Router<S> {
+        if let Some(token) = &self.auth_token {
+            let expected = format!("Bearer {token}");
+            router = router.layer(axum::middleware::from_fn(
+                move |request: Request, next: Next| {
+                    let authorized = is_authorized(request.headers(), &expected);
+                    async move {
+                        if authorized {
+                            next.run(request).await
+                        } else {
+                            unauthorized()
+                        }
+                    }
+                },
+            ));
+        }
+        if !self.cors_origins.is_empty() {
+            router = router.layer(cors(&self.cors_origins));
+        }
+        if self.log_requests {
+            router = router.layer(
+                TraceLayer::new_for_http()
+                    .make_span_with(DefaultMakeSpan::new().level(tracing::Level::INFO))
+                    .on_response(DefaultOnResponse::new().level(tracing::Level::INFO)),
+            );
+        }
+        router
+    }
+}
+
+/// Read a comma-separated list of origins, each `*` or a scheme and host
+/// such as `https://app.example.com`.
+pub fn parse_origins(value: &str) -> Result<Vec<String>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|origin| !origin.is_empty())
+        .map(|origin| {
+            if origin == "*" || (origin.contains("://") && HeaderValue::from_str(origin).is_ok()) {
+                Ok(origin.to_string())
+            } else {
+                Err(format!(
+                    "Invalid CORS origin: '{origin}'. Expected '*' or a scheme and host (e.g., 'https://app.example.com')."
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Whether `headers` carry the `expected` authorization, compared in time
+/// independent of where they differ.
+fn is_authorized(headers: &HeaderMap, expected: &str) -> bool {
+    let Some(given) = headers.get(header::AUTHORIZATION) else {
+        return false;
+    };
+    let given = given.as_bytes();
+    given.len() == expected.len()
+        && given
+            .iter()
+            .zip(expected.as_bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn unauthorized() -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        [(header::WWW_AUTHENTICATE, "Bearer")],
+        "Missing or invalid bearer token.",
+    )
+        .into_response()
+}
+
+fn cors(origins: &[String]) -> CorsLayer {
+    let allow_origin = if origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(
+            origins
+                .iter()
+                .filter_map(|origin| HeaderValue::from_str(origin).ok()),
+        )
+    };
+    let mcp_headers = MCP_HEADERS.map(HeaderName::from_static);
+    CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods([Method::GET, Method::POST, Method::DELETE])
+        .allow_headers(
+            [header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT]
+                .into_iter()
+                .chain(mcp_headers.clone())
+                .collect::<Vec<_>>(),
+        )
+        .expose_headers(mcp_headers)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn router(middleware: &Middleware) -> Router {
+        middleware.apply(Router::new().route("/mcp", axum::routing::post(|| async { "ok" })))
+    }
+
+    fn request(method: Method, headers: &[(&str, &str)]) -> Request {
+        let mut request = Request::builder().method(method).uri("/mcp");
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        request.body(Body::empty()).unwrap()
+    }
+
+    #[test]
+    fn parse_origins_reads_a_list() {
+        assert_eq!(
+            parse_origins(" https://a.example, *,"),
+            Ok(vec!["https://a.example".to_string(), "*".to_string()])
+        );
+        assert_eq!(parse_origins(""), Ok(Vec::new()));
+        let err = parse_origins("a.example").unwrap_err();
+        assert!(err.contains("'a.example'"), "Error was: {err}");
+    }
+
+    #[tokio::test]
+    async fn apply_requires_the_bearer_token() {
+        let middleware = Middleware {
+            auth_token: Some("secret".into()),
+            ..Middleware::default()
+        };
+        let response = router(&middleware)
+            .oneshot(request(Method::POST, &[]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = router(&middleware)
+            .oneshot(request(Method::POST, &[("authorization", "Bearer wrong")]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = router(&middleware)
+            .oneshot(request(Method::POST, &[("authorization", "Bearer secret")]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn apply_answers_cors_preflight_without_the_token() {
+        let middleware = Middleware {
+            auth_token: Some("secret".into()),
+            cors_origins: vec!["https://app.example".into()],
+            ..Middleware::default()
+        };
+        let preflight = request(
+            Method::OPTIONS,
+            &[
+                ("origin", "https://app.example"),
+                ("access-control-request-method", "POST"),
+            ],
+        );
+        let response = router(&middleware).oneshot(preflight).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["access-control-allow-origin"],
+            "https://app.example"
+        );
+
+        let other = request(
+            Method::POST,
+            &[
+                ("origin", "https://other.example"),
+                ("authorization", "Bearer secret"),
+            ],
+        );
+        let response = router(&middleware).oneshot(other).await.unwrap();
+        assert!(
+            response
+                .headers()
+                .get("access-control-allow-origin")
+                .is_none()
+        );
+    }
+}
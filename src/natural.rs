@@ -0,0 +1,285 @@
+use jiff::civil::{Date, Time, Weekday};
+
+use crate::{
+    duration::{self, Duration},
+    server::clock_time,
+};
+
+/// Summary of the grammar, included in parse errors.
+pub const GRAMMAR: &str = "Expected a day, a time of day, or both (e.g., 'tomorrow at 3pm', 'next Friday 09:00', 'noon', '2025-07-04 at 9am'), 'now', or a duration counted from now or from such a point (e.g., 'in 2 hours', '3 days ago', '2 days after next Friday').";
+
+/// Which occurrence of a weekday a phrase means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Occurrence {
+    /// "friday" or "this friday": the next such day, today included.
+    Coming,
+    /// "next friday": the next such day after today.
+    Next,
+    /// "last friday": the most recent such day before today.
+    Last,
+}
+
+/// The day a phrase names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Day {
+    /// Days from today: 0 for "today", 1 for "tomorrow", -2 for "the day
+    /// before yesterday".
+    Relative(i64),
+    Weekday(Weekday, Occurrence),
+    Date(Date),
+}
+
+impl Day {
+    /// The date this day falls on, counted from `today`.
+    pub fn resolve(self, today: Date) -> Result<Date, jiff::Error> {
+        let days = match self {
+            Day::Date(date) => return Ok(date),
+            Day::Relative(days) => days,
+            Day::Weekday(weekday, occurrence) => {
+                let ahead = i64::from(
+                    (weekday.to_monday_zero_offset() - today.weekday().to_monday_zero_offset())
+                        .rem_euclid(7),
+                );
+                match occurrence {
+                    Occurrence::Coming => ahead,
+                    Occurrence::Next if ahead == 0 => 7,
+                    Occurrence::Next => ahead,
+                    Occurrence::Last => ahead - 7,
+                }
+            }
+        };
+        today.checked_add(jiff::Span::new().try_days(days)?)
+    }
+}
+
+/// A phrase naming a point in time.
+#[derive(Debug, Clone)]
+pub enum Phrase {
+    Now,
+    /// A day, a time of day, or both. A day alone means its start; a time
+    /// alone means that time today.
+    Point {
+        day: Option<Day>,
+        time: Option<Time>,
+    },
+    /// A duration counted from now or from a starting point. Starting points
+    /// the duration grammar leaves as [`duration::Anchor::Datetime`] (e.g.,
+    /// "next friday 09:00") can be parsed again with [`parse`].
+    Offset(Duration),
+}
+
+/// Whether `text` reads as a duration counted from somewhere, in the forms
+/// [`duration::parse`] anchors.
+fn is_offset(text: &str) -> bool {
+    text.starts_with("in ")
+        || text.ends_with(" ago")
+        || text.ends_with(" from now")
+        || text.ends_with(" later")
+        || [" from ", " after ", " before "]
+            .iter()
+            .any(|keyword| text.contains(keyword))
+}
+
+/// Split into lowercase words, joining a separate "am"/"pm" to the time
+/// before it ("3 pm") and dropping filler words ("at", "on", "the").
+fn words(text: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        let word = word.trim_matches(',');
+        match word {
+            "" | "at" | "on" | "the" => {}
+            "am" | "pm" | "a.m." | "p.m." if !words.is_empty() => {
+                let last = words.len() - 1;
+                words[last].push_str(&word.replace('.', ""));
+            }
+            _ => words.push(word.to_string()),
+        }
+    }
+    words
+}
+
+/// The time of day `word` names, if any.
+fn time_of_day(word: &str) -> Option<Time> {
+    match word {
+        "noon" | "midday" => return Some(jiff::civil::time(12, 0, 0, 0)),
+        "midnight" => return Some(Time::midnight()),
+        _ => {}
+    }
+    // Accept a single-digit hour ("9:00") as well as "09:00".
+    let padded = match word.split_once(':') {
+        Some((hour, _)) if hour.len() == 1 => format!("0{word}"),
+        _ => word.to_string(),
+    };
+    clock_time(&padded)
+}
+
+/// Parse a phrase such as "tomorrow at 3pm", "next Friday 09:00", or
+/// "in 2 hours".
+///
+/// Inputs that are neither are errors, which callers may want to retry as
+/// plain datetimes (e.g., "2025-07-04T09:00").
+pub fn parse(input: &str) -> Result<Phrase, String> {
+    let text = input.trim().to_ascii_lowercase();
+    if text.is_empty() {
+        return Err(format!("Empty time expression. {GRAMMAR}"));
+    }
+    if text == "now" {
+        return Ok(Phrase::Now);
+    }
+    // "the day after tomorrow" reads as a day, not as a duration.
+    let offset = is_offset(&text).then(|| duration::parse(input));
+    if let Some(Ok(offset)) = offset {
+        return Ok(Phrase::Offset(offset));
+    }
+    point(input, &text).map_err(|err| match offset {
+        Some(Err(offset_err)) => offset_err,
+        _ => err,
+    })
+}
+
+/// Parse the day and time of day in `text`, the lowercase form of `input`.
+fn point(input: &str, text: &str) -> Result<Phrase, String> {
+    let words = words(text);
+    let mut day = None;
+    let mut time = None;
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i].as_str();
+        let next = words.get(i + 1).map(String::as_str);
+        let (parsed_day, used) = match (word, next, words.get(i + 2).map(String::as_str)) {
+            ("today", _, _) => (Some(Day::Relative(0)), 1),
+            ("tomorrow", _, _) => (Some(Day::Relative(1)), 1),
+            ("yesterday", _, _) => (Some(Day::Relative(-1)), 1),
+            ("day", Some("after"), Some("tomorrow")) => (Some(Day::Relative(2)), 3),
+            ("day", Some("before"), Some("yesterday")) => (Some(Day::Relative(-2)), 3),
+            ("this" | "next" | "last", Some(name), _) if duration::weekday(name).is_some() => {
+                let occurrence = match word {
+                    "this" => Occurrence::Coming,
+                    "next" => Occurrence::Next,
+                    _ => Occurrence::Last,
+                };
+                (
+                    duration::weekday(name).map(|w| Day::Weekday(w, occurrence)),
+                    2,
+                )
+            }
+            _ => (
+                duration::weekday(word)
+                    .map(|w| Day::Weekday(w, Occurrence::Coming))
+                    .or_else(|| Date::strptime("%Y-%m-%d", word).ok().map(Day::Date)),
+                1,
+            ),
+        };
+        if let Some(parsed_day) = parsed_day {
+            if day.replace(parsed_day).is_some() {
+                return Err(format!("'{input}' names more than one day. {GRAMMAR}"));
+            }
+            i += used;
+            continue;
+        }
+        match time_of_day(word) {
+            Some(parsed_time) => {
+                if time.replace(parsed_time).is_some() {
+                    return Err(format!(
+                        "'{input}' names more than one time of day. {GRAMMAR}"
+                    ));
+                }
+                i += 1;
+            }
+            None => {
+                return Err(format!(
+                    "Unrecognized word '{word}' in time expression '{input}'. {GRAMMAR}"
+                ));
+            }
+        }
+    }
+    if day.is_none() && time.is_none() {
+        return Err(format!(
+            "'{input}' names neither a day nor a time. {GRAMMAR}"
+        ));
+    }
+    Ok(Phrase::Point { day, time })
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::{date, time};
+
+    use super::*;
+
+    fn parse_point(input: &str) -> (Option<Day>, Option<Time>) {
+        match parse(input).unwrap() {
+            Phrase::Point { day, time } => (day, time),
+            other => panic!("Expected a point for '{input}', got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_reads_days_and_times() {
+        assert_eq!(
+            parse_point("tomorrow at 3pm"),
+            (Some(Day::Relative(1)), Some(time(15, 0, 0, 0)))
+        );
+        assert_eq!(
+            parse_point("Next Friday 09:00"),
+            (
+                Some(Day::Weekday(Weekday::Friday, Occurrence::Next)),
+                Some(time(9, 0, 0, 0))
+            )
+        );
+        assert_eq!(
+            parse_point("3 pm the day after tomorrow"),
+            (Some(Day::Relative(2)), Some(time(15, 0, 0, 0)))
+        );
+        assert_eq!(
+            parse_point("2025-07-04 at 9:30 a.m."),
+            (Some(Day::Date(date(2025, 7, 4))), Some(time(9, 30, 0, 0)))
+        );
+        assert_eq!(parse_point("noon"), (None, Some(time(12, 0, 0, 0))));
+        assert_eq!(
+            parse_point("last tue"),
+            (Some(Day::Weekday(Weekday::Tuesday, Occurrence::Last)), None)
+        );
+        assert!(matches!(parse("now"), Ok(Phrase::Now)));
+    }
+
+    #[test]
+    fn parse_reads_offsets() {
+        let Ok(Phrase::Offset(offset)) = parse("in 2 hours") else {
+            panic!("Expected an offset");
+        };
+        assert_eq!(offset.anchor, Some(duration::Anchor::Now));
+        assert_eq!(offset.fixed_seconds(), Some(7200.0));
+
+        let Ok(Phrase::Offset(offset)) = parse("2 days after next Friday 09:00") else {
+            panic!("Expected an offset");
+        };
+        assert_eq!(
+            offset.anchor,
+            Some(duration::Anchor::Datetime("next Friday 09:00".into()))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_and_repeated_parts() {
+        for input in ["", "whenever", "tomorrow today", "3pm 4pm", "next week"] {
+            let err = parse(input).unwrap_err();
+            assert!(err.contains("Expected"), "Error for '{input}' was: {err}");
+        }
+    }
+
+    #[test]
+    fn resolve_counts_weekdays_from_today() {
+        // 2025-07-02 is a Wednesday.
+        let today = date(2025, 7, 2);
+        let friday = |occurrence| Day::Weekday(Weekday::Friday, occurrence).resolve(today);
+        assert_eq!(friday(Occurrence::Coming).unwrap(), date(2025, 7, 4));
+        assert_eq!(friday(Occurrence::Next).unwrap(), date(2025, 7, 4));
+        assert_eq!(friday(Occurrence::Last).unwrap(), date(2025, 6, 27));
+        let wednesday = |occurrence| Day::Weekday(Weekday::Wednesday, occurrence).resolve(today);
+        assert_eq!(wednesday(Occurrence::Coming).unwrap(), today);
+        assert_eq!(wednesday(Occurrence::Next).unwrap(), date(2025, 7, 9));
+        assert_eq!(wednesday(Occurrence::Last).unwrap(), date(2025, 6, 25));
+        assert_eq!(Day::Relative(-2).resolve(today).unwrap(), date(2025, 6, 30));
+    }
+}
@@ -0,0 +1,356 @@
+use std::net::IpAddr;
+
+/// Environment variable naming the NTP server `check_clock_drift` queries.
+pub const SERVER_ENV: &str = "MCP_TIME_NTP_SERVER";
+
+/// Server queried when none is configured.
+pub const DEFAULT_SERVER: &str = "pool.ntp.org";
+
+/// Port NTP servers listen on.
+const NTP_PORT: u16 = 123;
+
+/// Seconds from the NTP epoch (1900-01-01) to the Unix epoch.
+const NTP_UNIX_OFFSET: i128 = 2_208_988_800;
+
+/// Length of an NTP packet without extension fields.
+const PACKET_LEN: usize = 48;
+
+/// First byte of a client request: no leap warning, version 4, client mode.
+const CLIENT_REQUEST: u8 = 0b00_100_011;
+
+/// Read an NTP server setting: a host name or IP address, optionally with a
+/// port (e.g., "time.google.com", "192.168.1.1:1123", "[::1]:123").
+///
+/// The port defaults to 123. Empty values fall back to
+/// [`DEFAULT_SERVER`].
+pub fn parse_server(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(format!("{DEFAULT_SERVER}:{NTP_PORT}"));
+    }
+    let invalid = || {
+        format!(
+            "Invalid NTP server: '{value}'. Expected a host name or IP address, optionally with a port (e.g., 'pool.ntp.org' or '192.168.1.1:123')."
+        )
+    };
+    if value.contains(char::is_whitespace) || value.contains('/') {
+        return Err(invalid());
+    }
+    if let Ok(ip) = value.parse::<IpAddr>() {
+        return Ok(std::net::SocketAddr::new(ip, NTP_PORT).to_string());
+    }
+    let (host, port) = match value.rsplit_once(':') {
+        // A colon inside the host can only be an IPv6 literal, which must
+        // be bracketed to carry a port.
+        Some((host, port)) if !host.contains(':') || host.starts_with('[') => {
+            let port = port.parse::<u16>().ok().filter(|&p| p != 0);
+            (host, port.ok_or_else(invalid)?)
+        }
+        Some(_) => return Err(invalid()),
+        None => (value, NTP_PORT),
+    };
+    let bare = host.trim_start_matches('[').trim_end_matches(']');
+    let valid = if host.starts_with('[') {
+        host.ends_with(']') && bare.parse::<std::net::Ipv6Addr>().is_ok()
+    } else {
+        !host.is_empty()
+            && host
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_'))
+    };
+    if !valid {
+        return Err(invalid());
+    }
+    Ok(format!("{host}:{port}"))
+}
+
+/// A 64-bit NTP timestamp: seconds since 1900 in the high half and the
+/// fraction of a second in the low half.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NtpTimestamp(pub u64);
+
+impl NtpTimestamp {
+    /// Encode an instant, wrapping into the current 136-year NTP era.
+    pub fn from_timestamp(ts: jiff::Timestamp) -> Self {
+        let nanos = ts.as_nanosecond() + NTP_UNIX_OFFSET * 1_000_000_000;
+        let seconds = nanos.div_euclid(1_000_000_000) as u64 & 0xFFFF_FFFF;
+        let fraction = ((nanos.rem_euclid(1_000_000_000) << 32) / 1_000_000_000) as u64;
+        NtpTimestamp(seconds << 32 | fraction)
+    }
+
+    /// Decode into the instant nearest `pivot`, so timestamps from after
+    /// the 2036 era rollover read correctly.
+    pub fn to_timestamp(self, pivot: jiff::Timestamp) -> Result<jiff::Timestamp, String> {
+        const ERA: i128 = 1 << 32;
+        let seconds = i128::from(self.0 >> 32);
+        // Round to the nearest nanosecond so encoded instants decode exactly.
+        let fraction = (i128::from(self.0 & 0xFFFF_FFFF) * 1_000_000_000 + (1 << 31)) >> 32;
+        let pivot_seconds = i128::from(pivot.as_second()) + NTP_UNIX_OFFSET;
+        let era = (pivot_seconds - seconds + ERA / 2).div_euclid(ERA);
+        let unix_seconds = seconds + era * ERA - NTP_UNIX_OFFSET;
+        jiff::Timestamp::from_nanosecond(unix_seconds * 1_000_000_000 + fraction)
+            .map_err(|e| format!("NTP timestamp is out of range: {e}"))
+    }
+}
+
+/// A client request carrying `transmit` as its transmit timestamp, which
+/// the server echoes back as the origin timestamp of its reply.
+pub fn request(transmit: NtpTimestamp) -> [u8; PACKET_LEN] {
+    let mut packet = [0; PACKET_LEN];
+    packet[0] = CLIENT_REQUEST;
+    packet[40..48].copy_from_slice(&transmit.0.to_be_bytes());
+    packet
+}
+
+/// The fields of a server reply that a clock measurement needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    /// Distance from a reference clock: 1 for a server with its own
+    /// reference (e.g., GPS), 2 for one synchronized to a stratum 1 server,
+    /// and so on.
+    pub stratum: u8,
+    /// The reference clock code (e.g., "GPS", "PPS") for stratum 1
+    /// servers, or the upstream server's IPv4 address otherwise.
+    pub reference_id: String,
+    /// When the server received the request.
+    pub receive: NtpTimestamp,
+    /// When the server sent the reply.
+    pub transmit: NtpTimestamp,
+}
+
+/// Read a server reply to the request sent with transmit timestamp `sent`.
+///
+/// Replies to other requests, from unsynchronized servers, and
+/// "kiss-of-death" refusals (e.g., RATE when querying too often) are
+/// rejected.
+pub fn parse_reply(packet: &[u8], sent: NtpTimestamp) -> Result<Reply, String> {
+    if packet.len() < PACKET_LEN {
+        return Err(format!(
+            "NTP reply is {} bytes; expected at least {PACKET_LEN}.",
+            packet.len()
+        ));
+    }
+    let timestamp = |at: usize| {
+        NtpTimestamp(u64::from_be_bytes(
+            packet[at..at + 8].try_into().expect("8-byte slice"),
+        ))
+    };
+    let mode = packet[0] & 0b111;
+    if mode != 4 {
+        return Err(format!("NTP reply has mode {mode}; expected 4 (server)."));
+    }
+    if timestamp(24) != sent {
+        return Err("NTP reply does not answer this request.".into());
+    }
+    let code = String::from_utf8_lossy(&packet[12..16])
+        .trim_end_matches('\0')
+        .to_string();
+    let stratum = packet[1];
+    match stratum {
+        0 => {
+            return Err(format!(
+                "NTP server refused the request (kiss code '{code}')."
+            ));
+        }
+        16.. => return Err("NTP server is not synchronized to a reference clock.".into()),
+        _ => {}
+    }
+    if packet[0] >> 6 == 3 {
+        return Err("NTP server reports that its clock is not synchronized.".into());
+    }
+    let reference_id = if stratum == 1 {
+        code
+    } else {
+        std::net::Ipv4Addr::new(packet[12], packet[13], packet[14], packet[15]).to_string()
+    };
+    Ok(Reply {
+        stratum,
+        reference_id,
+        receive: timestamp(32),
+        transmit: timestamp(40),
+    })
+}
+
+/// How far the local clock is from a server's, from one request and reply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    /// Server time minus local time: positive when the local clock is
+    /// behind.
+    pub offset: jiff::SignedDuration,
+    /// Time the request and reply spent on the network, excluding the
+    /// server's processing time.
+    pub delay: jiff::SignedDuration,
+    /// The local clock when the reply arrived.
+    pub host_time: jiff::Timestamp,
+    /// The server's clock at the same moment: `host_time` plus `offset`.
+    pub server_time: jiff::Timestamp,
+}
+
+/// Measure the local clock against `reply`, given the local instants the
+/// request was sent and the reply received.
+pub fn measure(
+    sent: jiff::Timestamp,
+    reply: &Reply,
+    received: jiff::Timestamp,
+) -> Result<Measurement, String> {
+    let server_received = reply.receive.to_timestamp(sent)?;
+    let server_sent = reply.transmit.to_timestamp(sent)?;
+    let out = sent.duration_until(server_received);
+    let back = received.duration_until(server_sent);
+    let offset = (out + back) / 2;
+    let delay = sent.duration_until(received) - server_received.duration_until(server_sent);
+    let server_time = received
+        .checked_add(offset)
+        .map_err(|e| format!("NTP timestamp is out of range: {e}"))?;
+    Ok(Measurement {
+        offset,
+        delay: delay.max(jiff::SignedDuration::ZERO),
+        host_time: received,
+        server_time,
+    })
+}
+
+/// A completed query of an NTP server.
+#[cfg(feature = "ntp")]
+#[derive(Debug, Clone)]
+pub struct Sample {
+    /// Address the reply came from.
+    pub address: std::net::SocketAddr,
+    pub reply: Reply,
+    pub measurement: Measurement,
+}
+
+/// Query `server` (as returned by [`parse_server`]) once over UDP, giving
+/// up after `timeout`.
+#[cfg(feature = "ntp")]
+pub async fn query(server: &str, timeout: std::time::Duration) -> Result<Sample, String> {
+    let exchange = async {
+        let address = tokio::net::lookup_host(server)
+            .await
+            .map_err(|e| format!("Could not resolve NTP server '{server}': {e}."))?
+            .next()
+            .ok_or_else(|| format!("Could not resolve NTP server '{server}'."))?;
+        let local: std::net::SocketAddr = match address {
+            std::net::SocketAddr::V4(_) => ([0, 0, 0, 0], 0).into(),
+            std::net::SocketAddr::V6(_) => (std::net::Ipv6Addr::UNSPECIFIED, 0).into(),
+        };
+        let io = |e: std::io::Error| format!("Could not query NTP server '{server}': {e}.");
+        let socket = tokio::net::UdpSocket::bind(local).await.map_err(io)?;
+        socket.connect(address).await.map_err(io)?;
+
+        let sent = jiff::Timestamp::now();
+        let transmit = NtpTimestamp::from_timestamp(sent);
+        socket.send(&request(transmit)).await.map_err(io)?;
+        let mut buf = [0; 1024];
+        let len = socket.recv(&mut buf).await.map_err(io)?;
+        let received = jiff::Timestamp::now();
+
+        let reply = parse_reply(&buf[..len], transmit)?;
+        let measurement = measure(sent, &reply, received)?;
+        Ok(Sample {
+            address,
+            reply,
+            measurement,
+        })
+    };
+    tokio::time::timeout(timeout, exchange)
+        .await
+        .unwrap_or_else(|_| {
+            Err(format!(
+                "NTP server '{server}' did not reply within {} seconds.",
+                timeout.as_secs_f64()
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A server reply to `sent` with the given receive and transmit times.
+    fn reply_packet(sent: NtpTimestamp, receive: NtpTimestamp, transmit: NtpTimestamp) -> Vec<u8> {
+        let mut packet = vec![0; PACKET_LEN];
+        packet[0] = 0b00_100_100;
+        packet[1] = 1;
+        packet[12..16].copy_from_slice(b"GPS\0");
+        packet[24..32].copy_from_slice(&sent.0.to_be_bytes());
+        packet[32..40].copy_from_slice(&receive.0.to_be_bytes());
+        packet[40..48].copy_from_slice(&transmit.0.to_be_bytes());
+        packet
+    }
+
+    #[test]
+    fn parse_server_adds_the_default_port() {
+        assert_eq!(parse_server("").unwrap(), "pool.ntp.org:123");
+        assert_eq!(
+            parse_server("time.google.com").unwrap(),
+            "time.google.com:123"
+        );
+        assert_eq!(parse_server("10.0.0.1:1123").unwrap(), "10.0.0.1:1123");
+        assert_eq!(parse_server("::1").unwrap(), "[::1]:123");
+        assert_eq!(parse_server("[::1]:9123").unwrap(), "[::1]:9123");
+        for invalid in ["ntp server", "host:port", "host:0", "[::1", "udp://host"] {
+            assert!(parse_server(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn timestamps_round_trip_across_the_era_rollover() {
+        for instant in ["2025-07-04T12:00:00.25Z", "2036-02-07T06:28:17Z"] {
+            let ts: jiff::Timestamp = instant.parse().unwrap();
+            let decoded = NtpTimestamp::from_timestamp(ts).to_timestamp(ts).unwrap();
+            assert_eq!(decoded, ts);
+        }
+        // The first second of era 1 wraps to 0 in the wire format.
+        let ts: jiff::Timestamp = "2036-02-07T06:28:16Z".parse().unwrap();
+        assert_eq!(NtpTimestamp::from_timestamp(ts).0 >> 32, 0);
+    }
+
+    #[test]
+    fn measure_computes_offset_and_delay() {
+        let at = |s: &str| s.parse::<jiff::Timestamp>().unwrap();
+        let sent = at("2025-07-04T12:00:00Z");
+        let received = at("2025-07-04T12:00:00.1Z");
+        // The server is 2 seconds ahead; each leg takes 40ms and the server
+        // holds the request for 20ms.
+        let transmit = NtpTimestamp::from_timestamp(sent);
+        let packet = reply_packet(
+            transmit,
+            NtpTimestamp::from_timestamp(at("2025-07-04T12:00:02.04Z")),
+            NtpTimestamp::from_timestamp(at("2025-07-04T12:00:02.06Z")),
+        );
+        let reply = parse_reply(&packet, transmit).unwrap();
+        assert_eq!(reply.stratum, 1);
+        assert_eq!(reply.reference_id, "GPS");
+
+        let measurement = measure(sent, &reply, received).unwrap();
+        let millis = |d: jiff::SignedDuration| d.as_millis();
+        assert_eq!(millis(measurement.offset), 2000);
+        assert_eq!(millis(measurement.delay), 80);
+        assert_eq!(
+            measurement
+                .server_time
+                .round(jiff::Unit::Millisecond)
+                .unwrap(),
+            at("2025-07-04T12:00:02.1Z")
+        );
+    }
+
+    #[test]
+    fn parse_reply_rejects_unrelated_and_refused_replies() {
+        let sent = NtpTimestamp(42 << 32);
+        let packet = reply_packet(sent, sent, sent);
+        assert!(parse_reply(&packet, NtpTimestamp(7)).is_err());
+        assert!(parse_reply(&packet[..40], sent).is_err());
+
+        let mut kiss = packet.clone();
+        kiss[1] = 0;
+        kiss[12..16].copy_from_slice(b"RATE");
+        let err = parse_reply(&kiss, sent).unwrap_err();
+        assert!(err.contains("RATE"), "Error was: {err}");
+
+        let mut client = packet;
+        client[0] = CLIENT_REQUEST;
+        assert!(parse_reply(&client, sent).is_err());
+    }
+}
@@ -0,0 +1,360 @@
+use jiff::{Timestamp, tz::TimeZone};
+use rmcp::model::{
+    GetPromptResult, JsonObject, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+};
+
+use crate::{cities, core::Error};
+
+/// A prompt template: its name, description, and arguments as (name,
+/// description, required).
+struct Template {
+    name: &'static str,
+    description: &'static str,
+    arguments: &'static [(&'static str, &'static str, bool)],
+}
+
+const TEMPLATES: &[Template] = &[
+    Template {
+        name: "schedule_meeting",
+        description: "Find a meeting time that works across several timezones, checking working hours, holidays, and DST changes.",
+        arguments: &[
+            (
+                "timezones",
+                "Comma-separated IANA timezones of the participants (e.g., 'America/New_York, Europe/London, Asia/Kolkata')",
+                true,
+            ),
+            (
+                "duration",
+                "Meeting length (e.g., '45 minutes'). Defaults to 30 minutes.",
+                false,
+            ),
+            (
+                "date",
+                "Preferred date (YYYY-MM-DD). Defaults to the next working day.",
+                false,
+            ),
+        ],
+    },
+    Template {
+        name: "explain_dst",
+        description: "Explain when and how daylight saving time changes the clocks in a timezone during a year.",
+        arguments: &[
+            ("timezone", "IANA timezone (e.g., 'Europe/Berlin')", true),
+            (
+                "year",
+                "Year to explain (e.g., 2026). Defaults to the current year.",
+                false,
+            ),
+        ],
+    },
+    Template {
+        name: "convert_deadline",
+        description: "Work out when a deadline falls in your own timezone and how long is left.",
+        arguments: &[
+            (
+                "deadline",
+                "The deadline as written, ideally with its zone or offset (e.g., '2026-03-31T17:00 America/Los_Angeles', '2026-03-31T23:59:59Z')",
+                true,
+            ),
+            ("timezone", "Your IANA timezone (e.g., 'Asia/Tokyo')", true),
+        ],
+    },
+    Template {
+        name: "plan_recurring_meeting",
+        description: "Check how a recurring meeting's local time moves for participants in other timezones as DST starts and ends.",
+        arguments: &[
+            (
+                "timezone",
+                "IANA timezone the meeting is scheduled in (e.g., 'America/New_York')",
+                true,
+            ),
+            (
+                "time",
+                "Meeting start time in that timezone (e.g., '10:00')",
+                true,
+            ),
+            (
+                "participant_timezones",
+                "Comma-separated IANA timezones of the other participants (e.g., 'Europe/London, Asia/Kolkata')",
+                true,
+            ),
+        ],
+    },
+];
+
+/// Every prompt, in the order they are listed.
+pub fn list() -> Vec<Prompt> {
+    TEMPLATES
+        .iter()
+        .map(|template| {
+            let arguments = template
+                .arguments
+                .iter()
+                .map(|&(name, description, required)| PromptArgument {
+                    name: name.into(),
+                    title: None,
+                    description: Some(description.into()),
+                    required: Some(required),
+                })
+                .collect();
+            Prompt::new(template.name, Some(template.description), Some(arguments))
+        })
+        .collect()
+}
+
+/// Render the prompt `name` with `arguments`, resolving timezone arguments
+/// with `resolve` so they reach the model as canonical IANA names.
+///
+/// `now` sets the defaults that depend on the current date.
+pub fn get(
+    name: &str,
+    arguments: Option<&JsonObject>,
+    now: Timestamp,
+    resolve: impl Fn(&str) -> Result<TimeZone, Error>,
+) -> Result<GetPromptResult, Error> {
+    let Some(template) = TEMPLATES.iter().find(|t| t.name == name) else {
+        let names: Vec<&str> = TEMPLATES.iter().map(|t| t.name).collect();
+        return Err(Error::new(
+            "unknown_prompt",
+            format!(
+                "Unknown prompt: '{name}'. Available prompts: {}.",
+                names.join(", ")
+            ),
+        )
+        .with_value(name)
+        .with_suggestions(names.iter().map(|n| n.to_string()).collect()));
+    };
+    let argument = |key: &str| -> Option<String> {
+        let value = arguments?.get(key)?;
+        let text = match value {
+            serde_json::Value::String(text) => text.trim().to_string(),
+            serde_json::Value::Null => return None,
+            other => other.to_string(),
+        };
+        Some(text).filter(|text| !text.is_empty())
+    };
+    let required = |key: &str| {
+        argument(key).ok_or_else(|| {
+            Error::new(
+                "invalid_arguments",
+                format!("The '{name}' prompt needs a '{key}' argument."),
+            )
+        })
+    };
+    let zone = |input: &str| resolve(input).map(|tz| crate::core::zone_label(&tz));
+    let zones = |key: &str| -> Result<Vec<String>, Error> {
+        required(key)?
+            .split(',')
+            .map(str::trim)
+            .filter(|zone| !zone.is_empty())
+            .map(zone)
+            .collect()
+    };
+
+    let text = match template.name {
+        "schedule_meeting" => {
+            let zones = zones("timezones")?;
+            if zones.len() < 2 {
+                return Err(Error::new(
+                    "invalid_arguments",
+                    "The 'schedule_meeting' prompt needs at least two timezones, separated by commas.",
+                ));
+            }
+            let duration = argument("duration").unwrap_or_else(|| "30-minute".into());
+            let date = argument("date")
+                .map(|date| format!("on {date}"))
+                .unwrap_or_else(|| "on the next working day".into());
+            format!(
+                "Help me schedule a {duration} meeting {date} for participants in {}.\n\n\
+                 1. Call `get_world_clock` with these timezones to see the current local time and UTC offset in each.\n\
+                 2. Call `find_meeting_time` with these timezones, the meeting length, and the date to get start times within everyone's working hours. If none fit, say who would be outside working hours and by how much.\n\
+                 3. Call `is_holiday` for the participants' countries on the date, where the country is clear from the timezone.\n\
+                 4. Call `get_dst_transitions` for any zone whose clocks may change near the date, and point out offsets that shift.\n\n\
+                 Reply with the best proposed time as a table of local times, one row per timezone, then any runner-up options.",
+                zones.join(", ")
+            )
+        }
+        "explain_dst" => {
+            let tz = resolve(&required("timezone")?)?;
+            let year =
+                argument("year").unwrap_or_else(|| now.to_zoned(tz.clone()).year().to_string());
+            let tz = crate::core::zone_label(&tz);
+            format!(
+                "Explain how daylight saving time affects {tz} in {year}.\n\n\
+                 Call `get_dst_transitions` with timezone '{tz}' and year {year} to get the transitions; do not rely on memory, since rules change. Then explain, in plain language:\n\
+                 - the date and local time of each change, and whether clocks go forward or back and by how much;\n\
+                 - the local times that are skipped or happen twice;\n\
+                 - the abbreviation and UTC offset before and after each change.\n\n\
+                 If {tz} has no transitions in {year}, say that it keeps one offset all year and give it."
+            )
+        }
+        "convert_deadline" => {
+            let deadline = required("deadline")?;
+            let tz = zone(&required("timezone")?)?;
+            format!(
+                "A deadline is given as: {deadline}\n\
+                 I am in {tz}.\n\n\
+                 1. If the deadline names no timezone or offset, ask me which one it is in rather than guessing. Call `parse_time` if it is written as a phrase (e.g., 'Friday 5pm').\n\
+                 2. Call `explain_time` with the deadline and timezone '{tz}' to get it in my local time, with its weekday and date, and `time_until` to get how long is left or how long ago it passed.\n\n\
+                 Point out if the deadline falls outside working hours, on a weekend, or on a different calendar day for me than where it was set."
+            )
+        }
+        "plan_recurring_meeting" => {
+            let tz = zone(&required("timezone")?)?;
+            let time = required("time")?;
+            let participants = zones("participant_timezones")?;
+            format!(
+                "A recurring meeting is held at {time} in {tz}, with participants in {}.\n\n\
+                 1. Call `recurrence_drift` with timezone '{tz}', time '{time}', these participant timezones, and a range covering the next twelve months, to see how the meeting's local time moves for each participant.\n\
+                 2. Call `dst_impact` for all of these timezones over the same range to find the weeks when their clocks change on different dates.\n\n\
+                 Summarize, per participant, their usual local time, the periods when it differs and by how much, and whether it leaves their working hours. Suggest a meeting time or zone that would drift less, if there is one.",
+                participants.join(", ")
+            )
+        }
+        _ => unreachable!("every template is rendered"),
+    };
+    Ok(GetPromptResult {
+        description: Some(template.description.into()),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    })
+}
+
+/// Completions for the `value` typed so far for the prompt `name`'s
+/// `argument`, as whole argument values.
+///
+/// Timezone arguments complete to zone names; in comma-separated lists, the
+/// last entry is completed and the earlier ones kept. Other arguments, and
+/// unknown prompts, have none.
+pub fn complete(name: &str, argument: &str, value: &str) -> Vec<String> {
+    let known = TEMPLATES
+        .iter()
+        .filter(|t| t.name == name)
+        .flat_map(|t| t.arguments)
+        .any(|&(key, _, _)| key == argument);
+    if !known {
+        return Vec::new();
+    }
+    if argument.ends_with("timezones") {
+        let (done, last) = match value.rsplit_once(',') {
+            Some((done, last)) => (Some(done), last),
+            None => (None, value),
+        };
+        cities::complete(last)
+            .into_iter()
+            .map(|zone| match done {
+                Some(done) => format!("{done}, {zone}"),
+                None => zone,
+            })
+            .collect()
+    } else if argument.ends_with("timezone") {
+        cities::complete(value)
+    } else {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(name: &str, arguments: serde_json::Value) -> Result<String, Error> {
+        let now: Timestamp = "2025-07-04T12:00:00Z".parse().unwrap();
+        let result = get(
+            name,
+            arguments.as_object(),
+            now,
+            crate::core::resolve_timezone,
+        )?;
+        match &result.messages[0].content {
+            rmcp::model::PromptMessageContent::Text { text } => Ok(text.clone()),
+            other => panic!("Expected text content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn list_describes_every_template() {
+        let prompts = list();
+        let names: Vec<&str> = prompts.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "schedule_meeting",
+                "explain_dst",
+                "convert_deadline",
+                "plan_recurring_meeting"
+            ]
+        );
+        let arguments = prompts[0].arguments.as_ref().unwrap();
+        assert_eq!(arguments[0].name, "timezones");
+        assert_eq!(arguments[0].required, Some(true));
+        assert_eq!(arguments[1].required, Some(false));
+    }
+
+    #[test]
+    fn get_fills_arguments_and_defaults() {
+        let text = render(
+            "schedule_meeting",
+            serde_json::json!({"timezones": "america/new_york, Europe/London", "duration": "45-minute"}),
+        )
+        .unwrap();
+        assert!(text.starts_with(
+            "Help me schedule a 45-minute meeting on the next working day for participants in America/New_York, Europe/London."
+        ), "{text}");
+
+        let text = render(
+            "explain_dst",
+            serde_json::json!({"timezone": "Europe/Berlin"}),
+        )
+        .unwrap();
+        assert!(
+            text.contains("timezone 'Europe/Berlin' and year 2025"),
+            "{text}"
+        );
+        let text = render(
+            "explain_dst",
+            serde_json::json!({"timezone": "Europe/Berlin", "year": 2026}),
+        )
+        .unwrap();
+        assert!(text.contains("in 2026"), "{text}");
+    }
+
+    #[test]
+    fn complete_offers_zones_for_timezone_arguments() {
+        assert_eq!(
+            complete("explain_dst", "timezone", "Asia/Kolk"),
+            ["Asia/Kolkata"]
+        );
+        assert_eq!(
+            complete(
+                "plan_recurring_meeting",
+                "participant_timezones",
+                "Europe/London,asia/kolk"
+            ),
+            ["Europe/London, Asia/Kolkata"]
+        );
+        assert!(complete("explain_dst", "year", "2").is_empty());
+        assert!(complete("explain_dst", "timezones", "Asia").is_empty());
+        assert!(complete("unknown", "timezone", "Asia").is_empty());
+    }
+
+    #[test]
+    fn get_rejects_bad_arguments() {
+        for (name, arguments, code) in [
+            ("no_such_prompt", serde_json::json!({}), "unknown_prompt"),
+            ("explain_dst", serde_json::json!({}), "invalid_arguments"),
+            (
+                "schedule_meeting",
+                serde_json::json!({"timezones": "UTC"}),
+                "invalid_arguments",
+            ),
+            (
+                "convert_deadline",
+                serde_json::json!({"deadline": "tomorrow", "timezone": "Mars/Base"}),
+                "invalid_timezone",
+            ),
+        ] {
+            let err = render(name, arguments).unwrap_err();
+            assert_eq!(err.code, code, "Error for '{name}' was: {err}");
+        }
+    }
+}
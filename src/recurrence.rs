@@ -0,0 +1,164 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::format_offset_diff;
+
+/// How often a recurring meeting repeats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Frequency {
+    /// Every day.
+    Daily,
+    /// Monday through Friday.
+    Weekdays,
+    /// Once a week, on the weekday of the first date.
+    #[default]
+    Weekly,
+}
+
+/// Consecutive occurrences that a participant sees at the same local time.
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+pub struct DriftPeriod {
+    /// First occurrence in the period, as a date in the meeting's timezone.
+    pub from: String,
+    /// Last occurrence in the period, as a date in the meeting's timezone.
+    pub to: String,
+    pub occurrences: usize,
+    /// Start time in the participant's timezone (HH:MM).
+    pub local_time: String,
+    /// Days between the meeting's date and the participant's date (e.g., 1
+    /// when a Monday evening meeting falls on Tuesday morning).
+    pub day_shift: i32,
+    /// Participant's UTC offset minus the meeting's (e.g., "+5:00").
+    pub time_difference: String,
+}
+
+/// How a recurring meeting lands for one participant zone.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ParticipantDrift {
+    pub timezone: String,
+    /// Number of times the local time changes between occurrences.
+    pub shifts: usize,
+    pub periods: Vec<DriftPeriod>,
+}
+
+/// Dates from `from` through `to` (inclusive) on which a meeting with
+/// `frequency` occurs.
+pub fn occurrences(
+    from: jiff::civil::Date,
+    to: jiff::civil::Date,
+    frequency: Frequency,
+) -> Vec<jiff::civil::Date> {
+    from.series(jiff::Span::new().days(1))
+        .take_while(|date| *date <= to)
+        .filter(|date| match frequency {
+            Frequency::Daily => true,
+            Frequency::Weekdays => date.weekday().to_monday_one_offset() <= 5,
+            Frequency::Weekly => date.weekday() == from.weekday(),
+        })
+        .collect()
+}
+
+/// Group `meetings` by the local time they start at in `tz`.
+///
+/// A new period begins whenever the participant's local start time or date
+/// shift changes, which happens when the two zones change their clocks on
+/// different dates.
+pub fn drift(meetings: &[jiff::Zoned], tz: &jiff::tz::TimeZone) -> ParticipantDrift {
+    let mut periods: Vec<DriftPeriod> = Vec::new();
+    for meeting in meetings {
+        let local = meeting.with_time_zone(tz.clone());
+        let day_shift = local
+            .date()
+            .since(meeting.date())
+            .map_or(0, |span| span.get_days());
+        let local_time = local.strftime("%H:%M").to_string();
+        let date = meeting.date().to_string();
+        match periods.last_mut() {
+            Some(period) if period.local_time == local_time && period.day_shift == day_shift => {
+                period.to = date;
+                period.occurrences += 1;
+            }
+            _ => periods.push(DriftPeriod {
+                from: date.clone(),
+                to: date,
+                occurrences: 1,
+                local_time,
+                day_shift,
+                time_difference: format_offset_diff(
+                    local.offset().seconds() - meeting.offset().seconds(),
+                ),
+            }),
+        }
+    }
+    ParticipantDrift {
+        timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+        shifts: periods.len().saturating_sub(1),
+        periods,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meetings(zone: &str, dates: &[jiff::civil::Date], hour: i8) -> Vec<jiff::Zoned> {
+        let tz = jiff::tz::TimeZone::get(zone).unwrap();
+        dates
+            .iter()
+            .map(|date| date.at(hour, 0, 0, 0).to_zoned(tz.clone()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn occurrences_follow_frequency() {
+        let from = jiff::civil::date(2025, 3, 3);
+        let to = jiff::civil::date(2025, 3, 16);
+        assert_eq!(occurrences(from, to, Frequency::Daily).len(), 14);
+        assert_eq!(occurrences(from, to, Frequency::Weekdays).len(), 10);
+        assert_eq!(
+            occurrences(from, to, Frequency::Weekly),
+            vec![
+                jiff::civil::date(2025, 3, 3),
+                jiff::civil::date(2025, 3, 10)
+            ]
+        );
+    }
+
+    #[test]
+    fn drift_reports_the_us_eu_gap() {
+        // New York springs forward on March 9, London on March 30.
+        let dates = occurrences(
+            jiff::civil::date(2025, 3, 3),
+            jiff::civil::date(2025, 4, 7),
+            Frequency::Weekly,
+        );
+        let london = jiff::tz::TimeZone::get("Europe/London").unwrap();
+        let report = drift(&meetings("America/New_York", &dates, 10), &london);
+        assert_eq!(report.shifts, 2);
+        let times: Vec<(&str, &str, &str)> = report
+            .periods
+            .iter()
+            .map(|p| (p.from.as_str(), p.to.as_str(), p.local_time.as_str()))
+            .collect();
+        assert_eq!(
+            times,
+            vec![
+                ("2025-03-03", "2025-03-03", "15:00"),
+                ("2025-03-10", "2025-03-24", "14:00"),
+                ("2025-03-31", "2025-04-07", "15:00"),
+            ]
+        );
+        assert_eq!(report.periods[1].time_difference, "+4:00");
+    }
+
+    #[test]
+    fn drift_tracks_day_shifts() {
+        let dates = [jiff::civil::date(2025, 6, 2)];
+        let tokyo = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+        let report = drift(&meetings("America/Los_Angeles", &dates, 17), &tokyo);
+        assert_eq!(report.shifts, 0);
+        assert_eq!(report.periods[0].local_time, "09:00");
+        assert_eq!(report.periods[0].day_shift, 1);
+    }
+}
@@ -0,0 +1,285 @@
+use std::borrow::Cow;
+
+use rmcp::model::{JsonObject, Tool};
+use rustyline::{
+    CompletionType, Context, Editor, Helper, completion::Completer, error::ReadlineError,
+    highlight::Highlighter, hint::Hinter, history::DefaultHistory, validate::Validator,
+};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::local::{self, LocalClient};
+
+const PROMPT: &str = "mcp-time> ";
+
+const HELP: &str = "\
+Commands:
+  <tool> [json]    Call a tool, e.g. convert_time {\"source_timezone\": \"UTC\", \"time\": \"12:00\", \"target_timezone\": \"Asia/Tokyo\"}
+  tools            List available tools
+  describe <tool>  Show a tool's description and parameter schema
+  help             Show this help
+  quit             Leave (or press Ctrl-D)
+
+Press Tab to complete tool names, then the tool's parameter names and timezones.";
+
+/// Read commands from `input` and write their results to `output` until
+/// `quit` or end of input.
+pub async fn run(
+    client: &mut LocalClient,
+    input: impl AsyncBufRead + Unpin,
+    mut output: impl AsyncWrite + Unpin,
+) -> std::io::Result<()> {
+    let tools = client
+        .list_tools()
+        .await
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+    let mut lines = input.lines();
+    loop {
+        output.write_all(PROMPT.as_bytes()).await?;
+        output.flush().await?;
+        let Some(line) = lines.next_line().await? else {
+            break;
+        };
+        let Some(reply) = evaluate(client, &tools, &line).await else {
+            break;
+        };
+        if !reply.is_empty() {
+            output.write_all(format!("{reply}\n").as_bytes()).await?;
+        }
+    }
+    output.write_all(b"\n").await?;
+    output.flush().await
+}
+
+/// Like [`run`], reading from the terminal with line editing, history, and
+/// Tab completion of tool, parameter, and timezone names.
+pub async fn run_editor(client: &mut LocalClient) -> std::io::Result<()> {
+    let tools = client
+        .list_tools()
+        .await
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+    let config = rustyline::Config::builder()
+        .completion_type(CompletionType::List)
+        .auto_add_history(true)
+        .build();
+    let mut editor = Editor::<ToolCompleter, DefaultHistory>::with_config(config)
+        .map_err(std::io::Error::other)?;
+    editor.set_helper(Some(ToolCompleter {
+        tools: tools.clone(),
+    }));
+    loop {
+        // The editor blocks on the terminal; let other tasks use this thread.
+        let line = match tokio::task::block_in_place(|| editor.readline(PROMPT)) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(std::io::Error::other(e)),
+        };
+        let Some(reply) = evaluate(client, &tools, &line).await else {
+            break;
+        };
+        if !reply.is_empty() {
+            println!("{reply}");
+        }
+    }
+    Ok(())
+}
+
+/// Offers [`complete`] to the line editor on Tab.
+struct ToolCompleter {
+    tools: Vec<Tool>,
+}
+
+impl Completer for ToolCompleter {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        Ok((word_start(line), complete(line, &self.tools)))
+    }
+}
+
+impl Hinter for ToolCompleter {
+    type Hint = String;
+}
+
+impl Highlighter for ToolCompleter {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        Cow::Borrowed(line)
+    }
+}
+
+impl Validator for ToolCompleter {}
+
+impl Helper for ToolCompleter {}
+
+/// Reply to one line of input, or `None` to leave.
+async fn evaluate(client: &mut LocalClient, tools: &[Tool], line: &str) -> Option<String> {
+    let line = line.trim_start();
+    let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let rest = rest.trim();
+    let reply = match command {
+        "" => String::new(),
+        "quit" | "exit" => return None,
+        "help" => HELP.to_string(),
+        "tools" => tools
+            .iter()
+            .map(|tool| {
+                let summary = tool
+                    .description
+                    .as_deref()
+                    .and_then(|d| d.lines().next())
+                    .unwrap_or_default();
+                format!("{:<24}{summary}", tool.name)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "describe" => match tools.iter().find(|tool| tool.name == rest) {
+            Some(tool) => format!(
+                "{}\n\n{}",
+                tool.description.as_deref().unwrap_or_default(),
+                serde_json::to_string_pretty(&*tool.input_schema).unwrap_or_default()
+            ),
+            None => format!("Unknown tool: '{rest}'. Type 'tools' to list them."),
+        },
+        tool => {
+            let arguments = if rest.is_empty() {
+                Ok(JsonObject::new())
+            } else {
+                serde_json::from_str::<JsonObject>(rest)
+            };
+            match arguments {
+                Ok(arguments) => match client.call(tool, arguments).await {
+                    Ok(result) => local::result_text(&result),
+                    Err(e) => format!("Error: {}", e.message),
+                },
+                Err(e) => format!("Parameters must be a JSON object: {e}"),
+            }
+        }
+    };
+    Some(reply)
+}
+
+/// Where the last word of `line` starts: after the last space or JSON
+/// punctuation.
+fn word_start(line: &str) -> usize {
+    line.rfind(|c: char| c.is_whitespace() || "\"'{},:".contains(c))
+        .map_or(0, |i| i + 1)
+}
+
+/// Completions for the last word of `line`, sorted.
+///
+/// The first word completes to a tool or command name; later words complete
+/// to the tool's parameter names and to IANA timezone names.
+pub fn complete(line: &str, tools: &[Tool]) -> Vec<String> {
+    let (before, word) = line.split_at(word_start(line));
+    let matches = |candidate: &str| {
+        candidate
+            .to_ascii_lowercase()
+            .starts_with(&word.to_ascii_lowercase())
+    };
+
+    let mut candidates: Vec<String> = if before.trim().is_empty() {
+        let commands = ["describe", "help", "quit", "tools"];
+        tools
+            .iter()
+            .map(|tool| tool.name.to_string())
+            .chain(commands.iter().map(|c| c.to_string()))
+            .filter(|name| matches(name))
+            .collect()
+    } else {
+        let command = before.split_whitespace().next().unwrap_or_default();
+        let parameters = tools
+            .iter()
+            .find(|tool| tool.name == command)
+            .and_then(|tool| tool.input_schema.get("properties"))
+            .and_then(|properties| properties.as_object())
+            .map(|properties| properties.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let zones = jiff::tz::db()
+            .available()
+            .map(|name| name.as_str().to_string());
+        parameters
+            .into_iter()
+            .chain(zones)
+            .filter(|name| matches(name))
+            .collect()
+    };
+    candidates.sort();
+    candidates.dedup();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::TimeServer;
+
+    async fn tools() -> Vec<Tool> {
+        LocalClient::new(TimeServer::new())
+            .list_tools()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn complete_offers_tools_then_parameters_and_zones() {
+        let tools = tools().await;
+        assert_eq!(
+            complete("conv", &tools),
+            vec![
+                "convert_agenda",
+                "convert_calendar",
+                "convert_time",
+                "convert_time_multi",
+                "convert_timestamp"
+            ]
+        );
+        assert_eq!(
+            complete("de", &tools),
+            vec!["describe", "detect_format", "detect_local_timezone"]
+        );
+        assert_eq!(
+            complete(r#"convert_time {"source_t"#, &tools),
+            vec!["source_timezone"]
+        );
+        assert_eq!(
+            complete(r#"get_current_time {"timezone": "europe/ber"#, &tools),
+            vec!["Europe/Berlin"]
+        );
+    }
+
+    #[tokio::test]
+    async fn tab_completes_the_word_before_the_cursor() {
+        let completer = ToolCompleter {
+            tools: tools().await,
+        };
+        let history = DefaultHistory::new();
+        let line = r#"get_current_time {"timezone": "asia/tok"} "#;
+        let (start, candidates) = completer
+            .complete(line, line.len() - 3, &Context::new(&history))
+            .unwrap();
+        assert_eq!(&line[start..line.len() - 3], "asia/tok");
+        assert_eq!(candidates, vec!["Asia/Tokyo"]);
+    }
+
+    #[tokio::test]
+    async fn run_evaluates_commands_until_quit() {
+        let mut client = LocalClient::new(TimeServer::new());
+        let input: &[u8] =
+            b"tools\nget_current_time {\"timezone\": \"Asia/Tokyo\"}\nhelp\nnope\nquit\ntools\n";
+        let mut output = Vec::new();
+        run(&mut client, input, &mut output).await.unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("convert_time"));
+        assert!(output.contains("\"timezone\": \"Asia/Tokyo\""));
+        assert!(output.contains("Press Tab to complete"));
+        assert!(output.contains("Error: tool not found"));
+        // Nothing after `quit` is evaluated.
+        assert_eq!(output.matches(PROMPT).count(), 5);
+    }
+}
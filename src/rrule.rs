@@ -0,0 +1,727 @@
+use std::collections::VecDeque;
+
+use jiff::{
+    Span, Timestamp,
+    civil::{Date, DateTime, Weekday},
+    tz::TimeZone,
+};
+
+/// Periods scanned for occurrences before giving up on a rule that rarely
+/// or never matches (e.g., February 30).
+const MAX_PERIODS: i64 = 200_000;
+
+const WEEKDAYS: [(&str, Weekday); 7] = [
+    ("MO", Weekday::Monday),
+    ("TU", Weekday::Tuesday),
+    ("WE", Weekday::Wednesday),
+    ("TH", Weekday::Thursday),
+    ("FR", Weekday::Friday),
+    ("SA", Weekday::Saturday),
+    ("SU", Weekday::Sunday),
+];
+
+/// How often a rule repeats: its FREQ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+    Weekly,
+    Daily,
+    Hourly,
+    Minutely,
+}
+
+/// The zone a DTSTART line reads its wall-clock time in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StartZone {
+    /// No zone: the time is read in the caller's timezone.
+    Floating,
+    Utc,
+    /// A `TZID` parameter.
+    Named(String),
+}
+
+/// A DTSTART line given with the rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Start {
+    pub datetime: DateTime,
+    pub zone: StartZone,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Until {
+    Utc(Timestamp),
+    Local(DateTime),
+}
+
+/// An iCalendar (RFC 5545) recurrence rule.
+///
+/// Supports FREQ from YEARLY to MINUTELY with INTERVAL, COUNT, UNTIL,
+/// BYMONTH, BYMONTHDAY, BYDAY, BYHOUR, BYMINUTE, BYSECOND, BYSETPOS, and
+/// WKST; BYYEARDAY and BYWEEKNO are rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRule {
+    pub frequency: Frequency,
+    interval: i64,
+    count: Option<u32>,
+    until: Option<Until>,
+    by_month: Vec<i32>,
+    by_month_day: Vec<i32>,
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_hour: Vec<i32>,
+    by_minute: Vec<i32>,
+    by_second: Vec<i32>,
+    by_set_pos: Vec<i32>,
+    week_start: Weekday,
+    /// DTSTART given alongside the rule, if any.
+    pub start: Option<Start>,
+}
+
+impl RRule {
+    /// Parse a rule such as "FREQ=WEEKLY;BYDAY=MO,WE" or "RRULE:FREQ=DAILY",
+    /// optionally preceded by a DTSTART line (e.g.,
+    /// "DTSTART;TZID=Europe/Paris:20250106T090000").
+    pub fn parse(input: &str) -> Result<RRule, String> {
+        let mut start = None;
+        let mut body = None;
+        for line in input.split_whitespace() {
+            let upper = line.to_ascii_uppercase();
+            if upper.starts_with("DTSTART") {
+                start = Some(parse_start(line)?);
+            } else if body.is_some() {
+                return Err(format!(
+                    "Unexpected '{line}': give one RRULE, optionally after a DTSTART line."
+                ));
+            } else if upper.starts_with("RRULE:") {
+                body = Some(&line["RRULE:".len()..]);
+            } else if line.contains(':') {
+                return Err(format!(
+                    "Unsupported line: '{line}'. Expected an RRULE, optionally after a DTSTART line."
+                ));
+            } else {
+                body = Some(line);
+            }
+        }
+        let body = body.ok_or("Missing RRULE: expected e.g. 'FREQ=WEEKLY;BYDAY=MO'.")?;
+
+        let mut frequency = None;
+        let mut rule = RRule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            by_hour: Vec::new(),
+            by_minute: Vec::new(),
+            by_second: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Monday,
+            start,
+        };
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid RRULE part: '{part}'. Expected KEY=VALUE."))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "YEARLY" => Frequency::Yearly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "WEEKLY" => Frequency::Weekly,
+                        "DAILY" => Frequency::Daily,
+                        "HOURLY" => Frequency::Hourly,
+                        "MINUTELY" => Frequency::Minutely,
+                        _ => {
+                            return Err(format!(
+                                "Unsupported FREQ: '{value}'. Expected YEARLY, MONTHLY, WEEKLY, DAILY, HOURLY, or MINUTELY."
+                            ));
+                        }
+                    })
+                }
+                "INTERVAL" => rule.interval = i64::from(number(key, value, 1, 10_000)?),
+                "COUNT" => rule.count = Some(number(key, value, 1, i32::MAX)? as u32),
+                "UNTIL" => {
+                    let (datetime, utc) = parse_stamp(value)
+                        .ok_or_else(|| format!("Invalid UNTIL: '{value}'. Expected e.g. 20251231 or 20251231T235959Z."))?;
+                    rule.until = Some(if utc {
+                        Until::Utc(
+                            datetime
+                                .to_zoned(TimeZone::UTC)
+                                .map_err(|e| format!("Invalid UNTIL: '{value}': {e}."))?
+                                .timestamp(),
+                        )
+                    } else if value.contains(['T', 't']) {
+                        Until::Local(datetime)
+                    } else {
+                        // A date-only UNTIL includes that whole day.
+                        Until::Local(datetime.date().at(23, 59, 59, 999_999_999))
+                    });
+                }
+                "BYMONTH" => rule.by_month = numbers(key, value, 1, 12, false)?,
+                "BYMONTHDAY" => rule.by_month_day = numbers(key, value, 1, 31, true)?,
+                "BYHOUR" => rule.by_hour = numbers(key, value, 0, 23, false)?,
+                "BYMINUTE" => rule.by_minute = numbers(key, value, 0, 59, false)?,
+                "BYSECOND" => rule.by_second = numbers(key, value, 0, 59, false)?,
+                "BYSETPOS" => rule.by_set_pos = numbers(key, value, 1, 366, true)?,
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(|day| {
+                            parse_weekday(day).ok_or_else(|| {
+                                format!("Invalid BYDAY: '{day}'. Expected a day like MO, or an nth day like 1MO or -1FR.")
+                            })
+                        })
+                        .collect::<Result<_, _>>()?
+                }
+                "WKST" => {
+                    rule.week_start = match parse_weekday(value) {
+                        Some((None, weekday)) => weekday,
+                        _ => return Err(format!("Invalid WKST: '{value}'. Expected a day like MO.")),
+                    }
+                }
+                "BYYEARDAY" | "BYWEEKNO" => {
+                    return Err(format!("Unsupported RRULE part: {key} is not supported."));
+                }
+                _ => return Err(format!("Unknown RRULE part: '{key}'.")),
+            }
+        }
+        rule.frequency = frequency.ok_or("Missing FREQ: expected e.g. 'FREQ=WEEKLY'.")?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err("COUNT and UNTIL cannot both be given.".to_string());
+        }
+        if !matches!(rule.frequency, Frequency::Yearly | Frequency::Monthly)
+            && rule.by_day.iter().any(|(nth, _)| nth.is_some())
+        {
+            return Err(
+                "BYDAY with a number (e.g., 1MO) needs FREQ=MONTHLY or FREQ=YEARLY.".to_string(),
+            );
+        }
+        Ok(rule)
+    }
+
+    /// Occurrences of the rule anchored at `start`, in order, with UNTIL
+    /// read in `tz`.
+    ///
+    /// Like most implementations, `start` is only an occurrence when it
+    /// matches the rule. Without COUNT, periods ending before `from` are
+    /// skipped rather than scanned.
+    pub fn occurrences(&self, start: DateTime, tz: &TimeZone, from: DateTime) -> Occurrences<'_> {
+        let until = self.until.map(|until| match until {
+            Until::Utc(instant) => instant.to_zoned(tz.clone()).datetime(),
+            Until::Local(datetime) => datetime,
+        });
+        Occurrences {
+            rule: self,
+            start,
+            until,
+            period: self.first_period(start, from),
+            scanned: 0,
+            emitted: 0,
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// The earliest period that can hold occurrences from `from` onwards.
+    fn first_period(&self, start: DateTime, from: DateTime) -> i64 {
+        if self.count.is_some() || from <= start {
+            return 0;
+        }
+        let days = start
+            .date()
+            .until(from.date())
+            .map_or(0, |span| i64::from(span.get_days()));
+        let hours = days * 24 + i64::from(from.hour() - start.hour());
+        let units = match self.frequency {
+            Frequency::Yearly => i64::from(from.year() - start.year()),
+            Frequency::Monthly => {
+                i64::from(from.year() - start.year()) * 12 + i64::from(from.month() - start.month())
+            }
+            Frequency::Weekly => (days + self.days_into_week(start.date())) / 7,
+            Frequency::Daily => days,
+            Frequency::Hourly => hours,
+            Frequency::Minutely => hours * 60 + i64::from(from.minute() - start.minute()),
+        };
+        (units / self.interval - 1).max(0)
+    }
+
+    fn days_into_week(&self, date: Date) -> i64 {
+        i64::from(
+            (date.weekday().to_monday_zero_offset() - self.week_start.to_monday_zero_offset())
+                .rem_euclid(7),
+        )
+    }
+
+    /// Candidate occurrences in period `period` after `start`, in order, or
+    /// `None` once periods run out of range.
+    fn candidates(&self, start: DateTime, period: i64) -> Option<Vec<DateTime>> {
+        let step = period.checked_mul(self.interval)?;
+        let or_start = |list: &[i32], default: i8| -> Vec<i8> {
+            if list.is_empty() {
+                vec![default]
+            } else {
+                list.iter().map(|&value| value as i8).collect()
+            }
+        };
+        let (dates, hours, minutes) = match self.frequency {
+            Frequency::Hourly | Frequency::Minutely => {
+                let (anchor, span) = if self.frequency == Frequency::Hourly {
+                    (
+                        start.date().at(start.hour(), 0, 0, 0),
+                        Span::new().try_hours(step),
+                    )
+                } else {
+                    (
+                        start.date().at(start.hour(), start.minute(), 0, 0),
+                        Span::new().try_minutes(step),
+                    )
+                };
+                let at = anchor.checked_add(span.ok()?).ok()?;
+                let minutes = if self.frequency == Frequency::Hourly {
+                    or_start(&self.by_minute, start.minute())
+                } else if contains(&self.by_minute, at.minute()) {
+                    vec![at.minute()]
+                } else {
+                    Vec::new()
+                };
+                if !self.day_matches(at.date()) || !contains(&self.by_hour, at.hour()) {
+                    return Some(Vec::new());
+                }
+                (vec![at.date()], vec![at.hour()], minutes)
+            }
+            _ => (
+                self.dates(start.date(), step)?,
+                or_start(&self.by_hour, start.hour()),
+                or_start(&self.by_minute, start.minute()),
+            ),
+        };
+        let seconds = or_start(&self.by_second, start.second());
+        let mut all = Vec::new();
+        for date in dates {
+            for &hour in &hours {
+                for &minute in &minutes {
+                    for &second in &seconds {
+                        all.push(date.at(hour, minute, second, 0));
+                    }
+                }
+            }
+        }
+        if !self.by_set_pos.is_empty() {
+            let len = all.len() as i32;
+            let mut selected: Vec<DateTime> = self
+                .by_set_pos
+                .iter()
+                .filter_map(|&pos| {
+                    let index = if pos > 0 { pos - 1 } else { len + pos };
+                    all.get(usize::try_from(index).ok()?).copied()
+                })
+                .collect();
+            selected.sort();
+            selected.dedup();
+            all = selected;
+        }
+        Some(all)
+    }
+
+    /// Dates in period `step` of a daily or longer rule anchored at `start`.
+    fn dates(&self, start: Date, step: i64) -> Option<Vec<Date>> {
+        Some(match self.frequency {
+            Frequency::Yearly => {
+                let year = i16::try_from(i64::from(start.year()) + step).ok()?;
+                if !self.by_month.is_empty() {
+                    let mut dates = Vec::new();
+                    for &month in &self.by_month {
+                        dates.extend(self.month_dates(year, month as i8, start)?);
+                    }
+                    dates
+                } else if !self.by_day.is_empty() || !self.by_month_day.is_empty() {
+                    let first = Date::new(year, 1, 1).ok()?;
+                    self.select(span(first, first.days_in_year()))
+                } else {
+                    Date::new(year, start.month(), start.day())
+                        .into_iter()
+                        .collect()
+                }
+            }
+            Frequency::Monthly => {
+                let index = i64::from(start.year()) * 12 + i64::from(start.month() - 1) + step;
+                let year = i16::try_from(index.div_euclid(12)).ok()?;
+                let month = (index.rem_euclid(12) + 1) as i8;
+                if !contains(&self.by_month, month) {
+                    return Some(Vec::new());
+                }
+                self.month_dates(year, month, start)?
+            }
+            Frequency::Weekly => {
+                let first = start
+                    .checked_sub(Span::new().days(self.days_into_week(start)))
+                    .ok()?
+                    .checked_add(Span::new().try_weeks(step).ok()?)
+                    .ok()?;
+                span(first, 7)
+                    .into_iter()
+                    .filter(|date| {
+                        let weekday = if self.by_day.is_empty() {
+                            date.weekday() == start.weekday()
+                        } else {
+                            self.by_day.iter().any(|&(_, day)| day == date.weekday())
+                        };
+                        weekday && contains(&self.by_month, date.month())
+                    })
+                    .collect()
+            }
+            Frequency::Daily | Frequency::Hourly | Frequency::Minutely => {
+                let date = start.checked_add(Span::new().try_days(step).ok()?).ok()?;
+                Some(date)
+                    .filter(|&date| self.day_matches(date))
+                    .into_iter()
+                    .collect()
+            }
+        })
+    }
+
+    /// Dates in one month of a monthly or yearly rule: BYMONTHDAY and BYDAY,
+    /// or the start's day of the month when neither is given.
+    fn month_dates(&self, year: i16, month: i8, start: Date) -> Option<Vec<Date>> {
+        let first = Date::new(year, month, 1).ok()?;
+        let days = span(first, i16::from(first.days_in_month()));
+        if self.by_day.is_empty() && self.by_month_day.is_empty() {
+            return Some(
+                days.into_iter()
+                    .filter(|date| date.day() == start.day())
+                    .collect(),
+            );
+        }
+        Some(self.select(days))
+    }
+
+    /// The dates in `days`, a whole month or year, that BYMONTHDAY and BYDAY
+    /// allow, with BYDAY numbers counting within `days`.
+    fn select(&self, days: Vec<Date>) -> Vec<Date> {
+        let len = days.len() as i32;
+        days.into_iter()
+            .enumerate()
+            .filter(|&(index, date)| {
+                let index = index as i32;
+                let weekday = self.by_day.is_empty()
+                    || self.by_day.iter().any(|&(nth, day)| {
+                        day == date.weekday()
+                            && match nth {
+                                None => true,
+                                Some(n) if n > 0 => index / 7 + 1 == n,
+                                Some(n) => (len - 1 - index) / 7 + 1 == -n,
+                            }
+                    });
+                weekday && self.month_day_matches(date)
+            })
+            .map(|(_, date)| date)
+            .collect()
+    }
+
+    fn month_day_matches(&self, date: Date) -> bool {
+        let day = i32::from(date.day());
+        let from_end = day - i32::from(date.days_in_month()) - 1;
+        self.by_month_day.is_empty() || self.by_month_day.iter().any(|&n| n == day || n == from_end)
+    }
+
+    /// Whether a daily or shorter rule allows `date`.
+    fn day_matches(&self, date: Date) -> bool {
+        contains(&self.by_month, date.month())
+            && self.month_day_matches(date)
+            && (self.by_day.is_empty() || self.by_day.iter().any(|&(_, day)| day == date.weekday()))
+    }
+}
+
+/// Iterator over a rule's occurrences, from [`RRule::occurrences`].
+#[derive(Debug)]
+pub struct Occurrences<'a> {
+    rule: &'a RRule,
+    start: DateTime,
+    until: Option<DateTime>,
+    period: i64,
+    scanned: i64,
+    emitted: u32,
+    pending: VecDeque<DateTime>,
+    done: bool,
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        while !self.done {
+            if let Some(datetime) = self.pending.pop_front() {
+                let counted_out = self.rule.count.is_some_and(|count| self.emitted >= count);
+                if counted_out || self.until.is_some_and(|until| datetime > until) {
+                    self.done = true;
+                    return None;
+                }
+                self.emitted += 1;
+                return Some(datetime);
+            }
+            if self.scanned >= MAX_PERIODS {
+                self.done = true;
+                return None;
+            }
+            match self.rule.candidates(self.start, self.period) {
+                Some(candidates) => self.pending.extend(
+                    candidates
+                        .into_iter()
+                        .filter(|&datetime| datetime >= self.start),
+                ),
+                None => self.done = true,
+            }
+            self.period += 1;
+            self.scanned += 1;
+        }
+        None
+    }
+}
+
+/// `len` consecutive dates from `first`.
+fn span(first: Date, len: i16) -> Vec<Date> {
+    first
+        .series(Span::new().days(1))
+        .take(len as usize)
+        .collect()
+}
+
+/// Whether a BY list allows `value`; an empty list allows everything.
+fn contains(list: &[i32], value: i8) -> bool {
+    list.is_empty() || list.contains(&i32::from(value))
+}
+
+fn number(key: &str, value: &str, low: i32, high: i32) -> Result<i32, String> {
+    value
+        .parse()
+        .ok()
+        .filter(|n| (low..=high).contains(n))
+        .ok_or_else(|| format!("Invalid {key}: '{value}'. Expected a number from {low} to {high}."))
+}
+
+/// A comma-separated BY list of numbers from `low` to `high`, or from
+/// `-high` to `-low` too when `negative`.
+fn numbers(
+    key: &str,
+    value: &str,
+    low: i32,
+    high: i32,
+    negative: bool,
+) -> Result<Vec<i32>, String> {
+    let mut list = value
+        .split(',')
+        .map(|item| {
+            item.parse::<i32>()
+                .ok()
+                .filter(|n| (low..=high).contains(n) || (negative && (-high..=-low).contains(n)))
+                .ok_or_else(|| {
+                    let range = if negative {
+                        format!("from {low} to {high}, or -{high} to -{low} counting from the end")
+                    } else {
+                        format!("from {low} to {high}")
+                    };
+                    format!("Invalid {key}: '{item}'. Expected numbers {range}.")
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    list.sort();
+    list.dedup();
+    Ok(list)
+}
+
+/// A BYDAY entry such as "MO", "+2TU", or "-1FR".
+fn parse_weekday(input: &str) -> Option<(Option<i32>, Weekday)> {
+    let split = input.len().checked_sub(2)?;
+    let (nth, day) = input.split_at_checked(split)?;
+    let weekday = WEEKDAYS
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(day))?
+        .1;
+    if nth.is_empty() {
+        return Some((None, weekday));
+    }
+    let n: i32 = nth.trim_start_matches('+').parse().ok()?;
+    ((1..=53).contains(&n.abs())).then_some((Some(n), weekday))
+}
+
+/// A DATE or DATE-TIME value, and whether it ends in `Z` (UTC).
+fn parse_stamp(value: &str) -> Option<(DateTime, bool)> {
+    let (value, utc) = match value.strip_suffix(['Z', 'z']) {
+        Some(value) => (value, true),
+        None => (value, false),
+    };
+    let datetime = if value.contains(['T', 't']) {
+        DateTime::strptime("%Y%m%dT%H%M%S", value.to_ascii_uppercase()).ok()?
+    } else {
+        Date::strptime("%Y%m%d", value).ok()?.at(0, 0, 0, 0)
+    };
+    Some((datetime, utc))
+}
+
+/// A DTSTART line, with an optional TZID or VALUE=DATE parameter.
+fn parse_start(line: &str) -> Result<Start, String> {
+    let invalid = || {
+        format!(
+            "Invalid DTSTART: '{line}'. Expected e.g. DTSTART:20250106T090000Z or DTSTART;TZID=Europe/Paris:20250106T090000."
+        )
+    };
+    let (head, value) = line.split_once(':').ok_or_else(invalid)?;
+    let (datetime, utc) = parse_stamp(value).ok_or_else(invalid)?;
+    let mut zone = if utc {
+        StartZone::Utc
+    } else {
+        StartZone::Floating
+    };
+    for param in head.split(';').skip(1) {
+        match param.split_once('=') {
+            Some((key, tzid)) if key.eq_ignore_ascii_case("TZID") && !utc => {
+                zone = StartZone::Named(tzid.to_string());
+            }
+            Some((key, _)) if key.eq_ignore_ascii_case("VALUE") => {}
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(Start { datetime, zone })
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+
+    fn list(rule: &str, start: DateTime, n: usize) -> Vec<String> {
+        RRule::parse(rule)
+            .unwrap()
+            .occurrences(start, &TimeZone::UTC, start)
+            .take(n)
+            .map(|datetime| datetime.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parse_reads_parts_and_rejects_bad_rules() {
+        let rule = RRule::parse(
+            "DTSTART;TZID=Europe/Paris:20250106T090000\nRRULE:FREQ=weekly;BYDAY=MO,we;INTERVAL=2",
+        )
+        .unwrap();
+        assert_eq!(rule.frequency, Frequency::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(
+            rule.by_day,
+            [(None, Weekday::Monday), (None, Weekday::Wednesday)]
+        );
+        assert_eq!(
+            rule.start,
+            Some(Start {
+                datetime: date(2025, 1, 6).at(9, 0, 0, 0),
+                zone: StartZone::Named("Europe/Paris".to_string()),
+            })
+        );
+        for (input, expected) in [
+            ("BYDAY=MO", "Missing FREQ"),
+            ("FREQ=SECONDLY", "Unsupported FREQ"),
+            ("FREQ=DAILY;COUNT=2;UNTIL=20250101", "cannot both"),
+            ("FREQ=WEEKLY;BYDAY=1MO", "needs FREQ=MONTHLY"),
+            ("FREQ=MONTHLY;BYMONTHDAY=0", "Invalid BYMONTHDAY"),
+            ("FREQ=YEARLY;BYWEEKNO=1", "not supported"),
+            ("FREQ=DAILY;COLOR=RED", "Unknown RRULE part"),
+            ("EXDATE:20250101", "Unsupported line"),
+        ] {
+            let err = RRule::parse(input).unwrap_err();
+            assert!(err.contains(expected), "Error for '{input}' was: {err}");
+        }
+    }
+
+    #[test]
+    fn occurrences_expand_by_parts() {
+        // 2025-01-06 is a Monday.
+        let start = date(2025, 1, 6).at(9, 30, 0, 0);
+        assert_eq!(
+            list("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR", start, 4),
+            [
+                "2025-01-06T09:30:00",
+                "2025-01-10T09:30:00",
+                "2025-01-20T09:30:00",
+                "2025-01-24T09:30:00"
+            ]
+        );
+        assert_eq!(
+            list("FREQ=MONTHLY;BYDAY=-1FR;BYHOUR=17;BYMINUTE=0", start, 2),
+            ["2025-01-31T17:00:00", "2025-02-28T17:00:00"]
+        );
+        // The last weekday of each month.
+        assert_eq!(
+            list("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", start, 3),
+            [
+                "2025-01-31T09:30:00",
+                "2025-02-28T09:30:00",
+                "2025-03-31T09:30:00"
+            ]
+        );
+        assert_eq!(
+            list("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH", start, 2),
+            ["2025-11-27T09:30:00", "2026-11-26T09:30:00"]
+        );
+        // Months without a 31st are skipped.
+        assert_eq!(
+            list("FREQ=MONTHLY", date(2025, 1, 31).at(8, 0, 0, 0), 3),
+            [
+                "2025-01-31T08:00:00",
+                "2025-03-31T08:00:00",
+                "2025-05-31T08:00:00"
+            ]
+        );
+        assert_eq!(
+            list("FREQ=HOURLY;INTERVAL=5;BYHOUR=9,14", start, 3),
+            [
+                "2025-01-06T09:30:00",
+                "2025-01-06T14:30:00",
+                "2025-01-11T09:30:00"
+            ]
+        );
+    }
+
+    #[test]
+    fn count_and_until_end_the_rule() {
+        let start = date(2025, 1, 6).at(9, 0, 0, 0);
+        assert_eq!(list("FREQ=DAILY;COUNT=3", start, 10).len(), 3);
+        assert_eq!(
+            list("FREQ=DAILY;UNTIL=20250108", start, 10),
+            [
+                "2025-01-06T09:00:00",
+                "2025-01-07T09:00:00",
+                "2025-01-08T09:00:00"
+            ]
+        );
+        assert!(list("FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30", start, 1).is_empty());
+
+        // UTC UNTIL is read in the rule's zone: 09:00 in Tokyo is 00:00Z.
+        let rule = RRule::parse("FREQ=DAILY;UNTIL=20250107T000000Z").unwrap();
+        let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+        assert_eq!(rule.occurrences(start, &tokyo, start).count(), 2);
+    }
+
+    #[test]
+    fn skipping_to_from_matches_scanning() {
+        let start = date(2024, 3, 4).at(10, 15, 0, 0);
+        let from = date(2025, 7, 9).at(12, 0, 0, 0);
+        for rule in [
+            "FREQ=MINUTELY;INTERVAL=7",
+            "FREQ=HOURLY;INTERVAL=5",
+            "FREQ=DAILY;INTERVAL=3",
+            "FREQ=WEEKLY;INTERVAL=3;BYDAY=MO,SU;WKST=SU",
+            "FREQ=MONTHLY;INTERVAL=5",
+            "FREQ=YEARLY;INTERVAL=2;BYMONTH=1,8",
+        ] {
+            let rule = RRule::parse(rule).unwrap();
+            let next = |from| {
+                rule.occurrences(start, &TimeZone::UTC, from)
+                    .find(|&datetime| datetime >= date(2025, 7, 9).at(12, 0, 0, 0))
+            };
+            assert_eq!(next(from), next(start), "{rule:?}");
+        }
+    }
+}
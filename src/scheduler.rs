@@ -0,0 +1,369 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Which tool a scheduled entry was created with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    /// A message from `schedule_notification`.
+    #[default]
+    Notification,
+    /// A countdown from `set_timer`, whose message is its label.
+    Countdown,
+}
+
+impl Kind {
+    pub fn is_notification(&self) -> bool {
+        *self == Kind::Notification
+    }
+
+    /// Prefix of the IDs handed out for this kind (e.g., "countdown-3").
+    fn id_prefix(self) -> &'static str {
+        match self {
+            Kind::Notification => "notification",
+            Kind::Countdown => "countdown",
+        }
+    }
+}
+
+/// A message waiting to be delivered as a server notification.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ScheduledNotification {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Kind::is_notification")]
+    pub kind: Kind,
+    pub message: String,
+    /// For timers set with a duration, the duration as given (e.g., "25
+    /// minutes").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    /// Delivery instant (RFC 3339) in the zone it was scheduled in.
+    pub fire_at: String,
+    /// Delivery instant in UTC.
+    pub fire_at_utc: jiff::Timestamp,
+    pub timezone: String,
+    /// Set when the delivery instant passed while the server was not running.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub missed: bool,
+}
+
+/// A pending notification and the task that will deliver it, once there
+/// is one.
+struct Entry {
+    notification: ScheduledNotification,
+    /// `None` while the notification is held (see [`Scheduler::hold`]).
+    task: Option<tokio::task::AbortHandle>,
+}
+
+/// Registry of pending notifications, each delivered by its own task.
+///
+/// Clones share the same registry.
+#[derive(Clone, Default)]
+pub struct Scheduler {
+    next_id: Arc<AtomicU64>,
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+impl Scheduler {
+    /// Schedule `message` for delivery at `fire_at`.
+    ///
+    /// When the instant arrives the entry is removed and `deliver` is called
+    /// with it. Instants in the past are delivered immediately.
+    pub fn schedule<F, Fut>(
+        &self,
+        message: String,
+        fire_at: &jiff::Zoned,
+        deliver: F,
+    ) -> ScheduledNotification
+    where
+        F: FnOnce(ScheduledNotification) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        self.create(Kind::Notification, message, None, fire_at, deliver)
+    }
+
+    /// Set a countdown named `label` to fire at `fire_at`, delivered
+    /// as [`Scheduler::schedule`] delivers messages.
+    ///
+    /// Countdowns share the registry and ID counter of notifications, with IDs
+    /// such as "countdown-3".
+    pub fn set_countdown<F, Fut>(
+        &self,
+        label: String,
+        duration: Option<String>,
+        fire_at: &jiff::Zoned,
+        deliver: F,
+    ) -> ScheduledNotification
+    where
+        F: FnOnce(ScheduledNotification) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        self.create(Kind::Countdown, label, duration, fire_at, deliver)
+    }
+
+    /// Re-register a notification saved by an earlier server process,
+    /// keeping its ID, without delivering it until [`Scheduler::release`].
+    ///
+    /// A held notification is pending like any other: it is listed, saved,
+    /// and can be cancelled.
+    pub fn hold(&self, notification: ScheduledNotification) {
+        self.lock().insert(
+            notification.id.clone(),
+            Entry {
+                notification,
+                task: None,
+            },
+        );
+    }
+
+    /// Start delivering every held notification with `deliver`, once a
+    /// client is there to receive them.
+    ///
+    /// Notifications whose instant has already passed are flagged as
+    /// `missed` and delivered immediately.
+    pub fn release<F, Fut>(&self, deliver: F)
+    where
+        F: Fn(ScheduledNotification) -> Fut + Clone + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let now = jiff::Timestamp::now();
+        let mut entries = self.lock();
+        for entry in entries.values_mut().filter(|entry| entry.task.is_none()) {
+            entry.notification.missed = entry.notification.fire_at_utc <= now;
+            entry.task = Some(self.spawn(&entry.notification, deliver.clone()));
+        }
+    }
+
+    /// Pending notifications, soonest first.
+    pub fn pending(&self) -> Vec<ScheduledNotification> {
+        let mut pending: Vec<ScheduledNotification> = self
+            .lock()
+            .values()
+            .map(|entry| entry.notification.clone())
+            .collect();
+        pending.sort_by_key(|n| n.fire_at_utc);
+        pending
+    }
+
+    /// Counter used to number new notification IDs.
+    pub fn last_id(&self) -> u64 {
+        self.next_id.load(Ordering::Relaxed)
+    }
+
+    /// Continue numbering IDs after `last_id`, so restored and new
+    /// notifications never share an ID.
+    pub fn resume_ids_after(&self, last_id: u64) {
+        self.next_id.fetch_max(last_id, Ordering::Relaxed);
+    }
+
+    /// Cancel a pending notification, returning it if it had not yet fired.
+    pub fn cancel(&self, id: &str) -> Option<ScheduledNotification> {
+        let entry = self.lock().remove(id)?;
+        if let Some(task) = entry.task {
+            task.abort();
+        }
+        Some(entry.notification)
+    }
+
+    /// Number a new entry of `kind` and register it.
+    fn create<F, Fut>(
+        &self,
+        kind: Kind,
+        message: String,
+        duration: Option<String>,
+        fire_at: &jiff::Zoned,
+        deliver: F,
+    ) -> ScheduledNotification
+    where
+        F: FnOnce(ScheduledNotification) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let notification = ScheduledNotification {
+            id: format!(
+                "{}-{}",
+                kind.id_prefix(),
+                self.next_id.fetch_add(1, Ordering::Relaxed) + 1
+            ),
+            kind,
+            message,
+            duration,
+            fire_at: fire_at.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+            fire_at_utc: fire_at.timestamp(),
+            timezone: fire_at.time_zone().iana_name().unwrap_or("UTC").to_string(),
+            missed: false,
+        };
+        self.insert(notification.clone(), deliver);
+        notification
+    }
+
+    /// Register `notification` and spawn the task that delivers it.
+    fn insert<F, Fut>(&self, notification: ScheduledNotification, deliver: F)
+    where
+        F: FnOnce(ScheduledNotification) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        // Holding the lock until the entry is inserted keeps an immediately
+        // due task from looking for its entry before it exists.
+        let mut entries = self.lock();
+        let task = self.spawn(&notification, deliver);
+        entries.insert(
+            notification.id.clone(),
+            Entry {
+                notification,
+                task: Some(task),
+            },
+        );
+    }
+
+    /// Spawn the task that removes `notification`'s entry and delivers it
+    /// when it is due. Callers hold the registry lock.
+    fn spawn<F, Fut>(
+        &self,
+        notification: &ScheduledNotification,
+        deliver: F,
+    ) -> tokio::task::AbortHandle
+    where
+        F: FnOnce(ScheduledNotification) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let delay = jiff::Timestamp::now()
+            .duration_until(notification.fire_at_utc)
+            .try_into()
+            .unwrap_or_default();
+        let registry = Arc::clone(&self.entries);
+        let task_id = notification.id.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let entry = registry
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&task_id);
+            if let Some(entry) = entry {
+                deliver(entry.notification).await;
+            }
+        })
+        .abort_handle()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn in_millis(millis: i64) -> jiff::Zoned {
+        jiff::Zoned::now()
+            .with_time_zone(jiff::tz::TimeZone::UTC)
+            .checked_add(jiff::SignedDuration::from_millis(millis))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn schedule_delivers_when_due() {
+        let scheduler = Scheduler::default();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let scheduled = scheduler.schedule("stand up".into(), &in_millis(10), |n| async move {
+            let _ = tx.send(n);
+        });
+        let delivered = rx.await.unwrap();
+        assert_eq!(delivered.id, scheduled.id);
+        assert_eq!(delivered.message, "stand up");
+        assert!(scheduler.cancel(&scheduled.id).is_none());
+    }
+
+    #[tokio::test]
+    async fn cancel_prevents_delivery() {
+        let scheduler = Scheduler::default();
+        let (tx, rx) = tokio::sync::oneshot::channel::<ScheduledNotification>();
+        let scheduled = scheduler.schedule("later".into(), &in_millis(60_000), |n| async move {
+            let _ = tx.send(n);
+        });
+        let cancelled = scheduler.cancel(&scheduled.id).unwrap();
+        assert_eq!(cancelled.message, "later");
+        // Aborting the task drops the sender without delivering.
+        assert!(rx.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn schedule_assigns_unique_ids() {
+        let scheduler = Scheduler::default();
+        let a = scheduler.schedule("a".into(), &in_millis(60_000), |_| async {});
+        let b = scheduler.schedule("b".into(), &in_millis(60_000), |_| async {});
+        assert_ne!(a.id, b.id);
+        assert_eq!(a.timezone, "UTC");
+    }
+
+    #[tokio::test]
+    async fn release_flags_missed_notifications_and_keeps_ids() {
+        let scheduler = Scheduler::default();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let past = ScheduledNotification {
+            id: "notification-7".into(),
+            kind: Kind::Notification,
+            message: "while down".into(),
+            duration: None,
+            fire_at: "2025-01-01T00:00:00+00:00".into(),
+            fire_at_utc: "2025-01-01T00:00:00Z".parse().unwrap(),
+            timezone: "UTC".into(),
+            missed: false,
+        };
+        scheduler.resume_ids_after(7);
+        scheduler.hold(past);
+        // Held notifications are pending but not delivered.
+        assert_eq!(scheduler.pending()[0].id, "notification-7");
+        tokio::task::yield_now().await;
+        assert!(rx.try_recv().is_err());
+
+        scheduler.release(move |n| {
+            let tx = tx.clone();
+            async move {
+                let _ = tx.send(n);
+            }
+        });
+        let delivered = rx.recv().await.unwrap();
+        assert_eq!(delivered.id, "notification-7");
+        assert!(delivered.missed);
+        assert!(scheduler.pending().is_empty());
+
+        let next = scheduler.schedule("new".into(), &in_millis(60_000), |_| async {});
+        assert_eq!(next.id, "notification-8");
+    }
+
+    #[tokio::test]
+    async fn countdowns_share_the_id_counter_with_their_own_prefix() {
+        let scheduler = Scheduler::default();
+        let a = scheduler.schedule("a".into(), &in_millis(60_000), |_| async {});
+        let countdown = scheduler.set_countdown(
+            "tea".into(),
+            Some("4 minutes".into()),
+            &in_millis(60_000),
+            |_| async {},
+        );
+        assert_eq!(a.id, "notification-1");
+        assert_eq!(countdown.id, "countdown-2");
+        assert_eq!(countdown.kind, Kind::Countdown);
+        assert_eq!(countdown.duration.as_deref(), Some("4 minutes"));
+        assert_eq!(scheduler.cancel("countdown-2").unwrap().message, "tea");
+    }
+
+    #[tokio::test]
+    async fn pending_lists_soonest_first() {
+        let scheduler = Scheduler::default();
+        scheduler.schedule("later".into(), &in_millis(120_000), |_| async {});
+        scheduler.schedule("sooner".into(), &in_millis(60_000), |_| async {});
+        let pending: Vec<String> = scheduler.pending().into_iter().map(|n| n.message).collect();
+        assert_eq!(pending, vec!["sooner", "later"]);
+    }
+}
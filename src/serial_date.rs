@@ -0,0 +1,189 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Milliseconds in one day; serial dates carry the time of day as a
+/// fraction of a day, which spreadsheets store to millisecond precision.
+const MILLIS_PER_DAY: f64 = 86_400_000.0;
+
+/// Spreadsheet date system a serial number is counted in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SerialDateSystem {
+    /// Excel's default system: serial 1 is 1900-01-01 and serial 60 is the
+    /// nonexistent 1900-02-29 kept for Lotus 1-2-3 compatibility.
+    #[default]
+    #[serde(rename = "excel_1900")]
+    Excel1900,
+    /// Excel's 1904 system (older Mac workbooks): serial 0 is 1904-01-01.
+    #[serde(rename = "excel_1904")]
+    Excel1904,
+    /// Google Sheets and LibreOffice: serial 0 is 1899-12-30, no leap-year bug.
+    Sheets,
+}
+
+/// Day counted as serial 0 for systems with a plain linear epoch.
+fn epoch(system: SerialDateSystem) -> jiff::civil::Date {
+    match system {
+        SerialDateSystem::Excel1900 | SerialDateSystem::Sheets => jiff::civil::date(1899, 12, 30),
+        SerialDateSystem::Excel1904 => jiff::civil::date(1904, 1, 1),
+    }
+}
+
+/// Note attached to 1900-system conversions affected by the leap-year bug.
+pub const LEAP_YEAR_BUG_NOTE: &str = "Excel's 1900 system counts a nonexistent 1900-02-29, so serials before 61 are one day off from Google Sheets and LibreOffice.";
+
+/// Convert a serial date number to a local (offset-free) datetime.
+///
+/// The integer part counts days from the system's epoch and the fractional
+/// part is the time of day, rounded to the millisecond.
+pub fn serial_to_datetime(
+    serial: f64,
+    system: SerialDateSystem,
+) -> Result<jiff::civil::DateTime, String> {
+    if !serial.is_finite() {
+        return Err("Serial date must be a finite number.".into());
+    }
+    if serial < 0.0 && system != SerialDateSystem::Sheets {
+        return Err("Excel serial dates cannot be negative.".into());
+    }
+
+    let total_millis = (serial * MILLIS_PER_DAY).round();
+    let mut days = (total_millis / MILLIS_PER_DAY).floor() as i64;
+    let millis = (total_millis - days as f64 * MILLIS_PER_DAY) as i64;
+
+    if system == SerialDateSystem::Excel1900 {
+        match days {
+            60 => {
+                return Err(
+                    "Serial 60 is 1900-02-29 in Excel's 1900 system, a date that does not exist (kept for Lotus 1-2-3 compatibility)."
+                        .into(),
+                );
+            }
+            // Before the phantom leap day, Excel is one day ahead of a linear count.
+            0..60 => days += 1,
+            _ => {}
+        }
+    }
+
+    let out_of_range = || format!("Serial date {serial} is outside the supported date range.");
+    // Spans carry a single sign, so negative days and the (always positive)
+    // time of day are added separately.
+    let days = jiff::Span::new()
+        .try_days(days)
+        .map_err(|_| out_of_range())?;
+    epoch(system)
+        .to_datetime(jiff::civil::Time::midnight())
+        .checked_add(days)
+        .and_then(|dt| dt.checked_add(jiff::SignedDuration::from_millis(millis)))
+        .map_err(|_| out_of_range())
+}
+
+/// Convert a local (offset-free) datetime to a serial date number.
+pub fn datetime_to_serial(
+    dt: jiff::civil::DateTime,
+    system: SerialDateSystem,
+) -> Result<f64, String> {
+    let mut days = i64::from((dt.date() - epoch(system)).get_days());
+    if system == SerialDateSystem::Excel1900 {
+        if dt.date() < jiff::civil::date(1900, 1, 1) {
+            return Err(
+                "Excel's 1900 date system cannot represent dates before 1900-01-01.".into(),
+            );
+        }
+        if dt.date() < jiff::civil::date(1900, 3, 1) {
+            days -= 1;
+        }
+    }
+    if days < 0 && system != SerialDateSystem::Sheets {
+        return Err(format!(
+            "Dates before {} cannot be represented in this date system.",
+            epoch(system)
+        ));
+    }
+
+    let time = dt.time();
+    let millis = (i64::from(time.hour()) * 3_600_000)
+        + (i64::from(time.minute()) * 60_000)
+        + (i64::from(time.second()) * 1_000)
+        + i64::from(time.millisecond());
+    Ok(days as f64 + millis as f64 / MILLIS_PER_DAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serial_to_datetime_converts_modern_excel_date() {
+        let dt = serial_to_datetime(45842.5, SerialDateSystem::Excel1900).unwrap();
+        assert_eq!(dt.to_string(), "2025-07-04T12:00:00");
+    }
+
+    #[test]
+    fn serial_to_datetime_applies_leap_year_bug_before_march_1900() {
+        let excel = serial_to_datetime(1.0, SerialDateSystem::Excel1900).unwrap();
+        assert_eq!(excel.date().to_string(), "1900-01-01");
+        let sheets = serial_to_datetime(1.0, SerialDateSystem::Sheets).unwrap();
+        assert_eq!(sheets.date().to_string(), "1899-12-31");
+        let excel = serial_to_datetime(61.0, SerialDateSystem::Excel1900).unwrap();
+        assert_eq!(excel.date().to_string(), "1900-03-01");
+    }
+
+    #[test]
+    fn serial_to_datetime_rejects_phantom_leap_day() {
+        let err = serial_to_datetime(60.25, SerialDateSystem::Excel1900).unwrap_err();
+        assert!(err.contains("1900-02-29"), "Error was: {err}");
+    }
+
+    #[test]
+    fn serial_to_datetime_uses_1904_epoch() {
+        let dt = serial_to_datetime(0.0, SerialDateSystem::Excel1904).unwrap();
+        assert_eq!(dt.to_string(), "1904-01-01T00:00:00");
+        let dt = serial_to_datetime(44380.5, SerialDateSystem::Excel1904).unwrap();
+        assert_eq!(dt.to_string(), "2025-07-04T12:00:00");
+    }
+
+    #[test]
+    fn serial_to_datetime_handles_negative_sheets_serials() {
+        let dt = serial_to_datetime(-1.25, SerialDateSystem::Sheets).unwrap();
+        assert_eq!(dt.to_string(), "1899-12-28T18:00:00");
+        assert!(serial_to_datetime(-1.0, SerialDateSystem::Excel1900).is_err());
+    }
+
+    #[test]
+    fn datetime_to_serial_round_trips() {
+        let dt = jiff::civil::datetime(2025, 7, 4, 18, 30, 0, 0);
+        for system in [
+            SerialDateSystem::Excel1900,
+            SerialDateSystem::Excel1904,
+            SerialDateSystem::Sheets,
+        ] {
+            let serial = datetime_to_serial(dt, system).unwrap();
+            assert_eq!(serial_to_datetime(serial, system).unwrap(), dt);
+        }
+    }
+
+    #[test]
+    fn datetime_to_serial_accounts_for_leap_year_bug() {
+        let serial = datetime_to_serial(
+            jiff::civil::datetime(1900, 1, 1, 0, 0, 0, 0),
+            SerialDateSystem::Excel1900,
+        )
+        .unwrap();
+        assert_eq!(serial, 1.0);
+        let serial = datetime_to_serial(
+            jiff::civil::datetime(1900, 3, 1, 0, 0, 0, 0),
+            SerialDateSystem::Excel1900,
+        )
+        .unwrap();
+        assert_eq!(serial, 61.0);
+    }
+
+    #[test]
+    fn datetime_to_serial_rejects_dates_before_epoch() {
+        let dt = jiff::civil::datetime(1899, 6, 1, 0, 0, 0, 0);
+        assert!(datetime_to_serial(dt, SerialDateSystem::Excel1900).is_err());
+        assert!(datetime_to_serial(dt, SerialDateSystem::Excel1904).is_err());
+        assert!(datetime_to_serial(dt, SerialDateSystem::Sheets).unwrap() < 0.0);
+    }
+}
@@ -1,69 +1,240 @@
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex, OnceLock},
+};
+
 use rmcp::{
-    ServerHandler,
-    handler::server::{tool::ToolRouter, wrapper::Parameters},
-    model::{CallToolResult, Content, Implementation, ServerCapabilities, ServerInfo},
-    tool, tool_handler, tool_router,
+    Peer, RoleServer, ServerHandler, ServiceExt,
+    handler::server::tool::{ToolCallContext, ToolRouter},
+    model::{
+        AnnotateAble, ArgumentInfo, CallToolRequestParams, CallToolResult, CompleteRequestParams,
+        CompleteResult, CompletionInfo, Content, GetPromptRequestParams, GetPromptResult,
+        Implementation, ListPromptsResult, ListResourceTemplatesResult, ListResourcesResult,
+        ListToolsResult, LoggingLevel, LoggingMessageNotificationParam, PaginatedRequestParams,
+        RawResource, RawResourceTemplate, ReadResourceRequestParams, ReadResourceResult, Reference,
+        ResourceContents, ResourceUpdatedNotificationParam, ServerCapabilities, ServerInfo,
+        SetLevelRequestParams, SubscribeRequestParams, Tool, UnsubscribeRequestParams,
+    },
+    service::{NotificationContext, QuitReason, RequestContext},
+    transport::IntoTransport,
 };
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
+use tracing::Instrument;
+use tracing_subscriber::EnvFilter;
+
+use crate::{
+    business_days,
+    calendar_system::{self, CalendarDate, CalendarSystem},
+    cities,
+    clock::{Clock, SystemClock},
+    config::ServerConfig,
+    core::{Ambiguity, Error as ToolError, Gap, format_utc_offset},
+    cron,
+    datetime_format::DatetimeFormat,
+    detect::{self, FormatCandidate},
+    display_names::{self, Language},
+    dst::{self, DstFinding},
+    duration::{self, Anchor, SpanUnit},
+    epoch::{self, EpochUnit, EpochValue, NormalizedEpoch},
+    expression::{self, Expression, Start, Step},
+    heartbeat, holidays,
+    ics::{self, IcsEvent, InvalidEvent},
+    limits::{self, Guard, Limits},
+    local_day::{self, InvalidTimestamp, LocalDay},
+    locale::Locale,
+    locations, meeting,
+    metrics::{Metrics, ToolStats},
+    middleware::Middleware,
+    natural::{self, Phrase},
+    prompts,
+    recurrence::{self, Frequency, ParticipantDrift},
+    rrule::{self, RRule},
+    scheduler::{Kind, ScheduledNotification, Scheduler},
+    serial_date::{self, SerialDateSystem},
+    sql_literal::{self, SqlDialect, SqlLiteral},
+    state::{Persistence, StateFile},
+    sun,
+    telemetry::{self, LogFormat},
+    tick::{self, TICK_URI},
+    timers::{TimerStatus, Timers},
+    transitions::{
+        self, AbbreviationMeaning, AbbreviationPeriod, ComparisonChange, ComparisonRow,
+        DifferencePeriod, DstEvent, Granularity, PairImpact, ZoneImpact, ZoneTransition,
+    },
+    transport::TransportError,
+    tzdb, unicode,
+    validate::{self, Expected, Validation},
+    world_clock::{self, WORLD_CLOCK_URI},
+    zone_info::{self, ZONE_URI_PREFIX},
+};
 
-/// Error message template for invalid timezone input.
-const ERR_INVALID_TIMEZONE: &str =
-    "Invalid timezone: '{}'. Please use a valid IANA timezone name (e.g., 'America/New_York').";
+mod calendars;
+#[cfg(feature = "ntp")]
+mod clock_drift;
+mod conversion;
+mod core_time;
+mod geography;
+mod scheduling;
+mod schema;
+
+pub use calendars::{
+    AbbreviationCalendarParams, BusinessDaysParams, CompareTimezonesParams, ConvertCalendarParams,
+    DstImpactParams, FindMeetingTimeParams, GetCalendarInfoParams, GetDstTransitionsParams,
+    GetHolidaysParams, GlobalDstEventsParams, IsHolidayParams, MeetingZone, NextOccurrencesParams,
+    RecurrenceDriftParams,
+};
+#[cfg(feature = "ntp")]
+pub use clock_drift::CheckClockDriftParams;
+pub use conversion::{
+    AuditDstParams, ConvertTimestampParams, DetectFormatParams, FormatDurationParams,
+    GroupByLocalDayParams, NormalizeEpochsParams, ParseDurationParams, ParseIcsParams,
+    SerialDateParams, SqlLiteralParams, ValidateDatetimeParams,
+};
+pub use core_time::{
+    AgendaEvent, ConvertAgendaParams, ConvertTimeMultiParams, ConvertTimeParams, DayBoundary,
+    EvaluateParams, ExplainTimeParams, GetCurrentTimeParams, GetWorldClockParams,
+    HumanizeTimeParams, ParseTimeParams, PhraseUnit, TimeArithmeticParams, TimeDifferenceParams,
+    TimeUntilParams, TravelArrivalParams,
+};
+pub use geography::{GetSunTimesParams, ListTimezonesParams, TimezoneGeoParams};
+pub use scheduling::{
+    CancelNotificationParams, CancelTimerParams, ListScheduledParams, ListTimersParams,
+    ScheduleNotificationParams, SetTimerParams, StopwatchParams, WaitParams,
+};
 
 /// Error message template for invalid time format input.
-const ERR_INVALID_TIME_FORMAT: &str =
-    "Invalid time format: '{}'. Expected HH:MM in 24-hour format (e.g., '14:30').";
-
-/// MCP server providing time-related tools.
-///
-/// Exposes `get_current_time` and `convert_time` as MCP tools over stdio transport.
-pub struct TimeServer {
-    pub tool_router: ToolRouter<Self>,
-}
-
-/// Parameters for the `get_current_time` tool.
-#[derive(Debug, Deserialize, JsonSchema)]
-pub struct GetCurrentTimeParams {
-    /// IANA timezone name (e.g., 'America/New_York', 'Europe/London', 'Asia/Tokyo'). Defaults to UTC.
-    #[serde(default)]
-    pub timezone: Option<String>,
+const ERR_INVALID_TIME_FORMAT: &str = "Invalid time format: '{}'. Expected HH:MM in 24-hour format (e.g., '14:30') or a 12-hour time with AM/PM (e.g., '2:30 PM').";
+
+/// Environment variable that enables lenient parsing of time inputs.
+pub const LENIENT_PARSING_ENV: &str = "MCP_TIME_LENIENT_PARSING";
+
+/// Environment variable naming the zone used when a timezone is omitted.
+pub const DEFAULT_TIMEZONE_ENV: &str = "MCP_TIME_DEFAULT_TIMEZONE";
+
+/// Error message template for datetime inputs that cannot be parsed.
+const ERR_INVALID_DATETIME: &str = "Invalid datetime: '{}'. Expected RFC 3339 (e.g., '2025-07-04T12:00:00Z'), a local datetime (e.g., '2025-07-04T12:00'), or a date (e.g., '2025-07-04').";
+
+/// Error message template for dates that cannot be parsed.
+const ERR_INVALID_DATE: &str = "Invalid date: '{}'. Expected YYYY-MM-DD (e.g., '2025-03-01').";
+
+/// A set of related tools that can be served on its own or combined with
+/// other groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolGroup {
+    /// `get_current_time`, `get_world_clock`, `convert_time`,
+    /// `convert_time_multi`, `convert_agenda`, `evaluate`, `explain_time`, `parse_time`,
+    /// `add_time`, `subtract_time`, `time_difference`, `time_until`,
+    /// `humanize_time`, `travel_arrival`, and, with the `ntp` feature,
+    /// `check_clock_drift`.
+    CoreTime,
+    /// Timestamp inspection and conversion: `detect_format`,
+    /// `validate_datetime`, `normalize_epochs`, `convert_timestamp`, `group_by_local_day`,
+    /// `audit_dst`, `serial_date`, `sql_literal`, `parse_ics`,
+    /// `parse_duration`, and `format_duration`.
+    Conversion,
+    /// Timezone, holiday, and working calendars and recurrences:
+    /// `abbreviation_calendar`, `get_dst_transitions`, `global_dst_events`,
+    /// `recurrence_drift`, `dst_impact`, `business_days`, `get_holidays`,
+    /// `is_holiday`, `next_occurrences`, `get_calendar_info`,
+    /// `convert_calendar`, `find_meeting_time`, and `compare_timezones`.
+    Calendars,
+    /// Finding zones, where they are used, and daylight there:
+    /// `list_timezones`, `timezone_geo`, `get_sun_times`,
+    /// `detect_local_timezone`, and `get_tzdb_info`.
+    Geography,
+    /// Notifications, waits, timers, and server diagnostics:
+    /// `schedule_notification`, `cancel_notification`, `list_scheduled`,
+    /// `set_timer`, `list_timers`, `cancel_timer`, `wait`, the
+    /// stopwatch tools, and `server_stats`.
+    Scheduling,
 }
 
-/// Parameters for the `convert_time` tool.
-#[derive(Debug, Deserialize, JsonSchema)]
-pub struct ConvertTimeParams {
-    /// Source IANA timezone name (e.g., 'America/New_York')
-    pub source_timezone: String,
-    /// Time to convert in 24-hour format (HH:MM)
-    pub time: String,
-    /// Target IANA timezone name (e.g., 'Europe/London')
-    pub target_timezone: String,
-}
-
-/// Response payload for `get_current_time`.
-#[derive(Debug, Serialize)]
-struct CurrentTimeResponse {
-    timezone: String,
-    datetime: String,
-    utc_offset: String,
-    is_dst: bool,
+impl ToolGroup {
+    /// Every tool group, in the order tools are listed.
+    pub const ALL: [ToolGroup; 5] = [
+        ToolGroup::CoreTime,
+        ToolGroup::Conversion,
+        ToolGroup::Calendars,
+        ToolGroup::Geography,
+        ToolGroup::Scheduling,
+    ];
+
+    /// The router fragment serving this group's tools.
+    pub fn router(self) -> ToolRouter<TimeServer> {
+        match self {
+            #[cfg(feature = "ntp")]
+            ToolGroup::CoreTime => {
+                TimeServer::core_time_router() + TimeServer::clock_drift_router()
+            }
+            #[cfg(not(feature = "ntp"))]
+            ToolGroup::CoreTime => TimeServer::core_time_router(),
+            ToolGroup::Conversion => TimeServer::conversion_router(),
+            ToolGroup::Calendars => TimeServer::calendars_router(),
+            ToolGroup::Geography => TimeServer::geography_router(),
+            ToolGroup::Scheduling => TimeServer::scheduling_router(),
+        }
+    }
 }
 
-/// Source or target entry in the convert_time response.
-#[derive(Debug, Serialize)]
-struct ConvertTimeEntry {
-    timezone: String,
-    datetime: String,
-    utc_offset: String,
+/// One router serving the tools of every group in `groups`.
+fn compose_routers(groups: &[ToolGroup]) -> ToolRouter<TimeServer> {
+    groups
+        .iter()
+        .fold(ToolRouter::new(), |router, group| router + group.router())
 }
 
-/// Response payload for `convert_time`.
-#[derive(Debug, Serialize)]
-struct ConvertTimeResponse {
-    source: ConvertTimeEntry,
-    target: ConvertTimeEntry,
-    time_difference: String,
+/// MCP server providing time-related tools.
+///
+/// Exposes time lookup, timezone conversion, and timestamp inspection tools,
+/// plus subscribable clock tick and world clock dashboard resources, over
+/// stdio or Streamable HTTP.
+pub struct TimeServer {
+    pub tool_router: ToolRouter<Self>,
+    /// Notification tasks for active resource subscriptions, keyed by URI.
+    subscriptions: Arc<Mutex<HashMap<String, tokio::task::AbortHandle>>>,
+    /// Messages registered with `schedule_notification`.
+    scheduler: Scheduler,
+    /// Named stopwatches managed by the stopwatch tools.
+    timers: Timers,
+    /// Per-tool call counts and latencies reported by `server_stats`.
+    metrics: Metrics,
+    /// Where notifications and timers are saved, when persistence is enabled.
+    persistence: Option<Persistence>,
+    /// Whether timezone parameters fall back to city names (e.g., "Mumbai").
+    allow_city_names: bool,
+    /// Whether time inputs may omit separators (e.g., "1430").
+    lenient_parsing: bool,
+    /// Zone used when a tool's timezone parameter is omitted.
+    default_timezone: jiff::tz::TimeZone,
+    /// Language of zone display names added to responses, if any.
+    language: Option<Language>,
+    /// Interval between heartbeat notifications sent while running, if any.
+    heartbeat: Option<std::time::Duration>,
+    /// How the logger installed by `run` and `serve_http` writes lines.
+    pub(crate) log_format: LogFormat,
+    /// OTLP/HTTP traces URL that `run` and `serve_http` export spans to.
+    pub(crate) otlp_endpoint: Option<String>,
+    /// Middleware wrapped around the routes of an HTTP server.
+    pub(crate) http_middleware: Middleware,
+    /// IANA zones shown on the world clock dashboard, in display order.
+    world_clock_zones: Vec<String>,
+    /// How often subscribers to the world clock dashboard are notified.
+    world_clock_refresh_interval: std::time::Duration,
+    /// Holiday rules served by `get_holidays` and `is_holiday`.
+    holidays: Arc<holidays::Database>,
+    /// Source of "now" for tools and resources.
+    clock: Arc<dyn Clock>,
+    /// Names of tools hidden from `tools/list` and rejected on call.
+    disabled_tools: Arc<Mutex<HashSet<String>>>,
+    /// Connected client, once initialized; used to announce tool list changes.
+    peer: Arc<Mutex<Option<Peer<RoleServer>>>>,
+    /// Rate limit and argument caps checked before every tool call.
+    guard: Guard,
+    /// NTP server queried by `check_clock_drift` when none is given.
+    #[cfg(feature = "ntp")]
+    ntp_server: String,
 }
 
 impl Default for TimeServer {
@@ -76,291 +247,1239 @@ impl TimeServer {
     /// Create a new TimeServer with tool routing configured.
     pub fn new() -> Self {
         Self {
-            tool_router: Self::tool_router(),
+            tool_router: compose_routers(&ToolGroup::ALL),
+            subscriptions: Arc::default(),
+            scheduler: Scheduler::default(),
+            timers: Timers::default(),
+            metrics: Metrics::default(),
+            persistence: None,
+            allow_city_names: false,
+            lenient_parsing: false,
+            default_timezone: jiff::tz::TimeZone::UTC,
+            language: None,
+            heartbeat: None,
+            log_format: LogFormat::Text,
+            otlp_endpoint: None,
+            http_middleware: Middleware::default(),
+            world_clock_zones: vec!["UTC".into()],
+            world_clock_refresh_interval: world_clock::DEFAULT_REFRESH_INTERVAL,
+            holidays: holidays::Database::bundled(),
+            clock: Arc::new(SystemClock),
+            disabled_tools: Arc::default(),
+            peer: Arc::default(),
+            guard: Guard::default(),
+            #[cfg(feature = "ntp")]
+            ntp_server: crate::ntp::DEFAULT_SERVER.into(),
         }
     }
-}
 
-#[tool_router]
-impl TimeServer {
-    /// Get the current time in a specific timezone. Defaults to UTC if no timezone is provided.
-    #[tool(
-        name = "get_current_time",
-        description = "Get the current time in a specific timezone. Defaults to UTC if no timezone is provided."
-    )]
-    pub async fn get_current_time(
-        &self,
-        Parameters(params): Parameters<GetCurrentTimeParams>,
-    ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let tz_input = params.timezone.unwrap_or_default();
-        let tz = if tz_input.is_empty() {
-            jiff::tz::TimeZone::UTC
-        } else {
-            match parse_timezone(&tz_input) {
-                Ok(tz) => tz,
-                Err(msg) => return Ok(tool_error(msg)),
-            }
-        };
+    /// Create a TimeServer that saves scheduled notifications and timers to
+    /// `path` and restores them from it.
+    ///
+    /// Restored notifications are pending straight away, so they are listed,
+    /// kept by every save, and can be cancelled, but are only delivered once
+    /// a client has initialized; any whose time passed while the server was
+    /// down are then delivered immediately, flagged as `missed`.
+    pub fn with_state_file(path: impl Into<std::path::PathBuf>) -> Result<Self, String> {
+        let file = StateFile::new(path);
+        let state = file.load()?;
+        let mut server = Self::new();
+        server.timers.import(state.timers);
+        server
+            .scheduler
+            .resume_ids_after(state.last_notification_id);
+        for notification in state.notifications {
+            server.scheduler.hold(notification);
+        }
+        server.persistence = Some(Persistence::new(
+            file,
+            server.scheduler.clone(),
+            server.timers.clone(),
+        ));
+        Ok(server)
+    }
 
-        let now = jiff::Zoned::now().with_time_zone(tz.clone());
-        let datetime = now.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
-        let utc_offset = format_utc_offset(now.offset());
+    /// Accept city names such as "Berlin" or "Mumbai" wherever a timezone is
+    /// expected, resolving them to their IANA zone when the input is not
+    /// already a valid zone name. Off by default.
+    pub fn allow_city_names(mut self, allow: bool) -> Self {
+        self.allow_city_names = allow;
+        self
+    }
 
-        // Determine DST status using jiff's offset info, which provides
-        // authoritative DST data from the timezone database.
-        let info = tz.to_offset_info(now.timestamp());
-        let is_dst = info.dst().is_dst();
+    /// Accept time inputs that omit the colon, reading bare 3–4 digit values
+    /// like "930" and "1430" as 09:30 and 14:30. Off by default.
+    pub fn lenient_parsing(mut self, lenient: bool) -> Self {
+        self.lenient_parsing = lenient;
+        self
+    }
 
-        let tz_name = tz.iana_name().unwrap_or("UTC").to_string();
+    /// Use `tz` wherever a tool's timezone parameter is omitted, instead of
+    /// UTC.
+    pub fn default_timezone(mut self, tz: jiff::tz::TimeZone) -> Self {
+        self.default_timezone = tz;
+        self
+    }
 
-        let response = CurrentTimeResponse {
-            timezone: tz_name,
-            datetime,
-            utc_offset,
-            is_dst,
-        };
+    /// Add zone display names in `language` (e.g., "Nueva York" for
+    /// "America/New_York" in Spanish) to `get_current_time` and
+    /// `convert_time` responses.
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
 
-        let json = serde_json::to_string_pretty(&response).map_err(|e| {
-            rmcp::ErrorData::internal_error(format!("Failed to serialize response: {e}"), None)
-        })?;
+    /// Send a heartbeat notification every `interval` while the server is
+    /// running (see [`TimeServer::run`]).
+    pub fn heartbeat(mut self, interval: std::time::Duration) -> Self {
+        self.heartbeat = Some(interval);
+        self
+    }
 
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+    /// Write the log lines of [`TimeServer::run`] as `format`. Text by
+    /// default.
+    pub fn log_format(mut self, format: LogFormat) -> Self {
+        self.log_format = format;
+        self
     }
 
-    /// Convert a time from one timezone to another.
-    #[tool(
-        name = "convert_time",
-        description = "Convert a time from one timezone to another."
-    )]
-    pub async fn convert_time(
-        &self,
-        Parameters(params): Parameters<ConvertTimeParams>,
-    ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let source_tz = match parse_timezone(&params.source_timezone) {
-            Ok(tz) => tz,
-            Err(msg) => return Ok(tool_error(msg)),
-        };
+    /// Export the `tool_call` spans of [`TimeServer::run`] and
+    /// [`crate::transport::serve_http`] to the OTLP/HTTP traces URL
+    /// `endpoint` (e.g., "http://localhost:4318/v1/traces"). Needs the `otlp`
+    /// feature; without it the endpoint is ignored.
+    pub fn otlp_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.otlp_endpoint = Some(endpoint.into());
+        self
+    }
 
-        let target_tz = match parse_timezone(&params.target_timezone) {
-            Ok(tz) => tz,
-            Err(msg) => return Ok(tool_error(msg)),
-        };
+    /// Wrap the routes of [`crate::transport::serve_http`] in `middleware`
+    /// (e.g., to require a bearer token). None by default.
+    pub fn http_middleware(mut self, middleware: Middleware) -> Self {
+        self.http_middleware = middleware;
+        self
+    }
+
+    /// Show `zones` on the world clock dashboard resource, in the order
+    /// given. Names that are not IANA zones are left off. Defaults to UTC.
+    pub fn world_clock_zones(mut self, zones: Vec<String>) -> Self {
+        self.world_clock_zones = zones;
+        self
+    }
+
+    /// Notify subscribers to the world clock dashboard resource every
+    /// `interval`, on whole multiples of it. Defaults to once a minute.
+    pub fn world_clock_refresh_interval(mut self, interval: std::time::Duration) -> Self {
+        self.world_clock_refresh_interval = interval;
+        self
+    }
+
+    /// Serve holidays from `database` (e.g., the bundled rules plus a file
+    /// of company holidays, see [`holidays::Database::load`]) instead of the
+    /// bundled rules alone.
+    pub fn holidays(mut self, database: holidays::Database) -> Self {
+        self.holidays = Arc::new(database);
+        self
+    }
+
+    /// Read "now" from `clock` instead of the system clock, e.g., a
+    /// [`crate::clock::FixedClock`] to pin every answer to one instant.
+    ///
+    /// Tools that default to the current time, and the clock tick, world
+    /// clock, and zone resources, use it. Timers, `wait`, scheduled
+    /// notifications, subscription updates, and `check_clock_drift` keep
+    /// running on real time.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Limit how often each client may call tools and how large their list
+    /// and count arguments may be. Calls over a limit get an error result
+    /// saying when to retry or how much to ask for. Unlimited by default.
+    ///
+    /// Each [`TimeServer::session`] gets its own call allowance.
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.guard = Guard::new(limits);
+        self
+    }
+
+    /// Query `server` (a host name or IP address, optionally with a port)
+    /// in `check_clock_drift` when the call names none. Defaults to
+    /// pool.ntp.org.
+    #[cfg(feature = "ntp")]
+    pub fn ntp_server(mut self, server: impl Into<String>) -> Self {
+        self.ntp_server = server.into();
+        self
+    }
+
+    /// Create a TimeServer from `config`, loading the state and holidays
+    /// files it names.
+    pub fn with_config(config: &ServerConfig) -> Result<Self, String> {
+        let mut server = match &config.state_file {
+            Some(path) => Self::with_state_file(path)?,
+            None => Self::new(),
+        }
+        .allow_city_names(config.allow_city_names)
+        .lenient_parsing(config.lenient_parsing)
+        .default_timezone(config.default_timezone.clone())
+        .world_clock_zones(config.world_clock_zones.clone())
+        .world_clock_refresh_interval(config.world_clock_refresh_interval)
+        .log_format(config.log_format)
+        .http_middleware(config.http_middleware.clone())
+        .limits(Limits {
+            calls_per_minute: config.rate_limit,
+            max_list_items: config.max_list_items,
+            max_results: config.max_results,
+        });
+        if let Some(path) = &config.holidays_file {
+            server = server.holidays(holidays::Database::load(path)?);
+        }
+        if let Some(clock) = config.freeze_time {
+            server = server.clock(clock);
+        }
+        server.heartbeat = config.heartbeat;
+        server.otlp_endpoint = config.otlp_endpoint.clone();
+        server.language = config.language;
+        #[cfg(feature = "ntp")]
+        {
+            server.ntp_server = config.ntp_server.clone();
+        }
+        Ok(server)
+    }
+
+    /// Create a TimeServer configured from `mcp-time.toml` and the
+    /// `MCP_TIME_*` environment variables, as the `mcp-time` binary does
+    /// (see [`ServerConfig::from_env`]).
+    pub fn from_env() -> Result<Self, String> {
+        Self::with_config(&ServerConfig::from_env()?)
+    }
+
+    /// Serve on `transport` until the client disconnects or the process is
+    /// asked to stop (Ctrl-C, or SIGTERM on Unix).
+    ///
+    /// Installs a stderr logger filtered by `RUST_LOG` (and the OTLP
+    /// exporter, if an [`TimeServer::otlp_endpoint`] is set) unless a global
+    /// tracing subscriber is already set, and starts the heartbeat if one is
+    /// configured. Fails with a [`TransportError`] if the connection fails
+    /// before the session is initialized; both ways of stopping return `Ok`.
+    ///
+    /// ```no_run
+    /// use mcp_time::server::TimeServer;
+    ///
+    /// # async fn example() -> anyhow::Result<()> {
+    /// TimeServer::new().run(rmcp::transport::stdio()).await
+    /// # }
+    /// ```
+    pub async fn run<T, E, A>(self, transport: T) -> anyhow::Result<()>
+    where
+        T: IntoTransport<RoleServer, E, A>,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        init_logging(self.log_format, self.otlp_endpoint.as_deref())?;
+        let heartbeat = self.heartbeat;
+        let service = self.serve(transport).await.map_err(TransportError::new)?;
+        let heartbeat =
+            heartbeat.map(|interval| heartbeat::spawn(service.peer().clone(), interval));
+        let token = service.cancellation_token();
+        let signals = tokio::spawn(async move {
+            shutdown_signal().await;
+            tracing::info!("shutting down");
+            token.cancel();
+        });
+
+        let reason = service.waiting().await;
+        signals.abort();
+        if let Some(heartbeat) = heartbeat {
+            heartbeat.abort();
+        }
+        match reason? {
+            QuitReason::Cancelled => tracing::info!("server stopped"),
+            QuitReason::Closed => tracing::info!("client disconnected"),
+            QuitReason::JoinError(e) => return Err(e.into()),
+        }
+        telemetry::flush_spans();
+        Ok(())
+    }
 
-        let trimmed_time = params.time.trim();
-
-        // Strict HH:MM format: reject anything that doesn't match exactly 5 chars (NN:NN)
-        if trimmed_time.len() != 5 || trimmed_time.as_bytes().get(2) != Some(&b':') {
-            return Ok(tool_error(ERR_INVALID_TIME_FORMAT.replacen(
-                "{}",
-                trimmed_time,
-                1,
-            )));
-        }
-
-        let time = match jiff::civil::Time::strptime("%H:%M", trimmed_time) {
-            Ok(t) => t,
-            Err(_) => {
-                return Ok(tool_error(ERR_INVALID_TIME_FORMAT.replacen(
-                    "{}",
-                    trimmed_time,
-                    1,
-                )));
+    /// A handler for another client connection, sharing this server's
+    /// configuration, scheduled notifications, timers, metrics, and disabled
+    /// tools.
+    ///
+    /// HTTP transports serve each session from its own handler (see
+    /// [`crate::transport::serve_http`]). Resource subscriptions and the
+    /// connected peer are per session, while rate limit allowances are per
+    /// client address; heartbeats are only sent by [`TimeServer::run`].
+    pub fn session(&self) -> Self {
+        Self {
+            tool_router: self.tool_router.clone(),
+            subscriptions: Arc::default(),
+            scheduler: self.scheduler.clone(),
+            timers: self.timers.clone(),
+            metrics: self.metrics.clone(),
+            persistence: self.persistence.clone(),
+            allow_city_names: self.allow_city_names,
+            lenient_parsing: self.lenient_parsing,
+            default_timezone: self.default_timezone.clone(),
+            language: self.language,
+            heartbeat: None,
+            log_format: self.log_format,
+            otlp_endpoint: self.otlp_endpoint.clone(),
+            http_middleware: self.http_middleware.clone(),
+            world_clock_zones: self.world_clock_zones.clone(),
+            world_clock_refresh_interval: self.world_clock_refresh_interval,
+            holidays: self.holidays.clone(),
+            clock: self.clock.clone(),
+            disabled_tools: self.disabled_tools.clone(),
+            peer: Arc::default(),
+            guard: self.guard.clone(),
+            #[cfg(feature = "ntp")]
+            ntp_server: self.ntp_server.clone(),
+        }
+    }
+
+    /// The world clock dashboard of the configured zones, as of now.
+    pub fn world_clock(&self, format: world_clock::Format) -> String {
+        world_clock::render(&self.world_clock_zones, self.now(), format, self.language)
+    }
+
+    /// Per-tool call metrics for this server and every session of it.
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Serve only the tools in `groups`, replacing the full tool set.
+    ///
+    /// ```
+    /// use mcp_time::server::{TimeServer, ToolGroup};
+    ///
+    /// let server = TimeServer::new().tool_groups(&[ToolGroup::CoreTime]);
+    /// assert!(server.tool_router.has_route("convert_time"));
+    /// assert!(!server.tool_router.has_route("wait"));
+    /// ```
+    pub fn tool_groups(mut self, groups: &[ToolGroup]) -> Self {
+        self.tool_router = compose_routers(groups);
+        self
+    }
+
+    /// Enable or disable a tool at runtime.
+    ///
+    /// Disabled tools disappear from `tools/list` and cannot be called. When
+    /// the set of available tools changes, connected clients are sent a
+    /// `notifications/tools/list_changed` so they re-fetch the list. Returns
+    /// whether anything changed.
+    pub async fn set_tool_enabled(&self, name: &str, enabled: bool) -> Result<bool, String> {
+        if !self.tool_router.has_route(name) {
+            return Err(format!("Unknown tool: '{name}'."));
+        }
+        let changed = {
+            let mut disabled = self
+                .disabled_tools
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            if enabled {
+                disabled.remove(name)
+            } else {
+                disabled.insert(name.to_string())
             }
         };
+        let peer = self.peer.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        if let (true, Some(peer)) = (changed, peer) {
+            // A closed connection has no tool list to refresh.
+            let _ = peer.notify_tool_list_changed().await;
+        }
+        Ok(changed)
+    }
 
-        // Use today's date in the source timezone
-        let today = jiff::Zoned::now().with_time_zone(source_tz.clone());
-        let date =
-            jiff::civil::Date::new(today.year(), today.month(), today.day()).map_err(|e| {
-                rmcp::ErrorData::internal_error(format!("Failed to create date: {e}"), None)
-            })?;
-        let datetime = date.at(time.hour(), time.minute(), 0, 0);
-
-        let source_zdt = match datetime.to_zoned(source_tz.clone()) {
-            Ok(zdt) => zdt,
-            Err(_) => {
-                return Ok(tool_error(format!(
-                    "The time {} does not exist in timezone '{}' due to a DST transition (spring forward). \
-                     Please choose a different time.",
-                    trimmed_time, params.source_timezone
-                )));
+    /// Parse a timezone parameter, falling back to city names when enabled.
+    fn resolve_timezone(&self, input: &str) -> Result<jiff::tz::TimeZone, ToolError> {
+        crate::core::resolve_timezone(input).or_else(|err| {
+            if !self.allow_city_names {
+                return Err(err);
             }
-        };
+            cities::lookup(&unicode::normalize_input(input))
+                .and_then(|zone| jiff::tz::TimeZone::get(zone).ok())
+                .ok_or(err)
+        })
+    }
 
-        let target_zdt = source_zdt.with_time_zone(target_tz.clone());
+    /// The current instant, from the configured clock.
+    fn now(&self) -> jiff::Timestamp {
+        self.clock.now()
+    }
 
-        let source_offset_secs = source_zdt.offset().seconds();
-        let target_offset_secs = target_zdt.offset().seconds();
-        let diff_secs = target_offset_secs - source_offset_secs;
-        let time_difference = format_offset_diff(diff_secs);
+    /// The current time in `tz`, from the configured clock.
+    fn now_in(&self, tz: &jiff::tz::TimeZone) -> jiff::Zoned {
+        self.now().to_zoned(tz.clone())
+    }
 
-        let source_tz_name = source_tz.iana_name().unwrap_or("UTC").to_string();
-        let target_tz_name = target_tz.iana_name().unwrap_or("UTC").to_string();
+    /// Parse an optional timezone parameter, defaulting to the configured
+    /// default timezone when it is absent or empty.
+    fn resolve_timezone_or_default(
+        &self,
+        input: Option<&str>,
+    ) -> Result<jiff::tz::TimeZone, ToolError> {
+        match input {
+            None | Some("") => Ok(self.default_timezone.clone()),
+            Some(input) => self.resolve_timezone(input),
+        }
+    }
 
-        let response = ConvertTimeResponse {
-            source: ConvertTimeEntry {
-                timezone: source_tz_name,
-                datetime: source_zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
-                utc_offset: format_utc_offset(source_zdt.offset()),
-            },
-            target: ConvertTimeEntry {
-                timezone: target_tz_name,
-                datetime: target_zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
-                utc_offset: format_utc_offset(target_zdt.offset()),
-            },
-            time_difference,
-        };
+    /// Save notifications and timers, if persistence is enabled.
+    fn save_state(&self) {
+        save_state(self.persistence.as_ref());
+    }
 
-        let json = serde_json::to_string_pretty(&response).map_err(|e| {
-            rmcp::ErrorData::internal_error(format!("Failed to serialize response: {e}"), None)
-        })?;
+    /// Save state after a timer operation that succeeded, then convert it
+    /// into a tool result.
+    fn save_timer_change(
+        &self,
+        result: Result<TimerStatus, String>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        if result.is_ok() {
+            self.save_state();
+        }
+        timer_result(result)
+    }
 
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+    /// The tool router with disabled tools removed.
+    fn enabled_tools(&self) -> ToolRouter<Self> {
+        let mut router = self.tool_router.clone();
+        for name in self
+            .disabled_tools
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+        {
+            router.remove_route(name);
+        }
+        router
     }
 }
 
-#[tool_handler]
 impl ServerHandler for TimeServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: Default::default(),
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_tool_list_changed()
+                .enable_logging()
+                .enable_completions()
+                .enable_prompts()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .build(),
             server_info: Implementation {
                 name: "mcp-time".into(),
                 version: env!("CARGO_PKG_VERSION").into(),
                 ..Default::default()
             },
             instructions: Some(
-                "A time server providing current time lookup and timezone conversion tools.".into(),
+                "A time server providing current time lookup, timezone conversion, and timestamp utility tools, with prompts for common scheduling workflows.".into(),
             ),
         }
     }
+
+    /// Check the call against the client's limits, then run the tool inside
+    /// a `tool_call` span recording its name, its
+    /// sanitized arguments and their hash, the outcome and any error code,
+    /// and the latency, joined to the client's trace when the request
+    /// carries a `traceparent`.
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let span = tracing::info_span!(
+            "tool_call",
+            tool = %request.name,
+            params = %telemetry::sanitize_params(request.arguments.as_ref()),
+            params_hash = %telemetry::params_hash(request.arguments.as_ref()),
+            traceparent = tracing::field::Empty,
+            outcome = tracing::field::Empty,
+            error_code = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+        let traceparent = request
+            .meta
+            .as_ref()
+            .and_then(telemetry::traceparent)
+            .or_else(|| telemetry::traceparent(&context.meta));
+        if let Some(traceparent) = traceparent {
+            span.record("traceparent", traceparent);
+            #[cfg(feature = "otlp")]
+            telemetry::set_remote_parent(&span, traceparent);
+        }
+
+        let started = std::time::Instant::now();
+        let router = self.enabled_tools();
+        // Only routed tools are counted, so clients cannot add names.
+        let tool = Some(request.name.to_string()).filter(|name| router.has_route(name));
+        let client = limits::client_of(&context.extensions);
+        let result = match self.guard.check(client, request.arguments.as_ref()) {
+            Ok(()) => {
+                router
+                    .call(ToolCallContext::new(self, request, context))
+                    .instrument(span.clone())
+                    .await
+            }
+            Err(error) => Ok(tool_error(error)),
+        };
+        let (outcome, error_code) = telemetry::Outcome::of(&result);
+        span.record("outcome", outcome.as_str());
+        if let Some(code) = &error_code {
+            span.record("error_code", code.as_str());
+        }
+        let latency = started.elapsed();
+        span.record("latency_ms", latency.as_secs_f64() * 1000.0);
+        if let Some(tool) = tool {
+            self.metrics.record(&tool, outcome, latency);
+        }
+        span.in_scope(|| match outcome {
+            telemetry::Outcome::InternalError => tracing::warn!("tool call failed"),
+            _ => tracing::info!("tool call finished"),
+        });
+        result
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, rmcp::ErrorData> {
+        Ok(ListToolsResult {
+            tools: self.enabled_tools().list_all(),
+            meta: None,
+            next_cursor: None,
+        })
+    }
+
+    fn get_tool(&self, name: &str) -> Option<Tool> {
+        self.enabled_tools().get(name).cloned()
+    }
+
+    async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
+        let peer = context.peer.clone();
+        let persistence = self.persistence.clone();
+        self.scheduler.release(move |notification| {
+            deliver_notification(peer.clone(), persistence.clone(), notification)
+        });
+        *self.peer.lock().unwrap_or_else(|e| e.into_inner()) = Some(context.peer);
+    }
+
+    /// Scheduled and heartbeat notifications are sent regardless of the
+    /// requested level, so the level is accepted without filtering anything.
+    async fn set_level(
+        &self,
+        _request: SetLevelRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), rmcp::ErrorData> {
+        Ok(())
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, rmcp::ErrorData> {
+        Ok(ListPromptsResult::with_all_items(prompts::list()))
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, rmcp::ErrorData> {
+        prompts::get(
+            &request.name,
+            request.arguments.as_ref(),
+            self.now(),
+            |input| self.resolve_timezone(input),
+        )
+        .map_err(|err| {
+            let data = serde_json::to_value(&err).ok();
+            rmcp::ErrorData::invalid_params(err.message, data)
+        })
+    }
+
+    /// Complete timezone arguments of prompts and of the `timezone://{+zone}`
+    /// resource template to zone names, at most 100 at a time.
+    async fn complete(
+        &self,
+        request: CompleteRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CompleteResult, rmcp::ErrorData> {
+        let ArgumentInfo { name, value } = &request.argument;
+        let mut values = match &request.r#ref {
+            Reference::Prompt(prompt) => prompts::complete(&prompt.name, name, value),
+            Reference::Resource(resource)
+                if resource.uri == format!("{ZONE_URI_PREFIX}{{+zone}}") && name == "zone" =>
+            {
+                cities::complete(value)
+            }
+            Reference::Resource(_) => Vec::new(),
+        };
+        let total = values.len();
+        values.truncate(CompletionInfo::MAX_VALUES);
+        let has_more = total > values.len();
+        let completion =
+            CompletionInfo::with_pagination(values, u32::try_from(total).ok(), has_more)
+                .map_err(|msg| rmcp::ErrorData::internal_error(msg, None))?;
+        Ok(CompleteResult { completion })
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, rmcp::ErrorData> {
+        let mut resource = RawResource::new(TICK_URI, "tick");
+        resource.description = Some(
+            "Current UTC instant. Subscribe to be notified at the top of every minute.".into(),
+        );
+        resource.mime_type = Some("application/json".into());
+        let mut dashboard = RawResource::new(WORLD_CLOCK_URI, "world-clock");
+        dashboard.description = Some(format!(
+            "Markdown world clock of the configured zones, regenerated on each read. Subscribe to be notified every {}.",
+            duration::spell(
+                &jiff::Span::try_from(self.world_clock_refresh_interval)
+                    .unwrap_or_default()
+                    .round(jiff::SpanRound::new().largest(jiff::Unit::Hour))
+                    .unwrap_or_default()
+            )
+        ));
+        dashboard.mime_type = Some(world_clock::Format::Markdown.mime_type().into());
+        let mut zones = RawResource::new(ZONE_URI_PREFIX, "timezones");
+        zones.description = Some(
+            "Every IANA zone name in the tz database. Read 'timezone://<zone>' for one zone's details.".into(),
+        );
+        zones.mime_type = Some("application/json".into());
+        Ok(ListResourcesResult::with_all_items(vec![
+            resource.no_annotation(),
+            dashboard.no_annotation(),
+            zones.no_annotation(),
+        ]))
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, rmcp::ErrorData> {
+        let template = RawResourceTemplate {
+            uri_template: format!("{TICK_URI}{{?granularity}}"),
+            name: "tick".into(),
+            title: None,
+            description: Some(
+                "Current UTC instant. Subscribers are notified each 'second', 'minute' (default), or 'hour'.".into(),
+            ),
+            mime_type: Some("application/json".into()),
+            icons: None,
+        };
+        let dashboard = RawResourceTemplate {
+            uri_template: format!("{WORLD_CLOCK_URI}{{?format}}"),
+            name: "world-clock".into(),
+            title: None,
+            description: Some(
+                "World clock of the configured zones as 'markdown' (default) or a self-contained 'html' page.".into(),
+            ),
+            mime_type: None,
+            icons: None,
+        };
+        let zone = RawResourceTemplate {
+            uri_template: format!("{ZONE_URI_PREFIX}{{+zone}}"),
+            name: "timezone".into(),
+            title: None,
+            description: Some(
+                "One IANA zone (e.g., 'America/New_York'): current offset, DST rule, transitions in the next year, and aliases.".into(),
+            ),
+            mime_type: Some("application/json".into()),
+            icons: None,
+        };
+        Ok(ListResourceTemplatesResult::with_all_items(vec![
+            template.no_annotation(),
+            dashboard.no_annotation(),
+            zone.no_annotation(),
+        ]))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, rmcp::ErrorData> {
+        if let Some(format) = world_clock::parse_uri(&request.uri) {
+            let format = format.map_err(|msg| rmcp::ErrorData::invalid_params(msg, None))?;
+            let text = self.world_clock(format);
+            return Ok(ReadResourceResult {
+                contents: vec![ResourceContents::TextResourceContents {
+                    uri: request.uri,
+                    mime_type: Some(format.mime_type().into()),
+                    text,
+                    meta: None,
+                }],
+            });
+        }
+        let json = match zone_info::parse_uri(&request.uri) {
+            Some("") => serde_json::to_string_pretty(&zone_info::index()),
+            Some(zone) => serde_json::to_string_pretty(
+                &zone_info::info(zone, self.now())
+                    .map_err(|msg| rmcp::ErrorData::resource_not_found(msg, None))?,
+            ),
+            None => {
+                let granularity = parse_tick_uri(&request.uri)?;
+                serde_json::to_string_pretty(&tick::tick(self.now(), granularity))
+            }
+        }
+        .map_err(|e| {
+            rmcp::ErrorData::internal_error(format!("Failed to serialize resource: {e}"), None)
+        })?;
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: request.uri,
+                mime_type: Some("application/json".into()),
+                text: json,
+                meta: None,
+            }],
+        })
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), rmcp::ErrorData> {
+        let period = match world_clock::parse_uri(&request.uri) {
+            Some(format) => format
+                .map(|_| self.world_clock_refresh_interval)
+                .map_err(|msg| rmcp::ErrorData::invalid_params(msg, None))?,
+            None => parse_tick_uri(&request.uri)?.period(),
+        };
+        let peer = context.peer;
+        let uri = request.uri.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(tick::until_next_multiple(jiff::Timestamp::now(), period)).await;
+                let notification = ResourceUpdatedNotificationParam { uri: uri.clone() };
+                if peer.notify_resource_updated(notification).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let previous = self
+            .subscriptions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(request.uri, task.abort_handle());
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), rmcp::ErrorData> {
+        let task = self
+            .subscriptions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&request.uri);
+        if let Some(task) = task {
+            task.abort();
+        }
+        Ok(())
+    }
+}
+
+/// Parse a resource URI as the tick resource, mapping unknown or malformed
+/// URIs to protocol errors.
+fn parse_tick_uri(uri: &str) -> Result<tick::Granularity, rmcp::ErrorData> {
+    match tick::parse_uri(uri) {
+        Some(Ok(granularity)) => Ok(granularity),
+        Some(Err(msg)) => Err(rmcp::ErrorData::invalid_params(msg, None)),
+        None => Err(rmcp::ErrorData::resource_not_found(
+            format!("Unknown resource: '{uri}'"),
+            None,
+        )),
+    }
+}
+
+/// How often `wait` reports progress: every 5% of the wait, but no more
+/// than once a second and no less than every ten seconds.
+fn progress_interval(duration: std::time::Duration) -> std::time::Duration {
+    (duration / 20).clamp(
+        std::time::Duration::from_secs(1),
+        std::time::Duration::from_secs(10),
+    )
+}
+
+/// Sleep for `duration`, returning `false` early if `cancelled` completes first.
+///
+/// With a `progress_interval`, `report` is called with the fraction of the
+/// wait elapsed (between 0 and 1) each time the interval passes.
+async fn sleep_unless_cancelled<F: Future<Output = ()>>(
+    duration: std::time::Duration,
+    cancelled: impl Future<Output = ()>,
+    progress_interval: Option<std::time::Duration>,
+    mut report: impl FnMut(f64) -> F,
+) -> bool {
+    let start = tokio::time::Instant::now();
+    let deadline = tokio::time::sleep_until(start + duration);
+    let mut cancelled = std::pin::pin!(cancelled);
+    let mut deadline = std::pin::pin!(deadline);
+    let interval = progress_interval.unwrap_or(duration);
+    let mut ticker = tokio::time::interval_at(start + interval, interval);
+
+    loop {
+        tokio::select! {
+            // Checked in order so a tick due at the same moment as the
+            // deadline or a cancellation is never reported.
+            biased;
+            () = &mut deadline => return true,
+            () = &mut cancelled => return false,
+            _ = ticker.tick(), if progress_interval.is_some() => {
+                report(start.elapsed().as_secs_f64() / duration.as_secs_f64()).await;
+            }
+        }
+    }
+}
+
+/// Install a stderr logger filtered by `RUST_LOG`, plus an OTLP span
+/// exporter when `otlp_endpoint` is set, unless a global tracing subscriber
+/// is already set. Fails if the exporter cannot be set up.
+pub(crate) fn init_logging(format: LogFormat, otlp_endpoint: Option<&str>) -> anyhow::Result<()> {
+    use tracing_subscriber::{Layer, layer::SubscriberExt, util::SubscriberInitExt};
+
+    let logger = tracing_subscriber::fmt::layer().with_writer(std::io::stderr);
+    let logger = match format {
+        LogFormat::Text => logger.boxed(),
+        LogFormat::Json => logger.json().boxed(),
+    };
+    let registry =
+        tracing_subscriber::registry().with(logger.with_filter(EnvFilter::from_default_env()));
+    #[cfg(feature = "otlp")]
+    let registry = registry.with(
+        otlp_endpoint
+            .map(telemetry::otlp_layer)
+            .transpose()
+            .map_err(anyhow::Error::msg)?,
+    );
+    #[cfg(not(feature = "otlp"))]
+    let _ = otlp_endpoint;
+    // Fails only when the embedder has installed its own subscriber.
+    let _ = registry.try_init();
+    Ok(())
+}
+
+/// Complete when the process receives Ctrl-C, or SIGTERM on Unix.
+pub(crate) async fn shutdown_signal() {
+    let ctrl_c = async {
+        if tokio::signal::ctrl_c().await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// Send a scheduled notification or fired countdown to the client as a logging
+/// message, then save the scheduler without it.
+async fn deliver_notification(
+    peer: Peer<RoleServer>,
+    persistence: Option<Persistence>,
+    notification: ScheduledNotification,
+) {
+    let logger = match notification.kind {
+        Kind::Notification => "mcp-time/schedule_notification",
+        Kind::Countdown => "mcp-time/set_timer",
+    };
+    let data = serde_json::to_value(&notification).unwrap_or_default();
+    // A closed connection has nobody left to notify.
+    let _ = peer
+        .notify_logging_message(LoggingMessageNotificationParam {
+            level: LoggingLevel::Notice,
+            logger: Some(logger.into()),
+            data,
+        })
+        .await;
+    save_state(persistence.as_ref());
+}
+
+/// Save notifications and timers, logging rather than failing on errors
+/// since the in-memory state is already up to date.
+fn save_state(persistence: Option<&Persistence>) {
+    if let Some(Err(e)) = persistence.map(Persistence::save) {
+        tracing::warn!("{e}");
+    }
+}
+
+/// Convert a timer operation into a tool result.
+fn timer_result(result: Result<TimerStatus, String>) -> Result<CallToolResult, rmcp::ErrorData> {
+    match result {
+        Ok(status) => tool_success(&status),
+        Err(msg) => Ok(tool_error(ToolError::new("invalid_timer_operation", msg))),
+    }
 }
 
 /// Construct a `CallToolResult` representing an input validation error.
 ///
-/// Sets `is_error` to `true` and wraps the message as text content.
-fn tool_error(msg: impl Into<String>) -> CallToolResult {
-    CallToolResult::error(vec![Content::text(msg)])
+/// Sets `is_error` to `true` and wraps the error as JSON text content.
+fn tool_error(error: impl Into<ToolError>) -> CallToolResult {
+    let error = error.into();
+    let json = serde_json::to_string_pretty(&error).unwrap_or(error.message);
+    CallToolResult::error(vec![Content::text(json)])
 }
 
-/// Parse and validate an IANA timezone string.
+/// Serialize a response payload into a successful `CallToolResult`.
 ///
-/// Returns an error for timezone abbreviations (e.g., "EST") and raw UTC
-/// offset strings (e.g., "+05:30", "UTC+5") with a message suggesting the
-/// IANA equivalent.
-fn parse_timezone(input: &str) -> Result<jiff::tz::TimeZone, String> {
-    // Reject raw offset strings like "+05:30", "-05:00"
-    if input.starts_with('+') || input.starts_with('-') {
-        return Err(format!(
-            "Timezone offset '{}' is not supported. Please use a valid IANA timezone name (e.g., 'Asia/Kolkata' instead of '+05:30').",
-            input
-        ));
+/// The payload is returned both as pretty-printed JSON text and, when it is
+/// a JSON object, as structured content matching the tool's output schema.
+/// Serialization failures are reported as internal errors.
+fn tool_success<T: Serialize>(response: &T) -> Result<CallToolResult, rmcp::ErrorData> {
+    let value = serde_json::to_value(response).map_err(|e| {
+        rmcp::ErrorData::internal_error(format!("Failed to serialize response: {e}"), None)
+    })?;
+    let json = serde_json::to_string_pretty(&value).map_err(|e| {
+        rmcp::ErrorData::internal_error(format!("Failed to serialize response: {e}"), None)
+    })?;
+
+    let mut result = CallToolResult::success(vec![Content::text(json)]);
+    result.structured_content = value.is_object().then_some(value);
+    Ok(result)
+}
+
+/// Output schema of a tool whose results serialize as `T`.
+///
+/// Generated for serialization, so fields skipped when empty are optional,
+/// and cached per type since routers are rebuilt for each request. Panics
+/// if `T` is not a JSON object, which the tool list tests catch.
+fn output_schema<T: JsonSchema + 'static>() -> Arc<rmcp::model::JsonObject> {
+    static SCHEMAS: OnceLock<Mutex<HashMap<TypeId, Arc<rmcp::model::JsonObject>>>> =
+        OnceLock::new();
+    let mut schemas = SCHEMAS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    schemas
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| {
+            let mut settings = schemars::generate::SchemaSettings::draft2020_12().for_serialize();
+            settings.transforms = vec![Box::new(schemars::transform::AddNullable::default())];
+            let schema = settings.into_generator().into_root_schema_for::<T>();
+            match serde_json::to_value(schema) {
+                Ok(serde_json::Value::Object(object))
+                    if object.get("type") == Some(&"object".into()) =>
+                {
+                    Arc::new(object)
+                }
+                _ => panic!(
+                    "Output schema for {} is not an object",
+                    std::any::type_name::<T>()
+                ),
+            }
+        })
+        .clone()
+}
+
+/// Parse a fixed UTC offset such as "+03:00", "-0530", "+3", "UTC+3", or
+/// "GMT-05:30" into a fixed-offset timezone.
+///
+/// Offsets beyond ±18 hours are rejected.
+fn parse_fixed_offset(input: &str) -> Option<jiff::tz::TimeZone> {
+    let input = unicode::normalize_input(input);
+    let input = input.trim();
+    let rest = input
+        .strip_prefix("UTC")
+        .or_else(|| input.strip_prefix("GMT"))
+        .unwrap_or(input);
+    let (sign, digits) = match rest.as_bytes().first()? {
+        b'+' => (1, &rest[1..]),
+        b'-' => (-1, &rest[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if digits.len() == 4 => digits.split_at_checked(2)?,
+        None => (digits, "00"),
+    };
+    let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !all_digits(hours) || hours.len() > 2 || !all_digits(minutes) || minutes.len() != 2 {
+        return None;
     }
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 18 || minutes > 59 || (hours == 18 && minutes > 0) {
+        return None;
+    }
+    let offset = jiff::tz::Offset::from_seconds(sign * (hours * 3600 + minutes * 60)).ok()?;
+    Some(jiff::tz::TimeZone::fixed(offset))
+}
 
-    // Reject "UTC+N" or "UTC-N" style offsets
-    if input.starts_with("UTC+")
-        || input.starts_with("UTC-")
-        || input.starts_with("GMT+")
-        || input.starts_with("GMT-")
+/// Parse a wall-clock time of day.
+///
+/// Accepts strict 24-hour `HH:MM` (e.g., "14:30") and 12-hour times with an
+/// AM/PM suffix in any case, with or without a space and minutes (e.g.,
+/// "2:30 PM", "2:30pm", "2pm"). "12 AM" is midnight and "12 PM" is noon.
+/// When `lenient`, bare 3–4 digit 24-hour times (e.g., "930", "1430") are
+/// accepted too.
+fn parse_clock_time(input: &str, lenient: bool) -> Result<jiff::civil::Time, ToolError> {
+    let input = &*unicode::normalize_input(input);
+    let invalid = || {
+        ToolError::new(
+            "invalid_time_format",
+            ERR_INVALID_TIME_FORMAT.replacen("{}", input, 1),
+        )
+        .with_value(input)
+    };
+
+    let lower = input.to_ascii_lowercase();
+    if let Some((clock, is_pm)) = lower
+        .strip_suffix("am")
+        .map(|c| (c, false))
+        .or_else(|| lower.strip_suffix("pm").map(|c| (c, true)))
     {
-        return Err(format!(
-            "Timezone offset '{}' is not supported. Please use a valid IANA timezone name (e.g., 'Asia/Kolkata' instead of 'UTC+5:30').",
-            input
-        ));
+        let clock = clock.trim_end();
+        let (hour, minute) = clock.split_once(':').unwrap_or((clock, "00"));
+        let valid_digits = |s: &str, max_len| {
+            !s.is_empty() && s.len() <= max_len && s.bytes().all(|b| b.is_ascii_digit())
+        };
+        if !valid_digits(hour, 2) || !valid_digits(minute, 2) || minute.len() != 2 {
+            return Err(invalid());
+        }
+        let hour: i8 = hour.parse().map_err(|_| invalid())?;
+        let minute: i8 = minute.parse().map_err(|_| invalid())?;
+        if !(1..=12).contains(&hour) {
+            return Err(invalid());
+        }
+        let hour = hour % 12 + if is_pm { 12 } else { 0 };
+        return jiff::civil::Time::new(hour, minute, 0, 0).map_err(|_| invalid());
     }
 
-    // Reject timezone abbreviations (all uppercase, no '/', short, not "UTC")
-    // before calling jiff, since some abbreviations like "EST" exist in the
-    // IANA database but are ambiguous and should not be accepted.
-    if input != "UTC"
-        && !input.contains('/')
-        && input.len() <= 5
-        && input.chars().all(|c| c.is_ascii_uppercase())
-    {
-        return Err(format!(
-            "Timezone abbreviation '{}' is ambiguous. Please use a valid IANA timezone name (e.g., 'America/New_York' instead of 'EST').",
-            input
-        ));
+    if lenient && (3..=4).contains(&input.len()) && input.bytes().all(|b| b.is_ascii_digit()) {
+        let (hour, minute) = input.split_at(input.len() - 2);
+        let hour: i8 = hour.parse().map_err(|_| invalid())?;
+        let minute: i8 = minute.parse().map_err(|_| invalid())?;
+        return jiff::civil::Time::new(hour, minute, 0, 0).map_err(|_| invalid());
     }
 
-    jiff::tz::TimeZone::get(input).map_err(|_| ERR_INVALID_TIMEZONE.replacen("{}", input, 1))
+    // Strict HH:MM format: reject anything that doesn't match exactly 5 chars (NN:NN)
+    if input.len() != 5 || input.as_bytes().get(2) != Some(&b':') {
+        return Err(invalid());
+    }
+    jiff::civil::Time::strptime("%H:%M", input).map_err(|_| invalid())
+}
+
+/// A clock time in any form [`parse_clock_time`] accepts without leniency,
+/// for parsers outside this module.
+pub(crate) fn clock_time(input: &str) -> Option<jiff::civil::Time> {
+    parse_clock_time(input, false).ok()
 }
 
-/// Format a UTC offset as "+HH:MM" or "-HH:MM".
+/// Parse a datetime input, resolving values without an offset in `tz`.
 ///
-/// Correctly handles fractional-hour offsets (e.g., +05:45 for Asia/Kathmandu).
-fn format_utc_offset(offset: jiff::tz::Offset) -> String {
-    let total_seconds = offset.seconds();
-    let sign = if total_seconds < 0 { '-' } else { '+' };
-    let abs_seconds = total_seconds.unsigned_abs();
-    let hours = abs_seconds / 3600;
-    let minutes = (abs_seconds % 3600) / 60;
-    format!("{}{:02}:{:02}", sign, hours, minutes)
+/// Accepts RFC 9557 (`2025-07-04T12:00:00-04:00[America/New_York]`) and
+/// RFC 3339 (`2025-07-04T16:00:00Z`) strings, which are converted into `tz`,
+/// as well as local datetimes (`2025-07-04T12:00`) and dates (`2025-07-04`,
+/// read as the start of that day), which are interpreted in `tz`.
+fn parse_datetime(input: &str, tz: &jiff::tz::TimeZone) -> Result<jiff::Zoned, ToolError> {
+    let input = &*unicode::normalize_input(input);
+    let invalid = || {
+        ToolError::new(
+            "invalid_datetime",
+            ERR_INVALID_DATETIME.replacen("{}", input, 1),
+        )
+        .with_value(input)
+    };
+
+    if let Ok(zdt) = input.parse::<jiff::Zoned>() {
+        return Ok(zdt.with_time_zone(tz.clone()));
+    }
+    if let Ok(ts) = input.parse::<jiff::Timestamp>() {
+        return Ok(ts.to_zoned(tz.clone()));
+    }
+    // A bare date also parses as a civil datetime at midnight, but midnight
+    // can be skipped by DST, so dates resolve to the actual start of day.
+    let has_time = input.contains(':') || input.contains('T');
+    if has_time {
+        let dt: jiff::civil::DateTime = input.parse().map_err(|_| invalid())?;
+        dt.to_zoned(tz.clone()).map_err(|_| invalid())
+    } else {
+        let date: jiff::civil::Date = input.parse().map_err(|_| invalid())?;
+        date.to_zoned(tz.clone())
+            .and_then(|zdt| zdt.start_of_day())
+            .map_err(|_| invalid())
+    }
+}
+
+/// Parse a calendar date in `YYYY-MM-DD` form.
+fn parse_date(input: &str) -> Result<jiff::civil::Date, ToolError> {
+    let input = input.trim();
+    input.parse().map_err(|_| {
+        ToolError::new("invalid_date", ERR_INVALID_DATE.replacen("{}", input, 1)).with_value(input)
+    })
+}
+
+/// Parse an optional `locale` parameter, as a BCP 47 tag such as "de-DE".
+fn parse_locale(input: Option<&str>) -> Result<Option<Locale>, ToolError> {
+    input
+        .map(|input| {
+            Locale::parse(input)
+                .map_err(|msg| ToolError::new("invalid_locale", msg).with_value(input))
+        })
+        .transpose()
+}
+
+/// The instant a duration phrase's `anchor` stands for, relative to `now`.
+///
+/// Named days resolve to their start; weekday names to the next such day,
+/// today included.
+fn resolve_anchor(
+    anchor: &Anchor,
+    now: &jiff::Zoned,
+    tz: &jiff::tz::TimeZone,
+) -> Result<jiff::Zoned, ToolError> {
+    let day_start = |days: i64| {
+        now.date()
+            .checked_add(jiff::Span::new().days(days))
+            .and_then(|date| date.to_zoned(tz.clone()))
+            .and_then(|zdt| zdt.start_of_day())
+            .map_err(|e| ToolError::from(format!("Failed to resolve '{anchor:?}': {e}")))
+    };
+    match anchor {
+        Anchor::Now => Ok(now.clone()),
+        Anchor::Today => day_start(0),
+        Anchor::Tomorrow => day_start(1),
+        Anchor::Yesterday => day_start(-1),
+        Anchor::Weekday(weekday) => day_start(i64::from(
+            (weekday.to_monday_zero_offset() - now.weekday().to_monday_zero_offset()).rem_euclid(7),
+        )),
+        Anchor::Datetime(input) => parse_datetime(input, tz),
+    }
 }
 
-/// Format an offset difference in seconds as a "+H:MM" or "-H:MM" string.
-fn format_offset_diff(diff_secs: i32) -> String {
-    let sign = if diff_secs < 0 { '-' } else { '+' };
-    let abs = diff_secs.unsigned_abs();
-    let hours = abs / 3600;
-    let minutes = (abs % 3600) / 60;
-    format!("{}{}:{:02}", sign, hours, minutes)
+/// Serde predicate for counts omitted when zero.
+fn is_zero(n: &i64) -> bool {
+    *n == 0
 }
 
 #[cfg(test)]
 mod tests {
+    use rmcp::handler::server::wrapper::Parameters;
+
     use super::*;
 
     #[test]
-    fn parse_timezone_returns_ok_for_valid_iana_name() {
-        let result = parse_timezone("America/New_York");
-        assert!(result.is_ok());
+    fn parse_fixed_offset_reads_common_forms() {
+        let offset = |input| {
+            parse_fixed_offset(input)
+                .and_then(|tz| tz.to_fixed_offset().ok())
+                .map(|o| o.seconds())
+        };
+        assert_eq!(offset("+03:00"), Some(3 * 3600));
+        assert_eq!(offset("UTC+3"), Some(3 * 3600));
+        assert_eq!(offset("GMT-05:30"), Some(-(5 * 3600 + 1800)));
+        assert_eq!(offset("-0530"), Some(-(5 * 3600 + 1800)));
+        assert_eq!(offset("+19"), None);
+        assert_eq!(offset("+3:5"), None);
+        assert_eq!(offset("Europe/Berlin"), None);
+        assert_eq!(offset("UTC"), None);
+        assert_eq!(offset("+ ẫ"), None);
     }
 
     #[test]
-    fn parse_timezone_returns_err_for_invalid_name() {
-        let result = parse_timezone("Fake/Zone");
-        assert!(result.is_err());
+    fn parse_clock_time_accepts_12_hour_inputs() {
+        let time = |h, m| jiff::civil::time(h, m, 0, 0);
+        assert_eq!(parse_clock_time("2:30 PM", false), Ok(time(14, 30)));
+        assert_eq!(parse_clock_time("2:30pm", false), Ok(time(14, 30)));
+        assert_eq!(parse_clock_time("2pm", false), Ok(time(14, 0)));
+        assert_eq!(parse_clock_time("09:05 am", false), Ok(time(9, 5)));
+        assert_eq!(parse_clock_time("12 AM", false), Ok(time(0, 0)));
+        assert_eq!(parse_clock_time("12:15 PM", false), Ok(time(12, 15)));
+        assert_eq!(parse_clock_time("14:30", false), Ok(time(14, 30)));
     }
 
     #[test]
-    fn parse_timezone_returns_err_for_abbreviation() {
-        let result = parse_timezone("PST");
-        let err = result.unwrap_err();
-        assert!(err.contains("IANA timezone name"), "Error was: {err}");
+    fn parse_clock_time_accepts_bare_digits_only_when_lenient() {
+        let time = |h, m| jiff::civil::time(h, m, 0, 0);
+        assert_eq!(parse_clock_time("930", true), Ok(time(9, 30)));
+        assert_eq!(parse_clock_time("1430", true), Ok(time(14, 30)));
+        assert_eq!(parse_clock_time("0005", true), Ok(time(0, 5)));
+        assert!(parse_clock_time("1430", false).is_err());
+        for input in ["2460", "1299", "14300", "93"] {
+            assert!(parse_clock_time(input, true).is_err(), "Accepted: {input}");
+        }
     }
 
     #[test]
-    fn parse_timezone_returns_err_for_offset_string() {
-        let result = parse_timezone("+05:30");
-        let err = result.unwrap_err();
-        assert!(err.contains("IANA timezone name"), "Error was: {err}");
+    fn schema_clock_time_examples_parse() {
+        for example in schema::CLOCK_TIME_EXAMPLES {
+            assert!(
+                parse_clock_time(example, false).is_ok(),
+                "Rejected: {example}"
+            );
+        }
     }
 
     #[test]
-    fn format_utc_offset_formats_positive_whole_hours() {
-        let offset = jiff::tz::Offset::from_seconds(5 * 3600).unwrap();
-        assert_eq!(format_utc_offset(offset), "+05:00");
+    fn parse_clock_time_rejects_invalid_12_hour_inputs() {
+        for input in [
+            "13 PM",
+            "0 AM",
+            "2:3 PM",
+            "2:60 PM",
+            "PM",
+            "2:30:00 PM",
+            "two pm",
+        ] {
+            assert!(parse_clock_time(input, false).is_err(), "Accepted: {input}");
+        }
     }
 
     #[test]
-    fn format_utc_offset_formats_negative_whole_hours() {
-        let offset = jiff::tz::Offset::from_seconds(-5 * 3600).unwrap();
-        assert_eq!(format_utc_offset(offset), "-05:00");
+    fn parse_datetime_converts_rfc3339_into_timezone() {
+        let tz = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+        let zdt = parse_datetime("2025-07-04T00:00:00Z", &tz).unwrap();
+        assert_eq!(zdt.to_string(), "2025-07-04T09:00:00+09:00[Asia/Tokyo]");
     }
 
     #[test]
-    fn format_utc_offset_formats_fractional_hours() {
-        // +05:45 for Asia/Kathmandu
-        let offset = jiff::tz::Offset::from_seconds(5 * 3600 + 45 * 60).unwrap();
-        assert_eq!(format_utc_offset(offset), "+05:45");
+    fn parse_datetime_interprets_local_values_in_timezone() {
+        let tz = jiff::tz::TimeZone::get("America/New_York").unwrap();
+        let zdt = parse_datetime("2025-07-04T12:00", &tz).unwrap();
+        assert_eq!(
+            zdt.to_string(),
+            "2025-07-04T12:00:00-04:00[America/New_York]"
+        );
+        let zdt = parse_datetime("2025-07-04", &tz).unwrap();
+        assert_eq!(
+            zdt.to_string(),
+            "2025-07-04T00:00:00-04:00[America/New_York]"
+        );
     }
 
     #[test]
-    fn format_utc_offset_formats_zero_as_positive() {
-        let offset = jiff::tz::Offset::from_seconds(0).unwrap();
-        assert_eq!(format_utc_offset(offset), "+00:00");
+    fn parse_datetime_returns_err_for_garbage() {
+        let err = parse_datetime("tomorrow", &jiff::tz::TimeZone::UTC).unwrap_err();
+        assert_eq!(err.code, "invalid_datetime");
+        assert!(err.message.contains("Invalid datetime"), "Error was: {err}");
     }
 
     #[test]
@@ -369,6 +1488,26 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
     }
 
+    #[test]
+    fn tool_error_serializes_structured_payload() {
+        let result = tool_error(
+            ToolError::new("invalid_timezone", "Invalid timezone: 'Bad/Zone'.")
+                .with_value("Bad/Zone")
+                .with_suggestions(vec!["America/Bahia".into()]),
+        );
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_timezone");
+        assert_eq!(json["message"], "Invalid timezone: 'Bad/Zone'.");
+        assert_eq!(json["offending_value"], "Bad/Zone");
+        assert_eq!(json["suggestions"][0], "America/Bahia");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&extract_text(&tool_error("plain message"))).unwrap();
+        assert_eq!(json["code"], "invalid_input");
+        assert!(json["offending_value"].is_null());
+        assert_eq!(json["suggestions"], serde_json::json!([]));
+    }
+
     #[test]
     fn tool_error_includes_message_in_content() {
         let result = tool_error("something went wrong");
@@ -380,336 +1519,255 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn get_current_time_defaults_to_utc_when_no_timezone() {
-        let server = TimeServer::new();
-        let params = GetCurrentTimeParams { timezone: None };
-        let result = server.get_current_time(Parameters(params)).await.unwrap();
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        assert_eq!(json["timezone"], "UTC");
-    }
-
-    #[tokio::test]
-    async fn get_current_time_defaults_to_utc_for_empty_string() {
-        let server = TimeServer::new();
-        let params = GetCurrentTimeParams {
-            timezone: Some(String::new()),
-        };
-        let result = server.get_current_time(Parameters(params)).await.unwrap();
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        assert_eq!(json["timezone"], "UTC");
-    }
-
-    #[tokio::test]
-    async fn get_current_time_returns_valid_response_for_known_timezone() {
-        let server = TimeServer::new();
-        let params = GetCurrentTimeParams {
+    async fn fixed_clock_pins_now_across_sessions() {
+        let instant: jiff::Timestamp = "2025-07-04T12:00:00Z".parse().unwrap();
+        let server = TimeServer::new()
+            .clock(crate::clock::FixedClock(instant))
+            .world_clock_zones(vec!["Asia/Tokyo".into()]);
+        let params = || GetCurrentTimeParams {
             timezone: Some("America/New_York".into()),
+            ..Default::default()
         };
-        let result = server.get_current_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(false));
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        assert_eq!(json["timezone"], "America/New_York");
-        assert!(json["datetime"].is_string());
-        assert!(json["utc_offset"].is_string());
-        assert!(json["is_dst"].is_boolean());
-    }
-
-    #[tokio::test]
-    async fn get_current_time_returns_error_for_invalid_timezone() {
-        let server = TimeServer::new();
-        let params = GetCurrentTimeParams {
-            timezone: Some("Not/A/Timezone".into()),
-        };
-        let result = server.get_current_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(text.contains("Invalid timezone"));
+        for session in [server.session(), server.session().session()] {
+            let result = session
+                .get_current_time(Parameters(params()))
+                .await
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["datetime"], "2025-07-04T08:00:00-04:00");
+            assert_eq!(json["is_dst"], true);
+        }
+        let dashboard = server.world_clock(world_clock::Format::Markdown);
+        assert!(dashboard.contains("21:00"), "Dashboard was: {dashboard}");
     }
 
     #[tokio::test]
-    async fn get_current_time_returns_fractional_offset_for_kathmandu() {
-        let server = TimeServer::new();
+    async fn default_timezone_applies_when_timezone_is_omitted() {
+        let instant: jiff::Timestamp = "2025-07-04T12:00:00Z".parse().unwrap();
+        let server = TimeServer::new()
+            .clock(crate::clock::FixedClock(instant))
+            .default_timezone(jiff::tz::TimeZone::get("Asia/Tokyo").unwrap());
         let params = GetCurrentTimeParams {
-            timezone: Some("Asia/Kathmandu".into()),
+            timezone: None,
+            ..Default::default()
         };
         let result = server.get_current_time(Parameters(params)).await.unwrap();
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        assert_eq!(json["utc_offset"], "+05:45");
-    }
-
-    #[tokio::test]
-    async fn convert_time_converts_utc_to_new_york() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "12:00".into(),
-            target_timezone: "America/New_York".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(false));
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        assert_eq!(json["source"]["timezone"], "UTC");
-        assert_eq!(json["target"]["timezone"], "America/New_York");
-        // NY is UTC-5 or UTC-4 depending on DST
-        let target_dt = json["target"]["datetime"].as_str().unwrap();
-        assert!(
-            target_dt.contains("07:00") || target_dt.contains("08:00"),
-            "Expected 07:00 or 08:00 but got: {target_dt}"
-        );
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["timezone"], "Asia/Tokyo");
+        assert_eq!(json["datetime"], "2025-07-04T21:00:00+09:00");
     }
 
     #[tokio::test]
-    async fn convert_time_returns_error_for_invalid_time_format() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "25:99".into(),
-            target_timezone: "America/New_York".into(),
+    async fn limits_refuse_calls_before_they_reach_the_tool() {
+        let server = TimeServer::new().limits(Limits {
+            calls_per_minute: Some(3),
+            max_list_items: Some(2),
+            max_results: None,
+        });
+        let mut other = crate::local::LocalClient::new(server.session());
+        let mut client = crate::local::LocalClient::new(server);
+        let arguments = |value: serde_json::Value| value.as_object().unwrap().clone();
+        let error = |result: &CallToolResult| {
+            assert_eq!(result.is_error, Some(true));
+            serde_json::from_str::<serde_json::Value>(&extract_text(result)).unwrap()
         };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(text.contains("Invalid time format"));
-    }
 
-    #[tokio::test]
-    async fn convert_time_returns_error_for_invalid_source_timezone() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "Bad/Zone".into(),
-            time: "12:00".into(),
-            target_timezone: "UTC".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(text.contains("Invalid timezone"));
-    }
-
-    #[tokio::test]
-    async fn convert_time_trims_whitespace_from_time_input() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "  14:30  ".into(),
-            target_timezone: "UTC".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(false));
-    }
-
-    #[tokio::test]
-    async fn convert_time_rejects_24_00() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "24:00".into(),
-            target_timezone: "UTC".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(text.contains("Invalid time format"));
-    }
-
-    #[tokio::test]
-    async fn convert_time_rejects_time_with_seconds() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "14:30:00".into(),
-            target_timezone: "UTC".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(text.contains("Invalid time format"));
+        let result = client
+            .call(
+                "convert_time_multi",
+                arguments(serde_json::json!({
+                    "source_timezone": "UTC",
+                    "time": "12:00",
+                    "target_timezones": ["Asia/Tokyo", "Europe/London", "America/New_York"],
+                })),
+            )
+            .await
+            .unwrap();
+        let json = error(&result);
+        assert_eq!(json["code"], "too_many_items");
+        assert_eq!(json["offending_value"], "3");
+
+        for _ in 0..2 {
+            let result = client
+                .call("get_current_time", serde_json::Map::new())
+                .await
+                .unwrap();
+            assert_eq!(result.is_error, Some(false));
+        }
+        let result = client
+            .call("get_current_time", serde_json::Map::new())
+            .await
+            .unwrap();
+        let json = error(&result);
+        assert_eq!(json["code"], "rate_limited");
+        assert_eq!(json["retry_after_seconds"], 20);
+
+        // Sessions are not clients: without an HTTP peer address, they all
+        // share the local client's allowance.
+        let result = other
+            .call("get_current_time", serde_json::Map::new())
+            .await
+            .unwrap();
+        assert_eq!(error(&result)["code"], "rate_limited");
     }
 
-    // --- get_current_time: missing tests ---
-
-    #[tokio::test]
-    async fn get_current_time_deprecated_timezone() {
-        let server = TimeServer::new();
-        let params = GetCurrentTimeParams {
-            timezone: Some("US/Eastern".into()),
-        };
-        let result = server.get_current_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(false));
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        // US/Eastern is a backward-compat IANA link; should resolve successfully
-        assert!(json["datetime"].is_string());
-        assert!(json["utc_offset"].is_string());
+    #[test]
+    fn tool_groups_cover_every_tool_once() {
+        let mut names: Vec<String> = ToolGroup::ALL
+            .iter()
+            .flat_map(|group| group.router().list_all())
+            .map(|tool| tool.name.to_string())
+            .collect();
+        let total = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), total);
+        assert_eq!(total, TimeServer::new().tool_router.list_all().len());
+
+        let server = TimeServer::new().tool_groups(&[ToolGroup::Calendars, ToolGroup::Scheduling]);
+        assert!(server.tool_router.has_route("global_dst_events"));
+        assert!(server.tool_router.has_route("start_stopwatch"));
+        assert!(!server.tool_router.has_route("get_current_time"));
     }
 
     #[tokio::test]
-    async fn get_current_time_dst_true() {
+    async fn set_tool_enabled_hides_and_restores_tools() {
         let server = TimeServer::new();
-        let params = GetCurrentTimeParams {
-            timezone: Some("America/New_York".into()),
-        };
-        let result = server.get_current_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(false));
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        // Structural test: is_dst key exists and is boolean.
-        // The actual value depends on when the test runs (DST vs standard time).
-        assert!(
-            json["is_dst"].is_boolean(),
-            "Expected is_dst to be a boolean, got: {:?}",
-            json["is_dst"]
+        assert_eq!(
+            server.set_tool_enabled("sql_literal", false).await,
+            Ok(true)
         );
-    }
-
-    #[tokio::test]
-    async fn get_current_time_abbreviation_timezone() {
-        let server = TimeServer::new();
-        let params = GetCurrentTimeParams {
-            timezone: Some("EST".into()),
-        };
-        let result = server.get_current_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(
-            text.contains("IANA timezone name"),
-            "Error should suggest IANA name, got: {text}"
+        assert_eq!(
+            server.set_tool_enabled("sql_literal", false).await,
+            Ok(false)
         );
+        assert!(!server.enabled_tools().has_route("sql_literal"));
+        assert!(server.enabled_tools().has_route("convert_time"));
+
+        assert_eq!(server.set_tool_enabled("sql_literal", true).await, Ok(true));
+        assert!(server.enabled_tools().has_route("sql_literal"));
     }
 
     #[tokio::test]
-    async fn get_current_time_offset_timezone() {
+    async fn set_tool_enabled_rejects_unknown_tool() {
         let server = TimeServer::new();
-        let params = GetCurrentTimeParams {
-            timezone: Some("UTC+5".into()),
-        };
-        let result = server.get_current_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(
-            text.contains("IANA timezone name"),
-            "Error should suggest IANA name, got: {text}"
-        );
+        let err = server
+            .set_tool_enabled("no_such_tool", false)
+            .await
+            .unwrap_err();
+        assert!(err.contains("no_such_tool"));
     }
 
-    // --- convert_time: missing tests ---
-
     #[tokio::test]
-    async fn convert_time_fractional_offset() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "12:00".into(),
-            target_timezone: "Asia/Kathmandu".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(false));
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        let target_dt = json["target"]["datetime"].as_str().unwrap();
-        assert!(
-            target_dt.contains("17:45"),
-            "Expected 17:45 for UTC+5:45, got: {target_dt}"
-        );
+    async fn sleep_unless_cancelled_stops_on_cancellation() {
+        let long = std::time::Duration::from_secs(3600);
+        let report = |_| async {};
+        assert!(!sleep_unless_cancelled(long, std::future::ready(()), None, report).await);
+        let short = std::time::Duration::from_millis(1);
+        assert!(sleep_unless_cancelled(short, std::future::pending(), None, report).await);
     }
 
     #[tokio::test]
-    async fn convert_time_invalid_target_timezone() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "12:00".into(),
-            target_timezone: "Bad/Zone".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(text.contains("Invalid timezone"));
+    async fn sleep_unless_cancelled_reports_progress_fractions() {
+        let reports = std::sync::Mutex::new(Vec::new());
+        let finished = sleep_unless_cancelled(
+            std::time::Duration::from_millis(100),
+            std::future::pending(),
+            Some(std::time::Duration::from_millis(20)),
+            |fraction| {
+                reports.lock().unwrap().push(fraction);
+                async {}
+            },
+        )
+        .await;
+        assert!(finished);
+        let reports = reports.into_inner().unwrap();
+        assert!(!reports.is_empty());
+        assert!(reports.windows(2).all(|w| w[0] < w[1]));
+        assert!(reports.iter().all(|&f| f > 0.0 && f < 1.0));
     }
 
-    #[tokio::test]
-    async fn convert_time_non_numeric_time() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "abc".into(),
-            target_timezone: "UTC".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(text.contains("Invalid time format"));
+    #[test]
+    fn progress_interval_scales_with_wait_length() {
+        let secs = std::time::Duration::from_secs;
+        assert_eq!(progress_interval(secs(5)), secs(1));
+        assert_eq!(progress_interval(secs(60)), secs(3));
+        assert_eq!(progress_interval(secs(3600)), secs(10));
     }
 
     #[tokio::test]
-    async fn convert_time_midnight() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "00:00".into(),
-            target_timezone: "America/New_York".into(),
+    async fn with_state_file_restores_timers() {
+        let path =
+            std::env::temp_dir().join(format!("mcp-time-{}-server-state.json", std::process::id()));
+        let params = || {
+            Parameters(StopwatchParams {
+                name: "focus".into(),
+            })
         };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(false));
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        let source_dt = json["source"]["datetime"].as_str().unwrap();
-        assert!(
-            source_dt.contains("00:00"),
-            "Source should be midnight, got: {source_dt}"
-        );
-        // NY is UTC-5 or UTC-4 — target should be previous day 19:00 or 20:00
-        let target_dt = json["target"]["datetime"].as_str().unwrap();
-        assert!(
-            target_dt.contains("19:00") || target_dt.contains("20:00"),
-            "Expected 19:00 or 20:00 for midnight UTC->NY, got: {target_dt}"
-        );
+        let server = TimeServer::with_state_file(&path).unwrap();
+        server.start_stopwatch(params()).await.unwrap();
+        server.pause_stopwatch(params()).await.unwrap();
+
+        let restarted = TimeServer::with_state_file(&path).unwrap();
+        let result = restarted.get_stopwatch(params()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["state"], "paused");
+        std::fs::remove_file(&path).unwrap();
     }
 
     #[tokio::test]
-    async fn convert_time_end_of_day() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "23:59".into(),
-            target_timezone: "Asia/Tokyo".into(), // UTC+9
+    async fn with_state_file_keeps_undelivered_notifications_when_saving() {
+        let path = std::env::temp_dir().join(format!(
+            "mcp-time-{}-undelivered-state.json",
+            std::process::id()
+        ));
+        let notification = ScheduledNotification {
+            id: "notification-1".into(),
+            kind: Kind::Notification,
+            message: "renew passport".into(),
+            duration: None,
+            fire_at: "2030-01-01T09:00:00+00:00".into(),
+            fire_at_utc: "2030-01-01T09:00:00Z".parse().unwrap(),
+            timezone: "UTC".into(),
+            missed: false,
         };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(false));
-        let text = extract_text(&result);
-        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
-        // 23:59 UTC + 9h = 08:59 next day in Tokyo
-        let target_dt = json["target"]["datetime"].as_str().unwrap();
-        assert!(
-            target_dt.contains("08:59"),
-            "Expected 08:59 for 23:59 UTC->Tokyo, got: {target_dt}"
-        );
+        StateFile::new(&path)
+            .save(&crate::state::SavedState {
+                last_notification_id: 1,
+                notifications: vec![notification],
+                timers: Default::default(),
+            })
+            .unwrap();
+
+        // No client initializes, as in the call, batch, and repl modes, but
+        // saving still keeps the restored notification.
+        let server = TimeServer::with_state_file(&path).unwrap();
+        let params = Parameters(StopwatchParams {
+            name: "focus".into(),
+        });
+        server.start_stopwatch(params).await.unwrap();
+        let saved = StateFile::new(&path).load().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(saved.notifications.len(), 1);
+        assert_eq!(saved.notifications[0].message, "renew passport");
+        assert!(saved.timers.contains_key("focus"));
+        assert_eq!(server.scheduler.pending()[0].id, "notification-1");
     }
 
-    #[tokio::test]
-    async fn convert_time_iso_datetime_input() {
-        let server = TimeServer::new();
-        let params = ConvertTimeParams {
-            source_timezone: "UTC".into(),
-            time: "2026-02-24T14:30:00".into(),
-            target_timezone: "UTC".into(),
-        };
-        let result = server.convert_time(Parameters(params)).await.unwrap();
-        assert_eq!(result.is_error, Some(true));
-        let text = extract_text(&result);
-        assert!(
-            text.contains("Invalid time format"),
-            "Should reject ISO datetime, got: {text}"
+    #[test]
+    fn parse_tick_uri_maps_errors_to_protocol_errors() {
+        assert_eq!(
+            parse_tick_uri("time://tick?granularity=hour").unwrap(),
+            tick::Granularity::Hour
         );
+        let err = parse_tick_uri("time://tick?granularity=week").unwrap_err();
+        assert_eq!(err.code, rmcp::model::ErrorCode::INVALID_PARAMS);
+        let err = parse_tick_uri("time://unknown").unwrap_err();
+        assert_eq!(err.code, rmcp::model::ErrorCode::RESOURCE_NOT_FOUND);
     }
 
     /// Extract text content from a CallToolResult.
-    fn extract_text(result: &CallToolResult) -> String {
+    pub(super) fn extract_text(result: &CallToolResult) -> String {
         match &result.content[0].raw {
             rmcp::model::RawContent::Text(t) => t.text.clone(),
             _ => panic!("Expected text content"),
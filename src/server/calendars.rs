@@ -0,0 +1,2439 @@
+use rmcp::{handler::server::wrapper::Parameters, model::CallToolResult, tool, tool_router};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Error message template for years outside the supported calendar range.
+const ERR_INVALID_YEAR: &str = "Invalid year: {}. Expected a year between 1 and 9998.";
+
+/// Error message template for lookahead windows outside the supported range.
+const ERR_INVALID_DAYS: &str = "Invalid number of days: {}. Expected a value between 1 and 366.";
+
+/// Longest date range, in days, that range reports accept.
+const MAX_RANGE_DAYS: i32 = 366;
+
+/// Error message template for date ranges that are reversed or too long.
+const ERR_INVALID_RANGE: &str = "Invalid date range: {}. Expected an end date on or after the start date and at most 366 days later.";
+
+/// Most zones a `dst_impact` report compares.
+const MAX_IMPACT_ZONES: usize = 10;
+
+/// Most zones a `find_meeting_time` search compares, and most days it
+/// searches.
+const MAX_MEETING_ZONES: usize = 10;
+const MAX_MEETING_DAYS: u32 = 14;
+
+/// Meeting times `find_meeting_time` suggests by default, and at most.
+const DEFAULT_MEETING_CANDIDATES: usize = 5;
+const MAX_MEETING_CANDIDATES: usize = 50;
+
+/// Longest date range, in days, that an hourly `compare_timezones` report
+/// accepts.
+const MAX_HOURLY_COMPARISON_DAYS: i32 = 31;
+
+/// Occurrences `next_occurrences` lists by default, and at most.
+const DEFAULT_OCCURRENCES: usize = 10;
+const MAX_OCCURRENCES: usize = 1000;
+
+/// Parameters for the `abbreviation_calendar` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct AbbreviationCalendarParams {
+    /// IANA timezone name (e.g., 'Europe/Berlin', 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub timezone: String,
+    /// Calendar year to list (e.g., 2025). Defaults to the current year in the timezone.
+    #[serde(default)]
+    pub year: Option<i16>,
+}
+
+/// One participant zone of a `find_meeting_time` search.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MeetingZone {
+    /// IANA timezone name (e.g., 'Europe/London')
+    #[schemars(transform = schema::timezone)]
+    pub timezone: String,
+    /// Start of working hours in the zone (e.g., '09:00', '8:30am'). Defaults to '09:00'.
+    #[serde(default)]
+    #[schemars(transform = schema::clock_time)]
+    pub work_start: Option<String>,
+    /// End of working hours in the zone (e.g., '17:00', '6pm'). An end at or before the start is an overnight shift. Defaults to '17:00'.
+    #[serde(default)]
+    #[schemars(transform = schema::clock_time)]
+    pub work_end: Option<String>,
+}
+
+/// Parameters for the `find_meeting_time` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct FindMeetingTimeParams {
+    /// Participant zones with their working hours, 2 to 10 (e.g., [{"timezone": "America/New_York"}, {"timezone": "Asia/Kolkata", "work_start": "10:00", "work_end": "19:00"}])
+    pub participants: Vec<MeetingZone>,
+    /// Meeting length in minutes, from 1 to 1440. Defaults to 30.
+    #[serde(default)]
+    #[schemars(range(min = 1, max = 1440))]
+    pub duration_minutes: Option<u32>,
+    /// First date to search (YYYY-MM-DD), read in the first participant's timezone. Defaults to searching from now.
+    #[serde(default)]
+    #[schemars(transform = schema::date)]
+    pub date: Option<String>,
+    /// Number of days to search, from 1 to 14. Defaults to 1.
+    #[serde(default)]
+    #[schemars(range(min = 1, max = MAX_MEETING_DAYS))]
+    pub days: Option<u32>,
+    /// Minutes between candidate start times, from 5 to 240. Defaults to 30.
+    #[serde(default)]
+    #[schemars(range(min = 5, max = 240))]
+    pub step_minutes: Option<u32>,
+    /// Number of candidates to return, from 1 to 50. Defaults to 5.
+    #[serde(default)]
+    #[schemars(range(min = 1, max = MAX_MEETING_CANDIDATES))]
+    pub limit: Option<usize>,
+    /// Count Saturdays and Sundays as working days. Defaults to false.
+    #[serde(default)]
+    pub include_weekends: bool,
+    /// Also write each candidate's local start out for a locale in `human_readable` (e.g., 'de-DE', 'en-US', 'ja'), as for `get_current_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Parameters for the `get_calendar_info` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct GetCalendarInfoParams {
+    /// Date to describe (YYYY-MM-DD). Defaults to today in the timezone.
+    #[serde(default)]
+    #[schemars(transform = schema::date)]
+    pub date: Option<String>,
+    /// IANA timezone whose current date is described when no date is given (e.g., 'Asia/Tokyo'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `convert_calendar` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ConvertCalendarParams {
+    /// Date to convert, written in `calendar`: YYYY-MM-DD for Gregorian, Hebrew (months numbered from Tishrei, e.g., '5786-01-01'), and Islamic (e.g., '1447-09-01'); an era name or initial and year for Japanese (e.g., 'Reiwa 7-07-04', 'R7-07-04'). Defaults to today in the timezone.
+    #[serde(default)]
+    pub date: Option<String>,
+    /// Calendar system `date` is written in. Defaults to 'gregorian'.
+    #[serde(default)]
+    pub calendar: CalendarSystem,
+    /// Calendar systems to convert into. Defaults to all of them.
+    #[serde(default)]
+    pub targets: Option<Vec<CalendarSystem>>,
+    /// IANA timezone whose current date is converted when no date is given (e.g., 'Asia/Jerusalem'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `get_dst_transitions` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct GetDstTransitionsParams {
+    /// IANA timezone name (e.g., 'Europe/Berlin', 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub timezone: String,
+    /// Calendar year to list (e.g., 1995 or 2026). Cannot be combined with a date range.
+    #[serde(default)]
+    pub year: Option<i16>,
+    /// First date of a range to list (YYYY-MM-DD), with end_date.
+    #[serde(default)]
+    #[schemars(transform = schema::date)]
+    pub start_date: Option<String>,
+    /// Last date of a range to list (YYYY-MM-DD), at most 366 days after start_date.
+    #[serde(default)]
+    #[schemars(transform = schema::date)]
+    pub end_date: Option<String>,
+}
+
+/// Parameters for the `global_dst_events` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct GlobalDstEventsParams {
+    /// Number of days ahead to scan, from 1 to 366. Defaults to 30.
+    #[serde(default)]
+    #[schemars(range(min = 1, max = MAX_RANGE_DAYS))]
+    pub days: Option<u32>,
+}
+
+/// Parameters for the `next_occurrences` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct NextOccurrencesParams {
+    /// An iCalendar RRULE (e.g., 'FREQ=WEEKLY;BYDAY=MO,WE;BYHOUR=9;BYMINUTE=0', optionally after a 'DTSTART;TZID=Europe/Paris:20250106T090000' line) or a five-field cron expression (e.g., '0 9 * * 1-5', '@daily')
+    pub rule: String,
+    /// IANA timezone the rule runs in (e.g., 'America/New_York'). Defaults to the DTSTART line's TZID, or the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// RRULE only: when the rule starts (DTSTART), as an RFC 3339 or local datetime in the timezone. Sets the time of day and weekday that parts the rule leaves out default to. Defaults to the rule's DTSTART line, or to after.
+    #[serde(default)]
+    pub start: Option<String>,
+    /// List occurrences after this datetime (RFC 3339, or local in the timezone). Defaults to now.
+    #[serde(default)]
+    pub after: Option<String>,
+    /// Number of occurrences to list, from 1 to 1000. Defaults to 10.
+    #[serde(default)]
+    #[schemars(range(min = 1, max = MAX_OCCURRENCES))]
+    pub count: Option<usize>,
+    /// Also write each occurrence out for a locale in `human_readable` (e.g., 'de-DE', 'en-US', 'ja'), as for `get_current_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Parameters for the `recurrence_drift` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct RecurrenceDriftParams {
+    /// IANA timezone the meeting is scheduled in (e.g., 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub timezone: String,
+    /// Meeting start time in that timezone, in 24-hour format (HH:MM) or 12-hour format with AM/PM (e.g., '10:00', '10am')
+    #[schemars(transform = schema::clock_time)]
+    pub time: String,
+    /// IANA timezones of the other participants (e.g., ['Europe/London', 'Asia/Kolkata'])
+    #[schemars(transform = schema::timezones)]
+    pub participant_timezones: Vec<String>,
+    /// First date of the range (YYYY-MM-DD). For weekly meetings, also sets the weekday.
+    #[schemars(transform = schema::date)]
+    pub start_date: String,
+    /// Last date of the range (YYYY-MM-DD), at most 366 days after start_date.
+    #[schemars(transform = schema::date)]
+    pub end_date: String,
+    /// How often the meeting repeats: 'daily', 'weekdays', or 'weekly'. Defaults to 'weekly'.
+    #[serde(default)]
+    pub frequency: Frequency,
+}
+
+/// Parameters for the `compare_timezones` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct CompareTimezonesParams {
+    /// IANA timezone to compare from; dates and samples are read in it (e.g., 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub first_timezone: String,
+    /// IANA timezone to compare with; differences are its offset minus the first's (e.g., 'Europe/London')
+    #[schemars(transform = schema::timezone)]
+    pub second_timezone: String,
+    /// First date of the range (YYYY-MM-DD)
+    #[schemars(transform = schema::date)]
+    pub start_date: String,
+    /// Last date of the range (YYYY-MM-DD), at most 366 days after start_date, or 31 for hourly samples.
+    #[schemars(transform = schema::date)]
+    pub end_date: String,
+    /// How often to sample the difference: 'daily' (at noon in the first timezone) or 'hourly'. Defaults to 'daily'.
+    #[serde(default)]
+    pub granularity: Granularity,
+}
+
+/// Parameters for the `dst_impact` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct DstImpactParams {
+    /// IANA timezones to compare, up to 10 (e.g., ['America/New_York', 'Europe/London'])
+    #[schemars(transform = schema::timezones)]
+    pub timezones: Vec<String>,
+    /// First date of the range (YYYY-MM-DD)
+    #[schemars(transform = schema::date)]
+    pub start_date: String,
+    /// Last date of the range (YYYY-MM-DD), at most 366 days after start_date.
+    #[schemars(transform = schema::date)]
+    pub end_date: String,
+}
+
+/// Parameters for the `business_days` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct BusinessDaysParams {
+    /// Date to count from (YYYY-MM-DD)
+    #[schemars(transform = schema::date)]
+    pub start_date: String,
+    /// Date to count business days to (YYYY-MM-DD). Days after start_date up to and including end_date are counted. Give either end_date or days.
+    #[serde(default)]
+    #[schemars(transform = schema::date)]
+    pub end_date: Option<String>,
+    /// Number of business days to add to start_date, negative to count back. Give either end_date or days.
+    #[serde(default)]
+    #[schemars(range(min = -business_days::MAX_OFFSET, max = business_days::MAX_OFFSET))]
+    pub days: Option<i64>,
+    /// Weekday names that are not business days (e.g., ['friday', 'saturday']). Defaults to ['saturday', 'sunday']; pass [] for a seven-day week.
+    #[serde(default)]
+    pub weekend: Option<Vec<String>>,
+    /// Holiday dates that are not business days (YYYY-MM-DD, e.g., ['2025-12-25', '2025-12-26']).
+    #[serde(default)]
+    #[schemars(transform = schema::dates)]
+    pub holidays: Vec<String>,
+}
+
+/// Parameters for the `get_holidays` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct GetHolidaysParams {
+    /// ISO 3166 country code (e.g., 'US', 'GB', 'DE')
+    pub country: String,
+    /// Calendar year to list (e.g., 2025). Defaults to the current year in the server's default timezone.
+    #[serde(default)]
+    pub year: Option<i16>,
+    /// Region code (e.g., 'BY' or 'DE-BY' for Bavaria, 'SCT' for Scotland). Lists the whole country's holidays plus the region's; without it, regional holidays are listed with the regions they apply to.
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+/// Parameters for the `is_holiday` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct IsHolidayParams {
+    /// ISO 3166 country code (e.g., 'US', 'GB', 'DE')
+    pub country: String,
+    /// Date to check (YYYY-MM-DD)
+    #[schemars(transform = schema::date)]
+    pub date: String,
+    /// Region code (e.g., 'BY' or 'DE-BY' for Bavaria, 'SCT' for Scotland). Without it, only holidays of the whole country count.
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+/// Response payload for `abbreviation_calendar`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct AbbreviationCalendarResponse {
+    timezone: String,
+    year: i16,
+    abbreviations: Vec<AbbreviationMeaning>,
+    periods: Vec<AbbreviationPeriod>,
+}
+
+/// Response payload for `business_days`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct BusinessDaysResponse {
+    start_date: String,
+    end_date: String,
+    /// Business days after start_date up to and including end_date,
+    /// negative when end_date is earlier.
+    business_days: i64,
+    /// Calendar days from start_date to end_date, negative when end_date is
+    /// earlier.
+    calendar_days: i32,
+    weekend: Vec<&'static str>,
+    /// Holidays in the range that fell on days that would otherwise be
+    /// business days.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    holidays_skipped: Vec<String>,
+}
+
+/// One holiday in a `get_holidays` or `is_holiday` response.
+#[derive(Debug, Serialize, JsonSchema)]
+struct HolidayEntry {
+    name: String,
+    date: String,
+    /// The weekday the holiday is observed on instead, when it falls on a
+    /// weekend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    observed: Option<String>,
+    /// Region codes the holiday applies to; omitted for the whole country.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    regions: Vec<String>,
+}
+
+impl From<holidays::Holiday> for HolidayEntry {
+    fn from(holiday: holidays::Holiday) -> Self {
+        HolidayEntry {
+            name: holiday.name,
+            date: holiday.date.to_string(),
+            observed: holiday.observed.map(|date| date.to_string()),
+            regions: holiday.regions,
+        }
+    }
+}
+
+/// Response payload for `get_holidays`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct GetHolidaysResponse {
+    country: String,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+    year: i16,
+    holidays: Vec<HolidayEntry>,
+}
+
+/// Response payload for `is_holiday`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct IsHolidayResponse {
+    date: String,
+    country: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+    /// Whether a holiday of the whole country, or of the region when one
+    /// is given, falls on or is observed on the date.
+    is_holiday: bool,
+    holidays: Vec<HolidayEntry>,
+    /// Holidays of only some regions on the date, listed when no region is
+    /// given.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    regional: Vec<HolidayEntry>,
+}
+
+/// Time inside every participant's working hours.
+#[derive(Debug, Serialize, JsonSchema)]
+struct SharedWindow {
+    start: String,
+    end: String,
+    minutes: i64,
+}
+
+/// A meeting slot in one participant's timezone.
+#[derive(Debug, Serialize, JsonSchema)]
+struct LocalMeetingTime {
+    timezone: String,
+    start: String,
+    end: String,
+    /// The start written out for the requested `locale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    human_readable: Option<String>,
+}
+
+/// One suggested slot in a `find_meeting_time` response.
+#[derive(Debug, Serialize, JsonSchema)]
+struct MeetingCandidate {
+    start: String,
+    end: String,
+    /// Minutes between the meeting and the nearest start or end of any
+    /// participant's working hours; candidates are ranked by it.
+    margin_minutes: i64,
+    local_times: Vec<LocalMeetingTime>,
+}
+
+/// Response payload for `find_meeting_time`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct FindMeetingTimeResponse {
+    /// Start of the searched range, in UTC.
+    from: String,
+    /// End of the searched range (exclusive), in UTC.
+    to: String,
+    duration_minutes: u32,
+    shared_windows: Vec<SharedWindow>,
+    candidates: Vec<MeetingCandidate>,
+    /// Set when no slot fits, explaining why.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Response payload for `convert_calendar`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ConvertCalendarResponse {
+    /// The date as given, when one was.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input: Option<String>,
+    calendar: CalendarSystem,
+    /// The same day in the Gregorian calendar (YYYY-MM-DD).
+    gregorian: String,
+    day_of_week: String,
+    /// Set when the date was taken from today in this timezone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    dates: Vec<CalendarDate>,
+    /// Calendars left out because the date falls before their first year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Response payload for `get_calendar_info`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct CalendarInfoResponse {
+    date: String,
+    /// Set when the date was taken from today in this timezone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    day_of_week: String,
+    /// ISO 8601 weekday number, Monday = 1 to Sunday = 7.
+    iso_weekday: i8,
+    iso_week: i8,
+    /// The year the ISO week belongs to, which differs from the calendar
+    /// year for some days around New Year.
+    iso_week_year: i16,
+    /// ISO 8601 week date (e.g., "2026-W01-4").
+    iso_week_date: String,
+    day_of_year: i16,
+    quarter: i8,
+    /// Days after the date until the end of its month.
+    days_remaining_in_month: i8,
+    /// Days after the date until the end of its year.
+    days_remaining_in_year: i16,
+    is_leap_year: bool,
+}
+
+/// Response payload for `get_dst_transitions`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct GetDstTransitionsResponse {
+    timezone: String,
+    /// Start of the listed window (inclusive), in the zone.
+    from: String,
+    /// End of the listed window (exclusive), in the zone.
+    to: String,
+    transitions: Vec<ZoneTransition>,
+}
+
+/// Response payload for `global_dst_events`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct GlobalDstEventsResponse {
+    from: String,
+    to: String,
+    /// Most recent DST changes before `from`, all at the same instant.
+    last: Vec<DstEvent>,
+    events: Vec<DstEvent>,
+}
+
+/// One occurrence in a `next_occurrences` response.
+#[derive(Debug, Serialize, JsonSchema)]
+struct OccurrenceEntry {
+    /// Local time in the rule's timezone (RFC 3339).
+    datetime: String,
+    /// The local time written out for the requested `locale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    human_readable: Option<String>,
+    utc: String,
+    /// Set when the rule's wall-clock time is skipped or repeated by a
+    /// clock change on that date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Response payload for `next_occurrences`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct NextOccurrencesResponse {
+    rule: String,
+    /// How the rule was read: "rrule" or "cron".
+    kind: &'static str,
+    timezone: String,
+    after: String,
+    occurrences: Vec<OccurrenceEntry>,
+    /// Set when the rule ends before the requested count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Response payload for `recurrence_drift`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct RecurrenceDriftResponse {
+    timezone: String,
+    time: String,
+    frequency: Frequency,
+    occurrences: usize,
+    participants: Vec<ParticipantDrift>,
+}
+
+/// Response payload for `compare_timezones`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct CompareTimezonesResponse {
+    /// The two zones; differences are the second zone's offset minus the
+    /// first's.
+    timezones: [String; 2],
+    start_date: String,
+    end_date: String,
+    granularity: Granularity,
+    usual_difference: String,
+    changes: Vec<ComparisonChange>,
+    periods: Vec<DifferencePeriod>,
+    rows: Vec<ComparisonRow>,
+}
+
+/// Response payload for `dst_impact`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct DstImpactResponse {
+    start_date: String,
+    end_date: String,
+    zones: Vec<ZoneImpact>,
+    pairs: Vec<PairImpact>,
+}
+
+/// Parse an inclusive `start`..=`end` date range of at most
+/// `MAX_RANGE_DAYS` days.
+fn parse_date_range(
+    start: &str,
+    end: &str,
+) -> Result<(jiff::civil::Date, jiff::civil::Date), ToolError> {
+    let (start, end) = (parse_date(start)?, parse_date(end)?);
+    let days = start.until(end).map_or(i32::MAX, |span| span.get_days());
+    if !(0..=MAX_RANGE_DAYS).contains(&days) {
+        let range = format!("{start} to {end}");
+        return Err(
+            ToolError::new("invalid_range", ERR_INVALID_RANGE.replacen("{}", &range, 1))
+                .with_value(range),
+        );
+    }
+    Ok((start, end))
+}
+
+impl TimeServer {
+    /// The holiday rules for `country` and the region code `region` names,
+    /// if one is given.
+    fn holiday_country(
+        &self,
+        country: &str,
+        region: Option<&str>,
+    ) -> Result<(&holidays::Country, Option<&str>), ToolError> {
+        let Some(found) = self.holidays.country(country) else {
+            let codes = self.holidays.codes().join(", ");
+            return Err(ToolError::new(
+                "invalid_country",
+                format!(
+                    "Unsupported country: '{country}'. Expected an ISO 3166 code with holiday rules: {codes}."
+                ),
+            )
+            .with_value(country));
+        };
+        let region = match region.filter(|region| !region.trim().is_empty()) {
+            Some(input) => Some(found.region(input).ok_or_else(|| {
+                let codes: Vec<String> = found.regions.keys().cloned().collect();
+                let message = if codes.is_empty() {
+                    format!(
+                        "Unknown region: '{input}'. {} has no regional holidays.",
+                        found.name
+                    )
+                } else {
+                    format!(
+                        "Unknown region: '{input}'. Expected a region code of {} (e.g., '{}').",
+                        found.name, codes[0]
+                    )
+                };
+                ToolError::new("invalid_region", message)
+                    .with_value(input)
+                    .with_suggestions(codes)
+            })?),
+            None => None,
+        };
+        Ok((found, region))
+    }
+}
+
+#[tool_router(router = calendars_router, vis = "pub")]
+impl TimeServer {
+    /// List the abbreviations a timezone uses over a year.
+    #[tool(
+        name = "abbreviation_calendar",
+        description = "List the timezone abbreviations (e.g., CET/CEST) a zone uses during a year, the date ranges each applies to, and the UTC offset each stands for.",
+        output_schema = output_schema::<AbbreviationCalendarResponse>()
+    )]
+    pub async fn abbreviation_calendar(
+        &self,
+        Parameters(params): Parameters<AbbreviationCalendarParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone(&params.timezone) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let year = params.year.unwrap_or_else(|| self.now_in(&tz).year());
+
+        if !(1..=9998).contains(&year) {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_year",
+                    ERR_INVALID_YEAR.replace("{}", &year.to_string()),
+                )
+                .with_value(year.to_string()),
+            ));
+        }
+
+        let year_start = |year: i16| {
+            jiff::civil::date(year, 1, 1)
+                .to_zoned(tz.clone())
+                .map(|zdt| zdt.timestamp())
+                .map_err(|e| {
+                    rmcp::ErrorData::internal_error(
+                        format!("Failed to resolve year start: {e}"),
+                        None,
+                    )
+                })
+        };
+        let start = year_start(year)?;
+        let end = year_start(year + 1)?;
+
+        let periods = transitions::abbreviation_periods(&tz, start, end);
+        let response = AbbreviationCalendarResponse {
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            year,
+            abbreviations: transitions::abbreviation_meanings(&periods),
+            periods,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Count or add business days.
+    #[tool(
+        name = "business_days",
+        description = "Count the business days between two dates, or add a number of business days to a date (negative to count back). Weekend days (Saturday and Sunday by default, configurable, e.g. Friday and Saturday) and the listed holiday dates are skipped. Counting starts the day after start_date, so one business day after a Friday is the next Monday, and the count from Monday to Friday is 4.",
+        output_schema = output_schema::<BusinessDaysResponse>()
+    )]
+    pub async fn business_days(
+        &self,
+        Parameters(params): Parameters<BusinessDaysParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let start = match parse_date(&params.start_date) {
+            Ok(date) => date,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let weekend = match params.weekend.as_deref() {
+            Some(names) => match business_days::Weekend::parse(names) {
+                Ok(weekend) => weekend,
+                Err(msg) => {
+                    return Ok(tool_error(
+                        ToolError::new("invalid_weekend", msg).with_value(names.join(", ")),
+                    ));
+                }
+            },
+            None => business_days::Weekend::default(),
+        };
+        let mut holidays = Vec::new();
+        for holiday in &params.holidays {
+            match parse_date(holiday) {
+                Ok(date) => holidays.push(date),
+                Err(msg) => return Ok(tool_error(msg)),
+            }
+        }
+        let calendar = business_days::Calendar::new(weekend, holidays);
+
+        let (end, count) = match (params.end_date.as_deref(), params.days) {
+            (Some(end), None) => {
+                let end = match parse_date(end) {
+                    Ok(date) => date,
+                    Err(msg) => return Ok(tool_error(msg)),
+                };
+                match calendar.count(start, end) {
+                    Ok(count) => (end, count),
+                    Err(e) => {
+                        return Ok(tool_error(
+                            ToolError::new("invalid_arguments", e.to_string())
+                                .with_value(&*params.start_date),
+                        ));
+                    }
+                }
+            }
+            (None, Some(days)) => match calendar.add(start, days) {
+                Ok(end) => (end, days),
+                Err(msg) => {
+                    return Ok(tool_error(
+                        ToolError::new("invalid_arguments", msg).with_value(days.to_string()),
+                    ));
+                }
+            },
+            _ => {
+                return Ok(tool_error(ToolError::new(
+                    "invalid_arguments",
+                    "Provide either end_date, to count business days, or days, to add them.",
+                )));
+            }
+        };
+
+        tool_success(&BusinessDaysResponse {
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            business_days: count,
+            calendar_days: start.until(end).map_or(0, |span| span.get_days()),
+            weekend: weekend.names(),
+            holidays_skipped: calendar
+                .holidays_between(start, end)
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        })
+    }
+
+    /// Compare two zones' offsets over a date range.
+    #[tool(
+        name = "compare_timezones",
+        description = "Compare two timezones over a date range: the difference between their UTC offsets sampled day by day (at noon in the first zone) or hour by hour, the periods of constant difference, and the exact instants it changes and which zone's clocks moved. The usual difference is that between the zones' standard offsets; periods when both zones observe DST but only one is on it are flagged as mismatches, such as the weeks in March and October or November when the US and Europe are on different DST schedules. Ranges can span 366 days, or 31 days hourly.",
+        output_schema = output_schema::<CompareTimezonesResponse>()
+    )]
+    pub async fn compare_timezones(
+        &self,
+        Parameters(params): Parameters<CompareTimezonesParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let first = match self.resolve_timezone(&params.first_timezone) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let second = match self.resolve_timezone(&params.second_timezone) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let (start, end) = match parse_date_range(&params.start_date, &params.end_date) {
+            Ok(range) => range,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let days = start.until(end).map_or(i32::MAX, |span| span.get_days());
+        if params.granularity == Granularity::Hourly && days > MAX_HOURLY_COMPARISON_DAYS {
+            let range = format!("{start} to {end}");
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_range",
+                    format!(
+                        "Invalid date range: {range}. Hourly comparisons cover at most {MAX_HOURLY_COMPARISON_DAYS} days after the start date; use 'daily' for longer ranges."
+                    ),
+                )
+                .with_value(range),
+            ));
+        }
+
+        let comparison =
+            transitions::compare_zones(&first, &second, start, end, params.granularity).map_err(
+                |e| {
+                    rmcp::ErrorData::internal_error(
+                        format!("Failed to compare timezones: {e}"),
+                        None,
+                    )
+                },
+            )?;
+
+        tool_success(&CompareTimezonesResponse {
+            timezones: [
+                crate::core::zone_label(&first),
+                crate::core::zone_label(&second),
+            ],
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            granularity: params.granularity,
+            usual_difference: comparison.usual_difference,
+            changes: comparison.changes,
+            periods: comparison.periods,
+            rows: comparison.rows,
+        })
+    }
+
+    /// Find meeting times inside everyone's working hours.
+    #[tool(
+        name = "find_meeting_time",
+        description = "Find meeting start times that fall within every participant's working hours (09:00-17:00 local by default, configurable per zone), skipping weekends unless include_weekends is set. Searches from now, or over days starting at a date in the first participant's timezone, and returns the shared windows and the best candidates, ranked so that nobody is close to the start or end of their working day. Candidates are shown in UTC and in each participant's timezone, following DST. Holidays are not considered; check them with is_holiday.",
+        output_schema = output_schema::<FindMeetingTimeResponse>()
+    )]
+    pub async fn find_meeting_time(
+        &self,
+        Parameters(params): Parameters<FindMeetingTimeParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        if !(2..=MAX_MEETING_ZONES).contains(&params.participants.len()) {
+            return Ok(tool_error(ToolError::new(
+                "invalid_arguments",
+                format!("Provide between 2 and {MAX_MEETING_ZONES} participants."),
+            )));
+        }
+        let duration_minutes = params.duration_minutes.unwrap_or(30);
+        let days = params.days.unwrap_or(1);
+        let step_minutes = params.step_minutes.unwrap_or(30);
+        let limit = params.limit.unwrap_or(DEFAULT_MEETING_CANDIDATES);
+        let out_of_range = [
+            ("duration_minutes", duration_minutes, 1..=1440),
+            ("days", days, 1..=MAX_MEETING_DAYS),
+            ("step_minutes", step_minutes, 5..=240),
+        ]
+        .into_iter()
+        .find(|(_, value, range)| !range.contains(value));
+        if let Some((name, value, range)) = out_of_range {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_arguments",
+                    format!(
+                        "Invalid {name}: {value}. Expected a value between {} and {}.",
+                        range.start(),
+                        range.end()
+                    ),
+                )
+                .with_value(value.to_string()),
+            ));
+        }
+        if !(1..=MAX_MEETING_CANDIDATES).contains(&limit) {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_arguments",
+                    format!(
+                        "Invalid limit: {limit}. Expected a value between 1 and {MAX_MEETING_CANDIDATES}."
+                    ),
+                )
+                .with_value(limit.to_string()),
+            ));
+        }
+
+        let locale = match parse_locale(params.locale.as_deref()) {
+            Ok(locale) => locale,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let mut participants = Vec::new();
+        for zone in &params.participants {
+            let tz = match self.resolve_timezone(&zone.timezone) {
+                Ok(tz) => tz,
+                Err(msg) => return Ok(tool_error(msg)),
+            };
+            let clock = |input: Option<&str>, default: &str| {
+                parse_clock_time(input.unwrap_or(default).trim(), self.lenient_parsing)
+            };
+            let (start, end) = match (
+                clock(zone.work_start.as_deref(), "09:00"),
+                clock(zone.work_end.as_deref(), "17:00"),
+            ) {
+                (Ok(start), Ok(end)) => (start, end),
+                (Err(msg), _) | (_, Err(msg)) => return Ok(tool_error(msg)),
+            };
+            participants.push(meeting::WorkingHours {
+                tz,
+                start,
+                end,
+                weekends: params.include_weekends,
+            });
+        }
+
+        let internal = |e: jiff::Error| {
+            rmcp::ErrorData::internal_error(format!("Failed to resolve search range: {e}"), None)
+        };
+        let step = jiff::SignedDuration::from_mins(i64::from(step_minutes));
+        let search = match params.date.as_deref() {
+            Some(input) => {
+                let date = match parse_date(input) {
+                    Ok(date) => date,
+                    Err(msg) => return Ok(tool_error(msg)),
+                };
+                let tz = &participants[0].tz;
+                let start = date.to_zoned(tz.clone()).and_then(|zdt| zdt.start_of_day());
+                let end = date
+                    .checked_add(jiff::Span::new().days(days))
+                    .and_then(|end| end.to_zoned(tz.clone()))
+                    .and_then(|zdt| zdt.start_of_day());
+                match (start, end) {
+                    (Ok(start), Ok(end)) => meeting::Interval {
+                        start: start.timestamp(),
+                        end: end.timestamp(),
+                    },
+                    _ => {
+                        return Ok(tool_error(
+                            ToolError::new(
+                                "invalid_date",
+                                format!("Date out of range: '{input}' plus {days} days."),
+                            )
+                            .with_value(input),
+                        ));
+                    }
+                }
+            }
+            None => {
+                // Round up to the step, so candidates start on round times.
+                let step_secs = step.as_secs();
+                let now = self.now().as_second();
+                let start = jiff::Timestamp::from_second(
+                    now + (step_secs - now.rem_euclid(step_secs)) % step_secs,
+                )
+                .map_err(internal)?;
+                let end = start
+                    .checked_add(jiff::SignedDuration::from_hours(24 * i64::from(days)))
+                    .map_err(internal)?;
+                meeting::Interval { start, end }
+            }
+        };
+
+        let mut schedules = Vec::new();
+        for hours in &participants {
+            schedules.push(hours.intervals(search).map_err(internal)?);
+        }
+        let shared = schedules[1..]
+            .iter()
+            .fold(schedules[0].clone(), |shared, next| {
+                meeting::intersect(&shared, next)
+            });
+        let duration = jiff::SignedDuration::from_mins(i64::from(duration_minutes));
+        let found = meeting::candidates(&shared, &schedules, duration, step);
+
+        let note = if shared.is_empty() {
+            Some("No time in the searched range is within every participant's working hours. Widen the working hours, search more days, or include weekends.".to_string())
+        } else if found.is_empty() {
+            let longest = shared
+                .iter()
+                .map(|window| window.duration().as_mins())
+                .max()
+                .unwrap_or(0);
+            Some(format!(
+                "The longest shared window is {longest} minutes, shorter than the {duration_minutes}-minute meeting."
+            ))
+        } else {
+            None
+        };
+        let render = |ts: jiff::Timestamp, tz: &jiff::tz::TimeZone| {
+            ts.to_zoned(tz.clone())
+                .strftime("%Y-%m-%dT%H:%M:%S%:z")
+                .to_string()
+        };
+        tool_success(&FindMeetingTimeResponse {
+            from: search.start.to_string(),
+            to: search.end.to_string(),
+            duration_minutes,
+            shared_windows: shared
+                .iter()
+                .map(|window| SharedWindow {
+                    start: window.start.to_string(),
+                    end: window.end.to_string(),
+                    minutes: window.duration().as_mins(),
+                })
+                .collect(),
+            candidates: found
+                .into_iter()
+                .take(limit)
+                .map(|candidate| MeetingCandidate {
+                    start: candidate.slot.start.to_string(),
+                    end: candidate.slot.end.to_string(),
+                    margin_minutes: candidate.margin.as_mins(),
+                    local_times: participants
+                        .iter()
+                        .map(|hours| LocalMeetingTime {
+                            timezone: crate::core::zone_label(&hours.tz),
+                            start: render(candidate.slot.start, &hours.tz),
+                            end: render(candidate.slot.end, &hours.tz),
+                            human_readable: locale.map(|locale| {
+                                locale.format(&candidate.slot.start.to_zoned(hours.tz.clone()))
+                            }),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            note,
+        })
+    }
+
+    /// Describe a date's place in the calendar.
+    #[tool(
+        name = "get_calendar_info",
+        description = "Describe a date (default today in a timezone): its ISO 8601 week number, week-year, and week date, day of the year, weekday, quarter, days remaining in its month and year, and whether its year is a leap year. Use this rather than working out week numbers by hand; near New Year the ISO week can belong to the previous or next year.",
+        output_schema = output_schema::<CalendarInfoResponse>()
+    )]
+    pub async fn get_calendar_info(
+        &self,
+        Parameters(params): Parameters<GetCalendarInfoParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let (date, timezone) = match params.date.as_deref() {
+            Some(input) => match parse_date(input) {
+                Ok(date) => (date, None),
+                Err(msg) => return Ok(tool_error(msg)),
+            },
+            None => {
+                let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+                    Ok(tz) => tz,
+                    Err(msg) => return Ok(tool_error(msg)),
+                };
+                (self.now_in(&tz).date(), Some(crate::core::zone_label(&tz)))
+            }
+        };
+        let week = date.iso_week_date();
+
+        tool_success(&CalendarInfoResponse {
+            date: date.to_string(),
+            timezone,
+            day_of_week: date.strftime("%A").to_string(),
+            iso_weekday: date.weekday().to_monday_one_offset(),
+            iso_week: week.week(),
+            iso_week_year: week.year(),
+            iso_week_date: format!(
+                "{:04}-W{:02}-{}",
+                week.year(),
+                week.week(),
+                week.weekday().to_monday_one_offset()
+            ),
+            day_of_year: date.day_of_year(),
+            quarter: (date.month() - 1) / 3 + 1,
+            days_remaining_in_month: date.days_in_month() - date.day(),
+            days_remaining_in_year: date.days_in_year() - date.day_of_year(),
+            is_leap_year: date.in_leap_year(),
+        })
+    }
+
+    /// Convert a date between the Gregorian, Hebrew, Islamic, and Japanese
+    /// calendars.
+    #[tool(
+        name = "convert_calendar",
+        description = "Convert a date (default today in a timezone) between calendar systems: Gregorian, Hebrew, tabular Islamic, and Japanese era. Returns each calendar's era, year, month number and name, day, and the date written out, so dates like '1 Tishrei 5786', '1 Ramadan 1447 AH', or 'Reiwa 7' need not be worked out by hand. The tabular Islamic calendar can differ by a day or two from calendars based on sighting the new moon, such as Umm al-Qura.",
+        output_schema = output_schema::<ConvertCalendarResponse>()
+    )]
+    pub async fn convert_calendar(
+        &self,
+        Parameters(params): Parameters<ConvertCalendarParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let (date, timezone) = match params.date.as_deref() {
+            Some(input) => match calendar_system::parse(params.calendar, input) {
+                Ok(date) => (date, None),
+                Err(msg) => {
+                    return Ok(tool_error(
+                        ToolError::new("invalid_date", msg).with_value(input.trim()),
+                    ));
+                }
+            },
+            None => {
+                let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+                    Ok(tz) => tz,
+                    Err(msg) => return Ok(tool_error(msg)),
+                };
+                (self.now_in(&tz).date(), Some(crate::core::zone_label(&tz)))
+            }
+        };
+
+        let mut dates = Vec::new();
+        let mut skipped = Vec::new();
+        match params.targets {
+            Some(targets) => {
+                for target in targets {
+                    match calendar_system::render(target, date) {
+                        Ok(written) => dates.push(written),
+                        Err(msg) => {
+                            return Ok(tool_error(
+                                ToolError::new("date_out_of_range", msg)
+                                    .with_value(date.to_string()),
+                            ));
+                        }
+                    }
+                }
+            }
+            None => {
+                for target in CalendarSystem::ALL {
+                    match calendar_system::render(target, date) {
+                        Ok(written) => dates.push(written),
+                        Err(_) => skipped.push(target.as_str()),
+                    }
+                }
+            }
+        }
+        let note = (!skipped.is_empty()).then(|| {
+            format!(
+                "{date} is before the first year of {}, so it is left out.",
+                skipped.join(" and ")
+            )
+        });
+
+        tool_success(&ConvertCalendarResponse {
+            input: params.date.map(|input| input.trim().to_string()),
+            calendar: params.calendar,
+            gregorian: date.to_string(),
+            day_of_week: date.strftime("%A").to_string(),
+            timezone,
+            dates,
+            note,
+        })
+    }
+
+    /// List one zone's transitions over a year, a date range, or the next
+    /// year.
+    #[tool(
+        name = "get_dst_transitions",
+        description = "List a timezone's DST and other offset transitions: the instant of each, the UTC offset and abbreviation before and after, and whether DST is in effect afterwards. Lists the next 12 months by default; pass a year or a start_date/end_date range (up to 366 days) for past or future transitions.",
+        output_schema = output_schema::<GetDstTransitionsResponse>()
+    )]
+    pub async fn get_dst_transitions(
+        &self,
+        Parameters(params): Parameters<GetDstTransitionsParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone(&params.timezone) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        // Local midnight at the start of `date`, as an instant.
+        let local_start = |date: jiff::civil::Date| {
+            date.to_zoned(tz.clone())
+                .map(|zdt| zdt.timestamp())
+                .map_err(|e| {
+                    rmcp::ErrorData::internal_error(format!("Failed to resolve date: {e}"), None)
+                })
+        };
+
+        let (start, end) = match (params.year, params.start_date, params.end_date) {
+            (None, None, None) => {
+                let now = self.now();
+                let end = now
+                    .checked_add(jiff::SignedDuration::from_hours(
+                        24 * i64::from(MAX_RANGE_DAYS),
+                    ))
+                    .map_err(|e| {
+                        rmcp::ErrorData::internal_error(
+                            format!("Failed to compute window end: {e}"),
+                            None,
+                        )
+                    })?;
+                (now, end)
+            }
+            (Some(year), None, None) => {
+                if !(1..=9998).contains(&year) {
+                    return Ok(tool_error(
+                        ToolError::new(
+                            "invalid_year",
+                            ERR_INVALID_YEAR.replace("{}", &year.to_string()),
+                        )
+                        .with_value(year.to_string()),
+                    ));
+                }
+                (
+                    local_start(jiff::civil::date(year, 1, 1))?,
+                    local_start(jiff::civil::date(year + 1, 1, 1))?,
+                )
+            }
+            (None, Some(start_date), Some(end_date)) => {
+                let (first, last) = match parse_date_range(&start_date, &end_date) {
+                    Ok(range) => range,
+                    Err(msg) => return Ok(tool_error(msg)),
+                };
+                let after_last = last.tomorrow().map_err(|e| {
+                    rmcp::ErrorData::internal_error(format!("Failed to resolve date: {e}"), None)
+                })?;
+                (local_start(first)?, local_start(after_last)?)
+            }
+            _ => {
+                return Ok(tool_error(ToolError::new(
+                    "invalid_arguments",
+                    "Provide either a year, or both start_date and end_date.",
+                )));
+            }
+        };
+
+        let render = |ts: jiff::Timestamp| {
+            ts.to_zoned(tz.clone())
+                .strftime("%Y-%m-%dT%H:%M:%S%:z")
+                .to_string()
+        };
+        let response = GetDstTransitionsResponse {
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            from: render(start),
+            to: render(end),
+            transitions: transitions::zone_transitions(&tz, start, end),
+        };
+
+        tool_success(&response)
+    }
+
+    /// List a country's public holidays in a year.
+    #[tool(
+        name = "get_holidays",
+        description = "List a country's public holidays in a year, with the weekday each is observed on when it falls on a weekend. Pass a region (e.g., 'BY' for Bavaria, 'SCT' for Scotland) for its holidays plus the nationwide ones; without one, regional holidays are listed with the regions they apply to.",
+        output_schema = output_schema::<GetHolidaysResponse>()
+    )]
+    pub async fn get_holidays(
+        &self,
+        Parameters(params): Parameters<GetHolidaysParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let (country, region) =
+            match self.holiday_country(&params.country, params.region.as_deref()) {
+                Ok(found) => found,
+                Err(msg) => return Ok(tool_error(msg)),
+            };
+        let year = params
+            .year
+            .unwrap_or_else(|| self.now_in(&self.default_timezone).year());
+        if !(1..=9998).contains(&year) {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_year",
+                    ERR_INVALID_YEAR.replace("{}", &year.to_string()),
+                )
+                .with_value(year.to_string()),
+            ));
+        }
+
+        tool_success(&GetHolidaysResponse {
+            country: country.code.clone(),
+            name: country.name.clone(),
+            region: region.map(str::to_string),
+            year,
+            holidays: country
+                .holidays(year, region)
+                .into_iter()
+                .map(HolidayEntry::from)
+                .collect(),
+        })
+    }
+
+    /// List upcoming DST transitions across all zones.
+    #[tool(
+        name = "global_dst_events",
+        description = "List DST transitions in every timezone over the next N days (default 30), grouped by instant, along with the most recent transition before now.",
+        output_schema = output_schema::<GlobalDstEventsResponse>()
+    )]
+    pub async fn global_dst_events(
+        &self,
+        Parameters(params): Parameters<GlobalDstEventsParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let days = params.days.unwrap_or(30);
+        if !(1..=366).contains(&days) {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_days",
+                    ERR_INVALID_DAYS.replace("{}", &days.to_string()),
+                )
+                .with_value(days.to_string()),
+            ));
+        }
+
+        let now = self.now();
+        let end = now
+            .checked_add(jiff::SignedDuration::from_hours(i64::from(days) * 24))
+            .map_err(|e| {
+                rmcp::ErrorData::internal_error(format!("Failed to compute window end: {e}"), None)
+            })?;
+
+        let response = GlobalDstEventsResponse {
+            from: now.to_string(),
+            to: end.to_string(),
+            last: transitions::last_dst_events(now),
+            events: transitions::upcoming_dst_events(now, end),
+        };
+
+        tool_success(&response)
+    }
+
+    /// Check whether a date is a public holiday.
+    #[tool(
+        name = "is_holiday",
+        description = "Check whether a date is a public holiday in a country, or in one of its regions (e.g., 'BY' for Bavaria). Dates a weekend holiday is observed on count too. Without a region, only nationwide holidays count, and regional ones on the date are listed separately.",
+        output_schema = output_schema::<IsHolidayResponse>()
+    )]
+    pub async fn is_holiday(
+        &self,
+        Parameters(params): Parameters<IsHolidayParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let (country, region) =
+            match self.holiday_country(&params.country, params.region.as_deref()) {
+                Ok(found) => found,
+                Err(msg) => return Ok(tool_error(msg)),
+            };
+        let date = match parse_date(&params.date) {
+            Ok(date) => date,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let (holidays, regional): (Vec<_>, Vec<_>) = country
+            .holidays_on(date, region)
+            .into_iter()
+            .partition(|holiday| region.is_some() || holiday.regions.is_empty());
+
+        tool_success(&IsHolidayResponse {
+            date: date.to_string(),
+            country: country.code.clone(),
+            region: region.map(str::to_string),
+            is_holiday: !holidays.is_empty(),
+            holidays: holidays.into_iter().map(HolidayEntry::from).collect(),
+            regional: regional.into_iter().map(HolidayEntry::from).collect(),
+        })
+    }
+
+    /// List the next times a recurrence rule fires.
+    #[tool(
+        name = "next_occurrences",
+        description = "List the next occurrences (default 10, up to 1000) of an iCalendar RRULE (e.g., 'FREQ=MONTHLY;BYDAY=-1FR;BYHOUR=17;BYMINUTE=0') or a cron expression (e.g., '*/15 9-17 * * mon-fri') in a timezone, after a datetime (default now). Wall-clock times skipped by a DST change move forward by the gap, and repeated ones happen once, at the first instance; both are noted.",
+        output_schema = output_schema::<NextOccurrencesResponse>()
+    )]
+    pub async fn next_occurrences(
+        &self,
+        Parameters(params): Parameters<NextOccurrencesParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let count = params.count.unwrap_or(DEFAULT_OCCURRENCES);
+        if !(1..=MAX_OCCURRENCES).contains(&count) {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_arguments",
+                    format!(
+                        "Invalid count: {count}. Expected a value between 1 and {MAX_OCCURRENCES}."
+                    ),
+                )
+                .with_value(count.to_string()),
+            ));
+        }
+        let locale = match parse_locale(params.locale.as_deref()) {
+            Ok(locale) => locale,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let text = params.rule.trim();
+        let upper = text.to_ascii_uppercase();
+        let is_rrule = ["FREQ=", "RRULE:", "DTSTART"]
+            .iter()
+            .any(|marker| upper.contains(marker));
+        let invalid_rule =
+            |msg: String| tool_error(ToolError::new("invalid_rule", msg).with_value(text));
+        let (rule, schedule) = if is_rrule {
+            match RRule::parse(text) {
+                Ok(rule) => (Some(rule), None),
+                Err(msg) => return Ok(invalid_rule(msg)),
+            }
+        } else {
+            match cron::Schedule::parse(text) {
+                Ok(schedule) => (None, Some(schedule)),
+                Err(msg) => return Ok(invalid_rule(msg)),
+            }
+        };
+
+        let tzid = match rule.as_ref().and_then(|rule| rule.start.as_ref()) {
+            Some(rrule::Start {
+                zone: rrule::StartZone::Named(name),
+                ..
+            }) => Some(name.as_str()),
+            _ => None,
+        };
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref().or(tzid)) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        if let (Some(tzid), Some(timezone)) = (tzid, tz.iana_name())
+            && tzid != timezone
+        {
+            return Ok(tool_error(ToolError::new(
+                "invalid_arguments",
+                format!(
+                    "The rule's DTSTART is in {tzid} but timezone is {timezone}. Give the timezone in one place."
+                ),
+            )));
+        }
+        let after = match params.after.as_deref() {
+            Some(input) => match parse_datetime(input, &tz) {
+                Ok(zdt) => zdt,
+                Err(msg) => return Ok(tool_error(msg)),
+            },
+            None => self.now_in(&tz),
+        };
+
+        let civil: Box<dyn Iterator<Item = jiff::civil::DateTime> + '_> = match (&rule, &schedule) {
+            (Some(rule), _) => {
+                let start = match (params.start.as_deref(), &rule.start) {
+                    (Some(input), _) => match parse_datetime(input, &tz) {
+                        Ok(zdt) => zdt.datetime(),
+                        Err(msg) => return Ok(tool_error(msg)),
+                    },
+                    (None, Some(start)) if start.zone == rrule::StartZone::Utc => start
+                        .datetime
+                        .to_zoned(jiff::tz::TimeZone::UTC)
+                        .map(|zdt| zdt.with_time_zone(tz.clone()).datetime())
+                        .unwrap_or(start.datetime),
+                    (None, Some(start)) => start.datetime,
+                    (None, None) => after
+                        .datetime()
+                        .with()
+                        .subsec_nanosecond(0)
+                        .build()
+                        .unwrap_or(after.datetime()),
+                };
+                Box::new(rule.occurrences(start, &tz, after.datetime()))
+            }
+            (None, Some(schedule)) => {
+                if params.start.is_some() {
+                    return Ok(tool_error(ToolError::new(
+                        "invalid_arguments",
+                        "start only applies to RRULEs; cron expressions have no start. Use after instead.",
+                    )));
+                }
+                Box::new(schedule.after(after.datetime()))
+            }
+            (None, None) => unreachable!("a rule or a schedule was parsed"),
+        };
+
+        let format = |zdt: &jiff::Zoned| zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
+        let mut occurrences: Vec<OccurrenceEntry> = Vec::new();
+        let mut last = after.timestamp();
+        for wall in civil {
+            let ambiguous = tz.to_ambiguous_zoned(wall);
+            let Ok(zdt) = ambiguous.clone().compatible() else {
+                break;
+            };
+            let clock = |time: jiff::civil::Time| time.strftime("%H:%M").to_string();
+            let note = match ambiguous.offset() {
+                jiff::tz::AmbiguousOffset::Unambiguous { .. } => None,
+                jiff::tz::AmbiguousOffset::Gap { .. } => Some(format!(
+                    "{} does not exist on {} because clocks move forward; moved to {}.",
+                    clock(wall.time()),
+                    wall.date(),
+                    clock(zdt.time())
+                )),
+                jiff::tz::AmbiguousOffset::Fold { .. } => Some(format!(
+                    "{} happens twice on {} because clocks move back; this is the first.",
+                    clock(wall.time()),
+                    wall.date()
+                )),
+            };
+            // A time moved forward out of a gap can land on or before one
+            // already listed.
+            if zdt.timestamp() <= last {
+                continue;
+            }
+            last = zdt.timestamp();
+            occurrences.push(OccurrenceEntry {
+                datetime: format(&zdt),
+                human_readable: locale.map(|locale| locale.format(&zdt)),
+                utc: zdt.timestamp().strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                note,
+            });
+            if occurrences.len() == count {
+                break;
+            }
+        }
+
+        let note = (occurrences.len() < count).then(|| {
+            format!(
+                "Only {} of {count} occurrences found: the rule ends, or matches no later dates in the range searched.",
+                occurrences.len()
+            )
+        });
+        tool_success(&NextOccurrencesResponse {
+            rule: text.to_string(),
+            kind: if rule.is_some() { "rrule" } else { "cron" },
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            after: format(&after),
+            occurrences,
+            note,
+        })
+    }
+
+    /// Report how a recurring meeting shifts for participants in other zones.
+    #[tool(
+        name = "recurrence_drift",
+        description = "For a meeting that recurs at a fixed local time in one timezone, report over a date range (up to 366 days) when its local time shifts for participants in other timezones because the zones change their clocks on different dates (e.g., the US/EU gap in March). Each participant gets periods of consecutive occurrences at the same local time.",
+        output_schema = output_schema::<RecurrenceDriftResponse>()
+    )]
+    pub async fn recurrence_drift(
+        &self,
+        Parameters(params): Parameters<RecurrenceDriftParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone(&params.timezone) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let time = match parse_clock_time(params.time.trim(), self.lenient_parsing) {
+            Ok(t) => t,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let (start, end) = match parse_date_range(&params.start_date, &params.end_date) {
+            Ok(range) => range,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let mut participants = Vec::new();
+        for name in &params.participant_timezones {
+            match self.resolve_timezone(name) {
+                Ok(tz) => participants.push(tz),
+                Err(msg) => return Ok(tool_error(msg)),
+            }
+        }
+
+        // Times skipped by a spring-forward gap resolve to the later side,
+        // just as calendar apps move such meetings.
+        let meetings: Vec<jiff::Zoned> = recurrence::occurrences(start, end, params.frequency)
+            .into_iter()
+            .map(|date| date.to_datetime(time).to_zoned(tz.clone()))
+            .collect::<Result<_, _>>()
+            .map_err(|e| {
+                rmcp::ErrorData::internal_error(format!("Failed to resolve meeting: {e}"), None)
+            })?;
+
+        let response = RecurrenceDriftResponse {
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            time: time.strftime("%H:%M").to_string(),
+            frequency: params.frequency,
+            occurrences: meetings.len(),
+            participants: participants
+                .iter()
+                .map(|participant| recurrence::drift(&meetings, participant))
+                .collect(),
+        };
+
+        tool_success(&response)
+    }
+
+    /// Summarize offset changes across a set of zones over a date range.
+    #[tool(
+        name = "dst_impact",
+        description = "For a set of timezones (up to 10) and a date range (up to 366 days), report every UTC offset change, the local days that are 23 or 25 hours long as a result, and how the offset difference between each pair of zones changes.",
+        output_schema = output_schema::<DstImpactResponse>()
+    )]
+    pub async fn dst_impact(
+        &self,
+        Parameters(params): Parameters<DstImpactParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        if params.timezones.is_empty() || params.timezones.len() > MAX_IMPACT_ZONES {
+            return Ok(tool_error(ToolError::new(
+                "invalid_arguments",
+                format!("Provide between 1 and {MAX_IMPACT_ZONES} timezones."),
+            )));
+        }
+        let mut zones = Vec::new();
+        for name in &params.timezones {
+            match self.resolve_timezone(name) {
+                Ok(tz) => zones.push(tz),
+                Err(msg) => return Ok(tool_error(msg)),
+            }
+        }
+        let (start, end) = match parse_date_range(&params.start_date, &params.end_date) {
+            Ok(range) => range,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let impact = transitions::dst_impact(&zones, start, end).map_err(|e| {
+            rmcp::ErrorData::internal_error(format!("Failed to compute DST impact: {e}"), None)
+        })?;
+
+        let response = DstImpactResponse {
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            zones: impact.zones,
+            pairs: impact.pairs,
+        };
+
+        tool_success(&response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::tests::extract_text;
+
+    fn business_days_params(end_date: Option<&str>, days: Option<i64>) -> BusinessDaysParams {
+        BusinessDaysParams {
+            start_date: "2025-12-19".into(),
+            end_date: end_date.map(Into::into),
+            days,
+            weekend: None,
+            holidays: vec!["2025-12-25".into(), "2025-12-26".into()],
+        }
+    }
+
+    #[tokio::test]
+    async fn business_days_counts_and_adds() {
+        let server = TimeServer::new();
+        let params = business_days_params(Some("2026-01-02"), None);
+        let result = server.business_days(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["business_days"], 8);
+        assert_eq!(json["calendar_days"], 14);
+        assert_eq!(json["weekend"], serde_json::json!(["saturday", "sunday"]));
+        assert_eq!(
+            json["holidays_skipped"],
+            serde_json::json!(["2025-12-25", "2025-12-26"])
+        );
+
+        let params = business_days_params(None, Some(8));
+        let result = server.business_days(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["end_date"], "2026-01-02");
+
+        let params = BusinessDaysParams {
+            weekend: Some(vec!["friday".into(), "saturday".into()]),
+            holidays: Vec::new(),
+            ..business_days_params(None, Some(-1))
+        };
+        let result = server.business_days(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["end_date"], "2025-12-18");
+        assert!(json.get("holidays_skipped").is_none());
+    }
+
+    #[tokio::test]
+    async fn business_days_rejects_bad_arguments() {
+        let server = TimeServer::new();
+        for params in [
+            business_days_params(None, None),
+            business_days_params(Some("2026-01-02"), Some(3)),
+            business_days_params(None, Some(1_000_000)),
+        ] {
+            let result = server.business_days(Parameters(params)).await.unwrap();
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["code"], "invalid_arguments");
+        }
+
+        let params = business_days_params(None, Some(1_000_000));
+        let result = server.business_days(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["offending_value"], "1000000");
+
+        let params = BusinessDaysParams {
+            weekend: Some(vec!["someday".into()]),
+            ..business_days_params(None, Some(1))
+        };
+        let result = server.business_days(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_weekend");
+
+        let params = BusinessDaysParams {
+            holidays: vec!["Christmas".into()],
+            ..business_days_params(None, Some(1))
+        };
+        let result = server.business_days(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_date");
+    }
+
+    #[tokio::test]
+    async fn get_holidays_lists_a_region() {
+        let server = TimeServer::new();
+        let params = GetHolidaysParams {
+            country: "gb".into(),
+            year: Some(2021),
+            region: Some("GB-SCT".into()),
+        };
+        let result = server.get_holidays(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["country"], "GB");
+        assert_eq!(json["region"], "SCT");
+        let holidays = json["holidays"].as_array().unwrap();
+        let names: Vec<&str> = holidays
+            .iter()
+            .map(|h| h["name"].as_str().unwrap())
+            .collect();
+        assert!(names.contains(&"St Andrew's Day"));
+        assert!(!names.contains(&"Easter Monday"));
+        let christmas = holidays
+            .iter()
+            .find(|h| h["name"] == "Christmas Day")
+            .unwrap();
+        assert_eq!(christmas["date"], "2021-12-25");
+        assert_eq!(christmas["observed"], "2021-12-27");
+        assert!(christmas.get("regions").is_none());
+
+        let params = GetHolidaysParams {
+            country: "DE".into(),
+            year: Some(2025),
+            ..Default::default()
+        };
+        let result = server.get_holidays(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert!(json.get("region").is_none());
+        let epiphany = &json["holidays"][1];
+        assert_eq!(epiphany["name"], "Epiphany");
+        assert_eq!(epiphany["regions"], serde_json::json!(["BW", "BY", "ST"]));
+    }
+
+    fn meeting_zone(timezone: &str) -> MeetingZone {
+        MeetingZone {
+            timezone: timezone.into(),
+            work_start: None,
+            work_end: None,
+        }
+    }
+
+    fn find_meeting_time_params(participants: Vec<MeetingZone>) -> FindMeetingTimeParams {
+        FindMeetingTimeParams {
+            participants,
+            duration_minutes: Some(60),
+            date: Some("2025-03-10".into()),
+            days: None,
+            step_minutes: None,
+            limit: Some(3),
+            include_weekends: false,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn find_meeting_time_ranks_slots_inside_everyones_hours() {
+        let server = TimeServer::new();
+        let params = find_meeting_time_params(vec![
+            meeting_zone("Europe/London"),
+            meeting_zone("America/New_York"),
+        ]);
+        let result = server.find_meeting_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        // New York is on EDT from March 9, London stays on GMT until March 30.
+        assert_eq!(
+            json["shared_windows"],
+            serde_json::json!([
+                { "start": "2025-03-10T13:00:00Z", "end": "2025-03-10T17:00:00Z", "minutes": 240 }
+            ])
+        );
+        let best = &json["candidates"][0];
+        assert_eq!(best["start"], "2025-03-10T14:30:00Z");
+        assert_eq!(best["margin_minutes"], 90);
+        assert_eq!(best["local_times"][0]["start"], "2025-03-10T14:30:00+00:00");
+        assert_eq!(best["local_times"][1]["start"], "2025-03-10T10:30:00-04:00");
+        assert_eq!(json["candidates"].as_array().unwrap().len(), 3);
+
+        let params = FindMeetingTimeParams {
+            participants: vec![
+                MeetingZone {
+                    work_end: Some("6pm".into()),
+                    ..meeting_zone("America/Los_Angeles")
+                },
+                meeting_zone("Asia/Tokyo"),
+            ],
+            ..find_meeting_time_params(Vec::new())
+        };
+        let result = server.find_meeting_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        // 17:00-18:00 in Los Angeles is 09:00-10:00 the next day in Tokyo.
+        assert_eq!(json["candidates"][0]["start"], "2025-03-11T00:00:00Z");
+        assert_eq!(
+            json["candidates"][0]["local_times"][1]["start"],
+            "2025-03-11T09:00:00+09:00"
+        );
+    }
+
+    #[cfg(feature = "locale")]
+    #[tokio::test]
+    async fn find_meeting_time_writes_local_starts_out_for_locale() {
+        let server = TimeServer::new();
+        let params = FindMeetingTimeParams {
+            locale: Some("en-US".into()),
+            ..find_meeting_time_params(vec![
+                meeting_zone("Europe/London"),
+                meeting_zone("America/New_York"),
+            ])
+        };
+        let result = server.find_meeting_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let local_times = &json["candidates"][0]["local_times"];
+        assert_eq!(
+            local_times[0]["human_readable"],
+            "Monday, March 10, 2025 at 2:30 PM"
+        );
+        assert_eq!(
+            local_times[1]["human_readable"],
+            "Monday, March 10, 2025 at 10:30 AM"
+        );
+    }
+
+    #[tokio::test]
+    async fn find_meeting_time_explains_when_nothing_fits() {
+        let server = TimeServer::new();
+        let params = find_meeting_time_params(vec![
+            meeting_zone("America/Los_Angeles"),
+            meeting_zone("Asia/Tokyo"),
+        ]);
+        let result = server.find_meeting_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["candidates"], serde_json::json!([]));
+        assert!(json["note"].as_str().unwrap().contains("working hours"));
+
+        for params in [
+            find_meeting_time_params(vec![meeting_zone("UTC")]),
+            FindMeetingTimeParams {
+                duration_minutes: Some(0),
+                ..find_meeting_time_params(vec![meeting_zone("UTC"), meeting_zone("UTC")])
+            },
+        ] {
+            let result = server.find_meeting_time(Parameters(params)).await.unwrap();
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["code"], "invalid_arguments");
+        }
+    }
+
+    #[tokio::test]
+    async fn get_calendar_info_reports_iso_weeks_and_counts() {
+        let server = TimeServer::new();
+        let info = |date: &str| GetCalendarInfoParams {
+            date: Some(date.into()),
+            ..Default::default()
+        };
+        // 2027-01-01 is a Friday, so it belongs to the last ISO week of 2026.
+        let result = server
+            .get_calendar_info(Parameters(info("2027-01-01")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["day_of_week"], "Friday");
+        assert_eq!(json["iso_weekday"], 5);
+        assert_eq!(json["iso_week"], 53);
+        assert_eq!(json["iso_week_year"], 2026);
+        assert_eq!(json["iso_week_date"], "2026-W53-5");
+        assert_eq!(json["day_of_year"], 1);
+        assert_eq!(json["quarter"], 1);
+        assert_eq!(json["days_remaining_in_month"], 30);
+        assert_eq!(json["days_remaining_in_year"], 364);
+        assert_eq!(json["is_leap_year"], false);
+        assert!(json.get("timezone").is_none());
+
+        let result = server
+            .get_calendar_info(Parameters(info("2028-12-31")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["day_of_year"], 366);
+        assert_eq!(json["quarter"], 4);
+        assert_eq!(json["days_remaining_in_year"], 0);
+        assert_eq!(json["is_leap_year"], true);
+
+        let result = server
+            .get_calendar_info(Parameters(info("2025-02-30")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_date");
+    }
+
+    #[tokio::test]
+    async fn get_calendar_info_defaults_to_today_in_the_timezone() {
+        let now: jiff::Timestamp = "2025-12-31T20:00:00Z".parse().unwrap();
+        let server = TimeServer::new().clock(crate::clock::FixedClock(now));
+        let params = GetCalendarInfoParams {
+            timezone: Some("Asia/Tokyo".into()),
+            ..Default::default()
+        };
+        let result = server.get_calendar_info(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["date"], "2026-01-01");
+        assert_eq!(json["timezone"], "Asia/Tokyo");
+        assert_eq!(json["iso_week_date"], "2026-W01-4");
+    }
+
+    #[tokio::test]
+    async fn convert_calendar_converts_between_calendars() {
+        let server = TimeServer::new();
+        let params = ConvertCalendarParams {
+            date: Some("5786-01-01".into()),
+            calendar: CalendarSystem::Hebrew,
+            ..Default::default()
+        };
+        let result = server.convert_calendar(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["gregorian"], "2025-09-23");
+        assert_eq!(json["day_of_week"], "Tuesday");
+        let dates = json["dates"].as_array().unwrap();
+        let calendars: Vec<&str> = dates
+            .iter()
+            .map(|d| d["calendar"].as_str().unwrap())
+            .collect();
+        assert_eq!(calendars, ["gregorian", "hebrew", "islamic", "japanese"]);
+        assert_eq!(dates[1]["formatted"], "1 Tishrei 5786");
+        assert_eq!(dates[3]["date"], "Reiwa 7-09-23");
+        assert_eq!(dates[3]["native"], "令和7年9月23日");
+
+        let params = ConvertCalendarParams {
+            date: Some("1850-01-01".into()),
+            calendar: CalendarSystem::Gregorian,
+            targets: Some(vec![CalendarSystem::Japanese]),
+            ..Default::default()
+        };
+        let result = server.convert_calendar(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "date_out_of_range");
+
+        let params = ConvertCalendarParams {
+            date: Some("1850-01-01".into()),
+            calendar: CalendarSystem::Gregorian,
+            ..Default::default()
+        };
+        let result = server.convert_calendar(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["dates"].as_array().unwrap().len(), 3);
+        assert!(json["note"].as_str().unwrap().contains("japanese"));
+    }
+
+    #[tokio::test]
+    async fn convert_calendar_defaults_to_today_and_rejects_invalid_dates() {
+        let now: jiff::Timestamp = "2025-02-28T22:00:00Z".parse().unwrap();
+        let server = TimeServer::new().clock(crate::clock::FixedClock(now));
+        let params = ConvertCalendarParams {
+            calendar: CalendarSystem::Gregorian,
+            targets: Some(vec![CalendarSystem::Islamic]),
+            timezone: Some("Asia/Riyadh".into()),
+            ..Default::default()
+        };
+        let result = server.convert_calendar(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["gregorian"], "2025-03-01");
+        assert_eq!(json["timezone"], "Asia/Riyadh");
+        assert_eq!(json["dates"][0]["formatted"], "1 Ramadan 1446 AH");
+
+        let params = ConvertCalendarParams {
+            date: Some("1446-13-01".into()),
+            calendar: CalendarSystem::Islamic,
+            ..Default::default()
+        };
+        let result = server.convert_calendar(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_date");
+    }
+
+    #[tokio::test]
+    async fn is_holiday_counts_observed_and_regional_days() {
+        let server = TimeServer::new();
+        let check = |country: &str, date: &str, region: Option<&str>| IsHolidayParams {
+            country: country.into(),
+            date: date.into(),
+            region: region.map(Into::into),
+        };
+        // Independence Day 2026 is a Saturday, observed on Friday.
+        let result = server
+            .is_holiday(Parameters(check("US", "2026-07-03", None)))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["is_holiday"], true);
+        assert_eq!(json["holidays"][0]["name"], "Independence Day");
+        assert_eq!(json["holidays"][0]["observed"], "2026-07-03");
+
+        let result = server
+            .is_holiday(Parameters(check("DE", "2025-06-19", None)))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["is_holiday"], false);
+        assert_eq!(json["regional"][0]["name"], "Corpus Christi");
+
+        let result = server
+            .is_holiday(Parameters(check("DE", "2025-06-19", Some("by"))))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["is_holiday"], true);
+        assert!(json.get("regional").is_none());
+
+        let server = TimeServer::new().holidays({
+            let mut database = (*holidays::Database::bundled()).clone();
+            database
+                .merge(r#"{"countries": {"US": {"holidays": [{"name": "Offsite", "date": "2026-03-13"}]}}}"#)
+                .unwrap();
+            database
+        });
+        let result = server
+            .is_holiday(Parameters(check("US", "2026-03-13", None)))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["holidays"][0]["name"], "Offsite");
+    }
+
+    #[tokio::test]
+    async fn holiday_tools_reject_bad_arguments() {
+        let server = TimeServer::new();
+        let error = |result: CallToolResult| {
+            assert_eq!(result.is_error, Some(true));
+            serde_json::from_str::<serde_json::Value>(&extract_text(&result)).unwrap()
+        };
+        let params = GetHolidaysParams {
+            country: "Narnia".into(),
+            ..Default::default()
+        };
+        let json = error(server.get_holidays(Parameters(params)).await.unwrap());
+        assert_eq!(json["code"], "invalid_country");
+        assert!(
+            json["message"]
+                .as_str()
+                .unwrap()
+                .contains("CA, DE, FR, GB, US")
+        );
+
+        let params = GetHolidaysParams {
+            country: "GB".into(),
+            region: Some("Cornwall".into()),
+            ..Default::default()
+        };
+        let json = error(server.get_holidays(Parameters(params)).await.unwrap());
+        assert_eq!(json["code"], "invalid_region");
+        assert_eq!(json["offending_value"], "Cornwall");
+        assert_eq!(
+            json["suggestions"],
+            serde_json::json!(["ENG", "NIR", "SCT", "WLS"])
+        );
+
+        let params = GetHolidaysParams {
+            country: "US".into(),
+            year: Some(0),
+            ..Default::default()
+        };
+        let json = error(server.get_holidays(Parameters(params)).await.unwrap());
+        assert_eq!(json["code"], "invalid_year");
+
+        let params = IsHolidayParams {
+            country: "US".into(),
+            date: "July 4th".into(),
+            ..Default::default()
+        };
+        let json = error(server.is_holiday(Parameters(params)).await.unwrap());
+        assert_eq!(json["code"], "invalid_date");
+    }
+
+    #[tokio::test]
+    async fn next_occurrences_lists_rules_across_dst() {
+        let server = TimeServer::new();
+        let next = |rule: &str, timezone: Option<&str>, after: &str| NextOccurrencesParams {
+            rule: rule.into(),
+            timezone: timezone.map(Into::into),
+            after: Some(after.into()),
+            count: Some(2),
+            ..Default::default()
+        };
+        // New York skips from 02:00 to 03:00 on 2025-03-09.
+        let params = next("30 2 * * *", Some("America/New_York"), "2025-03-08T12:00");
+        let result = server.next_occurrences(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["kind"], "cron");
+        let occurrences = json["occurrences"].as_array().unwrap();
+        assert_eq!(occurrences[0]["datetime"], "2025-03-09T03:30:00-04:00");
+        assert!(
+            occurrences[0]["note"]
+                .as_str()
+                .unwrap()
+                .contains("does not exist")
+        );
+        assert_eq!(occurrences[1]["datetime"], "2025-03-10T02:30:00-04:00");
+        assert!(occurrences[1].get("note").is_none());
+
+        let params = next("30 1 * * *", Some("America/New_York"), "2025-11-01T12:00");
+        let result = server.next_occurrences(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(
+            json["occurrences"][0]["datetime"],
+            "2025-11-02T01:30:00-04:00"
+        );
+        assert!(
+            json["occurrences"][0]["note"]
+                .as_str()
+                .unwrap()
+                .contains("twice")
+        );
+
+        // The DTSTART line's TZID sets the timezone, and COUNT ends the rule.
+        let params = NextOccurrencesParams {
+            count: Some(5),
+            ..next(
+                "DTSTART;TZID=Europe/Berlin:20250106T090000\nRRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=3",
+                None,
+                "2025-01-01T00:00:00Z",
+            )
+        };
+        let result = server.next_occurrences(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["kind"], "rrule");
+        assert_eq!(json["timezone"], "Europe/Berlin");
+        let utc: Vec<&str> = json["occurrences"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|o| o["utc"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            utc,
+            [
+                "2025-01-06T08:00:00Z",
+                "2025-01-13T08:00:00Z",
+                "2025-01-20T08:00:00Z"
+            ]
+        );
+        assert!(json["note"].as_str().unwrap().contains("Only 3 of 5"));
+    }
+
+    #[cfg(feature = "locale")]
+    #[tokio::test]
+    async fn next_occurrences_writes_occurrences_out_for_locale() {
+        let server = TimeServer::new();
+        let params = NextOccurrencesParams {
+            rule: "0 9 * * 1".into(),
+            timezone: Some("Europe/London".into()),
+            after: Some("2025-03-05T12:00".into()),
+            count: Some(1),
+            locale: Some("en-GB".into()),
+            ..Default::default()
+        };
+        let result = server.next_occurrences(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(
+            json["occurrences"][0]["human_readable"],
+            "Monday 10 March 2025 at 09:00"
+        );
+    }
+
+    #[tokio::test]
+    async fn next_occurrences_rejects_bad_arguments() {
+        let server = TimeServer::new();
+        let params = |rule: &str, timezone: Option<&str>, count: Option<usize>| {
+            Parameters(NextOccurrencesParams {
+                rule: rule.into(),
+                timezone: timezone.map(Into::into),
+                count,
+                ..Default::default()
+            })
+        };
+        let error = |result: CallToolResult| {
+            assert_eq!(result.is_error, Some(true));
+            serde_json::from_str::<serde_json::Value>(&extract_text(&result)).unwrap()
+        };
+        let json = error(
+            server
+                .next_occurrences(params("FREQ=FORTNIGHTLY", None, None))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(json["code"], "invalid_rule");
+        assert_eq!(json["offending_value"], "FREQ=FORTNIGHTLY");
+
+        let json = error(
+            server
+                .next_occurrences(params("0 9 * *", None, None))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(json["code"], "invalid_rule");
+
+        let json = error(
+            server
+                .next_occurrences(params("@daily", None, Some(0)))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(json["code"], "invalid_arguments");
+
+        let rule = "DTSTART;TZID=Asia/Tokyo:20250101T090000\nFREQ=DAILY";
+        let json = error(
+            server
+                .next_occurrences(params(rule, Some("Europe/Paris"), None))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(json["code"], "invalid_arguments");
+
+        let json = error(
+            server
+                .next_occurrences(params("@daily", Some("Mars/Olympus"), None))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(json["code"], "invalid_timezone");
+    }
+
+    #[tokio::test]
+    async fn abbreviation_calendar_lists_periods_for_year() {
+        let server = TimeServer::new();
+        let params = AbbreviationCalendarParams {
+            timezone: "Europe/Berlin".into(),
+            year: Some(2025),
+        };
+        let result = server
+            .abbreviation_calendar(Parameters(params))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["year"], 2025);
+        assert_eq!(json["abbreviations"].as_array().unwrap().len(), 2);
+        assert_eq!(json["abbreviations"][1]["abbreviation"], "CEST");
+        assert_eq!(json["abbreviations"][1]["utc_offset"], "+02:00");
+        assert_eq!(json["periods"].as_array().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn abbreviation_calendar_rejects_out_of_range_year() {
+        let server = TimeServer::new();
+        let params = AbbreviationCalendarParams {
+            timezone: "UTC".into(),
+            year: Some(9999),
+        };
+        let result = server
+            .abbreviation_calendar(Parameters(params))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(extract_text(&result).contains("Invalid year"));
+    }
+
+    fn dst_transitions_params(timezone: &str) -> GetDstTransitionsParams {
+        GetDstTransitionsParams {
+            timezone: timezone.into(),
+            year: None,
+            start_date: None,
+            end_date: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn get_dst_transitions_lists_a_historical_year() {
+        let server = TimeServer::new();
+        let params = GetDstTransitionsParams {
+            year: Some(2006),
+            ..dst_transitions_params("America/New_York")
+        };
+        let result = server
+            .get_dst_transitions(Parameters(params))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["from"], "2006-01-01T00:00:00-05:00");
+        assert_eq!(json["to"], "2007-01-01T00:00:00-05:00");
+        // Before the 2007 US change, DST ran from April to October.
+        assert_eq!(
+            json["transitions"][0]["datetime"],
+            "2006-04-02T03:00:00-04:00"
+        );
+        assert_eq!(
+            json["transitions"][1]["datetime"],
+            "2006-10-29T01:00:00-05:00"
+        );
+        assert_eq!(json["transitions"][1]["abbreviation_before"], "EDT");
+    }
+
+    #[tokio::test]
+    async fn get_dst_transitions_lists_a_date_range_and_defaults_to_the_next_year() {
+        let server = TimeServer::new();
+        let params = GetDstTransitionsParams {
+            start_date: Some("2025-10-01".into()),
+            end_date: Some("2025-10-26".into()),
+            ..dst_transitions_params("Europe/London")
+        };
+        let result = server
+            .get_dst_transitions(Parameters(params))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["to"], "2025-10-27T00:00:00+00:00");
+        assert_eq!(json["transitions"].as_array().unwrap().len(), 1);
+        assert_eq!(json["transitions"][0]["abbreviation"], "GMT");
+
+        let params = dst_transitions_params("Europe/London");
+        let result = server
+            .get_dst_transitions(Parameters(params))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["transitions"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_dst_transitions_rejects_mixed_or_partial_windows() {
+        let server = TimeServer::new();
+        for (year, start_date, end_date) in [
+            (Some(2025), Some("2025-01-01"), Some("2025-02-01")),
+            (None, Some("2025-01-01"), None),
+        ] {
+            let params = GetDstTransitionsParams {
+                year,
+                start_date: start_date.map(Into::into),
+                end_date: end_date.map(Into::into),
+                ..dst_transitions_params("UTC")
+            };
+            let result = server
+                .get_dst_transitions(Parameters(params))
+                .await
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["code"], "invalid_arguments");
+        }
+    }
+
+    #[tokio::test]
+    async fn global_dst_events_reports_window() {
+        let server = TimeServer::new();
+        let params = GlobalDstEventsParams { days: Some(366) };
+        let result = server.global_dst_events(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert!(!json["events"].as_array().unwrap().is_empty());
+        assert!(!json["last"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn global_dst_events_rejects_zero_days() {
+        let server = TimeServer::new();
+        let params = GlobalDstEventsParams { days: Some(0) };
+        let result = server.global_dst_events(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(extract_text(&result).contains("Invalid number of days"));
+    }
+
+    fn recurrence_drift_params(start_date: &str, end_date: &str) -> RecurrenceDriftParams {
+        RecurrenceDriftParams {
+            timezone: "America/New_York".into(),
+            time: "10:00".into(),
+            participant_timezones: vec!["Europe/Berlin".into(), "America/Chicago".into()],
+            start_date: start_date.into(),
+            end_date: end_date.into(),
+            frequency: Frequency::Weekly,
+        }
+    }
+
+    #[tokio::test]
+    async fn recurrence_drift_reports_mismatched_dst_dates() {
+        let server = TimeServer::new();
+        let params = recurrence_drift_params("2025-03-04", "2025-04-08");
+        let result = server.recurrence_drift(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["occurrences"], 6);
+
+        let berlin = &json["participants"][0];
+        assert_eq!(berlin["shifts"], 2);
+        assert_eq!(berlin["periods"][1]["from"], "2025-03-11");
+        assert_eq!(berlin["periods"][1]["local_time"], "15:00");
+        assert_eq!(berlin["periods"][1]["time_difference"], "+5:00");
+        assert_eq!(berlin["periods"][2]["local_time"], "16:00");
+
+        let chicago = &json["participants"][1];
+        assert_eq!(chicago["shifts"], 0);
+        assert_eq!(chicago["periods"][0]["occurrences"], 6);
+    }
+
+    #[tokio::test]
+    async fn recurrence_drift_rejects_reversed_and_long_ranges() {
+        let server = TimeServer::new();
+        for (start, end) in [("2025-04-01", "2025-03-01"), ("2025-01-01", "2026-06-01")] {
+            let params = recurrence_drift_params(start, end);
+            let result = server.recurrence_drift(Parameters(params)).await.unwrap();
+            assert_eq!(result.is_error, Some(true));
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["code"], "invalid_range");
+        }
+    }
+
+    fn compare_timezones_params(
+        end_date: &str,
+        granularity: Granularity,
+    ) -> CompareTimezonesParams {
+        CompareTimezonesParams {
+            first_timezone: "Europe/Paris".into(),
+            second_timezone: "America/Chicago".into(),
+            start_date: "2025-10-01".into(),
+            end_date: end_date.into(),
+            granularity,
+        }
+    }
+
+    #[tokio::test]
+    async fn compare_timezones_flags_asymmetric_dst_weeks() {
+        let server = TimeServer::new();
+        let params = compare_timezones_params("2025-11-30", Granularity::Daily);
+        let result = server.compare_timezones(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["usual_difference"], "-7:00");
+        assert_eq!(json["rows"].as_array().unwrap().len(), 61);
+        let mismatch = &json["periods"][1];
+        assert_eq!(mismatch["from"], "2025-10-26T12:00:00+01:00");
+        assert_eq!(mismatch["to"], "2025-11-01T12:00:00+01:00");
+        assert_eq!(mismatch["difference"], "-6:00");
+        assert_eq!(mismatch["mismatch"], true);
+        assert_eq!(
+            json["changes"][0]["changed_zones"],
+            serde_json::json!(["Europe/Paris"])
+        );
+        assert_eq!(json["changes"][1]["second"], "2025-11-02T01:00:00-06:00");
+
+        let params = compare_timezones_params("2025-11-30", Granularity::Hourly);
+        let result = server.compare_timezones(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_range");
+    }
+
+    #[tokio::test]
+    async fn dst_impact_reports_zone_and_pair_changes() {
+        let server = TimeServer::new();
+        let params = DstImpactParams {
+            timezones: vec!["Europe/Paris".into(), "America/Chicago".into()],
+            start_date: "2025-10-01".into(),
+            end_date: "2025-11-30".into(),
+        };
+        let result = server.dst_impact(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["zones"][0]["changes"][0]["local_date"], "2025-10-26");
+        assert_eq!(json["zones"][0]["changes"][0]["hours_in_day"], 25.0);
+        assert_eq!(json["zones"][1]["changes"][0]["local_date"], "2025-11-02");
+        let pair = &json["pairs"][0];
+        assert_eq!(pair["initial_difference"], "-7:00");
+        assert_eq!(pair["changes"][0]["to_difference"], "-6:00");
+        assert_eq!(pair["changes"][1]["to_difference"], "-7:00");
+    }
+
+    #[tokio::test]
+    async fn dst_impact_rejects_too_many_zones() {
+        let server = TimeServer::new();
+        let params = DstImpactParams {
+            timezones: vec!["UTC".into(); 11],
+            start_date: "2025-01-01".into(),
+            end_date: "2025-01-31".into(),
+        };
+        let result = server.dst_impact(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_arguments");
+    }
+}
@@ -0,0 +1,192 @@
+use rmcp::{handler::server::wrapper::Parameters, model::CallToolResult, tool, tool_router};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::ntp;
+
+/// How long `check_clock_drift` waits for a reply.
+const NTP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Offset beyond which the host clock is reported as inaccurate when no
+/// tolerance is given, in seconds.
+const DEFAULT_TOLERANCE_SECONDS: f64 = 1.0;
+
+/// Parameters for the `check_clock_drift` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct CheckClockDriftParams {
+    /// NTP server to query, optionally with a port (e.g., 'time.google.com', '192.168.1.1:123'). Defaults to the configured server (pool.ntp.org unless configured).
+    #[serde(default)]
+    pub server: Option<String>,
+    /// Largest offset, in seconds, at which the host clock still counts as accurate. Defaults to 1.
+    #[serde(default)]
+    pub tolerance_seconds: Option<f64>,
+}
+
+/// Response payload for `check_clock_drift`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct CheckClockDriftResponse {
+    /// The server queried, as `host:port`.
+    server: String,
+    /// Address the reply came from.
+    address: String,
+    stratum: u8,
+    /// Reference clock code (e.g., 'GPS') for stratum 1 servers, otherwise
+    /// the upstream server's IPv4 address.
+    reference_id: String,
+    /// The host clock when the reply arrived.
+    host_time: jiff::Timestamp,
+    /// The server's clock at the same moment.
+    server_time: jiff::Timestamp,
+    /// Server time minus host time: positive when the host clock is behind.
+    offset_seconds: f64,
+    /// Network round-trip delay, excluding the server's processing time.
+    delay_seconds: f64,
+    tolerance_seconds: f64,
+    /// Whether the offset is within the tolerance.
+    accurate: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+#[tool_router(router = clock_drift_router, vis = "pub")]
+impl TimeServer {
+    /// Measure the host clock against an NTP server.
+    #[tool(
+        name = "check_clock_drift",
+        description = "Query an NTP server and report how far the host clock is from it (offset, positive when the host is behind) and the network round-trip delay, so a wrong machine clock can be flagged before trusting get_current_time. Needs network access to the server on UDP port 123; the host clock is measured even when the server's time is frozen.",
+        output_schema = output_schema::<CheckClockDriftResponse>()
+    )]
+    pub async fn check_clock_drift(
+        &self,
+        Parameters(params): Parameters<CheckClockDriftParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let requested = params.server.as_deref().unwrap_or(&self.ntp_server);
+        let server = match ntp::parse_server(requested) {
+            Ok(server) => server,
+            Err(msg) => {
+                return Ok(tool_error(
+                    ToolError::new("invalid_ntp_server", msg).with_value(requested.trim()),
+                ));
+            }
+        };
+        let tolerance = params
+            .tolerance_seconds
+            .unwrap_or(DEFAULT_TOLERANCE_SECONDS);
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_arguments",
+                    format!(
+                        "Invalid tolerance_seconds: {tolerance}. Expected zero or more seconds."
+                    ),
+                )
+                .with_value(tolerance.to_string()),
+            ));
+        }
+        let sample = match ntp::query(&server, NTP_TIMEOUT).await {
+            Ok(sample) => sample,
+            Err(msg) => {
+                return Ok(tool_error(
+                    ToolError::new("ntp_unavailable", msg).with_value(&*server),
+                ));
+            }
+        };
+
+        let measurement = sample.measurement;
+        let offset_seconds = measurement.offset.as_secs_f64();
+        let accurate = offset_seconds.abs() <= tolerance;
+        let warning = (!accurate).then(|| {
+            let direction = if offset_seconds > 0.0 {
+                "behind"
+            } else {
+                "ahead of"
+            };
+            format!(
+                "The host clock is {:.3} seconds {direction} {server}; current times reported by this server are off by that much.",
+                offset_seconds.abs()
+            )
+        });
+        tool_success(&CheckClockDriftResponse {
+            server,
+            address: sample.address.to_string(),
+            stratum: sample.reply.stratum,
+            reference_id: sample.reply.reference_id,
+            host_time: measurement.host_time,
+            server_time: measurement.server_time,
+            offset_seconds,
+            delay_seconds: measurement.delay.as_secs_f64(),
+            tolerance_seconds: tolerance,
+            accurate,
+            warning,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::tests::extract_text;
+
+    /// Answer one NTP request on a local port as a server whose clock is
+    /// `ahead` of the host's.
+    async fn fake_ntp_server(ahead: jiff::SignedDuration) -> String {
+        let socket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let address = socket.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            let mut request = [0; 48];
+            let (_, client) = socket.recv_from(&mut request).await.unwrap();
+            let now = crate::ntp::NtpTimestamp::from_timestamp(
+                jiff::Timestamp::now().checked_add(ahead).unwrap(),
+            );
+            let mut reply = [0; 48];
+            reply[0] = 0b00_100_100;
+            reply[1] = 2;
+            reply[12..16].copy_from_slice(&[10, 0, 0, 1]);
+            reply[24..32].copy_from_slice(&request[40..48]);
+            reply[32..40].copy_from_slice(&now.0.to_be_bytes());
+            reply[40..48].copy_from_slice(&now.0.to_be_bytes());
+            socket.send_to(&reply, client).await.unwrap();
+        });
+        address
+    }
+
+    #[tokio::test]
+    async fn check_clock_drift_warns_when_the_host_clock_is_behind() {
+        let address = fake_ntp_server(jiff::SignedDuration::from_secs(3)).await;
+        let server = TimeServer::new().ntp_server(&address);
+        let params = CheckClockDriftParams::default();
+        let result = server.check_clock_drift(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false), "{}", extract_text(&result));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["server"], address);
+        assert_eq!(json["stratum"], 2);
+        assert_eq!(json["reference_id"], "10.0.0.1");
+        let offset = json["offset_seconds"].as_f64().unwrap();
+        assert!((2.9..3.1).contains(&offset), "Got: {offset}");
+        assert_eq!(json["accurate"], false);
+        assert!(json["warning"].as_str().unwrap().contains("behind"));
+
+        let address = fake_ntp_server(jiff::SignedDuration::ZERO).await;
+        let params = CheckClockDriftParams {
+            server: Some(address),
+            tolerance_seconds: Some(0.5),
+        };
+        let result = server.check_clock_drift(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["accurate"], true);
+        assert!(json.get("warning").is_none());
+    }
+
+    #[tokio::test]
+    async fn check_clock_drift_rejects_invalid_servers() {
+        let server = TimeServer::new();
+        let params = CheckClockDriftParams {
+            server: Some("ntp://pool.ntp.org".into()),
+            tolerance_seconds: None,
+        };
+        let result = server.check_clock_drift(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_ntp_server");
+    }
+}
@@ -0,0 +1,1371 @@
+use rmcp::{handler::server::wrapper::Parameters, model::CallToolResult, tool, tool_router};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Error message template for timestamps that match no known format.
+const ERR_UNRECOGNIZED_FORMAT: &str = "Unrecognized timestamp format: '{}'. Supported formats include Unix epoch values, ISO 8601 / RFC 3339, RFC 2822, and numeric day/month/year dates.";
+
+/// Parameters for the `detect_format` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct DetectFormatParams {
+    /// Timestamp string to inspect (e.g., '1700000000', '03/04/2025', '2025-07-04T12:00:00Z')
+    pub input: String,
+}
+
+/// Parameters for the `validate_datetime` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ValidateDatetimeParams {
+    /// Datetime string to check (e.g., '2025-07-04T12:00:00Z', '2025-13-01', 'Fri, 4 Jul 2025 12:00:00 -0400')
+    pub input: String,
+    /// Format the input must be written in: 'rfc3339', 'rfc9557', 'rfc2822', 'iso8601', 'epoch', or a strftime pattern (e.g., '%d/%m/%Y %H:%M'). Any standard format is accepted by default.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// IANA timezone to read datetimes and dates without an offset in (e.g., 'America/New_York'), so the canonical form pins an instant and DST gaps and overlaps are flagged
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Error message template for values that are neither epoch numbers nor datetimes.
+const ERR_INVALID_TIMESTAMP: &str = "Invalid timestamp: '{}'. Expected a Unix epoch number (e.g., '1751632440' or '1751632440123') or a datetime (e.g., '2025-07-04T12:00:00Z' or '2025-07-04T08:00').";
+
+/// Error message template for instants too far from 1970 for a 64-bit
+/// nanosecond epoch.
+const ERR_NANOSECOND_RANGE: &str = "'{}' is outside the range of 64-bit nanosecond epochs (1677-09-21 to 2262-04-11). Use microseconds for instants outside it.";
+
+/// Parameters for the `convert_timestamp` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ConvertTimestampParams {
+    /// Unix epoch number (e.g., '1751632440', '1751632440123') or datetime (e.g., '2025-07-04T12:00:00Z', '2025-07-04T08:00') to convert
+    pub timestamp: String,
+    /// Epoch unit: the unit to read an epoch number in (inferred from its magnitude by default), or the unit to report a datetime's epoch in (seconds by default)
+    #[serde(default)]
+    pub unit: Option<EpochUnit>,
+    /// IANA timezone to show epoch values in, and to read datetimes without an offset in (e.g., 'America/New_York'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `normalize_epochs` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct NormalizeEpochsParams {
+    /// Numeric Unix epoch values in seconds, milliseconds, microseconds, or nanoseconds (mixed units allowed)
+    pub timestamps: Vec<serde_json::Number>,
+    /// IANA timezone name to render the results in (e.g., 'Europe/Berlin'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `group_by_local_day` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct GroupByLocalDayParams {
+    /// RFC 3339 timestamps with a UTC offset (e.g., '2025-03-09T12:00:00Z')
+    pub timestamps: Vec<String>,
+    /// IANA timezone name whose calendar days to group by (e.g., 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub timezone: String,
+    /// Include days with no timestamps between the first and last populated day. Defaults to false.
+    #[serde(default)]
+    pub include_empty_days: bool,
+}
+
+/// Parameters for the `audit_dst` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct AuditDstParams {
+    /// Local datetimes without offset (e.g., '2025-03-09T02:30:00' or '2025-11-02 01:30')
+    pub timestamps: Vec<String>,
+    /// IANA timezone name the local datetimes belong to (e.g., 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub timezone: String,
+}
+
+/// Parameters for the `serial_date` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct SerialDateParams {
+    /// Spreadsheet serial date to convert to a datetime (e.g., 45842.5). Provide either this or `datetime`.
+    #[serde(default)]
+    pub serial: Option<f64>,
+    /// Datetime to convert to a serial date: RFC 3339 (e.g., '2025-07-04T12:00:00Z'), a local datetime (e.g., '2025-07-04T12:00'), or a date. Provide either this or `serial`.
+    #[serde(default)]
+    pub datetime: Option<String>,
+    /// IANA timezone of the spreadsheet's wall-clock time (e.g., 'Europe/London'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Date system: 'excel_1900' (Excel default, includes the 1900 leap-year bug), 'excel_1904' (older Mac workbooks), or 'sheets' (Google Sheets / LibreOffice). Defaults to 'excel_1900'.
+    #[serde(default)]
+    pub system: SerialDateSystem,
+}
+
+/// Parameters for the `sql_literal` tool.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SqlLiteralParams {
+    /// Datetime to render: RFC 3339 (e.g., '2025-07-04T12:00:00Z'), a local datetime (e.g., '2025-07-04T12:00'), or a date
+    pub datetime: String,
+    /// IANA timezone to express the datetime in (e.g., 'Europe/Paris'). Local inputs are read in this zone. Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// SQL dialect: 'postgresql', 'mysql', 'sqlite', or 'sqlserver'. Defaults to all four.
+    #[serde(default)]
+    pub dialect: Option<SqlDialect>,
+    /// Include the UTC offset so the literal pins an exact instant. Defaults to true.
+    #[serde(default = "default_true")]
+    pub with_timezone: bool,
+}
+
+/// Parameters for the `parse_ics` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ParseIcsParams {
+    /// iCalendar (.ics) text containing a VCALENDAR with VEVENTs
+    pub ics: String,
+    /// IANA timezone to render event times in (e.g., 'Europe/Paris'). Floating times without a TZID are also read in this zone. Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `parse_duration` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ParseDurationParams {
+    /// Duration phrase (e.g., 'an hour and a half', '3 business days', 'two weeks from Friday', '90m', '2 days ago')
+    pub input: String,
+    /// IANA timezone the phrase is read in: named days like 'Friday' or 'tomorrow', and local datetimes, are resolved there (e.g., 'America/New_York'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Datetime to treat as now, RFC 3339 or a local datetime (e.g., '2025-07-04T09:00'). Defaults to the current time.
+    #[serde(default)]
+    pub reference: Option<String>,
+}
+
+/// Parameters for the `format_duration` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct FormatDurationParams {
+    /// Duration to format: a phrase (e.g., '90 minutes', '1.5h', '5400s'), an ISO 8601 duration (e.g., 'PT1H30M', '-P2DT3H'), or a bare number of `unit`s (e.g., '5400')
+    pub input: String,
+    /// Unit of a bare number input. Defaults to seconds.
+    #[serde(default)]
+    pub unit: Option<EpochUnit>,
+    /// Largest unit to carry into (e.g., 'hour' keeps '2 days' as 48 hours). Defaults to 'day'.
+    #[serde(default)]
+    pub largest_unit: Option<SpanUnit>,
+    /// Unit to round to, half away from zero (e.g., 'minute'). Defaults to 'nanosecond', which keeps full precision.
+    #[serde(default)]
+    pub smallest_unit: Option<SpanUnit>,
+}
+
+/// Response payload for `detect_format`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct DetectFormatResponse {
+    input: String,
+    candidates: Vec<FormatCandidate>,
+}
+
+/// Response payload for `validate_datetime`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ValidateDatetimeResponse {
+    input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    #[serde(flatten)]
+    validation: Validation,
+}
+
+/// Response payload for `convert_timestamp`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ConvertTimestampResponse {
+    input: String,
+    /// `epoch_to_datetime` or `datetime_to_epoch`.
+    direction: &'static str,
+    /// Unit `epoch` is expressed in.
+    unit: EpochUnit,
+    /// `true` when an epoch number's unit could not be inferred with confidence.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    ambiguous: bool,
+    /// The instant as an epoch value in `unit`.
+    epoch: i64,
+    /// RFC 3339 datetime in `timezone`.
+    datetime: String,
+    timezone: String,
+    utc: String,
+    unix_seconds: i64,
+    unix_milliseconds: i64,
+    unix_microseconds: i64,
+    /// Omitted outside 1677-09-21 to 2262-04-11, where nanoseconds overflow
+    /// a 64-bit integer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unix_nanoseconds: Option<i64>,
+    /// IANA Time Zone Database release that gave `datetime`'s offset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tzdb_version: Option<&'static str>,
+}
+
+/// Response payload for `normalize_epochs`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct NormalizeEpochsResponse {
+    timezone: String,
+    results: Vec<NormalizedEpoch>,
+    ambiguous_count: usize,
+}
+
+/// Response payload for `group_by_local_day`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct GroupByLocalDayResponse {
+    timezone: String,
+    total: usize,
+    days: Vec<LocalDay>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    invalid: Vec<InvalidTimestamp>,
+}
+
+/// Response payload for `audit_dst`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct AuditDstResponse {
+    timezone: String,
+    total: usize,
+    ok_count: usize,
+    nonexistent_count: usize,
+    ambiguous_count: usize,
+    invalid_count: usize,
+    findings: Vec<DstFinding>,
+}
+
+/// Response payload for `serial_date`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct SerialDateResponse {
+    system: SerialDateSystem,
+    timezone: String,
+    serial: f64,
+    datetime: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    notes: Vec<String>,
+}
+
+/// Response payload for `sql_literal`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct SqlLiteralResponse {
+    datetime: String,
+    timezone: String,
+    with_timezone: bool,
+    literals: Vec<SqlLiteral>,
+}
+
+/// Response payload for `parse_ics`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ParseIcsResponse {
+    timezone: String,
+    events: Vec<IcsEvent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    invalid: Vec<InvalidEvent>,
+}
+
+/// Instants a duration phrase spans once resolved against its anchor, or
+/// against the reference time for business days.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ResolvedDuration {
+    timezone: String,
+    from: String,
+    to: String,
+}
+
+/// Response payload for `parse_duration`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ParseDurationResponse {
+    input: String,
+    /// ISO 8601 duration of the calendar and clock units (e.g., "PT1H30M").
+    /// With business days, the days and hours they cover once resolved
+    /// (e.g., "P5D" for 3 business days from a Wednesday).
+    iso8601: String,
+    /// Compact form (e.g., "1h 30m").
+    description: String,
+    /// Length in seconds, when it does not depend on the date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_seconds: Option<f64>,
+    #[serde(skip_serializing_if = "is_zero")]
+    business_days: i64,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    backward: bool,
+    /// Present when the phrase names a starting point.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolved: Option<ResolvedDuration>,
+}
+
+/// Response payload for `format_duration`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct FormatDurationResponse {
+    input: String,
+    /// ISO 8601 duration (e.g., "PT1H30M").
+    iso8601: String,
+    /// Spelled-out form (e.g., "1 hour 30 minutes").
+    human: String,
+    /// Compact form (e.g., "1h 30m").
+    compact: String,
+    total_seconds: f64,
+    total_milliseconds: f64,
+}
+
+/// Serde default for boolean parameters that are on unless disabled.
+fn default_true() -> bool {
+    true
+}
+
+#[tool_router(router = conversion_router, vis = "pub")]
+impl TimeServer {
+    /// Inspect a timestamp string and report the formats it is likely written in.
+    #[tool(
+        name = "detect_format",
+        description = "Inspect a timestamp string and report the most likely format(s) with confidence notes: Unix epoch seconds/milliseconds/microseconds/nanoseconds, ISO 8601 / RFC 3339 variants, RFC 2822, and US (month-first) vs European (day-first) date ordering.",
+        output_schema = output_schema::<DetectFormatResponse>()
+    )]
+    pub async fn detect_format(
+        &self,
+        Parameters(params): Parameters<DetectFormatParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let input = params.input.trim();
+        let candidates = detect::detect_format(input);
+        if candidates.is_empty() {
+            return Ok(tool_error(
+                ToolError::new(
+                    "unrecognized_format",
+                    ERR_UNRECOGNIZED_FORMAT.replacen("{}", input, 1),
+                )
+                .with_value(input),
+            ));
+        }
+
+        let response = DetectFormatResponse {
+            input: input.to_string(),
+            candidates,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Check that a datetime string parses and explain why when it does not.
+    #[tool(
+        name = "validate_datetime",
+        description = "Check whether a datetime string parses, optionally in an expected format (rfc3339, rfc9557, rfc2822, iso8601, epoch, or a strftime pattern). Reports the format that matched and the canonical form, or field-level diagnostics such as 'Month 13 is out of range (01-12)' when it fails. Use it to sanity-check user-provided timestamps before passing them to other tools; an invalid input is a normal result with valid set to false.",
+        output_schema = output_schema::<ValidateDatetimeResponse>()
+    )]
+    pub async fn validate_datetime(
+        &self,
+        Parameters(params): Parameters<ValidateDatetimeParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let expected = match params.format.as_deref().map(Expected::parse).transpose() {
+            Ok(expected) => expected,
+            Err(msg) => {
+                return Ok(tool_error(
+                    ToolError::new("invalid_format", msg)
+                        .with_value(params.format.unwrap_or_default()),
+                ));
+            }
+        };
+        let tz = match params
+            .timezone
+            .as_deref()
+            .map(|tz| self.resolve_timezone(tz))
+            .transpose()
+        {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let input = unicode::normalize_input(params.input.trim());
+        let response = ValidateDatetimeResponse {
+            input: input.to_string(),
+            timezone: tz
+                .as_ref()
+                .map(|tz| tz.iana_name().unwrap_or("UTC").to_string()),
+            validation: validate::validate(&input, expected.as_ref(), tz.as_ref()),
+        };
+
+        tool_success(&response)
+    }
+
+    /// Normalize a list of Unix epoch values with per-value unit inference.
+    #[tool(
+        name = "normalize_epochs",
+        description = "Normalize an array of numeric Unix timestamps to RFC 3339 datetimes in a timezone (defaults to the server's default timezone, UTC unless configured). The unit of each value (seconds, milliseconds, microseconds, nanoseconds) is inferred from its magnitude, and entries whose unit cannot be inferred confidently are flagged as ambiguous.",
+        output_schema = output_schema::<NormalizeEpochsResponse>()
+    )]
+    pub async fn normalize_epochs(
+        &self,
+        Parameters(params): Parameters<NormalizeEpochsParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let results = epoch::normalize(&params.timestamps, &tz);
+        let ambiguous_count = results.iter().filter(|r| r.ambiguous).count();
+
+        let response = NormalizeEpochsResponse {
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            results,
+            ambiguous_count,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Convert between a Unix epoch value and a zoned datetime.
+    #[tool(
+        name = "convert_timestamp",
+        description = "Convert between Unix epoch values and datetimes, in either direction. An epoch number (seconds, milliseconds, microseconds, or nanoseconds, inferred from its magnitude unless a unit is given) becomes an RFC 3339 datetime in the timezone (default UTC); a datetime becomes its epoch value in every unit. Useful for reading timestamps in logs.",
+        output_schema = output_schema::<ConvertTimestampResponse>()
+    )]
+    pub async fn convert_timestamp(
+        &self,
+        Parameters(params): Parameters<ConvertTimestampParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let input = unicode::normalize_input(params.timestamp.trim());
+
+        let (ts, direction, unit, ambiguous) = match EpochValue::parse(&input) {
+            Some(value) => {
+                let inference = value.infer_unit();
+                let (unit, ambiguous) = match params.unit {
+                    Some(unit) => (unit, false),
+                    None => (inference.unit, inference.ambiguous),
+                };
+                match value.to_timestamp(unit) {
+                    Ok(ts) => (ts, "epoch_to_datetime", unit, ambiguous),
+                    Err(msg) => {
+                        return Ok(tool_error(
+                            ToolError::new("invalid_timestamp", msg).with_value(&*input),
+                        ));
+                    }
+                }
+            }
+            None => match parse_datetime(&input, &tz) {
+                Ok(zdt) => (
+                    zdt.timestamp(),
+                    "datetime_to_epoch",
+                    params.unit.unwrap_or(EpochUnit::Seconds),
+                    false,
+                ),
+                Err(_) => {
+                    return Ok(tool_error(
+                        ToolError::new(
+                            "invalid_timestamp",
+                            ERR_INVALID_TIMESTAMP.replacen("{}", &input, 1),
+                        )
+                        .with_value(&*input),
+                    ));
+                }
+            },
+        };
+
+        let nanos = ts.as_nanosecond();
+        let Ok(epoch) = i64::try_from(nanos.div_euclid(unit.nanos_per_unit())) else {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_timestamp",
+                    ERR_NANOSECOND_RANGE.replacen("{}", &input, 1),
+                )
+                .with_value(&*input),
+            ));
+        };
+        let response = ConvertTimestampResponse {
+            direction,
+            unit,
+            ambiguous,
+            epoch,
+            datetime: ts
+                .to_zoned(tz.clone())
+                .strftime("%Y-%m-%dT%H:%M:%S%.f%:z")
+                .to_string(),
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            utc: ts.strftime("%Y-%m-%dT%H:%M:%S%.fZ").to_string(),
+            unix_seconds: ts.as_second(),
+            unix_milliseconds: ts.as_millisecond(),
+            unix_microseconds: ts.as_microsecond(),
+            unix_nanoseconds: i64::try_from(nanos).ok(),
+            tzdb_version: crate::tzdb::version(),
+            input: input.into_owned(),
+        };
+
+        tool_success(&response)
+    }
+
+    /// Group UTC timestamps by calendar day in a timezone.
+    #[tool(
+        name = "group_by_local_day",
+        description = "Group a list of UTC timestamps by local calendar day in a timezone and count them per day. Day boundaries follow local midnight, so DST transition days correctly span 23 or 25 hours; each day reports its length and UTC bounds.",
+        output_schema = output_schema::<GroupByLocalDayResponse>()
+    )]
+    pub async fn group_by_local_day(
+        &self,
+        Parameters(params): Parameters<GroupByLocalDayParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone(&params.timezone) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let grouping =
+            local_day::group_by_local_day(&params.timestamps, &tz, params.include_empty_days)
+                .map_err(|e| {
+                    rmcp::ErrorData::internal_error(
+                        format!("Failed to group timestamps: {e}"),
+                        None,
+                    )
+                })?;
+
+        let response = GroupByLocalDayResponse {
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            total: grouping.days.iter().map(|d| d.count).sum(),
+            days: grouping.days,
+            invalid: grouping.invalid,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Flag local timestamps that fall into a DST gap or fold.
+    #[tool(
+        name = "audit_dst",
+        description = "Scan a list of local datetimes (no offset) in a timezone and flag values that do not exist (spring-forward gap) or occur twice (fall-back overlap), with suggested resolutions for each flagged value.",
+        output_schema = output_schema::<AuditDstResponse>()
+    )]
+    pub async fn audit_dst(
+        &self,
+        Parameters(params): Parameters<AuditDstParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone(&params.timezone) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let audit = dst::audit(&params.timestamps, &tz);
+        let count =
+            |issue: dst::DstIssue| audit.findings.iter().filter(|f| f.issue == issue).count();
+
+        let response = AuditDstResponse {
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            total: params.timestamps.len(),
+            ok_count: audit.ok_count,
+            nonexistent_count: count(dst::DstIssue::Nonexistent),
+            ambiguous_count: count(dst::DstIssue::Ambiguous),
+            invalid_count: count(dst::DstIssue::Invalid),
+            findings: audit.findings,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Convert between spreadsheet serial dates and zoned datetimes.
+    #[tool(
+        name = "serial_date",
+        description = "Convert between Excel / Google Sheets serial date numbers and zoned datetimes, in either direction. Supports Excel's 1900 system (including its 1900 leap-year bug), the 1904 system, and the Google Sheets / LibreOffice system.",
+        output_schema = output_schema::<SerialDateResponse>()
+    )]
+    pub async fn serial_date(
+        &self,
+        Parameters(params): Parameters<SerialDateParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let (serial, local) = match (params.serial, params.datetime.as_deref()) {
+            (Some(serial), None) => match serial_date::serial_to_datetime(serial, params.system) {
+                Ok(dt) => (serial, dt),
+                Err(msg) => return Ok(tool_error(msg)),
+            },
+            (None, Some(input)) => {
+                let local = match parse_datetime(input.trim(), &tz) {
+                    Ok(zdt) => zdt.datetime(),
+                    Err(msg) => return Ok(tool_error(msg)),
+                };
+                match serial_date::datetime_to_serial(local, params.system) {
+                    Ok(serial) => (serial, local),
+                    Err(msg) => return Ok(tool_error(msg)),
+                }
+            }
+            _ => {
+                return Ok(tool_error(ToolError::new(
+                    "invalid_arguments",
+                    "Provide exactly one of 'serial' or 'datetime'.",
+                )));
+            }
+        };
+
+        let mut notes = Vec::new();
+        if params.system == SerialDateSystem::Excel1900 && serial < 61.0 {
+            notes.push(serial_date::LEAP_YEAR_BUG_NOTE.to_string());
+        }
+        let ambiguous = tz.to_ambiguous_zoned(local);
+        if ambiguous.is_ambiguous() {
+            notes.push(format!(
+                "{local} is skipped or repeated by a DST transition in this timezone; resolved to the compatible instant."
+            ));
+        }
+        let zdt = ambiguous.compatible().map_err(|e| {
+            rmcp::ErrorData::internal_error(format!("Failed to resolve datetime: {e}"), None)
+        })?;
+
+        let response = SerialDateResponse {
+            system: params.system,
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            serial,
+            datetime: zdt.strftime("%Y-%m-%dT%H:%M:%S%.f%:z").to_string(),
+            notes,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Format a datetime as SQL literals for common databases.
+    #[tool(
+        name = "sql_literal",
+        description = "Format a datetime as correctly quoted SQL datetime literals for PostgreSQL, MySQL, SQLite, and SQL Server, with or without the UTC offset.",
+        output_schema = output_schema::<SqlLiteralResponse>()
+    )]
+    pub async fn sql_literal(
+        &self,
+        Parameters(params): Parameters<SqlLiteralParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let zdt = match parse_datetime(params.datetime.trim(), &tz) {
+            Ok(zdt) => zdt,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let dialects = match params.dialect {
+            Some(dialect) => vec![dialect],
+            None => SqlDialect::ALL.to_vec(),
+        };
+        let literals = dialects
+            .into_iter()
+            .map(|dialect| sql_literal::literal(&zdt, dialect, params.with_timezone))
+            .collect();
+
+        let response = SqlLiteralResponse {
+            datetime: zdt.strftime("%Y-%m-%dT%H:%M:%S%.f%:z").to_string(),
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            with_timezone: params.with_timezone,
+            literals,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Read the events of an iCalendar file and render them in a timezone.
+    #[tool(
+        name = "parse_ics",
+        description = "Extract the events (summary, start, end, location, UID) from iCalendar (.ics) text and render their times in a timezone. TZID parameters are resolved through the file's VTIMEZONE definitions, then as IANA names; events that cannot be read are listed separately.",
+        output_schema = output_schema::<ParseIcsResponse>()
+    )]
+    pub async fn parse_ics(
+        &self,
+        Parameters(params): Parameters<ParseIcsParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let calendar = match ics::parse(&params.ics, &tz) {
+            Ok(calendar) => calendar,
+            Err(msg) => return Ok(tool_error(ToolError::new("invalid_ics", msg))),
+        };
+
+        let response = ParseIcsResponse {
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            events: calendar.events,
+            invalid: calendar.invalid,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Parse a natural-language duration, resolving it if it names a start.
+    #[tool(
+        name = "parse_duration",
+        description = "Parse a natural-language duration such as 'an hour and a half', '3 business days', '90m', or 'two and a half weeks' into a structured duration. Phrases that name a starting point ('two weeks from Friday', 'in 3 days', '2 hours ago', '45 minutes before 2025-07-04T09:00') are also resolved to an instant in the reference timezone, following its calendar across DST changes.",
+        output_schema = output_schema::<ParseDurationResponse>()
+    )]
+    pub async fn parse_duration(
+        &self,
+        Parameters(params): Parameters<ParseDurationParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let input = unicode::normalize_input(params.input.trim());
+        let parsed = match duration::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(msg) => {
+                return Ok(tool_error(
+                    ToolError::new("invalid_duration", msg).with_value(&*input),
+                ));
+            }
+        };
+
+        // Business days have no fixed length, so they are counted from the
+        // reference time even when the phrase names no starting point.
+        let mut span = parsed.span;
+        let resolved = if parsed.anchor.is_some() || parsed.business_days != 0 {
+            let now = match params.reference.as_deref() {
+                Some(reference) => match parse_datetime(reference.trim(), &tz) {
+                    Ok(zdt) => zdt,
+                    Err(msg) => return Ok(tool_error(msg)),
+                },
+                None => self.now_in(&tz),
+            };
+            let from = match &parsed.anchor {
+                Some(anchor) => match resolve_anchor(anchor, &now, &tz) {
+                    Ok(zdt) => zdt,
+                    Err(msg) => return Ok(tool_error(msg)),
+                },
+                None => now,
+            };
+            let to = match parsed.apply(&from) {
+                Ok(zdt) => zdt,
+                Err(msg) => return Ok(tool_error(msg)),
+            };
+            if parsed.business_days != 0 {
+                let (earlier, later) = if parsed.backward {
+                    (&to, &from)
+                } else {
+                    (&from, &to)
+                };
+                span = match earlier.until((jiff::Unit::Day, later)) {
+                    Ok(span) => span,
+                    Err(e) => {
+                        return Ok(tool_error(ToolError::new(
+                            "invalid_duration",
+                            format!("Result is out of range: {e}"),
+                        )));
+                    }
+                };
+            }
+            let render = |zdt: &jiff::Zoned| zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
+            Some(ResolvedDuration {
+                timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+                from: render(&from),
+                to: render(&to),
+            })
+        } else {
+            None
+        };
+
+        let response = ParseDurationResponse {
+            input: input.into_owned(),
+            iso8601: span.to_string(),
+            description: format!("{span:#}"),
+            total_seconds: parsed.fixed_seconds(),
+            business_days: parsed.business_days,
+            backward: parsed.backward,
+            resolved,
+        };
+
+        tool_success(&response)
+    }
+
+    /// Convert a duration between phrase, ISO 8601, and total forms.
+    #[tool(
+        name = "format_duration",
+        description = "Convert a duration between human-readable ('90 minutes', '1.5h'), ISO 8601 ('PT1H30M'), and total seconds or milliseconds, in any direction. The input is carried into larger units and optionally rounded, so '5400s' becomes '1 hour 30 minutes' and 'PT36H' becomes '1 day 12 hours'. Days count as 24 hours; months and years are rejected because their length depends on the date.",
+        output_schema = output_schema::<FormatDurationResponse>()
+    )]
+    pub async fn format_duration(
+        &self,
+        Parameters(params): Parameters<FormatDurationParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let input = unicode::normalize_input(params.input.trim());
+        let invalid = |msg: String| {
+            Ok(tool_error(
+                ToolError::new("invalid_duration", msg).with_value(&*input),
+            ))
+        };
+
+        let span = if let Some(number) = input.parse::<f64>().ok().filter(|n| n.is_finite()) {
+            let unit = params.unit.unwrap_or(EpochUnit::Seconds);
+            let nanos = (number * unit.nanos_per_unit() as f64).round();
+            match jiff::Span::new().try_nanoseconds(nanos as i64) {
+                Ok(span) if nanos.abs() < i64::MAX as f64 => span,
+                _ => return invalid("Duration is too large.".to_string()),
+            }
+        } else {
+            let parsed = match duration::parse(&input) {
+                Ok(parsed) => parsed,
+                Err(msg) => return invalid(msg),
+            };
+            // "in 2 hours" and "2 hours ago" only give the length a sign.
+            let anchored = !matches!(parsed.anchor, None | Some(Anchor::Now));
+            if anchored || parsed.business_days != 0 {
+                return invalid(
+                    "Only a length of time can be formatted; use parse_duration for business days or phrases with a starting point."
+                        .to_string(),
+                );
+            }
+            if parsed.backward {
+                parsed.span.negate()
+            } else {
+                parsed.span
+            }
+        };
+
+        let balanced = match duration::balance(
+            span,
+            params.largest_unit.unwrap_or(SpanUnit::Day),
+            params.smallest_unit.unwrap_or(SpanUnit::Nanosecond),
+        ) {
+            Ok(balanced) => balanced,
+            Err(msg) => return invalid(msg),
+        };
+        let (total_seconds, total_milliseconds) = match (
+            duration::total(&balanced, jiff::Unit::Second),
+            duration::total(&balanced, jiff::Unit::Millisecond),
+        ) {
+            (Ok(seconds), Ok(milliseconds)) => (seconds, milliseconds),
+            (Err(msg), _) | (_, Err(msg)) => return invalid(msg),
+        };
+
+        let response = FormatDurationResponse {
+            input: input.to_string(),
+            iso8601: balanced.to_string(),
+            human: duration::spell(&balanced),
+            compact: format!("{balanced:#}"),
+            total_seconds,
+            total_milliseconds,
+        };
+
+        tool_success(&response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::tests::extract_text;
+
+    #[tokio::test]
+    async fn detect_format_returns_candidates_for_epoch_millis() {
+        let server = TimeServer::new();
+        let params = DetectFormatParams {
+            input: " 1700000000000 ".into(),
+        };
+        let result = server.detect_format(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["input"], "1700000000000");
+        assert_eq!(json["candidates"][0]["format"], "epoch_milliseconds");
+        assert_eq!(json["candidates"][0]["confidence"], "high");
+        assert_eq!(
+            json["candidates"][0]["interpretation"],
+            "2023-11-14T22:13:20Z"
+        );
+    }
+
+    #[tokio::test]
+    async fn detect_format_returns_error_for_unrecognized_input() {
+        let server = TimeServer::new();
+        let params = DetectFormatParams {
+            input: "sometime soon".into(),
+        };
+        let result = server.detect_format(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Unrecognized timestamp format"));
+    }
+
+    #[tokio::test]
+    async fn validate_datetime_reports_canonical_form_and_diagnostics() {
+        let server = TimeServer::new();
+        let validate = async |input: &str, format: Option<&str>, timezone: Option<&str>| {
+            let params = ValidateDatetimeParams {
+                input: input.into(),
+                format: format.map(Into::into),
+                timezone: timezone.map(Into::into),
+            };
+            let result = server.validate_datetime(Parameters(params)).await.unwrap();
+            assert_eq!(result.is_error, Some(false));
+            serde_json::from_str::<serde_json::Value>(&extract_text(&result)).unwrap()
+        };
+
+        let json = validate(" 2025-07-04 08:00 ", None, Some("America/New_York")).await;
+        assert_eq!(json["valid"], true);
+        assert_eq!(json["input"], "2025-07-04 08:00");
+        assert_eq!(json["timezone"], "America/New_York");
+        assert_eq!(json["format"], "iso8601_local_datetime");
+        assert_eq!(
+            json["canonical"],
+            "2025-07-04T08:00:00-04:00[America/New_York]"
+        );
+        assert_eq!(json["utc"], "2025-07-04T12:00:00Z");
+
+        let json = validate("2025-13-01T10:00Z", None, None).await;
+        assert_eq!(json["valid"], false);
+        assert_eq!(json["diagnostics"][0]["field"], "month");
+        assert_eq!(
+            json["diagnostics"][0]["message"],
+            "Month 13 is out of range (01-12)."
+        );
+        assert!(json.get("canonical").is_none());
+
+        let json = validate("2025-07-04T08:00", Some("RFC3339"), None).await;
+        assert_eq!(json["valid"], false);
+        assert_eq!(json["format"], "iso8601_local_datetime");
+        assert_eq!(json["diagnostics"][1]["field"], "offset");
+    }
+
+    #[tokio::test]
+    async fn validate_datetime_rejects_unknown_formats_and_timezones() {
+        let server = TimeServer::new();
+        let params = ValidateDatetimeParams {
+            input: "2025-07-04".into(),
+            format: Some("yyyy-mm-dd".into()),
+            ..Default::default()
+        };
+        let result = server.validate_datetime(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(extract_text(&result).contains("invalid_format"));
+
+        let params = ValidateDatetimeParams {
+            input: "2025-07-04".into(),
+            timezone: Some("Mars/Olympus".into()),
+            ..Default::default()
+        };
+        let result = server.validate_datetime(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn normalize_epochs_flags_ambiguous_entries() {
+        let server = TimeServer::new();
+        let params = NormalizeEpochsParams {
+            timestamps: serde_json::from_str("[1700000000000, 5]").unwrap(),
+            timezone: Some("UTC".into()),
+        };
+        let result = server.normalize_epochs(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["timezone"], "UTC");
+        assert_eq!(json["results"][0]["unit"], "milliseconds");
+        assert_eq!(json["results"][0]["datetime"], "2023-11-14T22:13:20+00:00");
+        assert_eq!(json["results"][1]["ambiguous"], true);
+        assert_eq!(json["ambiguous_count"], 1);
+    }
+
+    #[tokio::test]
+    async fn normalize_epochs_returns_error_for_invalid_timezone() {
+        let server = TimeServer::new();
+        let params = NormalizeEpochsParams {
+            timestamps: serde_json::from_str("[1700000000]").unwrap(),
+            timezone: Some("Mars/Olympus".into()),
+        };
+        let result = server.normalize_epochs(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid timezone"));
+    }
+
+    fn convert_timestamp_params(timestamp: &str, timezone: Option<&str>) -> ConvertTimestampParams {
+        ConvertTimestampParams {
+            timestamp: timestamp.into(),
+            unit: None,
+            timezone: timezone.map(Into::into),
+        }
+    }
+
+    #[tokio::test]
+    async fn convert_timestamp_reads_epochs_in_inferred_or_given_units() {
+        let server = TimeServer::new();
+        let params = convert_timestamp_params("1751632440123", Some("America/New_York"));
+        let result = server.convert_timestamp(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["direction"], "epoch_to_datetime");
+        assert_eq!(json["unit"], "milliseconds");
+        assert_eq!(json["datetime"], "2025-07-04T08:34:00.123-04:00");
+        assert_eq!(json["utc"], "2025-07-04T12:34:00.123Z");
+        assert_eq!(json["unix_seconds"], 1751632440);
+        assert!(json.get("ambiguous").is_none());
+
+        let params = ConvertTimestampParams {
+            unit: Some(EpochUnit::Seconds),
+            ..convert_timestamp_params("86400", None)
+        };
+        let result = server.convert_timestamp(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["datetime"], "1970-01-02T00:00:00+00:00");
+        assert!(json.get("ambiguous").is_none());
+    }
+
+    #[tokio::test]
+    async fn convert_timestamp_turns_datetimes_into_epochs() {
+        let server = TimeServer::new();
+        let params = ConvertTimestampParams {
+            unit: Some(EpochUnit::Milliseconds),
+            ..convert_timestamp_params("2025-07-04T08:34:00", Some("America/New_York"))
+        };
+        let result = server.convert_timestamp(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["direction"], "datetime_to_epoch");
+        assert_eq!(json["unit"], "milliseconds");
+        assert_eq!(json["epoch"], 1751632440000_i64);
+        assert_eq!(json["unix_nanoseconds"], 1751632440000000000_i64);
+        assert_eq!(json["utc"], "2025-07-04T12:34:00Z");
+
+        let params = convert_timestamp_params("1969-12-31T23:59:59.5Z", None);
+        let result = server.convert_timestamp(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["epoch"], -1);
+        assert_eq!(json["unix_milliseconds"], -500);
+    }
+
+    #[tokio::test]
+    async fn convert_timestamp_handles_instants_outside_nanosecond_range() {
+        let server = TimeServer::new();
+        for input in ["1430-07-04", "0001-01-01T00:00:00Z"] {
+            let params = convert_timestamp_params(input, None);
+            let result = server.convert_timestamp(Parameters(params)).await.unwrap();
+            assert_eq!(result.is_error, Some(false), "{input}");
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert!(json["epoch"].as_i64().unwrap() < 0, "{input}");
+            assert!(json.get("unix_nanoseconds").is_none(), "{input}");
+        }
+        let json: serde_json::Value = serde_json::from_str(&extract_text(
+            &server
+                .convert_timestamp(Parameters(convert_timestamp_params("1430-07-04", None)))
+                .await
+                .unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(json["unix_seconds"], -17024860800_i64);
+
+        let params = ConvertTimestampParams {
+            unit: Some(EpochUnit::Nanoseconds),
+            ..convert_timestamp_params("1430-07-04", None)
+        };
+        let result = server.convert_timestamp(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_timestamp");
+        assert!(
+            json["message"]
+                .as_str()
+                .unwrap()
+                .contains("Use microseconds"),
+            "{json}"
+        );
+    }
+
+    #[tokio::test]
+    async fn convert_timestamp_rejects_unreadable_input() {
+        let server = TimeServer::new();
+        for input in ["yesterday-ish", "99999999999999999999"] {
+            let params = convert_timestamp_params(input, None);
+            let result = server.convert_timestamp(Parameters(params)).await.unwrap();
+            assert_eq!(result.is_error, Some(true), "{input}");
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["code"], "invalid_timestamp", "{input}");
+        }
+    }
+
+    #[tokio::test]
+    async fn group_by_local_day_counts_per_local_day() {
+        let server = TimeServer::new();
+        let params = GroupByLocalDayParams {
+            timestamps: vec![
+                "2025-03-09T04:00:00Z".into(),
+                "2025-03-09T12:00:00Z".into(),
+                "not a timestamp".into(),
+            ],
+            timezone: "America/New_York".into(),
+            ..Default::default()
+        };
+        let result = server.group_by_local_day(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["total"], 2);
+        assert_eq!(json["days"][0]["date"], "2025-03-08");
+        assert_eq!(json["days"][1]["date"], "2025-03-09");
+        assert_eq!(json["days"][1]["hours_in_day"], 23.0);
+        assert_eq!(json["invalid"][0]["index"], 2);
+    }
+
+    #[tokio::test]
+    async fn group_by_local_day_returns_error_for_invalid_timezone() {
+        let server = TimeServer::new();
+        let params = GroupByLocalDayParams {
+            timezone: "EST".into(),
+            ..Default::default()
+        };
+        let result = server.group_by_local_day(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn audit_dst_summarizes_findings() {
+        let server = TimeServer::new();
+        let params = AuditDstParams {
+            timestamps: vec![
+                "2025-03-30T02:30:00".into(),
+                "2025-10-26T02:30:00".into(),
+                "2025-07-01T12:00:00".into(),
+            ],
+            timezone: "Europe/Berlin".into(),
+        };
+        let result = server.audit_dst(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["total"], 3);
+        assert_eq!(json["ok_count"], 1);
+        assert_eq!(json["nonexistent_count"], 1);
+        assert_eq!(json["ambiguous_count"], 1);
+        assert_eq!(json["findings"][0]["issue"], "nonexistent");
+        assert_eq!(json["findings"][1]["issue"], "ambiguous");
+    }
+
+    #[tokio::test]
+    async fn serial_date_converts_serial_to_zoned_datetime() {
+        let server = TimeServer::new();
+        let params = SerialDateParams {
+            serial: Some(45842.75),
+            timezone: Some("Europe/London".into()),
+            system: SerialDateSystem::Excel1900,
+            ..Default::default()
+        };
+        let result = server.serial_date(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["system"], "excel_1900");
+        assert_eq!(json["datetime"], "2025-07-04T18:00:00+01:00");
+        assert!(json.get("notes").is_none());
+    }
+
+    #[tokio::test]
+    async fn serial_date_converts_datetime_to_serial() {
+        let server = TimeServer::new();
+        let params = SerialDateParams {
+            datetime: Some("2025-07-04T16:00:00Z".into()),
+            timezone: Some("America/New_York".into()),
+            system: SerialDateSystem::Sheets,
+            ..Default::default()
+        };
+        let result = server.serial_date(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["serial"], 45842.5);
+        assert_eq!(json["datetime"], "2025-07-04T12:00:00-04:00");
+    }
+
+    #[tokio::test]
+    async fn serial_date_requires_exactly_one_input() {
+        let server = TimeServer::new();
+        let params = SerialDateParams {
+            serial: Some(1.0),
+            datetime: Some("2025-07-04".into()),
+            ..Default::default()
+        };
+        let result = server.serial_date(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn sql_literal_renders_all_dialects_by_default() {
+        let server = TimeServer::new();
+        let params = SqlLiteralParams {
+            datetime: "2025-07-04T10:00:00Z".into(),
+            timezone: Some("Europe/Paris".into()),
+            dialect: None,
+            with_timezone: true,
+        };
+        let result = server.sql_literal(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["datetime"], "2025-07-04T12:00:00+02:00");
+        assert_eq!(json["literals"].as_array().unwrap().len(), 4);
+        assert_eq!(json["literals"][0]["dialect"], "postgresql");
+        assert_eq!(
+            json["literals"][0]["literal"],
+            "TIMESTAMPTZ '2025-07-04 12:00:00+02:00'"
+        );
+    }
+
+    #[tokio::test]
+    async fn sql_literal_returns_error_for_invalid_datetime() {
+        let server = TimeServer::new();
+        let params = SqlLiteralParams {
+            datetime: "04/07/2025".into(),
+            timezone: None,
+            dialect: Some(SqlDialect::Sqlite),
+            with_timezone: false,
+        };
+        let result = server.sql_literal(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid datetime"));
+    }
+
+    #[tokio::test]
+    async fn parse_ics_renders_events_in_timezone() {
+        let server = TimeServer::new();
+        let params = ParseIcsParams {
+            ics: "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nSUMMARY:Launch\r\nDTSTART;TZID=America/Los_Angeles:20250301T090000\r\nDTEND;TZID=America/Los_Angeles:20250301T100000\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n".into(),
+            timezone: Some("Asia/Kolkata".into()),
+        };
+        let result = server.parse_ics(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["events"][0]["summary"], "Launch");
+        assert_eq!(json["events"][0]["start"], "2025-03-01T22:30:00+05:30");
+        assert_eq!(json["events"][0]["source_timezone"], "America/Los_Angeles");
+        assert!(json.get("invalid").is_none());
+    }
+
+    #[tokio::test]
+    async fn parse_ics_rejects_text_without_a_calendar() {
+        let server = TimeServer::new();
+        let params = ParseIcsParams {
+            ics: "SUMMARY:Not a calendar".into(),
+            ..Default::default()
+        };
+        let result = server.parse_ics(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_ics");
+    }
+
+    #[tokio::test]
+    async fn parse_duration_returns_structured_duration() {
+        let server = TimeServer::new();
+        let params = ParseDurationParams {
+            input: "an hour and a half".into(),
+            ..Default::default()
+        };
+        let result = server.parse_duration(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["iso8601"], "PT1H30M");
+        assert_eq!(json["description"], "1h 30m");
+        assert_eq!(json["total_seconds"], 5400.0);
+        assert!(json.get("resolved").is_none());
+    }
+
+    #[tokio::test]
+    async fn parse_duration_resolves_anchored_phrases() {
+        let server = TimeServer::new();
+        let params = |input: &str| ParseDurationParams {
+            input: input.into(),
+            timezone: Some("America/New_York".into()),
+            // A Wednesday.
+            reference: Some("2025-07-02T15:00".into()),
+        };
+        let result = server
+            .parse_duration(Parameters(params("two weeks from Friday")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["resolved"]["from"], "2025-07-04T00:00:00-04:00");
+        assert_eq!(json["resolved"]["to"], "2025-07-18T00:00:00-04:00");
+
+        let result = server
+            .parse_duration(Parameters(params("3 business days from now")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["business_days"], 3);
+        assert_eq!(json["resolved"]["to"], "2025-07-07T15:00:00-04:00");
+
+        // Without a starting point, business days count from the reference.
+        let result = server
+            .parse_duration(Parameters(params("3 business days")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["business_days"], 3);
+        assert_eq!(json["iso8601"], "P5D");
+        assert_eq!(json["description"], "5d");
+        assert_eq!(json["resolved"]["from"], "2025-07-02T15:00:00-04:00");
+        assert_eq!(json["resolved"]["to"], "2025-07-07T15:00:00-04:00");
+        assert!(json.get("total_seconds").is_none());
+    }
+
+    #[tokio::test]
+    async fn parse_duration_rejects_unknown_words() {
+        let server = TimeServer::new();
+        let params = ParseDurationParams {
+            input: "three fortnights".into(),
+            ..Default::default()
+        };
+        let result = server.parse_duration(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_duration");
+        assert_eq!(json["offending_value"], "three fortnights");
+    }
+
+    #[tokio::test]
+    async fn format_duration_converts_between_forms() {
+        let server = TimeServer::new();
+        let format = async |input: &str, unit, largest_unit, smallest_unit| {
+            let params = FormatDurationParams {
+                input: input.into(),
+                unit,
+                largest_unit,
+                smallest_unit,
+            };
+            let result = server.format_duration(Parameters(params)).await.unwrap();
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            (result.is_error, json)
+        };
+
+        let (_, json) = format("5400s", None, None, None).await;
+        assert_eq!(json["iso8601"], "PT1H30M");
+        assert_eq!(json["human"], "1 hour 30 minutes");
+        assert_eq!(json["compact"], "1h 30m");
+        assert_eq!(json["total_seconds"], 5400.0);
+        assert_eq!(json["total_milliseconds"], 5_400_000.0);
+
+        let (_, json) = format("PT36H", None, None, None).await;
+        assert_eq!(json["human"], "1 day 12 hours");
+        let (_, json) = format("1.5h", None, Some(SpanUnit::Minute), None).await;
+        assert_eq!(json["iso8601"], "PT90M");
+        let (_, json) = format("90061500", Some(EpochUnit::Milliseconds), None, None).await;
+        assert_eq!(json["iso8601"], "P1DT1H1M1.5S");
+        let (_, json) = format(
+            "90061500",
+            Some(EpochUnit::Milliseconds),
+            None,
+            Some(SpanUnit::Minute),
+        )
+        .await;
+        assert_eq!(json["human"], "1 day 1 hour 1 minute");
+        let (_, json) = format("2 hours ago", None, None, None).await;
+        assert_eq!(json["iso8601"], "-PT2H");
+        assert_eq!(json["human"], "2 hours ago");
+        assert_eq!(json["total_seconds"], -7200.0);
+
+        for input in ["3 months", "3 business days", "2 hours from Friday", "soon"] {
+            let (is_error, json) = format(input, None, None, None).await;
+            assert_eq!(is_error, Some(true), "{input}");
+            assert_eq!(json["code"], "invalid_duration");
+        }
+        let (is_error, _) = format("1e30", None, None, None).await;
+        assert_eq!(is_error, Some(true));
+    }
+}
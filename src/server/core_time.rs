@@ -0,0 +1,3634 @@
+use rmcp::{handler::server::wrapper::Parameters, model::CallToolResult, tool, tool_router};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::core::{Conversion, ConvertedTime, CurrentTime, LocalTime, format_offset_diff};
+
+/// Most target timezones `convert_time_multi` converts to in one call.
+const MAX_CONVERSION_TARGETS: usize = 100;
+
+/// Parameters for the `get_current_time` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct GetCurrentTimeParams {
+    /// IANA timezone name (e.g., 'America/New_York', 'Europe/London', 'Asia/Tokyo'), or a comma-separated list of them (e.g., 'UTC, Asia/Tokyo'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Also render the datetime in `formatted`: 'rfc3339', 'rfc2822', 'unix' (epoch seconds), 'human' (e.g., 'Friday, 4 July 2025 at 12:00 PM EDT'), or a strftime string (e.g., '%d/%m/%Y %H:%M'). `datetime` stays RFC 3339.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Also write the datetime out for a locale in `human_readable` (e.g., 'de-DE' gives 'Dienstag, 5. März 2025, 14:30'; 'en-US' and 'en-GB' differ in date order and 12/24-hour clock). Append '-u-hc-h12' or '-u-hc-h23' to choose the clock.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Parameters for the `get_world_clock` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct GetWorldClockParams {
+    /// IANA timezone names to show (e.g., ['America/New_York', 'Asia/Tokyo']).
+    #[serde(default)]
+    #[schemars(transform = schema::timezones)]
+    pub timezones: Vec<String>,
+    /// Preset group of zones to show: 'major_financial_centers', 'us', 'americas', 'europe', or 'asia_pacific'. Combined with `timezones` when both are given. Without either, the server's world clock zones are shown.
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Also render each datetime in `formatted`, as for `get_current_time`.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Also write each datetime out for a locale in `human_readable`, as for `get_current_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Parameters for the `convert_time` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ConvertTimeParams {
+    /// Source IANA timezone name (e.g., 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub source_timezone: String,
+    /// Time to convert, in 24-hour format (HH:MM) or 12-hour format with AM/PM (e.g., '2:30 PM', '2pm')
+    #[schemars(transform = schema::clock_time)]
+    pub time: String,
+    /// Target IANA timezone name (e.g., 'Europe/London')
+    #[schemars(transform = schema::timezone)]
+    pub target_timezone: String,
+    /// Date of the time in the source timezone, as YYYY-MM-DD (e.g., '2025-03-10'). Defaults to today in the source timezone. Past dates use the rules in effect then (e.g., '1985-04-01').
+    #[serde(default)]
+    #[schemars(transform = schema::date)]
+    pub date: Option<String>,
+    /// Also accept fixed UTC offsets (e.g., '+03:00', 'UTC+3') as the source or target timezone for this call. Fixed offsets do not follow daylight saving time.
+    #[serde(default)]
+    pub allow_offsets: bool,
+    /// Also render the datetime in `formatted`: 'rfc3339', 'rfc2822', 'unix' (epoch seconds), 'human' (e.g., 'Friday, 4 July 2025 at 12:00 PM EDT'), or a strftime string (e.g., '%d/%m/%Y %H:%M'). `datetime` stays RFC 3339.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Also write both datetimes out for a locale in `human_readable` (e.g., 'de-DE', 'en-US', 'ja'), as for `get_current_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Which instant a time that happens twice, when clocks go back, stands for: 'earlier' (default, the first instance), 'later' (the second), or 'reject' to fail with an `ambiguous_local_time` error.
+    #[serde(default)]
+    pub ambiguity: Ambiguity,
+    /// How to read a time skipped when clocks go forward: 'reject' (default) to fail with a `nonexistent_local_time` error, or 'next_valid' to move it forward by the length of the gap (e.g., 02:30 becomes 03:30).
+    #[serde(default)]
+    pub gap: Gap,
+}
+
+/// Parameters for the `convert_time_multi` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ConvertTimeMultiParams {
+    /// Source IANA timezone name (e.g., 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub source_timezone: String,
+    /// Time to convert, in 24-hour format (HH:MM) or 12-hour format with AM/PM (e.g., '2:30 PM', '2pm')
+    #[schemars(transform = schema::clock_time)]
+    pub time: String,
+    /// Target IANA timezone names, up to 100 (e.g., ['Europe/London', 'Asia/Tokyo'])
+    #[schemars(transform = schema::timezones)]
+    pub target_timezones: Vec<String>,
+    /// Date of the time in the source timezone, as YYYY-MM-DD (e.g., '2025-03-10'). Defaults to today in the source timezone.
+    #[serde(default)]
+    #[schemars(transform = schema::date)]
+    pub date: Option<String>,
+    /// Also accept fixed UTC offsets (e.g., '+03:00', 'UTC+3') as the source or a target timezone for this call.
+    #[serde(default)]
+    pub allow_offsets: bool,
+    /// Also render each datetime in `formatted`, as for `convert_time`.
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Also write each datetime out for a locale in `human_readable`, as for `convert_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// How to read a time that happens twice when clocks go back, as for `convert_time`.
+    #[serde(default)]
+    pub ambiguity: Ambiguity,
+    /// How to read a time skipped when clocks go forward, as for `convert_time`.
+    #[serde(default)]
+    pub gap: Gap,
+}
+
+/// One event in a `convert_agenda` request.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AgendaEvent {
+    /// Event title (e.g., 'Opening keynote')
+    pub title: String,
+    /// Start time: a local datetime in the event's timezone (e.g., '2025-09-16T09:00') or RFC 3339 (e.g., '2025-09-16T07:00:00Z')
+    pub start: String,
+    /// End time, in the same formats as `start`
+    pub end: String,
+    /// IANA timezone the event is scheduled in. Defaults to the agenda's source_timezone.
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `convert_agenda` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ConvertAgendaParams {
+    /// Events to convert.
+    pub events: Vec<AgendaEvent>,
+    /// IANA timezone for events that do not name their own (e.g., 'Europe/Lisbon'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub source_timezone: Option<String>,
+    /// IANA timezones to render the agenda in (e.g., ['America/New_York', 'Asia/Tokyo']).
+    #[schemars(transform = schema::timezones)]
+    pub target_timezones: Vec<String>,
+}
+
+/// Parameters for the `evaluate` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct EvaluateParams {
+    /// Time expression, e.g. 'now + 3 days - 2 hours in Asia/Tokyo', '2025-07-01T09:00 Europe/Paris to America/Chicago + 45m', or '2025-07-04T17:00 America/New_York - 2025-07-04T09:00 Europe/London'
+    pub expression: String,
+    /// IANA timezone for 'now'/'today' and datetimes written without a zone. Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Datetime to treat as now, RFC 3339 or a local datetime (e.g., '2025-07-04T09:00'). Defaults to the current time.
+    #[serde(default)]
+    pub reference: Option<String>,
+}
+
+/// Parameters for the `explain_time` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ExplainTimeParams {
+    /// Timestamp to explain: RFC 3339 or RFC 9557 (e.g., '2025-07-04T16:00:00Z'), a local datetime or date (e.g., '2025-07-04T12:00'), RFC 2822 (e.g., 'Fri, 4 Jul 2025 12:00:00 -0400'), or a Unix epoch number in seconds, milliseconds, microseconds, or nanoseconds
+    pub timestamp: String,
+    /// IANA timezone to explain the timestamp in, also used for local datetimes without an offset. Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Datetime the relative description is measured from, in the same formats as `timestamp`. Defaults to the current time.
+    #[serde(default)]
+    pub reference: Option<String>,
+}
+
+/// Parameters for the `parse_time` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ParseTimeParams {
+    /// Phrase naming a point in time, e.g. 'tomorrow at 3pm', 'next Friday 09:00', 'in 2 hours', '3 days ago', or '2 days after next Monday noon'. Plain datetimes (e.g., '2025-07-04T09:00') are accepted too.
+    pub text: String,
+    /// IANA timezone the phrase is read in: 'today', '3pm', and local datetimes are in this zone. Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Datetime to treat as now, RFC 3339 or a local datetime (e.g., '2025-07-04T09:00'). Defaults to the current time.
+    #[serde(default)]
+    pub reference: Option<String>,
+}
+
+/// Parameters for the `add_time` and `subtract_time` tools.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct TimeArithmeticParams {
+    /// 'now', or the datetime to start from: RFC 3339 (e.g., '2025-03-08T09:00:00-05:00') or a local datetime or date in `timezone` (e.g., '2025-03-08T09:00')
+    pub datetime: String,
+    /// IANA timezone the result is given in and local datetimes are read in. Calendar units (days, weeks, months, years) follow its calendar. Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Duration, as a phrase ('3 days 4 hours', '1h30m', '2 business days') or ISO 8601 ('P3DT4H')
+    pub duration: String,
+    /// Also write the result out for a locale in `human_readable` (e.g., 'de-DE', 'en-US', 'ja'), as for `get_current_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Parameters for the `time_difference` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct TimeDifferenceParams {
+    /// Start datetime: RFC 3339 (e.g., '2025-03-01T09:00:00-05:00'), RFC 9557 with a zone (e.g., '2025-03-01T09:00[America/New_York]'), or a local datetime or date in `timezone`
+    pub start: String,
+    /// End datetime, in the same formats as `start`
+    pub end: String,
+    /// IANA timezone for local datetimes and for counting calendar days and months. Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `time_until` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct TimeUntilParams {
+    /// Deadline to count down to: RFC 3339 (e.g., '2026-03-31T17:00:00-07:00'), RFC 9557 with a zone (e.g., '2026-03-31T17:00[America/Los_Angeles]'), or a local datetime or date in `timezone` (e.g., '2026-03-31')
+    pub target: String,
+    /// IANA timezone for local targets and for counting days. Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// For a date without a time: 'end_of_day' counts to the end of the date (midnight at the start of the next day), 'start_of_day' to its start. Defaults to 'end_of_day'.
+    #[serde(default)]
+    pub date_only: DayBoundary,
+    /// Also write the deadline out for a locale in `human_readable` (e.g., 'de-DE', 'en-US', 'ja'), as for `get_current_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Which end of a date a date-only `time_until` target stands for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DayBoundary {
+    /// The first instant of the date.
+    StartOfDay,
+    /// The end of the date: the first instant of the next day.
+    #[default]
+    EndOfDay,
+}
+
+/// Parameters for the `humanize_time` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct HumanizeTimeParams {
+    /// Datetime to describe: RFC 3339 (e.g., '2025-07-04T12:00:00Z'), a local datetime or date in `timezone` (e.g., '2025-07-04T08:00'), or a Unix epoch number in seconds or milliseconds (e.g., '1751632440')
+    pub datetime: String,
+    /// Datetime to describe it from, in the same forms. Defaults to now.
+    #[serde(default)]
+    pub reference: Option<String>,
+    /// IANA timezone for local values and for counting days and months (e.g., 'America/New_York'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Smallest unit the phrase counts in: 'year', 'month', 'day', 'hour', 'minute', or 'second'. Defaults to 'second'.
+    #[serde(default)]
+    pub granularity: PhraseUnit,
+    /// Most units in the phrase, from the largest non-zero one down, the last rounded half up (e.g., 1 gives '3 hours ago', 2 gives '3 hours 20 minutes ago'). Defaults to 1.
+    #[serde(default)]
+    #[schemars(range(min = 1, max = PhraseUnit::UNITS.len()))]
+    pub max_units: Option<u8>,
+}
+
+/// Parameters for the `travel_arrival` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct TravelArrivalParams {
+    /// Departure time: a local datetime in departure_timezone (e.g., '2025-07-04T22:15'), or RFC 3339 (e.g., '2025-07-05T02:15:00Z')
+    pub departure: String,
+    /// IANA timezone the flight departs from (e.g., 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub departure_timezone: String,
+    /// Flight time as elapsed hours and minutes, as a phrase ('7h 25m', '13 hours 5 minutes') or ISO 8601 ('PT7H25M')
+    pub duration: String,
+    /// IANA timezone the flight arrives in (e.g., 'Europe/London')
+    #[schemars(transform = schema::timezone)]
+    pub arrival_timezone: String,
+}
+
+/// Smallest unit a `humanize_time` phrase counts in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PhraseUnit {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    #[default]
+    Second,
+}
+
+impl PhraseUnit {
+    /// Units a phrase can count in, largest first.
+    const UNITS: [jiff::Unit; 6] = [
+        jiff::Unit::Year,
+        jiff::Unit::Month,
+        jiff::Unit::Day,
+        jiff::Unit::Hour,
+        jiff::Unit::Minute,
+        jiff::Unit::Second,
+    ];
+
+    /// Position of this unit in [`PhraseUnit::UNITS`].
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Response payload for `get_current_time`: one zone's time, or a `times`
+/// list when several zones are asked for.
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(untagged)]
+#[schemars(extend("type" = "object"))]
+enum CurrentTimeResponse {
+    Single(CurrentTime),
+    List {
+        /// One entry per zone, in the order given, all at the same instant.
+        times: Vec<CurrentTime>,
+    },
+}
+
+/// Response payload for `get_world_clock`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct WorldClockResponse {
+    /// The instant every zone is shown at, in UTC.
+    as_of: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+    /// One entry per zone, west to east by current UTC offset.
+    zones: Vec<CurrentTime>,
+}
+
+/// One target zone in the `convert_time_multi` response.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ConversionTarget {
+    #[serde(flatten)]
+    time: ConvertedTime,
+    /// Target UTC offset minus the source's (e.g., "+5:30").
+    time_difference: String,
+    /// Days between the source date and the target date (e.g., 1 when a
+    /// Monday evening in New York is Tuesday in Tokyo).
+    day_shift: i32,
+}
+
+/// Response payload for `convert_time_multi`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ConvertTimeMultiResponse {
+    source: ConvertedTime,
+    /// One entry per target timezone, in the order requested.
+    targets: Vec<ConversionTarget>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    was_ambiguous: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    was_skipped: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chosen_offset: Option<String>,
+    /// Present when any side is a fixed offset, whose conversion ignores DST.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tzdb_version: Option<String>,
+}
+
+/// One event as seen from a target zone in the convert_agenda response.
+#[derive(Debug, Serialize, JsonSchema)]
+struct AgendaEntry {
+    title: String,
+    start: String,
+    end: String,
+    /// Days between the event's start date in its own timezone and in the
+    /// target zone (e.g., 1 when a Monday evening session lands on Tuesday).
+    day_shift: i32,
+    /// Set when the event runs past local midnight in the target zone.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    crosses_midnight: bool,
+}
+
+/// The agenda rendered in one target zone.
+#[derive(Debug, Serialize, JsonSchema)]
+struct AgendaSchedule {
+    timezone: String,
+    /// Zone name in the configured language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    /// Number of events whose start date differs from their source date.
+    different_day_count: usize,
+    events: Vec<AgendaEntry>,
+}
+
+/// Response payload for `convert_agenda`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ConvertAgendaResponse {
+    schedules: Vec<AgendaSchedule>,
+    /// IANA Time Zone Database release that gave the agenda's offsets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tzdb_version: Option<&'static str>,
+}
+
+/// The value an expression evaluates to.
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum EvaluationResult {
+    Instant {
+        datetime: String,
+        timezone: String,
+        utc: String,
+        unix_seconds: i64,
+    },
+    /// Time between two datetimes: the running value minus the operand.
+    Duration {
+        iso8601: String,
+        description: String,
+        total_seconds: f64,
+    },
+}
+
+/// One applied step of an expression, for showing the working.
+#[derive(Debug, Serialize, JsonSchema)]
+struct EvaluationStep {
+    /// `start`, `add`, `subtract`, `convert`, or `difference`.
+    operation: &'static str,
+    operand: String,
+    datetime: String,
+}
+
+/// Response payload for `evaluate`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct EvaluateResponse {
+    expression: String,
+    result: EvaluationResult,
+    steps: Vec<EvaluationStep>,
+}
+
+/// Response payload for `add_time` and `subtract_time`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct TimeArithmeticResponse {
+    start: String,
+    /// ISO 8601 duration of the calendar and clock units (e.g., "P3DT4H").
+    duration: String,
+    #[serde(skip_serializing_if = "is_zero")]
+    business_days: i64,
+    result: String,
+    /// The result written out for the requested `locale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    human_readable: Option<String>,
+    timezone: String,
+    utc: String,
+    /// Present when the calendar units land on a wall-clock time that DST
+    /// skips or repeats, explaining how it was resolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// How the wall-clock time that the calendar units of `duration` reach from
+/// `start` resolves, when DST skips or repeats it.
+///
+/// Calendar units are added to the local date and time before clock units
+/// are added as elapsed time, so only that intermediate time can be
+/// affected.
+fn dst_note(start: &jiff::Zoned, duration: &duration::Duration) -> Option<String> {
+    let span = duration.span;
+    let calendar = jiff::Span::new()
+        .years(span.get_years())
+        .months(span.get_months())
+        .weeks(span.get_weeks())
+        .days(span.get_days());
+    if calendar.is_zero() {
+        return None;
+    }
+    let calendar = if duration.backward {
+        calendar.negate()
+    } else {
+        calendar
+    };
+    let local = start.datetime().checked_add(calendar).ok()?;
+    let subject = format!(
+        "The calendar units land on {}, which",
+        local.strftime("%Y-%m-%dT%H:%M")
+    );
+    wall_clock_note(&subject, local, start.time_zone())
+}
+
+/// How `local` resolves in `tz` when DST skips or repeats it, as a sentence
+/// about `subject`.
+fn wall_clock_note(
+    subject: &str,
+    local: jiff::civil::DateTime,
+    tz: &jiff::tz::TimeZone,
+) -> Option<String> {
+    let zone = tz.iana_name().unwrap_or("UTC");
+    match tz.to_ambiguous_zoned(local).offset() {
+        jiff::tz::AmbiguousOffset::Unambiguous { .. } => None,
+        jiff::tz::AmbiguousOffset::Gap { before, after } => Some(format!(
+            "{subject} does not exist in {zone} (clocks jump from {} to {}); it was moved forward by the length of the gap.",
+            format_utc_offset(before),
+            format_utc_offset(after)
+        )),
+        jiff::tz::AmbiguousOffset::Fold { before, after } => Some(format!(
+            "{subject} occurs twice in {zone} (at {} and {}); the earlier one was used.",
+            format_utc_offset(before),
+            format_utc_offset(after)
+        )),
+    }
+}
+
+/// Elapsed time split into calendar and clock units, all with the same sign.
+#[derive(Debug, Serialize, JsonSchema)]
+struct DifferenceBreakdown {
+    years: i16,
+    months: i32,
+    days: i32,
+    hours: i32,
+    minutes: i64,
+    seconds: i64,
+}
+
+/// Response payload for `time_difference`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct TimeDifferenceResponse {
+    start: String,
+    end: String,
+    timezone: String,
+    breakdown: DifferenceBreakdown,
+    /// ISO 8601 duration of the breakdown (e.g., "P1M2DT3H").
+    iso8601: String,
+    /// Unsigned, friendly form of the breakdown (e.g., "1mo 2d 3h").
+    description: String,
+    /// Elapsed seconds, negative when `end` is before `start`.
+    total_seconds: f64,
+    /// Set when `end` is before `start`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    negative: bool,
+}
+
+/// Response payload for `time_until`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct TimeUntilResponse {
+    target: String,
+    /// The target written out for the requested `locale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    human_readable: Option<String>,
+    timezone: String,
+    now: String,
+    /// Time left, or time since the deadline once it has passed, in words
+    /// (e.g., "3 days, 4 hours, 12 minutes").
+    remaining: String,
+    /// Seconds left, negative once the deadline has passed.
+    total_seconds: i64,
+    passed: bool,
+    /// Set when the target was a date without a time of day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Whether a humanized datetime is before or after its reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum Direction {
+    Past,
+    Now,
+    Future,
+}
+
+/// Response payload for `humanize_time`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct HumanizeTimeResponse {
+    datetime: String,
+    reference: String,
+    timezone: String,
+    /// The difference in words (e.g., "3 hours ago", "in 2 days", "now").
+    phrase: String,
+    /// Direction of the phrase, after rounding.
+    direction: Direction,
+    /// Exact difference from the reference as an ISO 8601 duration,
+    /// negative for past datetimes (e.g., "-PT3H20M15S").
+    delta: String,
+    /// Exact difference in seconds, negative for past datetimes.
+    total_seconds: f64,
+}
+
+/// Response payload for `travel_arrival`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct TravelArrivalResponse {
+    departure: ConvertedTime,
+    arrival: ConvertedTime,
+    /// Flight time as an ISO 8601 duration (e.g., "PT7H25M").
+    duration: String,
+    /// Flight time in words (e.g., "7 hours 25 minutes").
+    description: String,
+    elapsed_seconds: f64,
+    /// Arrival UTC offset minus the departure's (e.g., "+5:00").
+    time_difference: String,
+    /// How far the local clock reading moves from departure to arrival:
+    /// the flight time plus the change of offset, as an ISO 8601 duration,
+    /// negative when the flight lands at an earlier local time (e.g.,
+    /// "PT12H25M").
+    wall_clock_difference: String,
+    /// Days between the local departure and arrival dates (e.g., 1 when an
+    /// evening departure lands the next morning).
+    day_change: i32,
+    /// The day change as printed on itineraries (e.g., "+1 day"), absent
+    /// when the flight lands on its departure date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day_change_label: Option<String>,
+    summary: String,
+    /// Present when the local departure time is skipped or repeated by a
+    /// DST change, explaining how it was read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Calendar and clock fields of an explained timestamp.
+#[derive(Debug, Serialize, JsonSchema)]
+struct TimeComponents {
+    year: i16,
+    month: i8,
+    day: i8,
+    hour: i8,
+    minute: i8,
+    second: i8,
+    nanosecond: i32,
+    /// Day of the year, 1 for January 1.
+    day_of_year: i16,
+}
+
+/// A change of offset near an explained timestamp.
+#[derive(Debug, Serialize, JsonSchema)]
+struct NearbyTransition {
+    /// Instant of the change, in the offset that takes effect.
+    datetime: String,
+    utc_offset_before: String,
+    utc_offset_after: String,
+    abbreviation: String,
+    is_dst: bool,
+}
+
+/// Response payload for `explain_time`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ExplainTimeResponse {
+    input: String,
+    /// How the input was read (e.g., `rfc3339`, `local_datetime`, `epoch_milliseconds`).
+    interpreted_as: String,
+    timezone: String,
+    /// Zone name in the configured language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    datetime: String,
+    components: TimeComponents,
+    weekday: String,
+    /// ISO 8601 week date (e.g., "2025-W27-5").
+    iso_week: String,
+    utc: String,
+    unix_seconds: i64,
+    unix_milliseconds: i64,
+    utc_offset: String,
+    abbreviation: String,
+    is_dst: bool,
+    /// Distance from the reference time (e.g., "in 3d 4h", "2h 30m ago").
+    relative: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_transition: Option<NearbyTransition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_transition: Option<NearbyTransition>,
+    /// Present when the input was read with a guessed epoch unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+/// Response payload for `parse_time`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ParseTimeResponse {
+    input: String,
+    datetime: String,
+    timezone: String,
+    utc: String,
+    weekday: String,
+    /// The time the phrase was read relative to.
+    reference: String,
+    /// Distance from the reference time (e.g., "in 1d 6h", "3d ago").
+    relative: String,
+    /// Present when the phrase lands on a wall-clock time that DST skips or
+    /// repeats, explaining how it was resolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Read any timestamp format `explain_time` accepts, resolving values
+/// without an offset in `tz`.
+///
+/// Returns the instant, a name for the format it was read in, and a warning
+/// when an epoch unit had to be guessed.
+fn parse_any_timestamp(
+    input: &str,
+    tz: &jiff::tz::TimeZone,
+) -> Result<(jiff::Zoned, String, Option<String>), ToolError> {
+    if let Some(value) = EpochValue::parse(input) {
+        let inference = value.infer_unit();
+        let ts = value
+            .to_timestamp(inference.unit)
+            .map_err(|msg| ToolError::new("invalid_datetime", msg).with_value(input))?;
+        let warning = inference.ambiguous.then(|| {
+            format!(
+                "No epoch unit places {input} between 1971 and 2100; it was read as {} from its digit count.",
+                inference.unit.as_str()
+            )
+        });
+        let format = format!("epoch_{}", inference.unit.as_str());
+        return Ok((ts.to_zoned(tz.clone()), format, warning));
+    }
+    if let Ok(zdt) = jiff::fmt::rfc2822::parse(input) {
+        return Ok((zdt.with_time_zone(tz.clone()), "rfc2822".into(), None));
+    }
+    let format = if input.parse::<jiff::Zoned>().is_ok() {
+        "rfc9557"
+    } else if input.parse::<jiff::Timestamp>().is_ok() {
+        "rfc3339"
+    } else if input.contains(':') || input.contains('T') {
+        "local_datetime"
+    } else {
+        "local_date"
+    };
+    Ok((parse_datetime(input, tz)?, format.into(), None))
+}
+
+/// The instant `text` names, read with [`natural::parse`] relative to `now`
+/// in `tz`, or as a plain datetime when it is not a phrase.
+///
+/// Also returns a note when a wall-clock time DST skips or repeats had to
+/// be resolved.
+fn resolve_phrase(
+    text: &str,
+    now: &jiff::Zoned,
+    tz: &jiff::tz::TimeZone,
+) -> Result<(jiff::Zoned, Option<String>), ToolError> {
+    let phrase = match natural::parse(text) {
+        Ok(phrase) => phrase,
+        Err(msg) => {
+            return parse_datetime(text, tz)
+                .map(|zdt| (zdt, None))
+                .map_err(|_| ToolError::new("invalid_time_expression", msg).with_value(text));
+        }
+    };
+    let out_of_range = |e: jiff::Error| ToolError::from(format!("'{text}' is out of range: {e}"));
+    match phrase {
+        Phrase::Now => Ok((now.clone(), None)),
+        Phrase::Point { day, time } => {
+            let date = match day {
+                Some(day) => day.resolve(now.date()).map_err(out_of_range)?,
+                None => now.date(),
+            };
+            let Some(time) = time else {
+                let zdt = date
+                    .to_zoned(tz.clone())
+                    .and_then(|zdt| zdt.start_of_day())
+                    .map_err(out_of_range)?;
+                return Ok((zdt, None));
+            };
+            let local = date.to_datetime(time);
+            let note = wall_clock_note(&local.strftime("%Y-%m-%dT%H:%M").to_string(), local, tz);
+            let zdt = local.to_zoned(tz.clone()).map_err(out_of_range)?;
+            Ok((zdt, note))
+        }
+        Phrase::Offset(duration) => {
+            let (start, start_note) = match &duration.anchor {
+                Some(Anchor::Datetime(start)) => resolve_phrase(start, now, tz)?,
+                Some(anchor) => (resolve_anchor(anchor, now, tz)?, None),
+                None => (now.clone(), None),
+            };
+            let zdt = duration.apply(&start)?;
+            Ok((zdt, dst_note(&start, &duration).or(start_note)))
+        }
+    }
+}
+
+/// `transition` as seen from `tz`, with the offset it replaces.
+fn nearby_transition(
+    tz: &jiff::tz::TimeZone,
+    transition: &jiff::tz::TimeZoneTransition,
+) -> NearbyTransition {
+    let before = transition
+        .timestamp()
+        .checked_sub(jiff::SignedDuration::from_secs(1))
+        .map_or(transition.offset(), |ts| tz.to_offset(ts));
+    NearbyTransition {
+        datetime: transition
+            .timestamp()
+            .to_zoned(tz.clone())
+            .strftime("%Y-%m-%dT%H:%M:%S%:z")
+            .to_string(),
+        utc_offset_before: format_utc_offset(before),
+        utc_offset_after: format_utc_offset(transition.offset()),
+        abbreviation: transition.abbreviation().to_string(),
+        is_dst: transition.dst().is_dst(),
+    }
+}
+
+/// How far `zdt` is from `reference`, in calendar units of the zone.
+fn relative_description(zdt: &jiff::Zoned, reference: &jiff::Zoned) -> Result<String, jiff::Error> {
+    let span = reference.until(
+        jiff::ZonedDifference::new(zdt)
+            .largest(jiff::Unit::Year)
+            .smallest(jiff::Unit::Second),
+    )?;
+    Ok(match span.signum() {
+        0 => "now".into(),
+        1 => format!("in {:#}", span),
+        _ => format!("{:#} ago", span.abs()),
+    })
+}
+
+/// The value of one calendar or clock `unit` of `span`.
+fn span_field(span: &jiff::Span, unit: jiff::Unit) -> i64 {
+    match unit {
+        jiff::Unit::Year => span.get_years().into(),
+        jiff::Unit::Month => span.get_months().into(),
+        jiff::Unit::Week => span.get_weeks().into(),
+        jiff::Unit::Day => span.get_days().into(),
+        jiff::Unit::Hour => span.get_hours().into(),
+        jiff::Unit::Minute => span.get_minutes(),
+        jiff::Unit::Second => span.get_seconds(),
+        jiff::Unit::Millisecond => span.get_milliseconds(),
+        jiff::Unit::Microsecond => span.get_microseconds(),
+        jiff::Unit::Nanosecond => span.get_nanoseconds(),
+    }
+}
+
+/// `span` in words, from its largest non-zero unit down to seconds
+/// (e.g., "3 days, 4 hours, 12 minutes").
+fn countdown_description(span: &jiff::Span) -> String {
+    let span = span.abs();
+    let units = [
+        (i64::from(span.get_days()), "day"),
+        (i64::from(span.get_hours()), "hour"),
+        (span.get_minutes(), "minute"),
+        (span.get_seconds(), "second"),
+    ];
+    let parts: Vec<String> = units
+        .iter()
+        .filter(|(count, _)| *count != 0)
+        .map(|(count, unit)| format!("{count} {unit}{}", if *count == 1 { "" } else { "s" }))
+        .collect();
+    if parts.is_empty() {
+        "0 seconds".into()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// The current time in `tz` at `now`, with the display name, `formatted`,
+/// and `human_readable` set when a language, format, or locale is
+/// configured.
+fn current_time(
+    tz: &jiff::tz::TimeZone,
+    now: jiff::Timestamp,
+    language: Option<Language>,
+    rendering: &Rendering,
+) -> Result<CurrentTime, ToolError> {
+    let mut time = crate::core::current_time_in(tz, now);
+    time.display_name =
+        language.map(|language| display_names::display_name(&time.timezone, language));
+    let zdt = now.to_zoned(tz.clone());
+    time.formatted = rendering.format(&zdt)?;
+    time.human_readable = rendering.human_readable(&zdt);
+    Ok(time)
+}
+
+/// The `format` and `locale` parameters of the tools that report
+/// datetimes.
+struct Rendering {
+    format: Option<DatetimeFormat>,
+    locale: Option<Locale>,
+}
+
+impl Rendering {
+    /// Read both parameters.
+    fn parse(format: Option<&str>, locale: Option<&str>) -> Result<Rendering, ToolError> {
+        let format = format
+            .map(|input| {
+                DatetimeFormat::parse(input)
+                    .map_err(|msg| ToolError::new("invalid_format", msg).with_value(input))
+            })
+            .transpose()?;
+        let locale = parse_locale(locale)?;
+        Ok(Rendering { format, locale })
+    }
+
+    /// `zdt` rendered in the requested format, if there is one.
+    fn format(&self, zdt: &jiff::Zoned) -> Result<Option<String>, ToolError> {
+        self.format
+            .as_ref()
+            .map(|format| {
+                format
+                    .format(zdt)
+                    .map_err(|msg| ToolError::new("invalid_format", msg))
+            })
+            .transpose()
+    }
+
+    /// `zdt` written out for the requested locale, if there is one.
+    fn human_readable(&self, zdt: &jiff::Zoned) -> Option<String> {
+        self.locale.map(|locale| locale.format(zdt))
+    }
+}
+
+/// The event from `start` to `end` as seen in `tz`.
+fn agenda_entry(
+    title: &str,
+    start: &jiff::Zoned,
+    end: &jiff::Zoned,
+    tz: &jiff::tz::TimeZone,
+) -> Result<AgendaEntry, jiff::Error> {
+    let local_start = start.with_time_zone(tz.clone());
+    let local_end = end.with_time_zone(tz.clone());
+    let day_shift = local_start.date().since(start.date())?.get_days();
+    // An event ending exactly at midnight stays on its start day.
+    let last_instant = if end > start {
+        local_end.checked_sub(jiff::SignedDuration::from_nanos(1))?
+    } else {
+        local_end.clone()
+    };
+    Ok(AgendaEntry {
+        title: title.to_string(),
+        start: local_start.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+        end: local_end.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+        day_shift,
+        crosses_midnight: last_instant.date() > local_start.date(),
+    })
+}
+
+#[tool_router(router = core_time_router, vis = "pub")]
+impl TimeServer {
+    /// Get the current time in a specific timezone. Defaults to the server's default timezone (UTC unless configured) if no timezone is provided.
+    #[tool(
+        name = "get_current_time",
+        description = "Get the current time in a specific timezone. Defaults to the server's default timezone (UTC unless configured) if no timezone is provided. Pass a comma-separated list of timezones to get a times array with one entry per zone. Pass a format to also get each datetime as RFC 2822, Unix seconds, human-readable text, or a strftime pattern, or a locale (e.g., 'de-DE') to get it written out in that language in human_readable.",
+        output_schema = output_schema::<CurrentTimeResponse>()
+    )]
+    pub async fn get_current_time(
+        &self,
+        Parameters(params): Parameters<GetCurrentTimeParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let input = params.timezone.as_deref().map(unicode::normalize_input);
+        let rendering = match Rendering::parse(params.format.as_deref(), params.locale.as_deref()) {
+            Ok(rendering) => rendering,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let now = self.now();
+
+        if let Some(list) = input.as_deref().filter(|v| v.contains(',')) {
+            let mut times = Vec::new();
+            for name in list.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                match self
+                    .resolve_timezone(name)
+                    .and_then(|tz| current_time(&tz, now, self.language, &rendering))
+                {
+                    Ok(time) => times.push(time),
+                    Err(msg) => return Ok(tool_error(msg)),
+                }
+            }
+            return tool_success(&CurrentTimeResponse::List { times });
+        }
+
+        let tz = match self.resolve_timezone_or_default(input.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        match current_time(&tz, now, self.language, &rendering) {
+            Ok(time) => tool_success(&CurrentTimeResponse::Single(time)),
+            Err(msg) => Ok(tool_error(msg)),
+        }
+    }
+
+    /// Get the current time in many timezones at once.
+    #[tool(
+        name = "get_world_clock",
+        description = "Get the current time, UTC offset, and DST status in many timezones in one call, sorted west to east by offset. Pass a list of timezones, a preset group ('major_financial_centers', 'us', 'americas', 'europe', 'asia_pacific'), or both; with neither, the server's configured world clock zones are shown.",
+        output_schema = output_schema::<WorldClockResponse>()
+    )]
+    pub async fn get_world_clock(
+        &self,
+        Parameters(params): Parameters<GetWorldClockParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let group = match params.group.as_deref() {
+            Some(name) => match world_clock::group(name) {
+                Some(zones) => Some((name.trim().to_ascii_lowercase(), zones)),
+                None => {
+                    let names: Vec<&str> = world_clock::GROUPS.iter().map(|(n, _)| *n).collect();
+                    return Ok(tool_error(
+                        ToolError::new(
+                            "invalid_group",
+                            format!(
+                                "Unknown world clock group: '{name}'. Expected one of: {}.",
+                                names.join(", ")
+                            ),
+                        )
+                        .with_value(name),
+                    ));
+                }
+            },
+            None => None,
+        };
+        let rendering = match Rendering::parse(params.format.as_deref(), params.locale.as_deref()) {
+            Ok(rendering) => rendering,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let mut names: Vec<String> = params
+            .timezones
+            .iter()
+            .map(|name| unicode::normalize_input(name).trim().to_string())
+            .collect();
+        if let Some((_, zones)) = &group {
+            names.extend(zones.iter().map(|zone| zone.to_string()));
+        }
+        if names.is_empty() {
+            names.clone_from(&self.world_clock_zones);
+        }
+
+        let now = self.now();
+        let mut seen = HashSet::new();
+        let mut zones = Vec::new();
+        for name in &names {
+            let tz = match self.resolve_timezone(name) {
+                Ok(tz) => tz,
+                Err(msg) => return Ok(tool_error(msg)),
+            };
+            if !seen.insert(tz.iana_name().unwrap_or("UTC").to_string()) {
+                continue;
+            }
+            match current_time(&tz, now, self.language, &rendering) {
+                Ok(entry) => zones.push((tz.to_offset(now), entry)),
+                Err(msg) => return Ok(tool_error(msg)),
+            }
+        }
+        zones.sort_by_key(|(offset, _)| offset.seconds());
+
+        tool_success(&WorldClockResponse {
+            as_of: now.strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            group: group.map(|(name, _)| name),
+            zones: zones.into_iter().map(|(_, entry)| entry).collect(),
+        })
+    }
+
+    /// Convert a time from one timezone to another.
+    #[tool(
+        name = "convert_time",
+        description = "Convert a time from one timezone to another, on today's date in the source timezone unless a date is given. Pass a format to also get both datetimes as RFC 2822, Unix seconds, human-readable text, or a strftime pattern, or a locale (e.g., 'de-DE') to get them written out in that language in human_readable. Times that happen twice or never because of a DST change are flagged with was_ambiguous or was_skipped and the chosen_offset; the ambiguity and gap parameters choose how they are read, or reject them.",
+        output_schema = output_schema::<Conversion>()
+    )]
+    pub async fn convert_time(
+        &self,
+        Parameters(params): Parameters<ConvertTimeParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let (source_tz, local) = match self.read_conversion_source(
+            &params.source_timezone,
+            &params.time,
+            params.date.as_deref(),
+            params.allow_offsets,
+            params.ambiguity,
+            params.gap,
+        ) {
+            Ok(source) => source,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let target_tz =
+            match self.resolve_conversion_zone(&params.target_timezone, params.allow_offsets) {
+                Ok(tz) => tz,
+                Err(msg) => return Ok(tool_error(msg)),
+            };
+        let rendering = match Rendering::parse(params.format.as_deref(), params.locale.as_deref()) {
+            Ok(rendering) => rendering,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let mut conversion = crate::core::convert_local(&local, &source_tz, &target_tz);
+        for (entry, tz) in [
+            (&mut conversion.source, &source_tz),
+            (&mut conversion.target, &target_tz),
+        ] {
+            if let Err(msg) = self.decorate(entry, &local.zoned, tz, &rendering) {
+                return Ok(tool_error(msg));
+            }
+        }
+
+        tool_success(&conversion)
+    }
+
+    /// Convert one time to many timezones at once.
+    #[tool(
+        name = "convert_time_multi",
+        description = "Convert a time from one timezone to up to 100 target timezones in one call, on today's date in the source timezone unless a date is given. Each target reports its local datetime, UTC offset, time difference from the source, and day_shift when its date differs. Accepts the same format, locale, ambiguity, and gap options as convert_time.",
+        output_schema = output_schema::<ConvertTimeMultiResponse>()
+    )]
+    pub async fn convert_time_multi(
+        &self,
+        Parameters(params): Parameters<ConvertTimeMultiParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        if params.target_timezones.is_empty()
+            || params.target_timezones.len() > MAX_CONVERSION_TARGETS
+        {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_arguments",
+                    format!(
+                        "Provide between 1 and {MAX_CONVERSION_TARGETS} target timezones; got {}.",
+                        params.target_timezones.len()
+                    ),
+                )
+                .with_value(params.target_timezones.len().to_string()),
+            ));
+        }
+        let (source_tz, local) = match self.read_conversion_source(
+            &params.source_timezone,
+            &params.time,
+            params.date.as_deref(),
+            params.allow_offsets,
+            params.ambiguity,
+            params.gap,
+        ) {
+            Ok(source) => source,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let rendering = match Rendering::parse(params.format.as_deref(), params.locale.as_deref()) {
+            Ok(rendering) => rendering,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let mut source = crate::core::convert_local(&local, &source_tz, &source_tz).source;
+        if let Err(msg) = self.decorate(&mut source, &local.zoned, &source_tz, &rendering) {
+            return Ok(tool_error(msg));
+        }
+        let mut warning = None;
+        let mut targets = Vec::with_capacity(params.target_timezones.len());
+        for name in &params.target_timezones {
+            let tz = match self.resolve_conversion_zone(name, params.allow_offsets) {
+                Ok(tz) => tz,
+                Err(msg) => return Ok(tool_error(msg)),
+            };
+            let mut conversion = crate::core::convert_local(&local, &source_tz, &tz);
+            if let Err(msg) = self.decorate(&mut conversion.target, &local.zoned, &tz, &rendering) {
+                return Ok(tool_error(msg));
+            }
+            let day_shift = local
+                .zoned
+                .with_time_zone(tz)
+                .date()
+                .since(local.zoned.date())
+                .map_err(|e| {
+                    rmcp::ErrorData::internal_error(format!("Date difference: {e}"), None)
+                })?
+                .get_days();
+            warning = warning.or(conversion.warning);
+            targets.push(ConversionTarget {
+                time: conversion.target,
+                time_difference: conversion.time_difference,
+                day_shift,
+            });
+        }
+        tool_success(&ConvertTimeMultiResponse {
+            source,
+            targets,
+            was_ambiguous: local.was_ambiguous,
+            was_skipped: local.was_skipped,
+            chosen_offset: (local.was_ambiguous || local.was_skipped)
+                .then(|| format_utc_offset(local.zoned.offset())),
+            warning,
+            tzdb_version: tzdb::version().map(str::to_string),
+        })
+    }
+
+    /// Convert a multi-event schedule into other timezones.
+    #[tool(
+        name = "convert_agenda",
+        description = "Convert a list of events (title, start, end, and source timezone) into one or more target timezones, ordered by start time. Events that start on a different calendar day than in their source timezone are flagged with a day_shift, and events running past local midnight with crosses_midnight.",
+        output_schema = output_schema::<ConvertAgendaResponse>()
+    )]
+    pub async fn convert_agenda(
+        &self,
+        Parameters(params): Parameters<ConvertAgendaParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        if params.events.is_empty() || params.target_timezones.is_empty() {
+            return Ok(tool_error(ToolError::new(
+                "invalid_arguments",
+                "Provide at least one event and at least one target timezone.",
+            )));
+        }
+        let default_tz = match self.resolve_timezone_or_default(params.source_timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+
+        let mut events = Vec::new();
+        for event in &params.events {
+            let tz = match event.timezone.as_deref() {
+                Some(name) => match self.resolve_timezone(name) {
+                    Ok(tz) => tz,
+                    Err(msg) => return Ok(tool_error(msg)),
+                },
+                None => default_tz.clone(),
+            };
+            let (start, end) = match (
+                parse_datetime(event.start.trim(), &tz),
+                parse_datetime(event.end.trim(), &tz),
+            ) {
+                (Ok(start), Ok(end)) => (start, end),
+                (Err(msg), _) | (_, Err(msg)) => return Ok(tool_error(msg)),
+            };
+            if end < start {
+                return Ok(tool_error(
+                    ToolError::new(
+                        "invalid_event",
+                        format!("Event '{}' ends before it starts.", event.title),
+                    )
+                    .with_value(&event.title),
+                ));
+            }
+            events.push((event.title.as_str(), start, end));
+        }
+        events.sort_by_key(|(_, start, _)| start.timestamp());
+
+        let mut schedules = Vec::new();
+        for name in &params.target_timezones {
+            let target_tz = match self.resolve_timezone(name) {
+                Ok(tz) => tz,
+                Err(msg) => return Ok(tool_error(msg)),
+            };
+            let entries: Vec<AgendaEntry> = events
+                .iter()
+                .map(|(title, start, end)| agenda_entry(title, start, end, &target_tz))
+                .collect::<Result<_, _>>()
+                .map_err(|e| {
+                    rmcp::ErrorData::internal_error(format!("Failed to convert event: {e}"), None)
+                })?;
+            let timezone = target_tz.iana_name().unwrap_or("UTC").to_string();
+            schedules.push(AgendaSchedule {
+                display_name: self
+                    .language
+                    .map(|language| display_names::display_name(&timezone, language)),
+                timezone,
+                different_day_count: entries.iter().filter(|e| e.day_shift != 0).count(),
+                events: entries,
+            });
+        }
+
+        tool_success(&ConvertAgendaResponse {
+            schedules,
+            tzdb_version: crate::tzdb::version(),
+        })
+    }
+
+    /// Evaluate a compound time expression.
+    #[tool(
+        name = "evaluate",
+        description = "Evaluate a time arithmetic expression in one call. Grammar: <start> followed by any of '+ <duration>', '- <duration>', 'in <zone>' / 'to <zone>', applied left to right, optionally ending with '- <start>' to get the time between two datetimes (e.g., 'now - 2025-07-04T09:00 Europe/London'). <start> is 'now', 'today', 'tomorrow', 'yesterday', or a datetime optionally followed by an IANA zone; durations are like '3 days', '45m', or '1h30m'. Examples: 'now + 3 days - 2 hours in Asia/Tokyo', '2025-07-01T09:00 Europe/Paris to America/Chicago + 45m'. Calendar units follow the current zone, so adding a day across a DST change keeps the wall-clock time.",
+        output_schema = output_schema::<EvaluateResponse>()
+    )]
+    pub async fn evaluate(
+        &self,
+        Parameters(params): Parameters<EvaluateParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let default_tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let input = unicode::normalize_input(params.expression.trim());
+        let parsed = match expression::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(msg) => {
+                return Ok(tool_error(
+                    ToolError::new("invalid_expression", msg).with_value(&*input),
+                ));
+            }
+        };
+        match self.evaluate_expression(&parsed, &default_tz, params.reference.as_deref()) {
+            Ok((result, steps)) => tool_success(&EvaluateResponse {
+                expression: input.into_owned(),
+                result,
+                steps,
+            }),
+            Err(msg) => Ok(tool_error(msg)),
+        }
+    }
+
+    /// Explain a timestamp in full.
+    #[tool(
+        name = "explain_time",
+        description = "Explain any accepted timestamp (RFC 3339, local datetime or date, RFC 2822, or a Unix epoch number in any unit) in one call: its calendar components in a chosen timezone, UTC equivalent, Unix epoch, ISO week, weekday, UTC offset and DST status, distance from now, and the nearest offset transitions before and after it.",
+        output_schema = output_schema::<ExplainTimeResponse>()
+    )]
+    pub async fn explain_time(
+        &self,
+        Parameters(params): Parameters<ExplainTimeParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let input = unicode::normalize_input(params.timestamp.trim()).into_owned();
+        let (zdt, interpreted_as, warning) = match parse_any_timestamp(&input, &tz) {
+            Ok(parsed) => parsed,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let reference = match params.reference.as_deref() {
+            Some(reference) => {
+                let reference = unicode::normalize_input(reference.trim());
+                match parse_any_timestamp(&reference, &tz) {
+                    Ok((reference, _, _)) => reference,
+                    Err(msg) => return Ok(tool_error(msg)),
+                }
+            }
+            None => self.now_in(&tz),
+        };
+        let relative = relative_description(&zdt, &reference).map_err(|e| {
+            rmcp::ErrorData::internal_error(format!("Failed to compare times: {e}"), None)
+        })?;
+
+        let ts = zdt.timestamp();
+        let info = tz.to_offset_info(ts);
+        let week = zdt.date().iso_week_date();
+        let timezone = tz.iana_name().unwrap_or("UTC").to_string();
+        tool_success(&ExplainTimeResponse {
+            input,
+            interpreted_as,
+            display_name: self
+                .language
+                .map(|language| display_names::display_name(&timezone, language)),
+            timezone,
+            datetime: zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+            components: TimeComponents {
+                year: zdt.year(),
+                month: zdt.month(),
+                day: zdt.day(),
+                hour: zdt.hour(),
+                minute: zdt.minute(),
+                second: zdt.second(),
+                nanosecond: zdt.subsec_nanosecond(),
+                day_of_year: zdt.day_of_year(),
+            },
+            weekday: zdt.strftime("%A").to_string(),
+            iso_week: format!(
+                "{:04}-W{:02}-{}",
+                week.year(),
+                week.week(),
+                week.weekday().to_monday_one_offset()
+            ),
+            utc: ts.to_string(),
+            unix_seconds: ts.as_second(),
+            unix_milliseconds: ts.as_millisecond(),
+            utc_offset: format_utc_offset(info.offset()),
+            abbreviation: info.abbreviation().to_string(),
+            is_dst: info.dst().is_dst(),
+            relative,
+            previous_transition: tz.preceding(ts).next().map(|t| nearby_transition(&tz, &t)),
+            next_transition: tz.following(ts).next().map(|t| nearby_transition(&tz, &t)),
+            warning,
+        })
+    }
+
+    /// Resolve a natural-language time phrase.
+    #[tool(
+        name = "parse_time",
+        description = "Resolve a natural-language phrase such as 'tomorrow at 3pm', 'next Friday 09:00', 'in 2 hours', or '3 days ago' to an RFC 3339 datetime in a timezone. Phrases name a day ('today', 'tomorrow', 'yesterday', 'the day after tomorrow', a weekday with optional 'this', 'next', or 'last', or a YYYY-MM-DD date), a time of day ('3pm', '15:30', 'noon', 'midnight'), or both; a day alone means its start and a time alone means today. A bare weekday or 'this Friday' includes today, 'next Friday' is the first after today, and 'last Friday' the most recent before today. Durations count from now ('in 2 hours', '3 days ago') or from a phrase ('2 days after next Friday 09:00'). Plain datetimes are accepted too.",
+        output_schema = output_schema::<ParseTimeResponse>()
+    )]
+    pub async fn parse_time(
+        &self,
+        Parameters(params): Parameters<ParseTimeParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let input = unicode::normalize_input(params.text.trim()).into_owned();
+        let now = match params.reference.as_deref() {
+            Some(reference) => match parse_datetime(reference.trim(), &tz) {
+                Ok(reference) => reference,
+                Err(msg) => return Ok(tool_error(msg)),
+            },
+            None => self.now_in(&tz),
+        };
+        let (zdt, note) = match resolve_phrase(&input, &now, &tz) {
+            Ok(resolved) => resolved,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let relative = relative_description(&zdt, &now).map_err(|e| {
+            rmcp::ErrorData::internal_error(format!("Failed to compare times: {e}"), None)
+        })?;
+        let render = |zdt: &jiff::Zoned| zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
+        tool_success(&ParseTimeResponse {
+            input,
+            datetime: render(&zdt),
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            utc: zdt.timestamp().to_string(),
+            weekday: zdt.strftime("%A").to_string(),
+            reference: render(&now),
+            relative,
+            note,
+        })
+    }
+
+    /// Add a duration to a datetime.
+    #[tool(
+        name = "add_time",
+        description = "Add a duration to a datetime (or 'now') and return the resulting datetime in a timezone. Durations can be phrases ('3 days 4 hours', '1h30m', '2 business days') or ISO 8601 ('P3DT4H'). Days, weeks, months, and years follow the local calendar, so adding a day across a DST change keeps the wall-clock time; hours and smaller units are elapsed time. Results that land on a skipped or repeated local time carry a note.",
+        output_schema = output_schema::<TimeArithmeticResponse>()
+    )]
+    pub async fn add_time(
+        &self,
+        Parameters(params): Parameters<TimeArithmeticParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        self.shift_time(params, false)
+    }
+
+    /// Subtract a duration from a datetime.
+    #[tool(
+        name = "subtract_time",
+        description = "Subtract a duration from a datetime (or 'now') and return the resulting datetime in a timezone. Durations can be phrases ('3 days 4 hours', '1h30m', '2 business days') or ISO 8601 ('P3DT4H'). Days, weeks, months, and years follow the local calendar, so subtracting a day across a DST change keeps the wall-clock time; hours and smaller units are elapsed time. Results that land on a skipped or repeated local time carry a note.",
+        output_schema = output_schema::<TimeArithmeticResponse>()
+    )]
+    pub async fn subtract_time(
+        &self,
+        Parameters(params): Parameters<TimeArithmeticParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        self.shift_time(params, true)
+    }
+
+    /// Compute the time between two datetimes.
+    #[tool(
+        name = "time_difference",
+        description = "Compute the time between two datetimes, broken down into years, months, days, hours, minutes, and seconds, plus the total elapsed seconds. Calendar units are counted in the given timezone, so a day across a DST change counts as one day even though it is 23 or 25 hours long; total_seconds is always the exact elapsed time.",
+        output_schema = output_schema::<TimeDifferenceResponse>()
+    )]
+    pub async fn time_difference(
+        &self,
+        Parameters(params): Parameters<TimeDifferenceParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let start = match parse_datetime(params.start.trim(), &tz) {
+            Ok(zdt) => zdt,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let end = match parse_datetime(params.end.trim(), &tz) {
+            Ok(zdt) => zdt,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let span = start
+            .until(
+                jiff::ZonedDifference::new(&end)
+                    .largest(jiff::Unit::Year)
+                    .smallest(jiff::Unit::Second),
+            )
+            .map_err(|e| {
+                rmcp::ErrorData::internal_error(format!("Failed to compare times: {e}"), None)
+            })?;
+        let elapsed = start.duration_until(&end);
+
+        let render = |zdt: &jiff::Zoned| zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
+        tool_success(&TimeDifferenceResponse {
+            start: render(&start),
+            end: render(&end),
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            breakdown: DifferenceBreakdown {
+                years: span.get_years(),
+                months: span.get_months(),
+                days: span.get_days(),
+                hours: span.get_hours(),
+                minutes: span.get_minutes(),
+                seconds: span.get_seconds(),
+            },
+            iso8601: span.to_string(),
+            description: format!("{:#}", span.abs()),
+            total_seconds: elapsed.as_secs_f64(),
+            negative: elapsed.is_negative(),
+        })
+    }
+
+    /// Count down to a deadline.
+    #[tool(
+        name = "time_until",
+        description = "Count down to a deadline: the time left in days, hours, minutes, and seconds (e.g., '3 days, 4 hours, 12 minutes'), the total seconds left, and whether it has passed. Days are counted in the timezone, so a day across a DST change is one day. A date without a time counts to the end of that day (midnight at the start of the next day) unless date_only is 'start_of_day'.",
+        output_schema = output_schema::<TimeUntilResponse>()
+    )]
+    pub async fn time_until(
+        &self,
+        Parameters(params): Parameters<TimeUntilParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let locale = match parse_locale(params.locale.as_deref()) {
+            Ok(locale) => locale,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let input = unicode::normalize_input(params.target.trim());
+        let date_only = !input.contains(':') && !input.contains('T');
+        let mut target = match parse_datetime(&input, &tz) {
+            Ok(zdt) => zdt,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let mut note = None;
+        if date_only {
+            let boundary = match params.date_only {
+                DayBoundary::StartOfDay => "the start of that day",
+                DayBoundary::EndOfDay => {
+                    // The next day's start, so days that end at a DST change
+                    // still last all of their hours.
+                    target = target
+                        .tomorrow()
+                        .and_then(|zdt| zdt.start_of_day())
+                        .map_err(|e| {
+                            rmcp::ErrorData::internal_error(
+                                format!("Failed to resolve end of day: {e}"),
+                                None,
+                            )
+                        })?;
+                    "the end of that day"
+                }
+            };
+            note = Some(format!(
+                "'{input}' has no time of day, so it was counted to {boundary}."
+            ));
+        }
+
+        let now = self.now_in(&tz);
+        let span = now
+            .until(
+                jiff::ZonedDifference::new(&target)
+                    .largest(jiff::Unit::Day)
+                    .smallest(jiff::Unit::Second),
+            )
+            .map_err(|e| {
+                rmcp::ErrorData::internal_error(format!("Failed to compare times: {e}"), None)
+            })?;
+        let left = now.duration_until(&target);
+
+        let render = |zdt: &jiff::Zoned| zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
+        tool_success(&TimeUntilResponse {
+            target: render(&target),
+            human_readable: locale.map(|locale| locale.format(&target)),
+            timezone: crate::core::zone_label(&tz),
+            now: render(&now),
+            remaining: countdown_description(&span),
+            total_seconds: left.as_secs(),
+            passed: left.is_negative() || left.is_zero(),
+            note,
+        })
+    }
+
+    /// Describe a datetime relative to a reference, e.g. "3 hours ago".
+    #[tool(
+        name = "humanize_time",
+        description = "Describe a datetime relative to a reference time (now by default) in words, such as '3 hours ago', 'in 2 days', or 'now', along with the exact difference as an ISO 8601 duration and in seconds. granularity sets the smallest unit counted and max_units how many units the phrase uses, the last rounded half up ('3 hours 20 minutes ago' with 2). Days and months are counted in the timezone, so a day across a DST change is one day. Accepts RFC 3339, local datetimes, and Unix epoch numbers.",
+        output_schema = output_schema::<HumanizeTimeResponse>()
+    )]
+    pub async fn humanize_time(
+        &self,
+        Parameters(params): Parameters<HumanizeTimeParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let max_units = params.max_units.unwrap_or(1);
+        if !(1..=PhraseUnit::UNITS.len() as u8).contains(&max_units) {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_arguments",
+                    format!(
+                        "max_units must be between 1 and {}; got {max_units}.",
+                        PhraseUnit::UNITS.len()
+                    ),
+                )
+                .with_value(max_units.to_string()),
+            ));
+        }
+        let read = |input: &str| {
+            let input = unicode::normalize_input(input.trim());
+            match epoch::EpochValue::parse(&input) {
+                Some(value) => value
+                    .to_timestamp(value.infer_unit().unit)
+                    .map(|ts| ts.to_zoned(tz.clone()))
+                    .map_err(|msg| ToolError::new("invalid_timestamp", msg).with_value(&*input)),
+                None => parse_datetime(&input, &tz),
+            }
+        };
+        let target = match read(&params.datetime) {
+            Ok(zdt) => zdt,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let reference = match params.reference.as_deref().map(read) {
+            Some(Ok(zdt)) => zdt,
+            Some(Err(msg)) => return Ok(tool_error(msg)),
+            None => self.now_in(&tz),
+        };
+
+        let difference = |smallest: jiff::Unit| {
+            reference
+                .until(
+                    jiff::ZonedDifference::new(&target)
+                        .largest(jiff::Unit::Year)
+                        .smallest(smallest)
+                        .mode(jiff::RoundMode::HalfExpand),
+                )
+                .map_err(|e| {
+                    rmcp::ErrorData::internal_error(format!("Failed to compare times: {e}"), None)
+                })
+        };
+        let exact = difference(jiff::Unit::Nanosecond)?;
+        // Count from the largest non-zero unit, and no finer than asked.
+        let largest = PhraseUnit::UNITS
+            .iter()
+            .position(|&unit| span_field(&exact, unit) != 0)
+            .unwrap_or(PhraseUnit::UNITS.len() - 1);
+        let smallest = (largest + usize::from(max_units) - 1).min(params.granularity.index());
+        let rounded = difference(PhraseUnit::UNITS[smallest])?;
+
+        let (phrase, direction) = match rounded.signum() {
+            0 => ("now".to_string(), Direction::Now),
+            1 => (
+                format!("in {}", duration::spell(&rounded)),
+                Direction::Future,
+            ),
+            _ => (
+                format!("{} ago", duration::spell(&rounded.abs())),
+                Direction::Past,
+            ),
+        };
+        let render = |zdt: &jiff::Zoned| zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
+        tool_success(&HumanizeTimeResponse {
+            datetime: render(&target),
+            reference: render(&reference),
+            timezone: crate::core::zone_label(&tz),
+            phrase,
+            direction,
+            delta: exact.to_string(),
+            total_seconds: reference.duration_until(&target).as_secs_f64(),
+        })
+    }
+
+    /// Work out a flight's local arrival time.
+    #[tool(
+        name = "travel_arrival",
+        description = "Work out when a flight lands in local time: give the departure time and timezone, the flight duration (e.g., '7h 25m'), and the arrival timezone. Returns the local arrival datetime, the day change as on itineraries ('+1 day'), and how far the wall clock moves compared with the time actually flown, which differ by the change of UTC offset. Both zones' DST rules on the day are applied, and a departure time that DST skips or repeats carries a note.",
+        output_schema = output_schema::<TravelArrivalResponse>()
+    )]
+    pub async fn travel_arrival(
+        &self,
+        Parameters(params): Parameters<TravelArrivalParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let departure_tz = match self.resolve_timezone(params.departure_timezone.trim()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let arrival_tz = match self.resolve_timezone(params.arrival_timezone.trim()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let input = unicode::normalize_input(params.departure.trim());
+        let departure = match parse_datetime(&input, &departure_tz) {
+            Ok(zdt) => zdt,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let note = input
+            .parse::<jiff::civil::DateTime>()
+            .ok()
+            // Only wall-clock inputs are read in the departure zone.
+            .filter(|_| {
+                input.parse::<jiff::Timestamp>().is_err() && input.parse::<jiff::Zoned>().is_err()
+            })
+            .and_then(|local| wall_clock_note("The departure time", local, &departure_tz));
+
+        let duration = unicode::normalize_input(params.duration.trim());
+        let flight_time = match duration::parse(&duration) {
+            Ok(parsed) if parsed.anchor.is_none() && !parsed.backward => parsed.fixed_seconds(),
+            Ok(_) => None,
+            Err(msg) => {
+                return Ok(tool_error(
+                    ToolError::new("invalid_duration", msg).with_value(&*duration),
+                ));
+            }
+        };
+        let Some(seconds) = flight_time else {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_duration",
+                    format!(
+                        "Invalid flight duration: '{duration}'. Give the elapsed time in hours and minutes (e.g., '7h 25m', '26 hours'), without days or a starting point."
+                    ),
+                )
+                .with_value(&*duration),
+            ));
+        };
+        let arrival = jiff::SignedDuration::try_from_secs_f64(seconds)
+            .and_then(|elapsed| Ok((elapsed, departure.checked_add(elapsed)?)));
+        let (elapsed, arrival) = match arrival {
+            Ok((elapsed, zdt)) => (elapsed, zdt.with_time_zone(arrival_tz.clone())),
+            Err(e) => {
+                return Ok(tool_error(ToolError::from(format!(
+                    "Arrival is out of range: {e}"
+                ))));
+            }
+        };
+
+        let offset_change = arrival.offset().seconds() - departure.offset().seconds();
+        let wall_clock = departure
+            .datetime()
+            .until((jiff::Unit::Hour, arrival.datetime()))
+            .map_err(|e| {
+                rmcp::ErrorData::internal_error(format!("Failed to compare times: {e}"), None)
+            })?;
+        let day_change = departure
+            .date()
+            .until((jiff::Unit::Day, arrival.date()))
+            .map_err(|e| {
+                rmcp::ErrorData::internal_error(format!("Failed to compare dates: {e}"), None)
+            })?
+            .get_days();
+        let day_change_label = (day_change != 0).then(|| {
+            let unit = if day_change.abs() == 1 { "day" } else { "days" };
+            format!("{day_change:+} {unit}")
+        });
+        let flight_span = jiff::Span::try_from(elapsed)
+            .and_then(|span| span.round(jiff::SpanRound::new().largest(jiff::Unit::Hour)))
+            .map_err(|e| {
+                rmcp::ErrorData::internal_error(format!("Failed to format duration: {e}"), None)
+            })?;
+        let description = duration::spell(&flight_span);
+
+        let mut departure_entry = crate::core::converted_time(&departure_tz, &departure);
+        let mut arrival_entry = crate::core::converted_time(&arrival_tz, &arrival);
+        let rendering = Rendering {
+            format: None,
+            locale: None,
+        };
+        for (entry, zdt, tz) in [
+            (&mut departure_entry, &departure, &departure_tz),
+            (&mut arrival_entry, &arrival, &arrival_tz),
+        ] {
+            if let Err(msg) = self.decorate(entry, zdt, tz, &rendering) {
+                return Ok(tool_error(msg));
+            }
+        }
+
+        let offsets = match offset_change {
+            0 => "both zones are on the same UTC offset".to_string(),
+            diff => {
+                let abs = i64::from(diff.unsigned_abs());
+                let span = jiff::Span::new().hours(abs / 3600).minutes(abs % 3600 / 60);
+                let direction = if diff > 0 { "ahead of" } else { "behind" };
+                format!(
+                    "{} is {} {direction} {}",
+                    arrival_entry.timezone,
+                    duration::spell(&span),
+                    departure_entry.timezone
+                )
+            }
+        };
+        let local = |zdt: &jiff::Zoned| zdt.strftime("%H:%M on %a %Y-%m-%d").to_string();
+        let summary = format!(
+            "Departs {} in {} and lands {} local time in {}{} after {description}; the wall clock moves {} because {offsets}.",
+            local(&departure),
+            departure_entry.timezone,
+            local(&arrival),
+            arrival_entry.timezone,
+            day_change_label
+                .as_ref()
+                .map(|label| format!(" ({label})"))
+                .unwrap_or_default(),
+            if wall_clock.is_negative() {
+                format!("back {}", duration::spell(&wall_clock.abs()))
+            } else {
+                duration::spell(&wall_clock)
+            },
+        );
+
+        tool_success(&TravelArrivalResponse {
+            departure: departure_entry,
+            arrival: arrival_entry,
+            duration: flight_span.to_string(),
+            description,
+            elapsed_seconds: elapsed.as_secs_f64(),
+            time_difference: format_offset_diff(offset_change),
+            wall_clock_difference: wall_clock.to_string(),
+            day_change,
+            day_change_label,
+            summary,
+            note,
+        })
+    }
+}
+
+impl TimeServer {
+    /// Shared implementation of `add_time` and `subtract_time`.
+    fn shift_time(
+        &self,
+        params: TimeArithmeticParams,
+        subtract: bool,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let locale = match parse_locale(params.locale.as_deref()) {
+            Ok(locale) => locale,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let datetime = params.datetime.trim();
+        let start = if datetime.eq_ignore_ascii_case("now") {
+            self.now_in(&tz)
+        } else {
+            match parse_datetime(datetime, &tz) {
+                Ok(zdt) => zdt,
+                Err(msg) => return Ok(tool_error(msg)),
+            }
+        };
+
+        let input = unicode::normalize_input(params.duration.trim());
+        let mut parsed = match duration::parse(&input) {
+            Ok(parsed) if parsed.anchor.is_some() => {
+                return Ok(tool_error(
+                    ToolError::new(
+                        "invalid_duration",
+                        format!(
+                            "The duration '{input}' names a starting point; pass the start as `datetime` and the duration alone (e.g., '3 days')."
+                        ),
+                    )
+                    .with_value(&*input),
+                ));
+            }
+            Ok(parsed) => parsed,
+            Err(msg) => {
+                return Ok(tool_error(
+                    ToolError::new("invalid_duration", msg).with_value(&*input),
+                ));
+            }
+        };
+        // Subtracting a negative ISO 8601 duration ("-P1D") adds it.
+        parsed.backward ^= subtract;
+
+        let result = match parsed.apply(&start) {
+            Ok(zdt) => zdt,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let render = |zdt: &jiff::Zoned| zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
+        tool_success(&TimeArithmeticResponse {
+            start: render(&start),
+            duration: parsed.span.to_string(),
+            business_days: parsed.business_days,
+            result: render(&result),
+            human_readable: locale.map(|locale| locale.format(&result)),
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            utc: result.timestamp().to_string(),
+            note: dst_note(&start, &parsed),
+        })
+    }
+
+    /// Apply the steps of `parsed` in order, starting from its start time.
+    fn evaluate_expression(
+        &self,
+        parsed: &Expression,
+        default_tz: &jiff::tz::TimeZone,
+        reference: Option<&str>,
+    ) -> Result<(EvaluationResult, Vec<EvaluationStep>), ToolError> {
+        let render = |zdt: &jiff::Zoned| zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string();
+        let now = match reference {
+            Some(reference) => parse_datetime(reference.trim(), default_tz)?,
+            None => self.now_in(default_tz),
+        };
+        // A start time (or the end of a difference) and the text it came from.
+        let resolve = |start: &Start, tz: &jiff::tz::TimeZone| match start {
+            Start::Anchor(anchor) => Ok((
+                resolve_anchor(anchor, &now, tz)?,
+                format!("{anchor:?}").to_lowercase(),
+            )),
+            Start::Datetime(operand) => {
+                let tz = match &operand.zone {
+                    Some(zone) => self.resolve_timezone(zone)?,
+                    None => tz.clone(),
+                };
+                Ok::<_, ToolError>((parse_datetime(&operand.value, &tz)?, operand.value.clone()))
+            }
+        };
+
+        let (mut current, start) = resolve(&parsed.start, default_tz)?;
+        let mut steps = vec![EvaluationStep {
+            operation: "start",
+            operand: start,
+            datetime: render(&current),
+        }];
+
+        for step in &parsed.steps {
+            let (operation, text) = match step {
+                Step::Add(duration, text) => {
+                    current = duration.apply(&current)?;
+                    ("add", text.clone())
+                }
+                Step::Subtract(duration, text) => {
+                    let duration = duration::Duration {
+                        backward: true,
+                        ..duration.clone()
+                    };
+                    current = duration.apply(&current)?;
+                    ("subtract", text.clone())
+                }
+                Step::Convert(zone) => {
+                    current = current.with_time_zone(self.resolve_timezone(zone)?);
+                    ("convert", zone.clone())
+                }
+                Step::Difference(other) => {
+                    let (other_zdt, text) = resolve(other, current.time_zone())?;
+                    let elapsed = other_zdt.duration_until(&current);
+                    steps.push(EvaluationStep {
+                        operation: "difference",
+                        operand: text,
+                        datetime: render(&other_zdt),
+                    });
+                    let result = EvaluationResult::Duration {
+                        iso8601: elapsed.to_string(),
+                        description: format!("{elapsed:#}"),
+                        total_seconds: elapsed.as_secs_f64(),
+                    };
+                    return Ok((result, steps));
+                }
+            };
+            steps.push(EvaluationStep {
+                operation,
+                operand: text,
+                datetime: render(&current),
+            });
+        }
+
+        let result = EvaluationResult::Instant {
+            datetime: render(&current),
+            timezone: current.time_zone().iana_name().unwrap_or("UTC").to_string(),
+            utc: current.timestamp().to_string(),
+            unix_seconds: current.timestamp().as_second(),
+        };
+        Ok((result, steps))
+    }
+}
+
+impl TimeServer {
+    /// A `convert_time` timezone argument: an IANA zone, or a fixed offset
+    /// when `allow_offsets` is set.
+    fn resolve_conversion_zone(
+        &self,
+        input: &str,
+        allow_offsets: bool,
+    ) -> Result<jiff::tz::TimeZone, ToolError> {
+        match parse_fixed_offset(input) {
+            Some(tz) if allow_offsets => Ok(tz),
+            _ => self.resolve_timezone(input),
+        }
+    }
+
+    /// The source zone of a conversion and its `time` on `date` there,
+    /// today when no date is given.
+    fn read_conversion_source(
+        &self,
+        timezone: &str,
+        time: &str,
+        date: Option<&str>,
+        allow_offsets: bool,
+        ambiguity: Ambiguity,
+        gap: Gap,
+    ) -> Result<(jiff::tz::TimeZone, LocalTime), ToolError> {
+        let tz = self.resolve_conversion_zone(timezone, allow_offsets)?;
+        let time = time.trim();
+        let clock = parse_clock_time(time, self.lenient_parsing)?;
+        let date = match date {
+            Some(date) => parse_date(date)?,
+            None => self.now_in(&tz).date(),
+        };
+        let datetime = date.at(clock.hour(), clock.minute(), 0, 0);
+        let local = crate::core::resolve_local(datetime, &tz, ambiguity, gap)
+            .map_err(|err| err.with_value(time))?;
+        Ok((tz, local))
+    }
+
+    /// Fill in `entry`'s display name and its `formatted` and
+    /// `human_readable` renderings of `instant` in `tz`.
+    fn decorate(
+        &self,
+        entry: &mut ConvertedTime,
+        instant: &jiff::Zoned,
+        tz: &jiff::tz::TimeZone,
+        rendering: &Rendering,
+    ) -> Result<(), ToolError> {
+        entry.display_name = tz
+            .iana_name()
+            .zip(self.language)
+            .map(|(zone, language)| display_names::display_name(zone, language));
+        let zdt = instant.with_time_zone(tz.clone());
+        entry.formatted = rendering.format(&zdt)?;
+        entry.human_readable = rendering.human_readable(&zdt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::tests::extract_text;
+
+    #[tokio::test]
+    async fn get_current_time_defaults_to_utc_when_no_timezone() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: None,
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["timezone"], "UTC");
+    }
+
+    #[tokio::test]
+    async fn get_current_time_defaults_to_utc_for_empty_string() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some(String::new()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["timezone"], "UTC");
+    }
+
+    #[tokio::test]
+    async fn get_current_time_returns_valid_response_for_known_timezone() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("America/New_York".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["timezone"], "America/New_York");
+        assert!(json["datetime"].is_string());
+        assert!(json["utc_offset"].is_string());
+        assert!(json["is_dst"].is_boolean());
+    }
+
+    #[tokio::test]
+    async fn get_current_time_returns_error_for_invalid_timezone() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("Not/A/Timezone".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid timezone"));
+    }
+
+    #[tokio::test]
+    async fn get_current_time_returns_fractional_offset_for_kathmandu() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("Asia/Kathmandu".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["utc_offset"], "+05:45");
+    }
+
+    #[tokio::test]
+    async fn get_current_time_renders_requested_format() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("UTC, Asia/Tokyo".into()),
+            format: Some("unix".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let now = jiff::Timestamp::now().as_second();
+        for entry in json["times"].as_array().unwrap() {
+            let formatted: i64 = entry["formatted"].as_str().unwrap().parse().unwrap();
+            assert!((now - formatted).abs() < 5, "Got: {entry}");
+        }
+
+        let params = GetCurrentTimeParams {
+            timezone: None,
+            format: Some("%Y %Q".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert!(json["formatted"].as_str().unwrap().ends_with(" UTC"));
+    }
+
+    #[tokio::test]
+    async fn get_current_time_rejects_invalid_formats() {
+        let server = TimeServer::new();
+        for format in ["rfc-9999", "%Y-%m-%d %"] {
+            let params = GetCurrentTimeParams {
+                timezone: None,
+                format: Some(format.into()),
+                ..Default::default()
+            };
+            let result = server.get_current_time(Parameters(params)).await.unwrap();
+            assert_eq!(result.is_error, Some(true));
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["code"], "invalid_format");
+            assert_eq!(json["offending_value"], format);
+        }
+    }
+
+    fn world_clock_params(timezones: &[&str], group: Option<&str>) -> GetWorldClockParams {
+        GetWorldClockParams {
+            timezones: timezones.iter().map(|zone| zone.to_string()).collect(),
+            group: group.map(Into::into),
+            format: None,
+            locale: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn get_world_clock_sorts_zones_by_offset() {
+        let server = TimeServer::new();
+        let params = world_clock_params(
+            &[
+                "Asia/Tokyo",
+                "America/Los_Angeles",
+                "Asia/Tokyo",
+                "Asia/Kathmandu",
+            ],
+            None,
+        );
+        let result = server.get_world_clock(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let zones: Vec<&str> = json["zones"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|zone| zone["timezone"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            zones,
+            ["America/Los_Angeles", "Asia/Kathmandu", "Asia/Tokyo"]
+        );
+        assert_eq!(json["zones"][1]["utc_offset"], "+05:45");
+        assert!(json["zones"][0]["is_dst"].is_boolean());
+        assert!(json.get("group").is_none());
+    }
+
+    #[tokio::test]
+    async fn get_world_clock_expands_groups_and_defaults() {
+        let server = TimeServer::new();
+        let params = world_clock_params(&["Pacific/Auckland"], Some("Major_Financial_Centers"));
+        let result = server.get_world_clock(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["group"], "major_financial_centers");
+        let zones = json["zones"].as_array().unwrap();
+        assert_eq!(zones.len(), 14);
+        assert!(
+            zones
+                .iter()
+                .any(|zone| zone["timezone"] == "Asia/Singapore")
+        );
+
+        let server = TimeServer::new().world_clock_zones(vec!["Europe/Paris".into()]);
+        let result = server
+            .get_world_clock(Parameters(world_clock_params(&[], None)))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["zones"][0]["timezone"], "Europe/Paris");
+    }
+
+    #[tokio::test]
+    async fn get_world_clock_rejects_unknown_groups_and_zones() {
+        let server = TimeServer::new();
+        let params = world_clock_params(&[], Some("antarctica"));
+        let result = server.get_world_clock(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_group");
+        assert!(json["message"].as_str().unwrap().contains("asia_pacific"));
+
+        let params = world_clock_params(&["UTC", "Mars/Olympus"], None);
+        let result = server.get_world_clock(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(extract_text(&result).contains("Mars/Olympus"));
+    }
+
+    #[tokio::test]
+    async fn convert_time_renders_both_sides_in_requested_format() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "America/New_York".into(),
+            time: "12:00".into(),
+            target_timezone: "Asia/Tokyo".into(),
+            date: Some("2025-07-04".into()),
+            format: Some("human".into()),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(
+            json["source"]["formatted"],
+            "Friday, 4 July 2025 at 12:00 PM EDT"
+        );
+        assert_eq!(
+            json["target"]["formatted"],
+            "Saturday, 5 July 2025 at 1:00 AM JST"
+        );
+        assert_eq!(json["source"]["datetime"], "2025-07-04T12:00:00-04:00");
+
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "12:00".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert!(json["source"].get("formatted").is_none());
+    }
+
+    #[tokio::test]
+    async fn convert_time_converts_utc_to_new_york() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "12:00".into(),
+            target_timezone: "America/New_York".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["source"]["timezone"], "UTC");
+        assert_eq!(json["target"]["timezone"], "America/New_York");
+        // NY is UTC-5 or UTC-4 depending on DST
+        let target_dt = json["target"]["datetime"].as_str().unwrap();
+        assert!(
+            target_dt.contains("07:00") || target_dt.contains("08:00"),
+            "Expected 07:00 or 08:00 but got: {target_dt}"
+        );
+    }
+
+    #[tokio::test]
+    async fn convert_time_applies_the_rules_in_effect_on_the_date() {
+        let server = TimeServer::new();
+        let convert = |date: &str| ConvertTimeParams {
+            source_timezone: "Europe/Moscow".into(),
+            time: "12:00".into(),
+            target_timezone: "UTC".into(),
+            date: Some(date.into()),
+            ..Default::default()
+        };
+        // Soviet summer time in 1985, permanent UTC+4 from 2011 to 2014,
+        // and UTC+3 since.
+        for (date, offset) in [
+            ("1985-04-01", "+04:00"),
+            ("1985-01-15", "+03:00"),
+            ("2012-01-15", "+04:00"),
+            ("2015-01-15", "+03:00"),
+            ("2035-07-01", "+03:00"),
+        ] {
+            let result = server
+                .convert_time(Parameters(convert(date)))
+                .await
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["source"]["utc_offset"], offset, "On {date}: {json}");
+            assert_eq!(
+                json.get("tzdb_version").and_then(|v| v.as_str()),
+                tzdb::version(),
+                "On {date}: {json}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn convert_time_reports_and_obeys_dst_policies() {
+        let server = TimeServer::new();
+        let convert = |time: &str, date: &str, ambiguity, gap| ConvertTimeParams {
+            source_timezone: "Europe/London".into(),
+            time: time.into(),
+            target_timezone: "UTC".into(),
+            date: Some(date.into()),
+            ambiguity,
+            gap,
+            ..Default::default()
+        };
+        let fall_back = convert("01:30", "2025-10-26", Ambiguity::Later, Gap::NextValid);
+        let result = server.convert_time(Parameters(fall_back)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["was_ambiguous"], true);
+        assert_eq!(json["chosen_offset"], "+00:00");
+        assert_eq!(json["target"]["datetime"], "2025-10-26T01:30:00+00:00");
+
+        // Skipped times are rejected unless a gap policy says otherwise.
+        let spring_forward = convert("01:30", "2025-03-30", Ambiguity::Earlier, Gap::default());
+        let result = server
+            .convert_time(Parameters(spring_forward))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "nonexistent_local_time");
+        assert_eq!(json["offending_value"], "01:30");
+
+        let plain = convert("12:00", "2025-07-04", Ambiguity::Reject, Gap::Reject);
+        let result = server.convert_time(Parameters(plain)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert!(json.get("was_ambiguous").is_none(), "{json}");
+        assert!(json.get("chosen_offset").is_none(), "{json}");
+    }
+
+    #[tokio::test]
+    async fn convert_time_multi_converts_to_every_target_in_order() {
+        let server = TimeServer::new();
+        let params = ConvertTimeMultiParams {
+            source_timezone: "America/New_York".into(),
+            time: "9pm".into(),
+            target_timezones: vec![
+                "Europe/London".into(),
+                "Asia/Kolkata".into(),
+                "America/Los_Angeles".into(),
+            ],
+            date: Some("2025-03-10".into()),
+            format: Some("%H:%M %Z".into()),
+            ..Default::default()
+        };
+        let result = server.convert_time_multi(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["source"]["datetime"], "2025-03-10T21:00:00-04:00");
+        assert_eq!(json["source"]["formatted"], "21:00 EDT");
+        let targets = json["targets"].as_array().unwrap();
+        let summary: Vec<(&str, &str, &str, i64)> = targets
+            .iter()
+            .map(|t| {
+                (
+                    t["timezone"].as_str().unwrap(),
+                    t["datetime"].as_str().unwrap(),
+                    t["time_difference"].as_str().unwrap(),
+                    t["day_shift"].as_i64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("Europe/London", "2025-03-11T01:00:00+00:00", "+4:00", 1),
+                ("Asia/Kolkata", "2025-03-11T06:30:00+05:30", "+9:30", 1),
+                (
+                    "America/Los_Angeles",
+                    "2025-03-10T18:00:00-07:00",
+                    "-3:00",
+                    0
+                ),
+            ]
+        );
+        assert_eq!(targets[1]["formatted"], "06:30 IST");
+
+        // A later fold instance is formatted at its own offset.
+        let params = ConvertTimeMultiParams {
+            source_timezone: "America/New_York".into(),
+            time: "01:30".into(),
+            target_timezones: vec!["UTC".into()],
+            date: Some("2025-11-02".into()),
+            format: Some("%H:%M %Z".into()),
+            ambiguity: Ambiguity::Later,
+            gap: Gap::NextValid,
+            ..Default::default()
+        };
+        let result = server.convert_time_multi(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["was_ambiguous"], true);
+        assert_eq!(json["source"]["formatted"], "01:30 EST");
+        assert_eq!(json["targets"][0]["datetime"], "2025-11-02T06:30:00+00:00");
+
+        let params = ConvertTimeMultiParams {
+            source_timezone: "UTC".into(),
+            time: "12:00".into(),
+            target_timezones: vec!["Asia/Tokyo".into(), "Mars/Base".into()],
+            ..Default::default()
+        };
+        let result = server.convert_time_multi(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(extract_text(&result).contains("Mars/Base"));
+    }
+
+    #[cfg(feature = "locale")]
+    #[tokio::test]
+    async fn convert_time_writes_both_sides_out_for_locale() {
+        let server = TimeServer::new();
+        let params = |locale: &str| ConvertTimeParams {
+            source_timezone: "Europe/Berlin".into(),
+            time: "14:30".into(),
+            target_timezone: "America/New_York".into(),
+            date: Some("2025-03-05".into()),
+            locale: Some(locale.into()),
+            ..Default::default()
+        };
+        let result = server
+            .convert_time(Parameters(params("de-DE")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(
+            json["source"]["human_readable"],
+            "Mittwoch, 5. März 2025, 14:30"
+        );
+        assert_eq!(
+            json["target"]["human_readable"],
+            "Mittwoch, 5. März 2025, 08:30"
+        );
+        assert_eq!(json["source"]["datetime"], "2025-03-05T14:30:00+01:00");
+
+        let result = server
+            .convert_time(Parameters(params("en-US")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(
+            json["target"]["human_readable"],
+            "Wednesday, March 5, 2025 at 8:30 AM"
+        );
+
+        let result = server
+            .convert_time(Parameters(params("tlh")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_locale");
+    }
+
+    #[cfg(feature = "locale")]
+    #[tokio::test]
+    async fn get_current_time_writes_datetime_out_for_locale() {
+        // 2025-03-05T13:30:00Z, a Wednesday.
+        let server = TimeServer::new().clock(crate::clock::FixedClock(
+            jiff::Timestamp::from_second(1_741_181_400).unwrap(),
+        ));
+        let params = GetCurrentTimeParams {
+            timezone: Some("Asia/Tokyo, Europe/London".into()),
+            locale: Some("en-GB".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(
+            json["times"][0]["human_readable"],
+            "Wednesday 5 March 2025 at 22:30"
+        );
+        assert_eq!(
+            json["times"][1]["human_readable"],
+            "Wednesday 5 March 2025 at 13:30"
+        );
+    }
+
+    #[tokio::test]
+    async fn convert_time_returns_error_for_invalid_time_format() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "25:99".into(),
+            target_timezone: "America/New_York".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid time format"));
+    }
+
+    #[tokio::test]
+    async fn convert_time_returns_error_for_invalid_source_timezone() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "Bad/Zone".into(),
+            time: "12:00".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid timezone"));
+    }
+
+    #[tokio::test]
+    async fn convert_time_trims_whitespace_from_time_input() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "  14:30  ".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+    }
+
+    #[tokio::test]
+    async fn convert_time_accepts_12_hour_input() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "2:30 PM".into(),
+            target_timezone: "Asia/Tokyo".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let source_dt = json["source"]["datetime"].as_str().unwrap();
+        assert!(source_dt.contains("T14:30:00"), "Got: {source_dt}");
+    }
+
+    #[tokio::test]
+    async fn convert_time_normalizes_unicode_digits_and_spaces() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "Ａｓｉａ／Ｔｏｋｙｏ".into(),
+            time: "１４：３０".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false), "{}", extract_text(&result));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["source"]["timezone"], "Asia/Tokyo");
+
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "٢:٣٠\u{202F}PM".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false), "{}", extract_text(&result));
+    }
+
+    #[tokio::test]
+    async fn convert_time_accepts_city_names_when_enabled() {
+        let params = || ConvertTimeParams {
+            source_timezone: "Mumbai".into(),
+            time: "12:00".into(),
+            target_timezone: "berlin".into(),
+            ..Default::default()
+        };
+        let strict = TimeServer::new();
+        let result = strict.convert_time(Parameters(params())).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+
+        let lenient = TimeServer::new().allow_city_names(true);
+        let result = lenient.convert_time(Parameters(params())).await.unwrap();
+        assert_eq!(result.is_error, Some(false), "{}", extract_text(&result));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["source"]["timezone"], "Asia/Kolkata");
+        assert_eq!(json["target"]["timezone"], "Europe/Berlin");
+    }
+
+    #[tokio::test]
+    async fn city_names_do_not_override_abbreviation_rejection() {
+        let server = TimeServer::new().allow_city_names(true);
+        let params = GetCurrentTimeParams {
+            timezone: Some("EST".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(extract_text(&result).contains("ambiguous"));
+    }
+
+    #[tokio::test]
+    async fn convert_time_accepts_offsets_only_when_allowed() {
+        let server = TimeServer::new();
+        let params = |allow_offsets| ConvertTimeParams {
+            source_timezone: "UTC+3".into(),
+            time: "12:00".into(),
+            target_timezone: "UTC".into(),
+            allow_offsets,
+            ..Default::default()
+        };
+        let result = server
+            .convert_time(Parameters(params(false)))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+
+        let result = server.convert_time(Parameters(params(true))).await.unwrap();
+        assert_eq!(result.is_error, Some(false), "{}", extract_text(&result));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["source"]["timezone"], "+03:00");
+        assert_eq!(json["source"]["fixed_offset"], true);
+        assert!(json["target"].get("fixed_offset").is_none());
+        assert!(
+            json["target"]["datetime"]
+                .as_str()
+                .unwrap()
+                .contains("T09:00:00")
+        );
+        assert!(json["warning"].as_str().unwrap().contains("DST-unaware"));
+    }
+
+    #[tokio::test]
+    async fn convert_time_accepts_bare_digits_in_lenient_mode() {
+        let params = || ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "1430".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let strict = TimeServer::new();
+        let result = strict.convert_time(Parameters(params())).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+
+        let lenient = TimeServer::new().lenient_parsing(true);
+        let result = lenient.convert_time(Parameters(params())).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let source_dt = json["source"]["datetime"].as_str().unwrap();
+        assert!(source_dt.contains("T14:30:00"), "Got: {source_dt}");
+    }
+
+    #[tokio::test]
+    async fn convert_time_uses_the_given_date() {
+        let server = TimeServer::new();
+        let params = |time: &str, target: &str, date: &str| ConvertTimeParams {
+            source_timezone: "America/New_York".into(),
+            time: time.into(),
+            target_timezone: target.into(),
+            date: Some(date.into()),
+            ..Default::default()
+        };
+        // New York has sprung forward; London has not.
+        let result = server
+            .convert_time(Parameters(params("09:00", "Europe/London", "2025-03-10")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["source"]["datetime"], "2025-03-10T09:00:00-04:00");
+        assert_eq!(json["target"]["datetime"], "2025-03-10T13:00:00+00:00");
+        assert_eq!(json["time_difference"], "+4:00");
+
+        let result = server
+            .convert_time(Parameters(params("8pm", "Asia/Tokyo", "2025-03-10")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["source"]["date"], "2025-03-10");
+        assert_eq!(json["target"]["date"], "2025-03-11");
+
+        let result = server
+            .convert_time(Parameters(params("09:00", "UTC", "2025-02-30")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_date");
+        assert_eq!(json["offending_value"], "2025-02-30");
+    }
+
+    #[tokio::test]
+    async fn convert_time_rejects_24_00() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "24:00".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid time format"));
+    }
+
+    #[tokio::test]
+    async fn convert_time_rejects_time_with_seconds() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "14:30:00".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid time format"));
+    }
+
+    // --- get_current_time: missing tests ---
+
+    #[tokio::test]
+    async fn get_current_time_returns_times_for_comma_separated_zones() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("UTC, Asia/Tokyo,America/Denver,".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(result.structured_content.as_ref(), Some(&json));
+        let zones: Vec<&str> = json["times"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["timezone"].as_str().unwrap())
+            .collect();
+        assert_eq!(zones, vec!["UTC", "Asia/Tokyo", "America/Denver"]);
+    }
+
+    #[tokio::test]
+    async fn get_current_time_rejects_list_with_invalid_zone() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("UTC, Not/AZone".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(extract_text(&result).contains("Not/AZone"));
+    }
+
+    #[tokio::test]
+    async fn get_current_time_includes_localized_display_name() {
+        let server = TimeServer::new()
+            .allow_city_names(true)
+            .language(Language::Spanish);
+        let params = GetCurrentTimeParams {
+            timezone: Some("Moskau".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["timezone"], "Europe/Moscow");
+        assert_eq!(json["display_name"], "Moscú");
+
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("Europe/Moscow".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert!(json.get("display_name").is_none());
+    }
+
+    #[tokio::test]
+    async fn get_current_time_deprecated_timezone() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("US/Eastern".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        // US/Eastern is a backward-compat IANA link; should resolve successfully
+        assert!(json["datetime"].is_string());
+        assert!(json["utc_offset"].is_string());
+    }
+
+    #[tokio::test]
+    async fn get_current_time_dst_true() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("America/New_York".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        // Structural test: is_dst key exists and is boolean.
+        // The actual value depends on when the test runs (DST vs standard time).
+        assert!(
+            json["is_dst"].is_boolean(),
+            "Expected is_dst to be a boolean, got: {:?}",
+            json["is_dst"]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_current_time_abbreviation_timezone() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("EST".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(
+            text.contains("IANA timezone name"),
+            "Error should suggest IANA name, got: {text}"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_current_time_offset_timezone() {
+        let server = TimeServer::new();
+        let params = GetCurrentTimeParams {
+            timezone: Some("UTC+5".into()),
+            ..Default::default()
+        };
+        let result = server.get_current_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(
+            text.contains("IANA timezone name"),
+            "Error should suggest IANA name, got: {text}"
+        );
+    }
+
+    // --- convert_time: missing tests ---
+
+    #[tokio::test]
+    async fn convert_time_fractional_offset() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "12:00".into(),
+            target_timezone: "Asia/Kathmandu".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        let target_dt = json["target"]["datetime"].as_str().unwrap();
+        assert!(
+            target_dt.contains("17:45"),
+            "Expected 17:45 for UTC+5:45, got: {target_dt}"
+        );
+    }
+
+    #[tokio::test]
+    async fn convert_time_invalid_target_timezone() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "12:00".into(),
+            target_timezone: "Bad/Zone".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid timezone"));
+    }
+
+    #[tokio::test]
+    async fn convert_time_non_numeric_time() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "abc".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(text.contains("Invalid time format"));
+    }
+
+    #[tokio::test]
+    async fn convert_time_midnight() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "00:00".into(),
+            target_timezone: "America/New_York".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        let source_dt = json["source"]["datetime"].as_str().unwrap();
+        assert!(
+            source_dt.contains("00:00"),
+            "Source should be midnight, got: {source_dt}"
+        );
+        // NY is UTC-5 or UTC-4 — target should be previous day 19:00 or 20:00
+        let target_dt = json["target"]["datetime"].as_str().unwrap();
+        assert!(
+            target_dt.contains("19:00") || target_dt.contains("20:00"),
+            "Expected 19:00 or 20:00 for midnight UTC->NY, got: {target_dt}"
+        );
+    }
+
+    #[tokio::test]
+    async fn convert_time_end_of_day() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "23:59".into(),
+            target_timezone: "Asia/Tokyo".into(), // UTC+9
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let text = extract_text(&result);
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        // 23:59 UTC + 9h = 08:59 next day in Tokyo
+        let target_dt = json["target"]["datetime"].as_str().unwrap();
+        assert!(
+            target_dt.contains("08:59"),
+            "Expected 08:59 for 23:59 UTC->Tokyo, got: {target_dt}"
+        );
+    }
+
+    #[tokio::test]
+    async fn convert_time_iso_datetime_input() {
+        let server = TimeServer::new();
+        let params = ConvertTimeParams {
+            source_timezone: "UTC".into(),
+            time: "2026-02-24T14:30:00".into(),
+            target_timezone: "UTC".into(),
+            ..Default::default()
+        };
+        let result = server.convert_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let text = extract_text(&result);
+        assert!(
+            text.contains("Invalid time format"),
+            "Should reject ISO datetime, got: {text}"
+        );
+    }
+
+    fn evaluate_params(expression: &str) -> EvaluateParams {
+        EvaluateParams {
+            expression: expression.into(),
+            timezone: Some("America/New_York".into()),
+            reference: Some("2025-07-02T15:00".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn evaluate_applies_steps_in_order() {
+        let server = TimeServer::new();
+        let result = server
+            .evaluate(Parameters(evaluate_params(
+                "now + 3 days - 2 hours in Asia/Tokyo",
+            )))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"]["kind"], "instant");
+        assert_eq!(json["result"]["datetime"], "2025-07-06T02:00:00+09:00");
+        assert_eq!(json["result"]["timezone"], "Asia/Tokyo");
+        let operations: Vec<&str> = json["steps"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["operation"].as_str().unwrap())
+            .collect();
+        assert_eq!(operations, vec!["start", "add", "subtract", "convert"]);
+
+        let result = server
+            .evaluate(Parameters(evaluate_params(
+                "2025-07-01T09:00 Europe/Paris to America/Chicago + 45m",
+            )))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"]["datetime"], "2025-07-01T02:45:00-05:00");
+    }
+
+    #[tokio::test]
+    async fn evaluate_keeps_wall_clock_across_dst() {
+        let server = TimeServer::new();
+        let result = server
+            .evaluate(Parameters(evaluate_params(
+                "2025-03-08T09:00 America/New_York + 1 day",
+            )))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"]["datetime"], "2025-03-09T09:00:00-04:00");
+    }
+
+    #[tokio::test]
+    async fn evaluate_returns_differences_as_durations() {
+        let server = TimeServer::new();
+        let result = server
+            .evaluate(Parameters(evaluate_params(
+                "2025-07-04T17:00 America/New_York - 2025-07-04T09:00 Europe/London",
+            )))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"]["kind"], "duration");
+        assert_eq!(json["result"]["iso8601"], "PT13H");
+        assert_eq!(json["result"]["total_seconds"], 46800.0);
+
+        let result = server
+            .evaluate(Parameters(evaluate_params("now - now")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"]["iso8601"], "PT0S");
+        assert_eq!(json["result"]["total_seconds"], 0.0);
+        assert_eq!(json["steps"][1]["operand"], "now");
+
+        let result = server
+            .evaluate(Parameters(evaluate_params("now - today")))
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"]["iso8601"], "PT15H");
+    }
+
+    #[tokio::test]
+    async fn evaluate_rejects_malformed_expressions() {
+        let server = TimeServer::new();
+        let result = server
+            .evaluate(Parameters(evaluate_params("now plus a bit")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_expression");
+        assert_eq!(json["offending_value"], "now plus a bit");
+        assert!(
+            json["message"]
+                .as_str()
+                .unwrap()
+                .contains("Expected: <start>")
+        );
+
+        let result = server
+            .evaluate(Parameters(evaluate_params("now in Mars/Olympus")))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    fn explain_time_params(timestamp: &str, timezone: Option<&str>) -> ExplainTimeParams {
+        ExplainTimeParams {
+            timestamp: timestamp.into(),
+            timezone: timezone.map(Into::into),
+            reference: Some("2025-07-01T09:30:00-04:00".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn explain_time_breaks_down_epoch_values() {
+        let server = TimeServer::new();
+        let params = explain_time_params("1751644800000", Some("America/New_York"));
+        let result = server.explain_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["interpreted_as"], "epoch_milliseconds");
+        assert_eq!(json["datetime"], "2025-07-04T12:00:00-04:00");
+        assert_eq!(json["components"]["day_of_year"], 185);
+        assert_eq!(json["weekday"], "Friday");
+        assert_eq!(json["iso_week"], "2025-W27-5");
+        assert_eq!(json["utc"], "2025-07-04T16:00:00Z");
+        assert_eq!(json["unix_seconds"], 1_751_644_800);
+        assert_eq!(json["abbreviation"], "EDT");
+        assert_eq!(json["is_dst"], true);
+        assert_eq!(json["relative"], "in 3d 2h 30m");
+        assert_eq!(
+            json["previous_transition"]["datetime"],
+            "2025-03-09T03:00:00-04:00"
+        );
+        assert_eq!(json["next_transition"]["utc_offset_after"], "-05:00");
+        assert!(json.get("warning").is_none());
+    }
+
+    #[tokio::test]
+    async fn explain_time_reads_other_formats() {
+        let server = TimeServer::new();
+        let params = explain_time_params("Sun, 1 Jun 2025 12:00:00 +0000", None);
+        let result = server.explain_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["interpreted_as"], "rfc2822");
+        assert_eq!(json["timezone"], "UTC");
+        assert_eq!(json["relative"], "1mo 1h 30m ago");
+        assert!(json.get("next_transition").is_none());
+
+        let params = explain_time_params("2025-01-01T00:00", Some("Asia/Tokyo"));
+        let result = server.explain_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["interpreted_as"], "local_datetime");
+        assert_eq!(json["utc"], "2024-12-31T15:00:00Z");
+        assert_eq!(json["iso_week"], "2025-W01-3");
+
+        let params = explain_time_params("42", None);
+        let result = server.explain_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["interpreted_as"], "epoch_seconds");
+        assert!(json["warning"].as_str().unwrap().contains("digit count"));
+    }
+
+    #[tokio::test]
+    async fn explain_time_rejects_unreadable_timestamps() {
+        let server = TimeServer::new();
+        let params = explain_time_params("next tuesday-ish", None);
+        let result = server.explain_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_datetime");
+        assert_eq!(json["offending_value"], "next tuesday-ish");
+    }
+
+    /// Resolve `text` in America/New_York relative to Wednesday
+    /// 2025-07-02 at 10:00.
+    async fn parse_time_json(text: &str) -> (bool, serde_json::Value) {
+        let server = TimeServer::new();
+        let params = ParseTimeParams {
+            text: text.into(),
+            timezone: Some("America/New_York".into()),
+            reference: Some("2025-07-02T10:00".into()),
+        };
+        let result = server.parse_time(Parameters(params)).await.unwrap();
+        let json = serde_json::from_str(&extract_text(&result)).unwrap();
+        (result.is_error == Some(true), json)
+    }
+
+    #[tokio::test]
+    async fn parse_time_resolves_days_and_times() {
+        let (is_error, json) = parse_time_json("tomorrow at 3pm").await;
+        assert!(!is_error);
+        assert_eq!(json["datetime"], "2025-07-03T15:00:00-04:00");
+        assert_eq!(json["utc"], "2025-07-03T19:00:00Z");
+        assert_eq!(json["weekday"], "Thursday");
+        assert_eq!(json["reference"], "2025-07-02T10:00:00-04:00");
+        assert_eq!(json["relative"], "in 1d 5h");
+
+        let (_, json) = parse_time_json("next Friday 09:00").await;
+        assert_eq!(json["datetime"], "2025-07-04T09:00:00-04:00");
+        let (_, json) = parse_time_json("last wednesday").await;
+        assert_eq!(json["datetime"], "2025-06-25T00:00:00-04:00");
+        let (_, json) = parse_time_json("2025-07-04T12:00").await;
+        assert_eq!(json["datetime"], "2025-07-04T12:00:00-04:00");
+    }
+
+    #[tokio::test]
+    async fn parse_time_counts_durations() {
+        let (_, json) = parse_time_json("in 2 hours").await;
+        assert_eq!(json["datetime"], "2025-07-02T12:00:00-04:00");
+        let (_, json) = parse_time_json("3 days ago").await;
+        assert_eq!(json["datetime"], "2025-06-29T10:00:00-04:00");
+        let (_, json) = parse_time_json("2 days after next friday noon").await;
+        assert_eq!(json["datetime"], "2025-07-06T12:00:00-04:00");
+        let (_, json) = parse_time_json("the day after tomorrow").await;
+        assert_eq!(json["datetime"], "2025-07-04T00:00:00-04:00");
+    }
+
+    #[tokio::test]
+    async fn parse_time_notes_skipped_times() {
+        let (_, json) = parse_time_json("2025-03-09 at 2:30am").await;
+        assert_eq!(json["datetime"], "2025-03-09T03:30:00-04:00");
+        let note = json["note"].as_str().unwrap();
+        assert!(note.contains("does not exist"), "Note was: {note}");
+    }
+
+    #[tokio::test]
+    async fn parse_time_rejects_unknown_phrases() {
+        let (is_error, json) = parse_time_json("sometime soonish").await;
+        assert!(is_error);
+        assert_eq!(json["code"], "invalid_time_expression");
+        assert_eq!(json["offending_value"], "sometime soonish");
+        assert!(
+            json["message"]
+                .as_str()
+                .unwrap()
+                .contains("next Friday 09:00")
+        );
+    }
+
+    fn time_arithmetic_params(datetime: &str, duration: &str) -> TimeArithmeticParams {
+        TimeArithmeticParams {
+            datetime: datetime.into(),
+            timezone: Some("America/New_York".into()),
+            duration: duration.into(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn add_time_follows_the_local_calendar() {
+        let server = TimeServer::new();
+        let params = time_arithmetic_params("2025-03-08T09:00", "1 day");
+        let result = server.add_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"], "2025-03-09T09:00:00-04:00");
+        assert!(json.get("note").is_none());
+
+        let params = time_arithmetic_params("2025-03-08T09:00", "PT24H");
+        let result = server.add_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"], "2025-03-09T10:00:00-04:00");
+    }
+
+    #[cfg(feature = "locale")]
+    #[tokio::test]
+    async fn add_time_writes_the_result_out_for_locale() {
+        let server = TimeServer::new();
+        let params = TimeArithmeticParams {
+            locale: Some("en-US".into()),
+            ..time_arithmetic_params("2025-03-08T09:00", "1 day")
+        };
+        let result = server.add_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["human_readable"], "Sunday, March 9, 2025 at 9:00 AM");
+
+        let params = TimeArithmeticParams {
+            locale: Some("tlh".into()),
+            ..time_arithmetic_params("2025-03-08T09:00", "1 day")
+        };
+        let result = server.subtract_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_locale");
+    }
+
+    #[tokio::test]
+    async fn add_time_notes_gaps_and_folds() {
+        let server = TimeServer::new();
+        let params = time_arithmetic_params("2025-03-08T02:30", "1 day");
+        let result = server.add_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"], "2025-03-09T03:30:00-04:00");
+        assert!(json["note"].as_str().unwrap().contains("does not exist"));
+
+        let params = time_arithmetic_params("2025-11-01T01:30", "1 day");
+        let result = server.add_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["result"], "2025-11-02T01:30:00-04:00");
+        assert!(json["note"].as_str().unwrap().contains("occurs twice"));
+    }
+
+    #[tokio::test]
+    async fn subtract_time_counts_backward() {
+        let server = TimeServer::new();
+        let params = time_arithmetic_params("2025-03-10T09:00", "3 days 4 hours");
+        let result = server.subtract_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["duration"], "P3DT4H");
+        assert_eq!(json["result"], "2025-03-07T05:00:00-05:00");
+
+        let params = time_arithmetic_params("2025-07-07T09:00", "2 business days");
+        let result = server.subtract_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["business_days"], 2);
+        assert_eq!(json["result"], "2025-07-03T09:00:00-04:00");
+    }
+
+    #[tokio::test]
+    async fn add_time_rejects_anchored_durations() {
+        let server = TimeServer::new();
+        let params = time_arithmetic_params("now", "3 days from Friday");
+        let result = server.add_time(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_duration");
+    }
+
+    fn time_difference_params(start: &str, end: &str) -> TimeDifferenceParams {
+        TimeDifferenceParams {
+            start: start.into(),
+            end: end.into(),
+            timezone: Some("America/New_York".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn time_difference_breaks_down_calendar_units() {
+        let server = TimeServer::new();
+        let params = time_difference_params("2025-03-08T12:00", "2025-04-10T15:30:20");
+        let result = server.time_difference(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["breakdown"]["months"], 1);
+        assert_eq!(json["breakdown"]["days"], 2);
+        assert_eq!(json["breakdown"]["hours"], 3);
+        assert_eq!(json["breakdown"]["minutes"], 30);
+        assert_eq!(json["breakdown"]["seconds"], 20);
+        assert_eq!(json["iso8601"], "P1M2DT3H30M20S");
+        // One hour shorter than the calendar suggests: DST began on March 9.
+        assert_eq!(json["total_seconds"], 2_860_220.0);
+        assert!(json.get("negative").is_none());
+    }
+
+    #[tokio::test]
+    async fn time_difference_reports_negative_differences() {
+        let server = TimeServer::new();
+        let params = time_difference_params(
+            "2025-07-04T17:00:00-04:00",
+            "2025-07-04T09:00[Europe/London]",
+        );
+        let result = server.time_difference(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["breakdown"]["hours"], -13);
+        assert_eq!(json["iso8601"], "-PT13H");
+        assert_eq!(json["description"], "13h");
+        assert_eq!(json["total_seconds"], -46_800.0);
+        assert_eq!(json["negative"], true);
+    }
+
+    #[tokio::test]
+    async fn time_difference_rejects_invalid_datetimes() {
+        let server = TimeServer::new();
+        let params = time_difference_params("2025-07-04", "last tuesday");
+        let result = server.time_difference(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_datetime");
+        assert_eq!(json["offending_value"], "last tuesday");
+    }
+
+    #[tokio::test]
+    async fn humanize_time_phrases_and_rounds_differences() {
+        // 2025-07-04T12:00:00Z
+        let server = TimeServer::new().clock(crate::clock::FixedClock(
+            jiff::Timestamp::from_second(1_751_630_400).unwrap(),
+        ));
+        let humanize = async |datetime: &str, granularity, max_units| {
+            let params = HumanizeTimeParams {
+                datetime: datetime.into(),
+                granularity,
+                max_units,
+                ..Default::default()
+            };
+            let result = server.humanize_time(Parameters(params)).await.unwrap();
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            (result.is_error, json)
+        };
+
+        let (_, json) = humanize("2025-07-04T08:39:45Z", PhraseUnit::Second, None).await;
+        assert_eq!(json["phrase"], "3 hours ago");
+        assert_eq!(json["direction"], "past");
+        assert_eq!(json["delta"], "-PT3H20M15S");
+        assert_eq!(json["total_seconds"], -12015.0);
+        let (_, json) = humanize("2025-07-04T08:39:45Z", PhraseUnit::Second, Some(2)).await;
+        assert_eq!(json["phrase"], "3 hours 20 minutes ago");
+        // 3h 40m rounds up to 4 hours.
+        let (_, json) = humanize("2025-07-04T15:40:00Z", PhraseUnit::Second, None).await;
+        assert_eq!(json["phrase"], "in 4 hours");
+        assert_eq!(json["direction"], "future");
+        let (_, json) = humanize("2025-07-06T17:00:00Z", PhraseUnit::Second, Some(3)).await;
+        assert_eq!(json["phrase"], "in 2 days 5 hours");
+        let (_, json) = humanize("2025-07-04T11:59:50Z", PhraseUnit::Minute, None).await;
+        assert_eq!(json["phrase"], "now");
+        assert_eq!(json["direction"], "now");
+        assert_eq!(json["delta"], "-PT10S");
+        // Epoch milliseconds, 90 seconds before the reference.
+        let (_, json) = humanize("1751630310000", PhraseUnit::Second, Some(2)).await;
+        assert_eq!(json["phrase"], "1 minute 30 seconds ago");
+
+        let (is_error, json) = humanize("2025-07-04", PhraseUnit::Second, Some(0)).await;
+        assert_eq!(is_error, Some(true));
+        assert_eq!(json["code"], "invalid_arguments");
+        let (is_error, json) = humanize("yesterdayish", PhraseUnit::Second, None).await;
+        assert_eq!(is_error, Some(true));
+        assert_eq!(json["code"], "invalid_datetime");
+    }
+
+    #[tokio::test]
+    async fn humanize_time_counts_days_in_the_timezone() {
+        let server = TimeServer::new();
+        // Clocks go forward overnight, so the day is 23 hours long.
+        let params = HumanizeTimeParams {
+            datetime: "2025-03-09T12:00".into(),
+            reference: Some("2025-03-08T12:00".into()),
+            timezone: Some("America/New_York".into()),
+            granularity: PhraseUnit::Second,
+            ..Default::default()
+        };
+        let result = server.humanize_time(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["phrase"], "in 1 day");
+        assert_eq!(json["delta"], "P1D");
+        assert_eq!(json["total_seconds"], 82800.0);
+        assert_eq!(json["reference"], "2025-03-08T12:00:00-05:00");
+    }
+
+    async fn travel_arrival(
+        departure: &str,
+        from: &str,
+        duration: &str,
+        to: &str,
+    ) -> (Option<bool>, serde_json::Value) {
+        let params = TravelArrivalParams {
+            departure: departure.into(),
+            departure_timezone: from.into(),
+            duration: duration.into(),
+            arrival_timezone: to.into(),
+        };
+        let result = TimeServer::new()
+            .travel_arrival(Parameters(params))
+            .await
+            .unwrap();
+        let json = serde_json::from_str(&extract_text(&result)).unwrap();
+        (result.is_error, json)
+    }
+
+    #[tokio::test]
+    async fn travel_arrival_reports_local_arrival_and_day_change() {
+        let (is_error, json) = travel_arrival(
+            "2025-07-04T22:15",
+            "America/New_York",
+            "7h 25m",
+            "Europe/London",
+        )
+        .await;
+        assert_eq!(is_error, Some(false));
+        assert_eq!(json["departure"]["datetime"], "2025-07-04T22:15:00-04:00");
+        assert_eq!(json["arrival"]["datetime"], "2025-07-05T10:40:00+01:00");
+        assert_eq!(json["duration"], "PT7H25M");
+        assert_eq!(json["description"], "7 hours 25 minutes");
+        assert_eq!(json["elapsed_seconds"], 26700.0);
+        assert_eq!(json["time_difference"], "+5:00");
+        assert_eq!(json["wall_clock_difference"], "PT12H25M");
+        assert_eq!(json["day_change"], 1);
+        assert_eq!(json["day_change_label"], "+1 day");
+        let summary = json["summary"].as_str().unwrap();
+        assert!(
+            summary.contains("lands 10:40 on Sat 2025-07-05 local time in Europe/London (+1 day)"),
+            "{summary}"
+        );
+
+        // Flying east to west across the date line lands the day before.
+        let (_, json) =
+            travel_arrival("2025-07-05T01:00", "Asia/Tokyo", "PT8H", "Pacific/Honolulu").await;
+        assert_eq!(json["arrival"]["datetime"], "2025-07-04T14:00:00-10:00");
+        assert_eq!(json["wall_clock_difference"], "-PT11H");
+        assert_eq!(json["day_change"], -1);
+        assert_eq!(json["day_change_label"], "-1 day");
+        let summary = json["summary"].as_str().unwrap();
+        assert!(summary.contains("moves back 11 hours"), "{summary}");
+        assert!(json.get("note").is_none());
+    }
+
+    #[tokio::test]
+    async fn travel_arrival_applies_dst_and_flags_skipped_departures() {
+        // New York springs forward during the flight's departure hour.
+        let (_, json) = travel_arrival(
+            "2025-03-09T02:30",
+            "America/New_York",
+            "3 hours",
+            "America/Los_Angeles",
+        )
+        .await;
+        assert_eq!(json["departure"]["datetime"], "2025-03-09T03:30:00-04:00");
+        assert_eq!(json["arrival"]["datetime"], "2025-03-09T03:30:00-07:00");
+        assert!(json["note"].as_str().unwrap().contains("does not exist"));
+        // RFC 3339 departures are exact, so carry no note.
+        let (_, json) = travel_arrival(
+            "2025-03-09T07:30:00Z",
+            "America/New_York",
+            "3 hours",
+            "America/Los_Angeles",
+        )
+        .await;
+        assert!(json.get("note").is_none());
+    }
+
+    #[tokio::test]
+    async fn travel_arrival_rejects_bad_arguments() {
+        for duration in ["2 days", "3 hours ago", "in 2 hours", "soon"] {
+            let (is_error, json) =
+                travel_arrival("2025-07-04T22:15", "UTC", duration, "Asia/Tokyo").await;
+            assert_eq!(is_error, Some(true), "{duration}");
+            assert_eq!(json["code"], "invalid_duration", "{duration}");
+        }
+        let (_, json) = travel_arrival("2025-07-04T22:15", "Mars/Olympus", "2h", "UTC").await;
+        assert_eq!(json["code"], "invalid_timezone");
+        let (_, json) = travel_arrival("tonight", "UTC", "2h", "UTC").await;
+        assert_eq!(json["code"], "invalid_datetime");
+    }
+
+    fn time_until_params(target: &str, date_only: DayBoundary) -> TimeUntilParams {
+        TimeUntilParams {
+            target: target.into(),
+            timezone: Some("Europe/Berlin".into()),
+            date_only,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn time_until_counts_down_across_dst() {
+        let now: jiff::Timestamp = "2026-03-27T12:00:00Z".parse().unwrap();
+        let server = TimeServer::new().clock(crate::clock::FixedClock(now));
+        let params = time_until_params("2026-03-31", DayBoundary::EndOfDay);
+        let result = server.time_until(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["target"], "2026-04-01T00:00:00+02:00");
+        assert_eq!(json["now"], "2026-03-27T13:00:00+01:00");
+        // Clocks go forward on 2026-03-29, so four days are an hour short.
+        assert_eq!(json["remaining"], "4 days, 11 hours");
+        assert_eq!(json["total_seconds"], 381_600);
+        assert_eq!(json["passed"], false);
+        assert!(json["note"].as_str().unwrap().contains("end of that day"));
+
+        let params = time_until_params("2026-03-31", DayBoundary::StartOfDay);
+        let result = server.time_until(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["target"], "2026-03-31T00:00:00+02:00");
+        assert_eq!(json["remaining"], "3 days, 11 hours");
+    }
+
+    #[tokio::test]
+    async fn time_until_reports_passed_deadlines() {
+        let now: jiff::Timestamp = "2026-03-27T12:00:00Z".parse().unwrap();
+        let server = TimeServer::new().clock(crate::clock::FixedClock(now));
+        let params = time_until_params("2026-03-27T11:00:00Z", DayBoundary::EndOfDay);
+        let result = server.time_until(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["remaining"], "1 hour");
+        assert_eq!(json["total_seconds"], -3600);
+        assert_eq!(json["passed"], true);
+        assert!(json.get("note").is_none());
+
+        let params = time_until_params("next week", DayBoundary::EndOfDay);
+        let result = server.time_until(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_datetime");
+    }
+
+    #[cfg(feature = "locale")]
+    #[tokio::test]
+    async fn time_until_writes_the_deadline_out_for_locale() {
+        let now: jiff::Timestamp = "2026-03-27T12:00:00Z".parse().unwrap();
+        let server = TimeServer::new().clock(crate::clock::FixedClock(now));
+        let params = TimeUntilParams {
+            locale: Some("en-GB".into()),
+            ..time_until_params("2026-03-31T17:00", DayBoundary::EndOfDay)
+        };
+        let result = server.time_until(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["human_readable"], "Tuesday 31 March 2026 at 17:00");
+    }
+
+    fn agenda_event(title: &str, start: &str, end: &str) -> AgendaEvent {
+        AgendaEvent {
+            title: title.into(),
+            start: start.into(),
+            end: end.into(),
+            timezone: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn convert_agenda_flags_events_on_another_day() {
+        let server = TimeServer::new();
+        let params = ConvertAgendaParams {
+            events: vec![
+                agenda_event("Closing panel", "2025-09-16T17:00", "2025-09-16T18:00"),
+                agenda_event("Keynote", "2025-09-16T09:00", "2025-09-16T10:00"),
+            ],
+            source_timezone: Some("Europe/Lisbon".into()),
+            target_timezones: vec!["America/Los_Angeles".into(), "Asia/Tokyo".into()],
+        };
+        let result = server.convert_agenda(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+
+        let la = &json["schedules"][0];
+        assert_eq!(la["timezone"], "America/Los_Angeles");
+        assert_eq!(la["events"][0]["title"], "Keynote");
+        assert_eq!(la["events"][0]["start"], "2025-09-16T01:00:00-07:00");
+        assert_eq!(la["different_day_count"], 0);
+
+        let tokyo = &json["schedules"][1];
+        assert_eq!(tokyo["events"][0]["day_shift"], 0);
+        assert_eq!(tokyo["events"][1]["title"], "Closing panel");
+        assert_eq!(tokyo["events"][1]["start"], "2025-09-17T01:00:00+09:00");
+        assert_eq!(tokyo["events"][1]["day_shift"], 1);
+        assert_eq!(tokyo["different_day_count"], 1);
+    }
+
+    #[tokio::test]
+    async fn convert_agenda_flags_events_crossing_midnight() {
+        let server = TimeServer::new();
+        let mut late = agenda_event("Late session", "2025-09-16T14:00", "2025-09-16T16:00");
+        late.timezone = Some("America/New_York".into());
+        let params = ConvertAgendaParams {
+            events: vec![
+                late,
+                agenda_event("Until midnight", "2025-09-16T22:00", "2025-09-17T00:00"),
+            ],
+            target_timezones: vec!["Asia/Kolkata".into(), "UTC".into()],
+            ..Default::default()
+        };
+        let result = server.convert_agenda(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let kolkata = &json["schedules"][0]["events"];
+        assert_eq!(kolkata[0]["start"], "2025-09-16T23:30:00+05:30");
+        assert_eq!(kolkata[0]["crosses_midnight"], true);
+        let utc = &json["schedules"][1]["events"];
+        assert_eq!(utc[1]["title"], "Until midnight");
+        assert!(utc[1].get("crosses_midnight").is_none());
+    }
+
+    #[tokio::test]
+    async fn convert_agenda_rejects_events_ending_before_they_start() {
+        let server = TimeServer::new();
+        let params = ConvertAgendaParams {
+            events: vec![agenda_event(
+                "Backwards",
+                "2025-09-16T10:00",
+                "2025-09-16T09:00",
+            )],
+            target_timezones: vec!["UTC".into()],
+            ..Default::default()
+        };
+        let result = server.convert_agenda(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_event");
+        assert_eq!(json["offending_value"], "Backwards");
+    }
+}
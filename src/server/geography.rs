@@ -0,0 +1,828 @@
+use rmcp::{handler::server::wrapper::Parameters, model::CallToolResult, tool, tool_router};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Number of nearby zones listed by `timezone_geo`.
+const NEIGHBOR_COUNT: usize = 5;
+
+/// Page size of `list_timezones` when no limit is given.
+const DEFAULT_LIST_LIMIT: usize = 100;
+
+/// Largest page `list_timezones` returns.
+const MAX_LIST_LIMIT: usize = 1000;
+
+/// Error message template for zones without location data.
+const ERR_NO_GEOGRAPHY: &str = "No geographic data for '{}': it is not tied to a location. Use a regional zone name such as 'Europe/Berlin'.";
+
+/// Parameters for the `timezone_geo` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct TimezoneGeoParams {
+    /// IANA timezone name (e.g., 'Europe/Berlin', 'America/New_York')
+    #[schemars(transform = schema::timezone)]
+    pub timezone: String,
+}
+
+/// Parameters for the `list_timezones` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ListTimezonesParams {
+    /// Case-insensitive text the zone name must contain; spaces match underscores (e.g., 'kolk', 'new york')
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Region the zone name must start with (e.g., 'Europe/', 'America/Argentina')
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Current UTC offset the zone must have (e.g., '+05:30', '-03:00', 'UTC+9')
+    #[serde(default)]
+    pub utc_offset: Option<String>,
+    /// Maximum number of zones to return, from 1 to 1000. Defaults to 100.
+    #[serde(default)]
+    #[schemars(range(min = 1, max = MAX_LIST_LIMIT))]
+    pub limit: Option<usize>,
+    /// Position to continue from, as returned in `next_cursor`. Defaults to 0.
+    #[serde(default)]
+    pub cursor: Option<usize>,
+}
+
+/// Parameters for the `get_sun_times` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct GetSunTimesParams {
+    /// Latitude in decimal degrees, north positive (e.g., 51.5074). Give with longitude, or give city instead.
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    /// Longitude in decimal degrees, east positive (e.g., -0.1278).
+    #[serde(default)]
+    pub longitude: Option<f64>,
+    /// City name (e.g., 'London', 'San Francisco') or IANA timezone name, whose principal city is used, instead of coordinates.
+    #[serde(default)]
+    pub city: Option<String>,
+    /// Date (YYYY-MM-DD). Defaults to today in the timezone.
+    #[serde(default)]
+    #[schemars(transform = schema::date)]
+    pub date: Option<String>,
+    /// IANA timezone the times are given in. Defaults to the city's zone, or for coordinates to the zone of the nearest tz database city.
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Also write sunrise, solar noon, and sunset out for a locale in `human_readable` (e.g., 'de-DE', 'en-US', 'ja'), as for `get_current_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// One zone in a `list_timezones` page.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ListedZone {
+    timezone: String,
+    utc_offset: String,
+    abbreviation: String,
+}
+
+/// Response payload for `list_timezones`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ListTimezonesResponse {
+    /// Number of zones matching the filters, across all pages.
+    total: usize,
+    zones: Vec<ListedZone>,
+    /// Cursor for the next page, when more zones match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<usize>,
+}
+
+/// A country using a zone.
+#[derive(Debug, Serialize, JsonSchema)]
+struct Country {
+    /// ISO 3166 alpha-2 code.
+    code: String,
+    name: Option<&'static str>,
+}
+
+/// Response payload for `timezone_geo`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct TimezoneGeoResponse {
+    timezone: String,
+    /// Countries using the zone, principal one first.
+    countries: Vec<Country>,
+    /// Part of the countries the zone covers, when they have several zones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+    /// Location of the zone's principal city.
+    coordinates: locations::Coordinates,
+    major_cities: Vec<String>,
+    /// Closest other zones by distance between principal cities.
+    neighbors: Vec<locations::NeighborZone>,
+}
+
+/// Sunrise, solar noon, and sunset written out for a locale.
+#[derive(Debug, Serialize, JsonSchema)]
+struct LocalizedSunTimes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sunrise: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solar_noon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sunset: Option<String>,
+}
+
+/// Response payload for `get_sun_times`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct SunTimesResponse {
+    date: String,
+    timezone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city: Option<&'static str>,
+    coordinates: locations::Coordinates,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sunrise: Option<String>,
+    /// When the sun is highest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solar_noon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sunset: Option<String>,
+    /// The times written out for the requested `locale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    human_readable: Option<LocalizedSunTimes>,
+    /// Time from sunrise to sunset as HH:MM:SS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day_length: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day_length_seconds: Option<i64>,
+    /// Set when the sun neither rises nor sets that day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    polar: Option<sun::Polar>,
+    /// How the timezone was chosen, when it was inferred from coordinates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Response payload for `detect_local_timezone`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct LocalTimezoneResponse {
+    /// IANA name of the host's zone, when it can be determined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    datetime: String,
+    utc_offset: String,
+    abbreviation: String,
+    is_dst: bool,
+    /// Where the zone was read from: the TZ environment variable or the
+    /// system configuration.
+    source: &'static str,
+    /// Zone tools use when their timezone parameter is omitted.
+    default_timezone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// Response payload for `get_tzdb_info`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct TzdbInfoResponse {
+    #[serde(flatten)]
+    info: tzdb::TzdbInfo,
+    /// Whether the rules come from the system's own copy of the database,
+    /// which system updates keep current, rather than one bundled with the
+    /// server.
+    system: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+#[tool_router(router = geography_router, vis = "pub")]
+impl TimeServer {
+    /// Describe where a timezone is used.
+    #[tool(
+        name = "timezone_geo",
+        description = "Describe where a timezone is used: the countries that use it, the region it covers, representative coordinates, major cities, and the nearest other zones. Useful for helping users pick the right zone.",
+        output_schema = output_schema::<TimezoneGeoResponse>()
+    )]
+    pub async fn timezone_geo(
+        &self,
+        Parameters(params): Parameters<TimezoneGeoParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone(&params.timezone) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let zone = locations::canonical(tz.iana_name().unwrap_or("UTC"));
+        let Some(location) = locations::location(zone) else {
+            return Ok(tool_error(
+                ToolError::new("no_geography", ERR_NO_GEOGRAPHY.replacen("{}", zone, 1))
+                    .with_value(&params.timezone),
+            ));
+        };
+
+        let response = TimezoneGeoResponse {
+            timezone: zone.to_string(),
+            countries: location
+                .countries
+                .iter()
+                .map(|code| Country {
+                    code: code.clone(),
+                    name: locations::country_name(code),
+                })
+                .collect(),
+            region: location.comment.clone(),
+            coordinates: location.coordinates,
+            major_cities: cities::cities_in(zone),
+            neighbors: locations::neighbors(location, NEIGHBOR_COUNT),
+        };
+        tool_success(&response)
+    }
+
+    /// Report when the sun rises and sets at a place.
+    #[tool(
+        name = "get_sun_times",
+        description = "Get sunrise, solar noon, sunset, and day length for a place on a date, in the place's local timezone. Give latitude and longitude, or a city name. Inside the polar circles, days without a sunrise or sunset are flagged as polar night or midnight sun.",
+        output_schema = output_schema::<SunTimesResponse>()
+    )]
+    pub async fn get_sun_times(
+        &self,
+        Parameters(params): Parameters<GetSunTimesParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let locale = match parse_locale(params.locale.as_deref()) {
+            Ok(locale) => locale,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let (coordinates, city, zone) = match (&params.city, params.latitude, params.longitude) {
+            (Some(name), None, None) => {
+                let input = unicode::normalize_input(name);
+                // A zone name stands for its principal city.
+                let place = cities::locate(&input)
+                    .map(|city| (city.coordinates, Some(city.name), city.zone))
+                    .or_else(|| {
+                        let location = locations::location(input.trim())?;
+                        Some((location.coordinates, None, location.zone.as_str()))
+                    });
+                match place {
+                    Some((coordinates, city, zone)) => (coordinates, city, Some(zone)),
+                    None => {
+                        return Ok(tool_error(
+                            ToolError::new(
+                                "invalid_city",
+                                format!(
+                                    "Unknown city: '{name}'. Use a major city (e.g., 'London', 'Mumbai') or pass latitude and longitude."
+                                ),
+                            )
+                            .with_value(name.as_str()),
+                        ));
+                    }
+                }
+            }
+            (None, Some(latitude), Some(longitude)) => {
+                if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+                    let value = format!("{latitude}, {longitude}");
+                    return Ok(tool_error(
+                        ToolError::new(
+                            "invalid_coordinates",
+                            format!(
+                                "Invalid coordinates: {value}. Expected a latitude between -90 and 90 and a longitude between -180 and 180."
+                            ),
+                        )
+                        .with_value(value),
+                    ));
+                }
+                (
+                    locations::Coordinates {
+                        latitude,
+                        longitude,
+                    },
+                    None,
+                    None,
+                )
+            }
+            _ => {
+                return Ok(tool_error(ToolError::new(
+                    "invalid_arguments",
+                    "Provide either latitude and longitude, or city.",
+                )));
+            }
+        };
+
+        let mut note = None;
+        let tz = match (params.timezone.as_deref(), zone) {
+            (Some(input), _) => match self.resolve_timezone(input) {
+                Ok(tz) => tz,
+                Err(msg) => return Ok(tool_error(msg)),
+            },
+            (None, Some(zone)) => jiff::tz::TimeZone::get(zone).unwrap_or(jiff::tz::TimeZone::UTC),
+            (None, None) => match locations::nearest(coordinates) {
+                Some(nearest) => {
+                    note = Some(format!(
+                        "Timezone inferred as {}, the zone of the nearest tz database city; pass timezone if the place uses another zone.",
+                        nearest.zone
+                    ));
+                    jiff::tz::TimeZone::get(&nearest.zone).unwrap_or(jiff::tz::TimeZone::UTC)
+                }
+                None => jiff::tz::TimeZone::UTC,
+            },
+        };
+        let date = match params.date.as_deref() {
+            Some(input) => match parse_date(input) {
+                Ok(date) => date,
+                Err(msg) => return Ok(tool_error(msg)),
+            },
+            None => self.now_in(&tz).date(),
+        };
+
+        let times = sun::sun_times(date, coordinates, &tz);
+        let render = |instant: Option<jiff::Timestamp>| {
+            instant.map(|instant| {
+                instant
+                    .to_zoned(tz.clone())
+                    .strftime("%Y-%m-%dT%H:%M:%S%:z")
+                    .to_string()
+            })
+        };
+        let human_readable = locale.map(|locale| {
+            let write = |instant: Option<jiff::Timestamp>| {
+                instant.map(|instant| locale.format(&instant.to_zoned(tz.clone())))
+            };
+            LocalizedSunTimes {
+                sunrise: write(times.sunrise),
+                solar_noon: write(times.solar_noon),
+                sunset: write(times.sunset),
+            }
+        });
+        let day_length = times.day_length();
+        tool_success(&SunTimesResponse {
+            date: date.to_string(),
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            city,
+            coordinates,
+            sunrise: render(times.sunrise),
+            solar_noon: render(times.solar_noon),
+            sunset: render(times.sunset),
+            human_readable,
+            day_length: day_length.map(|seconds| {
+                format!(
+                    "{:02}:{:02}:{:02}",
+                    seconds / 3600,
+                    seconds / 60 % 60,
+                    seconds % 60
+                )
+            }),
+            day_length_seconds: day_length,
+            polar: times.polar,
+            note,
+        })
+    }
+
+    /// List IANA timezone names, optionally filtered.
+    #[tool(
+        name = "list_timezones",
+        description = "List IANA timezone names, sorted, with each zone's current UTC offset and abbreviation. Filter by name substring ('kolk'), region prefix ('Europe/'), or current UTC offset ('+05:30'); results are paged with limit and cursor. Use this to find a valid zone name instead of guessing.",
+        output_schema = output_schema::<ListTimezonesResponse>()
+    )]
+    pub async fn list_timezones(
+        &self,
+        Parameters(params): Parameters<ListTimezonesParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let limit = params.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+        if limit == 0 || limit > MAX_LIST_LIMIT {
+            return Ok(tool_error(ToolError::new(
+                "invalid_arguments",
+                format!("Provide a limit between 1 and {MAX_LIST_LIMIT}."),
+            )));
+        }
+        let offset = match params.utc_offset.as_deref() {
+            None | Some("") => None,
+            Some(input) => match parse_fixed_offset(input) {
+                Some(tz) => Some(tz.to_fixed_offset().unwrap_or(jiff::tz::Offset::UTC)),
+                None => {
+                    return Ok(tool_error(
+                        ToolError::new(
+                            "invalid_offset",
+                            format!(
+                                "Invalid UTC offset '{input}'. Use a form like '+05:30', '-03:00', or 'UTC+9'."
+                            ),
+                        )
+                        .with_value(input),
+                    ));
+                }
+            },
+        };
+        let query = params
+            .query
+            .map(|q| {
+                unicode::normalize_input(&q)
+                    .trim()
+                    .to_lowercase()
+                    .replace(' ', "_")
+            })
+            .filter(|q| !q.is_empty());
+        let region = params
+            .region
+            .map(|r| {
+                unicode::normalize_input(&r)
+                    .trim()
+                    .trim_end_matches('/')
+                    .to_lowercase()
+            })
+            .filter(|r| !r.is_empty())
+            .map(|r| format!("{r}/"));
+
+        let now = self.now();
+        let matching: Vec<ListedZone> = zone_info::index()
+            .zones
+            .into_iter()
+            .filter(|zone| {
+                let lower = zone.to_lowercase();
+                query.as_ref().is_none_or(|q| lower.contains(q.as_str()))
+                    && region
+                        .as_ref()
+                        .is_none_or(|r| lower.starts_with(r.as_str()))
+            })
+            .filter_map(|zone| {
+                let tz = jiff::tz::TimeZone::get(&zone).ok()?;
+                let info = tz.to_offset_info(now);
+                if offset.is_some_and(|offset| offset != info.offset()) {
+                    return None;
+                }
+                Some(ListedZone {
+                    utc_offset: format_utc_offset(info.offset()),
+                    abbreviation: info.abbreviation().to_string(),
+                    timezone: zone,
+                })
+            })
+            .collect();
+
+        let total = matching.len();
+        let start = params.cursor.unwrap_or(0).min(total);
+        let zones: Vec<ListedZone> = matching.into_iter().skip(start).take(limit).collect();
+        let end = start + zones.len();
+        let response = ListTimezonesResponse {
+            total,
+            zones,
+            next_cursor: (end < total).then_some(end),
+        };
+        tool_success(&response)
+    }
+
+    /// Report the timezone of the machine running the server.
+    #[tool(
+        name = "detect_local_timezone",
+        description = "Report the IANA timezone of the machine running the server, read from its TZ environment variable or system configuration, with the current time, UTC offset, and DST status there. Also reports the server's default timezone, used when a tool's timezone is omitted. The host's zone is not necessarily the user's.",
+        output_schema = output_schema::<LocalTimezoneResponse>()
+    )]
+    pub async fn detect_local_timezone(&self) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match jiff::tz::TimeZone::try_system() {
+            Ok(tz) => tz,
+            Err(e) => {
+                return Ok(tool_error(ToolError::new(
+                    "timezone_unavailable",
+                    format!(
+                        "Could not detect the host's timezone: {e}. Pass an IANA timezone name (e.g., 'Europe/Berlin') to the tools instead."
+                    ),
+                )));
+            }
+        };
+        let now = self.now();
+        let zdt = now.to_zoned(tz.clone());
+        let info = tz.to_offset_info(now);
+        let source = match std::env::var_os("TZ") {
+            Some(value) if !value.is_empty() => "TZ environment variable",
+            _ => "system configuration",
+        };
+        let timezone = tz.iana_name().map(str::to_string);
+        let note = timezone.is_none().then(|| {
+            "The host's timezone has no IANA name; only its offset and abbreviation are known."
+                .to_string()
+        });
+        tool_success(&LocalTimezoneResponse {
+            timezone,
+            datetime: zdt.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+            utc_offset: format_utc_offset(info.offset()),
+            abbreviation: info.abbreviation().to_string(),
+            is_dst: info.dst().is_dst(),
+            source,
+            default_timezone: crate::core::zone_label(&self.default_timezone),
+            note,
+        })
+    }
+
+    /// Report which IANA Time Zone Database release answers queries.
+    #[tool(
+        name = "get_tzdb_info",
+        description = "Report the IANA Time Zone Database the server uses: its release (e.g., '2025b'), whether it is the system's copy or one bundled with the server, where it was loaded from, and how many zones it has. Conversions apply that release's rules as in effect on the date converted, for past and future dates alike; future dates use the rules known today.",
+        output_schema = output_schema::<TzdbInfoResponse>()
+    )]
+    pub async fn get_tzdb_info(&self) -> Result<CallToolResult, rmcp::ErrorData> {
+        let info = tzdb::info().clone();
+        let system = matches!(
+            info.source,
+            tzdb::Source::System | tzdb::Source::SystemConcatenated
+        );
+        let note = match info.source {
+            tzdb::Source::Unavailable => Some(
+                "No timezone database was found; only UTC and fixed offsets are available. Install the system tzdata package or set TZDIR."
+                    .to_string(),
+            ),
+            tzdb::Source::Unknown => Some(
+                "Could not tell where the timezone database was loaded from, or which release it is."
+                    .to_string(),
+            ),
+            _ if info.version.is_none() => {
+                Some("The database does not record its release.".to_string())
+            }
+            _ => None,
+        };
+        tool_success(&TzdbInfoResponse { info, system, note })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::tests::extract_text;
+
+    #[tokio::test]
+    async fn detect_local_timezone_reports_host_and_default_zones() {
+        let instant: jiff::Timestamp = "2025-07-04T12:00:00Z".parse().unwrap();
+        let server = TimeServer::new()
+            .clock(crate::clock::FixedClock(instant))
+            .default_timezone(jiff::tz::TimeZone::get("Asia/Tokyo").unwrap());
+        let result = server.detect_local_timezone().await.unwrap();
+        let Ok(host) = jiff::tz::TimeZone::try_system() else {
+            assert_eq!(result.is_error, Some(true));
+            return;
+        };
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["default_timezone"], "Asia/Tokyo");
+        assert_eq!(
+            json["utc_offset"],
+            format_utc_offset(host.to_offset(instant))
+        );
+        assert!(json["datetime"].as_str().unwrap().starts_with("2025-07-0"));
+    }
+
+    #[tokio::test]
+    async fn get_tzdb_info_reports_the_database_in_use() {
+        let result = TimeServer::new().get_tzdb_info().await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let info = tzdb::info();
+        assert_eq!(
+            json["system"],
+            matches!(
+                info.source,
+                tzdb::Source::System | tzdb::Source::SystemConcatenated
+            )
+        );
+        assert_eq!(json["zone_count"], info.zone_count);
+        assert!(info.zone_count > 300, "{json}");
+        assert_eq!(
+            json.get("version").and_then(|v| v.as_str()),
+            tzdb::version()
+        );
+    }
+
+    #[tokio::test]
+    async fn timezone_geo_describes_countries_cities_and_neighbors() {
+        let server = TimeServer::new();
+        let params = TimezoneGeoParams {
+            timezone: "Asia/Kolkata".into(),
+        };
+        let result = server.timezone_geo(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["countries"][0]["code"], "IN");
+        assert_eq!(json["countries"][0]["name"], "India");
+        assert_eq!(json["major_cities"][0], "Kolkata");
+        assert!(
+            json["major_cities"]
+                .as_array()
+                .unwrap()
+                .contains(&"Mumbai".into())
+        );
+        assert!(json["coordinates"]["latitude"].as_f64().unwrap() > 20.0);
+        assert_eq!(json["neighbors"].as_array().unwrap().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn timezone_geo_resolves_links_to_their_zone() {
+        let server = TimeServer::new();
+        let params = TimezoneGeoParams {
+            timezone: "US/Eastern".into(),
+        };
+        let result = server.timezone_geo(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["timezone"], "America/New_York");
+        assert_eq!(json["countries"][0]["code"], "US");
+        assert_eq!(json["major_cities"][0], "New York");
+    }
+
+    #[tokio::test]
+    async fn timezone_geo_rejects_zones_without_a_location() {
+        let server = TimeServer::new();
+        let params = TimezoneGeoParams {
+            timezone: "UTC".into(),
+        };
+        let result = server.timezone_geo(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "no_geography");
+    }
+
+    fn sun_times_params(city: Option<&str>, coordinates: Option<(f64, f64)>) -> GetSunTimesParams {
+        GetSunTimesParams {
+            latitude: coordinates.map(|(latitude, _)| latitude),
+            longitude: coordinates.map(|(_, longitude)| longitude),
+            city: city.map(Into::into),
+            date: Some("2025-06-21".into()),
+            timezone: None,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn get_sun_times_reports_local_times() {
+        let server = TimeServer::new();
+        let params = sun_times_params(Some("Mumbai"), None);
+        let result = server.get_sun_times(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["city"], "Mumbai");
+        assert_eq!(json["timezone"], "Asia/Kolkata");
+        assert!(
+            json["sunrise"]
+                .as_str()
+                .unwrap()
+                .starts_with("2025-06-21T06:0")
+        );
+        assert!(
+            json["sunset"]
+                .as_str()
+                .unwrap()
+                .starts_with("2025-06-21T19:1")
+        );
+        assert!(json.get("note").is_none());
+
+        let params = GetSunTimesParams {
+            timezone: Some("UTC".into()),
+            ..sun_times_params(None, Some((-33.87, 151.21)))
+        };
+        let result = server.get_sun_times(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        // On the UTC date, Sydney's sunset comes before the next sunrise.
+        assert_eq!(json["timezone"], "UTC");
+        assert!(
+            json["sunrise"]
+                .as_str()
+                .unwrap()
+                .starts_with("2025-06-21T21:0")
+        );
+        assert!(
+            json["sunset"]
+                .as_str()
+                .unwrap()
+                .starts_with("2025-06-21T06:5")
+        );
+        assert!(json.get("day_length").is_none());
+
+        let params = sun_times_params(None, Some((78.22, 15.65)));
+        let result = server.get_sun_times(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["timezone"], "Arctic/Longyearbyen");
+        assert_eq!(json["polar"], "midnight_sun");
+        assert_eq!(json["day_length"], "24:00:00");
+        assert!(json["note"].as_str().unwrap().contains("inferred"));
+    }
+
+    #[cfg(feature = "locale")]
+    #[tokio::test]
+    async fn get_sun_times_writes_times_out_for_locale() {
+        let server = TimeServer::new();
+        let params = GetSunTimesParams {
+            locale: Some("en-GB".into()),
+            ..sun_times_params(Some("Mumbai"), None)
+        };
+        let result = server.get_sun_times(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let sunrise = json["human_readable"]["sunrise"].as_str().unwrap();
+        assert!(
+            sunrise.starts_with("Saturday 21 June 2025 at 06:0"),
+            "{sunrise}"
+        );
+        assert!(json["human_readable"]["sunset"].is_string());
+    }
+
+    #[tokio::test]
+    async fn get_sun_times_rejects_bad_places() {
+        let server = TimeServer::new();
+        for (params, code) in [
+            (sun_times_params(None, None), "invalid_arguments"),
+            (
+                sun_times_params(Some("London"), Some((51.5, 0.0))),
+                "invalid_arguments",
+            ),
+            (
+                sun_times_params(None, Some((91.0, 0.0))),
+                "invalid_coordinates",
+            ),
+            (sun_times_params(Some("Atlantis"), None), "invalid_city"),
+        ] {
+            let result = server.get_sun_times(Parameters(params)).await.unwrap();
+            assert_eq!(result.is_error, Some(true));
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            assert_eq!(json["code"], code);
+        }
+    }
+
+    fn list_timezones_params() -> ListTimezonesParams {
+        ListTimezonesParams {
+            query: None,
+            region: None,
+            utc_offset: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn list_timezones_filters_by_query_region_and_offset() {
+        let server = TimeServer::new();
+        let params = ListTimezonesParams {
+            query: Some("KOLK".into()),
+            ..list_timezones_params()
+        };
+        let result = server.list_timezones(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["total"], 1);
+        assert_eq!(json["zones"][0]["timezone"], "Asia/Kolkata");
+        assert_eq!(json["zones"][0]["utc_offset"], "+05:30");
+
+        let params = ListTimezonesParams {
+            query: Some("new york".into()),
+            region: Some("America".into()),
+            ..list_timezones_params()
+        };
+        let result = server.list_timezones(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["zones"][0]["timezone"], "America/New_York");
+
+        let params = ListTimezonesParams {
+            utc_offset: Some("+05:45".into()),
+            ..list_timezones_params()
+        };
+        let result = server.list_timezones(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let zones: Vec<&str> = json["zones"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|z| z["timezone"].as_str().unwrap())
+            .collect();
+        assert!(zones.contains(&"Asia/Kathmandu"), "Got: {zones:?}");
+        assert!(zones.iter().all(|z| !z.starts_with("Europe/")));
+    }
+
+    #[tokio::test]
+    async fn list_timezones_pages_with_a_cursor() {
+        let server = TimeServer::new();
+        let params = ListTimezonesParams {
+            region: Some("Europe/".into()),
+            limit: Some(10),
+            ..list_timezones_params()
+        };
+        let result = server.list_timezones(Parameters(params)).await.unwrap();
+        let first: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(first["zones"].as_array().unwrap().len(), 10);
+        assert_eq!(first["next_cursor"], 10);
+        let total = first["total"].as_u64().unwrap();
+        assert!(total > 10);
+
+        let params = ListTimezonesParams {
+            region: Some("Europe/".into()),
+            limit: Some(1000),
+            cursor: Some(10),
+            ..list_timezones_params()
+        };
+        let result = server.list_timezones(Parameters(params)).await.unwrap();
+        let rest: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(rest["zones"].as_array().unwrap().len() as u64, total - 10);
+        assert!(rest.get("next_cursor").is_none());
+        assert!(first["zones"][9]["timezone"].as_str() < rest["zones"][0]["timezone"].as_str());
+    }
+
+    #[tokio::test]
+    async fn list_timezones_rejects_bad_offsets_and_limits() {
+        let server = TimeServer::new();
+        let params = ListTimezonesParams {
+            utc_offset: Some("+25:00".into()),
+            ..list_timezones_params()
+        };
+        let result = server.list_timezones(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_offset");
+
+        let params = ListTimezonesParams {
+            limit: Some(0),
+            ..list_timezones_params()
+        };
+        let result = server.list_timezones(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "invalid_arguments");
+    }
+}
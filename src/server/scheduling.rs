@@ -0,0 +1,957 @@
+use rmcp::{
+    Peer, RoleServer,
+    handler::server::wrapper::Parameters,
+    model::{CallToolResult, ProgressNotificationParam},
+    service::RequestContext,
+    tool, tool_router,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Error message template for scheduling at an instant that has passed.
+const ERR_PAST_DATETIME: &str = "Cannot schedule a notification in the past: '{}'.";
+
+/// Error message template for cancelling an unknown notification.
+const ERR_UNKNOWN_NOTIFICATION: &str =
+    "No pending notification with ID '{}'. It may have already been delivered or cancelled.";
+
+/// Error message template for countdowns set to fire at an instant that has
+/// passed.
+const ERR_PAST_COUNTDOWN: &str = "Cannot set a countdown to fire in the past: '{}'.";
+
+/// Error message template for cancelling an unknown countdown.
+const ERR_UNKNOWN_COUNTDOWN: &str =
+    "No pending countdown with ID '{}'. It may have already fired or been cancelled.";
+
+/// Longest wait accepted by the `wait` tool, in seconds.
+const MAX_WAIT_SECONDS: f64 = 3600.0;
+
+/// Error message template for wait durations outside the supported range.
+const ERR_INVALID_WAIT: &str =
+    "Invalid wait: {} seconds. Expected a duration greater than 0 and at most 3600 seconds.";
+
+/// Time left from `now` until `fire_at`, to the second and zero once due.
+fn remaining_until(now: jiff::Timestamp, fire_at: jiff::Timestamp) -> jiff::SignedDuration {
+    now.duration_until(fire_at)
+        .max(jiff::SignedDuration::ZERO)
+        .round(jiff::Unit::Second)
+        .unwrap_or_default()
+}
+
+/// Parameters for the `schedule_notification` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ScheduleNotificationParams {
+    /// Message to deliver when the time arrives
+    pub message: String,
+    /// When to deliver: RFC 3339 (e.g., '2025-07-04T16:45:00Z'), a local datetime (e.g., '2025-07-04T16:45'), or a date
+    pub datetime: String,
+    /// IANA timezone that local datetimes are read in (e.g., 'Europe/London'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `cancel_notification` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct CancelNotificationParams {
+    /// ID returned by `schedule_notification`
+    pub id: String,
+}
+
+/// Parameters for the `list_scheduled` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ListScheduledParams {
+    /// IANA timezone to show delivery times in (e.g., 'America/Chicago'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// List only entries of this kind: 'notification' (from schedule_notification) or 'countdown' (from set_timer). Defaults to both.
+    #[serde(default)]
+    pub kind: Option<Kind>,
+    /// Also write each delivery time out for a locale in `human_readable` (e.g., 'de-DE', 'en-US', 'ja'), as for `get_current_time`.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Parameters for the `list_timers` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ListTimersParams {
+    /// IANA timezone to show fire times in (e.g., 'America/Chicago'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+    /// Also write each fire time out for a locale in `human_readable`, as for `list_scheduled`.
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Parameters for the `set_timer` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct SetTimerParams {
+    /// What the countdown is for, sent back when it fires (e.g., 'tea is ready', 'check the deploy')
+    pub label: String,
+    /// How long until the countdown fires, as a phrase or ISO 8601 duration (e.g., '25 minutes', '1h30m', 'PT90S'). Give this or `datetime`.
+    #[serde(default)]
+    pub duration: Option<String>,
+    /// When the countdown fires instead: RFC 3339 (e.g., '2025-07-04T16:45:00Z') or a local datetime (e.g., '2025-07-04T16:45')
+    #[serde(default)]
+    pub datetime: Option<String>,
+    /// IANA timezone that local datetimes and calendar durations such as '1 day' are counted in (e.g., 'Europe/London'). Defaults to the server's default timezone (UTC unless configured).
+    #[serde(default)]
+    #[schemars(transform = schema::timezone)]
+    pub timezone: Option<String>,
+}
+
+/// Parameters for the `cancel_timer` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct CancelTimerParams {
+    /// ID returned by `set_timer` (e.g., 'countdown-3')
+    pub id: String,
+}
+
+/// Parameters for the `wait` tool.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct WaitParams {
+    /// Number of seconds to wait, up to 3600 (e.g., 90 or 0.5)
+    pub seconds: f64,
+}
+
+/// Parameters for the stopwatch tools.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct StopwatchParams {
+    /// Stopwatch name (e.g., 'deploy', 'focus-session')
+    pub name: String,
+}
+
+/// Response payload for `schedule_notification`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ScheduleNotificationResponse {
+    #[serde(flatten)]
+    scheduled: ScheduledNotification,
+    seconds_until: i64,
+}
+
+/// Response payload for `cancel_notification`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct CancelNotificationResponse {
+    cancelled: ScheduledNotification,
+}
+
+/// A pending notification or countdown as reported by `list_scheduled`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ListedNotification {
+    id: String,
+    #[serde(skip_serializing_if = "Kind::is_notification")]
+    kind: Kind,
+    /// The message, or a countdown's label.
+    message: String,
+    /// The duration a countdown was set with, if it was given one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<String>,
+    /// Delivery instant in the requested timezone.
+    fire_at: String,
+    /// The delivery instant written out for the requested `locale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    human_readable: Option<String>,
+    fire_at_utc: jiff::Timestamp,
+    /// Timezone the notification was scheduled in.
+    scheduled_timezone: String,
+    /// Time left until delivery, zero once due.
+    remaining: String,
+    remaining_seconds: i64,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    missed: bool,
+}
+
+/// Response payload for `list_scheduled`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ListScheduledResponse {
+    now: String,
+    timezone: String,
+    notifications: Vec<ListedNotification>,
+    stopwatches: Vec<TimerStatus>,
+}
+
+/// A pending countdown as reported by `list_timers`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ListedTimer {
+    id: String,
+    label: String,
+    /// The duration the countdown was set with, if it was given one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<String>,
+    /// When the countdown fires, in the requested timezone.
+    fire_at: String,
+    /// The fire time written out for the requested `locale`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    human_readable: Option<String>,
+    fire_at_utc: jiff::Timestamp,
+    /// Time left until it fires, zero once due.
+    remaining: String,
+    remaining_seconds: i64,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    missed: bool,
+}
+
+/// Response payload for `list_timers`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ListTimersResponse {
+    now: String,
+    timezone: String,
+    timers: Vec<ListedTimer>,
+}
+
+/// Response payload for `wait`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct WaitResponse {
+    seconds: f64,
+    started_at: String,
+    finished_at: String,
+}
+
+/// Response payload for `server_stats`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct ServerStatsResponse {
+    uptime_seconds: u64,
+    total_calls: u64,
+    total_errors: u64,
+    /// Tools called since the server started, by name.
+    tools: Vec<ToolStats>,
+}
+
+#[tool_router(router = scheduling_router, vis = "pub")]
+impl TimeServer {
+    /// Deliver a message as a server notification at a given instant.
+    #[tool(
+        name = "schedule_notification",
+        description = "Schedule a message to be sent back as a server logging notification at a specific datetime. Returns an ID for cancel_notification. Pending notifications are lost if the server restarts.",
+        output_schema = output_schema::<ScheduleNotificationResponse>()
+    )]
+    pub async fn schedule_notification(
+        &self,
+        Parameters(params): Parameters<ScheduleNotificationParams>,
+        peer: Peer<RoleServer>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let datetime = params.datetime.trim();
+        let fire_at = match parse_datetime(datetime, &tz) {
+            Ok(zdt) => zdt,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let seconds_until = jiff::Timestamp::now()
+            .duration_until(fire_at.timestamp())
+            .as_secs();
+        if seconds_until < 0 {
+            return Ok(tool_error(
+                ToolError::new("past_datetime", ERR_PAST_DATETIME.replace("{}", datetime))
+                    .with_value(datetime),
+            ));
+        }
+
+        let persistence = self.persistence.clone();
+        let scheduled = self
+            .scheduler
+            .schedule(params.message, &fire_at, move |notification| {
+                deliver_notification(peer, persistence, notification)
+            });
+        self.save_state();
+
+        tool_success(&ScheduleNotificationResponse {
+            scheduled,
+            seconds_until,
+        })
+    }
+
+    /// Cancel a notification registered with `schedule_notification`.
+    #[tool(
+        name = "cancel_notification",
+        description = "Cancel a pending notification registered with schedule_notification, by ID.",
+        output_schema = output_schema::<CancelNotificationResponse>()
+    )]
+    pub async fn cancel_notification(
+        &self,
+        Parameters(params): Parameters<CancelNotificationParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let id = params.id.trim();
+        match self.scheduler.cancel(id) {
+            Some(cancelled) => {
+                self.save_state();
+                tool_success(&CancelNotificationResponse { cancelled })
+            }
+            None => Ok(tool_error(
+                ToolError::new(
+                    "unknown_notification",
+                    ERR_UNKNOWN_NOTIFICATION.replace("{}", id),
+                )
+                .with_value(id)
+                .with_suggestions(self.scheduler.pending().into_iter().map(|n| n.id).collect()),
+            )),
+        }
+    }
+
+    /// List pending notifications and countdowns, and active stopwatches.
+    #[tool(
+        name = "list_scheduled",
+        description = "List pending scheduled notifications and countdowns set with set_timer (soonest first, with delivery time in the given timezone and time remaining; filter with kind), and all named stopwatches.",
+        output_schema = output_schema::<ListScheduledResponse>()
+    )]
+    pub async fn list_scheduled(
+        &self,
+        Parameters(params): Parameters<ListScheduledParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let locale = match parse_locale(params.locale.as_deref()) {
+            Ok(locale) => locale,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let now = jiff::Timestamp::now();
+        let notifications = self
+            .scheduler
+            .pending()
+            .into_iter()
+            .filter(|entry| params.kind.is_none_or(|kind| entry.kind == kind))
+            .map(|notification| {
+                let remaining = remaining_until(now, notification.fire_at_utc);
+                let fire_at = notification.fire_at_utc.to_zoned(tz.clone());
+                ListedNotification {
+                    id: notification.id,
+                    kind: notification.kind,
+                    message: notification.message,
+                    duration: notification.duration,
+                    fire_at: fire_at.strftime("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+                    human_readable: locale.map(|locale| locale.format(&fire_at)),
+                    fire_at_utc: notification.fire_at_utc,
+                    scheduled_timezone: notification.timezone,
+                    remaining: format!("{remaining:#}"),
+                    remaining_seconds: remaining.as_secs(),
+                    missed: notification.missed,
+                }
+            })
+            .collect();
+
+        tool_success(&ListScheduledResponse {
+            now: now
+                .to_zoned(tz.clone())
+                .strftime("%Y-%m-%dT%H:%M:%S%:z")
+                .to_string(),
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            notifications,
+            stopwatches: self.timers.list(now),
+        })
+    }
+
+    /// Set a countdown that notifies the client when it fires.
+    #[tool(
+        name = "set_timer",
+        description = "Set a countdown timer with a label that fires after a duration ('25 minutes', '1h30m', 'PT90S') or at a datetime. When it fires, the label is sent back to the client as a server logging notification, so the connection must stay open (e.g., a long-lived stdio or HTTP session). Returns an ID for cancel_timer; list pending countdowns with list_timers or list_scheduled. For stopwatches that count up, use start_stopwatch instead.",
+        output_schema = output_schema::<ScheduleNotificationResponse>()
+    )]
+    pub async fn set_timer(
+        &self,
+        Parameters(params): Parameters<SetTimerParams>,
+        peer: Peer<RoleServer>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let now = jiff::Timestamp::now().to_zoned(tz.clone());
+        let (fire_at, duration) = match (params.duration.as_deref(), params.datetime.as_deref()) {
+            (Some(input), None) => {
+                let input = unicode::normalize_input(input.trim());
+                let invalid =
+                    |msg: String| ToolError::new("invalid_duration", msg).with_value(&*input);
+                let parsed = match duration::parse(&input) {
+                    // "in 25 minutes" counts from now, as any duration here does.
+                    Ok(parsed) if matches!(parsed.anchor, None | Some(Anchor::Now)) => parsed,
+                    Ok(_) => {
+                        return Ok(tool_error(invalid(
+                            "A countdown counts from now; give a datetime to fire at a time counted from elsewhere."
+                                .to_string(),
+                        )));
+                    }
+                    Err(msg) => return Ok(tool_error(invalid(msg))),
+                };
+                if parsed.backward || (parsed.span.is_zero() && parsed.business_days == 0) {
+                    return Ok(tool_error(invalid(
+                        "A countdown duration must be greater than zero.".to_string(),
+                    )));
+                }
+                match parsed.apply(&now) {
+                    Ok(fire_at) => (fire_at, Some(input.into_owned())),
+                    Err(msg) => return Ok(tool_error(invalid(msg))),
+                }
+            }
+            (None, Some(datetime)) => {
+                let datetime = datetime.trim();
+                match parse_datetime(datetime, &tz) {
+                    Ok(fire_at) if fire_at.timestamp() >= now.timestamp() => (fire_at, None),
+                    Ok(_) => {
+                        return Ok(tool_error(
+                            ToolError::new(
+                                "past_datetime",
+                                ERR_PAST_COUNTDOWN.replace("{}", datetime),
+                            )
+                            .with_value(datetime),
+                        ));
+                    }
+                    Err(msg) => return Ok(tool_error(msg)),
+                }
+            }
+            _ => {
+                return Ok(tool_error(ToolError::new(
+                    "invalid_arguments",
+                    "Give either a duration (e.g., '25 minutes') or a datetime for the countdown, not both.",
+                )));
+            }
+        };
+
+        let seconds_until = now
+            .timestamp()
+            .duration_until(fire_at.timestamp())
+            .as_secs();
+        let persistence = self.persistence.clone();
+        let scheduled =
+            self.scheduler
+                .set_countdown(params.label, duration, &fire_at, move |notification| {
+                    deliver_notification(peer, persistence, notification)
+                });
+        self.save_state();
+
+        tool_success(&ScheduleNotificationResponse {
+            scheduled,
+            seconds_until,
+        })
+    }
+
+    /// List countdowns that have not fired yet.
+    #[tool(
+        name = "list_timers",
+        description = "List countdowns set with set_timer that have not fired yet, soonest first, with when each fires in the given timezone and the time left.",
+        output_schema = output_schema::<ListTimersResponse>()
+    )]
+    pub async fn list_timers(
+        &self,
+        Parameters(params): Parameters<ListTimersParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tz = match self.resolve_timezone_or_default(params.timezone.as_deref()) {
+            Ok(tz) => tz,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let locale = match parse_locale(params.locale.as_deref()) {
+            Ok(locale) => locale,
+            Err(msg) => return Ok(tool_error(msg)),
+        };
+        let now = jiff::Timestamp::now();
+        let render = |ts: jiff::Timestamp| {
+            ts.to_zoned(tz.clone())
+                .strftime("%Y-%m-%dT%H:%M:%S%:z")
+                .to_string()
+        };
+        let timers = self
+            .scheduler
+            .pending()
+            .into_iter()
+            .filter(|entry| entry.kind == Kind::Countdown)
+            .map(|countdown| {
+                let remaining = remaining_until(now, countdown.fire_at_utc);
+                ListedTimer {
+                    id: countdown.id,
+                    label: countdown.message,
+                    duration: countdown.duration,
+                    fire_at: render(countdown.fire_at_utc),
+                    human_readable: locale
+                        .map(|locale| locale.format(&countdown.fire_at_utc.to_zoned(tz.clone()))),
+                    fire_at_utc: countdown.fire_at_utc,
+                    remaining: format!("{remaining:#}"),
+                    remaining_seconds: remaining.as_secs(),
+                    missed: countdown.missed,
+                }
+            })
+            .collect();
+
+        tool_success(&ListTimersResponse {
+            now: render(now),
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            timers,
+        })
+    }
+
+    /// Cancel a countdown set with `set_timer`.
+    #[tool(
+        name = "cancel_timer",
+        description = "Cancel a countdown set with set_timer, by ID, before it fires.",
+        output_schema = output_schema::<CancelNotificationResponse>()
+    )]
+    pub async fn cancel_timer(
+        &self,
+        Parameters(params): Parameters<CancelTimerParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let id = params.id.trim();
+        let pending: Vec<String> = self
+            .scheduler
+            .pending()
+            .into_iter()
+            .filter(|entry| entry.kind == Kind::Countdown)
+            .map(|countdown| countdown.id)
+            .collect();
+        if !pending.iter().any(|pending| pending == id) {
+            return Ok(tool_error(
+                ToolError::new("unknown_countdown", ERR_UNKNOWN_COUNTDOWN.replace("{}", id))
+                    .with_value(id)
+                    .with_suggestions(pending),
+            ));
+        }
+        match self.scheduler.cancel(id) {
+            Some(cancelled) => {
+                self.save_state();
+                tool_success(&CancelNotificationResponse { cancelled })
+            }
+            // It fired between the check and the cancel.
+            None => Ok(tool_error(
+                ToolError::new("unknown_countdown", ERR_UNKNOWN_COUNTDOWN.replace("{}", id))
+                    .with_value(id),
+            )),
+        }
+    }
+
+    /// Wait for a number of seconds before responding.
+    #[tool(
+        name = "wait",
+        description = "Wait for the given number of seconds (up to 3600) before responding, then report when the wait started and finished. Cancelling the request stops the wait immediately.",
+        output_schema = output_schema::<WaitResponse>()
+    )]
+    pub async fn wait(
+        &self,
+        Parameters(params): Parameters<WaitParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        if !(params.seconds > 0.0 && params.seconds <= MAX_WAIT_SECONDS) {
+            return Ok(tool_error(
+                ToolError::new(
+                    "invalid_wait",
+                    ERR_INVALID_WAIT.replace("{}", &params.seconds.to_string()),
+                )
+                .with_value(params.seconds.to_string()),
+            ));
+        }
+
+        let started_at = jiff::Timestamp::now();
+        let duration = std::time::Duration::from_secs_f64(params.seconds);
+        let progress_token = context.meta.get_progress_token();
+        let report_progress = |fraction: f64| {
+            let peer = context.peer.clone();
+            let progress_token = progress_token.clone();
+            async move {
+                if let Some(progress_token) = progress_token {
+                    let percent = (fraction * 100.0).clamp(0.0, 100.0).round();
+                    // Progress is advisory; a failed send must not end the wait.
+                    let _ = peer
+                        .notify_progress(ProgressNotificationParam {
+                            progress_token,
+                            progress: percent,
+                            total: Some(100.0),
+                            message: Some(format!(
+                                "Waited {:.0} of {} seconds",
+                                fraction * params.seconds,
+                                params.seconds
+                            )),
+                        })
+                        .await;
+                }
+            }
+        };
+
+        let interval = progress_token.as_ref().map(|_| progress_interval(duration));
+        if !sleep_unless_cancelled(duration, context.ct.cancelled(), interval, report_progress)
+            .await
+        {
+            return Ok(tool_error(ToolError::new(
+                "cancelled",
+                "Wait cancelled by the client.",
+            )));
+        }
+        report_progress(1.0).await;
+
+        let response = WaitResponse {
+            seconds: params.seconds,
+            started_at: started_at.to_string(),
+            finished_at: jiff::Timestamp::now().to_string(),
+        };
+
+        tool_success(&response)
+    }
+
+    /// Start a named stopwatch.
+    #[tool(
+        name = "start_stopwatch",
+        description = "Start a named stopwatch. Use pause_stopwatch/resume_stopwatch for interruptions; elapsed time excludes paused intervals.",
+        output_schema = output_schema::<TimerStatus>()
+    )]
+    pub async fn start_stopwatch(
+        &self,
+        Parameters(params): Parameters<StopwatchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        self.save_timer_change(
+            self.timers
+                .start(params.name.trim(), jiff::Timestamp::now()),
+        )
+    }
+
+    /// Pause a running named stopwatch.
+    #[tool(
+        name = "pause_stopwatch",
+        description = "Pause a running named stopwatch. Time spent paused is not counted as elapsed.",
+        output_schema = output_schema::<TimerStatus>()
+    )]
+    pub async fn pause_stopwatch(
+        &self,
+        Parameters(params): Parameters<StopwatchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        self.save_timer_change(
+            self.timers
+                .pause(params.name.trim(), jiff::Timestamp::now()),
+        )
+    }
+
+    /// Resume a paused named stopwatch.
+    #[tool(
+        name = "resume_stopwatch",
+        description = "Resume a paused named stopwatch.",
+        output_schema = output_schema::<TimerStatus>()
+    )]
+    pub async fn resume_stopwatch(
+        &self,
+        Parameters(params): Parameters<StopwatchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        self.save_timer_change(
+            self.timers
+                .resume(params.name.trim(), jiff::Timestamp::now()),
+        )
+    }
+
+    /// Report a named stopwatch's elapsed time.
+    #[tool(
+        name = "get_stopwatch",
+        description = "Report a named stopwatch's state, elapsed running time, and total paused time.",
+        output_schema = output_schema::<TimerStatus>()
+    )]
+    pub async fn get_stopwatch(
+        &self,
+        Parameters(params): Parameters<StopwatchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        timer_result(self.timers.get(params.name.trim(), jiff::Timestamp::now()))
+    }
+
+    /// Stop and remove a named stopwatch.
+    #[tool(
+        name = "stop_stopwatch",
+        description = "Stop a named stopwatch and remove it, reporting its final elapsed time.",
+        output_schema = output_schema::<TimerStatus>()
+    )]
+    pub async fn stop_stopwatch(
+        &self,
+        Parameters(params): Parameters<StopwatchParams>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        self.save_timer_change(self.timers.stop(params.name.trim(), jiff::Timestamp::now()))
+    }
+
+    /// Report call counts, error counts, and latencies per tool.
+    #[tool(
+        name = "server_stats",
+        description = "Report how this server has been used since it started: uptime, and for each tool called, the number of calls, validation and internal errors, and median (p50) and 95th percentile (p95) latency in milliseconds over its most recent 1024 calls. Counts cover every client of the server.",
+        output_schema = output_schema::<ServerStatsResponse>()
+    )]
+    pub async fn server_stats(&self) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tools = self.metrics.snapshot();
+        tool_success(&ServerStatsResponse {
+            uptime_seconds: self.metrics.uptime().as_secs(),
+            total_calls: tools.iter().map(|t| t.calls).sum(),
+            total_errors: tools.iter().map(|t| t.errors).sum(),
+            tools,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::tests::extract_text;
+
+    #[tokio::test]
+    async fn server_stats_counts_calls_and_errors_per_tool() {
+        let server = TimeServer::new();
+        let metrics = server.metrics().clone();
+        let mut client = crate::local::LocalClient::new(server);
+        let arguments = |value: serde_json::Value| value.as_object().unwrap().clone();
+        for timezone in ["Asia/Tokyo", "Europe/London", "Mars/Base"] {
+            client
+                .call(
+                    "get_current_time",
+                    arguments(serde_json::json!({ "timezone": timezone })),
+                )
+                .await
+                .unwrap();
+        }
+        assert!(
+            client
+                .call("no_such_tool", serde_json::Map::new())
+                .await
+                .is_err()
+        );
+
+        let result = client
+            .call("server_stats", serde_json::Map::new())
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["total_calls"], 3);
+        assert_eq!(json["total_errors"], 1);
+        let tools = json["tools"].as_array().unwrap();
+        assert_eq!(tools.len(), 1, "Unknown tools are not counted: {json}");
+        assert_eq!(tools[0]["tool"], "get_current_time");
+        assert_eq!(tools[0]["validation_errors"], 1);
+        assert_eq!(tools[0]["internal_errors"], 0);
+        assert!(tools[0]["p95_ms"].as_f64().unwrap() >= tools[0]["p50_ms"].as_f64().unwrap());
+
+        // The stats call itself is counted once it finishes.
+        assert_eq!(metrics.snapshot()[1].tool, "server_stats");
+    }
+
+    #[tokio::test]
+    async fn timer_tools_pause_and_resume() {
+        let server = TimeServer::new();
+        let params = || {
+            Parameters(StopwatchParams {
+                name: "focus".into(),
+            })
+        };
+        let result = server.start_stopwatch(params()).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let result = server.pause_stopwatch(params()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["state"], "paused");
+        let result = server.pause_stopwatch(params()).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let result = server.resume_stopwatch(params()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["state"], "running");
+        let result = server.stop_stopwatch(params()).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let result = server.get_stopwatch(params()).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn list_scheduled_reports_notifications_and_stopwatches() {
+        let server = TimeServer::new();
+        let fire_at = jiff::Timestamp::now()
+            .checked_add(jiff::SignedDuration::from_hours(2))
+            .unwrap()
+            .to_zoned(jiff::tz::TimeZone::UTC);
+        server
+            .scheduler
+            .schedule("review".into(), &fire_at, |_| async {});
+        server
+            .timers
+            .start("focus", jiff::Timestamp::now())
+            .unwrap();
+
+        let params = ListScheduledParams {
+            timezone: Some("Asia/Tokyo".into()),
+            ..Default::default()
+        };
+        let result = server.list_scheduled(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["timezone"], "Asia/Tokyo");
+        let notification = &json["notifications"][0];
+        assert_eq!(notification["message"], "review");
+        assert_eq!(notification["scheduled_timezone"], "UTC");
+        assert!(
+            notification["fire_at"]
+                .as_str()
+                .unwrap()
+                .ends_with("+09:00")
+        );
+        let remaining = notification["remaining_seconds"].as_i64().unwrap();
+        assert!((7190..=7200).contains(&remaining), "Got: {remaining}");
+        assert_eq!(json["stopwatches"][0]["name"], "focus");
+    }
+
+    #[tokio::test]
+    async fn list_scheduled_rejects_invalid_timezone() {
+        let server = TimeServer::new();
+        let params = ListScheduledParams {
+            timezone: Some("Mars/Olympus".into()),
+            ..Default::default()
+        };
+        let result = server.list_scheduled(Parameters(params)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn cancel_notification_returns_error_for_unknown_id() {
+        let server = TimeServer::new();
+        let params = CancelNotificationParams {
+            id: "notification-42".into(),
+        };
+        let result = server
+            .cancel_notification(Parameters(params))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert!(extract_text(&result).contains("notification-42"));
+    }
+
+    #[tokio::test]
+    async fn list_timers_reports_only_countdowns() {
+        let server = TimeServer::new();
+        let fire_at = jiff::Timestamp::now()
+            .checked_add(jiff::SignedDuration::from_mins(25))
+            .unwrap()
+            .to_zoned(jiff::tz::TimeZone::UTC);
+        server
+            .scheduler
+            .schedule("review".into(), &fire_at, |_| async {});
+        let countdown = server.scheduler.set_countdown(
+            "tea".into(),
+            Some("25 minutes".into()),
+            &fire_at,
+            |_| async {},
+        );
+
+        let params = ListTimersParams {
+            timezone: Some("Asia/Tokyo".into()),
+            ..Default::default()
+        };
+        let result = server.list_timers(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        let countdowns = json["timers"].as_array().unwrap();
+        assert_eq!(countdowns.len(), 1);
+        assert_eq!(countdowns[0]["id"], countdown.id);
+        assert_eq!(countdowns[0]["label"], "tea");
+        assert_eq!(countdowns[0]["duration"], "25 minutes");
+        assert!(
+            countdowns[0]["fire_at"]
+                .as_str()
+                .unwrap()
+                .ends_with("+09:00")
+        );
+        let remaining = countdowns[0]["remaining_seconds"].as_i64().unwrap();
+        assert!((1490..=1500).contains(&remaining), "Got: {remaining}");
+    }
+
+    #[cfg(feature = "locale")]
+    #[tokio::test]
+    async fn listings_write_fire_times_out_for_locale() {
+        let server = TimeServer::new();
+        let fire_at: jiff::Zoned = "2099-01-02T03:04:00+00:00[UTC]".parse().unwrap();
+        server
+            .scheduler
+            .schedule("review".into(), &fire_at, |_| async {});
+        server
+            .scheduler
+            .set_countdown("tea".into(), None, &fire_at, |_| async {});
+
+        let params = ListScheduledParams {
+            locale: Some("en-GB".into()),
+            ..Default::default()
+        };
+        let result = server.list_scheduled(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        for listed in json["notifications"].as_array().unwrap() {
+            assert_eq!(listed["human_readable"], "Friday 2 January 2099 at 03:04");
+        }
+
+        let params = ListTimersParams {
+            timezone: Some("Asia/Tokyo".into()),
+            locale: Some("en-GB".into()),
+        };
+        let result = server.list_timers(Parameters(params)).await.unwrap();
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(
+            json["timers"][0]["human_readable"],
+            "Friday 2 January 2099 at 12:04"
+        );
+    }
+
+    #[tokio::test]
+    async fn list_scheduled_includes_countdowns_unless_filtered() {
+        let server = TimeServer::new();
+        let fire_at = jiff::Timestamp::now()
+            .checked_add(jiff::SignedDuration::from_mins(25))
+            .unwrap()
+            .to_zoned(jiff::tz::TimeZone::UTC);
+        server
+            .scheduler
+            .schedule("review".into(), &fire_at, |_| async {});
+        let countdown = server.scheduler.set_countdown(
+            "tea".into(),
+            Some("25 minutes".into()),
+            &fire_at,
+            |_| async {},
+        );
+
+        let list = async |kind: Option<Kind>| {
+            let params = ListScheduledParams {
+                kind,
+                ..Default::default()
+            };
+            let result = server.list_scheduled(Parameters(params)).await.unwrap();
+            let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+            json["notifications"].as_array().unwrap().clone()
+        };
+        let all = list(None).await;
+        assert_eq!(all.len(), 2);
+        let listed = all.iter().find(|n| n["id"] == countdown.id).unwrap();
+        assert_eq!(listed["kind"], "countdown");
+        assert_eq!(listed["message"], "tea");
+        assert_eq!(listed["duration"], "25 minutes");
+
+        let notifications = list(Some(Kind::Notification)).await;
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0]["message"], "review");
+        assert!(notifications[0].get("kind").is_none());
+
+        let countdowns = list(Some(Kind::Countdown)).await;
+        assert_eq!(countdowns.len(), 1);
+        assert_eq!(countdowns[0]["id"], countdown.id);
+    }
+
+    #[tokio::test]
+    async fn cancel_timer_cancels_only_countdowns() {
+        let server = TimeServer::new();
+        let fire_at = jiff::Timestamp::now()
+            .checked_add(jiff::SignedDuration::from_hours(1))
+            .unwrap()
+            .to_zoned(jiff::tz::TimeZone::UTC);
+        let notification = server
+            .scheduler
+            .schedule("review".into(), &fire_at, |_| async {});
+        let countdown =
+            server
+                .scheduler
+                .set_countdown("deploy".into(), None, &fire_at, |_| async {});
+
+        let params = |id: &str| Parameters(CancelTimerParams { id: id.into() });
+        let result = server.cancel_timer(params(&notification.id)).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["code"], "unknown_countdown");
+        assert_eq!(json["suggestions"][0], countdown.id);
+
+        let result = server.cancel_timer(params(&countdown.id)).await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+        assert_eq!(json["cancelled"]["kind"], "countdown");
+        assert_eq!(server.scheduler.pending().len(), 1);
+    }
+}
@@ -0,0 +1,62 @@
+//! Input schema annotations shared by tool parameters, applied with
+//! `#[schemars(transform = ...)]`.
+//!
+//! These describe the canonical forms so clients can validate and models
+//! can pattern-match on examples; the parsers stay more lenient (e.g.,
+//! full-width digits, bare `1430` times with lenient parsing).
+
+use schemars::Schema;
+use serde_json::{Value, json};
+
+/// Clock times in 24-hour `HH:MM` or 12-hour form with an AM/PM suffix, as
+/// [`super::parse_clock_time`] accepts them without leniency.
+pub(super) const CLOCK_TIME_PATTERN: &str =
+    "^(?:(?:[01][0-9]|2[0-3]):[0-5][0-9]|(?:0?[1-9]|1[0-2])(?::[0-5][0-9])? *[AaPp][Mm])$";
+
+/// Example clock times given in schemas.
+pub(super) const CLOCK_TIME_EXAMPLES: [&str; 3] = ["14:30", "2:30 PM", "9am"];
+
+/// Example timezones given in schemas.
+pub(super) const TIMEZONE_EXAMPLES: [&str; 4] =
+    ["America/New_York", "Europe/London", "Asia/Tokyo", "UTC"];
+
+/// A timezone: an IANA name, abbreviation, or UTC offset, and a city name
+/// when city lookups are enabled, so the schema gives examples rather than
+/// an enum of zone names.
+pub(super) fn timezone(schema: &mut Schema) {
+    schema.insert("examples".into(), json!(TIMEZONE_EXAMPLES));
+}
+
+/// A list of timezones, each as in [`timezone`].
+pub(super) fn timezones(schema: &mut Schema) {
+    schema.insert("examples".into(), json!([&TIMEZONE_EXAMPLES[..3]]));
+    items(schema, timezone);
+}
+
+/// A calendar date in `YYYY-MM-DD` form.
+pub(super) fn date(schema: &mut Schema) {
+    schema.insert("format".into(), "date".into());
+    schema.insert("examples".into(), json!(["2025-03-10"]));
+}
+
+/// A list of calendar dates, each as in [`date`].
+pub(super) fn dates(schema: &mut Schema) {
+    items(schema, date);
+}
+
+/// A clock time of day, matching [`CLOCK_TIME_PATTERN`].
+pub(super) fn clock_time(schema: &mut Schema) {
+    schema.insert("pattern".into(), CLOCK_TIME_PATTERN.into());
+    schema.insert("examples".into(), json!(CLOCK_TIME_EXAMPLES));
+}
+
+/// Apply `transform` to the schema of an array's items.
+fn items(schema: &mut Schema, transform: fn(&mut Schema)) {
+    let Some(items) = schema.get_mut("items") else {
+        return;
+    };
+    if let Ok(mut item) = Schema::try_from(items.clone()) {
+        transform(&mut item);
+        *items = Value::from(item);
+    }
+}
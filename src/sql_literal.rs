@@ -0,0 +1,197 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// SQL dialect to render a datetime literal for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SqlDialect {
+    Postgresql,
+    Mysql,
+    Sqlite,
+    Sqlserver,
+}
+
+impl SqlDialect {
+    /// All supported dialects, in response order.
+    pub const ALL: [SqlDialect; 4] = [
+        SqlDialect::Postgresql,
+        SqlDialect::Mysql,
+        SqlDialect::Sqlite,
+        SqlDialect::Sqlserver,
+    ];
+
+    /// Number of fractional-second digits the dialect's datetime types keep.
+    fn fractional_digits(self) -> usize {
+        match self {
+            SqlDialect::Postgresql | SqlDialect::Mysql => 6,
+            SqlDialect::Sqlite => 3,
+            SqlDialect::Sqlserver => 7,
+        }
+    }
+}
+
+/// A datetime literal ready to paste into a query.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SqlLiteral {
+    pub dialect: SqlDialect,
+    pub literal: String,
+    /// Column type the literal is typed as or best stored in.
+    pub sql_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<&'static str>,
+}
+
+/// Render `zdt` as a datetime literal for `dialect`.
+///
+/// With `with_timezone`, the literal carries the UTC offset so the exact
+/// instant survives; without it, the local wall-clock time is emitted.
+pub fn literal(zdt: &jiff::Zoned, dialect: SqlDialect, with_timezone: bool) -> SqlLiteral {
+    let fraction = fraction(zdt, dialect.fractional_digits());
+    let offset = if with_timezone {
+        zdt.strftime("%:z").to_string()
+    } else {
+        String::new()
+    };
+    let date = zdt.strftime("%Y-%m-%d");
+    let time = zdt.strftime("%H:%M:%S");
+    let text = |sep: char| quote(&format!("{date}{sep}{time}{fraction}{offset}"));
+
+    match (dialect, with_timezone) {
+        (SqlDialect::Postgresql, true) => SqlLiteral {
+            dialect,
+            literal: format!("TIMESTAMPTZ {}", text(' ')),
+            sql_type: "timestamptz",
+            note: None,
+        },
+        (SqlDialect::Postgresql, false) => SqlLiteral {
+            dialect,
+            literal: format!("TIMESTAMP {}", text(' ')),
+            sql_type: "timestamp",
+            note: None,
+        },
+        (SqlDialect::Mysql, true) => SqlLiteral {
+            dialect,
+            literal: text(' '),
+            sql_type: "TIMESTAMP",
+            note: Some(
+                "Offset literals need MySQL 8.0.19+; the value is converted to the session time zone on insert.",
+            ),
+        },
+        (SqlDialect::Mysql, false) => SqlLiteral {
+            dialect,
+            literal: text(' '),
+            sql_type: "DATETIME",
+            note: None,
+        },
+        (SqlDialect::Sqlite, true) => SqlLiteral {
+            dialect,
+            literal: text(' '),
+            sql_type: "TEXT",
+            note: Some(
+                "SQLite stores datetimes as TEXT; its date functions normalize the offset to UTC.",
+            ),
+        },
+        (SqlDialect::Sqlite, false) => SqlLiteral {
+            dialect,
+            literal: text(' '),
+            sql_type: "TEXT",
+            note: Some("SQLite stores datetimes as TEXT."),
+        },
+        // ISO 8601 with a 'T' separator is the only string form SQL Server
+        // parses identically under every DATEFORMAT and language setting.
+        (SqlDialect::Sqlserver, true) => SqlLiteral {
+            dialect,
+            literal: format!("CAST({} AS DATETIMEOFFSET)", text('T')),
+            sql_type: "DATETIMEOFFSET",
+            note: None,
+        },
+        (SqlDialect::Sqlserver, false) => SqlLiteral {
+            dialect,
+            literal: format!("CAST({} AS DATETIME2)", text('T')),
+            sql_type: "DATETIME2",
+            note: None,
+        },
+    }
+}
+
+/// Fractional seconds as `.ddd`, truncated to `digits` and without trailing
+/// zeros; empty when the datetime is on a whole second.
+fn fraction(zdt: &jiff::Zoned, digits: usize) -> String {
+    let nanos = zdt.subsec_nanosecond();
+    if nanos == 0 {
+        return String::new();
+    }
+    let mut frac = format!("{nanos:09}");
+    frac.truncate(digits);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        String::new()
+    } else {
+        format!(".{frac}")
+    }
+}
+
+/// Quote a value as a SQL string literal, doubling embedded single quotes.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paris() -> jiff::Zoned {
+        "2025-07-04T12:30:15.123456789+02:00[Europe/Paris]"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn literal_renders_postgresql_with_and_without_timezone() {
+        let with_tz = literal(&paris(), SqlDialect::Postgresql, true);
+        assert_eq!(
+            with_tz.literal,
+            "TIMESTAMPTZ '2025-07-04 12:30:15.123456+02:00'"
+        );
+        let without = literal(&paris(), SqlDialect::Postgresql, false);
+        assert_eq!(without.literal, "TIMESTAMP '2025-07-04 12:30:15.123456'");
+    }
+
+    #[test]
+    fn literal_renders_mysql_and_sqlite() {
+        assert_eq!(
+            literal(&paris(), SqlDialect::Mysql, false).literal,
+            "'2025-07-04 12:30:15.123456'"
+        );
+        assert_eq!(
+            literal(&paris(), SqlDialect::Sqlite, true).literal,
+            "'2025-07-04 12:30:15.123+02:00'"
+        );
+    }
+
+    #[test]
+    fn literal_renders_sqlserver_with_iso_separator() {
+        assert_eq!(
+            literal(&paris(), SqlDialect::Sqlserver, true).literal,
+            "CAST('2025-07-04T12:30:15.1234567+02:00' AS DATETIMEOFFSET)"
+        );
+        assert_eq!(
+            literal(&paris(), SqlDialect::Sqlserver, false).literal,
+            "CAST('2025-07-04T12:30:15.1234567' AS DATETIME2)"
+        );
+    }
+
+    #[test]
+    fn literal_omits_fraction_on_whole_seconds() {
+        let zdt: jiff::Zoned = "2025-01-01T00:00:00+00:00[UTC]".parse().unwrap();
+        assert_eq!(
+            literal(&zdt, SqlDialect::Postgresql, true).literal,
+            "TIMESTAMPTZ '2025-01-01 00:00:00+00:00'"
+        );
+    }
+
+    #[test]
+    fn quote_doubles_single_quotes() {
+        assert_eq!(quote("O'Brien"), "'O''Brien'");
+    }
+}
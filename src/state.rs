@@ -0,0 +1,146 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    scheduler::{ScheduledNotification, Scheduler},
+    timers::{Timer, Timers},
+};
+
+/// Environment variable holding the path of the state file.
+pub const STATE_FILE_ENV: &str = "MCP_TIME_STATE_FILE";
+
+/// Scheduled notifications and timers saved between server runs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SavedState {
+    /// Highest notification ID number handed out so far.
+    #[serde(default)]
+    pub last_notification_id: u64,
+    #[serde(default)]
+    pub notifications: Vec<ScheduledNotification>,
+    #[serde(default)]
+    pub timers: BTreeMap<String, Timer>,
+}
+
+/// JSON file that `SavedState` is written to.
+#[derive(Debug, Clone)]
+pub struct StateFile {
+    path: PathBuf,
+}
+
+impl StateFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read the saved state. A missing file is treated as empty state.
+    pub fn load(&self) -> Result<SavedState, String> {
+        let json = match std::fs::read_to_string(&self.path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(SavedState::default());
+            }
+            Err(e) => return Err(format!("Failed to read {}: {e}", self.path.display())),
+        };
+        serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse {}: {e}", self.path.display()))
+    }
+
+    /// Write `state`, replacing the file atomically so a crash mid-write
+    /// never leaves a truncated file behind.
+    pub fn save(&self, state: &SavedState) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(state)
+            .map_err(|e| format!("Failed to serialize state: {e}"))?;
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, json)
+            .and_then(|()| std::fs::rename(&tmp, &self.path))
+            .map_err(|e| format!("Failed to write {}: {e}", self.path.display()))
+    }
+}
+
+/// Writes the scheduler and timers to a state file.
+///
+/// Clones share the same registries and file.
+#[derive(Clone)]
+pub struct Persistence {
+    file: StateFile,
+    scheduler: Scheduler,
+    timers: Timers,
+}
+
+impl Persistence {
+    pub fn new(file: StateFile, scheduler: Scheduler, timers: Timers) -> Self {
+        Self {
+            file,
+            scheduler,
+            timers,
+        }
+    }
+
+    /// Save the current pending notifications and timers.
+    pub fn save(&self) -> Result<(), String> {
+        self.file.save(&SavedState {
+            last_notification_id: self.scheduler.last_id(),
+            notifications: self.scheduler.pending(),
+            timers: self.timers.export(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::Kind;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("mcp-time-{}-{name}.json", std::process::id()))
+    }
+
+    #[test]
+    fn load_treats_missing_file_as_empty() {
+        let state = StateFile::new(temp_path("missing")).load().unwrap();
+        assert!(state.notifications.is_empty());
+        assert!(state.timers.is_empty());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let file = StateFile::new(temp_path("round-trip"));
+        let state = SavedState {
+            last_notification_id: 3,
+            notifications: vec![ScheduledNotification {
+                id: "countdown-3".into(),
+                kind: Kind::Countdown,
+                message: "ping".into(),
+                duration: Some("2 hours".into()),
+                fire_at: "2030-01-01T09:00:00+01:00".into(),
+                fire_at_utc: "2030-01-01T08:00:00Z".parse().unwrap(),
+                timezone: "Europe/Paris".into(),
+                missed: false,
+            }],
+            timers: BTreeMap::new(),
+        };
+        file.save(&state).unwrap();
+        let loaded = file.load().unwrap();
+        std::fs::remove_file(file.path()).unwrap();
+        assert_eq!(loaded.last_notification_id, 3);
+        assert_eq!(loaded.notifications[0].message, "ping");
+        assert_eq!(loaded.notifications[0].kind, Kind::Countdown);
+    }
+
+    #[test]
+    fn load_reports_corrupt_file() {
+        let file = StateFile::new(temp_path("corrupt"));
+        std::fs::write(file.path(), "not json").unwrap();
+        let err = file.load().unwrap_err();
+        std::fs::remove_file(file.path()).unwrap();
+        assert!(err.contains("Failed to parse"), "Error was: {err}");
+    }
+}
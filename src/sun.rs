@@ -0,0 +1,262 @@
+use jiff::{Span, Timestamp, civil::Date, tz::TimeZone};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::locations::Coordinates;
+
+/// Zenith angle of the sun's centre at sunrise and sunset, in degrees: the
+/// horizon plus atmospheric refraction and the sun's apparent radius.
+const SUNRISE_ZENITH: f64 = 90.833;
+
+/// Julian day number of the Unix epoch.
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+/// Why a day has no sunrise or sunset, inside the polar circles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Polar {
+    /// The sun stays below the horizon all day.
+    PolarNight,
+    /// The sun stays above the horizon all day.
+    MidnightSun,
+}
+
+/// When the sun rises, culminates, and sets on one local date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SunTimes {
+    pub sunrise: Option<Timestamp>,
+    pub sunset: Option<Timestamp>,
+    pub solar_noon: Option<Timestamp>,
+    /// Set when the sun neither rises nor sets.
+    pub polar: Option<Polar>,
+}
+
+impl SunTimes {
+    /// Seconds from sunrise to sunset: 0 in polar night, a full day under
+    /// the midnight sun, and `None` when only one of them happens.
+    pub fn day_length(&self) -> Option<i64> {
+        match (self.sunrise, self.sunset, self.polar) {
+            (_, _, Some(Polar::PolarNight)) => Some(0),
+            (_, _, Some(Polar::MidnightSun)) => Some(86_400),
+            (Some(rise), Some(set), None) if set > rise => Some(set.as_second() - rise.as_second()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    Sunrise,
+    SolarNoon,
+    Sunset,
+}
+
+/// Sunrise, solar noon, and sunset at `at` on `date` in `tz`, by the NOAA
+/// solar position equations, accurate to about a minute within 72° of the
+/// equator.
+pub fn sun_times(date: Date, at: Coordinates, tz: &TimeZone) -> SunTimes {
+    let sunrise = event_on(date, at, tz, Event::Sunrise);
+    let sunset = event_on(date, at, tz, Event::Sunset);
+    let polar = if sunrise.is_none() && sunset.is_none() {
+        let noon = julian_day(date).map(|jd| jd + 0.5 - at.longitude / 360.0);
+        noon.and_then(|jd| {
+            let (declination, _) = position(julian_century(jd));
+            let cos = hour_angle_cos(at.latitude, declination);
+            (cos.abs() > 1.0).then_some(if cos > 0.0 {
+                Polar::PolarNight
+            } else {
+                Polar::MidnightSun
+            })
+        })
+    } else {
+        None
+    };
+    SunTimes {
+        sunrise,
+        sunset,
+        solar_noon: event_on(date, at, tz, Event::SolarNoon),
+        polar,
+    }
+}
+
+/// The instant of `event` that falls on local `date` in `tz`.
+///
+/// Each UTC day has one of each event, but far from a zone's meridian it
+/// can land on the local day before or after, so the neighbouring days are
+/// tried too.
+fn event_on(date: Date, at: Coordinates, tz: &TimeZone, event: Event) -> Option<Timestamp> {
+    (-1..=1).find_map(|days| {
+        let day = date.checked_add(Span::new().days(days)).ok()?;
+        let midnight = day.to_zoned(TimeZone::UTC).ok()?.timestamp();
+        let minutes = event_minutes(julian_day(day)?, at, event)?;
+        let instant = midnight
+            .checked_add(Span::new().seconds((minutes * 60.0).round() as i64))
+            .ok()?;
+        (instant.to_zoned(tz.clone()).date() == date).then_some(instant)
+    })
+}
+
+/// Julian day at midnight UTC starting `date`.
+fn julian_day(date: Date) -> Option<f64> {
+    let midnight = date.to_zoned(TimeZone::UTC).ok()?.timestamp();
+    Some(UNIX_EPOCH_JD + midnight.as_second() as f64 / 86_400.0)
+}
+
+/// Julian centuries since J2000.0.
+fn julian_century(jd: f64) -> f64 {
+    (jd - 2451545.0) / 36525.0
+}
+
+/// Minutes after midnight UTC on Julian day `jd` at which `event` happens,
+/// or `None` when the sun does not cross the horizon that day.
+fn event_minutes(jd: f64, at: Coordinates, event: Event) -> Option<f64> {
+    // Start from mean solar noon and refine with the sun's position then.
+    let mut minutes = 720.0 - 4.0 * at.longitude;
+    for _ in 0..3 {
+        let (declination, equation_of_time) = position(julian_century(jd + minutes / 1440.0));
+        let hour_angle = match event {
+            Event::SolarNoon => 0.0,
+            Event::Sunrise | Event::Sunset => {
+                let cos = hour_angle_cos(at.latitude, declination);
+                if !(-1.0..=1.0).contains(&cos) {
+                    return None;
+                }
+                let angle = cos.acos().to_degrees();
+                if event == Event::Sunrise {
+                    angle
+                } else {
+                    -angle
+                }
+            }
+        };
+        minutes = 720.0 - 4.0 * (at.longitude + hour_angle) - equation_of_time;
+    }
+    Some(minutes)
+}
+
+/// Cosine of the sun's hour angle at sunrise; beyond ±1 the sun does not
+/// reach the horizon.
+fn hour_angle_cos(latitude: f64, declination: f64) -> f64 {
+    let latitude = latitude.to_radians();
+    SUNRISE_ZENITH.to_radians().cos() / (latitude.cos() * declination.cos())
+        - latitude.tan() * declination.tan()
+}
+
+/// The sun's declination, in radians, and the equation of time, in
+/// minutes, at Julian century `t`.
+fn position(t: f64) -> (f64, f64) {
+    let mean_longitude = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let mean_anomaly = (357.52911 + t * (35999.05029 - 0.0001537 * t)).to_radians();
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+    let center = mean_anomaly.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * mean_anomaly).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * mean_anomaly).sin() * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude =
+        (mean_longitude + center - 0.00569 - 0.00478 * omega.sin()).to_radians();
+    let seconds = 21.448 - t * (46.8150 + t * (0.00059 - t * 0.001813));
+    let obliquity = (23.0 + (26.0 + seconds / 60.0) / 60.0 + 0.00256 * omega.cos()).to_radians();
+
+    let declination = (obliquity.sin() * apparent_longitude.sin()).asin();
+    let y = (obliquity / 2.0).tan().powi(2);
+    let l0 = mean_longitude.to_radians();
+    let equation_of_time = y * (2.0 * l0).sin() - 2.0 * eccentricity * mean_anomaly.sin()
+        + 4.0 * eccentricity * y * mean_anomaly.sin() * (2.0 * l0).cos()
+        - 0.5 * y * y * (4.0 * l0).sin()
+        - 1.25 * eccentricity * eccentricity * (2.0 * mean_anomaly).sin();
+    (declination, 4.0 * equation_of_time.to_degrees())
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+
+    fn local(instant: Option<Timestamp>, zone: &str) -> String {
+        let tz = TimeZone::get(zone).unwrap();
+        instant
+            .unwrap()
+            .to_zoned(tz)
+            .strftime("%Y-%m-%d %H:%M")
+            .to_string()
+    }
+
+    /// Whether `actual` is within two minutes of `expected`.
+    fn close(actual: &str, expected: &str) -> bool {
+        let parse = |s: &str| jiff::civil::DateTime::strptime("%Y-%m-%d %H:%M", s).unwrap();
+        let minutes = parse(expected)
+            .until(parse(actual))
+            .unwrap()
+            .total(jiff::Unit::Minute)
+            .unwrap();
+        minutes.abs() <= 2.0
+    }
+
+    #[test]
+    fn sun_times_match_published_tables() {
+        let london = Coordinates {
+            latitude: 51.5074,
+            longitude: -0.1278,
+        };
+        let tz = TimeZone::get("Europe/London").unwrap();
+        let times = sun_times(date(2025, 6, 21), london, &tz);
+        for (actual, expected) in [
+            (local(times.sunrise, "Europe/London"), "2025-06-21 04:43"),
+            (local(times.solar_noon, "Europe/London"), "2025-06-21 13:02"),
+            (local(times.sunset, "Europe/London"), "2025-06-21 21:21"),
+        ] {
+            assert!(close(&actual, expected), "{actual} vs {expected}");
+        }
+        assert_eq!(times.polar, None);
+        let length = times.day_length().unwrap();
+        assert!((16 * 3600 + 36 * 60..16 * 3600 + 40 * 60).contains(&length));
+    }
+
+    #[test]
+    fn events_stay_on_the_local_date() {
+        // Tokyo rises before midnight UTC, and Kiritimati (UTC+14) is far
+        // east of its zone's meridian.
+        let tokyo = Coordinates {
+            latitude: 35.6762,
+            longitude: 139.6503,
+        };
+        let tz = TimeZone::get("Asia/Tokyo").unwrap();
+        let times = sun_times(date(2025, 12, 21), tokyo, &tz);
+        assert!(close(
+            &local(times.sunrise, "Asia/Tokyo"),
+            "2025-12-21 06:47"
+        ));
+        assert!(close(
+            &local(times.sunset, "Asia/Tokyo"),
+            "2025-12-21 16:32"
+        ));
+
+        let kiritimati = Coordinates {
+            latitude: 1.87,
+            longitude: -157.43,
+        };
+        let tz = TimeZone::get("Pacific/Kiritimati").unwrap();
+        let times = sun_times(date(2025, 3, 20), kiritimati, &tz);
+        assert!(local(times.sunrise, "Pacific/Kiritimati").starts_with("2025-03-20 06:"));
+        assert!(local(times.sunset, "Pacific/Kiritimati").starts_with("2025-03-20 18:"));
+    }
+
+    #[test]
+    fn polar_days_have_no_sunrise() {
+        let tromso = Coordinates {
+            latitude: 69.6492,
+            longitude: 18.9553,
+        };
+        let tz = TimeZone::get("Europe/Oslo").unwrap();
+        let winter = sun_times(date(2025, 12, 21), tromso, &tz);
+        assert_eq!((winter.sunrise, winter.sunset), (None, None));
+        assert_eq!(winter.polar, Some(Polar::PolarNight));
+        assert_eq!(winter.day_length(), Some(0));
+        assert!(winter.solar_noon.is_some());
+
+        let summer = sun_times(date(2025, 6, 21), tromso, &tz);
+        assert_eq!(summer.polar, Some(Polar::MidnightSun));
+        assert_eq!(summer.day_length(), Some(86_400));
+    }
+}
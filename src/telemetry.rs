@@ -0,0 +1,353 @@
+use rmcp::model::{CallToolResult, ErrorCode, JsonObject, Meta};
+
+/// Environment variable choosing how log lines are written to stderr.
+pub const LOG_FORMAT_ENV: &str = "MCP_TIME_LOG_FORMAT";
+
+/// Environment variable naming the OpenTelemetry collector that `tool_call`
+/// spans are exported to over OTLP/HTTP (e.g., "http://localhost:4318").
+pub const OTLP_ENDPOINT_ENV: &str = "MCP_TIME_OTLP_ENDPOINT";
+
+/// Path of the trace signal under an OTLP/HTTP collector's base URL.
+const OTLP_TRACES_PATH: &str = "/v1/traces";
+
+/// How log lines are written to stderr.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line, for log collectors.
+    Json,
+}
+
+impl LogFormat {
+    /// Parse "text" or "json", in any case.
+    pub fn parse(value: &str) -> Result<LogFormat, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!(
+                "Invalid log format: '{value}'. Expected 'text' or 'json'."
+            )),
+        }
+    }
+}
+
+/// Stable fingerprint of a tool call's arguments, so spans can be grouped by
+/// identical calls without recording the arguments themselves.
+///
+/// 64-bit FNV-1a over the arguments' JSON, whose keys serialize in sorted
+/// order; calls without arguments hash like an empty object.
+pub fn params_hash(arguments: Option<&JsonObject>) -> String {
+    let json = match arguments {
+        Some(arguments) => serde_json::to_string(arguments).unwrap_or_default(),
+        None => "{}".to_string(),
+    };
+    let hash = json.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Longest string argument recorded verbatim by [`sanitize_params`], in
+/// characters, and the most array items it keeps.
+const MAX_LOGGED_STRING: usize = 64;
+const MAX_LOGGED_ITEMS: usize = 8;
+
+/// A tool call's arguments as compact JSON that is safe to log: strings
+/// longer than 64 characters are cut short with their length noted, arrays
+/// keep their first 8 items and a count of the rest, and objects nested more
+/// than three levels deep are elided.
+pub fn sanitize_params(arguments: Option<&JsonObject>) -> String {
+    fn sanitize(value: &serde_json::Value, depth: usize) -> serde_json::Value {
+        use serde_json::Value;
+        match value {
+            Value::String(text) => {
+                let length = text.chars().count();
+                if length <= MAX_LOGGED_STRING {
+                    value.clone()
+                } else {
+                    let head: String = text.chars().take(MAX_LOGGED_STRING).collect();
+                    Value::String(format!("{head}... ({length} chars)"))
+                }
+            }
+            Value::Array(items) => {
+                let mut kept: Vec<Value> = items
+                    .iter()
+                    .take(MAX_LOGGED_ITEMS)
+                    .map(|item| sanitize(item, depth + 1))
+                    .collect();
+                if items.len() > MAX_LOGGED_ITEMS {
+                    kept.push(Value::String(format!(
+                        "... ({} more)",
+                        items.len() - MAX_LOGGED_ITEMS
+                    )));
+                }
+                Value::Array(kept)
+            }
+            Value::Object(_) if depth >= 3 => Value::String("{...}".into()),
+            Value::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(key, value)| (key.clone(), sanitize(value, depth + 1)))
+                    .collect(),
+            ),
+            _ => value.clone(),
+        }
+    }
+    let arguments = arguments.cloned().unwrap_or_default();
+    sanitize(&serde_json::Value::Object(arguments), 0).to_string()
+}
+
+/// How a tool call ended, as recorded on its `tool_call` span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Success,
+    /// The tool rejected its input, or the request named an unknown tool or
+    /// did not match the tool's parameters.
+    ValidationError,
+    /// The server failed while handling a valid request.
+    InternalError,
+}
+
+impl Outcome {
+    /// Classify a tool call's result, with the error code it failed with:
+    /// the tool error's `code`, or the JSON-RPC error code.
+    pub fn of(result: &Result<CallToolResult, rmcp::ErrorData>) -> (Outcome, Option<String>) {
+        match result {
+            Ok(result) if result.is_error == Some(true) => {
+                let code = result
+                    .content
+                    .first()
+                    .and_then(|content| content.as_text())
+                    .and_then(|text| serde_json::from_str::<serde_json::Value>(&text.text).ok())
+                    .and_then(|json| json["code"].as_str().map(str::to_string));
+                (Outcome::ValidationError, code)
+            }
+            Ok(_) => (Outcome::Success, None),
+            Err(error) if error.code == ErrorCode::INTERNAL_ERROR => {
+                (Outcome::InternalError, Some(error.code.0.to_string()))
+            }
+            Err(error) => (Outcome::ValidationError, Some(error.code.0.to_string())),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Success => "success",
+            Outcome::ValidationError => "validation_error",
+            Outcome::InternalError => "internal_error",
+        }
+    }
+}
+
+/// W3C `traceparent` propagated by the client in a request's `_meta`, if it
+/// is well formed (e.g., "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").
+pub fn traceparent(meta: &Meta) -> Option<&str> {
+    let value = meta.0.get("traceparent")?.as_str()?;
+    let parts: Vec<&str> = value.split('-').collect();
+    let well_formed = parts.len() == 4
+        && [2, 32, 16, 2]
+            .iter()
+            .zip(&parts)
+            .all(|(&len, part)| part.len() == len && part.bytes().all(|b| b.is_ascii_hexdigit()))
+        && parts[1].bytes().any(|b| b != b'0')
+        && parts[2].bytes().any(|b| b != b'0');
+    well_formed.then_some(value)
+}
+
+/// Read an OTLP collector URL, as the full traces URL: a base URL such as
+/// "http://localhost:4318" gets the `/v1/traces` path. Empty values export
+/// nothing.
+///
+/// Fails if the server was built without the `otlp` feature.
+pub fn parse_otlp_endpoint(value: &str) -> Result<Option<String>, String> {
+    let value = value.trim().trim_end_matches('/');
+    if value.is_empty() {
+        return Ok(None);
+    }
+    if !cfg!(feature = "otlp") {
+        return Err(format!(
+            "{OTLP_ENDPOINT_ENV} is set, but this build of mcp-time has no OTLP exporter. Rebuild with `--features otlp`."
+        ));
+    }
+    if !value.starts_with("http://") && !value.starts_with("https://") {
+        return Err(format!(
+            "Invalid {OTLP_ENDPOINT_ENV}: '{value}'. Expected an http:// or https:// URL (e.g., 'http://localhost:4318')."
+        ));
+    }
+    Ok(Some(if value.ends_with(OTLP_TRACES_PATH) {
+        value.to_string()
+    } else {
+        format!("{value}{OTLP_TRACES_PATH}")
+    }))
+}
+
+#[cfg(feature = "otlp")]
+static TRACER_PROVIDER: std::sync::OnceLock<opentelemetry_sdk::trace::SdkTracerProvider> =
+    std::sync::OnceLock::new();
+
+/// A tracing layer exporting `mcp_time` spans at info level and above, such
+/// as `tool_call`, to the OTLP/HTTP traces URL `endpoint`, in batches.
+#[cfg(feature = "otlp")]
+pub fn otlp_layer<S>(endpoint: &str) -> Result<impl tracing_subscriber::Layer<S>, String>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    use opentelemetry::trace::TracerProvider;
+    use opentelemetry_otlp::WithExportConfig;
+    use tracing_subscriber::Layer;
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint)
+        .build()
+        .map_err(|e| format!("Failed to set up OTLP export to {endpoint}: {e}"))?;
+    let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            opentelemetry_sdk::Resource::builder()
+                .with_service_name(env!("CARGO_PKG_NAME"))
+                .build(),
+        )
+        .build();
+    let tracer = provider.tracer(env!("CARGO_PKG_NAME"));
+    let _ = TRACER_PROVIDER.set(provider);
+    let spans = tracing_subscriber::filter::Targets::new()
+        .with_target(env!("CARGO_CRATE_NAME"), tracing::Level::INFO);
+    Ok(tracing_opentelemetry::layer()
+        .with_tracer(tracer)
+        .with_filter(spans))
+}
+
+/// Make the client's trace, named by a W3C `traceparent`, the parent of
+/// `span` in exported traces.
+#[cfg(feature = "otlp")]
+pub fn set_remote_parent(span: &tracing::Span, traceparent: &str) {
+    use opentelemetry::propagation::TextMapPropagator;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let carrier =
+        std::collections::HashMap::from([("traceparent".to_string(), traceparent.to_string())]);
+    let parent = opentelemetry_sdk::propagation::TraceContextPropagator::new().extract(&carrier);
+    let _ = span.set_parent(parent);
+}
+
+/// Export the spans still waiting in the OTLP batch, if export is set up.
+pub fn flush_spans() {
+    #[cfg(feature = "otlp")]
+    if let Some(provider) = TRACER_PROVIDER.get() {
+        let _ = provider.force_flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(value: serde_json::Value) -> JsonObject {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn params_hash_ignores_key_order() {
+        let a = object(serde_json::json!({"time": "14:30", "source_timezone": "UTC"}));
+        let b = object(serde_json::json!({"source_timezone": "UTC", "time": "14:30"}));
+        assert_eq!(params_hash(Some(&a)), params_hash(Some(&b)));
+        assert_eq!(params_hash(None), params_hash(Some(&JsonObject::new())));
+        assert_ne!(params_hash(Some(&a)), params_hash(None));
+        assert_eq!(params_hash(None).len(), 16);
+    }
+
+    #[test]
+    fn traceparent_accepts_only_well_formed_values() {
+        let meta = |value: &str| Meta(object(serde_json::json!({ "traceparent": value })));
+        let valid = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        assert_eq!(traceparent(&meta(valid)), Some(valid));
+        assert_eq!(traceparent(&meta("00-abc-def-01")), None);
+        assert_eq!(
+            traceparent(&meta(
+                "00-00000000000000000000000000000000-00f067aa0ba902b7-01"
+            )),
+            None
+        );
+        assert_eq!(traceparent(&Meta::new()), None);
+    }
+
+    #[test]
+    fn parse_otlp_endpoint_adds_the_traces_path() {
+        assert_eq!(parse_otlp_endpoint(" "), Ok(None));
+        if cfg!(feature = "otlp") {
+            let traces = Some("http://localhost:4318/v1/traces".to_string());
+            assert_eq!(
+                parse_otlp_endpoint("http://localhost:4318"),
+                Ok(traces.clone())
+            );
+            assert_eq!(
+                parse_otlp_endpoint("http://localhost:4318/"),
+                Ok(traces.clone())
+            );
+            assert_eq!(
+                parse_otlp_endpoint("http://localhost:4318/v1/traces"),
+                Ok(traces)
+            );
+            assert!(parse_otlp_endpoint("localhost:4318").is_err());
+        } else {
+            let error = parse_otlp_endpoint("http://localhost:4318").unwrap_err();
+            assert!(error.contains("--features otlp"), "{error}");
+        }
+    }
+
+    #[cfg(feature = "otlp")]
+    #[tokio::test]
+    async fn otlp_layer_builds_inside_a_runtime() {
+        let layer = otlp_layer::<tracing_subscriber::Registry>("http://127.0.0.1:1/v1/traces");
+        assert!(layer.is_ok());
+        flush_spans();
+    }
+
+    #[test]
+    fn sanitize_params_shortens_long_values() {
+        let long = "x".repeat(100);
+        let items: Vec<u32> = (0..10).collect();
+        let arguments = object(serde_json::json!({
+            "timezone": "Asia/Tokyo",
+            "ics": long,
+            "timestamps": items,
+            "deep": {"a": {"b": {"c": 1}}},
+        }));
+        let sanitized: serde_json::Value =
+            serde_json::from_str(&sanitize_params(Some(&arguments))).unwrap();
+        assert_eq!(sanitized["timezone"], "Asia/Tokyo");
+        assert_eq!(
+            sanitized["ics"],
+            format!("{}... (100 chars)", "x".repeat(64))
+        );
+        assert_eq!(sanitized["timestamps"].as_array().unwrap().len(), 9);
+        assert_eq!(sanitized["timestamps"][8], "... (2 more)");
+        assert_eq!(sanitized["deep"]["a"]["b"], "{...}");
+        assert_eq!(sanitize_params(None), "{}");
+    }
+
+    #[test]
+    fn outcome_separates_validation_and_internal_errors() {
+        let tool_error = CallToolResult::error(vec![rmcp::model::Content::text(
+            r#"{"code": "invalid_timezone", "message": "Unknown timezone"}"#,
+        )]);
+        assert_eq!(
+            Outcome::of(&Ok(tool_error)),
+            (Outcome::ValidationError, Some("invalid_timezone".into()))
+        );
+        assert_eq!(
+            Outcome::of(&Ok(CallToolResult::success(Vec::new()))),
+            (Outcome::Success, None)
+        );
+        let internal = rmcp::ErrorData::internal_error("boom", None);
+        assert_eq!(
+            Outcome::of(&Err(internal)),
+            (Outcome::InternalError, Some("-32603".into()))
+        );
+        let invalid = rmcp::ErrorData::invalid_params("bad", None);
+        assert_eq!(Outcome::of(&Err(invalid)).0, Outcome::ValidationError);
+    }
+}
@@ -0,0 +1,128 @@
+use serde::Serialize;
+
+/// URI of the clock tick resource. A `granularity` query parameter selects
+/// how often subscribers are notified (e.g., `time://tick?granularity=second`).
+pub const TICK_URI: &str = "time://tick";
+
+/// How often the tick resource notifies subscribers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Granularity {
+    Second,
+    #[default]
+    Minute,
+    Hour,
+}
+
+impl Granularity {
+    fn seconds(self) -> i64 {
+        match self {
+            Granularity::Second => 1,
+            Granularity::Minute => 60,
+            Granularity::Hour => 3600,
+        }
+    }
+
+    /// Time between ticks.
+    pub fn period(self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.seconds() as u64)
+    }
+}
+
+/// Contents of the tick resource at one instant.
+#[derive(Debug, Serialize)]
+pub struct Tick {
+    /// Current instant in UTC (RFC 3339), truncated to the granularity.
+    pub utc: String,
+    pub unix_seconds: i64,
+    pub granularity: Granularity,
+}
+
+/// Parse a tick resource URI, returning its granularity.
+///
+/// Returns `None` for URIs that are not the tick resource.
+pub fn parse_uri(uri: &str) -> Option<Result<Granularity, String>> {
+    let query = match uri.strip_prefix(TICK_URI)? {
+        "" => return Some(Ok(Granularity::default())),
+        rest => rest.strip_prefix('?')?,
+    };
+    let granularity = match query.strip_prefix("granularity=") {
+        Some("second") => Ok(Granularity::Second),
+        Some("minute") => Ok(Granularity::Minute),
+        Some("hour") => Ok(Granularity::Hour),
+        _ => Err(format!(
+            "Invalid tick resource URI: '{uri}'. Expected '{TICK_URI}' with an optional granularity of 'second', 'minute', or 'hour'."
+        )),
+    };
+    Some(granularity)
+}
+
+/// The tick at `now`, truncated to the start of the current period.
+pub fn tick(now: jiff::Timestamp, granularity: Granularity) -> Tick {
+    let unix_seconds = now.as_second() - now.as_second().rem_euclid(granularity.seconds());
+    let utc = jiff::Timestamp::from_second(unix_seconds).unwrap_or(now);
+    Tick {
+        utc: utc.strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        unix_seconds,
+        granularity,
+    }
+}
+
+/// Time from `now` until the start of the next period.
+pub fn until_next(now: jiff::Timestamp, granularity: Granularity) -> std::time::Duration {
+    until_next_multiple(now, granularity.period())
+}
+
+/// Time from `now` until the next whole multiple of `period` since the Unix
+/// epoch (e.g., the top of the next minute for one minute).
+pub fn until_next_multiple(
+    now: jiff::Timestamp,
+    period: std::time::Duration,
+) -> std::time::Duration {
+    let period_nanos = period.as_nanos().max(1) as i128;
+    let remaining = period_nanos - now.as_nanosecond().rem_euclid(period_nanos);
+    std::time::Duration::from_nanos(remaining as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_uri_reads_granularity() {
+        assert_eq!(parse_uri("time://tick"), Some(Ok(Granularity::Minute)));
+        assert_eq!(
+            parse_uri("time://tick?granularity=second"),
+            Some(Ok(Granularity::Second))
+        );
+        assert!(parse_uri("time://tick?granularity=day").unwrap().is_err());
+        assert_eq!(parse_uri("time://ticker"), None);
+        assert_eq!(parse_uri("file:///tmp"), None);
+    }
+
+    #[test]
+    fn tick_truncates_to_granularity() {
+        let now: jiff::Timestamp = "2025-07-04T12:34:56.789Z".parse().unwrap();
+        assert_eq!(tick(now, Granularity::Second).utc, "2025-07-04T12:34:56Z");
+        assert_eq!(tick(now, Granularity::Minute).utc, "2025-07-04T12:34:00Z");
+        assert_eq!(tick(now, Granularity::Hour).utc, "2025-07-04T12:00:00Z");
+    }
+
+    #[test]
+    fn until_next_waits_for_period_boundary() {
+        let now: jiff::Timestamp = "2025-07-04T12:34:56.5Z".parse().unwrap();
+        assert_eq!(
+            until_next(now, Granularity::Minute),
+            std::time::Duration::from_millis(3500)
+        );
+        let on_boundary: jiff::Timestamp = "2025-07-04T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            until_next(on_boundary, Granularity::Hour),
+            std::time::Duration::from_secs(3600)
+        );
+        assert_eq!(
+            until_next_multiple(now, std::time::Duration::from_secs(15 * 60)),
+            std::time::Duration::from_millis(10 * 60 * 1000 + 3500)
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Whether a timer is currently counting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TimerState {
+    Running,
+    Paused,
+}
+
+/// A named stopwatch that can be paused and resumed.
+///
+/// Serializable so timers can be saved across restarts; all instants are
+/// wall-clock time, so a running timer keeps counting while the server is
+/// down.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timer {
+    started_at: jiff::Timestamp,
+    /// Time counted before the current running stretch began.
+    banked: jiff::SignedDuration,
+    /// Start of the current running stretch; `None` while paused.
+    running_since: Option<jiff::Timestamp>,
+    /// Start of the current pause; `None` while running.
+    paused_since: Option<jiff::Timestamp>,
+    /// Total time spent paused in completed pauses.
+    paused: jiff::SignedDuration,
+}
+
+impl Timer {
+    fn elapsed(&self, now: jiff::Timestamp) -> jiff::SignedDuration {
+        match self.running_since {
+            Some(since) => self.banked + since.duration_until(now),
+            None => self.banked,
+        }
+    }
+
+    fn paused(&self, now: jiff::Timestamp) -> jiff::SignedDuration {
+        match self.paused_since {
+            Some(since) => self.paused + since.duration_until(now),
+            None => self.paused,
+        }
+    }
+}
+
+/// Snapshot of a timer at one instant.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TimerStatus {
+    pub name: String,
+    pub state: TimerState,
+    /// When the timer was started, in UTC.
+    pub started_at: String,
+    /// Running time, excluding pauses.
+    pub elapsed: String,
+    pub elapsed_seconds: f64,
+    /// Time spent paused, including the current pause.
+    pub paused_seconds: f64,
+}
+
+/// Registry of named timers.
+///
+/// Clones share the same registry.
+#[derive(Clone, Default)]
+pub struct Timers {
+    timers: Arc<Mutex<HashMap<String, Timer>>>,
+}
+
+impl Timers {
+    /// Start a new timer called `name`.
+    pub fn start(&self, name: &str, now: jiff::Timestamp) -> Result<TimerStatus, String> {
+        if name.is_empty() {
+            return Err("Timer name must not be empty.".into());
+        }
+        let mut timers = self.lock();
+        if timers.contains_key(name) {
+            return Err(format!("A timer named '{name}' already exists."));
+        }
+        let timer = Timer {
+            started_at: now,
+            banked: jiff::SignedDuration::ZERO,
+            running_since: Some(now),
+            paused_since: None,
+            paused: jiff::SignedDuration::ZERO,
+        };
+        let status = status(name, &timer, now);
+        timers.insert(name.to_string(), timer);
+        Ok(status)
+    }
+
+    /// Pause a running timer, banking the time counted so far.
+    pub fn pause(&self, name: &str, now: jiff::Timestamp) -> Result<TimerStatus, String> {
+        self.update(name, now, |timer| {
+            if timer.running_since.is_none() {
+                return Err(format!("Timer '{name}' is already paused."));
+            }
+            timer.banked = timer.elapsed(now);
+            timer.running_since = None;
+            timer.paused_since = Some(now);
+            Ok(())
+        })
+    }
+
+    /// Resume a paused timer.
+    pub fn resume(&self, name: &str, now: jiff::Timestamp) -> Result<TimerStatus, String> {
+        self.update(name, now, |timer| {
+            if timer.paused_since.is_none() {
+                return Err(format!("Timer '{name}' is not paused."));
+            }
+            timer.paused = timer.paused(now);
+            timer.paused_since = None;
+            timer.running_since = Some(now);
+            Ok(())
+        })
+    }
+
+    /// Status of every timer, ordered by name.
+    pub fn list(&self, now: jiff::Timestamp) -> Vec<TimerStatus> {
+        let mut statuses: Vec<TimerStatus> = self
+            .lock()
+            .iter()
+            .map(|(name, timer)| status(name, timer, now))
+            .collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        statuses
+    }
+
+    /// Copy of every timer, for saving.
+    pub fn export(&self) -> BTreeMap<String, Timer> {
+        self.lock()
+            .iter()
+            .map(|(name, timer)| (name.clone(), timer.clone()))
+            .collect()
+    }
+
+    /// Add previously exported timers, replacing any with the same name.
+    pub fn import(&self, timers: BTreeMap<String, Timer>) {
+        self.lock().extend(timers);
+    }
+
+    /// Current status of a timer.
+    pub fn get(&self, name: &str, now: jiff::Timestamp) -> Result<TimerStatus, String> {
+        self.update(name, now, |_| Ok(()))
+    }
+
+    /// Remove a timer, returning its final status.
+    pub fn stop(&self, name: &str, now: jiff::Timestamp) -> Result<TimerStatus, String> {
+        let timer = self.lock().remove(name).ok_or_else(|| unknown(name))?;
+        Ok(status(name, &timer, now))
+    }
+
+    fn update(
+        &self,
+        name: &str,
+        now: jiff::Timestamp,
+        change: impl FnOnce(&mut Timer) -> Result<(), String>,
+    ) -> Result<TimerStatus, String> {
+        let mut timers = self.lock();
+        let timer = timers.get_mut(name).ok_or_else(|| unknown(name))?;
+        change(timer)?;
+        Ok(status(name, timer, now))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Timer>> {
+        self.timers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn unknown(name: &str) -> String {
+    format!("No timer named '{name}'.")
+}
+
+fn status(name: &str, timer: &Timer, now: jiff::Timestamp) -> TimerStatus {
+    let elapsed = timer.elapsed(now);
+    TimerStatus {
+        name: name.to_string(),
+        state: if timer.running_since.is_some() {
+            TimerState::Running
+        } else {
+            TimerState::Paused
+        },
+        started_at: timer.started_at.to_string(),
+        elapsed: format!("{elapsed:#}"),
+        elapsed_seconds: elapsed.as_secs_f64(),
+        paused_seconds: timer.paused(now).as_secs_f64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> jiff::Timestamp {
+        jiff::Timestamp::from_second(1_750_000_000 + seconds).unwrap()
+    }
+
+    #[test]
+    fn elapsed_excludes_paused_intervals() {
+        let timers = Timers::default();
+        timers.start("build", at(0)).unwrap();
+        timers.pause("build", at(60)).unwrap();
+        let paused = timers.get("build", at(90)).unwrap();
+        assert_eq!(paused.state, TimerState::Paused);
+        assert_eq!(paused.elapsed_seconds, 60.0);
+        assert_eq!(paused.paused_seconds, 30.0);
+
+        timers.resume("build", at(120)).unwrap();
+        let running = timers.get("build", at(150)).unwrap();
+        assert_eq!(running.state, TimerState::Running);
+        assert_eq!(running.elapsed_seconds, 90.0);
+        assert_eq!(running.paused_seconds, 60.0);
+        assert_eq!(running.elapsed, "1m 30s");
+    }
+
+    #[test]
+    fn pause_and_resume_reject_wrong_state() {
+        let timers = Timers::default();
+        timers.start("t", at(0)).unwrap();
+        assert!(
+            timers
+                .resume("t", at(1))
+                .unwrap_err()
+                .contains("not paused")
+        );
+        timers.pause("t", at(2)).unwrap();
+        assert!(
+            timers
+                .pause("t", at(3))
+                .unwrap_err()
+                .contains("already paused")
+        );
+    }
+
+    #[test]
+    fn start_rejects_duplicate_names_and_stop_removes() {
+        let timers = Timers::default();
+        timers.start("t", at(0)).unwrap();
+        assert!(timers.start("t", at(1)).is_err());
+        assert_eq!(timers.stop("t", at(10)).unwrap().elapsed_seconds, 10.0);
+        assert!(timers.get("t", at(11)).unwrap_err().contains("No timer"));
+    }
+
+    #[test]
+    fn list_reports_every_timer_by_name() {
+        let timers = Timers::default();
+        timers.start("b", at(0)).unwrap();
+        timers.start("a", at(10)).unwrap();
+        timers.pause("b", at(20)).unwrap();
+        let list = timers.list(at(30));
+        let names: Vec<&str> = list.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(list[0].elapsed_seconds, 20.0);
+        assert_eq!(list[1].state, TimerState::Paused);
+    }
+
+    #[test]
+    fn export_and_import_round_trip() {
+        let timers = Timers::default();
+        timers.start("a", at(0)).unwrap();
+        timers.pause("a", at(30)).unwrap();
+        let json = serde_json::to_string(&timers.export()).unwrap();
+
+        let restored = Timers::default();
+        restored.import(serde_json::from_str(&json).unwrap());
+        let status = restored.get("a", at(100)).unwrap();
+        assert_eq!(status.state, TimerState::Paused);
+        assert_eq!(status.elapsed_seconds, 30.0);
+    }
+}
@@ -0,0 +1,879 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::{format_offset_diff, format_utc_offset};
+
+/// A stretch of time during which a zone uses one offset and abbreviation.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AbbreviationPeriod {
+    pub abbreviation: String,
+    pub utc_offset: String,
+    pub is_dst: bool,
+    /// Start of the period (inclusive), in the period's own offset.
+    pub start: String,
+    /// End of the period (exclusive), in the period's own offset.
+    pub end: String,
+}
+
+/// A distinct abbreviation used by a zone, with the offset it stands for.
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+pub struct AbbreviationMeaning {
+    pub abbreviation: String,
+    pub utc_offset: String,
+    pub is_dst: bool,
+    /// `true` for numeric designations like "+0545", which the tzdb uses
+    /// where no real-world abbreviation exists.
+    pub numeric: bool,
+}
+
+/// Offset, abbreviation, and DST flag in effect from some instant onward.
+struct OffsetState {
+    start: jiff::Timestamp,
+    offset: jiff::tz::Offset,
+    abbreviation: String,
+    is_dst: bool,
+}
+
+/// Offset states covering `[start, end)`, beginning with the one in effect
+/// at `start` and followed by one per transition.
+fn offset_states(
+    tz: &jiff::tz::TimeZone,
+    start: jiff::Timestamp,
+    end: jiff::Timestamp,
+) -> Vec<OffsetState> {
+    let info = tz.to_offset_info(start);
+    let mut states = vec![OffsetState {
+        start,
+        offset: info.offset(),
+        abbreviation: info.abbreviation().to_string(),
+        is_dst: info.dst().is_dst(),
+    }];
+    states.extend(
+        tz.following(start)
+            .take_while(|t| t.timestamp() < end)
+            .map(|t| OffsetState {
+                start: t.timestamp(),
+                offset: t.offset(),
+                abbreviation: t.abbreviation().to_string(),
+                is_dst: t.dst().is_dst(),
+            }),
+    );
+    states
+}
+
+/// List the abbreviation periods of `tz` between `start` and `end`.
+///
+/// Consecutive transitions that leave the offset, abbreviation, and DST flag
+/// unchanged are merged into one period.
+pub fn abbreviation_periods(
+    tz: &jiff::tz::TimeZone,
+    start: jiff::Timestamp,
+    end: jiff::Timestamp,
+) -> Vec<AbbreviationPeriod> {
+    let mut states = offset_states(tz, start, end);
+    states.dedup_by(|next, prev| {
+        next.offset == prev.offset
+            && next.abbreviation == prev.abbreviation
+            && next.is_dst == prev.is_dst
+    });
+
+    let ends: Vec<jiff::Timestamp> = states
+        .iter()
+        .skip(1)
+        .map(|s| s.start)
+        .chain([end])
+        .collect();
+    states
+        .into_iter()
+        .zip(ends)
+        .map(|(state, period_end)| {
+            let fixed = jiff::tz::TimeZone::fixed(state.offset);
+            let render = |ts: jiff::Timestamp| {
+                ts.to_zoned(fixed.clone())
+                    .strftime("%Y-%m-%dT%H:%M:%S%:z")
+                    .to_string()
+            };
+            AbbreviationPeriod {
+                utc_offset: format_utc_offset(state.offset),
+                start: render(state.start),
+                end: render(period_end),
+                abbreviation: state.abbreviation,
+                is_dst: state.is_dst,
+            }
+        })
+        .collect()
+}
+
+/// Distinct abbreviations across `periods`, in order of first use.
+pub fn abbreviation_meanings(periods: &[AbbreviationPeriod]) -> Vec<AbbreviationMeaning> {
+    let mut meanings: Vec<AbbreviationMeaning> = Vec::new();
+    for period in periods {
+        let meaning = AbbreviationMeaning {
+            abbreviation: period.abbreviation.clone(),
+            utc_offset: period.utc_offset.clone(),
+            is_dst: period.is_dst,
+            numeric: period.abbreviation.starts_with(['+', '-']),
+        };
+        if !meanings.contains(&meaning) {
+            meanings.push(meaning);
+        }
+    }
+    meanings
+}
+
+/// A change in one zone's offset, abbreviation, or DST flag.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ZoneTransition {
+    /// Instant of the change, in the offset that takes effect.
+    pub datetime: String,
+    pub utc: String,
+    pub utc_offset_before: String,
+    pub utc_offset_after: String,
+    /// Change in offset, e.g. "+1:00" when clocks go forward an hour.
+    pub change: String,
+    pub abbreviation_before: String,
+    pub abbreviation: String,
+    /// Whether DST is in effect after the change.
+    pub is_dst: bool,
+}
+
+/// Transitions of `tz` after `start` and before `end`, oldest first.
+pub fn zone_transitions(
+    tz: &jiff::tz::TimeZone,
+    start: jiff::Timestamp,
+    end: jiff::Timestamp,
+) -> Vec<ZoneTransition> {
+    offset_states(tz, start, end)
+        .windows(2)
+        .map(|pair| {
+            let (before, after) = (&pair[0], &pair[1]);
+            ZoneTransition {
+                datetime: after
+                    .start
+                    .to_zoned(jiff::tz::TimeZone::fixed(after.offset))
+                    .strftime("%Y-%m-%dT%H:%M:%S%:z")
+                    .to_string(),
+                utc: after.start.strftime("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                utc_offset_before: format_utc_offset(before.offset),
+                utc_offset_after: format_utc_offset(after.offset),
+                change: format_offset_diff(after.offset.seconds() - before.offset.seconds()),
+                abbreviation_before: before.abbreviation.clone(),
+                abbreviation: after.abbreviation.clone(),
+                is_dst: after.is_dst,
+            }
+        })
+        .collect()
+}
+
+/// A DST change that one or more zones make at the same instant.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DstEvent {
+    /// Instant of the change, in UTC.
+    pub instant: String,
+    /// Hours from the reference time to the change (negative for past changes).
+    pub hours_from_now: f64,
+    /// `spring_forward` when clocks move ahead, `fall_back` when they go back.
+    pub direction: &'static str,
+    pub from_offset: String,
+    pub to_offset: String,
+    pub zones: Vec<String>,
+}
+
+/// Zones in the tz database, skipping legacy names without a region
+/// (e.g., "EST5EDT", "GB") that duplicate a canonical zone.
+fn regional_zones() -> Vec<(String, jiff::tz::TimeZone)> {
+    jiff::tz::db()
+        .available()
+        .filter(|name| name.as_str().contains('/'))
+        .filter_map(|name| {
+            let tz = jiff::tz::TimeZone::get(name.as_str()).ok()?;
+            Some((name.as_str().to_string(), tz))
+        })
+        .collect()
+}
+
+/// The offsets on either side of `transition`, if it switches DST on or off.
+fn dst_change(
+    tz: &jiff::tz::TimeZone,
+    transition: &jiff::tz::TimeZoneTransition,
+) -> Option<(jiff::tz::Offset, jiff::tz::Offset)> {
+    let before = tz.to_offset_info(
+        transition
+            .timestamp()
+            .checked_sub(jiff::SignedDuration::from_secs(1))
+            .ok()?,
+    );
+    let changes_dst = before.dst().is_dst() != transition.dst().is_dst();
+    (changes_dst && before.offset() != transition.offset())
+        .then(|| (before.offset(), transition.offset()))
+}
+
+type ChangeKey = (jiff::Timestamp, jiff::tz::Offset, jiff::tz::Offset);
+
+/// Group per-zone changes into events ordered by instant.
+fn group_changes(
+    changes: impl IntoIterator<Item = (ChangeKey, String)>,
+    now: jiff::Timestamp,
+) -> Vec<DstEvent> {
+    let mut grouped: BTreeMap<ChangeKey, Vec<String>> = BTreeMap::new();
+    for (key, zone) in changes {
+        grouped.entry(key).or_default().push(zone);
+    }
+    grouped
+        .into_iter()
+        .map(|((instant, from, to), zones)| DstEvent {
+            instant: instant.to_string(),
+            hours_from_now: (instant.as_second() - now.as_second()) as f64 / 3600.0,
+            direction: if to > from {
+                "spring_forward"
+            } else {
+                "fall_back"
+            },
+            from_offset: format_utc_offset(from),
+            to_offset: format_utc_offset(to),
+            zones,
+        })
+        .collect()
+}
+
+/// DST changes in any zone after `now` and before `end`, grouped by
+/// instant and offset change.
+pub fn upcoming_dst_events(now: jiff::Timestamp, end: jiff::Timestamp) -> Vec<DstEvent> {
+    let changes = regional_zones().into_iter().flat_map(|(name, tz)| {
+        tz.following(now)
+            .take_while(|t| t.timestamp() < end)
+            .filter_map(|t| dst_change(&tz, &t).map(|(from, to)| (t.timestamp(), from, to)))
+            .map(|key| (key, name.clone()))
+            .collect::<Vec<_>>()
+    });
+    group_changes(changes, now)
+}
+
+/// The most recent DST changes in any zone at or before `now`.
+///
+/// Every zone changing at that latest instant is included, split by offset
+/// change.
+pub fn last_dst_events(now: jiff::Timestamp) -> Vec<DstEvent> {
+    let latest: Vec<(ChangeKey, String)> = regional_zones()
+        .into_iter()
+        .filter_map(|(name, tz)| {
+            tz.preceding(
+                now.checked_add(jiff::SignedDuration::from_nanos(1))
+                    .unwrap_or(now),
+            )
+            .find_map(|t| dst_change(&tz, &t).map(|(from, to)| (t.timestamp(), from, to)))
+            .map(|key| (key, name))
+        })
+        .collect();
+    let Some(instant) = latest.iter().map(|((ts, _, _), _)| *ts).max() else {
+        return Vec::new();
+    };
+    group_changes(
+        latest.into_iter().filter(|((ts, _, _), _)| *ts == instant),
+        now,
+    )
+}
+
+/// A change in one zone's UTC offset, with the local day it lands on.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct OffsetChange {
+    /// Instant of the change, in UTC.
+    pub instant: String,
+    /// `spring_forward` when clocks move ahead, `fall_back` when they go back.
+    pub direction: &'static str,
+    pub from_offset: String,
+    pub to_offset: String,
+    /// Whether DST is in effect after the change.
+    pub is_dst: bool,
+    /// Local calendar date of the change (YYYY-MM-DD).
+    pub local_date: String,
+    /// Length of that local day in hours: 23 or 25 for a one-hour change.
+    pub hours_in_day: f64,
+}
+
+/// Offset changes of one zone within a report's date range.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ZoneImpact {
+    pub timezone: String,
+    pub changes: Vec<OffsetChange>,
+}
+
+/// A change in the difference between two zones' offsets.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DifferenceChange {
+    /// Instant of the change, in UTC.
+    pub instant: String,
+    pub from_difference: String,
+    pub to_difference: String,
+}
+
+/// How the offset difference between two zones evolves over a report.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PairImpact {
+    /// The two zones; differences are the second zone's offset minus the
+    /// first's.
+    pub zones: [String; 2],
+    /// Difference in effect at the start of the range.
+    pub initial_difference: String,
+    pub changes: Vec<DifferenceChange>,
+}
+
+/// Offset changes per zone and difference changes per pair of zones.
+#[derive(Debug)]
+pub struct DstImpact {
+    pub zones: Vec<ZoneImpact>,
+    pub pairs: Vec<PairImpact>,
+}
+
+/// Summarize how the offsets of `zones` change on the local dates `from`
+/// through `to` (inclusive), and how each pair's difference changes as a
+/// result.
+///
+/// Each zone's changes are found within its own local days, so a change just
+/// after midnight on `from` is included in every zone.
+pub fn dst_impact(
+    zones: &[jiff::tz::TimeZone],
+    from: jiff::civil::Date,
+    to: jiff::civil::Date,
+) -> Result<DstImpact, jiff::Error> {
+    let mut impacts = Vec::new();
+    let mut instants = Vec::new();
+    let mut range_start: Option<jiff::Timestamp> = None;
+    for tz in zones {
+        let start = from.to_zoned(tz.clone())?.start_of_day()?.timestamp();
+        let end = to
+            .tomorrow()?
+            .to_zoned(tz.clone())?
+            .start_of_day()?
+            .timestamp();
+        range_start = Some(range_start.map_or(start, |earliest| earliest.min(start)));
+
+        let mut changes = Vec::new();
+        for pair in offset_states(tz, start, end).windows(2) {
+            let (before, after) = (&pair[0], &pair[1]);
+            if before.offset == after.offset {
+                continue;
+            }
+            let local = after.start.to_zoned(tz.clone());
+            let day_start = local.start_of_day()?;
+            let day_end = local.tomorrow()?.start_of_day()?;
+            instants.push(after.start);
+            changes.push(OffsetChange {
+                instant: after.start.to_string(),
+                direction: if after.offset > before.offset {
+                    "spring_forward"
+                } else {
+                    "fall_back"
+                },
+                from_offset: format_utc_offset(before.offset),
+                to_offset: format_utc_offset(after.offset),
+                is_dst: after.is_dst,
+                local_date: local.date().to_string(),
+                hours_in_day: day_start.duration_until(&day_end).as_secs_f64() / 3600.0,
+            });
+        }
+        impacts.push(ZoneImpact {
+            timezone: tz.iana_name().unwrap_or("UTC").to_string(),
+            changes,
+        });
+    }
+    instants.sort();
+    instants.dedup();
+
+    let mut pairs = Vec::new();
+    let Some(range_start) = range_start else {
+        return Ok(DstImpact {
+            zones: impacts,
+            pairs,
+        });
+    };
+    for (i, first) in zones.iter().enumerate() {
+        for (j, second) in zones.iter().enumerate().skip(i + 1) {
+            let difference = |ts| second.to_offset(ts).seconds() - first.to_offset(ts).seconds();
+            let mut current = difference(range_start);
+            let initial_difference = format_offset_diff(current);
+            let mut changes = Vec::new();
+            for &instant in &instants {
+                let next = difference(instant);
+                if next != current {
+                    changes.push(DifferenceChange {
+                        instant: instant.to_string(),
+                        from_difference: format_offset_diff(current),
+                        to_difference: format_offset_diff(next),
+                    });
+                    current = next;
+                }
+            }
+            pairs.push(PairImpact {
+                zones: [impacts[i].timezone.clone(), impacts[j].timezone.clone()],
+                initial_difference,
+                changes,
+            });
+        }
+    }
+    Ok(DstImpact {
+        zones: impacts,
+        pairs,
+    })
+}
+
+/// How often `compare_zones` samples the difference between two zones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Granularity {
+    /// Once a day, at noon in the first zone.
+    #[default]
+    Daily,
+    /// Every hour, on the hour in the first zone.
+    Hourly,
+}
+
+/// The two zones' clocks at one sampled instant.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ComparisonRow {
+    pub utc: String,
+    pub first: String,
+    pub second: String,
+    /// The second zone's offset minus the first's (e.g., "+5:00").
+    pub difference: String,
+    /// Set when the difference is not the one at the previous sample.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub changed: bool,
+}
+
+/// Consecutive samples with the same difference.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DifferencePeriod {
+    /// First sample in the period, in the first zone.
+    pub from: String,
+    /// Last sample in the period, in the first zone.
+    pub to: String,
+    pub samples: usize,
+    pub difference: String,
+    /// Set when both zones observe DST but only one is on it, as in the
+    /// weeks when one zone has changed its clocks and the other has not yet.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub mismatch: bool,
+}
+
+/// A moment when the difference between two zones changes.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ComparisonChange {
+    /// Instant of the change, in UTC.
+    pub utc: String,
+    pub first: String,
+    pub second: String,
+    pub from_difference: String,
+    pub to_difference: String,
+    /// The zones whose clocks changed at that instant.
+    pub changed_zones: Vec<String>,
+}
+
+/// The offset difference between two zones over a range of dates.
+#[derive(Debug)]
+pub struct ZoneComparison {
+    /// The difference between the zones' standard (non-DST) offsets, which
+    /// does not depend on the range compared.
+    pub usual_difference: String,
+    pub rows: Vec<ComparisonRow>,
+    pub periods: Vec<DifferencePeriod>,
+    pub changes: Vec<ComparisonChange>,
+}
+
+/// `tz`'s offset outside DST around `ts`: its offset at `ts` when it is not
+/// on DST, and otherwise that of the nearest transition off DST.
+fn standard_offset(tz: &jiff::tz::TimeZone, ts: jiff::Timestamp) -> jiff::tz::Offset {
+    let info = tz.to_offset_info(ts);
+    if !info.dst().is_dst() {
+        return info.offset();
+    }
+    tz.preceding(ts)
+        .chain(tz.following(ts))
+        .find(|t| !t.dst().is_dst())
+        .map_or(info.offset(), |t| t.offset())
+}
+
+/// Whether `tz` is on DST at some point in the year from `ts`.
+fn observes_dst(tz: &jiff::tz::TimeZone, ts: jiff::Timestamp) -> bool {
+    let Ok(year_later) = ts.checked_add(jiff::SignedDuration::from_hours(366 * 24)) else {
+        return tz.to_offset_info(ts).dst().is_dst();
+    };
+    tz.to_offset_info(ts).dst().is_dst()
+        || tz
+            .following(ts)
+            .take_while(|t| t.timestamp() < year_later)
+            .any(|t| t.dst().is_dst())
+}
+
+/// Compare `second`'s offset with `first`'s over the local dates `from`
+/// through `to` (inclusive) of `first`, sampled at `granularity`.
+pub fn compare_zones(
+    first: &jiff::tz::TimeZone,
+    second: &jiff::tz::TimeZone,
+    from: jiff::civil::Date,
+    to: jiff::civil::Date,
+    granularity: Granularity,
+) -> Result<ZoneComparison, jiff::Error> {
+    let start = from.to_zoned(first.clone())?.start_of_day()?.timestamp();
+    let end = to
+        .tomorrow()?
+        .to_zoned(first.clone())?
+        .start_of_day()?
+        .timestamp();
+    let samples: Vec<jiff::Timestamp> = match granularity {
+        Granularity::Daily => from
+            .series(jiff::Span::new().days(1))
+            .take_while(|date| *date <= to)
+            .map(|date| {
+                date.at(12, 0, 0, 0)
+                    .to_zoned(first.clone())
+                    .map(|zdt| zdt.timestamp())
+            })
+            .collect::<Result<_, _>>()?,
+        Granularity::Hourly => start
+            .series(jiff::Span::new().hours(1))
+            .take_while(|ts| *ts < end)
+            .collect(),
+    };
+    let difference = |ts| second.to_offset(ts).seconds() - first.to_offset(ts).seconds();
+    let local = |ts: jiff::Timestamp, tz: &jiff::tz::TimeZone| {
+        ts.to_zoned(tz.clone())
+            .strftime("%Y-%m-%dT%H:%M:%S%:z")
+            .to_string()
+    };
+
+    let mismatch = |ts| {
+        let on_dst = |tz: &jiff::tz::TimeZone| tz.to_offset_info(ts).dst().is_dst();
+        observes_dst(first, ts) && observes_dst(second, ts) && on_dst(first) != on_dst(second)
+    };
+
+    let mut rows = Vec::new();
+    let mut periods: Vec<DifferencePeriod> = Vec::new();
+    let mut previous = None;
+    for &ts in &samples {
+        let diff = difference(ts);
+        let text = format_offset_diff(diff);
+        rows.push(ComparisonRow {
+            utc: ts.to_string(),
+            first: local(ts, first),
+            second: local(ts, second),
+            difference: text.clone(),
+            changed: previous.is_some_and(|previous| previous != diff),
+        });
+        match periods.last_mut() {
+            Some(period) if previous == Some(diff) => {
+                period.to = local(ts, first);
+                period.samples += 1;
+            }
+            _ => periods.push(DifferencePeriod {
+                from: local(ts, first),
+                to: local(ts, first),
+                samples: 1,
+                difference: text,
+                mismatch: mismatch(ts),
+            }),
+        }
+        previous = Some(diff);
+    }
+    let usual_difference = format_offset_diff(
+        standard_offset(second, start).seconds() - standard_offset(first, start).seconds(),
+    );
+
+    let mut instants: Vec<jiff::Timestamp> = [first, second]
+        .iter()
+        .flat_map(|tz| {
+            offset_states(tz, start, end)
+                .into_iter()
+                .skip(1)
+                .map(|state| state.start)
+        })
+        .collect();
+    instants.sort();
+    instants.dedup();
+    let mut changes = Vec::new();
+    for instant in instants {
+        let Ok(before) = instant.checked_sub(jiff::SignedDuration::from_secs(1)) else {
+            continue;
+        };
+        let (old, new) = (difference(before), difference(instant));
+        if old == new {
+            continue;
+        }
+        changes.push(ComparisonChange {
+            utc: instant.to_string(),
+            first: local(instant, first),
+            second: local(instant, second),
+            from_difference: format_offset_diff(old),
+            to_difference: format_offset_diff(new),
+            changed_zones: [first, second]
+                .into_iter()
+                .filter(|tz| tz.to_offset(before) != tz.to_offset(instant))
+                .map(|tz| tz.iana_name().unwrap_or("UTC").to_string())
+                .collect(),
+        });
+    }
+
+    Ok(ZoneComparison {
+        usual_difference,
+        rows,
+        periods,
+        changes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn year_bounds(tz: &jiff::tz::TimeZone, year: i16) -> (jiff::Timestamp, jiff::Timestamp) {
+        let start = jiff::civil::date(year, 1, 1).to_zoned(tz.clone()).unwrap();
+        let end = jiff::civil::date(year + 1, 1, 1)
+            .to_zoned(tz.clone())
+            .unwrap();
+        (start.timestamp(), end.timestamp())
+    }
+
+    #[test]
+    fn abbreviation_periods_lists_cet_and_cest() {
+        let tz = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+        let (start, end) = year_bounds(&tz, 2025);
+        let periods = abbreviation_periods(&tz, start, end);
+        let abbreviations: Vec<&str> = periods.iter().map(|p| p.abbreviation.as_str()).collect();
+        assert_eq!(abbreviations, vec!["CET", "CEST", "CET"]);
+        assert_eq!(periods[0].start, "2025-01-01T00:00:00+01:00");
+        assert_eq!(periods[1].start, "2025-03-30T03:00:00+02:00");
+        assert_eq!(periods[1].end, "2025-10-26T03:00:00+02:00");
+        assert_eq!(periods[2].start, "2025-10-26T02:00:00+01:00");
+        assert_eq!(periods[2].end, "2026-01-01T00:00:00+01:00");
+        assert!(periods[1].is_dst);
+    }
+
+    #[test]
+    fn abbreviation_periods_returns_single_period_without_dst() {
+        let tz = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+        let (start, end) = year_bounds(&tz, 2025);
+        let periods = abbreviation_periods(&tz, start, end);
+        assert_eq!(periods.len(), 1);
+        assert_eq!(periods[0].abbreviation, "JST");
+    }
+
+    #[test]
+    fn zone_transitions_reports_both_sides_of_each_change() {
+        let tz = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+        let (start, end) = year_bounds(&tz, 2025);
+        let transitions = zone_transitions(&tz, start, end);
+        assert_eq!(transitions.len(), 2);
+        let spring = &transitions[0];
+        assert_eq!(spring.datetime, "2025-03-30T03:00:00+02:00");
+        assert_eq!(spring.utc, "2025-03-30T01:00:00Z");
+        assert_eq!(
+            (
+                spring.utc_offset_before.as_str(),
+                spring.utc_offset_after.as_str()
+            ),
+            ("+01:00", "+02:00")
+        );
+        assert_eq!(spring.change, "+1:00");
+        assert_eq!(
+            (
+                spring.abbreviation_before.as_str(),
+                spring.abbreviation.as_str()
+            ),
+            ("CET", "CEST")
+        );
+        assert!(spring.is_dst);
+        assert_eq!(transitions[1].change, "-1:00");
+        assert!(!transitions[1].is_dst);
+    }
+
+    #[test]
+    fn abbreviation_meanings_deduplicates_and_flags_numeric() {
+        let tz = jiff::tz::TimeZone::get("Europe/Berlin").unwrap();
+        let (start, end) = year_bounds(&tz, 2025);
+        let meanings = abbreviation_meanings(&abbreviation_periods(&tz, start, end));
+        assert_eq!(meanings.len(), 2);
+        assert_eq!(meanings[0].abbreviation, "CET");
+        assert_eq!(meanings[0].utc_offset, "+01:00");
+        assert_eq!(meanings[1].abbreviation, "CEST");
+
+        let tz = jiff::tz::TimeZone::get("Asia/Kathmandu").unwrap();
+        let (start, end) = year_bounds(&tz, 2025);
+        let meanings = abbreviation_meanings(&abbreviation_periods(&tz, start, end));
+        assert!(meanings[0].numeric, "Got: {meanings:?}");
+    }
+
+    #[test]
+    fn upcoming_dst_events_groups_zones_by_instant() {
+        let now: jiff::Timestamp = "2025-03-01T00:00:00Z".parse().unwrap();
+        let end: jiff::Timestamp = "2025-04-01T00:00:00Z".parse().unwrap();
+        let events = upcoming_dst_events(now, end);
+        let us = events
+            .iter()
+            .find(|e| e.zones.iter().any(|z| z == "America/New_York"))
+            .unwrap();
+        assert_eq!(us.instant, "2025-03-09T07:00:00Z");
+        assert_eq!(us.direction, "spring_forward");
+        assert_eq!(us.from_offset, "-05:00");
+        assert_eq!(us.to_offset, "-04:00");
+        assert_eq!(us.hours_from_now, 199.0);
+        let eu = events
+            .iter()
+            .find(|e| e.zones.iter().any(|z| z == "Europe/Berlin"))
+            .unwrap();
+        assert_eq!(eu.instant, "2025-03-30T01:00:00Z");
+        assert!(eu.zones.iter().any(|z| z == "Europe/Paris"));
+        assert!(events.windows(2).all(|w| w[0].instant <= w[1].instant));
+        assert!(
+            events
+                .iter()
+                .all(|e| !e.zones.iter().any(|z| z == "Asia/Tokyo"))
+        );
+    }
+
+    #[test]
+    fn last_dst_events_finds_most_recent_change() {
+        let now: jiff::Timestamp = "2025-03-31T00:00:00Z".parse().unwrap();
+        let events = last_dst_events(now);
+        assert!(!events.is_empty());
+        assert!(events.iter().all(|e| e.instant == "2025-03-30T01:00:00Z"));
+        assert!(events[0].hours_from_now < 0.0);
+    }
+
+    #[test]
+    fn dst_impact_reports_changes_short_days_and_pair_differences() {
+        let zones: Vec<jiff::tz::TimeZone> = ["America/New_York", "Europe/London", "Asia/Tokyo"]
+            .iter()
+            .map(|zone| jiff::tz::TimeZone::get(zone).unwrap())
+            .collect();
+        let impact = dst_impact(
+            &zones,
+            jiff::civil::date(2025, 3, 1),
+            jiff::civil::date(2025, 4, 30),
+        )
+        .unwrap();
+
+        let new_york = &impact.zones[0].changes;
+        assert_eq!(new_york.len(), 1);
+        assert_eq!(new_york[0].local_date, "2025-03-09");
+        assert_eq!(new_york[0].hours_in_day, 23.0);
+        assert_eq!(new_york[0].direction, "spring_forward");
+        assert_eq!(impact.zones[1].changes[0].local_date, "2025-03-30");
+        assert!(impact.zones[2].changes.is_empty());
+
+        let new_york_london = &impact.pairs[0];
+        assert_eq!(new_york_london.initial_difference, "+5:00");
+        let differences: Vec<&str> = new_york_london
+            .changes
+            .iter()
+            .map(|c| c.to_difference.as_str())
+            .collect();
+        assert_eq!(differences, vec!["+4:00", "+5:00"]);
+        assert_eq!(impact.pairs[1].changes.len(), 1);
+        assert_eq!(impact.pairs[2].zones[0], "Europe/London");
+        assert_eq!(impact.pairs[2].changes[0].to_difference, "+8:00");
+    }
+
+    #[test]
+    fn dst_impact_reports_long_fall_back_days() {
+        let zones = [jiff::tz::TimeZone::get("Australia/Lord_Howe").unwrap()];
+        let impact = dst_impact(
+            &zones,
+            jiff::civil::date(2025, 4, 1),
+            jiff::civil::date(2025, 4, 30),
+        )
+        .unwrap();
+        assert_eq!(impact.zones[0].changes[0].direction, "fall_back");
+        assert_eq!(impact.zones[0].changes[0].hours_in_day, 24.5);
+        assert!(impact.pairs.is_empty());
+    }
+
+    #[test]
+    fn compare_zones_flags_the_us_eu_mismatch_weeks() {
+        let new_york = jiff::tz::TimeZone::get("America/New_York").unwrap();
+        let london = jiff::tz::TimeZone::get("Europe/London").unwrap();
+        let comparison = compare_zones(
+            &new_york,
+            &london,
+            jiff::civil::date(2025, 3, 1),
+            jiff::civil::date(2025, 4, 30),
+            Granularity::Daily,
+        )
+        .unwrap();
+        assert_eq!(comparison.usual_difference, "+5:00");
+        assert_eq!(comparison.rows.len(), 61);
+        assert_eq!(comparison.rows[0].first, "2025-03-01T12:00:00-05:00");
+        let periods: Vec<(&str, &str, bool)> = comparison
+            .periods
+            .iter()
+            .map(|p| (&p.from[..10], p.difference.as_str(), p.mismatch))
+            .collect();
+        assert_eq!(
+            periods,
+            vec![
+                ("2025-03-01", "+5:00", false),
+                ("2025-03-09", "+4:00", true),
+                ("2025-03-30", "+5:00", false),
+            ]
+        );
+        assert!(comparison.rows[8].changed);
+        assert_eq!(comparison.changes.len(), 2);
+        assert_eq!(comparison.changes[0].utc, "2025-03-09T07:00:00Z");
+        assert_eq!(comparison.changes[0].changed_zones, ["America/New_York"]);
+        assert_eq!(comparison.changes[1].changed_zones, ["Europe/London"]);
+
+        let hourly = compare_zones(
+            &new_york,
+            &london,
+            jiff::civil::date(2025, 3, 9),
+            jiff::civil::date(2025, 3, 9),
+            Granularity::Hourly,
+        )
+        .unwrap();
+        // The spring-forward day in New York is 23 hours long.
+        assert_eq!(hourly.rows.len(), 23);
+        assert_eq!(hourly.changes.len(), 1);
+    }
+
+    #[test]
+    fn compare_zones_flags_mismatches_regardless_of_the_range() {
+        let new_york = jiff::tz::TimeZone::get("America/New_York").unwrap();
+        let london = jiff::tz::TimeZone::get("Europe/London").unwrap();
+        // Most of this range is in the mismatch weeks.
+        let comparison = compare_zones(
+            &new_york,
+            &london,
+            jiff::civil::date(2025, 3, 1),
+            jiff::civil::date(2025, 4, 5),
+            Granularity::Daily,
+        )
+        .unwrap();
+        assert_eq!(comparison.usual_difference, "+5:00");
+        let periods: Vec<(&str, bool)> = comparison
+            .periods
+            .iter()
+            .map(|p| (p.difference.as_str(), p.mismatch))
+            .collect();
+        assert_eq!(
+            periods,
+            vec![("+5:00", false), ("+4:00", true), ("+5:00", false)]
+        );
+
+        // A zone without DST never mismatches, and its usual difference is
+        // the standard one even when the range is all summer.
+        let tokyo = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+        let summer = compare_zones(
+            &new_york,
+            &tokyo,
+            jiff::civil::date(2025, 6, 1),
+            jiff::civil::date(2025, 6, 30),
+            Granularity::Daily,
+        )
+        .unwrap();
+        assert_eq!(summer.usual_difference, "+14:00");
+        assert_eq!(summer.periods.len(), 1);
+        assert_eq!(summer.periods[0].difference, "+13:00");
+        assert!(!summer.periods[0].mismatch);
+    }
+}
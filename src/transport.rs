@@ -0,0 +1,195 @@
+use std::{future::Future, net::SocketAddr, sync::Arc};
+
+use rmcp::transport::streamable_http_server::{
+    StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    server::{TimeServer, init_logging, shutdown_signal},
+    telemetry, world_clock,
+};
+
+/// Path the Streamable HTTP endpoint is served at.
+pub const MCP_PATH: &str = "/mcp";
+
+/// Path the HTML world clock dashboard is served at.
+pub const WORLD_CLOCK_PATH: &str = "/world-clock";
+
+/// Path the Prometheus metrics are served at.
+pub const METRICS_PATH: &str = "/metrics";
+
+/// Default address for `--transport http`.
+pub const DEFAULT_BIND: &str = "127.0.0.1:8080";
+
+/// Environment variable choosing how the `mcp-time` binary serves MCP.
+pub const TRANSPORT_ENV: &str = "MCP_TIME_TRANSPORT";
+
+/// Environment variable with the address to listen on with HTTP.
+pub const BIND_ENV: &str = "MCP_TIME_BIND";
+
+/// Exit status of the `mcp-time` binary when the transport fails, as
+/// opposed to a clean shutdown (`EX_IOERR` from sysexits.h).
+pub const EXIT_TRANSPORT_ERROR: i32 = 74;
+
+/// A failure of the transport MCP is served over: the HTTP listener could
+/// not bind or broke, or the client's connection failed before the session
+/// was initialized.
+///
+/// A client disconnecting and a shutdown signal are clean shutdowns, not
+/// transport errors.
+#[derive(Debug)]
+pub struct TransportError(anyhow::Error);
+
+impl TransportError {
+    pub(crate) fn new(error: impl Into<anyhow::Error>) -> Self {
+        TransportError(error.into())
+    }
+}
+
+impl std::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Transport error: {:#}", self.0)
+    }
+}
+
+impl std::error::Error for TransportError {}
+
+/// How to serve MCP.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Transport {
+    /// Newline-delimited JSON-RPC on stdin/stdout.
+    #[default]
+    Stdio,
+    /// Streamable HTTP (with SSE streaming) for multiple network clients.
+    Http,
+}
+
+impl Transport {
+    /// Parse "stdio" or "http", in any case.
+    pub fn parse(value: &str) -> Result<Transport, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "stdio" => Ok(Transport::Stdio),
+            "http" => Ok(Transport::Http),
+            _ => Err(format!(
+                "Invalid transport: '{value}'. Expected 'stdio' or 'http'."
+            )),
+        }
+    }
+}
+
+/// Parse an address to listen on (e.g., "0.0.0.0:8080").
+pub fn parse_bind(value: &str) -> Result<SocketAddr, String> {
+    value.trim().parse().map_err(|_| {
+        format!("Invalid bind address: '{value}'. Expected an IP address and port (e.g., '0.0.0.0:8080').")
+    })
+}
+
+/// Serve MCP over Streamable HTTP at [`MCP_PATH`], the world clock
+/// dashboard at [`WORLD_CLOCK_PATH`], and tool call metrics for Prometheus
+/// at [`METRICS_PATH`], on `bind` until the process is asked
+/// to stop (Ctrl-C, or SIGTERM on Unix).
+///
+/// Each client session is served by its own [`TimeServer::session`], so
+/// all clients share the same notifications, timers, and configuration.
+/// Routes are wrapped in the server's [`TimeServer::http_middleware`].
+/// Installs the same stderr logger as [`TimeServer::run`], and fails with a
+/// [`TransportError`] if `bind` cannot be listened on.
+pub async fn serve_http(server: TimeServer, bind: SocketAddr) -> anyhow::Result<()> {
+    init_logging(server.log_format, server.otlp_endpoint.as_deref())?;
+    let listener = tokio::net::TcpListener::bind(bind)
+        .await
+        .map_err(|e| TransportError::new(anyhow::anyhow!("Failed to listen on {bind}: {e}")))?;
+    let served = serve_http_on(server, listener, shutdown_signal()).await;
+    telemetry::flush_spans();
+    served
+}
+
+/// Serve MCP over Streamable HTTP on an already bound `listener` until
+/// `shutdown` completes.
+///
+/// On shutdown the listener stops accepting connections, open sessions are
+/// closed, and requests already in flight are allowed to finish. Fails with
+/// a [`TransportError`] if the listener breaks.
+pub async fn serve_http_on(
+    server: TimeServer,
+    listener: tokio::net::TcpListener,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> anyhow::Result<()> {
+    serve_http_with(server, listener, shutdown, tower_layer::Identity::new()).await
+}
+
+/// Like [`serve_http_on`], with `layer` wrapped around every route, for
+/// embedders adding their own middleware to the MCP endpoint, dashboard,
+/// and metrics. `layer` runs outside the configured
+/// [`crate::middleware::Middleware`].
+///
+/// Any tower layer that axum's `Router::layer` accepts works, such as
+/// `axum::middleware::from_fn` or a `tower::ServiceBuilder` stack.
+pub async fn serve_http_with<L>(
+    server: TimeServer,
+    listener: tokio::net::TcpListener,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+    layer: L,
+) -> anyhow::Result<()>
+where
+    L: tower_layer::Layer<axum::routing::Route> + Clone + Send + Sync + 'static,
+    L::Service: tower_service::Service<axum::extract::Request> + Clone + Send + Sync + 'static,
+    <L::Service as tower_service::Service<axum::extract::Request>>::Response:
+        axum::response::IntoResponse + 'static,
+    <L::Service as tower_service::Service<axum::extract::Request>>::Error:
+        Into<std::convert::Infallible> + 'static,
+    <L::Service as tower_service::Service<axum::extract::Request>>::Future: Send + 'static,
+{
+    let sessions = CancellationToken::new();
+    let dashboard = Arc::new(server.session());
+    let metrics = server.metrics().clone();
+    let middleware = server.http_middleware.clone();
+    let service = StreamableHttpService::new(
+        move || Ok(server.session()),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig {
+            cancellation_token: sessions.child_token(),
+            ..Default::default()
+        },
+    );
+    let router = axum::Router::new()
+        .nest_service(MCP_PATH, service)
+        .route(
+            WORLD_CLOCK_PATH,
+            axum::routing::get(move || {
+                let page = dashboard.world_clock(world_clock::Format::Html);
+                async move { axum::response::Html(page) }
+            }),
+        )
+        .route(
+            METRICS_PATH,
+            axum::routing::get(move || {
+                let text = metrics.render_prometheus();
+                async move {
+                    (
+                        [(
+                            axum::http::header::CONTENT_TYPE,
+                            "text/plain; version=0.0.4; charset=utf-8",
+                        )],
+                        text,
+                    )
+                }
+            }),
+        );
+    let router = middleware.apply(router).layer(layer);
+    tracing::info!("serving MCP on http://{}{MCP_PATH}", listener.local_addr()?);
+    // Peer addresses identify clients for the rate limit.
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(async move {
+        shutdown.await;
+        tracing::info!("shutting down");
+        sessions.cancel();
+    })
+    .await
+    .map_err(TransportError::new)?;
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Where timezone rules are loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Source {
+    /// The system's zoneinfo directory of TZif files (e.g.,
+    /// `/usr/share/zoneinfo`), or the one named by `TZDIR`.
+    System,
+    /// The system's concatenated `tzdata` file, as on Android.
+    SystemConcatenated,
+    /// The copy of the IANA database compiled into the binary, used where
+    /// the system has none (e.g., Windows).
+    Bundled,
+    /// No database was found; only UTC and fixed offsets are available.
+    Unavailable,
+    /// Zones are available, but not from any of the places above.
+    Unknown,
+}
+
+/// The zoneinfo directories jiff searches when `TZDIR` is not set.
+#[cfg(unix)]
+const ZONEINFO_DIRECTORIES: &[&str] = &[
+    "/usr/share/zoneinfo",
+    "/usr/share/lib/zoneinfo",
+    "/etc/zoneinfo",
+];
+
+/// jiff searches no zoneinfo directories outside Unix.
+#[cfg(not(unix))]
+const ZONEINFO_DIRECTORIES: &[&str] = &[];
+
+/// Where jiff looks for a concatenated `tzdata` file: an environment
+/// variable, the directory used when it is unset, and the file within.
+const CONCATENATED_LOCATIONS: [(&str, &str, &str); 2] = [
+    ("ANDROID_ROOT", "/system", "usr/share/zoneinfo/tzdata"),
+    ("ANDROID_DATA", "/data/misc", "zoneinfo/current/tzdata"),
+];
+
+/// Whether jiff compiles in its bundled database, as its default
+/// `tzdb-bundle-platform` feature does on these platforms only.
+const BUNDLED: bool = cfg!(any(windows, target_family = "wasm"));
+
+/// The IANA Time Zone Database the server answers with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct TzdbInfo {
+    /// IANA release (e.g., "2025b"), when the database records it.
+    pub version: Option<String>,
+    pub source: Source,
+    /// Directory or file the database was loaded from, for system
+    /// databases.
+    pub path: Option<String>,
+    /// Number of zone names the database provides.
+    pub zone_count: usize,
+}
+
+/// The database jiff resolves timezones with, as found at first use.
+///
+/// jiff reads zone files lazily and picks up changes to them, but the
+/// release is read once; restart the server after updating system tzdata.
+pub fn info() -> &'static TzdbInfo {
+    static INFO: OnceLock<TzdbInfo> = OnceLock::new();
+    INFO.get_or_init(|| {
+        let zone_count = jiff::tz::db().available().count();
+        // jiff has no accessor for where its database came from, so look
+        // where it does, in the same order.
+        let tzdir = std::env::var_os("TZDIR").map(PathBuf::from);
+        let zoneinfo: Vec<PathBuf> = tzdir
+            .into_iter()
+            .chain(ZONEINFO_DIRECTORIES.iter().map(PathBuf::from))
+            .collect();
+        let concatenated: Vec<PathBuf> = CONCATENATED_LOCATIONS
+            .iter()
+            .map(|(name, default, file)| {
+                std::env::var_os(name)
+                    .map_or_else(|| PathBuf::from(default), PathBuf::from)
+                    .join(file)
+            })
+            .collect();
+        let (source, path) = locate(&zoneinfo, &concatenated, BUNDLED, zone_count);
+        let version = match (source, &path) {
+            (Source::System, Some(dir)) => zoneinfo_version(Path::new(dir)),
+            (Source::SystemConcatenated, Some(file)) => concatenated_version(Path::new(file)),
+            _ => None,
+        };
+        TzdbInfo {
+            version,
+            source,
+            path,
+            zone_count,
+        }
+    })
+}
+
+/// The IANA release behind every conversion, when known.
+pub fn version() -> Option<&'static str> {
+    info().version.as_deref()
+}
+
+/// The source and path of a database with `zone_count` zones: the first
+/// of the `zoneinfo` directories that exists, else the first of the
+/// `concatenated` files, else the bundled copy if there is one.
+fn locate(
+    zoneinfo: &[PathBuf],
+    concatenated: &[PathBuf],
+    bundled: bool,
+    zone_count: usize,
+) -> (Source, Option<String>) {
+    let display = |path: &PathBuf| Some(path.display().to_string());
+    if zone_count == 0 {
+        (Source::Unavailable, None)
+    } else if let Some(dir) = zoneinfo.iter().find(|dir| dir.is_dir()) {
+        (Source::System, display(dir))
+    } else if let Some(file) = concatenated.iter().find(|file| file.is_file()) {
+        (Source::SystemConcatenated, display(file))
+    } else if bundled {
+        (Source::Bundled, None)
+    } else {
+        (Source::Unknown, None)
+    }
+}
+
+/// The release recorded in a zoneinfo directory: the "# version" line of
+/// `tzdata.zi`, or the contents of `+VERSION`.
+fn zoneinfo_version(dir: &Path) -> Option<String> {
+    let from_zi = || {
+        let text = std::fs::read_to_string(dir.join("tzdata.zi")).ok()?;
+        let first = text.lines().next()?;
+        Some(first.strip_prefix("# version ")?.trim().to_string())
+    };
+    let from_version_file = || {
+        let text = std::fs::read_to_string(PathBuf::from(dir).join("+VERSION")).ok()?;
+        Some(text.trim().to_string()).filter(|version| !version.is_empty())
+    };
+    from_zi().or_else(from_version_file)
+}
+
+/// The release in a concatenated `tzdata` file's header (e.g.,
+/// "tzdata2025b").
+fn concatenated_version(file: &Path) -> Option<String> {
+    let mut header = [0; 11];
+    std::fs::File::open(file)
+        .ok()?
+        .read_exact(&mut header)
+        .ok()?;
+    let version = std::str::from_utf8(header.strip_prefix(b"tzdata")?).ok()?;
+    Some(version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_checks_each_kind_of_database_in_order() {
+        let dir = std::env::temp_dir().join(format!("mcp-time-locate-{}", std::process::id()));
+        let zoneinfo = dir.join("zoneinfo");
+        let tzdata = dir.join("tzdata");
+        let missing = dir.join("missing");
+        std::fs::create_dir_all(&zoneinfo).unwrap();
+        std::fs::write(&tzdata, b"tzdata2025b").unwrap();
+        let path = |path: &PathBuf| Some(path.display().to_string());
+        let none = std::slice::from_ref(&missing);
+
+        let zoneinfo_dirs = [missing.clone(), zoneinfo.clone()];
+        let concatenated = [missing.clone(), tzdata.clone()];
+        assert_eq!(
+            locate(&zoneinfo_dirs, &concatenated, true, 597),
+            (Source::System, path(&zoneinfo))
+        );
+        assert_eq!(
+            locate(none, &concatenated, true, 597),
+            (Source::SystemConcatenated, path(&tzdata))
+        );
+        assert_eq!(locate(none, none, true, 597), (Source::Bundled, None));
+        assert_eq!(locate(none, none, false, 597), (Source::Unknown, None));
+        assert_eq!(
+            locate(&zoneinfo_dirs, &concatenated, true, 0),
+            (Source::Unavailable, None)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn versions_are_read_from_database_files() {
+        let dir = std::env::temp_dir().join(format!("mcp-time-tzdb-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(zoneinfo_version(&dir), None);
+        std::fs::write(dir.join("+VERSION"), "2024a\n").unwrap();
+        assert_eq!(zoneinfo_version(&dir).as_deref(), Some("2024a"));
+        std::fs::write(dir.join("tzdata.zi"), "# version 2025b\n# ddeps\n").unwrap();
+        assert_eq!(zoneinfo_version(&dir).as_deref(), Some("2025b"));
+
+        let file = dir.join("tzdata");
+        std::fs::write(&file, b"tzdata2025b\0\0\0\0").unwrap();
+        assert_eq!(concatenated_version(&file).as_deref(), Some("2025b"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+use std::borrow::Cow;
+
+/// Replace look-alike Unicode characters that copy-pasted time and timezone
+/// inputs often contain with their ASCII equivalents.
+///
+/// Covers full-width digits and punctuation (e.g., "１４：３０"), Arabic-Indic
+/// and Extended Arabic-Indic digits (e.g., "١٤:٣٠"), and non-breaking or
+/// narrow spaces (e.g., the U+202F that macOS puts before "PM"). ASCII input
+/// is returned unchanged without allocating.
+pub fn normalize_input(input: &str) -> Cow<'_, str> {
+    if input.is_ascii() {
+        return Cow::Borrowed(input);
+    }
+    Cow::Owned(input.chars().map(normalize_char).collect())
+}
+
+fn normalize_char(c: char) -> char {
+    let offset_from = |base: char| char::from(b'0' + (c as u32 - base as u32) as u8);
+    match c {
+        '\u{FF10}'..='\u{FF19}' => offset_from('\u{FF10}'),
+        '\u{0660}'..='\u{0669}' => offset_from('\u{0660}'),
+        '\u{06F0}'..='\u{06F9}' => offset_from('\u{06F0}'),
+        // Full-width ASCII punctuation and letters map onto printable ASCII.
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        '\u{00A0}' | '\u{2007}' | '\u{2009}' | '\u{202F}' | '\u{3000}' => ' ',
+        // Arabic comma and the ratio sign, both seen in place of separators.
+        '\u{060C}' => ',',
+        '\u{2236}' => ':',
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_input_maps_full_width_characters() {
+        assert_eq!(normalize_input("１４：３０"), "14:30");
+        assert_eq!(normalize_input("Ａｓｉａ／Ｔｏｋｙｏ"), "Asia/Tokyo");
+    }
+
+    #[test]
+    fn normalize_input_maps_arabic_indic_digits() {
+        assert_eq!(normalize_input("١٤:٣٠"), "14:30");
+        assert_eq!(normalize_input("۰۹:۰۵"), "09:05");
+    }
+
+    #[test]
+    fn normalize_input_maps_non_breaking_spaces() {
+        assert_eq!(normalize_input("2:30\u{202F}PM"), "2:30 PM");
+        assert_eq!(
+            normalize_input("2025-07-04\u{00A0}12:00"),
+            "2025-07-04 12:00"
+        );
+    }
+
+    #[test]
+    fn normalize_input_borrows_ascii_input() {
+        assert!(matches!(normalize_input("14:30"), Cow::Borrowed("14:30")));
+    }
+}
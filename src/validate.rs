@@ -0,0 +1,852 @@
+use jiff::{
+    civil,
+    fmt::temporal::Pieces,
+    tz::{AmbiguousOffset, Offset, TimeZone},
+};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::{
+    core::format_utc_offset,
+    detect,
+    epoch::{EpochUnit, EpochValue},
+};
+
+/// Expected formats accepted besides strftime patterns, for error messages.
+pub const EXPECTED_FORMATS: &str = "'rfc3339', 'rfc9557', 'rfc2822', 'iso8601', or 'epoch'";
+
+/// The format a caller expects an input to be written in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    /// A date and time with `Z` or a UTC offset.
+    Rfc3339,
+    /// RFC 3339 with a bracketed IANA zone.
+    Rfc9557,
+    /// Email and HTTP dates (e.g., "Fri, 4 Jul 2025 12:00:00 -0400").
+    Rfc2822,
+    /// Any ISO 8601 form: RFC 3339, RFC 9557, a local datetime, or a date.
+    Iso8601,
+    /// A Unix epoch number in seconds, milliseconds, microseconds, or
+    /// nanoseconds.
+    Epoch,
+    /// A strftime pattern (e.g., "%d/%m/%Y %H:%M").
+    Strftime(String),
+}
+
+impl Expected {
+    /// Read a format name, in any case, or a strftime pattern.
+    pub fn parse(input: &str) -> Result<Expected, String> {
+        Ok(match input.trim().to_ascii_lowercase().as_str() {
+            "rfc3339" => Expected::Rfc3339,
+            "rfc9557" => Expected::Rfc9557,
+            "rfc2822" => Expected::Rfc2822,
+            "iso8601" | "iso" => Expected::Iso8601,
+            "epoch" | "unix" => Expected::Epoch,
+            _ if input.contains('%') => Expected::Strftime(input.to_string()),
+            _ => {
+                return Err(format!(
+                    "Unknown format: '{input}'. Expected {EXPECTED_FORMATS}, or a strftime pattern (e.g., '%d/%m/%Y %H:%M')."
+                ));
+            }
+        })
+    }
+
+    /// Name of the expected format in diagnostics.
+    fn describe(&self) -> String {
+        match self {
+            Expected::Rfc3339 => "RFC 3339".into(),
+            Expected::Rfc9557 => "RFC 9557".into(),
+            Expected::Rfc2822 => "RFC 2822".into(),
+            Expected::Iso8601 => "ISO 8601".into(),
+            Expected::Epoch => "a Unix epoch number".into(),
+            Expected::Strftime(pattern) => format!("'{pattern}'"),
+        }
+    }
+
+    /// Whether an input recognized as `format` is written in this format.
+    fn admits(&self, format: &str) -> bool {
+        match self {
+            Expected::Rfc3339 => format == "rfc3339",
+            Expected::Rfc9557 => format == "rfc9557",
+            Expected::Rfc2822 => format == "rfc2822",
+            Expected::Iso8601 => matches!(
+                format,
+                "rfc3339" | "rfc9557" | "iso8601_local_datetime" | "iso8601_date"
+            ),
+            Expected::Epoch => format.starts_with("epoch_"),
+            Expected::Strftime(_) => format == "strftime",
+        }
+    }
+}
+
+/// A specific reason an input is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct Diagnostic {
+    /// The part of the input at fault (e.g., `month`, `offset`,
+    /// `timezone`), when there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<&'static str>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(field: Option<&'static str>, message: impl Into<String>) -> Self {
+        Diagnostic {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+/// Outcome of validating an input.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct Validation {
+    pub valid: bool,
+    /// Identifier of the format the input is written in (e.g., `rfc3339`,
+    /// `iso8601_local_datetime`, `epoch_milliseconds`), as `detect_format`
+    /// names them, when it was recognized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'static str>,
+    /// The input normalized: RFC 3339 with its offset when it pins an
+    /// instant, RFC 9557 when it also names a zone, and ISO 8601 otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+    /// The instant in UTC, when the input pins one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utc: Option<String>,
+    /// Why the input is invalid, most specific first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<Diagnostic>,
+    /// What to double-check about the input even when it parses (e.g., a
+    /// local time that DST skips).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// A recognized input before normalization.
+enum Value {
+    /// An instant in a zone or at a fixed offset.
+    Zoned(jiff::Zoned),
+    /// A wall-clock datetime without an offset.
+    Local(civil::DateTime),
+    Date(civil::Date),
+    Time(civil::Time),
+}
+
+/// Check that `input` is a datetime, in `expected` when given, and
+/// normalize it.
+///
+/// Local datetimes and dates are placed in `tz` when given, so that the
+/// canonical form pins an instant and DST gaps and folds are flagged.
+pub fn validate(input: &str, expected: Option<&Expected>, tz: Option<&TimeZone>) -> Validation {
+    let input = input.trim();
+    let recognized = match expected {
+        Some(Expected::Strftime(pattern)) => strftime(input, pattern),
+        _ => recognize(input),
+    };
+    let (format, description, value) = match recognized {
+        Ok(recognized) => recognized,
+        Err(diagnostics) => {
+            let diagnostics = match expected {
+                Some(Expected::Strftime(_)) | None => diagnostics,
+                Some(expected) => expected_diagnostics(input, expected, diagnostics),
+            };
+            return invalid(None, diagnostics);
+        }
+    };
+    if let Some(expected) = expected.filter(|expected| !expected.admits(format)) {
+        let mut diagnostics = vec![Diagnostic::new(
+            None,
+            format!(
+                "Expected {}; the input is written as: {description}.",
+                expected.describe()
+            ),
+        )];
+        diagnostics.extend(missing_parts(format, expected));
+        return Validation {
+            format: Some(format),
+            description: Some(description),
+            ..invalid(None, diagnostics)
+        };
+    }
+
+    let mut warnings = Vec::new();
+    if split_iso(input).second == Some("60") {
+        warnings.push("Leap second 60 was read as 59.".into());
+    }
+    // Whether the canonical form keeps the zone name rather than just its offset.
+    let (zoned, named) = match value {
+        Value::Zoned(zdt) => (Some(zdt), format == "rfc9557" || format == "strftime"),
+        Value::Local(dt) => match tz {
+            Some(tz) => {
+                warnings.extend(wall_clock_warning(dt, tz));
+                (dt.to_zoned(tz.clone()).ok(), true)
+            }
+            None => {
+                warnings.push(
+                    "No UTC offset or zone: pass a timezone to pin the instant it stands for."
+                        .into(),
+                );
+                return valid(format, description, dt.to_string(), None, warnings);
+            }
+        },
+        Value::Date(date) => {
+            let canonical = date.to_string();
+            let utc = tz
+                .and_then(|tz| date.to_zoned(tz.clone()).ok())
+                .and_then(|zdt| zdt.start_of_day().ok())
+                .map(|zdt| zdt.timestamp().to_string());
+            if utc.is_none() {
+                warnings.push("Date only; no time of day or timezone.".into());
+            }
+            return valid(format, description, canonical, utc, warnings);
+        }
+        Value::Time(time) => {
+            warnings.push("Time only; no date or timezone.".into());
+            return valid(format, description, time.to_string(), None, warnings);
+        }
+    };
+    let Some(zdt) = zoned else {
+        return invalid(
+            Some(format),
+            vec![Diagnostic::new(None, "The datetime is out of range.")],
+        );
+    };
+    if let Some(inference) = format
+        .starts_with("epoch_")
+        .then(|| EpochValue::parse(input).map(EpochValue::infer_unit))
+        .flatten()
+        .filter(|inference| inference.ambiguous)
+    {
+        warnings.push(format!(
+            "No epoch unit places this value between 1971 and 2100; it was read as {} from its digit count.",
+            inference.unit.as_str()
+        ));
+    }
+    let canonical = if format.starts_with("epoch_") {
+        zdt.timestamp().to_string()
+    } else if named && zdt.time_zone().iana_name().is_some() {
+        zdt.to_string()
+    } else {
+        zdt.timestamp()
+            .display_with_offset(zdt.offset())
+            .to_string()
+    };
+    valid(
+        format,
+        description,
+        canonical,
+        Some(zdt.timestamp().to_string()),
+        warnings,
+    )
+}
+
+fn valid(
+    format: &'static str,
+    description: &'static str,
+    canonical: String,
+    utc: Option<String>,
+    warnings: Vec<String>,
+) -> Validation {
+    Validation {
+        valid: true,
+        format: Some(format),
+        description: Some(description),
+        canonical: Some(canonical),
+        utc,
+        diagnostics: Vec::new(),
+        warnings,
+    }
+}
+
+fn invalid(format: Option<&'static str>, diagnostics: Vec<Diagnostic>) -> Validation {
+    Validation {
+        valid: false,
+        format,
+        description: None,
+        canonical: None,
+        utc: None,
+        diagnostics,
+        warnings: Vec::new(),
+    }
+}
+
+type Recognized = (&'static str, &'static str, Value);
+
+/// Read `input` in the first standard format it is written in.
+fn recognize(input: &str) -> Result<Recognized, Vec<Diagnostic>> {
+    if let Some(value) = EpochValue::parse(input) {
+        let unit = value.infer_unit().unit;
+        let (format, description) = epoch_format(unit);
+        return match value.to_timestamp(unit) {
+            Ok(ts) => Ok((
+                format,
+                description,
+                Value::Zoned(ts.to_zoned(TimeZone::UTC)),
+            )),
+            Err(msg) => Err(vec![Diagnostic::new(None, msg)]),
+        };
+    }
+    let has_time = input.contains(':') || input.contains('T');
+    if input.contains('[') {
+        if let Ok(zdt) = input.parse::<jiff::Zoned>() {
+            return Ok((
+                "rfc9557",
+                "RFC 9557 datetime with offset and bracketed IANA zone",
+                Value::Zoned(zdt),
+            ));
+        }
+    } else if let Ok(ts) = input.parse::<jiff::Timestamp>() {
+        let offset = Pieces::parse(input)
+            .ok()
+            .and_then(|pieces| pieces.to_numeric_offset())
+            .unwrap_or(Offset::UTC);
+        return Ok((
+            "rfc3339",
+            "RFC 3339 datetime with UTC offset",
+            Value::Zoned(ts.to_zoned(TimeZone::fixed(offset))),
+        ));
+    } else if has_time && let Ok(dt) = input.parse::<civil::DateTime>() {
+        return Ok((
+            "iso8601_local_datetime",
+            "ISO 8601 datetime without offset",
+            Value::Local(dt),
+        ));
+    } else if let Ok(date) = input.parse::<civil::Date>() {
+        return Ok((
+            "iso8601_date",
+            "ISO 8601 calendar date (YYYY-MM-DD)",
+            Value::Date(date),
+        ));
+    }
+    if let Ok(zdt) = jiff::fmt::rfc2822::parse(input) {
+        return Ok(("rfc2822", "RFC 2822 / HTTP date", Value::Zoned(zdt)));
+    }
+    // jiff reads the time out of a full datetime, so only bare times count.
+    if !input.contains('-')
+        && let Ok(time) = input.parse::<civil::Time>()
+    {
+        return Ok(("time_of_day", "24-hour time of day", Value::Time(time)));
+    }
+    Err(diagnose(input))
+}
+
+/// Read `input` with a strftime `pattern`, as specific a value as the
+/// pattern's fields allow.
+fn strftime(input: &str, pattern: &str) -> Result<Recognized, Vec<Diagnostic>> {
+    let fail = |e: jiff::Error| vec![Diagnostic::new(None, jiff_message(&e))];
+    let parsed = jiff::fmt::strtime::parse(pattern, input).map_err(fail)?;
+    const DESCRIPTION: &str = "Datetime in the given strftime pattern";
+    let value = if let Ok(zdt) = parsed.to_zoned() {
+        Value::Zoned(zdt)
+    } else if let (Ok(ts), Some(offset)) = (parsed.to_timestamp(), parsed.offset()) {
+        Value::Zoned(ts.to_zoned(TimeZone::fixed(offset)))
+    } else if parsed.hour().is_some() {
+        Value::Local(parsed.to_datetime().map_err(fail)?)
+    } else if parsed.year().is_some() || parsed.month().is_some() || parsed.day().is_some() {
+        Value::Date(parsed.to_date().map_err(fail)?)
+    } else {
+        Value::Time(parsed.to_time().map_err(fail)?)
+    };
+    Ok(("strftime", DESCRIPTION, value))
+}
+
+/// Format identifier and description for an epoch unit, as in
+/// `detect_format`.
+fn epoch_format(unit: EpochUnit) -> (&'static str, &'static str) {
+    match unit {
+        EpochUnit::Seconds => ("epoch_seconds", "Unix epoch seconds"),
+        EpochUnit::Milliseconds => ("epoch_milliseconds", "Unix epoch milliseconds"),
+        EpochUnit::Microseconds => ("epoch_microseconds", "Unix epoch microseconds"),
+        EpochUnit::Nanoseconds => ("epoch_nanoseconds", "Unix epoch nanoseconds"),
+    }
+}
+
+/// Why `input` matches no standard format.
+fn diagnose(input: &str) -> Vec<Diagnostic> {
+    let bytes = input.as_bytes();
+    let iso_like =
+        bytes.len() >= 5 && bytes[..4].iter().all(u8::is_ascii_digit) && bytes[4] == b'-';
+    if iso_like {
+        let diagnostics = iso_diagnostics(input);
+        if !diagnostics.is_empty() {
+            return diagnostics;
+        }
+        if let Err(e) = input
+            .parse::<jiff::Zoned>()
+            .and(input.parse::<jiff::Timestamp>())
+        {
+            return vec![Diagnostic::new(None, jiff_message(&e))];
+        }
+    }
+    if looks_like_rfc2822(input) {
+        if let Err(e) = jiff::fmt::rfc2822::parse(input) {
+            return vec![Diagnostic::new(None, jiff_message(&e))];
+        }
+    }
+    let candidates = detect::detect_format(input);
+    if !candidates.is_empty() {
+        let readings: Vec<String> = candidates
+            .iter()
+            .map(|c| format!("{} ({})", c.interpretation, c.description))
+            .collect();
+        return vec![Diagnostic::new(
+            None,
+            format!(
+                "Not a standard datetime format, but it can be read as {}. Pass the strftime pattern it is written in as format to check it.",
+                readings.join(" or ")
+            ),
+        )];
+    }
+    vec![Diagnostic::new(
+        None,
+        "Not a recognized datetime. Expected RFC 3339 (e.g., '2025-07-04T12:00:00Z'), RFC 9557 (e.g., '2025-07-04T12:00:00-04:00[America/New_York]'), a local datetime or date (e.g., '2025-07-04T12:00', '2025-07-04'), RFC 2822 (e.g., 'Fri, 4 Jul 2025 12:00:00 -0400'), or a Unix epoch number.",
+    )]
+}
+
+/// Whether `input` has a day number and an English month abbreviation, as
+/// RFC 2822 dates do (e.g., "4 Jul 2025 12:00 -0400").
+fn looks_like_rfc2822(input: &str) -> bool {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    input.contains(|c: char| c.is_ascii_digit())
+        && input.split([' ', ',']).any(|word| {
+            word.get(..3)
+                .is_some_and(|prefix| MONTHS.contains(&prefix.to_ascii_lowercase().as_str()))
+        })
+}
+
+/// Diagnostics for an input that matched no format, in terms of `expected`
+/// where that is more specific.
+fn expected_diagnostics(
+    input: &str,
+    expected: &Expected,
+    diagnostics: Vec<Diagnostic>,
+) -> Vec<Diagnostic> {
+    if *expected == Expected::Epoch && EpochValue::parse(input).is_none() {
+        return vec![Diagnostic::new(
+            None,
+            "A Unix epoch value is a plain number, optionally negative or with a fractional part (e.g., '1751632440').",
+        )];
+    }
+    diagnostics
+}
+
+/// The parts an input recognized as `format` lacks for `expected`.
+fn missing_parts(format: &str, expected: &Expected) -> Option<Diagnostic> {
+    let wants_offset = matches!(expected, Expected::Rfc3339 | Expected::Rfc9557);
+    match format {
+        "iso8601_date" | "time_of_day" if wants_offset => Some(Diagnostic::new(
+            Some("time"),
+            "A date, time of day, and UTC offset are all required (e.g., '2025-07-04T12:00:00Z').",
+        )),
+        "iso8601_local_datetime" if wants_offset => Some(Diagnostic::new(
+            Some("offset"),
+            "The UTC offset is missing: add 'Z' or an offset such as '+02:00'.",
+        )),
+        "rfc3339" if *expected == Expected::Rfc9557 => Some(Diagnostic::new(
+            Some("timezone"),
+            "The bracketed IANA zone is missing (e.g., '[America/New_York]').",
+        )),
+        _ => None,
+    }
+}
+
+/// The last, most specific clause of a jiff error, capitalized (e.g.,
+/// "Parameter 'month' is not in the required range of 1..=12").
+fn jiff_message(e: &jiff::Error) -> String {
+    let text = e.to_string();
+    let clause = text.rsplit(": ").next().unwrap_or(&text);
+    let mut chars = clause.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => text,
+    }
+}
+
+/// How `local` resolves in `tz` when DST skips or repeats it.
+fn wall_clock_warning(local: civil::DateTime, tz: &TimeZone) -> Option<String> {
+    let zone = tz.iana_name().unwrap_or("UTC");
+    match tz.to_ambiguous_zoned(local).offset() {
+        AmbiguousOffset::Unambiguous { .. } => None,
+        AmbiguousOffset::Gap { before, after } => Some(format!(
+            "{local} does not exist in {zone}: clocks jump from {} to {}, so tools move it forward by the length of the gap.",
+            format_utc_offset(before),
+            format_utc_offset(after)
+        )),
+        AmbiguousOffset::Fold { before, after } => Some(format!(
+            "{local} occurs twice in {zone}, at {} and {}; tools use the earlier one unless told otherwise.",
+            format_utc_offset(before),
+            format_utc_offset(after)
+        )),
+    }
+}
+
+/// The fields of an ISO 8601 datetime, split without checking their values.
+#[derive(Default)]
+struct IsoFields<'a> {
+    year: &'a str,
+    month: &'a str,
+    day: &'a str,
+    hour: Option<&'a str>,
+    minute: Option<&'a str>,
+    second: Option<&'a str>,
+    /// Sign, hours, and minutes of a numeric offset.
+    offset: Option<(char, &'a str, &'a str)>,
+    zone: Option<&'a str>,
+    /// Text after the datetime that fits no field.
+    rest: &'a str,
+}
+
+/// Split `input` into ISO 8601 fields: `YYYY-MM-DD`, then optionally
+/// `THH:MM[:SS[.fff]]`, an offset, and a bracketed zone.
+fn split_iso(input: &str) -> IsoFields<'_> {
+    fn digits(s: &str) -> (&str, &str) {
+        let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        s.split_at(end)
+    }
+    let mut fields = IsoFields::default();
+    let (year, rest) = digits(input);
+    fields.year = year;
+    let Some(rest) = rest.strip_prefix('-') else {
+        fields.rest = rest;
+        return fields;
+    };
+    let (month, rest) = digits(rest);
+    fields.month = month;
+    let Some(rest) = rest.strip_prefix('-') else {
+        fields.rest = rest;
+        return fields;
+    };
+    let (day, mut rest) = digits(rest);
+    fields.day = day;
+
+    if let Some(time) = rest.strip_prefix(['T', 't', ' ']) {
+        let (hour, after) = digits(time);
+        fields.hour = Some(hour);
+        rest = after;
+        if let Some(after) = rest.strip_prefix(':') {
+            let (minute, after) = digits(after);
+            fields.minute = Some(minute);
+            rest = after;
+            if let Some(after) = rest.strip_prefix(':') {
+                let (second, after) = digits(after);
+                fields.second = Some(second);
+                rest = after;
+                if let Some(after) = rest.strip_prefix(['.', ',']) {
+                    rest = digits(after).1;
+                }
+            }
+        }
+    }
+    if let Some(after) = rest.strip_prefix(['Z', 'z']) {
+        rest = after;
+    } else if let Some(sign) = rest.chars().next().filter(|&c| c == '+' || c == '-') {
+        let (hours, after) = digits(&rest[1..]);
+        let (hours, minutes, after) = match after.strip_prefix(':') {
+            Some(after) => {
+                let (minutes, after) = digits(after);
+                (hours, minutes, after)
+            }
+            // Basic form: +HHMM.
+            None if hours.len() == 4 => (&hours[..2], &hours[2..], after),
+            None => (hours, "00", after),
+        };
+        fields.offset = Some((sign, hours, minutes));
+        rest = after;
+    }
+    if let Some((zone, after)) = rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+        fields.zone = Some(zone.trim_start_matches('!'));
+        rest = after;
+    }
+    fields.rest = rest;
+    fields
+}
+
+/// Field-by-field diagnostics for an ISO 8601 datetime that fails to parse
+/// (e.g., "Month 13 is out of range (01-12)").
+fn iso_diagnostics(input: &str) -> Vec<Diagnostic> {
+    let fields = split_iso(input);
+    let mut diagnostics = Vec::new();
+
+    if fields.year.len() != 4 {
+        number(&mut diagnostics, "year", fields.year, 0..=9999);
+        diagnostics.push(Diagnostic::new(
+            Some("year"),
+            "Year must have four digits (e.g., '2025').",
+        ));
+    }
+    let year = fields.year.parse::<i16>().ok();
+    let month = number(&mut diagnostics, "month", fields.month, 1..=12);
+    let day = number(&mut diagnostics, "day", fields.day, 1..=31);
+    if let (Some(year), Some(month), Some(day)) = (year, month, day)
+        && let Ok(first) = civil::Date::new(year, month as i8, 1)
+        && day > i64::from(first.days_in_month())
+    {
+        diagnostics.push(Diagnostic::new(
+            Some("day"),
+            format!(
+                "Day {day} is out of range: {} has {} days.",
+                first.strftime("%B %Y"),
+                first.days_in_month()
+            ),
+        ));
+    }
+    if let Some(hour) = fields.hour {
+        if number(&mut diagnostics, "hour", hour, 0..=23).is_none() && hour == "24" {
+            diagnostics.push(Diagnostic::new(
+                Some("hour"),
+                "Midnight at the end of a day is written as 00:00 on the next day.",
+            ));
+        }
+        match fields.minute {
+            Some(minute) => {
+                number(&mut diagnostics, "minute", minute, 0..=59);
+            }
+            None => diagnostics.push(Diagnostic::new(Some("minute"), "Minute is missing.")),
+        }
+    }
+    if let Some(second) = fields.second
+        && number(&mut diagnostics, "second", second, 0..=59).is_none()
+        && second == "60"
+    {
+        diagnostics.push(Diagnostic::new(
+            Some("second"),
+            "Leap seconds are not supported; use 59.",
+        ));
+    }
+    if let Some((sign, hours, minutes)) = fields.offset {
+        let valid = |s: &str, max: u8| s.len() == 2 && s.parse::<u8>().is_ok_and(|v| v <= max);
+        if !valid(hours, 25) || !valid(minutes, 59) {
+            diagnostics.push(Diagnostic::new(
+                Some("offset"),
+                format!(
+                    "UTC offset {sign}{hours}:{minutes} is invalid; write it as ±HH:MM with hours 00-25 and minutes 00-59 (e.g., '+05:30')."
+                ),
+            ));
+        }
+    }
+    if let Some(zone) = fields.zone {
+        let offset_like = zone.starts_with(['+', '-']);
+        if !offset_like && TimeZone::get(zone).is_err() {
+            diagnostics.push(Diagnostic::new(
+                Some("timezone"),
+                format!("Unknown timezone '{zone}' in brackets."),
+            ));
+        } else if diagnostics.is_empty()
+            && let (Some((sign, hours, minutes)), Ok(tz)) = (fields.offset, TimeZone::get(zone))
+            && let Ok(dt) = input
+                .split_once('[')
+                .map_or(input, |(datetime, _)| datetime)
+                .parse::<civil::DateTime>()
+        {
+            let offsets = match tz.to_ambiguous_zoned(dt).offset() {
+                AmbiguousOffset::Unambiguous { offset } => vec![offset],
+                AmbiguousOffset::Gap { before, after }
+                | AmbiguousOffset::Fold { before, after } => {
+                    vec![before, after]
+                }
+            };
+            let offsets: Vec<String> = offsets.into_iter().map(format_utc_offset).collect();
+            diagnostics.push(Diagnostic::new(
+                Some("offset"),
+                format!(
+                    "UTC offset {sign}{hours}:{minutes} does not match {zone}, which is at {} then.",
+                    offsets.join(" or ")
+                ),
+            ));
+        }
+    }
+    if !fields.rest.is_empty() && diagnostics.is_empty() {
+        diagnostics.push(Diagnostic::new(
+            None,
+            format!("Unexpected text '{}' after the datetime.", fields.rest),
+        ));
+    }
+    diagnostics
+}
+
+/// Check a numeric ISO 8601 field, recording what is wrong with it.
+fn number(
+    diagnostics: &mut Vec<Diagnostic>,
+    field: &'static str,
+    value: &str,
+    range: std::ops::RangeInclusive<i64>,
+) -> Option<i64> {
+    let name = capitalize(field);
+    if value.is_empty() {
+        diagnostics.push(Diagnostic::new(Some(field), format!("{name} is missing.")));
+        return None;
+    }
+    if value.len() != 2 && field != "year" {
+        diagnostics.push(Diagnostic::new(
+            Some(field),
+            format!("{name} must have two digits (e.g., '{value:0>2}')."),
+        ));
+    }
+    let parsed: i64 = value.parse().ok()?;
+    if !range.contains(&parsed) {
+        diagnostics.push(Diagnostic::new(
+            Some(field),
+            format!(
+                "{name} {parsed} is out of range ({:02}-{:02}).",
+                range.start(),
+                range.end()
+            ),
+        ));
+        return None;
+    }
+    Some(parsed)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(input: &str) -> Vec<String> {
+        let validation = validate(input, None, None);
+        assert!(!validation.valid, "Accepted: {input}");
+        validation
+            .diagnostics
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn validate_recognizes_and_normalizes_standard_formats() {
+        let check = |input: &str, format: &str, canonical: &str| {
+            let validation = validate(input, None, None);
+            assert!(validation.valid, "Rejected: {input}");
+            assert_eq!(validation.format, Some(format), "{input}");
+            assert_eq!(validation.canonical.as_deref(), Some(canonical), "{input}");
+        };
+        check(
+            "2025-07-04T12:00:00Z",
+            "rfc3339",
+            "2025-07-04T12:00:00+00:00",
+        );
+        check(
+            "2025-07-04 12:00+05:30",
+            "rfc3339",
+            "2025-07-04T12:00:00+05:30",
+        );
+        check(
+            "2025-07-04T12:00-04:00[America/New_York]",
+            "rfc9557",
+            "2025-07-04T12:00:00-04:00[America/New_York]",
+        );
+        check(
+            "2025-07-04T12:00",
+            "iso8601_local_datetime",
+            "2025-07-04T12:00:00",
+        );
+        check("2025-07-04", "iso8601_date", "2025-07-04");
+        check(
+            "Fri, 4 Jul 2025 12:00:00 -0400",
+            "rfc2822",
+            "2025-07-04T12:00:00-04:00",
+        );
+        check("1751630400", "epoch_seconds", "2025-07-04T12:00:00Z");
+        check(
+            "1751630400000",
+            "epoch_milliseconds",
+            "2025-07-04T12:00:00Z",
+        );
+        check("14:30", "time_of_day", "14:30:00");
+    }
+
+    #[test]
+    fn validate_pins_local_values_in_a_timezone() {
+        let tz = TimeZone::get("America/New_York").unwrap();
+        let validation = validate("2025-07-04T12:00", None, Some(&tz));
+        assert_eq!(
+            validation.canonical.as_deref(),
+            Some("2025-07-04T12:00:00-04:00[America/New_York]")
+        );
+        assert_eq!(validation.utc.as_deref(), Some("2025-07-04T16:00:00Z"));
+        assert!(validation.warnings.is_empty());
+
+        let validation = validate("2025-03-09T02:30", None, Some(&tz));
+        assert!(validation.valid);
+        assert!(validation.warnings[0].contains("does not exist"));
+        let validation = validate("2025-11-02T01:30", None, Some(&tz));
+        assert!(validation.warnings[0].contains("occurs twice"));
+    }
+
+    #[test]
+    fn validate_explains_out_of_range_fields() {
+        assert_eq!(
+            messages("2025-13-01"),
+            ["Month 13 is out of range (01-12)."]
+        );
+        assert_eq!(
+            messages("2025-02-30T10:00"),
+            ["Day 30 is out of range: February 2025 has 28 days."]
+        );
+        assert_eq!(
+            messages("2025-07-04T24:00"),
+            [
+                "Hour 24 is out of range (00-23).",
+                "Midnight at the end of a day is written as 00:00 on the next day."
+            ]
+        );
+        assert_eq!(
+            messages("2025-7-4"),
+            [
+                "Month must have two digits (e.g., '07').",
+                "Day must have two digits (e.g., '04')."
+            ]
+        );
+        assert_eq!(
+            messages("2025-07-04T12:00:00+05:00[Mars/Olympus]"),
+            ["Unknown timezone 'Mars/Olympus' in brackets."]
+        );
+        assert_eq!(
+            messages("2025-07-04T12:00:00+05:00[America/New_York]"),
+            ["UTC offset +05:00 does not match America/New_York, which is at -04:00 then."]
+        );
+        assert_eq!(
+            messages("2025-07-04T12:00:00Zulu"),
+            ["Unexpected text 'ulu' after the datetime."]
+        );
+        assert!(messages("03/04/2025")[0].contains("2025-03-04"));
+        assert!(messages("next tuesday")[0].starts_with("Not a recognized datetime"));
+        assert!(messages("Sat, 4 Jul 2025 12:00:00 -0400")[0].contains("weekday"));
+    }
+
+    #[test]
+    fn validate_checks_the_expected_format() {
+        let validation = validate("2025-07-04T12:00", Some(&Expected::Rfc3339), None);
+        assert!(!validation.valid);
+        assert_eq!(validation.format, Some("iso8601_local_datetime"));
+        assert_eq!(validation.diagnostics[1].field, Some("offset"));
+        let validation = validate("2025-07-04T12:00Z", Some(&Expected::Rfc9557), None);
+        assert_eq!(validation.diagnostics[1].field, Some("timezone"));
+        assert!(validate("2025-07-04", Some(&Expected::Iso8601), None).valid);
+        assert_eq!(
+            validate("2025-07-04T12:00:60Z", None, None).warnings,
+            ["Leap second 60 was read as 59."]
+        );
+        assert!(!validate("soon", Some(&Expected::Epoch), None).valid);
+
+        let pattern = Expected::parse("%d/%m/%Y %H:%M").unwrap();
+        let validation = validate("04/07/2025 12:00", Some(&pattern), None);
+        assert_eq!(validation.format, Some("strftime"));
+        assert_eq!(validation.canonical.as_deref(), Some("2025-07-04T12:00:00"));
+        let validation = validate("04/13/2025 12:00", Some(&pattern), None);
+        assert!(validation.diagnostics[0].message.contains("'month'"));
+        assert!(Expected::parse("rfc9999").is_err());
+    }
+}
@@ -0,0 +1,320 @@
+use crate::display_names::{self, Language};
+
+/// URI of the world clock dashboard resource. A `format` query parameter
+/// selects HTML instead of Markdown (e.g., `time://world-clock?format=html`).
+pub const WORLD_CLOCK_URI: &str = "time://world-clock";
+
+/// Environment variable listing the dashboard's zones, comma-separated.
+pub const ZONES_ENV: &str = "MCP_TIME_WORLD_CLOCK_ZONES";
+
+/// Environment variable setting how often, in whole seconds, subscribers
+/// to the dashboard are told it changed.
+pub const REFRESH_INTERVAL_ENV: &str = "MCP_TIME_WORLD_CLOCK_REFRESH_INTERVAL";
+
+/// How often dashboard subscribers are notified unless configured. The
+/// dashboard shows minutes, so it changes once a minute.
+pub const DEFAULT_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Preset zone groups for the `get_world_clock` tool, by name.
+pub const GROUPS: &[(&str, &[&str])] = &[
+    (
+        "major_financial_centers",
+        &[
+            "America/New_York",
+            "America/Chicago",
+            "America/Sao_Paulo",
+            "Europe/London",
+            "Europe/Berlin",
+            "Europe/Zurich",
+            "Asia/Dubai",
+            "Asia/Kolkata",
+            "Asia/Singapore",
+            "Asia/Hong_Kong",
+            "Asia/Shanghai",
+            "Asia/Tokyo",
+            "Australia/Sydney",
+        ],
+    ),
+    (
+        "us",
+        &[
+            "America/New_York",
+            "America/Chicago",
+            "America/Denver",
+            "America/Phoenix",
+            "America/Los_Angeles",
+            "America/Anchorage",
+            "Pacific/Honolulu",
+        ],
+    ),
+    (
+        "americas",
+        &[
+            "America/Los_Angeles",
+            "America/Mexico_City",
+            "America/Bogota",
+            "America/New_York",
+            "America/Santiago",
+            "America/Sao_Paulo",
+            "America/Argentina/Buenos_Aires",
+        ],
+    ),
+    (
+        "europe",
+        &[
+            "Europe/London",
+            "Europe/Lisbon",
+            "Europe/Paris",
+            "Europe/Berlin",
+            "Europe/Athens",
+            "Europe/Istanbul",
+            "Europe/Moscow",
+        ],
+    ),
+    (
+        "asia_pacific",
+        &[
+            "Asia/Kolkata",
+            "Asia/Bangkok",
+            "Asia/Singapore",
+            "Asia/Shanghai",
+            "Asia/Tokyo",
+            "Australia/Sydney",
+            "Pacific/Auckland",
+        ],
+    ),
+];
+
+/// The zones of the preset group `name`, in any case.
+pub fn group(name: &str) -> Option<&'static [&'static str]> {
+    GROUPS
+        .iter()
+        .find(|(group, _)| group.eq_ignore_ascii_case(name.trim()))
+        .map(|&(_, zones)| zones)
+}
+
+/// How the dashboard is rendered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl Format {
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Format::Markdown => "text/markdown",
+            Format::Html => "text/html",
+        }
+    }
+}
+
+/// One zone's line on the dashboard.
+struct ClockRow {
+    zone: String,
+    /// Zone name in the configured language.
+    display_name: Option<String>,
+    time: String,
+    abbreviation: String,
+    date: String,
+    utc_offset: String,
+}
+
+/// Parse a world clock resource URI, returning its format.
+///
+/// Returns `None` for URIs that are not the world clock resource.
+pub fn parse_uri(uri: &str) -> Option<Result<Format, String>> {
+    let query = match uri.strip_prefix(WORLD_CLOCK_URI)? {
+        "" => return Some(Ok(Format::default())),
+        rest => rest.strip_prefix('?')?,
+    };
+    let format = match query.strip_prefix("format=") {
+        Some("markdown") => Ok(Format::Markdown),
+        Some("html") => Ok(Format::Html),
+        _ => Err(format!(
+            "Invalid world clock resource URI: '{uri}'. Expected '{WORLD_CLOCK_URI}' with an optional format of 'markdown' or 'html'."
+        )),
+    };
+    Some(format)
+}
+
+/// Read the dashboard's refresh interval from a raw environment value.
+///
+/// Unset or empty values keep [`DEFAULT_REFRESH_INTERVAL`].
+pub fn parse_refresh_interval(value: Option<&str>) -> Result<std::time::Duration, String> {
+    let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(DEFAULT_REFRESH_INTERVAL);
+    };
+    match value.parse::<u64>() {
+        Ok(seconds) if seconds > 0 => Ok(std::time::Duration::from_secs(seconds)),
+        _ => Err(format!(
+            "Invalid {REFRESH_INTERVAL_ENV}: '{value}'. Expected a whole number of seconds greater than 0."
+        )),
+    }
+}
+
+/// Read the dashboard's zones from a raw environment value.
+///
+/// Unset or empty values show UTC alone.
+pub fn parse_zones(value: Option<&str>) -> Result<Vec<String>, String> {
+    let zones: Vec<&str> = value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|zone| !zone.is_empty())
+        .collect();
+    if zones.is_empty() {
+        return Ok(vec!["UTC".into()]);
+    }
+    zones
+        .into_iter()
+        .map(|zone| match jiff::tz::TimeZone::get(zone) {
+            Ok(tz) => Ok(tz.iana_name().unwrap_or(zone).to_string()),
+            Err(_) => Err(format!(
+                "Invalid {ZONES_ENV}: '{zone}' is not an IANA timezone name (e.g., 'America/New_York')."
+            )),
+        })
+        .collect()
+}
+
+fn rows(zones: &[String], now: jiff::Timestamp, language: Option<Language>) -> Vec<ClockRow> {
+    zones
+        .iter()
+        .filter_map(|zone| {
+            let tz = jiff::tz::TimeZone::get(zone).ok()?;
+            let zdt = now.to_zoned(tz.clone());
+            Some(ClockRow {
+                zone: zone.clone(),
+                display_name: language.map(|language| display_names::display_name(zone, language)),
+                time: zdt.strftime("%H:%M").to_string(),
+                abbreviation: tz.to_offset_info(now).abbreviation().to_string(),
+                date: zdt.strftime("%a %-d %b").to_string(),
+                utc_offset: zdt.strftime("%:z").to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Escape text for HTML element content and attribute values.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Render the dashboard for `zones` at `now`, in the order given.
+///
+/// HTML output is a complete page with inline styles, so clients can show
+/// it without fetching anything else.
+pub fn render(
+    zones: &[String],
+    now: jiff::Timestamp,
+    format: Format,
+    language: Option<Language>,
+) -> String {
+    let rows = rows(zones, now, language);
+    let as_of = now.strftime("%Y-%m-%dT%H:%M:%SZ").to_string();
+    let label = |row: &ClockRow| match &row.display_name {
+        Some(name) => format!("{name} ({})", row.zone),
+        None => row.zone.clone(),
+    };
+    match format {
+        Format::Markdown => {
+            let mut out = format!(
+                "# World clock\n\nAs of {as_of}\n\n| Zone | Time | Date | UTC offset |\n| --- | --- | --- | --- |\n"
+            );
+            for row in &rows {
+                out.push_str(&format!(
+                    "| {} | {} {} | {} | {} |\n",
+                    label(row),
+                    row.time,
+                    row.abbreviation,
+                    row.date,
+                    row.utc_offset
+                ));
+            }
+            out
+        }
+        Format::Html => {
+            let mut out = format!(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>World clock</title>\n<style>\nbody {{ font-family: system-ui, sans-serif; margin: 1.5rem; }}\ntable {{ border-collapse: collapse; }}\nth, td {{ padding: 0.4rem 1rem; text-align: left; border-bottom: 1px solid #ddd; }}\n.time {{ font-size: 1.5rem; font-variant-numeric: tabular-nums; }}\n.muted {{ color: #666; }}\n</style>\n</head>\n<body>\n<h1>World clock</h1>\n<p class=\"muted\">As of <time datetime=\"{as_of}\">{as_of}</time></p>\n<table>\n<tr><th>Zone</th><th>Time</th><th>Date</th><th>UTC offset</th></tr>\n"
+            );
+            for row in &rows {
+                out.push_str(&format!(
+                    "<tr><td>{}</td><td><span class=\"time\">{}</span> <span class=\"muted\">{}</span></td><td>{}</td><td>{}</td></tr>\n",
+                    escape_html(&label(row)),
+                    row.time,
+                    escape_html(&row.abbreviation),
+                    row.date,
+                    row.utc_offset
+                ));
+            }
+            out.push_str("</table>\n</body>\n</html>\n");
+            out
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zones(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_uri_reads_format() {
+        assert_eq!(parse_uri("time://world-clock"), Some(Ok(Format::Markdown)));
+        assert_eq!(
+            parse_uri("time://world-clock?format=html"),
+            Some(Ok(Format::Html))
+        );
+        assert!(matches!(
+            parse_uri("time://world-clock?format=pdf"),
+            Some(Err(_))
+        ));
+        assert_eq!(parse_uri("time://tick"), None);
+    }
+
+    #[test]
+    fn parse_zones_validates_names() {
+        assert_eq!(parse_zones(None).unwrap(), zones(&["UTC"]));
+        assert_eq!(
+            parse_zones(Some(" America/New_York, Asia/Tokyo ,")).unwrap(),
+            zones(&["America/New_York", "Asia/Tokyo"])
+        );
+        let err = parse_zones(Some("America/New_York, Mars/Olympus")).unwrap_err();
+        assert!(err.contains("Mars/Olympus"), "Error was: {err}");
+    }
+
+    #[test]
+    fn groups_name_known_zones() {
+        for (name, zones) in GROUPS {
+            assert_eq!(group(name), Some(*zones));
+            for zone in *zones {
+                assert!(jiff::tz::TimeZone::get(zone).is_ok(), "{name}: {zone}");
+            }
+        }
+        assert!(group("US").is_some());
+        assert!(group("antarctica").is_none());
+    }
+
+    #[test]
+    fn render_lists_zones_in_order() {
+        let now: jiff::Timestamp = "2025-07-04T23:30:00Z".parse().unwrap();
+        let zones = zones(&["America/New_York", "Asia/Tokyo"]);
+        let markdown = render(&zones, now, Format::Markdown, None);
+        assert!(markdown.contains("As of 2025-07-04T23:30:00Z"));
+        assert!(markdown.contains("| America/New_York | 19:30 EDT | Fri 4 Jul | -04:00 |"));
+        assert!(markdown.contains("| Asia/Tokyo | 08:30 JST | Sat 5 Jul | +09:00 |"));
+        assert!(markdown.find("New_York") < markdown.find("Tokyo"));
+
+        let html = render(&zones, now, Format::Html, Some(Language::Spanish));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<td>Nueva York (America/New_York)</td>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+}
@@ -0,0 +1,268 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use serde::Serialize;
+
+use crate::{
+    core::format_utc_offset,
+    locations,
+    transitions::{self, ZoneTransition},
+};
+
+/// URI prefix of the timezone resources. The bare prefix is the index of
+/// every zone; `timezone://<zone>` describes one (e.g.,
+/// `timezone://America/New_York`).
+pub const ZONE_URI_PREFIX: &str = "timezone://";
+
+/// How far ahead a zone's upcoming transitions are listed.
+const UPCOMING_DAYS: i64 = 366;
+
+/// Contents of the `timezone://` index resource.
+#[derive(Debug, Serialize)]
+pub struct ZoneIndex {
+    pub count: usize,
+    /// Every zone name in the tz database, sorted, including legacy aliases.
+    pub zones: Vec<String>,
+}
+
+/// One offset a zone uses, as standard or daylight saving time.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OffsetRule {
+    pub abbreviation: String,
+    pub utc_offset: String,
+}
+
+/// The zone's DST rule, as observed over the coming year.
+#[derive(Debug, Serialize)]
+pub struct DstRule {
+    pub standard: OffsetRule,
+    pub daylight: OffsetRule,
+    /// Next switch to daylight saving time (RFC 3339, new offset).
+    pub next_start: Option<String>,
+    /// Next switch back to standard time (RFC 3339, new offset).
+    pub next_end: Option<String>,
+}
+
+/// Contents of a `timezone://<zone>` resource.
+#[derive(Debug, Serialize)]
+pub struct ZoneInfo {
+    pub timezone: String,
+    pub uri: String,
+    pub utc_offset: String,
+    pub abbreviation: String,
+    pub is_dst: bool,
+    pub observes_dst: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_rule: Option<DstRule>,
+    pub upcoming_transitions: Vec<ZoneTransition>,
+    /// Other names that have kept the same clock as this zone since 1970:
+    /// links such as "US/Eastern", and zones the tz database has merged.
+    pub aliases: Vec<String>,
+    /// ISO 3166 codes of the countries using the zone, principal one first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub countries: Vec<String>,
+    /// Region the zone covers within its countries, when they have several.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// The part of a timezone resource URI after the prefix: empty for the
+/// index, otherwise a zone name.
+///
+/// Returns `None` for URIs that are not timezone resources.
+pub fn parse_uri(uri: &str) -> Option<&str> {
+    uri.strip_prefix(ZONE_URI_PREFIX)
+}
+
+/// Every zone name in the tz database, sorted.
+pub fn index() -> ZoneIndex {
+    let mut zones: Vec<String> = jiff::tz::db()
+        .available()
+        .map(|name| name.as_str().to_string())
+        // A copy of the default DST rules some systems install, not a zone.
+        .filter(|name| name != "posixrules")
+        .collect();
+    zones.sort();
+    ZoneIndex {
+        count: zones.len(),
+        zones,
+    }
+}
+
+/// Describe `zone` as of `now`.
+pub fn info(zone: &str, now: jiff::Timestamp) -> Result<ZoneInfo, String> {
+    let tz = jiff::tz::TimeZone::get(zone).map_err(|_| {
+        format!(
+            "Unknown timezone resource: '{ZONE_URI_PREFIX}{zone}'. Read '{ZONE_URI_PREFIX}' for the list of zones."
+        )
+    })?;
+    let name = tz.iana_name().unwrap_or(zone).to_string();
+    let current = tz.to_offset_info(now);
+    let end = now
+        .checked_add(jiff::SignedDuration::from_hours(24 * UPCOMING_DAYS))
+        .unwrap_or(jiff::Timestamp::MAX);
+
+    let upcoming_transitions = transitions::zone_transitions(&tz, now, end);
+    let mut standard = (!current.dst().is_dst()).then(|| rule(&current));
+    let mut daylight = current.dst().is_dst().then(|| rule(&current));
+    let (mut next_start, mut next_end) = (None, None);
+    for transition in &upcoming_transitions {
+        let offset_rule = OffsetRule {
+            abbreviation: transition.abbreviation.clone(),
+            utc_offset: transition.utc_offset_after.clone(),
+        };
+        if transition.is_dst {
+            next_start.get_or_insert_with(|| transition.datetime.clone());
+            daylight.get_or_insert(offset_rule);
+        } else {
+            next_end.get_or_insert_with(|| transition.datetime.clone());
+            standard.get_or_insert(offset_rule);
+        }
+    }
+
+    let dst_rule = match (standard, daylight) {
+        (Some(standard), Some(daylight)) => Some(DstRule {
+            standard,
+            daylight,
+            next_start,
+            next_end,
+        }),
+        _ => None,
+    };
+    let location = locations::location(&name);
+    Ok(ZoneInfo {
+        uri: format!("{ZONE_URI_PREFIX}{name}"),
+        aliases: aliases(&name, &tz),
+        utc_offset: format_utc_offset(current.offset()),
+        abbreviation: current.abbreviation().to_string(),
+        is_dst: current.dst().is_dst(),
+        observes_dst: dst_rule.is_some(),
+        dst_rule,
+        upcoming_transitions,
+        countries: location.map(|l| l.countries.clone()).unwrap_or_default(),
+        comment: location.and_then(|l| l.comment.clone()),
+        timezone: name,
+    })
+}
+
+fn rule(info: &jiff::tz::TimeZoneOffsetInfo<'_>) -> OffsetRule {
+    OffsetRule {
+        abbreviation: info.abbreviation().to_string(),
+        utc_offset: format_utc_offset(info.offset()),
+    }
+}
+
+/// Offset, abbreviation, and DST flag from 1970 onward: the state at the
+/// start of 1970, then one entry per transition before 2038.
+type Fingerprint = Vec<(i64, i32, String, bool)>;
+
+fn fingerprint(tz: &jiff::tz::TimeZone) -> Fingerprint {
+    let start = jiff::Timestamp::UNIX_EPOCH;
+    let end = jiff::Timestamp::from_second(i64::from(i32::MAX)).unwrap_or(jiff::Timestamp::MAX);
+    let info = tz.to_offset_info(start);
+    let mut fingerprint = vec![(
+        start.as_second(),
+        info.offset().seconds(),
+        info.abbreviation().to_string(),
+        info.dst().is_dst(),
+    )];
+    fingerprint.extend(
+        tz.following(start)
+            .take_while(|t| t.timestamp() < end)
+            .map(|t| {
+                (
+                    t.timestamp().as_second(),
+                    t.offset().seconds(),
+                    t.abbreviation().to_string(),
+                    t.dst().is_dst(),
+                )
+            }),
+    );
+    fingerprint
+}
+
+/// Other zone names with the same fingerprint as `tz`, sorted.
+///
+/// The tz database does not say which names are links, so zones are grouped
+/// by behaviour instead; the grouping is built on first use.
+fn aliases(name: &str, tz: &jiff::tz::TimeZone) -> Vec<String> {
+    static GROUPS: OnceLock<HashMap<Fingerprint, Vec<String>>> = OnceLock::new();
+    let groups = GROUPS.get_or_init(|| {
+        let mut groups: HashMap<Fingerprint, Vec<String>> = HashMap::new();
+        for zone in index().zones {
+            if let Ok(tz) = jiff::tz::TimeZone::get(&zone) {
+                groups.entry(fingerprint(&tz)).or_default().push(zone);
+            }
+        }
+        groups
+    });
+    groups
+        .get(&fingerprint(tz))
+        .map(|zones| zones.iter().filter(|z| *z != name).cloned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_uri_splits_index_and_zones() {
+        assert_eq!(parse_uri("timezone://"), Some(""));
+        assert_eq!(
+            parse_uri("timezone://America/New_York"),
+            Some("America/New_York")
+        );
+        assert_eq!(parse_uri("time://tick"), None);
+    }
+
+    #[test]
+    fn index_lists_every_zone_sorted() {
+        let index = index();
+        assert_eq!(index.count, index.zones.len());
+        assert!(index.count > 300);
+        assert!(index.zones.is_sorted());
+        assert!(index.zones.iter().any(|z| z == "Asia/Kolkata"));
+    }
+
+    #[test]
+    fn info_describes_dst_rule_and_transitions() {
+        let now: jiff::Timestamp = "2025-07-04T12:00:00Z".parse().unwrap();
+        let info = info("America/New_York", now).unwrap();
+        assert_eq!(info.uri, "timezone://America/New_York");
+        assert_eq!(info.utc_offset, "-04:00");
+        assert_eq!(info.abbreviation, "EDT");
+        assert!(info.is_dst && info.observes_dst);
+        let rule = info.dst_rule.unwrap();
+        assert_eq!(
+            rule.standard,
+            OffsetRule {
+                abbreviation: "EST".into(),
+                utc_offset: "-05:00".into(),
+            }
+        );
+        assert_eq!(rule.daylight.abbreviation, "EDT");
+        assert_eq!(rule.next_end.as_deref(), Some("2025-11-02T01:00:00-05:00"));
+        assert_eq!(
+            rule.next_start.as_deref(),
+            Some("2026-03-08T03:00:00-04:00")
+        );
+        assert_eq!(info.upcoming_transitions.len(), 2);
+        assert_eq!(info.upcoming_transitions[0].change, "-1:00");
+        assert_eq!(info.upcoming_transitions[0].utc, "2025-11-02T06:00:00Z");
+        assert!(info.aliases.iter().any(|z| z == "US/Eastern"));
+        assert_eq!(info.countries, vec!["US"]);
+    }
+
+    #[test]
+    fn info_handles_zones_without_dst() {
+        let now: jiff::Timestamp = "2025-07-04T12:00:00Z".parse().unwrap();
+        let info = info("Asia/Kolkata", now).unwrap();
+        assert_eq!(info.utc_offset, "+05:30");
+        assert!(!info.observes_dst);
+        assert!(info.dst_rule.is_none());
+        assert!(info.upcoming_transitions.is_empty());
+
+        let err = super::info("Mars/Olympus", now).unwrap_err();
+        assert!(err.contains("timezone://Mars/Olympus"), "Error was: {err}");
+    }
+}
@@ -0,0 +1,272 @@
+use std::process::Command;
+
+fn mcp_time() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_mcp-time"))
+}
+
+#[test]
+fn call_prints_tool_result() {
+    let output = mcp_time()
+        .args(["call", "convert_time", "--json"])
+        .arg(r#"{"source_timezone": "UTC", "time": "12:00", "target_timezone": "Asia/Tokyo"}"#)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["target"]["timezone"], "Asia/Tokyo");
+}
+
+#[test]
+fn call_exits_nonzero_on_tool_errors() {
+    let output = mcp_time()
+        .args([
+            "call",
+            "get_current_time",
+            "--json",
+            r#"{"timezone": "Nowhere"}"#,
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["code"], "invalid_timezone");
+
+    let output = mcp_time().args(["call", "no_such_tool"]).output().unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn describe_tools_prints_catalog() {
+    let output = mcp_time().arg("--describe-tools").output().unwrap();
+    assert!(output.status.success());
+    let catalog: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(catalog["server"]["name"], "mcp-time");
+    let tools = catalog["tools"].as_array().unwrap();
+    let convert = tools
+        .iter()
+        .find(|tool| tool["name"] == "convert_time")
+        .unwrap();
+    assert!(convert["description"].is_string());
+    assert_eq!(convert["inputSchema"]["type"], "object");
+    assert!(convert["inputSchema"]["properties"]["source_timezone"].is_object());
+}
+
+#[test]
+fn check_config_reports_problems_with_line_context() {
+    let dir = std::env::temp_dir().join(format!("mcp-time-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let good = dir.join("good.env");
+    std::fs::write(&good, "MCP_TIME_LANGUAGE=de\n").unwrap();
+    let bad = dir.join("bad.env");
+    std::fs::write(&bad, "# settings\nMCP_TIME_HEARTBEAT_INTERVAL=soon\n").unwrap();
+
+    let output = mcp_time().arg("check-config").arg(&good).output().unwrap();
+    assert!(output.status.success());
+
+    let output = mcp_time().arg("check-config").arg(&bad).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("bad.env:2: Invalid MCP_TIME_HEARTBEAT_INTERVAL"));
+    assert!(stderr.contains("| MCP_TIME_HEARTBEAT_INTERVAL=soon"));
+
+    let toml = dir.join("mcp-time.toml");
+    std::fs::write(
+        &toml,
+        "transport = \"http\"\nheartbeat_interval = \"soon\"\n",
+    )
+    .unwrap();
+    let output = mcp_time().arg("check-config").arg(&toml).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("mcp-time.toml:2: Invalid heartbeat_interval"));
+    assert!(stderr.contains("| heartbeat_interval = \"soon\""));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn holidays_file_adds_holidays() {
+    let dir = std::env::temp_dir().join(format!("mcp-time-holidays-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("holidays.json");
+    std::fs::write(
+        &file,
+        r#"{"countries": {"US": {"holidays": [{"name": "Offsite", "date": "2025-03-14"}]}}}"#,
+    )
+    .unwrap();
+
+    let output = mcp_time()
+        .args(["call", "is_holiday", "--json"])
+        .arg(r#"{"country": "US", "date": "2025-03-14"}"#)
+        .arg("--holidays-file")
+        .arg(&file)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["holidays"][0]["name"], "Offsite");
+
+    let output = mcp_time()
+        .args(["call", "is_holiday", "--holidays-file"])
+        .arg(dir.join("missing.json"))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Failed to read holidays file"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn freeze_time_pins_now() {
+    let output = mcp_time()
+        .args(["call", "get_current_time", "--json"])
+        .arg(r#"{"timezone": "Asia/Tokyo"}"#)
+        .args(["--freeze-time", "2025-07-04T12:00:00Z"])
+        .env("MCP_TIME_FREEZE_TIME", "2000-01-01T00:00:00Z")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["datetime"], "2025-07-04T21:00:00+09:00");
+
+    let output = mcp_time()
+        .args(["call", "get_current_time", "--freeze-time", "tomorrow"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Invalid freeze time"));
+}
+
+#[test]
+fn config_file_is_overridden_by_env_and_flags() {
+    let dir = std::env::temp_dir().join(format!("mcp-time-config-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("mcp-time.toml");
+    std::fs::write(
+        &file,
+        "default_timezone = \"Asia/Tokyo\"\nfreeze_time = \"2025-07-04T12:00:00Z\"\n",
+    )
+    .unwrap();
+    let current_time = |env: &[(&str, &str)], args: &[&str]| {
+        let output = mcp_time()
+            .current_dir(&dir)
+            .args(["call", "get_current_time"])
+            .args(args)
+            .envs(env.iter().copied())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["datetime"].as_str().unwrap().to_string()
+    };
+
+    // Read from the working directory without being named.
+    assert_eq!(current_time(&[], &[]), "2025-07-04T21:00:00+09:00");
+    let env = [("MCP_TIME_DEFAULT_TIMEZONE", "Europe/Paris")];
+    assert_eq!(current_time(&env, &[]), "2025-07-04T14:00:00+02:00");
+    assert_eq!(
+        current_time(&env, &["--default-timezone", "America/New_York"]),
+        "2025-07-04T08:00:00-04:00"
+    );
+
+    let output = mcp_time()
+        .args(["call", "get_current_time", "--config"])
+        .arg(dir.join("missing.toml"))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Failed to read config file"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn system_default_timezone_follows_the_host() {
+    let output = mcp_time()
+        .args(["call", "get_current_time", "--default-timezone", "system"])
+        .args(["--freeze-time", "2025-07-04T12:00:00Z"])
+        .env("TZ", "America/Denver")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["timezone"], "America/Denver");
+    assert_eq!(json["datetime"], "2025-07-04T06:00:00-06:00");
+
+    let output = mcp_time()
+        .args(["call", "detect_local_timezone"])
+        .env("TZ", "America/Denver")
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["timezone"], "America/Denver");
+    assert_eq!(json["source"], "TZ environment variable");
+    assert_eq!(json["default_timezone"], "UTC");
+}
+
+/// Start `mcp-time` on stdio and complete the MCP handshake.
+fn spawn_initialized_server() -> std::process::Child {
+    use std::io::{BufRead, BufReader, Write};
+
+    let mut child = mcp_time()
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let stdin = child.stdin.as_mut().unwrap();
+    writeln!(
+        stdin,
+        r#"{{"jsonrpc":"2.0","id":1,"method":"initialize","params":{{"protocolVersion":"2025-06-18","capabilities":{{}},"clientInfo":{{"name":"test","version":"0"}}}}}}"#
+    )
+    .unwrap();
+    let mut response = String::new();
+    BufReader::new(child.stdout.as_mut().unwrap())
+        .read_line(&mut response)
+        .unwrap();
+    assert!(response.contains("mcp-time"), "{response}");
+    writeln!(
+        stdin,
+        r#"{{"jsonrpc":"2.0","method":"notifications/initialized"}}"#
+    )
+    .unwrap();
+    child
+}
+
+#[test]
+fn serve_exits_cleanly_when_the_client_disconnects() {
+    let mut child = spawn_initialized_server();
+    drop(child.stdin.take());
+    assert_eq!(child.wait().unwrap().code(), Some(0));
+}
+
+#[cfg(unix)]
+#[test]
+fn serve_exits_cleanly_on_sigterm() {
+    let mut child = spawn_initialized_server();
+    // Give the server a moment to install its signal handlers.
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    let killed = Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(killed.success());
+    assert_eq!(child.wait().unwrap().code(), Some(0));
+}
+
+#[test]
+fn serve_exits_with_transport_status_when_it_cannot_listen() {
+    let taken = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let bind = taken.local_addr().unwrap().to_string();
+    let output = mcp_time()
+        .args(["--transport", "http", "--bind", &bind])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(74), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to listen"), "{stderr}");
+}
@@ -0,0 +1,252 @@
+use axum::response::IntoResponse;
+use mcp_time::{middleware::Middleware, server::TimeServer, transport};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+/// Send a raw HTTP/1.1 request and return the raw response.
+async fn send(addr: std::net::SocketAddr, request: String) -> String {
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+/// POST a JSON-RPC message to the MCP endpoint and return the raw response.
+async fn post(addr: std::net::SocketAddr, body: &str) -> String {
+    let request = format!(
+        "POST {} HTTP/1.1\r\nHost: {addr}\r\nContent-Type: application/json\r\nAccept: application/json, text/event-stream\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        transport::MCP_PATH,
+        body.len()
+    );
+    send(addr, request).await
+}
+
+#[tokio::test]
+async fn http_transport_serves_mcp_and_shuts_down_gracefully() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let time_server = TimeServer::new();
+    time_server.metrics().record(
+        "convert_time",
+        mcp_time::telemetry::Outcome::Success,
+        std::time::Duration::from_millis(3),
+    );
+    let server = tokio::spawn(transport::serve_http_on(
+        time_server,
+        listener,
+        async move {
+            let _ = stopped.await;
+        },
+    ));
+
+    let response = post(
+        addr,
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18","capabilities":{},"clientInfo":{"name":"test","version":"1.0"}}}"#,
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200"), "Got: {response}");
+    assert!(
+        response.to_ascii_lowercase().contains("mcp-session-id:"),
+        "Got: {response}"
+    );
+    assert!(response.contains(r#""name":"mcp-time""#), "Got: {response}");
+
+    let response = send(
+        addr,
+        format!(
+            "GET {} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n",
+            transport::WORLD_CLOCK_PATH
+        ),
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200"), "Got: {response}");
+    assert!(response.contains("<h1>World clock</h1>"), "Got: {response}");
+
+    let response = send(
+        addr,
+        format!(
+            "GET {} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n",
+            transport::METRICS_PATH
+        ),
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200"), "Got: {response}");
+    assert!(
+        response.contains("content-type: text/plain; version=0.0.4"),
+        "Got: {response}"
+    );
+    assert!(
+        response.contains("mcp_time_tool_calls_total{tool=\"convert_time\"} 1"),
+        "Got: {response}"
+    );
+
+    stop.send(()).unwrap();
+    tokio::time::timeout(std::time::Duration::from_secs(5), server)
+        .await
+        .expect("server did not shut down")
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn http_transport_wraps_routes_in_the_given_layer() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let layer = axum::middleware::from_fn(
+        |request: axum::extract::Request, next: axum::middleware::Next| async move {
+            if request.headers().contains_key("x-api-key") {
+                let mut response = next.run(request).await;
+                response
+                    .headers_mut()
+                    .insert("x-checked", axum::http::HeaderValue::from_static("yes"));
+                response
+            } else {
+                axum::http::StatusCode::UNAUTHORIZED.into_response()
+            }
+        },
+    );
+    let server = tokio::spawn(transport::serve_http_with(
+        TimeServer::new(),
+        listener,
+        async move {
+            let _ = stopped.await;
+        },
+        layer,
+    ));
+
+    for path in [transport::WORLD_CLOCK_PATH, transport::METRICS_PATH] {
+        let response = send(
+            addr,
+            format!("GET {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n"),
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 401"), "Got: {response}");
+
+        let response = send(
+            addr,
+            format!(
+                "GET {path} HTTP/1.1\r\nHost: {addr}\r\nx-api-key: secret\r\nConnection: close\r\n\r\n"
+            ),
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 200"), "Got: {response}");
+        assert!(response.contains("x-checked: yes"), "Got: {response}");
+    }
+    let response = post(addr, r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#).await;
+    assert!(response.starts_with("HTTP/1.1 401"), "Got: {response}");
+
+    stop.send(()).unwrap();
+    tokio::time::timeout(std::time::Duration::from_secs(5), server)
+        .await
+        .expect("server did not shut down")
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn http_transport_applies_the_configured_middleware() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let server = TimeServer::new().http_middleware(Middleware {
+        auth_token: Some("secret".into()),
+        ..Default::default()
+    });
+    let server = tokio::spawn(transport::serve_http_on(server, listener, async move {
+        let _ = stopped.await;
+    }));
+
+    let path = transport::WORLD_CLOCK_PATH;
+    let response = send(
+        addr,
+        format!("GET {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n"),
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 401"), "Got: {response}");
+    let response = send(
+        addr,
+        format!(
+            "GET {path} HTTP/1.1\r\nHost: {addr}\r\nAuthorization: Bearer secret\r\nConnection: close\r\n\r\n"
+        ),
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200"), "Got: {response}");
+
+    stop.send(()).unwrap();
+    tokio::time::timeout(std::time::Duration::from_secs(5), server)
+        .await
+        .expect("server did not shut down")
+        .unwrap()
+        .unwrap();
+}
+
+/// Open an MCP session and return its ID.
+async fn open_session(addr: std::net::SocketAddr) -> String {
+    let response = post(
+        addr,
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18","capabilities":{},"clientInfo":{"name":"test","version":"1.0"}}}"#,
+    )
+    .await;
+    let session = response
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("mcp-session-id")
+                .then(|| value.trim().to_string())
+        })
+        .expect("no session ID");
+    send_in_session(
+        addr,
+        &session,
+        r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+    )
+    .await;
+    session
+}
+
+/// POST a JSON-RPC message within `session` and return the raw response.
+async fn send_in_session(addr: std::net::SocketAddr, session: &str, body: &str) -> String {
+    let request = format!(
+        "POST {} HTTP/1.1\r\nHost: {addr}\r\nContent-Type: application/json\r\nAccept: application/json, text/event-stream\r\nMcp-Session-Id: {session}\r\nMcp-Protocol-Version: 2025-06-18\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        transport::MCP_PATH,
+        body.len()
+    );
+    send(addr, request).await
+}
+
+#[tokio::test]
+async fn http_transport_rate_limits_clients_across_sessions() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let config = mcp_time::config::ServerConfig {
+        rate_limit: Some(1),
+        ..Default::default()
+    };
+    let server = tokio::spawn(transport::serve_http_on(
+        TimeServer::with_config(&config).unwrap(),
+        listener,
+        async move {
+            let _ = stopped.await;
+        },
+    ));
+
+    let call = r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"get_current_time","arguments":{}}}"#;
+    let first = open_session(addr).await;
+    let response = send_in_session(addr, &first, call).await;
+    assert!(response.contains(r#""isError":false"#), "Got: {response}");
+
+    // A new session from the same address does not get a new allowance.
+    let second = open_session(addr).await;
+    let response = send_in_session(addr, &second, call).await;
+    assert!(response.contains("rate_limited"), "Got: {response}");
+
+    stop.send(()).unwrap();
+    tokio::time::timeout(std::time::Duration::from_secs(5), server)
+        .await
+        .expect("server did not shut down")
+        .unwrap()
+        .unwrap();
+}
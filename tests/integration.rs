@@ -10,20 +10,75 @@ fn extract_text(result: &rmcp::model::CallToolResult) -> String {
 }
 
 #[tokio::test]
-async fn server_exposes_exactly_two_tools_with_metadata() {
+async fn server_exposes_expected_tools_with_metadata() {
     let server = TimeServer::new();
     let tools = server.tool_router.list_all();
 
-    assert_eq!(
-        tools.len(),
-        2,
-        "Expected exactly 2 tools, got {}",
-        tools.len()
-    );
-
     let mut names: Vec<&str> = tools.iter().map(|t| &*t.name).collect();
     names.sort();
-    assert_eq!(names, vec!["convert_time", "get_current_time"]);
+    let mut expected = vec![
+        "abbreviation_calendar",
+        "add_time",
+        "audit_dst",
+        "business_days",
+        "cancel_notification",
+        "cancel_timer",
+        "compare_timezones",
+        "convert_agenda",
+        "convert_calendar",
+        "convert_time",
+        "convert_time_multi",
+        "convert_timestamp",
+        "detect_format",
+        "detect_local_timezone",
+        "dst_impact",
+        "evaluate",
+        "explain_time",
+        "find_meeting_time",
+        "format_duration",
+        "get_calendar_info",
+        "get_current_time",
+        "get_dst_transitions",
+        "get_holidays",
+        "get_stopwatch",
+        "get_sun_times",
+        "get_tzdb_info",
+        "get_world_clock",
+        "global_dst_events",
+        "group_by_local_day",
+        "humanize_time",
+        "is_holiday",
+        "list_scheduled",
+        "list_timers",
+        "list_timezones",
+        "next_occurrences",
+        "normalize_epochs",
+        "parse_duration",
+        "parse_ics",
+        "parse_time",
+        "pause_stopwatch",
+        "recurrence_drift",
+        "resume_stopwatch",
+        "schedule_notification",
+        "serial_date",
+        "server_stats",
+        "set_timer",
+        "sql_literal",
+        "start_stopwatch",
+        "stop_stopwatch",
+        "subtract_time",
+        "time_difference",
+        "time_until",
+        "timezone_geo",
+        "travel_arrival",
+        "validate_datetime",
+        "wait",
+    ];
+    if cfg!(feature = "ntp") {
+        expected.push("check_clock_drift");
+        expected.sort();
+    }
+    assert_eq!(names, expected);
 
     for tool in &tools {
         assert!(
@@ -36,13 +91,162 @@ async fn server_exposes_exactly_two_tools_with_metadata() {
             "Tool '{}' should have an input schema",
             tool.name
         );
+        let schema = tool
+            .output_schema
+            .as_ref()
+            .unwrap_or_else(|| panic!("Tool '{}' should have an output schema", tool.name));
+        assert_eq!(schema["type"], "object", "Tool '{}'", tool.name);
     }
+
+    // One zone's time, or a `times` list for several zones.
+    let current_time = tools.iter().find(|t| t.name == "get_current_time").unwrap();
+    let shapes = current_time.output_schema.as_ref().unwrap()["anyOf"]
+        .as_array()
+        .unwrap();
+    assert_eq!(shapes.len(), 2);
+    assert_eq!(shapes[1]["properties"]["times"]["type"], "array");
+}
+
+#[tokio::test]
+async fn tool_schemas_describe_times_dates_and_timezones() {
+    let tools = TimeServer::new().tool_router.list_all();
+    let property = |tool: &str, name: &str| {
+        let tool = tools.iter().find(|t| t.name == tool).unwrap();
+        tool.input_schema["properties"][name].clone()
+    };
+
+    let time = property("convert_time", "time");
+    let pattern = time["pattern"].as_str().unwrap();
+    assert!(
+        pattern.starts_with('^') && pattern.ends_with('$'),
+        "{pattern}"
+    );
+    assert_eq!(time["examples"][0], "14:30");
+    assert_eq!(property("convert_time", "date")["format"], "date");
+    assert_eq!(
+        property("convert_time", "source_timezone")["examples"][0],
+        "America/New_York"
+    );
+    let zones = property("dst_impact", "timezones");
+    assert_eq!(zones["items"]["examples"][1], "Europe/London");
+    assert!(zones["examples"][0].is_array());
+    assert_eq!(
+        property("business_days", "holidays")["items"]["format"],
+        "date"
+    );
+    // Dates in other calendars are not ISO dates.
+    assert!(property("convert_calendar", "date").get("format").is_none());
+
+    // Counts carry the bounds the tools enforce.
+    let days = property("find_meeting_time", "days");
+    assert_eq!(
+        (&days["minimum"], &days["maximum"]),
+        (&1.into(), &14.into())
+    );
+    let limit = property("find_meeting_time", "limit");
+    assert_eq!(
+        (&limit["minimum"], &limit["maximum"]),
+        (&1.into(), &50.into())
+    );
+    assert_eq!(property("next_occurrences", "count")["maximum"], 1000);
+    assert_eq!(property("list_timezones", "limit")["maximum"], 1000);
+}
+
+#[tokio::test]
+async fn timezone_arguments_complete_via_protocol() {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (client, transport) = tokio::io::duplex(64 * 1024);
+    let server = tokio::spawn(TimeServer::new().run(transport));
+    let (read, mut write) = tokio::io::split(client);
+    let mut read = BufReader::new(read);
+    let mut send = async |message: serde_json::Value| {
+        write
+            .write_all(format!("{message}\n").as_bytes())
+            .await
+            .unwrap();
+    };
+
+    send(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0" }
+        }
+    }))
+    .await;
+    send(serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })).await;
+    let complete = |id: u32, reference: serde_json::Value, name: &str, value: &str| {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "completion/complete",
+            "params": { "ref": reference, "argument": { "name": name, "value": value } }
+        })
+    };
+    send(complete(
+        2,
+        serde_json::json!({ "type": "ref/prompt", "name": "explain_dst" }),
+        "timezone",
+        "europe/ber",
+    ))
+    .await;
+    send(complete(
+        3,
+        serde_json::json!({ "type": "ref/prompt", "name": "schedule_meeting" }),
+        "timezones",
+        "Europe/London, tok",
+    ))
+    .await;
+    send(complete(
+        4,
+        serde_json::json!({ "type": "ref/resource", "uri": "timezone://{+zone}" }),
+        "zone",
+        "",
+    ))
+    .await;
+    send(complete(
+        5,
+        serde_json::json!({ "type": "ref/prompt", "name": "explain_dst" }),
+        "year",
+        "20",
+    ))
+    .await;
+
+    let mut responses = Vec::new();
+    for _ in 0..5 {
+        let mut line = String::new();
+        read.read_line(&mut line).await.unwrap();
+        responses.push(serde_json::from_str::<serde_json::Value>(&line).unwrap());
+    }
+    assert!(responses[0]["result"]["capabilities"]["completions"].is_object());
+    let completion = |i: usize| responses[i]["result"]["completion"].clone();
+    assert_eq!(
+        completion(1)["values"],
+        serde_json::json!(["Europe/Berlin"])
+    );
+    assert_eq!(completion(2)["values"][0], "Europe/London, Asia/Tokyo");
+    let all = completion(3);
+    assert_eq!(all["values"].as_array().unwrap().len(), 100);
+    assert_eq!(all["hasMore"], true);
+    assert!(all["total"].as_u64().unwrap() > 300);
+    assert_eq!(completion(4)["values"], serde_json::json!([]));
+
+    drop(read);
+    drop(write);
+    server.await.unwrap().unwrap();
 }
 
 #[tokio::test]
 async fn get_current_time_returns_successful_result_via_protocol() {
     let server = TimeServer::new();
-    let params = GetCurrentTimeParams { timezone: None };
+    let params = GetCurrentTimeParams {
+        timezone: None,
+        ..Default::default()
+    };
     let result = server.get_current_time(Parameters(params)).await.unwrap();
 
     assert_eq!(result.is_error, Some(false));
@@ -61,6 +265,7 @@ async fn convert_time_returns_successful_result_via_protocol() {
         source_timezone: "UTC".into(),
         time: "12:00".into(),
         target_timezone: "Europe/London".into(),
+        ..Default::default()
     };
     let result = server.convert_time(Parameters(params)).await.unwrap();
 
@@ -72,11 +277,42 @@ async fn convert_time_returns_successful_result_via_protocol() {
     assert!(json["target"]["datetime"].is_string());
 }
 
+#[tokio::test]
+async fn tool_results_carry_structured_content_matching_the_text() {
+    let server = TimeServer::new();
+    let params = ConvertTimeParams {
+        source_timezone: "UTC".into(),
+        time: "12:00".into(),
+        target_timezone: "Asia/Tokyo".into(),
+        ..Default::default()
+    };
+    let result = server.convert_time(Parameters(params)).await.unwrap();
+    let text: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+    assert_eq!(result.structured_content, Some(text));
+
+    let params = GetCurrentTimeParams {
+        timezone: Some("UTC, Asia/Tokyo".into()),
+        ..Default::default()
+    };
+    let result = server.get_current_time(Parameters(params)).await.unwrap();
+    let text: serde_json::Value = serde_json::from_str(&extract_text(&result)).unwrap();
+    assert_eq!(text["times"].as_array().map(Vec::len), Some(2));
+    assert_eq!(result.structured_content, Some(text));
+
+    let params = GetCurrentTimeParams {
+        timezone: Some("Invalid/Timezone".into()),
+        ..Default::default()
+    };
+    let result = server.get_current_time(Parameters(params)).await.unwrap();
+    assert_eq!(result.structured_content, None);
+}
+
 #[tokio::test]
 async fn get_current_time_propagates_error_for_invalid_timezone() {
     let server = TimeServer::new();
     let params = GetCurrentTimeParams {
         timezone: Some("Invalid/Timezone".into()),
+        ..Default::default()
     };
     let result = server.get_current_time(Parameters(params)).await.unwrap();
 
@@ -91,3 +327,173 @@ async fn get_current_time_propagates_error_for_invalid_timezone() {
         "Error message should mention invalid timezone, got: {text}"
     );
 }
+
+#[tokio::test]
+async fn run_serves_over_any_transport_until_the_client_disconnects() {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (client, transport) = tokio::io::duplex(64 * 1024);
+    let server = tokio::spawn(TimeServer::new().run(transport));
+
+    let (read, mut write) = tokio::io::split(client);
+    let initialize = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0" }
+        }
+    });
+    write
+        .write_all(format!("{initialize}\n").as_bytes())
+        .await
+        .unwrap();
+    let mut response = String::new();
+    BufReader::new(read).read_line(&mut response).await.unwrap();
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(response["result"]["serverInfo"]["name"], "mcp-time");
+    let initialized = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/initialized"
+    });
+    write
+        .write_all(format!("{initialized}\n").as_bytes())
+        .await
+        .unwrap();
+
+    drop(write);
+    server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn prompts_are_listed_and_rendered_via_protocol() {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (client, transport) = tokio::io::duplex(64 * 1024);
+    let server = tokio::spawn(TimeServer::new().run(transport));
+    let (read, mut write) = tokio::io::split(client);
+    let mut read = BufReader::new(read);
+    let mut send = async |message: serde_json::Value| {
+        write
+            .write_all(format!("{message}\n").as_bytes())
+            .await
+            .unwrap();
+    };
+
+    send(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0" }
+        }
+    }))
+    .await;
+    send(serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })).await;
+    send(serde_json::json!({ "jsonrpc": "2.0", "id": 2, "method": "prompts/list" })).await;
+    send(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 3,
+        "method": "prompts/get",
+        "params": { "name": "explain_dst", "arguments": { "timezone": "Europe/Berlin", "year": "2026" } }
+    }))
+    .await;
+    send(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 4,
+        "method": "prompts/get",
+        "params": { "name": "explain_dst", "arguments": { "timezone": "Europe/Berln" } }
+    }))
+    .await;
+
+    let mut responses = Vec::new();
+    for _ in 0..4 {
+        let mut line = String::new();
+        read.read_line(&mut line).await.unwrap();
+        responses.push(serde_json::from_str::<serde_json::Value>(&line).unwrap());
+    }
+    assert!(responses[0]["result"]["capabilities"]["prompts"].is_object());
+    let prompts = responses[1]["result"]["prompts"].as_array().unwrap();
+    assert!(prompts.iter().any(|p| p["name"] == "schedule_meeting"));
+    let text = responses[2]["result"]["messages"][0]["content"]["text"]
+        .as_str()
+        .unwrap();
+    assert!(
+        text.contains("timezone 'Europe/Berlin' and year 2026"),
+        "{text}"
+    );
+    assert_eq!(responses[3]["error"]["code"], -32602);
+    assert_eq!(responses[3]["error"]["data"]["code"], "invalid_timezone");
+    assert_eq!(
+        responses[3]["error"]["data"]["suggestions"][0],
+        "Europe/Berlin"
+    );
+
+    drop(read);
+    drop(write);
+    server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn set_timer_notifies_the_client_when_it_fires() {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (client, transport) = tokio::io::duplex(64 * 1024);
+    let server = tokio::spawn(TimeServer::new().run(transport));
+    let (read, mut write) = tokio::io::split(client);
+    let mut read = BufReader::new(read);
+    let mut send = async |message: serde_json::Value| {
+        write
+            .write_all(format!("{message}\n").as_bytes())
+            .await
+            .unwrap();
+    };
+
+    send(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0" }
+        }
+    }))
+    .await;
+    send(serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })).await;
+    send(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": { "name": "set_timer", "arguments": { "label": "tea", "duration": "0.2 seconds" } }
+    }))
+    .await;
+
+    let mut messages = Vec::new();
+    let fired = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            let mut line = String::new();
+            read.read_line(&mut line).await.unwrap();
+            let message: serde_json::Value = serde_json::from_str(&line).unwrap();
+            if message["method"] == "notifications/message" {
+                return message;
+            }
+            messages.push(message);
+        }
+    })
+    .await
+    .expect("countdown should fire");
+    assert_eq!(fired["params"]["logger"], "mcp-time/set_timer");
+    assert_eq!(fired["params"]["data"]["message"], "tea");
+    assert_eq!(fired["params"]["data"]["kind"], "countdown");
+    let set = messages.iter().find(|m| m["id"] == 2).unwrap();
+    assert_eq!(set["result"]["structuredContent"]["id"], "countdown-1");
+
+    drop(read);
+    drop(write);
+    server.await.unwrap().unwrap();
+}
@@ -0,0 +1,621 @@
+//! Property-style robustness checks for the input parsers.
+//!
+//! Every string-accepting tool is fed generated arguments (fragments of
+//! valid values recombined, stray Unicode, and oversized strings) and must
+//! answer with a result, never a panic, within a bounded time. [`TARGETS`]
+//! lists each tool with the generator of its arguments. The generator is
+//! seeded, so failures reproduce; set `MCP_TIME_ROBUSTNESS_CASES` to run more
+//! cases locally. The `fuzz/` crate runs the underlying parsers under
+//! cargo-fuzz.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use mcp_time::server::TimeServer;
+use rmcp::handler::server::wrapper::Parameters;
+use serde_json::{Value, json};
+
+/// Longest a single tool call may take before it counts as a hang.
+const MAX_CALL: Duration = Duration::from_secs(2);
+
+/// Pieces of realistic input that generated strings are assembled from.
+const FRAGMENTS: &[&str] = &[
+    "2025",
+    "-07-04",
+    "T",
+    "12:00",
+    ":30",
+    ".123",
+    "Z",
+    "+05:30",
+    "-0800",
+    "+14",
+    "UTC",
+    "GMT",
+    "EST",
+    "America/",
+    "New_York",
+    "Europe/Berlin",
+    "Etc/GMT+5",
+    "Mumbai",
+    "14:30",
+    "2:30 PM",
+    "am",
+    "1430",
+    "930",
+    "1750000000",
+    "1750000000123",
+    "45678.5",
+    "e10",
+    "-",
+    "/",
+    " ",
+    ":",
+    "０９：３０",
+    "٢٠٢٥",
+    "\u{202f}",
+    "\u{3000}",
+    "日本",
+    "🕐",
+    "two ",
+    "hours",
+    " and a half",
+    "3 business days",
+    " from ",
+    " ago",
+    "Friday",
+    "de-DE",
+    "en_GB",
+    "-u-hc-h12",
+    "Reiwa ",
+    "H31",
+    "元年",
+    "月",
+    "\0",
+    "\u{fffd}",
+    "99999999999999999999",
+];
+
+/// Deterministic xorshift generator, so failing cases reproduce.
+struct Gen(u64);
+
+impl Gen {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// `value(self)` half of the time, otherwise `None`.
+    fn maybe<T>(&mut self, value: impl FnOnce(&mut Self) -> T) -> Option<T> {
+        (self.below(2) == 0).then(|| value(self))
+    }
+
+    /// A country code, mostly a real one so region and date parsing is
+    /// reached.
+    fn country(&mut self) -> String {
+        match self.below(4) {
+            0 => self.string(),
+            n => ["US", "gb", "DE"][n - 1].to_string(),
+        }
+    }
+
+    /// A string of up to eight fragments and random characters.
+    fn string(&mut self) -> String {
+        let mut out = String::new();
+        for _ in 0..=self.below(8) {
+            if self.below(4) == 0 {
+                let c = char::from_u32(self.below(0x3000) as u32).unwrap_or('?');
+                out.push(c);
+            } else {
+                out.push_str(FRAGMENTS[self.below(FRAGMENTS.len())]);
+            }
+        }
+        // Occasionally oversized, to catch work that grows with input length.
+        if self.below(50) == 0 {
+            out = out.repeat(500);
+        }
+        out
+    }
+}
+
+/// Parts of RRULEs and cron expressions, so both parsers get past the first
+/// token.
+const RULE_PARTS: &[&str] = &[
+    "FREQ=",
+    "MINUTELY",
+    "YEARLY",
+    "MONTHLY",
+    ";",
+    "=",
+    ",",
+    "BYDAY=",
+    "-1FR",
+    "53MO",
+    "BYMONTH=2",
+    "BYMONTHDAY=30",
+    "BYSETPOS=-1",
+    "INTERVAL=",
+    "COUNT=",
+    "UNTIL=",
+    "20250101T000000Z",
+    "DTSTART;TZID=",
+    "Europe/Berlin:",
+    "RRULE:",
+    "\n",
+    "*",
+    "*/",
+    "0",
+    "7",
+    "59",
+    "-",
+    " ",
+    "@daily",
+    "jan",
+    "mon-fri",
+];
+
+/// Values of the `ambiguity` and `gap` parameters.
+const AMBIGUITIES: &[&str] = &["earlier", "later", "reject"];
+const GAPS: &[&str] = &["next_valid", "reject"];
+
+/// A tool under test and the generator of its arguments.
+struct Target {
+    tool: &'static str,
+    arguments: fn(&mut Gen) -> Value,
+}
+
+/// Every tool that parses string input, with arguments shaped so that most
+/// cases get past the first field.
+const TARGETS: &[Target] = &[
+    Target {
+        tool: "get_current_time",
+        arguments: |g| json!({ "timezone": g.string(), "locale": g.string() }),
+    },
+    Target {
+        tool: "get_current_time",
+        // Lead with a directive so most inputs reach the strftime checks.
+        arguments: |g| {
+            json!({
+                "timezone": "Asia/Kathmandu",
+                "format": format!("%{}", g.string()),
+                "locale": g.string(),
+            })
+        },
+    },
+    Target {
+        tool: "get_world_clock",
+        arguments: |g| {
+            json!({
+                "timezones": [g.string(), "UTC"],
+                "group": g.maybe(Gen::string),
+                "locale": g.string(),
+            })
+        },
+    },
+    Target {
+        tool: "convert_time",
+        arguments: |g| {
+            json!({
+                "source_timezone": g.string(),
+                "time": g.string(),
+                "target_timezone": g.string(),
+                "allow_offsets": g.below(2) == 0,
+                "locale": g.string(),
+                "ambiguity": AMBIGUITIES[g.below(AMBIGUITIES.len())],
+                "gap": GAPS[g.below(GAPS.len())],
+            })
+        },
+    },
+    Target {
+        tool: "convert_time_multi",
+        arguments: |g| {
+            json!({
+                "source_timezone": g.string(),
+                "time": g.string(),
+                "target_timezones": (0..g.below(4)).map(|_| g.string()).collect::<Vec<_>>(),
+                "date": g.string(),
+                "allow_offsets": g.below(2) == 0,
+                "locale": g.string(),
+                "ambiguity": AMBIGUITIES[g.below(AMBIGUITIES.len())],
+                "gap": GAPS[g.below(GAPS.len())],
+            })
+        },
+    },
+    Target {
+        tool: "detect_format",
+        arguments: |g| json!({ "input": g.string() }),
+    },
+    Target {
+        tool: "validate_datetime",
+        arguments: |g| {
+            const FORMATS: &[&str] = &[
+                "rfc3339",
+                "rfc2822",
+                "iso8601",
+                "%d/%m/%Y %H:%M",
+                "%Y-%m-%dT%H:%M%z",
+            ];
+            json!({
+                "input": g.string(),
+                "format": g.maybe(|g| FORMATS[g.below(FORMATS.len())]),
+                "timezone": g.maybe(|_| "America/New_York"),
+            })
+        },
+    },
+    Target {
+        tool: "sql_literal",
+        arguments: |g| json!({ "datetime": g.string(), "timezone": g.string() }),
+    },
+    Target {
+        tool: "serial_date",
+        arguments: |g| json!({ "datetime": g.string(), "timezone": g.string() }),
+    },
+    Target {
+        tool: "group_by_local_day",
+        arguments: |g| {
+            json!({
+                "timestamps": (0..g.below(5)).map(|_| g.string()).collect::<Vec<_>>(),
+                "timezone": g.string(),
+                "include_empty_days": g.below(2) == 0,
+            })
+        },
+    },
+    Target {
+        tool: "audit_dst",
+        arguments: |g| {
+            json!({
+                "timestamps": (0..g.below(5)).map(|_| g.string()).collect::<Vec<_>>(),
+                "timezone": g.string(),
+            })
+        },
+    },
+    Target {
+        tool: "convert_timestamp",
+        arguments: |g| json!({ "timestamp": g.string(), "timezone": "Asia/Kathmandu" }),
+    },
+    Target {
+        tool: "parse_duration",
+        arguments: |g| {
+            json!({
+                "input": g.string(),
+                "timezone": "America/New_York",
+                "reference": g.maybe(Gen::string),
+            })
+        },
+    },
+    Target {
+        tool: "format_duration",
+        arguments: |g| {
+            const UNITS: &[&str] = &[
+                "week",
+                "day",
+                "hour",
+                "minute",
+                "second",
+                "millisecond",
+                "microsecond",
+                "nanosecond",
+            ];
+            const EPOCH_UNITS: &[&str] =
+                &["seconds", "milliseconds", "microseconds", "nanoseconds"];
+            json!({
+                "input": g.string(),
+                "unit": EPOCH_UNITS[g.below(EPOCH_UNITS.len())],
+                "largest_unit": UNITS[g.below(UNITS.len())],
+                "smallest_unit": UNITS[g.below(UNITS.len())],
+            })
+        },
+    },
+    Target {
+        tool: "evaluate",
+        arguments: |g| json!({ "expression": g.string(), "timezone": "Europe/Paris" }),
+    },
+    Target {
+        tool: "explain_time",
+        arguments: |g| {
+            json!({
+                "timestamp": g.string(),
+                "timezone": "Australia/Lord_Howe",
+                "reference": g.maybe(Gen::string),
+            })
+        },
+    },
+    Target {
+        tool: "parse_time",
+        arguments: |g| json!({ "text": g.string(), "timezone": "America/Santiago" }),
+    },
+    Target {
+        tool: "add_time",
+        arguments: |g| {
+            json!({
+                "datetime": g.string(),
+                "timezone": "America/New_York",
+                "duration": g.string(),
+                "locale": g.maybe(Gen::string),
+            })
+        },
+    },
+    Target {
+        tool: "time_difference",
+        arguments: |g| json!({ "start": g.string(), "end": g.string(), "timezone": "Pacific/Chatham" }),
+    },
+    Target {
+        tool: "time_until",
+        arguments: |g| json!({ "target": g.string(), "timezone": "Pacific/Chatham" }),
+    },
+    Target {
+        tool: "humanize_time",
+        arguments: |g| {
+            const UNITS: &[&str] = &["year", "month", "day", "hour", "minute", "second"];
+            json!({
+                "datetime": g.string(),
+                "reference": g.string(),
+                "timezone": g.string(),
+                "granularity": UNITS[g.below(UNITS.len())],
+                "max_units": g.below(8),
+            })
+        },
+    },
+    Target {
+        tool: "travel_arrival",
+        arguments: |g| {
+            json!({
+                "departure": g.string(),
+                "departure_timezone": g.string(),
+                "duration": g.string(),
+                "arrival_timezone": g.string(),
+            })
+        },
+    },
+    Target {
+        tool: "abbreviation_calendar",
+        arguments: |g| json!({ "timezone": g.string(), "year": (g.next() % 40_000) as i16 }),
+    },
+    Target {
+        tool: "list_timezones",
+        arguments: |g| {
+            json!({
+                "query": g.string(),
+                "region": g.string(),
+                "utc_offset": g.string(),
+                "cursor": g.below(1000),
+            })
+        },
+    },
+    Target {
+        tool: "get_sun_times",
+        arguments: |g| {
+            // Raw bits cover huge magnitudes and the poles' neighbourhood
+            // (JSON has no NaN or infinities, so those arrive as null).
+            let coordinate = |g: &mut Gen| match g.below(3) {
+                0 => f64::from_bits(g.next()),
+                1 => (g.below(18_001) as f64 - 9_000.0) / 100.0,
+                _ => (g.below(36_001) as f64 - 18_000.0) / 100.0,
+            };
+            let mut arguments = if g.below(2) == 0 {
+                json!({ "city": g.string() })
+            } else {
+                json!({ "latitude": coordinate(g), "longitude": coordinate(g) })
+            };
+            arguments["date"] = json!(g.maybe(Gen::string));
+            arguments["timezone"] = json!(g.maybe(Gen::string));
+            arguments["locale"] = json!(g.maybe(Gen::string));
+            arguments
+        },
+    },
+    Target {
+        tool: "business_days",
+        arguments: |g| {
+            json!({
+                "start_date": g.string(),
+                "end_date": g.maybe(Gen::string),
+                "days": g.maybe(|g| g.next() as i64 % 200_000),
+                "weekend": [g.string(), "sunday"],
+                "holidays": [g.string()],
+            })
+        },
+    },
+    Target {
+        tool: "is_holiday",
+        arguments: |g| json!({ "country": g.country(), "date": g.string(), "region": g.string() }),
+    },
+    Target {
+        tool: "get_holidays",
+        arguments: |g| {
+            json!({
+                "country": g.country(),
+                "year": g.maybe(|g| g.next() as i16),
+                "region": g.string(),
+            })
+        },
+    },
+    Target {
+        tool: "next_occurrences",
+        arguments: |g| {
+            let mut rule = String::new();
+            for _ in 0..=g.below(12) {
+                match g.below(5) {
+                    0 => rule.push_str(&g.string()),
+                    1 => rule.push_str(&g.next().to_string()),
+                    _ => rule.push_str(RULE_PARTS[g.below(RULE_PARTS.len())]),
+                }
+            }
+            json!({
+                "rule": rule,
+                "timezone": g.maybe(Gen::string),
+                "start": g.maybe(Gen::string),
+                "after": g.maybe(Gen::string),
+                "count": g.maybe(|g| g.below(1_200)),
+                "locale": g.maybe(Gen::string),
+            })
+        },
+    },
+    Target {
+        tool: "get_calendar_info",
+        arguments: |g| json!({ "date": g.maybe(Gen::string), "timezone": g.maybe(Gen::string) }),
+    },
+    Target {
+        tool: "convert_calendar",
+        arguments: |g| {
+            const CALENDARS: &[&str] = &["gregorian", "hebrew", "islamic", "japanese"];
+            json!({
+                "date": g.string(),
+                "calendar": CALENDARS[g.below(CALENDARS.len())],
+                "targets": (0..g.below(3))
+                    .map(|_| CALENDARS[g.below(CALENDARS.len())])
+                    .collect::<Vec<_>>(),
+                "timezone": g.string(),
+            })
+        },
+    },
+    Target {
+        tool: "find_meeting_time",
+        arguments: |g| {
+            let zone = |g: &mut Gen| {
+                json!({
+                    "timezone": match g.below(3) {
+                        0 => g.string(),
+                        n => ["Asia/Kathmandu", "America/St_Johns"][n - 1].to_string(),
+                    },
+                    "work_start": g.maybe(Gen::string),
+                    "work_end": g.maybe(Gen::string),
+                })
+            };
+            json!({
+                "participants": [zone(g), zone(g)],
+                "duration_minutes": g.maybe(|g| g.below(1_500)),
+                "date": g.maybe(Gen::string),
+                "days": g.maybe(|g| g.below(16)),
+                "step_minutes": g.maybe(|g| g.below(250)),
+                "include_weekends": g.below(2) == 0,
+                "locale": g.maybe(Gen::string),
+            })
+        },
+    },
+    Target {
+        tool: "compare_timezones",
+        arguments: |g| {
+            json!({
+                "first_timezone": g.string(),
+                "second_timezone": "Australia/Lord_Howe",
+                "start_date": g.string(),
+                "end_date": g.string(),
+            })
+        },
+    },
+];
+
+/// Call `tool` on `server` with `arguments`, read as its parameters.
+async fn call(
+    server: &TimeServer,
+    tool: &str,
+    arguments: Value,
+) -> Result<rmcp::model::CallToolResult, rmcp::ErrorData> {
+    macro_rules! dispatch {
+        ($($name:ident),* $(,)?) => {
+            match tool {
+                $(stringify!($name) => {
+                    let params = serde_json::from_value(arguments)
+                        .unwrap_or_else(|e| panic!("bad {tool} arguments: {e}"));
+                    server.$name(Parameters(params)).await
+                })*
+                _ => panic!("no tool named {tool}"),
+            }
+        };
+    }
+    dispatch!(
+        abbreviation_calendar,
+        add_time,
+        audit_dst,
+        business_days,
+        compare_timezones,
+        convert_calendar,
+        convert_time,
+        convert_time_multi,
+        convert_timestamp,
+        detect_format,
+        evaluate,
+        explain_time,
+        find_meeting_time,
+        format_duration,
+        get_calendar_info,
+        get_current_time,
+        get_holidays,
+        get_sun_times,
+        get_world_clock,
+        group_by_local_day,
+        humanize_time,
+        is_holiday,
+        list_timezones,
+        next_occurrences,
+        parse_duration,
+        parse_time,
+        serial_date,
+        sql_literal,
+        time_difference,
+        time_until,
+        travel_arrival,
+        validate_datetime,
+    )
+}
+
+fn cases() -> usize {
+    std::env::var("MCP_TIME_ROBUSTNESS_CASES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(300)
+}
+
+/// Call `target` with generated arguments, failing on panics, protocol
+/// errors, or slow calls.
+async fn exercise(server: Arc<TimeServer>, target: &'static Target, seed: u64) {
+    let mut generator = Gen(seed);
+    for _ in 0..cases() {
+        let arguments = (target.arguments)(&mut generator);
+        let input = arguments.to_string();
+        let shown = input.chars().take(80).collect::<String>();
+        let started = Instant::now();
+        // Run each call as its own task, so a panic names its input.
+        let server = server.clone();
+        let result = tokio::spawn(async move { call(&server, target.tool, arguments).await }).await;
+        let elapsed = started.elapsed();
+        let Ok(result) = result else {
+            panic!("{} panicked for input {shown:?}", target.tool);
+        };
+        assert!(
+            result.is_ok(),
+            "{}: protocol error for input {input:?}",
+            target.tool
+        );
+        assert!(
+            elapsed < MAX_CALL,
+            "{} took {elapsed:?} for input of {} bytes: {shown:?}",
+            target.tool,
+            input.len(),
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn parsers_never_panic() {
+    let server = Arc::new(
+        TimeServer::new()
+            .allow_city_names(true)
+            .lenient_parsing(true),
+    );
+    let mut runs = tokio::task::JoinSet::new();
+    for (seed, target) in (1..).zip(TARGETS) {
+        runs.spawn(exercise(server.clone(), target, seed));
+    }
+    while let Some(run) = runs.join_next().await {
+        if let Err(failure) = run {
+            std::panic::resume_unwind(failure.into_panic());
+        }
+    }
+}